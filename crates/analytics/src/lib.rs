@@ -63,7 +63,7 @@ use error_stack::report;
 use router_env::{
     logger,
     tracing::{self, instrument},
-    types::FlowMetric,
+    types::{FlowMetric, RateLimitedApi},
 };
 use storage_impl::config::Database;
 use strum::Display;
@@ -1167,4 +1167,37 @@ pub enum AnalyticsFlow {
     GetSankey,
 }
 
-impl FlowMetric for AnalyticsFlow {}
+impl FlowMetric for AnalyticsFlow {
+    fn get_rate_limited_api(&self) -> Option<RateLimitedApi> {
+        match self {
+            Self::GetInfo => None,
+            Self::GeneratePaymentReport
+            | Self::GenerateDisputeReport
+            | Self::GenerateRefundReport
+            | Self::GenerateAuthenticationReport => Some(RateLimitedApi::Export),
+            Self::GetGlobalSearchResults | Self::GetSearchResults => Some(RateLimitedApi::Search),
+            Self::GetPaymentMetrics
+            | Self::GetPaymentIntentMetrics
+            | Self::GetRefundsMetrics
+            | Self::GetFrmMetrics
+            | Self::GetSdkMetrics
+            | Self::GetAuthMetrics
+            | Self::GetAuthEventFilters
+            | Self::GetActivePaymentsMetrics
+            | Self::GetPaymentFilters
+            | Self::GetPaymentIntentFilters
+            | Self::GetRefundFilters
+            | Self::GetFrmFilters
+            | Self::GetSdkEventFilters
+            | Self::GetApiEvents
+            | Self::GetSdkEvents
+            | Self::GetApiEventMetrics
+            | Self::GetApiEventFilters
+            | Self::GetConnectorEvents
+            | Self::GetOutgoingWebhookEvents
+            | Self::GetDisputeFilters
+            | Self::GetDisputeMetrics
+            | Self::GetSankey => Some(RateLimitedApi::Analytics),
+        }
+    }
+}
@@ -682,6 +682,98 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// If this property is true, a change of the webhook URL takes effect only after it is confirmed using the token sent to the new URL
+    #[schema(example = false)]
+    pub url_change_confirmation_enabled: Option<bool>,
+
+    /// The policy for handling redirects returned by the webhook endpoint. Redirects are refused by default
+    #[schema(value_type = Option<WebhookRedirectPolicy>, example = "refuse")]
+    pub redirect_policy: Option<api_enums::WebhookRedirectPolicy>,
+
+    /// The webhook URL change awaiting confirmation, if any. This is ignored when passed in the request
+    #[serde(default, skip_deserializing)]
+    pub pending_url_change: Option<PendingWebhookUrlChange>,
+
+    /// The configuration of the daily digest of the webhook delivery failures of the profile
+    pub digest_config: Option<WebhookDigestConfig>,
+
+    /// The channel through which the merchant is notified about problems with the delivery of the webhooks, such as the webhook endpoint being down
+    pub secondary_notification_config: Option<WebhookSecondaryNotificationConfig>,
+
+    /// The event types whose webhooks are sent, the webhooks of all the event types are sent if not specified or empty. This is ignored when passed in the request, the event types are updated using the Update Event Subscription API
+    #[serde(default, skip_deserializing)]
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "payment_failed"]))]
+    pub enabled_event_types: Option<Vec<api_enums::EventType>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookDigestConfig {
+    /// Whether the daily digest is sent
+    #[schema(example = true)]
+    pub enabled: bool,
+
+    /// The hour of the day (0 to 23), in the specified timezone, at which the digest of the preceding day is sent
+    #[schema(minimum = 0, maximum = 23, example = 9)]
+    pub hour_of_day: u8,
+
+    /// The IANA name of the timezone in which the hour of the day is specified
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: String,
+
+    /// The channels through which the digest is sent
+    #[schema(value_type = Vec<WebhookDigestChannel>, example = json!(["webhook", "email"]))]
+    pub channels: Vec<api_enums::WebhookDigestChannel>,
+
+    /// The email address to which the digest is emailed, the primary email of the merchant is used if not specified
+    #[schema(value_type = Option<String>, max_length = 255, example = "johndoe@test.com")]
+    pub email_recipient: Option<pii::Email>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSecondaryNotificationConfig {
+    /// Whether the notifications are sent
+    #[schema(example = true)]
+    pub enabled: bool,
+
+    /// The url to which the notifications are posted, which must be different from the url of the webhook endpoint
+    #[schema(value_type = Option<String>, example = "https://www.ekart.com/webhook-alerts")]
+    pub notification_url: Option<Secret<String>>,
+
+    /// The email address to which the notifications are emailed, if an email provider is configured
+    #[schema(value_type = Option<String>, max_length = 255, example = "alerts@ekart.com")]
+    pub email_recipient: Option<pii::Email>,
+
+    /// The kinds of the notifications which are sent, all the kinds are sent if not specified
+    #[schema(value_type = Option<Vec<WebhookMetaNotificationKind>>, example = json!(["delivery_backlog_threshold_crossed", "secret_rotation_reminder"]))]
+    pub notification_kinds: Option<Vec<api_enums::WebhookMetaNotificationKind>>,
+
+    /// The number of the webhooks pending delivery above which the merchant is notified, a default threshold is used if not specified
+    #[schema(minimum = 1, example = 500)]
+    pub backlog_threshold: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema, PartialEq)]
+pub struct PendingWebhookUrlChange {
+    /// The new url for the webhook endpoint
+    #[schema(value_type = String, example = "www.ekart.com/webhooks")]
+    pub webhook_url: Secret<String>,
+
+    /// The status of the webhook URL change
+    #[schema(value_type = WebhookUrlChangeStatus, example = "pending")]
+    pub status: api_enums::WebhookUrlChangeStatus,
+
+    /// The time at which the change was requested
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub requested_at: time::PrimitiveDateTime,
+
+    /// The time after which the change can no longer be confirmed
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -976,6 +1068,31 @@ pub struct RevenueRecoveryMetadata {
     /// Merchants need to provide a mapping between these merchant connector account and the corresponding account reference IDs for each `billing connector`.
     #[schema(value_type = u16, example = r#"{ "mca_vDSg5z6AxnisHq5dbJ6g": "stripe_123", "mca_vDSg5z6AumisHqh4x5m1": "adyen_123" }"#)]
     pub billing_account_reference: HashMap<id_type::MerchantConnectorAccountId, String>,
+    /// Whether the open invoices of the `billing connector` are reconciled nightly against their status at the billing connector, to catch the invoices paid or voided there whose webhooks were missed.
+    #[serde(default)]
+    #[schema(value_type = bool, example = false)]
+    pub reconciliation_enabled: bool,
+    /// Polling of the `billing connector` for the transactions of the invoices whose webhooks have gone quiet. Polling is disabled when not provided.
+    #[schema(value_type = Option<RevenueRecoveryPaymentsSyncPolling>)]
+    pub payments_sync_polling: Option<RevenueRecoveryPaymentsSyncPolling>,
+    /// Number of seconds after which a payments sync call to the `billing connector` is timed out. The timeout configured for the deployment is used when not provided.
+    #[schema(value_type = Option<u16>, example = 10)]
+    pub payments_sync_timeout_in_secs: Option<u16>,
+    /// Whether the payments sync of the `billing connector` is called to get the details of the transactions of the webhooks, for the integrations whose webhooks do not carry all of them. The `billing connectors` configured for the deployment are used when not provided.
+    #[schema(value_type = Option<bool>, example = true)]
+    pub payments_sync_required: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Polling of the payments sync of a `billing connector`, for the `billing connectors` with unreliable webhook delivery
+pub struct RevenueRecoveryPaymentsSyncPolling {
+    /// Number of hours without any transaction event of an invoice after which the `billing connector` is polled for the transactions of the invoice. The interval between two polls doubles after every poll which finds no new transaction.
+    #[schema(value_type = u16, example = 6)]
+    pub quiet_period_in_hours: u16,
+    /// Number of consecutive polls finding no new transaction after which the invoice is no longer polled
+    #[schema(value_type = u16, example = 5)]
+    pub max_empty_polls: u16,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -1052,6 +1169,14 @@ pub struct MerchantConnectorWebhookDetails {
     pub merchant_secret: Secret<String>,
     #[schema(value_type = String, example = "12345678900987654321")]
     pub additional_secret: Option<Secret<String>>,
+    /// Maximum size of the body of the incoming webhooks in bytes, overriding the limit configured
+    /// for all the connectors
+    #[schema(example = 1048576)]
+    pub max_body_size_in_bytes: Option<u32>,
+    /// Content types accepted for the incoming webhooks, overriding the content types configured
+    /// for all the connectors. Any content type is accepted when empty.
+    #[schema(example = json!(["application/json"]))]
+    pub accepted_content_types: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
@@ -2424,6 +2549,69 @@ pub struct ProfileResponse {
     //Merchant country for the profile
     #[schema(value_type = Option<CountryAlpha2>, example = "US")]
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
+
+    /// Merchant Connector id of the payment connector used for revenue recovery retries when the
+    /// billing connector does not provide a mapping for the payment connector account
+    #[schema(value_type = Option<String>)]
+    pub default_recovery_payment_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
+    /// Payment connectors to which the revenue recovery retries of a payment switch, in order,
+    /// after repeated soft declines on its current payment connector
+    #[schema(value_type = Option<RevenueRecoveryConnectorFallback>)]
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+
+    /// Notification sent ahead of every revenue recovery retry of a payment, through the
+    /// `recovery.retry_upcoming` event
+    #[schema(value_type = Option<RevenueRecoveryRetryNotification>)]
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+
+    /// Calendar of the dates on which the revenue recovery retries are not made, the retries
+    /// falling within a blackout being moved to its end
+    #[schema(value_type = Option<RevenueRecoveryBlackoutCalendar>)]
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
+
+    /// The capabilities of the profile, present only when requested with `include_capabilities=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<ProfileCapabilities>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileRetrieveQuery {
+    /// Whether the capabilities of the profile are included in the response
+    #[schema(default = false, example = true)]
+    pub include_capabilities: Option<bool>,
+}
+
+/// A summary of the features configured for the profile, used by the dashboard for deciding which
+/// sections to show
+#[cfg(feature = "v2")]
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize, PartialEq)]
+pub struct ProfileCapabilities {
+    /// Whether revenue recovery is enabled, i.e. the profile has an enabled billing connector
+    #[schema(example = true)]
+    pub is_revenue_recovery_enabled: bool,
+
+    /// The number of the billing connector accounts of the profile, including the disabled ones
+    #[schema(example = 1)]
+    pub billing_connector_accounts_count: usize,
+
+    /// The number of the webhook endpoints configured for the profile, including the url of the
+    /// secondary notification channel when it is enabled
+    #[schema(example = 1)]
+    pub webhook_endpoints_count: usize,
+
+    /// Whether the delivery of the outgoing webhooks is disabled on the server
+    #[schema(example = false)]
+    pub is_webhook_delivery_disabled: bool,
+
+    /// The number of days for which the events of the profile can be listed
+    #[schema(example = 90)]
+    pub events_retention_days: u16,
 }
 
 #[cfg(feature = "v1")]
@@ -2698,6 +2886,29 @@ pub struct ProfileUpdate {
     //Merchant country for the profile
     #[schema(value_type = Option<CountryAlpha2>, example = "US")]
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
+
+    /// Merchant Connector id of the payment connector used for revenue recovery retries when the
+    /// billing connector does not provide a mapping for the payment connector account
+    #[schema(value_type = Option<String>)]
+    pub default_recovery_payment_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
+    /// Payment connectors to which the revenue recovery retries of a payment switch, in order,
+    /// after repeated soft declines on its current payment connector
+    #[schema(value_type = Option<RevenueRecoveryConnectorFallback>)]
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+
+    /// Notification sent ahead of every revenue recovery retry of a payment, through the
+    /// `recovery.retry_upcoming` event
+    #[schema(value_type = Option<RevenueRecoveryRetryNotification>)]
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+
+    /// Calendar of the dates on which the revenue recovery retries are not made, the retries
+    /// falling within a blackout being moved to its end
+    #[schema(value_type = Option<RevenueRecoveryBlackoutCalendar>)]
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
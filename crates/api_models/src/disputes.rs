@@ -55,6 +55,9 @@ pub struct DisputeResponse {
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     #[schema(value_type = Option<String>)]
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    /// Version of the dispute, incremented on every update of its status. A later state of the dispute always carries a greater version
+    #[schema(example = 2)]
+    pub entity_version: Option<i64>,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
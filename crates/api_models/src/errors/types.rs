@@ -96,7 +96,11 @@ pub enum ApiErrorResponse {
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
+    PayloadTooLarge(ApiError),
+    UnsupportedMediaType(ApiError),
     DomainError(ApiError),
+    /// Rate limit exceeded, along with the number of seconds after which the request can be retried
+    TooManyRequests(ApiError, #[serde(skip_serializing)] u64),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -125,8 +129,11 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::PayloadTooLarge(i)
+            | Self::UnsupportedMediaType(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -143,8 +150,11 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::PayloadTooLarge(i)
+            | Self::UnsupportedMediaType(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -159,9 +169,12 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::PayloadTooLarge(_)
+            | Self::UnsupportedMediaType(_) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
+            Self::TooManyRequests(_, _) => "rate_limit",
             Self::ConnectorError(_, _) => "connector",
         }
     }
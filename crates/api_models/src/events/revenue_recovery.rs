@@ -1,6 +1,25 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::process_tracker::revenue_recovery::{RevenueRecoveryId, RevenueRecoveryResponse};
+use crate::{
+    pagination::PaginatedListResponse,
+    process_tracker::revenue_recovery::{
+        RecoveryTaskResponse, RevenueRecoveryAnalyticsRequest, RevenueRecoveryAnalyticsResponse,
+        RevenueRecoveryBillingSyncRequest, RevenueRecoveryBillingSyncResponse,
+        RevenueRecoveryCancelResponse, RevenueRecoveryCollectionAssignRequest,
+        RevenueRecoveryCollectionItemResponse, RevenueRecoveryCollectionListConstraints,
+        RevenueRecoveryCollectionResolveRequest, RevenueRecoveryDecisionListResponse,
+        RevenueRecoveryId, RevenueRecoveryKillSwitchRequest, RevenueRecoveryKillSwitchResponse,
+        RevenueRecoveryOverdueTasksResponse, RevenueRecoveryOverviewRequest,
+        RevenueRecoveryOverviewResponse, RevenueRecoveryReconciliationReportsResponse,
+        RevenueRecoveryRescheduleResponse, RevenueRecoveryResponse,
+        RevenueRecoveryRetryOverrideResponse, RevenueRecoveryRetryScheduleDeleteResponse,
+        RevenueRecoveryRetryScheduleRequest, RevenueRecoveryRetryScheduleResponse,
+        RevenueRecoveryReviewTaskListConstraints, RevenueRecoverySchedulePreviewRequest,
+        RevenueRecoverySchedulePreviewResponse, RevenueRecoveryStatusResponse,
+        RevenueRecoveryTaskBulkRequeueRequest, RevenueRecoveryTaskBulkRequeueResponse,
+        RevenueRecoveryTaskListConstraints, RevenueRecoveryTaskSnapshotResponse,
+    },
+};
 
 impl ApiEventMetric for RevenueRecoveryResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -12,3 +31,175 @@ impl ApiEventMetric for RevenueRecoveryId {
         Some(ApiEventsType::ProcessTracker)
     }
 }
+impl ApiEventMetric for RevenueRecoveryAnalyticsRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryAnalyticsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryRetryOverrideResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for RevenueRecoveryCancelResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for RevenueRecoveryRescheduleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for RevenueRecoveryStatusResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for RevenueRecoveryDecisionListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for RevenueRecoveryCollectionListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for PaginatedListResponse<RevenueRecoveryCollectionItemResponse> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryCollectionAssignRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryCollectionResolveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryCollectionItemResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for RevenueRecoveryTaskListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for PaginatedListResponse<RecoveryTaskResponse> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryReviewTaskListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RecoveryTaskResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryTaskBulkRequeueRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryTaskBulkRequeueResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryKillSwitchRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryKillSwitchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryRetryScheduleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryRetryScheduleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryRetryScheduleDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryReconciliationReportsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryOverdueTasksResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoverySchedulePreviewRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoverySchedulePreviewResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryOverviewRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryOverviewResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryTaskSnapshotResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryBillingSyncRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for RevenueRecoveryBillingSyncResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
@@ -24,6 +24,7 @@ pub mod health_check;
 pub mod locker_migration;
 pub mod mandates;
 pub mod organization;
+pub mod pagination;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
@@ -0,0 +1,226 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use utoipa::ToSchema;
+
+use crate::{
+    process_tracker::revenue_recovery::{
+        RecoveryTaskResponse, RevenueRecoveryCollectionItemResponse,
+    },
+    webhook_events::{EventDeliveryAttemptResponse, EventListItemResponse, EventsArchiveResponse},
+};
+
+/// The envelope of the responses of the list apis.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[aliases(
+    EventListResponse = PaginatedListResponse<EventListItemResponse>,
+    EventDeliveryAttemptListResponse = PaginatedListResponse<EventDeliveryAttemptResponse>,
+    EventsArchiveListResponse = PaginatedListResponse<EventsArchiveResponse>,
+    RecoveryTaskListResponse = PaginatedListResponse<RecoveryTaskResponse>,
+    RecoveryCollectionItemListResponse = PaginatedListResponse<RevenueRecoveryCollectionItemResponse>
+)]
+pub struct PaginatedListResponse<T> {
+    /// The items of the page
+    pub items: Vec<T>,
+    /// Total number of items matching the constraints of the request, present only for the apis
+    /// which count them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i64>,
+    /// Cursor to be passed for fetching the next page, present only for the apis which are paged
+    /// by a cursor, when there may be more items after the page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Max number of items in the page
+    #[schema(example = 10)]
+    pub limit: u16,
+}
+
+impl<T> PaginatedListResponse<T> {
+    pub fn new(items: Vec<T>, limit: u16) -> Self {
+        Self {
+            items,
+            total_count: None,
+            next_cursor: None,
+            limit,
+        }
+    }
+
+    pub fn with_total_count(self, total_count: i64) -> Self {
+        Self {
+            total_count: Some(total_count),
+            ..self
+        }
+    }
+
+    /// Sets the cursor of the next page when the page is full, from the last item of the page
+    pub fn with_next_cursor(self, get_cursor: impl FnOnce(&T) -> String) -> Self {
+        let next_cursor = self
+            .items
+            .last()
+            .filter(|_| self.items.len() >= usize::from(self.limit))
+            .map(get_cursor);
+
+        Self {
+            next_cursor,
+            ..self
+        }
+    }
+
+    /// Keeps returning the page under the field names used by the api before it was migrated to
+    /// the envelope
+    pub fn with_legacy_fields(self, legacy_fields: LegacyListFields) -> LegacyListResponse<T> {
+        LegacyListResponse {
+            page: self,
+            legacy_fields,
+        }
+    }
+
+    /// Keeps returning the page as a bare array of its items, as the api did before it was
+    /// migrated to the envelope, unless the envelope is requested
+    pub fn with_legacy_array(self, is_envelope_requested: bool) -> LegacyArrayListResponse<T> {
+        LegacyArrayListResponse {
+            page: self,
+            is_envelope_requested,
+        }
+    }
+}
+
+/// Field names under which a list api returned its page before it was migrated to the envelope
+#[derive(Debug, Clone, Copy)]
+pub struct LegacyListFields {
+    /// Field of the items of the page
+    pub items: &'static str,
+    /// Field of the number of items in the page, if any
+    pub count: Option<&'static str>,
+}
+
+/// The envelope of the responses of the list apis along with the fields the api returned before it
+/// was migrated to the envelope. The legacy fields are deprecated, and are returned only until the
+/// clients have moved to the envelope.
+#[derive(Debug, Clone)]
+pub struct LegacyListResponse<T> {
+    pub page: PaginatedListResponse<T>,
+    pub legacy_fields: LegacyListFields,
+}
+
+impl<T: Serialize> Serialize for LegacyListResponse<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let page = &self.page;
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry("items", &page.items)?;
+        if let Some(total_count) = page.total_count {
+            map.serialize_entry("total_count", &total_count)?;
+        }
+        if let Some(next_cursor) = &page.next_cursor {
+            map.serialize_entry("next_cursor", next_cursor)?;
+        }
+        map.serialize_entry("limit", &page.limit)?;
+
+        map.serialize_entry(self.legacy_fields.items, &page.items)?;
+        if let Some(count) = self.legacy_fields.count {
+            map.serialize_entry(count, &page.items.len())?;
+        }
+
+        map.end()
+    }
+}
+
+impl<T> ApiEventMetric for LegacyListResponse<T>
+where
+    PaginatedListResponse<T>: ApiEventMetric,
+{
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.page.get_api_event_type()
+    }
+}
+
+/// The envelope of the responses of the list apis, for an api which returned a bare array of its
+/// items before it was migrated to the envelope. The bare array is returned unless the envelope is
+/// requested, until the clients have moved to the envelope.
+#[derive(Debug, Clone)]
+pub struct LegacyArrayListResponse<T> {
+    pub page: PaginatedListResponse<T>,
+    pub is_envelope_requested: bool,
+}
+
+impl<T: Serialize> Serialize for LegacyArrayListResponse<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.is_envelope_requested {
+            self.page.serialize(serializer)
+        } else {
+            self.page.items.serialize(serializer)
+        }
+    }
+}
+
+impl<T> ApiEventMetric for LegacyArrayListResponse<T>
+where
+    PaginatedListResponse<T>: ApiEventMetric,
+{
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.page.get_api_event_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_recovery_list_response_keeps_legacy_fields() {
+        let response = PaginatedListResponse::new(vec!["task_1", "task_2"], 2)
+            .with_next_cursor(|task| task.to_string())
+            .with_legacy_fields(
+                crate::process_tracker::revenue_recovery::RECOVERY_LIST_LEGACY_FIELDS,
+            );
+
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({
+                "items": ["task_1", "task_2"],
+                "next_cursor": "task_2",
+                "limit": 2,
+                "data": ["task_1", "task_2"],
+                "count": 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_event_list_response_keeps_legacy_fields() {
+        let response = PaginatedListResponse::new(vec!["evt_1"], 10)
+            .with_total_count(25)
+            .with_next_cursor(|event| event.to_string())
+            .with_legacy_fields(crate::webhook_events::EVENT_LIST_LEGACY_FIELDS);
+
+        // The page is not full, so there is no next page
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({
+                "items": ["evt_1"],
+                "total_count": 25,
+                "limit": 10,
+                "events": ["evt_1"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_delivery_attempt_list_is_a_bare_array_unless_envelope_is_requested() {
+        let page = PaginatedListResponse::new(vec!["evt_1", "evt_1_retry"], 2);
+
+        assert_eq!(
+            serde_json::to_value(page.clone().with_legacy_array(false)).unwrap(),
+            serde_json::json!(["evt_1", "evt_1_retry"])
+        );
+        assert_eq!(
+            serde_json::to_value(page.with_legacy_array(true)).unwrap(),
+            serde_json::json!({
+                "items": ["evt_1", "evt_1_retry"],
+                "limit": 2,
+            })
+        );
+    }
+}
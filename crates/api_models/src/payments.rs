@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt,
     num::NonZeroI64,
 };
@@ -1630,6 +1630,21 @@ pub struct PaymentAttemptRevenueRecoveryData {
     /// Flag to find out whether an attempt was created by external or internal system.
     #[schema(value_type = Option<TriggeredBy>, example = "internal")]
     pub attempt_triggered_by: common_enums::TriggeredBy,
+    /// Raw response details of the payment processor as reported by the billing connector, like response code and avs / cvv check results.
+    #[schema(value_type = Option<ProcessorResponseDetails>)]
+    pub processor_response_details: Option<common_types::payments::ProcessorResponseDetails>,
+    /// Adjustments applied on the retry schedule which resulted in this attempt, present only for internally triggered attempts.
+    #[schema(value_type = Option<Vec<RecoveryScheduleAdjustment>>)]
+    pub schedule_adjustments: Option<Vec<common_enums::RecoveryScheduleAdjustment>>,
+    /// Source of each field of the recovery data from which the attempt was recorded, present only when the recording of the data provenance is enabled.
+    #[schema(value_type = Option<HashMap<String, RecoveryDataSource>>, example = json!({"processor_payment_method_token": "webhook", "amount": "sync"}))]
+    pub data_provenance: Option<BTreeMap<String, common_enums::RecoveryDataSource>>,
+    /// Identifier of the billing connector webhook from which the attempt originated, shared by the attempt recorded from the webhook and the retries scheduled by it.
+    #[schema(example = "rrd_01H8V3PZ7J5K6M4N2Q9R0S1T2U")]
+    pub recovery_correlation_id: Option<String>,
+    /// Switch of the payment connector of the retries to the payment connector of this attempt, along with the reason for the switch. Present for the retries made on a payment connector the retries were switched to.
+    #[schema(value_type = Option<RevenueRecoveryConnectorSwitch>)]
+    pub connector_switch: Option<common_types::payments::RevenueRecoveryConnectorSwitch>,
 }
 
 #[derive(
@@ -5118,6 +5133,10 @@ pub struct PaymentsResponse {
 
     /// Error message received from the issuer in case of failed payments
     pub issuer_error_message: Option<String>,
+
+    /// Version of the payment, incremented on every update of its status. A later state of the payment always carries a greater version, which can be used to discard webhooks delivered out of order
+    #[schema(example = 2)]
+    pub entity_version: Option<i64>,
 }
 
 #[cfg(feature = "v2")]
@@ -5682,18 +5701,23 @@ pub struct PaymentsResponse {
 
 #[cfg(feature = "v2")]
 impl PaymentsResponse {
+    /// Finds the attempt made with the connector transaction id. The transaction ids of the
+    /// attempts are normalized with `normalize_transaction_id` before being compared with the
+    /// already normalized `connector_transaction_id`.
     pub fn find_attempt_in_attempts_list_using_connector_transaction_id(
         self,
         connector_transaction_id: &common_utils::types::ConnectorTransactionId,
+        normalize_transaction_id: impl Fn(
+            &common_utils::types::ConnectorTransactionId,
+        ) -> common_utils::types::ConnectorTransactionId,
     ) -> Option<PaymentAttemptResponse> {
         self.attempts
             .as_ref()
             .and_then(|attempts| {
                 attempts.iter().find(|attempt| {
-                    attempt
-                        .connector_payment_id
-                        .as_ref()
-                        .is_some_and(|txn_id| txn_id == connector_transaction_id)
+                    attempt.connector_payment_id.as_ref().is_some_and(|txn_id| {
+                        normalize_transaction_id(txn_id) == *connector_transaction_id
+                    })
                 })
             })
             .cloned()
@@ -7603,6 +7627,9 @@ pub struct FeatureMetadata {
     pub apple_pay_recurring_details: Option<ApplePayRecurringDetails>,
     /// revenue recovery data for payment intent
     pub payment_revenue_recovery_metadata: Option<PaymentRevenueRecoveryMetadata>,
+    /// Subscription id at the billing connector, to which the invoice of the revenue recovery payment belongs
+    #[schema(value_type = Option<String>, example = "sub_1234567890")]
+    pub billing_connector_subscription_id: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -7622,6 +7649,7 @@ impl FeatureMetadata {
             search_tags: self.search_tags,
             apple_pay_recurring_details: self.apple_pay_recurring_details,
             payment_revenue_recovery_metadata: Some(payment_revenue_recovery_metadata),
+            billing_connector_subscription_id: self.billing_connector_subscription_id,
         }
     }
 }
@@ -8547,7 +8575,84 @@ pub struct PaymentRevenueRecoveryMetadata {
     /// The name of the payment connector through which the payment attempt was made.
     #[schema(value_type = Connector, example = "stripe")]
     pub connector: common_enums::connector_enums::Connector,
+    /// Override of the retry threshold and schedule, applied for this payment alone
+    pub retry_override: Option<RevenueRecoveryRetryOverride>,
+    /// Hold on the retries of the payment, while a dispute raised by the customer is open
+    pub dispute_hold: Option<RevenueRecoveryDisputeHold>,
+    /// Identifier of the billing connector webhook which last recorded an attempt for the payment
+    #[schema(example = "rrd_01H8V3PZ7J5K6M4N2Q9R0S1T2U")]
+    pub recovery_correlation_id: Option<String>,
+    /// Manual cancellation of the scheduled retry of the payment, if any
+    pub manual_cancellation: Option<RevenueRecoveryManualCancellation>,
+    /// Rotation of the payment connectors of the retries, when the connector fallback of the
+    /// profile is configured
+    #[schema(value_type = Option<RevenueRecoveryConnectorRotation>)]
+    pub connector_rotation: Option<common_types::payments::RevenueRecoveryConnectorRotation>,
+    /// Status of the record back of the outcome of the retries to the billing connector, not
+    /// present until the retries of the payment are over
+    #[schema(value_type = Option<RecoveryRecordBackStatus>, example = "completed")]
+    pub record_back_status: Option<common_enums::RecoveryRecordBackStatus>,
 }
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryRetryOverride {
+    /// Number of retries allowed for the payment after the override was applied
+    #[schema(value_type = u16, example = 3)]
+    pub additional_retries: u16,
+    /// Times at which the upcoming retries are to be made, in ascending order. Retries beyond the
+    /// schedule follow the retry schedule configured for the merchant
+    #[schema(value_type = Vec<PrimitiveDateTime>, example = json!(["2025-01-01T10:00:00.000Z"]))]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::vec")]
+    pub schedule: Vec<PrimitiveDateTime>,
+    /// Retry count of the payment at the time the override was applied
+    #[schema(value_type = u16, example = 5)]
+    pub retry_count_at_override: u16,
+    /// The reason for which the override was applied
+    #[schema(example = "High value invoice, retry daily")]
+    pub reason: String,
+    /// The user or the api key which applied the override
+    pub created_by: String,
+    /// The time at which the override was applied
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryDisputeHold {
+    /// The time at which the dispute was opened by the customer
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub held_at: PrimitiveDateTime,
+    /// The time after which the retries are resumed, even if the dispute has not been resolved
+    #[schema(value_type = PrimitiveDateTime, example = "2025-04-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryManualCancellation {
+    /// The process tracker task which was cancelled
+    pub task_id: String,
+    /// The user or the api key which cancelled the task
+    pub cancelled_by: String,
+    /// The time at which the task was cancelled
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub cancelled_at: PrimitiveDateTime,
+}
+
+#[cfg(feature = "v2")]
+impl RevenueRecoveryDisputeHold {
+    /// Holds stop pausing the retries once they expire
+    pub fn is_active(&self, current_time: PrimitiveDateTime) -> bool {
+        self.expires_at > current_time
+    }
+}
+
 #[cfg(feature = "v2")]
 impl PaymentRevenueRecoveryMetadata {
     pub fn set_payment_transmission_field_for_api_request(
@@ -8577,6 +8682,8 @@ pub struct BillingConnectorPaymentDetails {
     pub payment_processor_token: String,
     /// Billing Connector's Customer Id
     pub connector_customer_id: String,
+    /// Details of the card of the payment processor token, as reported by the billing connector
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
 }
 
 // Serialize is required because the api event requires Serialize to be implemented
@@ -8648,6 +8755,9 @@ pub struct PaymentsAttemptRecordRequest {
     /// customer id at payment connector for which mandate is attached.
     #[schema(value_type = String, example = "cust_12345")]
     pub connector_customer_id: String,
+
+    /// Details of the card of the payment method token, like the card network and expiry.
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
 }
 
 /// Error details for the payment
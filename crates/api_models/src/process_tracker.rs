@@ -1,2 +1,48 @@
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
+
+#[cfg(feature = "v2")]
+use time::PrimitiveDateTime;
+#[cfg(feature = "v2")]
+use utoipa::ToSchema;
+
+#[cfg(feature = "v2")]
+use crate::enums;
+
+/// A process tracker task, as exposed by the apis
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ProcessTaskResponse {
+    /// The identifier for the process tracker task
+    pub id: String,
+    pub name: Option<String>,
+    pub tag: Vec<String>,
+    pub runner: Option<String>,
+    /// Number of times the task has been retried
+    pub retry_count: i32,
+    /// Time at which the task is scheduled to be run
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    #[schema(value_type = ProcessTrackerStatus, example = "pending")]
+    pub status: enums::ProcessTrackerStatus,
+    pub business_status: String,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+    pub tracking_data: ProcessTaskTrackingData,
+}
+
+/// Tracking data of a process tracker task. The tracking data of the runners which are not known
+/// to the apis, and the tracking data which could not be read, is not exposed.
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ProcessTaskTrackingData {
+    RevenueRecovery(revenue_recovery::RecoveryTaskTrackingData),
+    RevenueRecoveryUnresolvedConnector(revenue_recovery::RecoveryUnresolvedConnectorTrackingData),
+    Redacted,
+}
@@ -1,9 +1,10 @@
-use common_utils::id_type;
+use common_utils::{id_type, pii};
+use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::enums;
+use crate::{enums, pagination::LegacyListFields, payments};
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RevenueRecoveryResponse {
     pub id: String,
@@ -13,9 +14,912 @@ pub struct RevenueRecoveryResponse {
     #[schema(value_type = ProcessTrackerStatus, example = "finish")]
     pub status: enums::ProcessTrackerStatus,
     pub business_status: String,
+    /// Contact details of the customer linked to the invoice, present only when the user has the permission to read customers
+    pub customer_details: Option<RevenueRecoveryCustomerDetails>,
+    /// Override of the retry threshold and schedule applied for the invoice, if any
+    pub retry_override: Option<payments::RevenueRecoveryRetryOverride>,
+    /// Identifier of the billing connector webhook which scheduled the task
+    pub recovery_correlation_id: Option<String>,
+    /// Decline class of the failed attempt, whose delay profile decided the schedule time of the task
+    pub decline_class: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryStatusResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "failed")]
+    pub status: enums::IntentStatus,
+    /// Revenue recovery details of the payment, like the retries made so far and the connector used for retrying the payment
+    pub recovery_metadata: payments::PaymentRevenueRecoveryMetadata,
+    /// Time at which the next retry of the payment is scheduled, present only when a retry is pending
+    /// and the payment is not on dispute hold
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+    /// Whether the retries of the payment are paused, since a dispute raised by the customer is open
+    pub is_on_dispute_hold: bool,
+    /// The process tracker task which retries the payment, not present when no retry has been scheduled for the payment
+    pub execute_task: Option<RecoveryTaskResponse>,
+    /// The process tracker task which retries the failed record back of the outcome of the retries
+    /// to the billing connector, not present when the record back has not failed
+    pub record_back_task: Option<RecoveryTaskResponse>,
+    /// The attempts recorded from the billing connector and the retries made by revenue recovery
+    pub attempts: Vec<payments::PaymentAttemptResponse>,
+}
+
+/// A process tracker task of revenue recovery
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecoveryTaskResponse {
+    #[serde(flatten)]
+    pub task: super::ProcessTaskResponse,
+    /// Whether the task is yet to be picked up even though its schedule time has passed
+    pub is_overdue: bool,
+}
+
+/// Tracking data of the tasks of revenue recovery
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecoveryTaskTrackingData {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The identifier for the payment retried by the task
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The attempt which was last made or recorded for the payment when the task was scheduled
+    #[schema(value_type = String)]
+    pub payment_attempt_id: id_type::GlobalAttemptId,
+    /// The billing connector account from which the payment was received
+    #[schema(value_type = String)]
+    pub billing_connector_id: id_type::MerchantConnectorAccountId,
+    /// Adjustments applied while computing the schedule time of the task
+    pub schedule_adjustments: Vec<enums::RecoveryScheduleAdjustment>,
+    /// Identifier of the billing connector webhook which scheduled the task
+    pub recovery_correlation_id: Option<String>,
+    /// Decline class of the failed attempt, whose delay profile decided the schedule time of the task
+    pub decline_class: Option<String>,
+    /// Number of consecutive executions of the task which failed with an error, the task is moved to review once it reaches the configured limit
+    pub execution_failure_count: u16,
+    /// Error with which the latest failed execution of the task failed
+    pub last_execution_error: Option<String>,
+    /// Inputs of the decision of the schedule time of the task, not present for the tasks scheduled before the decisions were recorded
+    pub schedule_decision: Option<RecoveryScheduleDecision>,
+}
+
+/// Tracking data of the task holding a failed payment in review, since the payment connector of
+/// the failed attempt could not be resolved
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecoveryUnresolvedConnectorTrackingData {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The identifier for the payment held in review
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The billing connector account from which the payment was received
+    #[schema(value_type = String)]
+    pub billing_connector_id: id_type::MerchantConnectorAccountId,
+    /// Reference of the account of the payment connector at the billing connector, which is to be mapped to a payment connector account
+    pub connector_account_reference_id: String,
+    /// Identifier of the billing connector webhook which held the payment in review
+    pub recovery_correlation_id: String,
+}
+
+/// Inputs of the decision of the schedule time of a task of revenue recovery
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecoveryScheduleDecision {
+    /// The source of the base schedule time of the task
+    #[schema(value_type = RecoveryScheduleAlgorithm, example = "decline_class_delay_profile")]
+    pub algorithm: enums::RecoveryScheduleAlgorithm,
+    /// Delay of the base schedule time from the time of the decision, in seconds
+    #[schema(example = 64800)]
+    pub base_delay_in_secs: i64,
+    /// Decline class of the failed attempt, when the delay profile of the class was used
+    #[schema(example = "insufficient_funds")]
+    pub decline_class: Option<String>,
+    /// Shift of the base schedule time applied by the retry window and the exclusion window of the processor token, in seconds
+    #[schema(example = 19800)]
+    pub window_shift_in_secs: i64,
+    /// Time at which the schedule time was decided
+    #[schema(value_type = PrimitiveDateTime, example = "2024-01-15T10:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub computed_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryDecisionListConstraints {
+    /// Number of decisions to be returned, defaults to 10 and can be at most 100
+    #[schema(example = 10)]
+    pub limit: Option<u16>,
+    /// Number of decisions to be skipped, starting from the latest one
+    #[schema(example = 0)]
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryDecisionListResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// Total number of decisions recorded for the payment
+    pub total_count: i64,
+    /// The decisions recorded for the payment, latest first
+    pub decisions: Vec<RevenueRecoveryDecisionResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryDecisionResponse {
+    /// The identifier for the decision, which is the recovery correlation id carried by the attempts and the retries that originated from the webhook
+    pub id: String,
+    /// The billing connector webhook event which was processed
+    #[schema(example = "recovery_payment_failure")]
+    pub event_type: String,
+    /// The recovery action resolved for the event, not present when the processing failed before the action was resolved
+    #[schema(example = "schedule_failed_payment")]
+    pub recovery_action: Option<String>,
+    /// Whether the attempt of the event was triggered externally or internally
+    #[schema(example = "external")]
+    pub attempt_triggered_by: Option<String>,
+    /// Number of retries made for the payment when the event was processed
+    pub retry_count: Option<u16>,
+    /// Retry threshold of the billing connector when the event was processed
+    pub retry_threshold: Option<u16>,
+    /// Whether the payment details were fetched from the billing connector
+    pub is_billing_connector_payment_sync_called: bool,
+    /// Action taken on consuming the event, not present when the processing failed
+    #[schema(example = "retry_scheduled")]
+    pub webhook_action: Option<String>,
+    /// The process tracker task which was scheduled for retrying the payment
+    pub task_id: Option<String>,
+    /// The error with which the processing of the event failed
+    #[schema(example = "RetryCountFetchFailed")]
+    pub error: Option<String>,
+    /// Time at which the event was processed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryCustomerDetails {
+    /// The email of the customer, as shared by the billing connector
+    #[schema(value_type = Option<String>, example = "JonTest@test.com")]
+    pub email: Option<pii::Email>,
+    /// The locale in which the customer prefers to be notified, as shared by the billing connector
+    #[schema(value_type = Option<String>, example = "en-US")]
+    pub locale: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RevenueRecoveryId {
     pub revenue_recovery_id: id_type::GlobalPaymentId,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryAnalyticsRequest {
+    /// The start time of the window in which the retry attempts were created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    /// The end time of the window in which the retry attempts were created. If not passed the default time is now
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+    /// Dimension by which the outcome of the retry attempts is additionally broken down
+    pub group_by: Option<RevenueRecoveryAnalyticsGroupBy>,
+    /// Only consider the retry attempts made after a decline of this decline class
+    pub decline_class: Option<String>,
+    /// Only consider the retry attempts made with a card of this card network
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<enums::CardNetwork>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RevenueRecoveryAnalyticsGroupBy {
+    /// Break down the outcome by the payment connector through which the retry attempts were made
+    PaymentConnector,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryAnalyticsResponse {
+    /// Outcome of all the internally triggered retry attempts in the window
+    pub retries: RetryOutcomeSummary,
+    /// Outcome of the retry attempts with and without each of the schedule adjustments
+    pub schedule_adjustments: Vec<ScheduleAdjustmentBreakdown>,
+    /// Outcome of the retry attempts made through each payment connector, present only when
+    /// grouped by `payment_connector`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_connectors: Option<Vec<PaymentConnectorBreakdown>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PaymentConnectorBreakdown {
+    /// The merchant connector id of the payment connector, not present for the retry attempts
+    /// which were not attributed to a payment connector
+    #[schema(value_type = Option<String>, example = "mca_primary")]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// The name of the payment connector
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// Outcome of the retry attempts made through the payment connector
+    pub retries: RetryOutcomeSummary,
+    /// Average number of retries taken by the payments recovered through the payment connector,
+    /// counting the successful retry. Not present when no payment was recovered through it.
+    pub average_retries_to_success: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScheduleAdjustmentBreakdown {
+    #[schema(value_type = RecoveryScheduleAdjustment)]
+    pub adjustment: enums::RecoveryScheduleAdjustment,
+    /// Outcome of the retry attempts which had this adjustment applied
+    pub with_adjustment: RetryOutcomeSummary,
+    /// Outcome of the retry attempts which did not have this adjustment applied
+    pub without_adjustment: RetryOutcomeSummary,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RetryOutcomeSummary {
+    pub total_attempts: u32,
+    pub successful_attempts: u32,
+    /// Percentage of successful attempts, not present when there are no attempts
+    pub success_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryRetryOverrideRequest {
+    /// Number of retries allowed for the payment on top of the retries made so far, defaults to
+    /// the number of retries in the schedule
+    #[schema(value_type = Option<u16>, example = 3)]
+    pub additional_retries: Option<u16>,
+    /// Times in the future at which the upcoming retries are to be made
+    #[schema(value_type = Vec<PrimitiveDateTime>, example = json!(["2025-01-01T10:00:00.000Z"]))]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::vec")]
+    pub schedule: Vec<PrimitiveDateTime>,
+    /// The reason for which the override is applied, recorded for auditing
+    #[schema(example = "High value invoice, retry daily")]
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryRetryOverrideResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The override applied for the payment
+    pub retry_override: payments::RevenueRecoveryRetryOverride,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryCancelResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The identifier of the execute task of the payment
+    pub task_id: String,
+    /// Status of the task after the request
+    #[schema(value_type = ProcessTrackerStatus, example = "finish")]
+    pub status: enums::ProcessTrackerStatus,
+    /// Business status of the task after the request
+    pub business_status: String,
+    /// The manual cancellation recorded for the payment, absent when the task finished without
+    /// being cancelled
+    pub manual_cancellation: Option<payments::RevenueRecoveryManualCancellation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryRescheduleRequest {
+    /// Time in the future at which the next retry is to be made, within the max recovery window of
+    /// the merchant
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryRescheduleResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The identifier of the execute task of the payment
+    pub task_id: String,
+    /// Time at which the next retry is scheduled
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+    /// Time at which the retry was scheduled before the request, absent when the task was created
+    /// or revived by the request
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2025-01-01T08:00:00.000Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_schedule_time: Option<PrimitiveDateTime>,
+    /// Status of the task after the request
+    #[schema(value_type = ProcessTrackerStatus, example = "pending")]
+    pub status: enums::ProcessTrackerStatus,
+    /// Identifier of the decision recorded for the reschedule in the audit trail of the payment
+    pub decision_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryCollectionListConstraints {
+    /// Only include the items in this status
+    #[schema(value_type = Option<RecoveryCollectionStatus>, example = "open")]
+    pub status: Option<enums::RecoveryCollectionStatus>,
+    /// Only include the items assigned to this member of the collections team
+    #[schema(example = "collector@example.com")]
+    pub assignee: Option<String>,
+    /// Number of items to be returned, defaults to 10 and can be at most 100
+    #[schema(example = 10)]
+    pub limit: Option<u16>,
+    /// Number of items to be skipped, starting from the latest one
+    #[schema(example = 0)]
+    pub offset: Option<u16>,
+}
+
+/// Field names under which the list apis of the recovery tasks and the manual collection items
+/// returned their pages before they were migrated to the paginated envelope
+pub const RECOVERY_LIST_LEGACY_FIELDS: LegacyListFields = LegacyListFields {
+    items: "data",
+    count: Some("count"),
+};
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryCollectionItemResponse {
+    /// The identifier for the collection item
+    #[schema(example = "rci_0195e7b5c5ab7c2282a6fb1f0dc0b0c1")]
+    pub id: String,
+    /// The identifier for the payment whose recovery was exhausted
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// Reference of the invoice at the billing connector
+    #[schema(value_type = Option<String>, example = "invoice_1234")]
+    pub merchant_reference_id: Option<id_type::PaymentReferenceId>,
+    /// Amount of the invoice which is yet to be collected
+    #[schema(value_type = i64, example = 6540)]
+    pub amount_outstanding: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// Error code of the last failed retry
+    pub last_error_code: Option<String>,
+    /// Reason for which the last retry failed
+    #[schema(example = "Insufficient funds")]
+    pub last_failure_reason: Option<String>,
+    /// The customer of the invoice, whose contact details can be retrieved using the customers api
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::GlobalCustomerId>,
+    #[schema(value_type = RecoveryCollectionStatus, example = "open")]
+    pub status: enums::RecoveryCollectionStatus,
+    /// Member of the collections team to whom the item is assigned
+    pub assignee: Option<String>,
+    /// The user who assigned the item
+    pub assigned_by: Option<String>,
+    /// Outcome with which the item was resolved
+    #[schema(value_type = Option<RecoveryCollectionOutcome>, example = "paid_externally")]
+    pub outcome: Option<enums::RecoveryCollectionOutcome>,
+    /// The user who resolved the item
+    pub resolved_by: Option<String>,
+    /// Time at which the item was resolved
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub resolved_at: Option<PrimitiveDateTime>,
+    /// Time at which the invoice was handed off to the manual collection queue
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryCollectionAssignRequest {
+    /// The identifier for the collection item, taken from the path
+    #[schema(value_type = String)]
+    #[serde(skip_deserializing)]
+    pub collection_item_id: String,
+    /// Member of the collections team to whom the item is to be assigned
+    #[schema(example = "collector@example.com")]
+    pub assignee: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryCollectionResolveRequest {
+    /// The identifier for the collection item, taken from the path
+    #[schema(value_type = String)]
+    #[serde(skip_deserializing)]
+    pub collection_item_id: String,
+    /// Outcome of the collection, the payment is marked as succeeded when the invoice was paid externally
+    #[schema(value_type = RecoveryCollectionOutcome, example = "paid_externally")]
+    pub outcome: enums::RecoveryCollectionOutcome,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryTaskListConstraints {
+    /// Only include the tasks of the payments of this profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Only include the tasks in this status
+    #[schema(value_type = Option<ProcessTrackerStatus>, example = "pending")]
+    pub status: Option<enums::ProcessTrackerStatus>,
+    /// Only include the tasks scheduled at or after this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time_start: Option<PrimitiveDateTime>,
+    /// Only include the tasks scheduled at or before this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time_end: Option<PrimitiveDateTime>,
+    /// Only include the tasks of the payments of this billing connector. The tasks created before
+    /// the tasks were tagged with their billing connector are not included.
+    #[schema(value_type = Option<Connector>, example = "chargebee")]
+    pub billing_connector: Option<enums::Connector>,
+    /// The identifier for the last task of the previous page, the tasks scheduled after it are returned
+    pub starting_after: Option<String>,
+    /// Number of tasks to be returned, defaults to 10 and can be at most 100
+    #[schema(example = 10)]
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryReviewTaskListConstraints {
+    /// Only include the tasks of the payments of this profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The identifier for the last task of the previous page, the tasks scheduled after it are returned
+    pub starting_after: Option<String>,
+    /// Number of tasks to be returned, defaults to 10 and can be at most 100
+    #[schema(example = 10)]
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryTaskBulkRequeueRequest {
+    /// Only requeue the tasks in this status, which can be `processing` or `process_started` for
+    /// the tasks locked by a scheduler worker, or `review` for the tasks moved to review after
+    /// failed executions. The tasks in all of these statuses are requeued when not provided.
+    #[schema(value_type = Option<ProcessTrackerStatus>, example = "processing")]
+    pub status: Option<enums::ProcessTrackerStatus>,
+    /// Only requeue the tasks of the payments of this profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Only requeue the tasks scheduled at or after this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time_start: Option<PrimitiveDateTime>,
+    /// Only requeue the tasks scheduled at or before this time, the tasks scheduled in the future
+    /// are never requeued
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time_end: Option<PrimitiveDateTime>,
+    /// Only requeue the tasks of the payments of this billing connector
+    #[schema(value_type = Option<Connector>, example = "chargebee")]
+    pub billing_connector: Option<enums::Connector>,
+    /// Whether to only count the tasks which would be requeued, without requeueing them
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Number of tasks to be examined, defaults to 500 and can be at most 1000
+    #[schema(example = 500)]
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryTaskBulkRequeueResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the tasks were only counted, without being requeued
+    pub dry_run: bool,
+    /// Number of tasks matching the filters which were examined
+    pub examined_count: usize,
+    /// Number of tasks which were requeued, or which would be requeued for a dry run
+    pub updated_count: usize,
+    /// Number of tasks which were left as is, since they are not stuck or could not be requeued
+    pub skipped_count: usize,
+    /// Whether more tasks matching the filters may be left after the tasks examined, in which case
+    /// the request can be made again to requeue them
+    pub has_more: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryKillSwitchRequest {
+    /// Whether the retries of revenue recovery are to be halted for all the merchants
+    pub is_active: bool,
+    /// The reason for changing the switch
+    #[schema(example = "Retries are charging the customers twice")]
+    pub reason: String,
+    /// The person or the system changing the switch
+    #[schema(example = "oncall@example.com")]
+    pub actor: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryKillSwitchResponse {
+    /// Whether the retries of revenue recovery are halted for all the merchants
+    pub is_active: bool,
+    /// Changes made to the switch, the latest first
+    pub history: Vec<RevenueRecoveryKillSwitchChange>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryKillSwitchChange {
+    pub is_active: bool,
+    pub reason: String,
+    pub actor: String,
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub changed_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryRetryScheduleRequest {
+    /// Name of the retry schedule
+    #[schema(example = "weekly_backoff")]
+    pub name: String,
+    /// Delays of the retries in seconds, the first delay being that of the first retry. The delays
+    /// must not decrease and must be between 1 hour and 30 days
+    #[schema(example = json!([3600, 86400, 259200]))]
+    pub delays_in_secs: Vec<i32>,
+    /// Delay of the retries beyond the delays of the schedule in seconds, not less than the last of
+    /// the delays
+    #[schema(example = 604800)]
+    pub default_delay_in_secs: i32,
+    /// Number of retries after which the payment is no longer retried
+    #[schema(example = 8)]
+    pub max_retries: i32,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryRetryScheduleResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    pub name: String,
+    pub delays_in_secs: Vec<i32>,
+    pub default_delay_in_secs: i32,
+    pub max_retries: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoverySchedulePreviewRequest {
+    /// The identifier for the merchant whose retry configuration is previewed
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Number of attempts of the payment made so far, the failed attempt included
+    #[schema(example = 3)]
+    pub retry_count: u16,
+    /// Error code with which the attempt failed, whose decline class decides the delay of the
+    /// retry when the decline class has a delay profile
+    #[schema(example = "insufficient_funds")]
+    pub decline_code: Option<String>,
+    /// Time at which the attempt failed. If not passed the default time is now
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub failed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoverySchedulePreviewResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(example = 3)]
+    pub retry_count: u16,
+    /// Whether the payment would be retried, the payment not being retried once the retries
+    /// configured for the merchant have been made
+    pub is_retried: bool,
+    /// Time at which the next retry would be made, present only when the payment would be retried
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2025-01-02T04:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    /// The algorithm and the parameters with which the schedule time would be decided
+    pub schedule_decision: Option<RecoveryScheduleDecision>,
+    /// Adjustments which would be applied to the schedule time
+    pub schedule_adjustments: Vec<enums::RecoveryScheduleAdjustment>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryRetryScheduleDeleteResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the retry schedule was deleted, the retries of the merchant are scheduled as per
+    /// the default retry configuration from then on
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryReconciliationReportsResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The billing connector account whose open invoices are reconciled
+    #[schema(value_type = String)]
+    pub billing_connector_id: id_type::MerchantConnectorAccountId,
+    /// Whether the nightly reconciliation is enabled for the billing connector account
+    pub reconciliation_enabled: bool,
+    /// Reports of the latest completed reconciliation runs, the latest first
+    pub reports: Vec<RevenueRecoveryReconciliationReport>,
+}
+
+/// Outcome of a reconciliation run of the open invoices of a billing connector account
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryReconciliationReport {
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T02:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: PrimitiveDateTime,
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T02:10:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub completed_at: PrimitiveDateTime,
+    /// Number of open invoices whose status was fetched from the billing connector
+    pub checked_count: u32,
+    /// Number of invoices found in a terminal state at the billing connector which was not
+    /// reflected in their payments
+    pub drifted_count: u32,
+    /// Number of drifted invoices whose recovery was stopped by the run
+    pub reconciled_count: u32,
+    /// Number of invoices which could not be checked or reconciled because of an error
+    pub errors_count: u32,
+    /// Whether the run stopped at the limit on the invoices checked in a run, before all the open
+    /// invoices were checked
+    pub is_truncated: bool,
+    /// The drifted invoices, capped at the first 100 of them
+    pub drifts: Vec<RevenueRecoveryReconciliationDrift>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryReconciliationDrift {
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The invoice id at the billing connector
+    #[schema(value_type = Option<String>)]
+    pub merchant_reference_id: Option<id_type::PaymentReferenceId>,
+    #[schema(value_type = RecoveryReconciliationDriftKind)]
+    pub kind: enums::RecoveryReconciliationDriftKind,
+    /// Status of the payment when the drift was found
+    #[schema(value_type = IntentStatus)]
+    pub previous_status: enums::IntentStatus,
+    /// Whether the recovery of the invoice was stopped
+    pub reconciled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryOverdueTasksResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Number of recovery tasks of the merchant which have not been picked up by the scheduler
+    /// although their schedule time was more than the threshold ago
+    #[schema(example = 0)]
+    pub overdue_count: i64,
+    /// Number of seconds after its schedule time beyond which a task is counted as overdue
+    #[schema(example = 900)]
+    pub overdue_threshold_in_secs: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryOverviewRequest {
+    /// Only include the payments of this profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+/// Snapshot of the recovery of the failed payments of a merchant
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryOverviewResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Number of payments with a recovery task which is yet to be finished
+    #[schema(example = 120)]
+    pub payments_in_recovery_count: i64,
+    /// Number of recovery tasks waiting for their schedule time or to be picked up
+    #[schema(example = 115)]
+    pub pending_task_count: i64,
+    /// Number of the pending tasks which have not been picked up although their schedule time was
+    /// more than the overdue threshold ago
+    #[schema(example = 0)]
+    pub overdue_task_count: i64,
+    /// Number of recovery tasks held in review
+    #[schema(example = 2)]
+    pub review_task_count: i64,
+    /// Number of attempts of the recovery payments made by revenue recovery, recorded in the last
+    /// 24 hours
+    #[schema(example = 40)]
+    pub internal_attempt_count: i64,
+    /// Number of attempts of the recovery payments made by the billing connector, recorded in the
+    /// last 24 hours
+    #[schema(example = 25)]
+    pub external_attempt_count: i64,
+    /// Number of payments whose retries were exhausted in the last 7 days
+    #[schema(example = 3)]
+    pub retries_exhausted_count: i64,
+    /// Time at which the snapshot was taken
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub computed_at: PrimitiveDateTime,
+}
+
+/// Snapshot of a recovery task along with the payment it recovers, for handing over the complete
+/// state of a stuck recovery. The tokens of the payment are redacted.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryTaskSnapshotResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    pub task: RecoveryTaskResponse,
+    /// The payment recovered by the task, not present when the payment could not be fetched
+    pub payment: Option<RevenueRecoveryPaymentSnapshot>,
+    /// The attempts recorded from the billing connector and the retries made by revenue recovery,
+    /// the oldest first
+    pub attempts: Vec<payments::PaymentAttemptResponse>,
+    /// The latest decisions taken on the billing connector webhooks of the payment, including
+    /// whether the payment details were synced from the billing connector, latest first
+    pub decisions: Vec<RevenueRecoveryDecisionResponse>,
+    /// Parts of the snapshot which could not be fetched, and are left out of it
+    pub missing_parts: Vec<RevenueRecoveryTaskSnapshotPart>,
+    /// Time at which the snapshot was generated
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub generated_at: PrimitiveDateTime,
+}
+
+/// State of the payment recovered by a task, in the snapshot of the task
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryPaymentSnapshot {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "failed")]
+    pub status: enums::IntentStatus,
+    /// The amount of the invoice in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The identifier of the invoice at the billing connector
+    #[schema(value_type = Option<String>)]
+    pub merchant_reference_id: Option<id_type::PaymentReferenceId>,
+    /// The attempt which was last made or recorded for the payment
+    #[schema(value_type = Option<String>)]
+    pub active_attempt_id: Option<id_type::GlobalAttemptId>,
+    /// Revenue recovery details of the payment, not present when they could not be read
+    pub recovery_metadata: Option<payments::PaymentRevenueRecoveryMetadata>,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// A part of the snapshot of a recovery task
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RevenueRecoveryTaskSnapshotPart {
+    Payment,
+    Attempts,
+    Decisions,
+}
+
+/// Request of a payments sync with the billing connector, made for debugging the recovery of a
+/// transaction or an invoice. Either the transaction id or the invoice id is to be provided.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RevenueRecoveryBillingSyncRequest {
+    /// The billing connector account through which the billing connector is called
+    #[schema(value_type = String)]
+    pub billing_connector_id: id_type::MerchantConnectorAccountId,
+    /// The transaction id at the billing connector
+    #[schema(example = "txn_1234")]
+    pub transaction_id: Option<String>,
+    /// The invoice id at the billing connector, whose latest transaction is synced
+    #[schema(example = "inv_1234")]
+    pub invoice_id: Option<String>,
+}
+
+/// Transaction returned by the billing connector for a payments sync made for debugging, along with
+/// the attempt it would be recorded as. The payment method token and the customer id at the
+/// payment connector are masked.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryBillingSyncResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[schema(value_type = String)]
+    pub billing_connector_id: id_type::MerchantConnectorAccountId,
+    /// The billing connector which was called
+    #[schema(example = "chargebee")]
+    pub connector: String,
+    /// The transaction as parsed from the response of the billing connector
+    pub payments_sync_response: RevenueRecoveryBillingSyncTransaction,
+    /// The attempt derived from the transaction, as it would be recorded for the payment
+    pub attempt_data: RevenueRecoveryBillingSyncAttempt,
+    /// Time at which the billing connector was called
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-01T10:00:00.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub synced_at: PrimitiveDateTime,
+}
+
+/// Transaction returned by the billing connector in a payments sync
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryBillingSyncTransaction {
+    /// The amount of the transaction in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The invoice id at the billing connector
+    #[schema(value_type = String)]
+    pub merchant_reference_id: id_type::PaymentReferenceId,
+    /// The transaction id at the payment connector
+    pub connector_transaction_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    /// The mandate token at the payment connector, masked
+    pub processor_payment_method_token: String,
+    /// The customer id at the payment connector, masked
+    pub connector_customer_id: String,
+    /// The identifier of the payment connector at the billing connector
+    pub connector_account_reference_id: String,
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub transaction_created_at: Option<PrimitiveDateTime>,
+    #[schema(value_type = AttemptStatus, example = "failure")]
+    pub status: enums::AttemptStatus,
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method_type: enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_sub_type: enums::PaymentMethodType,
+    pub network_advice_code: Option<String>,
+    pub network_decline_code: Option<String>,
+    pub network_error_message: Option<String>,
+    pub processor_response_details: Option<common_types::payments::ProcessorResponseDetails>,
+    #[schema(value_type = Option<CountryAlpha2>)]
+    pub card_issuing_country: Option<enums::CountryAlpha2>,
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
+    /// The subscription id at the billing connector, to which the invoice belongs
+    pub billing_connector_subscription_id: Option<String>,
+}
+
+/// Attempt derived from the transaction returned by the billing connector
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevenueRecoveryBillingSyncAttempt {
+    /// The amount of the attempt in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The invoice id at the billing connector
+    #[schema(value_type = String)]
+    pub merchant_reference_id: id_type::PaymentReferenceId,
+    /// The transaction id at the payment connector
+    pub connector_transaction_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    /// The mandate token at the payment connector, masked
+    pub processor_payment_method_token: String,
+    /// The customer id at the payment connector, masked
+    pub connector_customer_id: String,
+    /// The identifier of the payment connector at the billing connector
+    pub connector_account_reference_id: String,
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub transaction_created_at: Option<PrimitiveDateTime>,
+    #[schema(value_type = AttemptStatus, example = "failure")]
+    pub status: enums::AttemptStatus,
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method_type: enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_sub_type: enums::PaymentMethodType,
+    pub network_advice_code: Option<String>,
+    pub network_decline_code: Option<String>,
+    pub network_error_message: Option<String>,
+    pub processor_response_details: Option<common_types::payments::ProcessorResponseDetails>,
+    #[schema(value_type = Option<CountryAlpha2>)]
+    pub card_issuing_country: Option<enums::CountryAlpha2>,
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
+}
@@ -214,6 +214,9 @@ pub struct RefundResponse {
     pub issuer_error_code: Option<String>,
     /// Error message received from the issuer in case of failed refunds
     pub issuer_error_message: Option<String>,
+    /// Version of the refund, incremented on every update of its status. A later state of the refund always carries a greater version
+    #[schema(example = 2)]
+    pub entity_version: Option<i64>,
 }
 
 #[cfg(feature = "v1")]
@@ -1,9 +1,14 @@
-use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
+use common_enums::{
+    EventClass, EventDeliveryState, EventType, WebhookDeliveryAttempt,
+    WebhookDeliveryStatusCodeClass, WebhookMetaNotificationKind,
+};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::pagination::{LegacyListFields, PaginatedListResponse};
+
 /// The constraints to apply when filtering events.
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct EventListConstraints {
@@ -21,6 +26,10 @@ pub struct EventListConstraints {
     /// Include events after the specified offset.
     pub offset: Option<u16>,
 
+    /// Include the events of the page after the one the cursor was returned with, as the
+    /// `next_cursor` of that page. Cannot be combined with `offset`.
+    pub cursor: Option<String>,
+
     /// Filter all events associated with the specified object identifier (Payment Intent ID,
     /// Refund ID, etc.)
     pub object_id: Option<String>,
@@ -31,6 +40,25 @@ pub struct EventListConstraints {
 
     /// Filter all events by `is_overall_delivery_successful` field of the event.
     pub is_delivered: Option<bool>,
+
+    /// Filter all events by the state of their delivery. Unlike `is_delivered`, the undelivered
+    /// events which were sent at least once are told apart from the ones which were never sent,
+    /// such as when the webhooks could not be sent as the events were created.
+    pub delivery_state: Option<EventDeliveryState>,
+
+    /// Filter all events of any of the specified event types. The parameter can be repeated, or
+    /// the event types can be provided as a comma separated list.
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub event_types: Option<Vec<EventType>>,
+
+    /// Filter all events of the specified event class.
+    pub event_class: Option<EventClass>,
+
+    /// Filter all events whose latest delivery attempt received a response status code of the
+    /// specified class, or failed with the specified kind of error. The events which were not sent
+    /// since the profile is not subscribed to their event type are filtered with
+    /// `skipped_not_subscribed`.
+    pub delivery_status_code_class: Option<WebhookDeliveryStatusCodeClass>,
 }
 
 #[derive(Debug)]
@@ -41,9 +69,19 @@ pub enum EventListConstraintsInternal {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<EventDeliveryState>,
+        event_types: Option<Vec<EventType>>,
+        event_class: Option<EventClass>,
+        delivery_status_code_class: Option<WebhookDeliveryStatusCodeClass>,
+        cursor: Option<String>,
     },
     ObjectIdFilter {
         object_id: String,
+        is_delivered: Option<bool>,
+        delivery_state: Option<EventDeliveryState>,
+        event_types: Option<Vec<EventType>>,
+        event_class: Option<EventClass>,
+        delivery_status_code_class: Option<WebhookDeliveryStatusCodeClass>,
     },
 }
 
@@ -84,30 +122,43 @@ pub struct EventListItemResponse {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
-}
 
-/// The response body of list initial delivery attempts api call.
-#[derive(Debug, Serialize, ToSchema)]
-pub struct TotalEventsResponse {
-    /// The list of events
-    pub events: Vec<EventListItemResponse>,
-    /// Count of total events
-    pub total_count: i64,
-}
+    /// Version of the object at the time the event was created.
+    #[schema(example = 2)]
+    pub entity_version: Option<i64>,
 
-impl TotalEventsResponse {
-    pub fn new(total_count: i64, events: Vec<EventListItemResponse>) -> Self {
-        Self {
-            events,
-            total_count,
-        }
-    }
+    /// Time at which the request and response bodies of the event were replaced by a placeholder.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub payload_scrubbed_at: Option<PrimitiveDateTime>,
+
+    /// Number of the delivery attempts made for the event, the initial attempt included. Present
+    /// only when listing events.
+    #[schema(example = 3)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt_count: Option<u32>,
+
+    /// Time at which the latest delivery attempt of the event was made. Present only when listing
+    /// events.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "common_utils::custom_serde::iso8601::option"
+    )]
+    pub last_attempted_at: Option<PrimitiveDateTime>,
 }
 
-impl common_utils::events::ApiEventMetric for TotalEventsResponse {
+/// Field names under which the list initial delivery attempts api returned the events before it was
+/// migrated to the paginated envelope, the total count being returned under the same name
+pub const EVENT_LIST_LEGACY_FIELDS: LegacyListFields = LegacyListFields {
+    items: "events",
+    count: None,
+};
+
+impl common_utils::events::ApiEventMetric for PaginatedListResponse<EventListItemResponse> {
     fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
         Some(common_utils::events::ApiEventsType::Events {
-            merchant_id: self.events.first().map(|event| event.merchant_id.clone())?,
+            merchant_id: self.items.first().map(|event| event.merchant_id.clone())?,
         })
     }
 }
@@ -136,6 +187,160 @@ impl common_utils::events::ApiEventMetric for EventRetrieveResponse {
     }
 }
 
+/// The details of an event which can be expanded in the response of retrieving the event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventRetrieveExpand {
+    /// The request and response of the initial delivery attempt of the event.
+    Payload,
+}
+
+/// The constraints to apply when retrieving an event.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventRetrieveConstraints {
+    /// Include the specified details of the event in the response.
+    pub expand: Option<EventRetrieveExpand>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The profile the event is to be of, when the event is retrieved on behalf of a profile
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub event_id: String,
+    pub constraints: EventRetrieveConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for EventRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The outcome of the latest delivery attempt of an event.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventLatestDeliveryAttempt {
+    /// The identifier for the delivery attempt.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub attempt_id: String,
+
+    /// Indicates the type of delivery attempt.
+    pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+
+    /// Indicates whether the webhook endpoint responded with a success status code.
+    pub is_delivery_successful: bool,
+
+    /// The HTTP status code the webhook endpoint responded with, not set when no response was
+    /// received.
+    #[schema(example = 200)]
+    pub status_code: Option<u16>,
+
+    /// Time at which the delivery attempt was made.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for retrieving an event along with its latest delivery attempt.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventDetailsResponse {
+    #[serde(flatten)]
+    pub event_information: EventListItemResponse,
+
+    /// Number of the delivery attempts made for the event, including the initial attempt.
+    #[schema(example = 3)]
+    pub delivery_attempts_count: u32,
+
+    /// The outcome of the latest delivery attempt of the event.
+    pub latest_delivery_attempt: EventLatestDeliveryAttempt,
+
+    /// The request information (headers and body) sent in the initial delivery attempt, included
+    /// when `payload` is expanded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<OutgoingWebhookRequestContent>,
+
+    /// The response information (headers, body and status code) received for the initial delivery
+    /// attempt, included when `payload` is expanded and the attempt was made.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<OutgoingWebhookResponseContent>,
+}
+
+impl common_utils::events::ApiEventMetric for EventDetailsResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.event_information.merchant_id.clone(),
+        })
+    }
+}
+
+impl common_utils::events::ApiEventMetric for PaginatedListResponse<EventRetrieveResponse> {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        self.items
+            .first()
+            .and_then(common_utils::events::ApiEventMetric::get_api_event_type)
+    }
+}
+
+/// The response body for each item when listing the delivery attempts of an event. The request
+/// and response bodies are previewed, the complete request and response of an attempt can be
+/// retrieved separately.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventDeliveryAttemptResponse {
+    #[serde(flatten)]
+    pub event_information: EventListItemResponse,
+
+    /// Indicates the type of delivery attempt.
+    pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+
+    /// The HTTP status code received for the webhook sent.
+    #[schema(example = 200)]
+    pub status_code: Option<u16>,
+
+    /// Error message in case any error occurred when trying to deliver the webhook.
+    pub error_message: Option<String>,
+
+    /// The beginning of the request body sent in the webhook.
+    #[schema(value_type = String)]
+    pub request_body_preview: Secret<String>,
+
+    /// The beginning of the response body received for the webhook sent.
+    #[schema(value_type = Option<String>)]
+    pub response_body_preview: Option<Secret<String>>,
+
+    /// Indicates whether the request or the response body is longer than its preview.
+    pub is_truncated: bool,
+}
+
+impl common_utils::events::ApiEventMetric for PaginatedListResponse<EventDeliveryAttemptResponse> {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self
+                .items
+                .first()
+                .map(|attempt| attempt.event_information.merchant_id.clone())?,
+        })
+    }
+}
+
+/// The constraints to apply when listing the delivery attempts of an event.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventDeliveryAttemptListConstraints {
+    /// Include the complete request and response of each delivery attempt instead of the previews
+    /// of their bodies, as the delivery attempts were listed before. Deprecated, to be removed in
+    /// the next release: the complete request and response of an attempt are to be retrieved
+    /// separately.
+    #[serde(default)]
+    pub include_payloads: bool,
+
+    /// Return the delivery attempts in the envelope of the list apis, instead of as a bare array.
+    #[serde(default)]
+    pub paginated: bool,
+}
+
 /// The request information (headers and body) sent in the webhook.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct OutgoingWebhookRequestContent {
@@ -174,6 +379,28 @@ pub struct OutgoingWebhookResponseContent {
     /// Error message in case any error occurred when trying to deliver the webhook.
     #[schema(example = 200)]
     pub error_message: Option<String>,
+
+    /// The redirects returned by the webhook endpoint, in the order they were received.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_chain: Option<Vec<OutgoingWebhookRedirect>>,
+
+    /// The reason the webhook delivery failed, in case the endpoint responded with a redirect that
+    /// was not followed.
+    #[schema(value_type = Option<WebhookDeliveryFailureReason>, example = "redirect_refused")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<common_enums::WebhookDeliveryFailureReason>,
+}
+
+/// A redirect returned by the webhook endpoint for the webhook sent.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct OutgoingWebhookRedirect {
+    /// The HTTP status code of the redirect response.
+    #[schema(example = 301)]
+    pub status_code: u16,
+
+    /// The host of the URL in the `Location` header of the redirect response.
+    #[schema(example = "example.com")]
+    pub location_host: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -190,10 +417,134 @@ impl common_utils::events::ApiEventMetric for EventListRequestInternal {
     }
 }
 
+/// The format in which the events are exported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventExportFormat {
+    /// Newline delimited JSON, one event per line.
+    Ndjson,
+    /// Comma separated values, one event per row after a header row.
+    Csv,
+}
+
+/// The constraints to apply when exporting events. The events are filtered as when listing them,
+/// and are exported latest first.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventExportConstraints {
+    /// The format of the exported file.
+    pub format: EventExportFormat,
+
+    /// Export events created after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Export events created before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Export the events associated with the specified business profile ID.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Export the events by `is_overall_delivery_successful` field of the event.
+    pub is_delivered: Option<bool>,
+
+    /// Export the events in the specified delivery state.
+    pub delivery_state: Option<EventDeliveryState>,
+
+    /// Export the events of any of the specified event types. The parameter can be repeated, or
+    /// the event types can be provided as a comma separated list.
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub event_types: Option<Vec<EventType>>,
+
+    /// Export the events of the specified event class.
+    pub event_class: Option<EventClass>,
+
+    /// Export the events whose latest delivery attempt received a response status code of the
+    /// specified class, or failed with the specified kind of error.
+    pub delivery_status_code_class: Option<WebhookDeliveryStatusCodeClass>,
+
+    /// Include the request and response bodies of the initial delivery attempt of each event.
+    /// The bodies are excluded by default.
+    #[serde(default)]
+    pub include_payload: bool,
+}
+
+/// An exported event, a line of an NDJSON export or a row of a CSV export.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventExportRecord {
+    /// The identifier for the Event.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The identifier for the object (Payment Intent ID, Refund ID, etc.)
+    #[schema(max_length = 64, example = "QHrfd5LUDdZaKtAjdJmMu0dMa1")]
+    pub object_id: String,
+
+    /// Specifies the type of event, which includes the object and its status.
+    pub event_type: EventType,
+
+    /// Specifies the class of event (the type of object: Payment, Refund, etc.)
+    pub event_class: EventClass,
+
+    /// Indicates whether the webhook was ultimately delivered or not.
+    pub is_delivery_successful: Option<bool>,
+
+    /// The class of the response status code received on the latest delivery attempt, or the
+    /// kind of error the attempt failed with.
+    pub delivery_status_code_class: Option<WebhookDeliveryStatusCodeClass>,
+
+    /// Time at which the event was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the request and response bodies of the event were replaced by a placeholder.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub payload_scrubbed_at: Option<PrimitiveDateTime>,
+
+    /// The body of the request sent on the initial delivery attempt, present only when the
+    /// payloads are included in the export.
+    #[schema(value_type = Option<String>)]
+    pub request_body: Option<Secret<String>>,
+
+    /// The body of the response received on the initial delivery attempt, present only when the
+    /// payloads are included in the export.
+    #[schema(value_type = Option<String>)]
+    pub response_body: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventExportRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: EventExportConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for EventExportRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct WebhookDeliveryAttemptListRequestInternal {
     pub merchant_id: common_utils::id_type::MerchantId,
+    /// The profile the event is to be of, when the attempts are listed on behalf of a profile
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub initial_attempt_id: String,
+    pub constraints: EventDeliveryAttemptListConstraints,
 }
 
 impl common_utils::events::ApiEventMetric for WebhookDeliveryAttemptListRequestInternal {
@@ -204,9 +555,37 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryAttemptListRequestI
     }
 }
 
+/// The request to retry the delivery of an event.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookDeliveryRetryRequest {
+    /// Deliver the event to this URL instead of the webhook URL configured for the profile. The
+    /// URL must use HTTPS and must not point to an internal address. The configured webhook URL is
+    /// left unchanged.
+    #[schema(value_type = Option<String>, example = "https://merchant.example.com/v2/webhooks")]
+    pub override_url: Option<Secret<String>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryAttemptPayloadRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub initial_attempt_id: String,
+    pub attempt_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryAttemptPayloadRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct WebhookDeliveryRetryRequestInternal {
     pub merchant_id: common_utils::id_type::MerchantId,
+    /// The profile the event is to be of, when the event is retried on behalf of a profile
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub event_id: String,
 }
 
@@ -217,3 +596,848 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventPayloadScrubRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub event_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for EventPayloadScrubRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body for scrubbing the stored payload of an event.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventPayloadScrubResponse {
+    /// The identifier for the Event.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The identifier for the initial delivery attempt of the event, whose delivery attempts were
+    /// all scrubbed along with it.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub initial_attempt_id: String,
+
+    /// The identifiers of the delivery attempts whose payloads were scrubbed.
+    pub scrubbed_attempt_ids: Vec<String>,
+
+    /// The placeholder stored in place of the request and response bodies of the attempts.
+    pub placeholder: ScrubbedEventPayload,
+}
+
+impl common_utils::events::ApiEventMetric for EventPayloadScrubResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The placeholder stored in place of the request and response bodies of an event whose payload
+/// was scrubbed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ScrubbedEventPayload {
+    /// Time at which the payload was scrubbed.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scrubbed_at: PrimitiveDateTime,
+
+    /// The identifier of the user who scrubbed the payload.
+    #[schema(max_length = 64, example = "usr_018e31720d1b7a2b82677d3032cab959")]
+    pub scrubbed_by: String,
+}
+
+/// The request body for confirming a change of the outgoing webhook URL.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookUrlChangeConfirmRequest {
+    /// The one-time token sent to the new webhook URL in the verification event.
+    #[schema(value_type = String)]
+    pub verification_token: Secret<String>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookUrlChangeConfirmRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookUrlChangeConfirmRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub verification_token: Option<Secret<String>>,
+    pub force_confirm: bool,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookUrlChangeConfirmRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body for confirming a change of the outgoing webhook URL.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookUrlChangeConfirmResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2", value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The webhook URL to which webhooks are delivered.
+    #[schema(value_type = String, example = "www.ekart.com/webhooks")]
+    pub webhook_url: Secret<String>,
+
+    /// The status of the webhook URL change.
+    #[schema(value_type = WebhookUrlChangeStatus, example = "confirmed")]
+    pub status: common_enums::WebhookUrlChangeStatus,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookUrlChangeConfirmResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The request body for updating the event types whose webhooks are sent to a profile.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEventSubscriptionUpdateRequest {
+    /// The event types whose webhooks are sent. The webhooks of all the event types are sent if
+    /// the list is empty.
+    #[schema(example = json!(["payment_succeeded", "payment_failed"]))]
+    pub enabled_event_types: Vec<EventType>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventSubscriptionUpdateRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventSubscriptionUpdateRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub enabled_event_types: Vec<EventType>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventSubscriptionUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body for updating the event types whose webhooks are sent to a profile.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEventSubscriptionResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2", value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The event types whose webhooks are sent. The webhooks of all the event types are sent if
+    /// the list is empty. The events of the other event types are still recorded, with the
+    /// `skipped_not_subscribed` delivery status.
+    #[schema(example = json!(["payment_succeeded", "payment_failed"]))]
+    pub enabled_event_types: Vec<EventType>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventSubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The body of the verification event sent to a new webhook URL, containing the token to be
+/// echoed back for confirming the URL change.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OutgoingWebhookUrlVerification {
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2", value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[schema(example = "webhook_url_verification")]
+    pub event_type: EventType,
+    #[schema(value_type = String, example = "7bKJuZ2Xn1sPqcRLmW4v")]
+    pub verification_token: Secret<String>,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T11:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: PrimitiveDateTime,
+}
+
+/// The daily summary of the webhook delivery failures of a profile, sent to the webhook URL of
+/// the profile or to the email recipient configured in its digest configuration.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OutgoingWebhookDigest {
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2", value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[schema(example = "digest.daily")]
+    pub event_type: EventType,
+    /// The timezone in which the day of the digest was computed
+    #[schema(example = "Asia/Kolkata")]
+    pub timezone: String,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-09T18:30:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_start: PrimitiveDateTime,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T18:30:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_end: PrimitiveDateTime,
+    /// Number of the delivery attempts which failed during the day, including the retries
+    #[schema(example = 12)]
+    pub total_failed_deliveries: i64,
+    pub failed_deliveries_by_event_type: Vec<WebhookDigestEventTypeCount>,
+    /// The most frequent status codes returned by the webhook endpoint for the failed attempts.
+    /// Attempts which did not receive a response are counted without a status code.
+    pub top_failure_status_codes: Vec<WebhookDigestStatusCodeCount>,
+    /// Number of the webhooks which are yet to be delivered successfully
+    #[schema(example = 3)]
+    pub backlog_size: i64,
+    /// Number of the payments whose recovery retries were exhausted during the day, when revenue
+    /// recovery is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_exhaustions: Option<i64>,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T18:30:05Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: PrimitiveDateTime,
+}
+
+/// The notification of an upcoming revenue recovery retry of a payment, sent to the webhook URL of
+/// the profile ahead of the retry, so that the merchant can notify the customer before the payment
+/// method is charged again.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OutgoingRecoveryRetryUpcoming {
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2", value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[schema(example = "recovery.retry_upcoming")]
+    pub event_type: EventType,
+    #[schema(value_type = String, example = "12345_pay_01926c58bc6e77c09e809964e72af8c8")]
+    pub payment_id: common_utils::id_type::GlobalPaymentId,
+    /// The amount which is charged by the retry, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+    /// The number of the retry, counting from the first retry of the payment
+    #[schema(example = 2)]
+    pub retry_count: i32,
+    /// The time at which the retry is scheduled, the retry is not made before this time
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scheduled_retry_time: PrimitiveDateTime,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookDigestEventTypeCount {
+    pub event_type: EventType,
+    pub count: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookDigestStatusCodeCount {
+    pub status_code: Option<u16>,
+    pub count: i64,
+}
+
+/// A notification about the webhook delivery of a profile, sent through the secondary notification
+/// channel of the profile, since the webhook endpoint of the profile may not be reachable.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookMetaNotification {
+    /// The identifier of the notification, which is the same for the retries of its delivery
+    #[schema(example = "mn_3q9ADQKh8xVGNpJfH2Lr")]
+    pub notification_id: String,
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2", value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[serde(flatten)]
+    pub details: WebhookMetaNotificationDetails,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "kind", content = "details", rename_all = "snake_case")]
+pub enum WebhookMetaNotificationDetails {
+    /// The number of the webhooks pending delivery crossed the backlog threshold of the profile
+    DeliveryBacklogThresholdCrossed {
+        /// Number of the webhooks which are yet to be delivered successfully
+        #[schema(example = 742)]
+        backlog_size: i64,
+        #[schema(example = 500)]
+        backlog_threshold: i64,
+        /// The start of the window over which the pending webhooks are counted
+        #[schema(value_type = PrimitiveDateTime, example = "2022-09-07T10:11:12Z")]
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        window_start: PrimitiveDateTime,
+    },
+    /// An API key of the merchant is about to expire
+    SecretRotationReminder {
+        /// The name of the API key
+        #[schema(example = "Sandbox integration key")]
+        key_name: String,
+        /// The prefix of the API key
+        #[schema(example = "snd_wBQMLh")]
+        key_prefix: String,
+        /// Number of days after which the API key expires
+        #[schema(example = 7)]
+        expires_in_days: u8,
+    },
+}
+
+impl WebhookMetaNotificationDetails {
+    pub fn get_kind(&self) -> WebhookMetaNotificationKind {
+        match self {
+            Self::DeliveryBacklogThresholdCrossed { .. } => {
+                WebhookMetaNotificationKind::DeliveryBacklogThresholdCrossed
+            }
+            Self::SecretRotationReminder { .. } => {
+                WebhookMetaNotificationKind::SecretRotationReminder
+            }
+        }
+    }
+}
+
+/// The retry policy for the delivery of outgoing webhooks.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryRetryPolicy {
+    /// The delay in seconds after the initial delivery attempt, for the first retry.
+    #[schema(example = 60)]
+    pub start_after_in_secs: i32,
+
+    /// The intervals between the subsequent retries, applied in the specified order.
+    pub intervals: Vec<WebhookDeliveryRetryInterval>,
+}
+
+/// The interval between a number of consecutive retries.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryRetryInterval {
+    /// The interval in seconds between the retries.
+    #[schema(example = 300)]
+    pub interval_in_secs: i32,
+
+    /// The number of retries made with this interval.
+    #[schema(example = 5)]
+    pub count: i32,
+}
+
+/// The request body for previewing the delivery attempts produced by a retry policy.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookRetryPolicyPreviewRequest {
+    /// The candidate retry policy to be previewed. The retry policy currently in use is previewed,
+    /// if not specified.
+    pub policy: Option<WebhookDeliveryRetryPolicy>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookRetryPolicyPreviewRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The response body for previewing the delivery attempts produced by a retry policy.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookRetryPolicyPreviewResponse {
+    /// The maximum number of delivery attempts made for an event, including the initial attempt.
+    pub max_attempts: u32,
+
+    /// The time in seconds after the initial delivery attempt, at which the last retry is made.
+    pub total_horizon_in_secs: i64,
+
+    /// The delivery attempts made for an event, relative to the initial delivery attempt.
+    pub attempts: Vec<WebhookDeliveryAttemptPreview>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookRetryPolicyPreviewResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// A delivery attempt produced by a retry policy.
+#[derive(Debug, Serialize, ToSchema, PartialEq)]
+pub struct WebhookDeliveryAttemptPreview {
+    /// The number of the delivery attempt, starting from 1 for the initial delivery attempt.
+    pub attempt_number: u32,
+
+    /// The time in seconds after the initial delivery attempt, at which this attempt is scheduled.
+    pub scheduled_after_in_secs: i64,
+
+    /// The earliest time in seconds after the initial delivery attempt, at which this attempt can
+    /// be made after applying jitter.
+    pub earliest_after_in_secs: i64,
+
+    /// The latest time in seconds after the initial delivery attempt, at which this attempt can be
+    /// made after applying jitter.
+    pub latest_after_in_secs: i64,
+}
+
+/// The request for listing the event types, made on behalf of a profile when the enablement of
+/// the event types for the profile is to be included.
+#[derive(Debug, Serialize)]
+pub struct EventTypeCatalogRequestInternal {
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+impl common_utils::events::ApiEventMetric for EventTypeCatalogRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// An event type which can be sent to the webhook URL of a profile, along with the schema of its
+/// payload.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventTypeCatalogItem {
+    /// The type of the event.
+    #[schema(example = "payment_succeeded")]
+    pub event_type: EventType,
+
+    /// The class of the event, not set for the events which are not about an object (Payment,
+    /// Refund, etc.)
+    #[schema(example = "payments")]
+    pub event_class: Option<EventClass>,
+
+    /// A short description of when the event is sent.
+    #[schema(example = "Sent when a payment succeeds")]
+    pub description: String,
+
+    /// Whether the event is sent to the webhook URL of the profile. Set only when the event types
+    /// are listed for a profile.
+    pub is_enabled: Option<bool>,
+
+    /// The current version of the payload of the event.
+    #[schema(example = 1)]
+    pub payload_version: u16,
+
+    /// The name of the schema of the payload. For the events of an object, the payload is the
+    /// object sent in `content.object` of the webhook.
+    #[schema(example = "PaymentsResponse")]
+    pub payload_schema_name: String,
+
+    /// The JSON schema of the payload. The schemas referred to in it are listed in the API
+    /// reference.
+    #[schema(value_type = Object)]
+    pub payload_schema: serde_json::Value,
+
+    /// An example of the payload, made up of the examples of the fields of the payload.
+    #[schema(value_type = Object)]
+    pub payload_example: serde_json::Value,
+}
+
+/// The response body of listing the event types.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventTypeCatalogResponse {
+    /// The event types, each listed once.
+    pub event_types: Vec<EventTypeCatalogItem>,
+}
+
+impl common_utils::events::ApiEventMetric for EventTypeCatalogResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The request body for retrying the failed deliveries of the events of a merchant in bulk.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookBulkRetryRequest {
+    /// Retry the events created after the specified time. Defaults to the earliest time for which
+    /// events can be listed.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Retry the events created before the specified time. Defaults to the current time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Retry only the events of any of the specified event types.
+    pub event_types: Option<Vec<EventType>>,
+
+    /// Retry only the events in the specified delivery state, either `failed` or
+    /// `pending_first_attempt`. All the undelivered events are retried if not specified.
+    pub delivery_state: Option<EventDeliveryState>,
+
+    /// Retry only the events of the specified business profile.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Retry at most the specified number of events, the most recent events being retried first.
+    #[schema(example = 1000, maximum = 10000)]
+    pub max_count: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookBulkRetryRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: WebhookBulkRetryRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookBulkRetryRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookBulkRetryJobRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub job_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookBulkRetryJobRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body of a bulk retry job, which redelivers the events whose delivery failed.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookBulkRetryJobResponse {
+    /// The identifier of the bulk retry job.
+    #[schema(max_length = 64, example = "whrj_018e31720d1b7a2b8267")]
+    pub job_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The status of the bulk retry job.
+    #[schema(value_type = WebhookBulkRetryJobStatus, example = "in_progress")]
+    pub status: common_enums::WebhookBulkRetryJobStatus,
+
+    /// Number of the events queued for redelivery by the job, set once the events are queued.
+    #[schema(example = 120)]
+    pub total_count: Option<i64>,
+
+    /// Number of the queued events which are yet to be redelivered.
+    #[schema(example = 40)]
+    pub queued_count: i64,
+
+    /// Number of the queued events which were redelivered successfully.
+    #[schema(example = 70)]
+    pub succeeded_count: i64,
+
+    /// Number of the queued events whose redelivery failed.
+    #[schema(example = 6)]
+    pub failed_count: i64,
+
+    /// Number of the queued events which were not redelivered, as they were delivered in the
+    /// meantime or their payloads were scrubbed.
+    #[schema(example = 4)]
+    pub skipped_count: i64,
+
+    /// Time at which the job was created.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookBulkRetryJobResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventsRetentionScheduleRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+impl common_utils::events::ApiEventMetric for EventsRetentionScheduleRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The response body of scheduling the retention job of the events of a merchant, which deletes
+/// the events older than the retention period, archiving the undelivered ones first.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventsRetentionScheduleResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Number of days for which the events are retained.
+    #[schema(example = 90)]
+    pub retention_days: i64,
+
+    /// Whether the undelivered events are archived before they are deleted.
+    pub is_archive_enabled: bool,
+
+    /// Time of the next run of the retention job.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for EventsRetentionScheduleResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The constraints for listing the archives of the events of a merchant.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventsArchiveListConstraints {
+    /// Include at most the specified number of archives.
+    #[schema(example = 20, maximum = 100)]
+    pub limit: Option<u16>,
+
+    /// Include the archives after the specified offset.
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventsArchiveListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: EventsArchiveListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for EventsArchiveListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// An archive of the undelivered events of a merchant, written to the archive storage by the
+/// retention job before the events were deleted. Each line of the archive object is an event
+/// along with the summary of its delivery attempts.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventsArchiveResponse {
+    /// The identifier of the archive.
+    #[schema(max_length = 64, example = "evar_018e31720d1b7a2b8267")]
+    pub archive_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The key of the archive object in the archive storage.
+    #[schema(
+        max_length = 255,
+        example = "events_archive/y3oqhf46pyzuxjbcn2giaqnb44/evar_018e31720d1b7a2b8267.ndjson"
+    )]
+    pub object_key: String,
+
+    /// The hex encoded SHA-256 digest of the archive object, verified after it was written.
+    #[schema(max_length = 64)]
+    pub checksum: String,
+
+    /// Number of the events in the archive.
+    #[schema(example = 120)]
+    pub events_count: i32,
+
+    /// Time at which the oldest event of the archive was created.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub oldest_event_created_at: PrimitiveDateTime,
+
+    /// Time at which the newest event of the archive was created.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub newest_event_created_at: PrimitiveDateTime,
+
+    /// Time at which the archive was written.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for PaginatedListResponse<EventsArchiveResponse> {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self
+                .items
+                .first()
+                .map(|archive| archive.merchant_id.clone())?,
+        })
+    }
+}
+
+/// The constraints for computing the webhook delivery statistics of a merchant or a profile.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookDeliveryStatsRequest {
+    /// Include the events created after the specified time. Defaults to 30 days before
+    /// `created_before`.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Include the events created before the specified time. Defaults to the current time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include only the events of the specified business profile.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookDeliveryStatsRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: WebhookDeliveryStatsRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryStatsRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The number of the events by the outcome of their delivery.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookDeliveryOutcomeCounts {
+    /// Number of the events delivered successfully, by the initial attempt or by a retry.
+    #[schema(example = 950)]
+    pub delivered_count: i64,
+
+    /// Number of the events which are not delivered yet, but may still be delivered by an
+    /// automatic retry.
+    #[schema(example = 30)]
+    pub pending_count: i64,
+
+    /// Number of the events which were not delivered, with no automatic retries remaining.
+    #[schema(example = 20)]
+    pub failed_count: i64,
+}
+
+/// The delivery outcomes of the events of an event type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookDeliveryEventTypeStats {
+    #[schema(example = "payment_succeeded")]
+    pub event_type: EventType,
+
+    #[serde(flatten)]
+    pub outcome_counts: WebhookDeliveryOutcomeCounts,
+}
+
+/// The delivery outcomes of the events created on a day.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookDeliveryDailyStats {
+    /// The start of the day in UTC.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub date: PrimitiveDateTime,
+
+    #[serde(flatten)]
+    pub outcome_counts: WebhookDeliveryOutcomeCounts,
+}
+
+/// The latency of the initial delivery attempts which received a response from the webhook
+/// endpoint, not set when there are no such attempts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, ToSchema)]
+pub struct WebhookDeliveryLatencyStats {
+    /// The average latency in milliseconds.
+    #[schema(example = 182.5)]
+    pub average_ms: Option<f64>,
+
+    /// The 95th percentile of the latency in milliseconds.
+    #[schema(example = 640.0)]
+    pub p95_ms: Option<f64>,
+}
+
+/// The response body of the webhook delivery statistics of a merchant or a profile. The events
+/// are counted once each, by the outcome of their delivery across all of their attempts.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryStatsResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile, set when the statistics are of a profile.
+    #[schema(max_length = 64, value_type = Option<String>, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The events created after this time are included.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-08-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// The events created before this time are included.
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// The delivery outcomes of all the included events.
+    pub total: WebhookDeliveryOutcomeCounts,
+
+    /// The delivery outcomes by event type, the event types with no events being omitted.
+    pub by_event_type: Vec<WebhookDeliveryEventTypeStats>,
+
+    /// The delivery outcomes by the day the events were created on, earliest first, the days with
+    /// no events being omitted.
+    pub by_day: Vec<WebhookDeliveryDailyStats>,
+
+    /// The latency of the initial delivery attempts.
+    pub initial_attempt_latency: WebhookDeliveryLatencyStats,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryStatsResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+fn parse_comma_separated<'de, D, T>(v: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Display,
+{
+    use serde::de::Error;
+
+    Option::<String>::deserialize(v)?
+        .map(|s| {
+            s.split(',')
+                .map(|x| x.trim().parse::<T>().map_err(D::Error::custom))
+                .collect::<Result<_, _>>()
+        })
+        .transpose()
+}
@@ -7,8 +7,9 @@ use utoipa::ToSchema;
 use crate::payouts;
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, strum::Display)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     /// Authorization + Capture success
     PaymentIntentFailure,
@@ -65,6 +66,12 @@ pub enum IncomingWebhookEvent {
     RecoveryPaymentPending,
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     RecoveryInvoiceCancel,
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    RecoverySubscriptionCancel,
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    RecoveryDisputeOpened,
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    RecoveryDisputeClosed,
 }
 
 pub enum WebhookFlow {
@@ -134,6 +141,38 @@ pub enum WebhookResponseTracker {
         relay_id: common_utils::id_type::RelayId,
         status: common_enums::RelayStatus,
     },
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    Recovery {
+        payment_id: common_utils::id_type::GlobalPaymentId,
+        status: common_enums::IntentStatus,
+        action: RecoveryWebhookAction,
+        task_id: Option<String>,
+    },
+}
+
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+/// This enum tells about the action taken by revenue recovery on consuming a webhook
+pub enum RecoveryWebhookAction {
+    /// A retry of the failed payment has been scheduled in the process tracker
+    RetryScheduled,
+    /// The retry count of the payment has not exceeded the retry threshold of the billing connector
+    RetryThresholdNotExceeded,
+    /// The payment has been succeeded via external system
+    SuccessPaymentExternal,
+    /// The payment is pending in the billing processor
+    PendingPayment,
+    /// No action is required for the event
+    NoAction,
+    /// Invalid event has been received for the payment
+    InvalidAction,
+    /// The pending retry of the payment has been cancelled, since the invoice has no amount due
+    RetryCancelled,
+    /// The payment connector of the failed attempt could not be resolved, the payment has been held
+    /// in review until the payment connector is mapped
+    PaymentConnectorUnresolved,
 }
 
 impl WebhookResponseTracker {
@@ -156,6 +195,8 @@ impl WebhookResponseTracker {
             Self::Payment { payment_id, .. }
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_owned()),
+            #[cfg(feature = "revenue_recovery")]
+            Self::Recovery { payment_id, .. } => Some(payment_id.to_owned()),
             Self::NoEffect | Self::Mandate { .. } => None,
             #[cfg(feature = "payouts")]
             Self::Payout { .. } => None,
@@ -209,6 +250,9 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             | IncomingWebhookEvent::PayoutReversed => Self::Payout,
             #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
             IncomingWebhookEvent::RecoveryInvoiceCancel
+            | IncomingWebhookEvent::RecoverySubscriptionCancel
+            | IncomingWebhookEvent::RecoveryDisputeOpened
+            | IncomingWebhookEvent::RecoveryDisputeClosed
             | IncomingWebhookEvent::RecoveryPaymentFailure
             | IncomingWebhookEvent::RecoveryPaymentPending
             | IncomingWebhookEvent::RecoveryPaymentSuccess => Self::Recovery,
@@ -259,6 +303,10 @@ pub enum ObjectReferenceId {
 #[derive(Clone)]
 pub enum InvoiceIdType {
     ConnectorInvoiceId(String),
+    /// Subscription id at the billing connector, referring to all the invoices of the subscription
+    ConnectorSubscriptionId(String),
+    /// Customer id at the billing connector, referring to all the invoices of the customer
+    ConnectorCustomerId(String),
 }
 
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
@@ -329,6 +377,13 @@ pub struct OutgoingWebhook {
     /// The time at which webhook was sent
     #[serde(default, with = "custom_serde::iso8601")]
     pub timestamp: PrimitiveDateTime,
+
+    /// The version of the object in `content` at the time this webhook was created. The version
+    /// increases with every status change, so webhooks delivered out of order can be discarded by
+    /// comparing versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 2)]
+    pub entity_version: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -348,6 +403,20 @@ pub enum OutgoingWebhookContent {
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
 }
 
+#[cfg(feature = "v1")]
+impl OutgoingWebhookContent {
+    pub fn get_entity_version(&self) -> Option<i64> {
+        match self {
+            Self::PaymentDetails(payment) => payment.entity_version,
+            Self::RefundDetails(refund) => refund.entity_version,
+            Self::DisputeDetails(dispute) => dispute.entity_version,
+            Self::MandateDetails(_) => None,
+            #[cfg(feature = "payouts")]
+            Self::PayoutDetails(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 #[cfg(feature = "v2")]
@@ -197,6 +197,10 @@ pub enum Connector {
     #[serde(rename = "paypal_test")]
     #[strum(serialize = "paypal_test")]
     DummyConnector7,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "dummybillingconnector")]
+    #[strum(serialize = "dummybillingconnector")]
+    DummyBillingConnector,
     Aci,
     Adyen,
     Airwallex,
@@ -352,6 +356,17 @@ impl Connector {
     pub fn requires_defend_dispute(self) -> bool {
         matches!(self, Self::Checkout)
     }
+    pub fn supports_billing_connector_payments_sync(self) -> bool {
+        match self {
+            Self::Chargebee | Self::Recurly | Self::Stripebilling => true,
+            #[cfg(feature = "dummy_connector")]
+            Self::DummyBillingConnector => true,
+            _ => false,
+        }
+    }
+    pub fn supports_billing_connector_invoice_sync(self) -> bool {
+        matches!(self, Self::Recurly)
+    }
     pub fn is_separate_authentication_supported(self) -> bool {
         match self {
             #[cfg(feature = "dummy_connector")]
@@ -361,7 +376,8 @@ impl Connector {
             | Self::DummyConnector4
             | Self::DummyConnector5
             | Self::DummyConnector6
-            | Self::DummyConnector7 => false,
+            | Self::DummyConnector7
+            | Self::DummyBillingConnector => false,
             Self::Aci
             // Add Separate authentication support for connectors
             | Self::Adyen
@@ -490,6 +506,7 @@ impl Connector {
                 | Self::DummyConnector5
                 | Self::DummyConnector6
                 | Self::DummyConnector7
+                | Self::DummyBillingConnector
         ) && !is_dummy_connector_enabled
     }
 }
@@ -712,6 +729,8 @@ impl TryFrom<Connector> for RoutableConnectors {
             | Connector::Taxjar
             | Connector::Threedsecureio
             | Connector::CtpVisa => Err("Invalid conversion. Not a routable connector"),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyBillingConnector => Err("Invalid conversion. Not a routable connector"),
         }
     }
 }
@@ -1388,6 +1388,7 @@ pub enum EventClass {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
     ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "db_enum")]
@@ -1421,6 +1422,17 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    /// Sent to a new webhook URL to verify it before the URL change takes effect
+    WebhookUrlVerification,
+    /// Sent once a day with the summary of the webhook delivery failures of the profile
+    #[serde(rename = "digest.daily")]
+    #[strum(serialize = "digest.daily")]
+    DigestDaily,
+    /// Sent ahead of a revenue recovery retry of a payment, so that the customer can be notified
+    /// before the payment method is charged again
+    #[serde(rename = "recovery.retry_upcoming")]
+    #[strum(serialize = "recovery.retry_upcoming")]
+    RecoveryRetryUpcoming,
 }
 
 #[derive(
@@ -1442,6 +1454,244 @@ pub enum WebhookDeliveryAttempt {
     InitialAttempt,
     AutomaticRetry,
     ManualRetry,
+    /// A manual retry delivered to a URL other than the webhook URL configured for the profile
+    ManualRetryWithOverrideUrl,
+}
+
+/// The status of a pending change of the outgoing webhook URL
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookUrlChangeStatus {
+    /// The new URL is awaiting confirmation, webhooks are delivered to the existing URL
+    Pending,
+    /// The new URL has been confirmed and webhooks are delivered to it
+    Confirmed,
+    /// The change was not confirmed before its expiry, webhooks are delivered to the existing URL
+    Expired,
+}
+
+/// The status of a bulk retry job of webhook deliveries
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookBulkRetryJobStatus {
+    /// The events to be retried are yet to be queued for redelivery
+    Pending,
+    /// The events have been queued, and some of them are yet to be redelivered
+    InProgress,
+    /// All the queued events have been redelivered or skipped
+    Completed,
+    /// The events to be retried could not be queued for redelivery
+    Failed,
+}
+
+/// The policy for handling redirects returned by the webhook endpoint
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookRedirectPolicy {
+    /// Redirects are not followed and the delivery is considered failed
+    #[default]
+    Refuse,
+    /// Redirects are followed only if they point to the same host as the webhook URL
+    FollowSameHost,
+    /// Redirects are followed to any host, up to a maximum of 2 redirects
+    Follow,
+}
+
+/// The channel through which the daily digest of the webhook delivery failures is sent
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDigestChannel {
+    /// The digest is delivered to the webhook URL of the profile as a `digest.daily` event
+    Webhook,
+    /// The digest is emailed, if an email provider is configured
+    Email,
+}
+
+/// The kind of a notification about the webhook delivery of a profile, sent through the secondary
+/// notification channel of the profile instead of its webhook endpoint
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookMetaNotificationKind {
+    /// The number of the webhooks pending delivery crossed the backlog threshold of the profile
+    DeliveryBacklogThresholdCrossed,
+    /// A secret of the merchant is about to expire and has to be rotated
+    SecretRotationReminder,
+}
+
+/// The reason for the failure of a webhook delivery, in case the webhook endpoint was not reached
+/// or its response could not be accepted
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryFailureReason {
+    /// The webhook endpoint returned a redirect, which was refused by the redirect policy
+    RedirectRefused,
+    /// The webhook endpoint returned more redirects than allowed
+    RedirectLimitExceeded,
+    /// The webhook endpoint redirected to a URL to which webhooks are not allowed to be delivered
+    RedirectTargetNotAllowed,
+}
+
+/// The class of the HTTP status code of the response to a webhook delivery, the kind of error in
+/// case no response was received, or the reason the webhook was not sent at all
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum WebhookDeliveryStatusCodeClass {
+    /// The webhook endpoint responded with a 2xx status code
+    #[serde(rename = "2xx")]
+    #[strum(serialize = "2xx")]
+    Successful,
+    /// The webhook endpoint responded with a 3xx status code
+    #[serde(rename = "3xx")]
+    #[strum(serialize = "3xx")]
+    Redirection,
+    /// The webhook endpoint responded with a 4xx status code
+    #[serde(rename = "4xx")]
+    #[strum(serialize = "4xx")]
+    ClientError,
+    /// The webhook endpoint responded with a 5xx status code
+    #[serde(rename = "5xx")]
+    #[strum(serialize = "5xx")]
+    ServerError,
+    /// The webhook endpoint did not respond in time
+    #[serde(rename = "timeout")]
+    #[strum(serialize = "timeout")]
+    Timeout,
+    /// The webhook could not be sent to the webhook endpoint, or the connection failed before a
+    /// response was received
+    #[serde(rename = "connection_error")]
+    #[strum(serialize = "connection_error")]
+    ConnectionError,
+    /// The webhook was not sent, since the profile is not subscribed to the type of the event
+    #[serde(rename = "skipped_not_subscribed")]
+    #[strum(serialize = "skipped_not_subscribed")]
+    SkippedNotSubscribed,
+}
+
+impl WebhookDeliveryStatusCodeClass {
+    /// The class of the status code of a response, if it is a final response status code
+    pub fn from_status_code(status_code: u16) -> Option<Self> {
+        match status_code {
+            200..=299 => Some(Self::Successful),
+            300..=399 => Some(Self::Redirection),
+            400..=499 => Some(Self::ClientError),
+            500..=599 => Some(Self::ServerError),
+            _ => None,
+        }
+    }
+}
+
+/// The state of the delivery of an event, as of its latest delivery attempt
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventDeliveryState {
+    /// The event was delivered to the webhook endpoint
+    Delivered,
+    /// The event was sent to the webhook endpoint at least once, but not delivered yet
+    Failed,
+    /// The event was not sent to the webhook endpoint yet, which is the case when the webhook
+    /// could not be sent as soon as the event was created
+    PendingFirstAttempt,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -7929,6 +8179,29 @@ pub enum PaymentConnectorTransmission {
     ConnectorCallSucceeded,
 }
 
+/// Status of the record back of the outcome of the recovery retries to the billing connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryRecordBackStatus {
+    /// The record back failed and is being retried by a separate task
+    Pending,
+    /// The billing connector has recorded the outcome of the retries
+    Completed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -7954,6 +8227,193 @@ pub enum TriggeredBy {
     External,
 }
 
+/// Adjustments applied on top of the base retry schedule of a revenue recovery payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryScheduleAdjustment {
+    /// Retry time was shifted into the preferred business hours window
+    BusinessHoursWindow,
+    /// Retry delay was overridden based on the decline class of the last attempt
+    DeclineClassOverride,
+    /// Retry time was spread out to avoid a burst of retries
+    Smoothing,
+    /// Retry time was pushed past the exclusion window of another retry scheduled for the same processor token
+    ProcessorTokenExclusionWindow,
+    /// Retry time was set by the merchant through the reschedule api
+    ManualReschedule,
+    /// Retry time was pushed past the lead time of the notification sent ahead of the retry
+    RetryNotificationLeadTime,
+    /// Retry time was pushed past a blackout of the recovery blackout calendar of the profile
+    BlackoutCalendar,
+    /// Retry time was pushed past the expiry month of the card, after the renewed card is issued
+    CardRenewal,
+}
+
+/// The source of the base schedule time of a revenue recovery retry, before any adjustments
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryScheduleAlgorithm {
+    /// The retry schedule configured for the merchant
+    MerchantRetrySchedule,
+    /// The delay profile of the decline class of the failed attempt
+    DeclineClassDelayProfile,
+    /// The retry override of the payment
+    RetryOverride,
+    /// The time asked for by the merchant through the reschedule api
+    ManualReschedule,
+}
+
+/// The terminal state of an invoice at the billing connector which was not reflected in its
+/// revenue recovery payment, as found by the nightly reconciliation
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryReconciliationDriftKind {
+    /// The invoice has been paid outside of revenue recovery
+    Paid,
+    /// The invoice has been voided at the billing connector
+    Voided,
+}
+
+/// The reason for which the revenue recovery retries of a payment were switched to another payment
+/// connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryConnectorSwitchReason {
+    /// The retries on the previous payment connector were soft declined as many times in a row as
+    /// allowed by the connector fallback of the profile
+    SoftDeclineLimitReached,
+}
+
+/// Code path which supplied a field of the revenue recovery data of a billing connector webhook
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryDataSource {
+    /// Parsed from the body of the webhook
+    Webhook,
+    /// Fetched from the billing connector using the payments sync call
+    Sync,
+    /// Not provided by either of the code paths
+    Absent,
+}
+
+/// Status of an invoice handed off to the manual collection queue once revenue recovery is exhausted
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecoveryCollectionStatus {
+    /// Waiting to be collected by the collections team
+    #[default]
+    Open,
+    /// Closed by the collections team with an outcome
+    Resolved,
+}
+
+/// Outcome with which the collections team resolves an invoice in the manual collection queue
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecoveryCollectionOutcome {
+    /// The customer paid the invoice outside of hyperswitch, the payment is marked as succeeded
+    PaidExternally,
+    /// The invoice could not be collected
+    Uncollectible,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -7992,6 +8452,7 @@ pub enum ProcessTrackerStatus {
     Debug,
     PartialEq,
     Eq,
+    Hash,
     strum::EnumString,
     strum::Display,
 )]
@@ -8006,6 +8467,11 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    WebhookDigestWorkflow,
+    RevenueRecoveryReconciliationWorkflow,
+    RevenueRecoveryPaymentsSyncPollingWorkflow,
+    WebhookBulkRetryWorkflow,
+    WebhookEventsRetentionWorkflow,
 }
 
 #[derive(Debug)]
@@ -254,3 +254,186 @@ pub struct XenditMultipleSplitResponse {
     pub routes: Vec<XenditSplitRoute>,
 }
 impl_to_sql_from_sql_json!(XenditMultipleSplitResponse);
+
+/// Raw response details of the payment processor for an attempt, as reported by the billing connector
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, ToSchema)]
+pub struct ProcessorResponseDetails {
+    /// Response code returned by the payment processor for the transaction
+    pub response_code: Option<String>,
+    /// Response message returned by the payment processor for the transaction
+    pub response_message: Option<String>,
+    /// Result of the address verification check performed by the payment processor
+    pub avs_result: Option<String>,
+    /// Result of the card verification value check performed by the payment processor
+    pub cvv_result: Option<String>,
+}
+
+/// Details of the card of a payment, as reported by the billing connector
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, ToSchema)]
+pub struct RevenueRecoveryCardDetails {
+    /// Network of the card
+    #[schema(example = "Visa")]
+    pub card_network: Option<enums::CardNetwork>,
+    /// Last four digits of the card number
+    #[schema(example = "4242")]
+    pub last4: Option<String>,
+    /// Month in which the card expires, from 1 to 12
+    #[schema(example = 12)]
+    pub card_exp_month: Option<u8>,
+    /// Year in which the card expires
+    #[schema(example = 2030)]
+    pub card_exp_year: Option<u16>,
+    /// Funding type of the card, either credit or debit
+    #[schema(example = "credit")]
+    pub card_funding: Option<enums::PaymentMethodType>,
+}
+
+/// Payment connectors through which the revenue recovery retries of a payment are made in turn,
+/// when the retries keep getting soft declined on a payment connector
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct RevenueRecoveryConnectorFallback {
+    /// Merchant connector ids of the payment connectors, in the order in which the retries are
+    /// switched to them
+    #[schema(value_type = Vec<String>, example = json!(["mca_primary", "mca_secondary"]))]
+    pub payment_connector_ids: Vec<common_utils::id_type::MerchantConnectorAccountId>,
+    /// Number of soft declines in a row of the retries on a payment connector, after which the
+    /// retries are switched to the next payment connector
+    #[schema(example = 2)]
+    pub soft_declines_before_switch: u16,
+}
+
+impl_to_sql_from_sql_json!(RevenueRecoveryConnectorFallback);
+
+/// Notification sent to the merchant ahead of every revenue recovery retry of a payment, so that
+/// the merchant can notify the customer before the payment method is charged again
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct RevenueRecoveryRetryNotification {
+    /// Whether the notifications are sent. The retries are not delayed for the notifications once
+    /// they are disabled, and the notifications which are pending are dropped.
+    #[schema(example = true)]
+    pub enabled: bool,
+    /// Time before the retry at which the notification is sent, in seconds. A retry is never made
+    /// earlier than this time after its notification.
+    #[schema(example = 86400)]
+    pub lead_time_in_secs: u32,
+}
+
+impl_to_sql_from_sql_json!(RevenueRecoveryRetryNotification);
+
+/// Calendar of the dates on which the revenue recovery retries of the profile are not made, such as
+/// religious holidays or the outages of regional banks. A retry falling within a blackout is moved
+/// to the end of the blackout.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct RevenueRecoveryBlackoutCalendar {
+    /// The blackouts of the calendar, which replace the blackouts set earlier. An empty list
+    /// removes all the blackouts.
+    pub blackouts: Vec<RevenueRecoveryBlackout>,
+}
+
+impl_to_sql_from_sql_json!(RevenueRecoveryBlackoutCalendar);
+
+/// Range of dates on which the revenue recovery retries are not made, observed in the given
+/// timezone
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct RevenueRecoveryBlackout {
+    /// The first date of the blackout
+    #[schema(value_type = String, example = "2025-12-24")]
+    pub start_date: time::Date,
+    /// The last date of the blackout, the retries are resumed at the start of the next date
+    #[schema(value_type = String, example = "2025-12-26")]
+    pub end_date: time::Date,
+    /// IANA timezone in which the dates of the blackout are observed
+    #[schema(example = "Europe/Berlin")]
+    pub timezone: String,
+    /// The reason of the blackout, for the reference of the merchant
+    #[schema(example = "Christmas")]
+    pub description: Option<String>,
+}
+
+impl RevenueRecoveryBlackout {
+    /// Number of days covered by the blackout, both of its dates included
+    pub fn get_length_in_days(&self) -> i64 {
+        (self.end_date - self.start_date)
+            .whole_days()
+            .saturating_add(1)
+    }
+}
+
+/// State of the rotation of the payment connectors through which the revenue recovery retries of a
+/// payment are made
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, ToSchema)]
+pub struct RevenueRecoveryConnectorRotation {
+    /// Number of soft declines in a row of the retries on the active payment connector
+    pub soft_decline_count: u16,
+    /// Processor payment method tokens of the payment, one for each payment connector through
+    /// which the payment was attempted
+    pub processor_payment_tokens: Vec<RevenueRecoveryProcessorPaymentToken>,
+    /// The latest switch of the payment connector of the retries
+    pub last_switch: Option<RevenueRecoveryConnectorSwitch>,
+}
+
+impl RevenueRecoveryConnectorRotation {
+    /// Records the processor payment token of the payment for the payment connector, replacing the
+    /// token recorded earlier for the payment connector
+    pub fn set_processor_payment_token(
+        &mut self,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        processor_payment_token: String,
+    ) {
+        self.processor_payment_tokens
+            .retain(|token| token.merchant_connector_id != *merchant_connector_id);
+        self.processor_payment_tokens
+            .push(RevenueRecoveryProcessorPaymentToken {
+                merchant_connector_id: merchant_connector_id.clone(),
+                processor_payment_token,
+            });
+    }
+
+    /// Processor payment token of the payment for the payment connector, if the payment was
+    /// attempted through it
+    pub fn get_processor_payment_token(
+        &self,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> Option<&str> {
+        self.processor_payment_tokens
+            .iter()
+            .find(|token| token.merchant_connector_id == *merchant_connector_id)
+            .map(|token| token.processor_payment_token.as_str())
+    }
+}
+
+/// Processor payment method token of a payment for a payment connector
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct RevenueRecoveryProcessorPaymentToken {
+    /// Merchant connector id of the payment connector
+    #[schema(value_type = String, example = "mca_1234567890")]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// Payment method token of the payment at the payment connector
+    pub processor_payment_token: String,
+}
+
+/// Switch of the payment connector through which the revenue recovery retries of a payment are made
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct RevenueRecoveryConnectorSwitch {
+    /// Merchant connector id of the payment connector from which the retries were switched
+    #[schema(value_type = String, example = "mca_primary")]
+    pub from_merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// Merchant connector id of the payment connector to which the retries were switched
+    #[schema(value_type = String, example = "mca_secondary")]
+    pub to_merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// The reason for which the retries were switched
+    #[schema(value_type = RecoveryConnectorSwitchReason, example = "soft_decline_limit_reached")]
+    pub reason: common_enums::RecoveryConnectorSwitchReason,
+    /// Number of soft declines in a row of the retries on the payment connector switched from
+    #[schema(example = 2)]
+    pub soft_decline_count: u16,
+}
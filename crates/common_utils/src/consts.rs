@@ -176,3 +176,7 @@ pub const DEFAULT_CARD_TESTING_GUARD_EXPIRY_IN_SECS: i32 = 3600;
 
 /// SOAP 1.1 Envelope Namespace
 pub const SOAP_ENV_NAMESPACE: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+
+/// Version of payments, refunds and disputes when they are created. The version is incremented on
+/// every update of the status of the object
+pub const INITIAL_ENTITY_VERSION: i64 = 1;
@@ -131,6 +131,45 @@ pub mod iso8601 {
                 .transpose()
         }
     }
+
+    /// Use the well-known ISO 8601 format when serializing and deserializing a
+    /// [`Vec<PrimitiveDateTime>`][PrimitiveDateTime].
+    ///
+    /// [PrimitiveDateTime]: ::time::PrimitiveDateTime
+    pub mod vec {
+        use serde::{Deserialize, Serialize};
+
+        use super::*;
+
+        #[derive(Serialize, Deserialize)]
+        struct Iso8601DateTime(#[serde(with = "super")] PrimitiveDateTime);
+
+        /// Serialize a [`Vec<PrimitiveDateTime>`] using the well-known ISO 8601 format.
+        pub fn serialize<S>(date_times: &[PrimitiveDateTime], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            date_times
+                .iter()
+                .copied()
+                .map(Iso8601DateTime)
+                .collect::<Vec<_>>()
+                .serialize(serializer)
+        }
+
+        /// Deserialize a [`Vec<PrimitiveDateTime>`] from its ISO 8601 representation.
+        pub fn deserialize<'a, D>(deserializer: D) -> Result<Vec<PrimitiveDateTime>, D::Error>
+        where
+            D: Deserializer<'a>,
+        {
+            Vec::<Iso8601DateTime>::deserialize(deserializer).map(|date_times| {
+                date_times
+                    .into_iter()
+                    .map(|Iso8601DateTime(date_time)| date_time)
+                    .collect()
+            })
+        }
+    }
 }
 
 /// Use the UNIX timestamp when serializing and deserializing an
@@ -294,4 +333,27 @@ mod tests {
 
         assert!(deser.is_ok())
     }
+
+    #[test]
+    fn test_iso8601_vec_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct Try {
+            #[serde(with = "crate::custom_serde::iso8601::vec")]
+            f: Vec<time::PrimitiveDateTime>,
+        }
+        let date_times = json!({"f": ["2025-01-01T10:00:00.000Z", "2025-01-02T10:00:00+05:30"]});
+        let deser = serde_json::from_value::<Try>(date_times).expect("valid date times");
+
+        assert_eq!(
+            deser.f,
+            vec![
+                time::macros::datetime!(2025-01-01 10:00:00),
+                time::macros::datetime!(2025-01-02 04:30:00),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&deser).expect("serializable date times"),
+            json!({"f": ["2025-01-01T10:00:00.000Z", "2025-01-02T04:30:00.000Z"]})
+        );
+    }
 }
@@ -203,6 +203,35 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// Get the key of the config which enables handing off the invoices to the manual collection
+    /// queue once revenue recovery is exhausted
+    pub fn get_recovery_manual_collection_handoff_key(&self) -> String {
+        format!(
+            "recovery_manual_collection_handoff_{}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the config which overrides the concurrency limits on the revenue recovery
+    /// retries of the merchant
+    pub fn get_recovery_execution_concurrency_limit_key(&self) -> String {
+        format!(
+            "recovery_execution_concurrency_limit_{}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the config which turns the billing connector payments sync of the revenue
+    /// recovery webhooks of the merchant on or off
+    pub fn get_recovery_payments_sync_override_key(&self) -> String {
+        format!("recovery_payments_sync_override_{}", self.get_string_repr())
+    }
+
+    /// Get the key of the config which overrides the API rate limits for the merchant
+    pub fn get_api_rate_limit_override_key(&self) -> String {
+        format!("api_rate_limit_override_{}", self.get_string_repr())
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
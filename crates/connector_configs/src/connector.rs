@@ -262,6 +262,7 @@ pub struct ConnectorConfig {
     pub dummy_connector: Option<ConnectorTomlConfig>,
     pub stripe_test: Option<ConnectorTomlConfig>,
     pub paypal_test: Option<ConnectorTomlConfig>,
+    pub dummy_billing_connector: Option<ConnectorTomlConfig>,
     pub zen: Option<ConnectorTomlConfig>,
     pub zsl: Option<ConnectorTomlConfig>,
     pub taxjar: Option<ConnectorTomlConfig>,
@@ -446,6 +447,8 @@ impl ConnectorConfig {
             Connector::DummyConnector6 => Ok(connector_data.dummy_connector),
             #[cfg(feature = "dummy_connector")]
             Connector::DummyConnector7 => Ok(connector_data.paypal_test),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyBillingConnector => Ok(connector_data.dummy_billing_connector),
             Connector::Netcetera => Ok(connector_data.netcetera),
             Connector::CtpMastercard => Ok(connector_data.ctp_mastercard),
             Connector::Xendit => Ok(connector_data.xendit),
@@ -364,6 +364,14 @@ pub struct Profile {
     pub three_ds_decision_manager_config: Option<common_types::payments::DecisionManagerRecord>,
     pub should_collect_cvv_during_payment:
         Option<primitive_wrappers::ShouldCollectCvvDuringPayment>,
+    pub default_recovery_payment_connector_id:
+        Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
 }
 
 impl Profile {
@@ -431,6 +439,14 @@ pub struct ProfileNew {
     pub three_ds_decision_manager_config: Option<common_types::payments::DecisionManagerRecord>,
     pub should_collect_cvv_during_payment:
         Option<primitive_wrappers::ShouldCollectCvvDuringPayment>,
+    pub default_recovery_payment_connector_id:
+        Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
     pub id: common_utils::id_type::ProfileId,
 }
 
@@ -484,6 +500,14 @@ pub struct ProfileUpdateInternal {
     pub three_ds_decision_manager_config: Option<common_types::payments::DecisionManagerRecord>,
     pub should_collect_cvv_during_payment:
         Option<primitive_wrappers::ShouldCollectCvvDuringPayment>,
+    pub default_recovery_payment_connector_id:
+        Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
 }
 
 #[cfg(feature = "v2")]
@@ -533,6 +557,10 @@ impl ProfileUpdateInternal {
             is_clear_pan_retries_enabled,
             is_debit_routing_enabled,
             merchant_business_country,
+            default_recovery_payment_connector_id,
+            revenue_recovery_connector_fallback,
+            revenue_recovery_retry_notification,
+            revenue_recovery_blackout_calendar,
         } = self;
         Profile {
             id: source.id,
@@ -613,6 +641,15 @@ impl ProfileUpdateInternal {
             is_debit_routing_enabled,
             merchant_business_country: merchant_business_country
                 .or(source.merchant_business_country),
+
+            default_recovery_payment_connector_id: default_recovery_payment_connector_id
+                .or(source.default_recovery_payment_connector_id),
+            revenue_recovery_connector_fallback: revenue_recovery_connector_fallback
+                .or(source.revenue_recovery_connector_fallback),
+            revenue_recovery_retry_notification: revenue_recovery_retry_notification
+                .or(source.revenue_recovery_retry_notification),
+            revenue_recovery_blackout_calendar: revenue_recovery_blackout_calendar
+                .or(source.revenue_recovery_blackout_calendar),
         }
     }
 }
@@ -641,7 +678,7 @@ pub struct CardTestingGuardConfig {
 
 common_utils::impl_to_sql_from_sql_json!(CardTestingGuardConfig);
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Json)]
 pub struct WebhookDetails {
     pub webhook_version: Option<String>,
@@ -651,10 +688,88 @@ pub struct WebhookDetails {
     pub payment_created_enabled: Option<bool>,
     pub payment_succeeded_enabled: Option<bool>,
     pub payment_failed_enabled: Option<bool>,
+    pub url_change_confirmation_enabled: Option<bool>,
+    pub pending_url_change: Option<PendingWebhookUrlChange>,
+    pub redirect_policy: Option<common_enums::WebhookRedirectPolicy>,
+    pub digest_config: Option<WebhookDigestConfig>,
+    pub secondary_notification_config: Option<WebhookSecondaryNotificationConfig>,
+    /// The webhooks of all the event types are sent if not specified or empty
+    pub enabled_event_types: Option<Vec<common_enums::EventType>>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
 
+impl WebhookDetails {
+    pub fn is_subscribed_to(&self, event_type: common_enums::EventType) -> bool {
+        self.enabled_event_types
+            .as_ref()
+            .map_or(true, |enabled_event_types| {
+                enabled_event_types.is_empty() || enabled_event_types.contains(&event_type)
+            })
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PendingWebhookUrlChange {
+    pub webhook_url: Secret<String>,
+    pub verification_token: Secret<String>,
+    pub status: common_enums::WebhookUrlChangeStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub requested_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
+
+impl PendingWebhookUrlChange {
+    /// Returns the status of the change, considering a pending change past its expiry as expired
+    pub fn get_status(&self, now: time::PrimitiveDateTime) -> common_enums::WebhookUrlChangeStatus {
+        match self.status {
+            common_enums::WebhookUrlChangeStatus::Pending if now > self.expires_at => {
+                common_enums::WebhookUrlChangeStatus::Expired
+            }
+            status => status,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct WebhookDigestConfig {
+    pub enabled: bool,
+    pub hour_of_day: u8,
+    /// IANA name of the timezone in which the hour of the day is specified
+    pub timezone: String,
+    pub channels: Vec<common_enums::WebhookDigestChannel>,
+    pub email_recipient: Option<pii::Email>,
+}
+
+impl WebhookDigestConfig {
+    pub fn is_channel_enabled(&self, channel: common_enums::WebhookDigestChannel) -> bool {
+        self.enabled && self.channels.contains(&channel)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct WebhookSecondaryNotificationConfig {
+    pub enabled: bool,
+    pub notification_url: Option<Secret<String>>,
+    pub email_recipient: Option<pii::Email>,
+    /// All the kinds are sent if not specified
+    pub notification_kinds: Option<Vec<common_enums::WebhookMetaNotificationKind>>,
+    pub backlog_threshold: Option<i64>,
+}
+
+impl WebhookSecondaryNotificationConfig {
+    pub fn is_subscribed_to(&self, kind: common_enums::WebhookMetaNotificationKind) -> bool {
+        self.enabled
+            && self
+                .notification_kinds
+                .as_ref()
+                .map_or(true, |notification_kinds| {
+                    notification_kinds.contains(&kind)
+                })
+    }
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct BusinessPaymentLinkConfig {
@@ -32,7 +32,10 @@ pub struct ConfigUpdateInternal {
 
 impl ConfigUpdateInternal {
     pub fn create_config(self, source: Config) -> Config {
-        Config { ..source }
+        Config {
+            config: self.config.unwrap_or(source.config),
+            ..source
+        }
     }
 }
 
@@ -63,6 +63,8 @@ pub struct Dispute {
     pub dispute_amount: i64,
     pub organization_id: common_utils::id_type::OrganizationId,
     pub dispute_currency: Option<storage_enums::Currency>,
+    /// Incremented on every update of the status of the dispute
+    pub entity_version: i64,
 }
 
 #[derive(Debug)]
@@ -99,6 +101,12 @@ pub struct DisputeUpdateInternal {
     evidence: Option<Secret<serde_json::Value>>,
 }
 
+impl DisputeUpdateInternal {
+    pub fn is_status_update(&self) -> bool {
+        self.dispute_status.is_some()
+    }
+}
+
 impl From<DisputeUpdate> for DisputeUpdateInternal {
     fn from(merchant_account_update: DisputeUpdate) -> Self {
         match merchant_account_update {
@@ -27,14 +27,22 @@ pub struct EventNew {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub entity_version: Option<i64>,
+    pub payload_scrubbed_at: Option<PrimitiveDateTime>,
+    pub delivery_latency_ms: Option<i64>,
+    pub latest_delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = events)]
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
+    pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub payload_scrubbed_at: Option<PrimitiveDateTime>,
+    pub delivery_latency_ms: Option<i64>,
+    pub latest_delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
@@ -60,6 +68,12 @@ pub struct Event {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub entity_version: Option<i64>,
+    pub payload_scrubbed_at: Option<PrimitiveDateTime>,
+    pub delivery_latency_ms: Option<i64>,
+    // Populated on the initial delivery attempt only, from the latest delivery attempt of the
+    // event, so that the initial delivery attempts can be filtered by it
+    pub latest_delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -87,3 +101,79 @@ pub enum EventMetadata {
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
+
+/// Counts of the initial delivery attempts of an event type created on a day, by the outcome of
+/// their delivery
+#[derive(Clone, Debug, Eq, PartialEq, Queryable)]
+pub struct EventDeliveryOutcomeCounts {
+    pub event_type: storage_enums::EventType,
+    /// The day in UTC on which the events were created
+    pub date: time::Date,
+    pub delivered_count: i64,
+    /// Number of the events which are not delivered yet, but may still be delivered by a retry
+    pub pending_count: i64,
+    pub failed_count: i64,
+}
+
+/// Latency of the initial delivery attempts which received a response from the webhook endpoint
+#[derive(Clone, Copy, Debug, Default, PartialEq, Queryable)]
+pub struct EventDeliveryLatency {
+    pub average_ms: Option<f64>,
+    pub p95_ms: Option<f64>,
+}
+
+/// Delivery attempts made for an event, the initial attempt included
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventAttemptsSummary {
+    pub initial_attempt_id: String,
+    pub attempt_count: i64,
+    /// Time at which the latest delivery attempt was created
+    pub last_attempted_at: PrimitiveDateTime,
+}
+
+/// Tracking data of the process tracker task which sends the daily digest of the webhook delivery
+/// failures of a profile
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookDigestTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Date, in the timezone of the digest, of the last digest sent. The task does not send a
+    /// digest for the same date again, even if it is run again after a restart.
+    pub last_digest_date: Option<time::Date>,
+}
+
+/// Tracking data of the process tracker task which deletes the webhook events of a merchant older
+/// than the retention period, archiving the undelivered ones first
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookEventsRetentionTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+/// Tracking data of the process tracker task which enqueues the failed deliveries of the merchant
+/// matching the constraints of a bulk retry job, one task per event
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookBulkRetryJobTrackingData {
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+    pub event_types: Option<Vec<storage_enums::EventType>>,
+    /// Only the undelivered events in this delivery state are retried, if specified
+    #[serde(default)]
+    pub delivery_state: Option<storage_enums::EventDeliveryState>,
+    pub max_count: u32,
+    /// Number of the events enqueued for delivery, set once the events have been enqueued
+    pub queued_count: Option<u32>,
+}
+
+/// Tracking data of the process tracker task which delivers an event again, as part of a bulk
+/// retry job
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookBulkRetryTrackingData {
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub initial_attempt_id: String,
+}
@@ -0,0 +1,20 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::events_archive;
+
+/// Reference to an archive of the events of a merchant written to the events archive storage by
+/// the events retention job, before the events were deleted.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Insertable)]
+#[diesel(table_name = events_archive, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct EventsArchive {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// Key of the archive object in the events archive storage
+    pub object_key: String,
+    /// Hex encoded SHA-256 digest of the archive object, verified after it was written
+    pub checksum: String,
+    pub events_count: i32,
+    pub oldest_event_created_at: time::PrimitiveDateTime,
+    pub newest_event_created_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+}
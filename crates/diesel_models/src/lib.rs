@@ -18,6 +18,7 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
+pub mod events_archive;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
@@ -49,6 +50,10 @@ pub mod unified_translations;
 
 #[cfg(feature = "v2")]
 pub mod payment_methods_session;
+#[cfg(feature = "v2")]
+pub mod revenue_recovery_collection_item;
+#[cfg(feature = "v2")]
+pub mod revenue_recovery_decision;
 
 #[allow(unused_qualifications)]
 pub mod schema;
@@ -297,6 +297,28 @@ pub struct RevenueRecoveryMetadata {
     /// Merchants need to provide a mapping between these merchant connector account and the corresponding  
     /// account reference IDs for each `billing connector`.
     pub billing_account_reference: BillingAccountReference,
+    /// Whether the open invoices of the `billing connector` are reconciled nightly against the billing connector.
+    #[serde(default)]
+    pub reconciliation_enabled: bool,
+    /// Polling of the `billing connector` for the transactions of the invoices whose webhooks
+    /// have gone quiet, disabled when not present
+    #[serde(default)]
+    pub payments_sync_polling: Option<RevenueRecoveryPaymentsSyncPolling>,
+    /// Timeout of the payments sync calls to the `billing connector`, overriding the timeout
+    /// configured for the deployment
+    #[serde(default)]
+    pub payments_sync_timeout_in_secs: Option<u16>,
+    /// Whether the payments sync of the `billing connector` is called for the webhooks,
+    /// overriding the `billing connectors` configured for the deployment
+    #[serde(default)]
+    pub payments_sync_required: Option<bool>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RevenueRecoveryPaymentsSyncPolling {
+    pub quiet_period_in_hours: u16,
+    pub max_empty_polls: u16,
 }
 
 #[cfg(feature = "v2")]
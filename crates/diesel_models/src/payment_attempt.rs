@@ -3668,6 +3668,12 @@ pub struct PaymentAttemptFeatureMetadata {
 #[diesel(sql_type = diesel::pg::sql_types::Jsonb)]
 pub struct PaymentAttemptRecoveryData {
     pub attempt_triggered_by: common_enums::TriggeredBy,
+    pub processor_response_details: Option<common_types::payments::ProcessorResponseDetails>,
+    pub schedule_adjustments: Option<Vec<common_enums::RecoveryScheduleAdjustment>>,
+    pub data_provenance:
+        Option<std::collections::BTreeMap<String, common_enums::RecoveryDataSource>>,
+    pub recovery_correlation_id: Option<String>,
+    pub connector_switch: Option<common_types::payments::RevenueRecoveryConnectorSwitch>,
 }
 #[cfg(feature = "v2")]
 common_utils::impl_to_sql_from_sql_json!(PaymentAttemptFeatureMetadata);
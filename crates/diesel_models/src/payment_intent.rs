@@ -61,6 +61,7 @@ pub struct PaymentIntent {
     pub platform_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub force_3ds_challenge: Option<bool>,
     pub force_3ds_challenge_trigger: Option<bool>,
+    pub entity_version: i64,
     pub merchant_reference_id: Option<common_utils::id_type::PaymentReferenceId>,
     pub billing_address: Option<Encryption>,
     pub shipping_address: Option<Encryption>,
@@ -149,6 +150,8 @@ pub struct PaymentIntent {
     pub platform_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub force_3ds_challenge: Option<bool>,
     pub force_3ds_challenge_trigger: Option<bool>,
+    /// Incremented on every update of the status of the payment
+    pub entity_version: i64,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression, PartialEq)]
@@ -339,6 +342,7 @@ pub struct PaymentIntentNew {
     pub platform_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub force_3ds_challenge: Option<bool>,
     pub force_3ds_challenge_trigger: Option<bool>,
+    pub entity_version: i64,
 }
 
 #[cfg(feature = "v1")]
@@ -410,6 +414,7 @@ pub struct PaymentIntentNew {
     pub platform_merchant_id: Option<common_utils::id_type::MerchantId>,
     pub force_3ds_challenge: Option<bool>,
     pub force_3ds_challenge_trigger: Option<bool>,
+    pub entity_version: i64,
 }
 
 #[cfg(feature = "v2")]
@@ -698,6 +703,11 @@ impl PaymentIntentUpdate {
             tax_details,
             force_3ds_challenge,
         } = self.into();
+        let entity_version = if status.is_some() {
+            source.entity_version + 1
+        } else {
+            source.entity_version
+        };
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
             currency: currency.or(source.currency),
@@ -743,6 +753,7 @@ impl PaymentIntentUpdate {
                 .or(source.is_payment_processor_token_flow),
             tax_details: tax_details.or(source.tax_details),
             force_3ds_challenge: force_3ds_challenge.or(source.force_3ds_challenge),
+            entity_version,
             ..source
         }
     }
@@ -154,6 +154,30 @@ impl Default for ProcessTrackerUpdateInternal {
     }
 }
 
+impl ProcessTrackerUpdateInternal {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        let Self {
+            name,
+            retry_count,
+            schedule_time,
+            tracking_data,
+            business_status,
+            status,
+            updated_at,
+        } = self;
+        ProcessTracker {
+            name: name.or(source.name),
+            retry_count: retry_count.unwrap_or(source.retry_count),
+            schedule_time: schedule_time.or(source.schedule_time),
+            tracking_data: tracking_data.unwrap_or(source.tracking_data),
+            business_status: business_status.unwrap_or(source.business_status),
+            status: status.unwrap_or(source.status),
+            updated_at: updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     fn from(process_tracker_update: ProcessTrackerUpdate) -> Self {
         match process_tracker_update {
@@ -196,6 +220,38 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     }
 }
 
+/// Filters for listing the tasks of a merchant, the merchant and the profile being matched
+/// against the `merchant_id` and `profile_id` of the tracking data of the task
+#[derive(Clone, Debug)]
+pub struct ProcessTrackerMerchantTaskConstraints {
+    /// Tags which the tasks are to be tagged with, all of them
+    pub tags: Vec<String>,
+    pub runner: ProcessTrackerRunner,
+    pub names: Vec<String>,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub status: Option<storage_enums::ProcessTrackerStatus>,
+    pub business_status: Option<String>,
+    pub schedule_time_start: Option<PrimitiveDateTime>,
+    pub schedule_time_end: Option<PrimitiveDateTime>,
+    /// Schedule time and id of the task after which the tasks are to be listed, the tasks being
+    /// ordered by their schedule time and then by their id
+    pub starting_after: Option<(PrimitiveDateTime, String)>,
+    pub limit: i64,
+}
+
+/// Counts of the tasks of a runner which are yet to be finished, of a merchant
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Queryable)]
+pub struct ProcessTrackerMerchantTaskCounts {
+    /// Number of distinct payments the tasks are of
+    pub payments_count: i64,
+    /// Number of the tasks waiting for their schedule time or to be picked up
+    pub pending_count: i64,
+    /// Number of the pending tasks which were scheduled before the given time
+    pub overdue_count: i64,
+    pub review_count: i64,
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -247,6 +303,23 @@ pub mod business_status {
     /// A task that reaches this status should not be retried (rescheduled for execution) later.
     pub const RESOURCE_STATUS_MISMATCH: &str = "RESOURCE_STATUS_MISMATCH";
 
+    /// The payload of the event associated with the task was scrubbed on the request of the
+    /// merchant, due to which the event must not be delivered again.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const EVENT_PAYLOAD_SCRUBBED: &str = "EVENT_PAYLOAD_SCRUBBED";
+
+    /// The initial delivery attempt of the event associated with the task had already been
+    /// delivered when the task was run, due to which the event was not delivered again.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const EVENT_ALREADY_DELIVERED: &str = "EVENT_ALREADY_DELIVERED";
+
+    /// The event was delivered again by the bulk retry of the failed deliveries of the merchant.
+    pub const BULK_RETRY_DELIVERY_SUCCESSFUL: &str = "BULK_RETRY_DELIVERY_SUCCESSFUL";
+
+    /// The event could not be delivered again by the bulk retry of the failed deliveries of the
+    /// merchant. The event is not retried again by the bulk retry.
+    pub const BULK_RETRY_DELIVERY_FAILED: &str = "BULK_RETRY_DELIVERY_FAILED";
+
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
 
@@ -264,4 +337,109 @@ pub mod business_status {
 
     /// This status indicates the completion of a psync task
     pub const PSYNC_WORKFLOW_COMPLETE: &str = "COMPLETED_PSYNC_TASK";
+
+    /// This status indicates that the calculate task was completed after scheduling the execute task
+    pub const CALCULATE_WORKFLOW_COMPLETE: &str = "COMPLETED_CALCULATE_TASK";
+
+    /// This status indicates that the execute task was completed with the retries of the payment
+    /// exhausted, the invoice being left to be collected by the merchant
+    pub const EXECUTE_WORKFLOW_RETRIES_EXHAUSTED: &str = "COMPLETED_EXECUTE_TASK_RETRIES_EXHAUSTED";
+
+    /// This status indicates that the execute task was cancelled, since the invoice no longer has
+    /// any amount to be recovered
+    pub const EXECUTE_WORKFLOW_CANCELLED_FOR_ZERO_AMOUNT_INVOICE: &str =
+        "CANCELLED_EXECUTE_TASK_FOR_ZERO_AMOUNT_INVOICE";
+
+    /// This status indicates that the execute task was cancelled, since the subscription of the
+    /// invoice has been cancelled in the billing connector
+    pub const EXECUTE_WORKFLOW_CANCELLED_FOR_SUBSCRIPTION_CANCELLATION: &str =
+        "CANCELLED_EXECUTE_TASK_FOR_SUBSCRIPTION_CANCELLATION";
+
+    /// This status indicates that the execute task was cancelled, since the invoice has been paid
+    /// outside of revenue recovery
+    pub const EXECUTE_WORKFLOW_CANCELLED_FOR_EXTERNAL_PAYMENT: &str =
+        "CANCELLED_EXECUTE_TASK_FOR_EXTERNAL_PAYMENT";
+
+    /// This status indicates that the execute task was cancelled, since the invoice has been voided
+    /// in the billing connector
+    pub const EXECUTE_WORKFLOW_CANCELLED_FOR_VOIDED_INVOICE: &str =
+        "CANCELLED_EXECUTE_TASK_FOR_VOIDED_INVOICE";
+
+    /// This status indicates that the retry being made by the execute task was aborted, since the
+    /// invoice was closed in the billing connector while the task was being executed
+    pub const EXECUTE_WORKFLOW_ABORTED_FOR_CLOSED_INVOICE: &str =
+        "ABORTED_EXECUTE_TASK_FOR_CLOSED_INVOICE";
+
+    /// This status indicates that the execute task has been paused, since a dispute raised by the
+    /// customer of the invoice is open
+    pub const EXECUTE_WORKFLOW_PAUSED_FOR_DISPUTE: &str = "PAUSED_EXECUTE_TASK_FOR_DISPUTE";
+
+    /// This status indicates that the execute task has been cancelled manually through the api
+    pub const EXECUTE_WORKFLOW_CANCELLED_MANUALLY: &str = "CANCELLED_EXECUTE_TASK_MANUALLY";
+
+    /// This status indicates that the execute task has been halted by the global kill switch of
+    /// revenue recovery, and is picked up again after the re-check interval
+    pub const EXECUTE_WORKFLOW_HALTED_BY_KILL_SWITCH: &str = "HALTED_EXECUTE_TASK_BY_KILL_SWITCH";
+
+    /// This status indicates that the calculate task has been halted by the global kill switch of
+    /// revenue recovery, and is picked up again after the re-check interval
+    pub const CALCULATE_WORKFLOW_HALTED_BY_KILL_SWITCH: &str =
+        "HALTED_CALCULATE_TASK_BY_KILL_SWITCH";
+
+    /// This status indicates that a revenue recovery task halted by the global kill switch has been
+    /// rescheduled within the resume window once the switch was turned off, and is executed at its
+    /// new schedule time
+    pub const RECOVERY_WORKFLOW_RESUMED_AFTER_KILL_SWITCH: &str =
+        "RESUMED_RECOVERY_TASK_AFTER_KILL_SWITCH";
+
+    /// This status indicates that the execute task has been throttled by the concurrency limits on
+    /// the retries of the merchant, and is picked up again after a short randomized delay
+    pub const EXECUTE_WORKFLOW_THROTTLED: &str = "THROTTLED_EXECUTE_TASK";
+
+    /// This status indicates that the retry made by the execute task timed out at the payment
+    /// connector, and the task is picked up again after a short delay to sync the retry
+    pub const EXECUTE_WORKFLOW_REQUEUED_FOR_CONNECTOR_TIMEOUT: &str =
+        "REQUEUED_EXECUTE_TASK_FOR_CONNECTOR_TIMEOUT";
+
+    /// This status indicates that the execution of a revenue recovery task failed with an error,
+    /// and the task is picked up again after the retry interval of the failed executions
+    pub const RECOVERY_WORKFLOW_EXECUTION_FAILED: &str = "EXECUTION_FAILED_RECOVERY_TASK";
+
+    /// This status indicates that the executions of a revenue recovery task failed with an error
+    /// too many times in a row, and the task has been moved to review until it is requeued
+    pub const RECOVERY_WORKFLOW_DEAD_LETTERED: &str = "DEAD_LETTERED_RECOVERY_TASK";
+
+    /// This status indicates that the payment connector of a failed recovery payment could not be
+    /// resolved, and the task has been held in review until the payment connector is mapped
+    pub const UNRESOLVED_CONNECTOR_WORKFLOW_PENDING_MAPPING: &str =
+        "PENDING_CONNECTOR_MAPPING_UNRESOLVED_CONNECTOR_TASK";
+
+    /// This status indicates that the payment connector of the failed recovery payment has been
+    /// resolved on requeueing the task, and the retry of the payment has been scheduled
+    pub const UNRESOLVED_CONNECTOR_WORKFLOW_RESOLVED: &str = "RESOLVED_UNRESOLVED_CONNECTOR_TASK";
+
+    /// This status indicates that the notify task sent the notification of the upcoming retry
+    pub const NOTIFY_WORKFLOW_COMPLETE: &str = "COMPLETED_NOTIFY_TASK";
+
+    /// This status indicates that the notify task did not send the notification, since the
+    /// notifications were disabled or the retry is no longer scheduled
+    pub const NOTIFY_WORKFLOW_SKIPPED: &str = "SKIPPED_NOTIFY_TASK";
+
+    /// This status indicates that the notify task has been halted by the global kill switch of
+    /// revenue recovery, and is picked up again after the re-check interval
+    pub const NOTIFY_WORKFLOW_HALTED_BY_KILL_SWITCH: &str = "HALTED_NOTIFY_TASK_BY_KILL_SWITCH";
+
+    /// This status indicates that the record back task recorded the outcome of the revenue
+    /// recovery retries with the billing connector
+    pub const RECORD_BACK_WORKFLOW_COMPLETE: &str = "COMPLETED_RECORD_BACK_TASK";
+
+    /// This status indicates that the payments sync polling task was completed, since the recovery
+    /// of the invoice is over
+    pub const PAYMENTS_SYNC_POLLING_WORKFLOW_COMPLETE: &str =
+        "COMPLETED_PAYMENTS_SYNC_POLLING_TASK";
+
+    /// This status indicates that the payments sync polling task stopped polling the billing
+    /// connector, since the allowed number of polls finding no new transaction has been reached
+    pub const PAYMENTS_SYNC_POLLING_WORKFLOW_EMPTY_POLLS_EXHAUSTED: &str =
+        "COMPLETED_PAYMENTS_SYNC_POLLING_TASK_EMPTY_POLLS_EXHAUSTED";
 }
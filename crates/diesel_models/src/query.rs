@@ -16,6 +16,7 @@ pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod events;
+pub mod events_archive;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
@@ -36,6 +37,10 @@ pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod relay;
+#[cfg(feature = "v2")]
+pub mod revenue_recovery_collection_item;
+#[cfg(feature = "v2")]
+pub mod revenue_recovery_decision;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
@@ -68,6 +68,9 @@ impl Dispute {
     }
 
     pub async fn update(self, conn: &PgPooledConn, dispute: DisputeUpdate) -> StorageResult<Self> {
+        let dispute_update = DisputeUpdateInternal::from(dispute);
+        let entity_version_increment = i64::from(dispute_update.is_status_update());
+
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
             _,
@@ -76,7 +79,10 @@ impl Dispute {
         >(
             conn,
             dsl::dispute_id.eq(self.dispute_id.to_owned()),
-            DisputeUpdateInternal::from(dispute),
+            (
+                dispute_update,
+                dsl::entity_version.eq(dsl::entity_version + entity_version_increment),
+            ),
         )
         .await
         {
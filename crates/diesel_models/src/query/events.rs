@@ -1,10 +1,15 @@
 use diesel::{
     associations::HasTable, BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods,
+    PgExpressionMethods,
 };
 
 use super::generics;
 use crate::{
-    events::{Event, EventNew, EventUpdateInternal},
+    enums as storage_enums,
+    events::{
+        Event, EventAttemptsSummary, EventDeliveryLatency, EventDeliveryOutcomeCounts, EventNew,
+        EventUpdateInternal,
+    },
     schema::events::dsl,
     PgPooledConn, StorageResult,
 };
@@ -34,21 +39,49 @@ impl Event {
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage_enums::EventDeliveryState>,
+        event_types: Option<Vec<storage_enums::EventType>>,
+        event_class: Option<storage_enums::EventClass>,
+        delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
     ) -> StorageResult<Vec<Self>> {
-        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::event_id
-                .nullable()
-                .eq(dsl::initial_attempt_id) // Filter initial attempts only
-                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
-                .and(dsl::primary_object_id.eq(primary_object_id.to_owned())),
-            None,
-            None,
-            Some(dsl::created_at.desc()),
-        )
-        .await
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::primary_object_id.eq(primary_object_id.to_owned())),
+            )
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        query = Self::apply_event_filters(
+            query,
+            is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+        );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering events by primary object id")
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn list_initial_attempts_by_merchant_id_constraints(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -57,6 +90,11 @@ impl Event {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage_enums::EventDeliveryState>,
+        event_types: Option<Vec<storage_enums::EventType>>,
+        event_class: Option<storage_enums::EventClass>,
+        delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -73,7 +111,7 @@ impl Event {
                     .eq(dsl::initial_attempt_id) // Filter initial attempts only
                     .and(dsl::merchant_id.eq(merchant_id.to_owned())),
             )
-            .order(dsl::created_at.desc())
+            .order((dsl::created_at.desc(), dsl::event_id.desc()))
             .into_boxed();
 
         query = Self::apply_filters(
@@ -83,8 +121,22 @@ impl Event {
             limit,
             offset,
             is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
         );
 
+        // The events are listed latest first, so the events of the page are the ones older than
+        // the last event of the previous page
+        if let Some((created_at, event_id)) = starting_after {
+            query = query.filter(
+                dsl::created_at.lt(created_at).or(dsl::created_at
+                    .eq(created_at)
+                    .and(dsl::event_id.lt(event_id))),
+            );
+        }
+
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
 
         track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
@@ -114,21 +166,49 @@ impl Event {
         conn: &PgPooledConn,
         profile_id: &common_utils::id_type::ProfileId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage_enums::EventDeliveryState>,
+        event_types: Option<Vec<storage_enums::EventType>>,
+        event_class: Option<storage_enums::EventClass>,
+        delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
     ) -> StorageResult<Vec<Self>> {
-        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::event_id
-                .nullable()
-                .eq(dsl::initial_attempt_id) // Filter initial attempts only
-                .and(dsl::business_profile_id.eq(profile_id.to_owned()))
-                .and(dsl::primary_object_id.eq(primary_object_id.to_owned())),
-            None,
-            None,
-            Some(dsl::created_at.desc()),
-        )
-        .await
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::business_profile_id.eq(profile_id.to_owned()))
+                    .and(dsl::primary_object_id.eq(primary_object_id.to_owned())),
+            )
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        query = Self::apply_event_filters(
+            query,
+            is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+        );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering events by primary object id")
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn list_initial_attempts_by_profile_id_constraints(
         conn: &PgPooledConn,
         profile_id: &common_utils::id_type::ProfileId,
@@ -137,6 +217,11 @@ impl Event {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage_enums::EventDeliveryState>,
+        event_types: Option<Vec<storage_enums::EventType>>,
+        event_class: Option<storage_enums::EventClass>,
+        delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -153,7 +238,7 @@ impl Event {
                     .eq(dsl::initial_attempt_id) // Filter initial attempts only
                     .and(dsl::business_profile_id.eq(profile_id.to_owned())),
             )
-            .order(dsl::created_at.desc())
+            .order((dsl::created_at.desc(), dsl::event_id.desc()))
             .into_boxed();
 
         query = Self::apply_filters(
@@ -163,8 +248,22 @@ impl Event {
             limit,
             offset,
             is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
         );
 
+        // The events are listed latest first, so the events of the page are the ones older than
+        // the last event of the previous page
+        if let Some((created_at, event_id)) = starting_after {
+            query = query.filter(
+                dsl::created_at.lt(created_at).or(dsl::created_at
+                    .eq(created_at)
+                    .and(dsl::event_id.lt(event_id))),
+            );
+        }
+
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
 
         track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
@@ -211,6 +310,277 @@ impl Event {
         .await
     }
 
+    /// Failed delivery attempts of the profile, including the retries, latest first. The events
+    /// which were not sent since the profile is not subscribed to their event type are left out.
+    pub async fn list_failed_delivery_attempts_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::business_profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::is_webhook_notified.eq(false))
+                .and(dsl::latest_delivery_status_code_class.is_distinct_from(
+                    storage_enums::WebhookDeliveryStatusCodeClass::SkippedNotSubscribed,
+                ))
+                .and(dsl::created_at.ge(created_after))
+                .and(dsl::created_at.le(created_before)),
+            Some(limit),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Number of the failed delivery attempts of the profile, including the retries, by event type
+    pub async fn count_failed_delivery_attempts_by_profile_id_event_type(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<(storage_enums::EventType, i64)>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let query = Self::table()
+            .group_by(dsl::event_type)
+            .select((dsl::event_type, diesel::dsl::count_star()))
+            .filter(
+                dsl::business_profile_id
+                    .eq(profile_id.to_owned())
+                    .and(dsl::is_webhook_notified.eq(false))
+                    .and(dsl::latest_delivery_status_code_class.is_distinct_from(
+                        storage_enums::WebhookDeliveryStatusCodeClass::SkippedNotSubscribed,
+                    ))
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.le(created_before)),
+            )
+            .into_boxed();
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_results_async::<(storage_enums::EventType, i64)>(conn),
+            DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting failed delivery attempts by event type")
+    }
+
+    /// Number of the initial delivery attempts of the merchant, or of the given profile of the
+    /// merchant, by event type and by the day they were created on, in a single scan. The events
+    /// which are not delivered yet are counted as pending if they were created after
+    /// `pending_after`, since their delivery may still be retried, and as failed otherwise. The
+    /// events which were never sent, since the profile is not subscribed to their event type, are
+    /// not counted.
+    pub async fn count_initial_attempts_by_event_type_date_outcome(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        pending_after: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<EventDeliveryOutcomeCounts>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{
+            debug_query,
+            dsl::sql,
+            pg::Pg,
+            sql_types::{BigInt, Date, Timestamp},
+            QueryDsl,
+        };
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::latest_delivery_status_code_class.is_distinct_from(
+                        storage_enums::WebhookDeliveryStatusCodeClass::SkippedNotSubscribed,
+                    ))
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.le(created_before)),
+            )
+            .group_by((
+                sql::<storage_enums::DbEventType>("event_type"),
+                sql::<Date>("created_at::date"),
+            ))
+            .select((
+                sql::<storage_enums::DbEventType>("event_type"),
+                sql::<Date>("created_at::date"),
+                sql::<BigInt>("COUNT(*) FILTER (WHERE is_overall_delivery_successful IS TRUE)"),
+                sql::<BigInt>(
+                    "COUNT(*) FILTER (WHERE is_overall_delivery_successful IS NOT TRUE \
+                     AND created_at > ",
+                )
+                .bind::<Timestamp, _>(pending_after)
+                .sql(")"),
+                sql::<BigInt>(
+                    "COUNT(*) FILTER (WHERE is_overall_delivery_successful IS NOT TRUE \
+                     AND created_at <= ",
+                )
+                .bind::<Timestamp, _>(pending_after)
+                .sql(")"),
+            ))
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::business_profile_id.eq(profile_id));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_results_async::<EventDeliveryOutcomeCounts>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting initial delivery attempts by event type and date")
+    }
+
+    /// Average and 95th percentile of the latency of the initial delivery attempts of the
+    /// merchant, or of the given profile of the merchant, which received a response
+    pub async fn get_initial_attempts_delivery_latency(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<EventDeliveryLatency> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{
+            debug_query,
+            dsl::sql,
+            pg::Pg,
+            sql_types::{Double, Nullable},
+            QueryDsl,
+        };
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .select((
+                sql::<Nullable<Double>>("AVG(delivery_latency_ms)::DOUBLE PRECISION"),
+                sql::<Nullable<Double>>(
+                    "PERCENTILE_CONT(0.95) WITHIN GROUP (ORDER BY delivery_latency_ms)",
+                ),
+            ))
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.le(created_before))
+                    .and(dsl::delivery_latency_ms.is_not_null()),
+            )
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::business_profile_id.eq(profile_id));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<EventDeliveryLatency>(conn),
+            DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error computing the delivery latency of initial delivery attempts")
+    }
+
+    /// Number of the delivery attempts and the time of the latest attempt of each of the given
+    /// initial delivery attempts of the merchant, aggregated in a single query
+    pub async fn summarize_attempts_by_merchant_id_initial_attempt_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> StorageResult<Vec<EventAttemptsSummary>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, dsl::max, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let query = Self::table()
+            .group_by(dsl::initial_attempt_id)
+            .select((
+                dsl::initial_attempt_id,
+                diesel::dsl::count_star(),
+                max(dsl::created_at),
+            ))
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::initial_attempt_id.eq_any(initial_attempt_ids)),
+            )
+            .into_boxed();
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        let summaries = track_database_call::<Self, _, _>(
+            query.get_results_async::<(Option<String>, i64, Option<time::PrimitiveDateTime>)>(conn),
+            DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error summarizing delivery attempts by initial attempt ID")?;
+
+        // The initial attempt IDs are filtered on, and each group has at least one attempt, so
+        // neither of these is null
+        Ok(summaries
+            .into_iter()
+            .filter_map(|(initial_attempt_id, attempt_count, last_attempted_at)| {
+                Some(EventAttemptsSummary {
+                    initial_attempt_id: initial_attempt_id?,
+                    attempt_count,
+                    last_attempted_at: last_attempted_at?,
+                })
+            })
+            .collect())
+    }
+
+    /// Deletes the given initial delivery attempts of the merchant along with all of their retry
+    /// attempts
+    pub async fn delete_by_merchant_id_initial_attempt_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::initial_attempt_id.eq_any(initial_attempt_ids)),
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn apply_filters<T>(
         mut query: T,
         profile_id: Option<common_utils::id_type::ProfileId>,
@@ -222,6 +592,10 @@ impl Event {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage_enums::EventDeliveryState>,
+        event_types: Option<Vec<storage_enums::EventType>>,
+        event_class: Option<storage_enums::EventClass>,
+        delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
     ) -> T
     where
         T: diesel::query_dsl::methods::LimitDsl<Output = T>
@@ -242,6 +616,35 @@ impl Event {
             diesel::dsl::Eq<dsl::is_overall_delivery_successful, bool>,
             Output = T,
         >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::EqAny<dsl::event_type, Vec<storage_enums::EventType>>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<dsl::event_class, storage_enums::EventClass>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<
+                dsl::latest_delivery_status_code_class,
+                storage_enums::WebhookDeliveryStatusCodeClass,
+            >,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::And<
+                diesel::dsl::IsNull<dsl::response>,
+                diesel::dsl::IsNull<dsl::latest_delivery_status_code_class>,
+            >,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Or<
+                diesel::dsl::IsNotNull<dsl::response>,
+                diesel::dsl::IsNotNull<dsl::latest_delivery_status_code_class>,
+            >,
+            Output = T,
+        >,
     {
         if let Some(profile_id) = profile_id {
             query = query.filter(dsl::business_profile_id.eq(profile_id));
@@ -259,13 +662,108 @@ impl Event {
             query = query.offset(offset);
         }
 
+        Self::apply_event_filters(
+            query,
+            is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+        )
+    }
+
+    fn apply_event_filters<T>(
+        mut query: T,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage_enums::EventDeliveryState>,
+        event_types: Option<Vec<storage_enums::EventType>>,
+        event_class: Option<storage_enums::EventClass>,
+        delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
+    ) -> T
+    where
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<dsl::is_overall_delivery_successful, bool>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::EqAny<dsl::event_type, Vec<storage_enums::EventType>>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<dsl::event_class, storage_enums::EventClass>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<
+                dsl::latest_delivery_status_code_class,
+                storage_enums::WebhookDeliveryStatusCodeClass,
+            >,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::And<
+                diesel::dsl::IsNull<dsl::response>,
+                diesel::dsl::IsNull<dsl::latest_delivery_status_code_class>,
+            >,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Or<
+                diesel::dsl::IsNotNull<dsl::response>,
+                diesel::dsl::IsNotNull<dsl::latest_delivery_status_code_class>,
+            >,
+            Output = T,
+        >,
+    {
         if let Some(is_delivered) = is_delivered {
             query = query.filter(dsl::is_overall_delivery_successful.eq(is_delivered));
         }
 
+        // The initial attempt of an event records its response once it is sent, and every retry
+        // records the class of its outcome on the initial attempt. An event with neither has not
+        // been sent yet, which is found without looking up the other attempts of the event.
+        match delivery_state {
+            Some(storage_enums::EventDeliveryState::Delivered) => {
+                query = query.filter(dsl::is_overall_delivery_successful.eq(true));
+            }
+            Some(storage_enums::EventDeliveryState::Failed) => {
+                query = query
+                    .filter(dsl::is_overall_delivery_successful.eq(false))
+                    .filter(
+                        dsl::response
+                            .is_not_null()
+                            .or(dsl::latest_delivery_status_code_class.is_not_null()),
+                    );
+            }
+            Some(storage_enums::EventDeliveryState::PendingFirstAttempt) => {
+                query = query
+                    .filter(dsl::is_overall_delivery_successful.eq(false))
+                    .filter(
+                        dsl::response
+                            .is_null()
+                            .and(dsl::latest_delivery_status_code_class.is_null()),
+                    );
+            }
+            None => {}
+        }
+
+        if let Some(event_types) = event_types {
+            query = query.filter(dsl::event_type.eq_any(event_types));
+        }
+
+        if let Some(event_class) = event_class {
+            query = query.filter(dsl::event_class.eq(event_class));
+        }
+
+        if let Some(delivery_status_code_class) = delivery_status_code_class {
+            query =
+                query.filter(dsl::latest_delivery_status_code_class.eq(delivery_status_code_class));
+        }
+
         query
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn count_initial_attempts_by_constraints(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -273,6 +771,10 @@ impl Event {
         created_after: time::PrimitiveDateTime,
         created_before: time::PrimitiveDateTime,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage_enums::EventDeliveryState>,
+        event_types: Option<Vec<storage_enums::EventType>>,
+        event_class: Option<storage_enums::EventClass>,
+        delivery_status_code_class: Option<storage_enums::WebhookDeliveryStatusCodeClass>,
     ) -> StorageResult<i64> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -299,6 +801,10 @@ impl Event {
             None,
             None,
             is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
         );
 
         logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
@@ -257,6 +257,29 @@ impl PaymentAttempt {
         .await
     }
 
+    #[cfg(feature = "v2")]
+    pub async fn find_by_profile_id_time_range(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> StorageResult<Vec<Self>> {
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::created_at.ge(time_range.start_time))
+                .and(dsl::created_at.le(end_time)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn find_by_merchant_id_preprocessing_id(
         conn: &PgPooledConn,
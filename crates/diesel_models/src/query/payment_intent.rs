@@ -24,10 +24,17 @@ impl PaymentIntent {
         conn: &PgPooledConn,
         payment_intent_update: payment_intent::PaymentIntentUpdateInternal,
     ) -> StorageResult<Self> {
-        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+        // The version is incremented by the database, so that concurrent updates of the status
+        // always result in distinct versions
+        let entity_version_increment = i64::from(payment_intent_update.status.is_some());
+
+        match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
-            self.id.to_owned(),
-            payment_intent_update,
+            dsl::id.eq(self.id.to_owned()),
+            (
+                payment_intent_update,
+                dsl::entity_version.eq(dsl::entity_version + entity_version_increment),
+            ),
         )
         .await
         {
@@ -35,7 +42,9 @@ impl PaymentIntent {
                 errors::DatabaseError::NoFieldsToUpdate => Ok(self),
                 _ => Err(error),
             },
-            Ok(payment_intent) => Ok(payment_intent),
+            Ok(mut payment_intents) => payment_intents
+                .pop()
+                .ok_or(error_stack::report!(errors::DatabaseError::NotFound)),
         }
     }
 
@@ -53,12 +62,21 @@ impl PaymentIntent {
         conn: &PgPooledConn,
         payment_intent: payment_intent::PaymentIntentUpdate,
     ) -> StorageResult<Self> {
+        let payment_intent_update =
+            payment_intent::PaymentIntentUpdateInternal::from(payment_intent);
+        // The version is incremented by the database, so that concurrent updates of the status
+        // always result in distinct versions
+        let entity_version_increment = i64::from(payment_intent_update.status.is_some());
+
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::payment_id
                 .eq(self.payment_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            payment_intent::PaymentIntentUpdateInternal::from(payment_intent),
+            (
+                payment_intent_update,
+                dsl::entity_version.eq(dsl::entity_version + entity_version_increment),
+            ),
         )
         .await
         {
@@ -104,6 +122,108 @@ impl PaymentIntent {
         .await
     }
 
+    /// Finds the revenue recovery payment intents of the profile that belong to the subscription at
+    /// the billing connector, ordered by their creation time.
+    #[cfg(feature = "v2")]
+    pub async fn find_by_billing_connector_subscription_id_profile_id(
+        conn: &PgPooledConn,
+        billing_connector_subscription_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()).and(
+                diesel::dsl::sql::<diesel::sql_types::Bool>(
+                    "feature_metadata ->> 'billing_connector_subscription_id' = ",
+                )
+                .bind::<diesel::sql_types::Text, _>(billing_connector_subscription_id.to_owned()),
+            ),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Finds the open revenue recovery payment intents of the profile that are recovered for the
+    /// billing connector account, ordered by their ids. The intents are paged using the id of the
+    /// last intent of the previous page, so that intents closed in the meanwhile do not shift the
+    /// pages.
+    #[cfg(feature = "v2")]
+    pub async fn find_open_recovery_intents_by_billing_connector_id_profile_id(
+        conn: &PgPooledConn,
+        billing_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        profile_id: &common_utils::id_type::ProfileId,
+        starting_after: Option<&common_utils::id_type::GlobalPaymentId>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let filter = dsl::profile_id
+            .eq(profile_id.to_owned())
+            .and(dsl::status.ne_all(vec![
+                common_enums::IntentStatus::Succeeded,
+                common_enums::IntentStatus::Cancelled,
+                common_enums::IntentStatus::PartiallyCaptured,
+            ]))
+            .and(
+                diesel::dsl::sql::<diesel::sql_types::Bool>(
+                    "feature_metadata -> 'payment_revenue_recovery_metadata' ->> 'billing_connector_id' = ",
+                )
+                .bind::<diesel::sql_types::Text, _>(
+                    billing_connector_id.get_string_repr().to_owned(),
+                ),
+            );
+
+        match starting_after {
+            Some(starting_after) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    filter.and(dsl::id.gt(starting_after.to_owned())),
+                    Some(limit),
+                    None,
+                    Some(dsl::id.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    filter,
+                    Some(limit),
+                    None,
+                    Some(dsl::id.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Finds the revenue recovery payment intents of the profile that belong to the customer at
+    /// the billing connector, ordered by their creation time.
+    #[cfg(feature = "v2")]
+    pub async fn find_by_billing_connector_customer_id_profile_id(
+        conn: &PgPooledConn,
+        billing_connector_customer_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()).and(
+                diesel::dsl::sql::<diesel::sql_types::Bool>(
+                    "feature_metadata -> 'payment_revenue_recovery_metadata' -> 'billing_connector_payment_details' ->> 'connector_customer_id' = ",
+                )
+                .bind::<diesel::sql_types::Text, _>(billing_connector_customer_id.to_owned()),
+            ),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn find_by_payment_id_merchant_id(
         conn: &PgPooledConn,
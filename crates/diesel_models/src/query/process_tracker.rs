@@ -1,12 +1,18 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    PgArrayExpressionMethods, QueryDsl, Table,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
     enums, errors,
     process_tracker::{
-        ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
+        ProcessTracker, ProcessTrackerMerchantTaskConstraints, ProcessTrackerMerchantTaskCounts,
+        ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
     },
     schema::process_tracker::dsl,
     PgPooledConn, StorageResult,
@@ -91,6 +97,275 @@ impl ProcessTracker {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_pending_processes_by_tag_runner(
+        conn: &PgPooledConn,
+        tags: Vec<String>,
+        runner: &str,
+        time_lower_limit: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::tag
+                .contains(tags)
+                .and(dsl::runner.eq(runner.to_owned()))
+                .and(dsl::schedule_time.ge(time_lower_limit))
+                .and(dsl::status.eq_any(vec![
+                    enums::ProcessTrackerStatus::New,
+                    enums::ProcessTrackerStatus::Pending,
+                    enums::ProcessTrackerStatus::ProcessStarted,
+                    enums::ProcessTrackerStatus::Processing,
+                ])),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Lists the tasks of the merchant in the order of their schedule time, a page at a time
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_task_constraints(
+        conn: &PgPooledConn,
+        constraints: ProcessTrackerMerchantTaskConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(
+                dsl::tag
+                    .contains(constraints.tags)
+                    .and(dsl::runner.eq(constraints.runner.to_string()))
+                    .and(dsl::name.eq_any(constraints.names))
+                    .and(
+                        diesel::dsl::sql::<diesel::sql_types::Bool>(
+                            "tracking_data ->> 'merchant_id' = ",
+                        )
+                        .bind::<diesel::sql_types::Text, _>(
+                            constraints.merchant_id.get_string_repr().to_owned(),
+                        ),
+                    ),
+            )
+            .order((dsl::schedule_time.asc(), dsl::id.asc()))
+            .limit(constraints.limit)
+            .into_boxed();
+
+        if let Some(profile_id) = constraints.profile_id {
+            query = query.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>("tracking_data ->> 'profile_id' = ")
+                    .bind::<diesel::sql_types::Text, _>(profile_id.get_string_repr().to_owned()),
+            );
+        }
+
+        if let Some(status) = constraints.status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(business_status) = constraints.business_status {
+            query = query.filter(dsl::business_status.eq(business_status));
+        }
+
+        if let Some(schedule_time_start) = constraints.schedule_time_start {
+            query = query.filter(dsl::schedule_time.ge(schedule_time_start));
+        }
+
+        if let Some(schedule_time_end) = constraints.schedule_time_end {
+            query = query.filter(dsl::schedule_time.le(schedule_time_end));
+        }
+
+        if let Some((schedule_time, id)) = constraints.starting_after {
+            query = query.filter(
+                dsl::schedule_time
+                    .gt(schedule_time)
+                    .or(dsl::schedule_time.eq(schedule_time).and(dsl::id.gt(id))),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to list the tasks of the merchant")
+    }
+
+    /// Counts the tasks of the runner which are yet to be picked up although they were scheduled
+    /// before the given time, for all the merchants or for the given merchant
+    #[instrument(skip(conn))]
+    pub async fn count_overdue_by_runner(
+        conn: &PgPooledConn,
+        runner: &str,
+        scheduled_before: PrimitiveDateTime,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+    ) -> StorageResult<i64> {
+        let mut query = <Self as HasTable>::table()
+            .count()
+            .filter(
+                dsl::runner
+                    .eq(runner.to_owned())
+                    .and(dsl::schedule_time.lt(scheduled_before))
+                    .and(dsl::status.eq_any(vec![
+                        enums::ProcessTrackerStatus::New,
+                        enums::ProcessTrackerStatus::Pending,
+                    ])),
+            )
+            .into_boxed();
+
+        if let Some(merchant_id) = merchant_id {
+            query = query.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>("tracking_data ->> 'merchant_id' = ")
+                    .bind::<diesel::sql_types::Text, _>(merchant_id.get_string_repr().to_owned()),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to count the overdue tasks of the runner")
+    }
+
+    /// Counts the tasks of the runner which are yet to be finished, of the merchant or of the
+    /// given profile of the merchant, in a single scan of the unfinished tasks of the merchant
+    #[instrument(skip(conn))]
+    pub async fn count_unfinished_by_merchant_runner(
+        conn: &PgPooledConn,
+        runner: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        scheduled_before: PrimitiveDateTime,
+    ) -> StorageResult<ProcessTrackerMerchantTaskCounts> {
+        use diesel::{
+            dsl::sql,
+            sql_types::{BigInt, Bool, Text, Timestamp},
+        };
+
+        let mut query = <Self as HasTable>::table()
+            .select((
+                sql::<BigInt>("COUNT(DISTINCT tracking_data ->> 'global_payment_id')"),
+                sql::<BigInt>("COUNT(*) FILTER (WHERE status IN ('new', 'pending'))"),
+                sql::<BigInt>(
+                    "COUNT(*) FILTER (WHERE status IN ('new', 'pending') AND schedule_time < ",
+                )
+                .bind::<Timestamp, _>(scheduled_before)
+                .sql(")"),
+                sql::<BigInt>("COUNT(*) FILTER (WHERE status = 'review')"),
+            ))
+            .filter(
+                dsl::runner
+                    .eq(runner.to_owned())
+                    .and(dsl::status.ne(enums::ProcessTrackerStatus::Finish))
+                    .and(
+                        sql::<Bool>("tracking_data ->> 'merchant_id' = ")
+                            .bind::<Text, _>(merchant_id.get_string_repr().to_owned()),
+                    ),
+            )
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(
+                sql::<Bool>("tracking_data ->> 'profile_id' = ")
+                    .bind::<Text, _>(profile_id.get_string_repr().to_owned()),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<ProcessTrackerMerchantTaskCounts>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to count the unfinished tasks of the merchant")
+    }
+
+    /// Counts the tasks of the runner which were finished with the business status at or after the
+    /// given time, of the merchant or of the given profile of the merchant
+    #[instrument(skip(conn))]
+    pub async fn count_finished_by_merchant_business_status(
+        conn: &PgPooledConn,
+        runner: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        business_status: &str,
+        finished_after: PrimitiveDateTime,
+    ) -> StorageResult<i64> {
+        let mut query = <Self as HasTable>::table()
+            .count()
+            .filter(
+                dsl::runner
+                    .eq(runner.to_owned())
+                    .and(dsl::status.eq(enums::ProcessTrackerStatus::Finish))
+                    .and(dsl::business_status.eq(business_status.to_owned()))
+                    .and(dsl::updated_at.ge(finished_after))
+                    .and(
+                        diesel::dsl::sql::<diesel::sql_types::Bool>(
+                            "tracking_data ->> 'merchant_id' = ",
+                        )
+                        .bind::<diesel::sql_types::Text, _>(
+                            merchant_id.get_string_repr().to_owned(),
+                        ),
+                    ),
+            )
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>("tracking_data ->> 'profile_id' = ")
+                    .bind::<diesel::sql_types::Text, _>(profile_id.get_string_repr().to_owned()),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to count the finished tasks of the merchant")
+    }
+
+    /// Counts the tasks of the runner carrying the tag, grouped by their status and business status
+    #[instrument(skip(conn))]
+    pub async fn count_by_tag_runner_status(
+        conn: &PgPooledConn,
+        tag: &str,
+        runner: &str,
+    ) -> StorageResult<Vec<(enums::ProcessTrackerStatus, String, i64)>> {
+        let query = <Self as HasTable>::table()
+            .filter(
+                dsl::tag
+                    .contains(vec![tag.to_owned()])
+                    .and(dsl::runner.eq(runner.to_owned())),
+            )
+            .group_by((dsl::status, dsl::business_status))
+            .select((dsl::status, dsl::business_status, diesel::dsl::count_star()))
+            .into_boxed();
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(enums::ProcessTrackerStatus, String, i64)>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to count the tasks by tag")
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
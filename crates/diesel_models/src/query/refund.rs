@@ -26,6 +26,9 @@ impl RefundNew {
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
 impl Refund {
     pub async fn update(self, conn: &PgPooledConn, refund: RefundUpdate) -> StorageResult<Self> {
+        let refund_update = RefundUpdateInternal::from(refund);
+        let entity_version_increment = i64::from(refund_update.is_status_update());
+
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
             _,
@@ -36,7 +39,10 @@ impl Refund {
             dsl::refund_id
                 .eq(self.refund_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            RefundUpdateInternal::from(refund),
+            (
+                refund_update,
+                dsl::entity_version.eq(dsl::entity_version + entity_version_increment),
+            ),
         )
         .await
         {
@@ -145,10 +151,21 @@ impl Refund {
         conn: &PgPooledConn,
         refund: RefundUpdate,
     ) -> StorageResult<Self> {
-        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+        let refund_update = RefundUpdateInternal::from(refund);
+        let entity_version_increment = i64::from(refund_update.is_status_update());
+
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
             conn,
-            self.id.to_owned(),
-            RefundUpdateInternal::from(refund),
+            dsl::id.eq(self.id.to_owned()),
+            (
+                refund_update,
+                dsl::entity_version.eq(dsl::entity_version + entity_version_increment),
+            ),
         )
         .await
         {
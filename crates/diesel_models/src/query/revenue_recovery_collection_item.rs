@@ -0,0 +1,120 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    revenue_recovery_collection_item::{
+        RevenueRecoveryCollectionItem, RevenueRecoveryCollectionItemNew,
+        RevenueRecoveryCollectionItemUpdate, RevenueRecoveryCollectionItemUpdateInternal,
+    },
+    schema_v2::revenue_recovery_collection_item::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RevenueRecoveryCollectionItemNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RevenueRecoveryCollectionItem> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RevenueRecoveryCollectionItem {
+    pub async fn find_by_profile_id_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: Option<storage_enums::RecoveryCollectionStatus>,
+        assignee: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::profile_id.eq(profile_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(assignee) = assignee {
+            query = query.filter(dsl::assignee.eq(assignee));
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to list revenue recovery collection items")
+    }
+
+    pub async fn count_by_profile_id_created_at(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table()
+            .count()
+            .filter(
+                dsl::profile_id
+                    .eq(profile_id.to_owned())
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.le(created_before)),
+            )
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to count revenue recovery collection items")
+    }
+
+    /// Only the items which are open can be updated, so that an item is not resolved twice
+    pub async fn update_open_item_by_id(
+        conn: &PgPooledConn,
+        id: &str,
+        update: RevenueRecoveryCollectionItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id
+                .eq(id.to_owned())
+                .and(dsl::status.eq(storage_enums::RecoveryCollectionStatus::Open)),
+            RevenueRecoveryCollectionItemUpdateInternal::from(update),
+        )
+        .await
+    }
+}
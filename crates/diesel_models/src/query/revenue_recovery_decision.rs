@@ -0,0 +1,87 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    errors, revenue_recovery_decision::RevenueRecoveryDecision,
+    schema_v2::revenue_recovery_decision::dsl, PgPooledConn, StorageResult,
+};
+
+impl RevenueRecoveryDecision {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Self> {
+        generics::generic_insert(conn, self).await
+    }
+
+    pub async fn list_by_payment_id(
+        conn: &PgPooledConn,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::payment_id.eq(payment_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn count_by_payment_id(
+        conn: &PgPooledConn,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table()
+            .count()
+            .filter(dsl::payment_id.eq(payment_id.to_owned()))
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of revenue recovery decisions")
+    }
+
+    /// Number of the decisions of the merchant taken on the attempts recorded at or after the
+    /// given time, by whom the attempts were triggered
+    pub async fn count_by_merchant_id_attempt_triggered_by(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<(Option<String>, i64)>> {
+        let mut query = <Self as HasTable>::table()
+            .group_by(dsl::attempt_triggered_by)
+            .select((dsl::attempt_triggered_by, diesel::dsl::count_star()))
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::attempt_triggered_by.is_not_null()),
+            )
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::profile_id.eq(profile_id.to_owned()));
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(Option<String>, i64)>(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to count revenue recovery decisions by attempt trigger")
+    }
+}
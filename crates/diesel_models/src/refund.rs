@@ -67,6 +67,8 @@ pub struct Refund {
     pub processor_transaction_data: Option<String>,
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
+    /// Incremented on every update of the status of the refund
+    pub entity_version: i64,
 }
 
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
@@ -115,6 +117,7 @@ pub struct Refund {
     pub unified_message: Option<String>,
     pub processor_refund_data: Option<String>,
     pub processor_transaction_data: Option<String>,
+    pub entity_version: i64,
     pub id: common_utils::id_type::GlobalRefundId,
     pub merchant_reference_id: common_utils::id_type::RefundReferenceId,
     pub connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
@@ -342,8 +345,18 @@ pub struct RefundUpdateInternal {
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
 impl RefundUpdateInternal {
+    pub fn is_status_update(&self) -> bool {
+        self.refund_status.is_some()
+    }
+
     pub fn create_refund(self, source: Refund) -> Refund {
+        let entity_version = if self.is_status_update() {
+            source.entity_version + 1
+        } else {
+            source.entity_version
+        };
         Refund {
+            entity_version,
             connector_refund_id: self.connector_refund_id,
             refund_status: self.refund_status.unwrap_or_default(),
             sent_to_gateway: self.sent_to_gateway.unwrap_or_default(),
@@ -364,8 +377,18 @@ impl RefundUpdateInternal {
 
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
 impl RefundUpdateInternal {
+    pub fn is_status_update(&self) -> bool {
+        self.refund_status.is_some()
+    }
+
     pub fn create_refund(self, source: Refund) -> Refund {
+        let entity_version = if self.is_status_update() {
+            source.entity_version + 1
+        } else {
+            source.entity_version
+        };
         Refund {
+            entity_version,
             connector_refund_id: self.connector_refund_id,
             refund_status: self.refund_status.unwrap_or_default(),
             sent_to_gateway: self.sent_to_gateway.unwrap_or_default(),
@@ -646,7 +669,13 @@ impl RefundUpdate {
             issuer_error_code,
             issuer_error_message,
         } = self.into();
+        let entity_version = if refund_status.is_some() {
+            source.entity_version + 1
+        } else {
+            source.entity_version
+        };
         Refund {
+            entity_version,
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
             refund_status: refund_status.unwrap_or(source.refund_status),
             sent_to_gateway: sent_to_gateway.unwrap_or(source.sent_to_gateway),
@@ -685,7 +714,13 @@ impl RefundUpdate {
             unified_code,
             unified_message,
         } = self.into();
+        let entity_version = if refund_status.is_some() {
+            source.entity_version + 1
+        } else {
+            source.entity_version
+        };
         Refund {
+            entity_version,
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
             refund_status: refund_status.unwrap_or(source.refund_status),
             sent_to_gateway: sent_to_gateway.unwrap_or(source.sent_to_gateway),
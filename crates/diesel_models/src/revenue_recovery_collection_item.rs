@@ -0,0 +1,123 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema_v2::revenue_recovery_collection_item};
+
+/// Invoice handed off to the manual collection queue of the merchant, once the retries of revenue
+/// recovery are exhausted for the payment.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = revenue_recovery_collection_item, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct RevenueRecoveryCollectionItem {
+    pub id: String,
+    pub payment_id: common_utils::id_type::GlobalPaymentId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub merchant_reference_id: Option<common_utils::id_type::PaymentReferenceId>,
+    pub amount_outstanding: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub last_error_code: Option<String>,
+    pub last_failure_reason: Option<String>,
+    /// Customer of the payment, whose contact details are used by the collections team
+    pub customer_id: Option<common_utils::id_type::GlobalCustomerId>,
+    pub status: storage_enums::RecoveryCollectionStatus,
+    pub assignee: Option<String>,
+    pub assigned_by: Option<String>,
+    pub outcome: Option<storage_enums::RecoveryCollectionOutcome>,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = revenue_recovery_collection_item)]
+pub struct RevenueRecoveryCollectionItemNew {
+    pub id: String,
+    pub payment_id: common_utils::id_type::GlobalPaymentId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub merchant_reference_id: Option<common_utils::id_type::PaymentReferenceId>,
+    pub amount_outstanding: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub last_error_code: Option<String>,
+    pub last_failure_reason: Option<String>,
+    pub customer_id: Option<common_utils::id_type::GlobalCustomerId>,
+    pub status: storage_enums::RecoveryCollectionStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum RevenueRecoveryCollectionItemUpdate {
+    Assign {
+        assignee: String,
+        assigned_by: String,
+    },
+    Resolve {
+        outcome: storage_enums::RecoveryCollectionOutcome,
+        resolved_by: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = revenue_recovery_collection_item)]
+pub struct RevenueRecoveryCollectionItemUpdateInternal {
+    pub status: Option<storage_enums::RecoveryCollectionStatus>,
+    pub assignee: Option<String>,
+    pub assigned_by: Option<String>,
+    pub outcome: Option<storage_enums::RecoveryCollectionOutcome>,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl RevenueRecoveryCollectionItemUpdateInternal {
+    pub fn apply_changeset(
+        self,
+        source: RevenueRecoveryCollectionItem,
+    ) -> RevenueRecoveryCollectionItem {
+        RevenueRecoveryCollectionItem {
+            status: self.status.unwrap_or(source.status),
+            assignee: self.assignee.or(source.assignee),
+            assigned_by: self.assigned_by.or(source.assigned_by),
+            outcome: self.outcome.or(source.outcome),
+            resolved_by: self.resolved_by.or(source.resolved_by),
+            resolved_at: self.resolved_at.or(source.resolved_at),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+impl From<RevenueRecoveryCollectionItemUpdate> for RevenueRecoveryCollectionItemUpdateInternal {
+    fn from(update: RevenueRecoveryCollectionItemUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match update {
+            RevenueRecoveryCollectionItemUpdate::Assign {
+                assignee,
+                assigned_by,
+            } => Self {
+                status: None,
+                assignee: Some(assignee),
+                assigned_by: Some(assigned_by),
+                outcome: None,
+                resolved_by: None,
+                resolved_at: None,
+                modified_at: now,
+            },
+            RevenueRecoveryCollectionItemUpdate::Resolve {
+                outcome,
+                resolved_by,
+            } => Self {
+                status: Some(storage_enums::RecoveryCollectionStatus::Resolved),
+                assignee: None,
+                assigned_by: None,
+                outcome: Some(outcome),
+                resolved_by: Some(resolved_by),
+                resolved_at: Some(now),
+                modified_at: now,
+            },
+        }
+    }
+}
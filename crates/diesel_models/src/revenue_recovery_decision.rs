@@ -0,0 +1,25 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema_v2::revenue_recovery_decision;
+
+/// Decision taken by revenue recovery on consuming a billing connector webhook, persisted so that
+/// the handling of an invoice can be audited later.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Insertable)]
+#[diesel(table_name = revenue_recovery_decision, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct RevenueRecoveryDecision {
+    pub id: String,
+    pub payment_id: common_utils::id_type::GlobalPaymentId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub event_type: String,
+    pub recovery_action: Option<String>,
+    pub attempt_triggered_by: Option<String>,
+    pub retry_count: Option<i16>,
+    pub retry_threshold: Option<i16>,
+    pub is_billing_connector_payment_sync_called: bool,
+    pub webhook_action: Option<String>,
+    pub task_id: Option<String>,
+    /// Variant of the error with which the processing of the webhook failed
+    pub error: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
@@ -232,6 +232,11 @@ diesel::table! {
         default_fallback_routing -> Nullable<Jsonb>,
         three_ds_decision_manager_config -> Nullable<Jsonb>,
         should_collect_cvv_during_payment -> Nullable<Bool>,
+        #[max_length = 64]
+        default_recovery_payment_connector_id -> Nullable<Varchar>,
+        revenue_recovery_connector_fallback -> Nullable<Jsonb>,
+        revenue_recovery_retry_notification -> Nullable<Jsonb>,
+        revenue_recovery_blackout_calendar -> Nullable<Jsonb>,
     }
 }
 
@@ -422,6 +427,7 @@ diesel::table! {
         #[max_length = 32]
         organization_id -> Varchar,
         dispute_currency -> Nullable<Currency>,
+        entity_version -> Int8,
     }
 }
 
@@ -486,6 +492,31 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        entity_version -> Nullable<Int8>,
+        payload_scrubbed_at -> Nullable<Timestamp>,
+        delivery_latency_ms -> Nullable<Int8>,
+        #[max_length = 32]
+        latest_delivery_status_code_class -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    events_archive (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        object_key -> Varchar,
+        #[max_length = 64]
+        checksum -> Varchar,
+        events_count -> Int4,
+        oldest_event_created_at -> Timestamp,
+        newest_event_created_at -> Timestamp,
+        created_at -> Timestamp,
     }
 }
 
@@ -955,6 +986,7 @@ diesel::table! {
         platform_merchant_id -> Nullable<Varchar>,
         force_3ds_challenge -> Nullable<Bool>,
         force_3ds_challenge_trigger -> Nullable<Bool>,
+        entity_version -> Int8,
         #[max_length = 64]
         merchant_reference_id -> Nullable<Varchar>,
         billing_address -> Nullable<Bytea>,
@@ -1217,6 +1249,7 @@ diesel::table! {
         unified_message -> Nullable<Varchar>,
         processor_refund_data -> Nullable<Text>,
         processor_transaction_data -> Nullable<Text>,
+        entity_version -> Int8,
         #[max_length = 64]
         id -> Varchar,
         #[max_length = 64]
@@ -1255,6 +1288,76 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    revenue_recovery_collection_item (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        merchant_reference_id -> Nullable<Varchar>,
+        amount_outstanding -> Int8,
+        currency -> Currency,
+        #[max_length = 255]
+        last_error_code -> Nullable<Varchar>,
+        last_failure_reason -> Nullable<Text>,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        assignee -> Nullable<Varchar>,
+        #[max_length = 64]
+        assigned_by -> Nullable<Varchar>,
+        #[max_length = 32]
+        outcome -> Nullable<Varchar>,
+        #[max_length = 64]
+        resolved_by -> Nullable<Varchar>,
+        resolved_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    revenue_recovery_decision (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        event_type -> Varchar,
+        #[max_length = 64]
+        recovery_action -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_triggered_by -> Nullable<Varchar>,
+        retry_count -> Nullable<Int2>,
+        retry_threshold -> Nullable<Int2>,
+        is_billing_connector_payment_sync_called -> Bool,
+        #[max_length = 64]
+        webhook_action -> Nullable<Varchar>,
+        #[max_length = 255]
+        task_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        error -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1487,6 +1590,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     dispute,
     dynamic_routing_stats,
     events,
+    events_archive,
     file_metadata,
     fraud_check,
     gateway_status_map,
@@ -1507,6 +1611,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     process_tracker,
     refund,
     relay,
+    revenue_recovery_collection_item,
+    revenue_recovery_decision,
     reverse_lookup,
     roles,
     routing_algorithm,
@@ -56,6 +56,8 @@ pub struct FeatureMetadata {
     pub apple_pay_recurring_details: Option<ApplePayRecurringDetails>,
     /// revenue recovery data for payment intent
     pub payment_revenue_recovery_metadata: Option<PaymentRevenueRecoveryMetadata>,
+    /// Subscription id at the billing connector, to which the invoice of the revenue recovery payment belongs
+    pub billing_connector_subscription_id: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -171,6 +173,62 @@ pub struct PaymentRevenueRecoveryMetadata {
     pub payment_method_subtype: common_enums::enums::PaymentMethodType,
     /// The name of the payment connector through which the payment attempt was made.
     pub connector: common_enums::connector_enums::Connector,
+    /// Override of the retry threshold and schedule, applied for this payment alone
+    pub retry_override: Option<RevenueRecoveryRetryOverride>,
+    /// Hold on the retries of the payment, while a dispute raised by the customer is open
+    pub dispute_hold: Option<RevenueRecoveryDisputeHold>,
+    /// Identifier of the billing connector webhook which last recorded an attempt for the payment
+    pub recovery_correlation_id: Option<String>,
+    /// Manual cancellation of the scheduled retry of the payment, if any
+    pub manual_cancellation: Option<RevenueRecoveryManualCancellation>,
+    /// Rotation of the payment connectors of the retries, when the connector fallback of the
+    /// profile is configured
+    pub connector_rotation: Option<common_types::payments::RevenueRecoveryConnectorRotation>,
+    /// Status of the record back of the outcome of the retries to the billing connector
+    #[serde(default)]
+    pub record_back_status: Option<common_enums::RecoveryRecordBackStatus>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RevenueRecoveryRetryOverride {
+    /// Number of retries allowed for the payment after the override was applied
+    pub additional_retries: u16,
+    /// Times at which the upcoming retries are to be made, in ascending order
+    #[serde(default, with = "common_utils::custom_serde::iso8601::vec")]
+    pub schedule: Vec<time::PrimitiveDateTime>,
+    /// Retry count of the payment at the time the override was applied
+    pub retry_count_at_override: u16,
+    /// The reason for which the override was applied
+    pub reason: String,
+    /// The user or the api key which applied the override
+    pub created_by: String,
+    /// The time at which the override was applied
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RevenueRecoveryDisputeHold {
+    /// The time at which the dispute was opened by the customer
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub held_at: time::PrimitiveDateTime,
+    /// The time after which the retries are resumed, even if the dispute has not been resolved
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RevenueRecoveryManualCancellation {
+    /// The process tracker task which was cancelled
+    pub task_id: String,
+    /// The user or the api key which cancelled the task
+    pub cancelled_by: String,
+    /// The time at which the task was cancelled
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub cancelled_at: time::PrimitiveDateTime,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -180,4 +238,7 @@ pub struct BillingConnectorPaymentDetails {
     pub payment_processor_token: String,
     /// Billing Connector's Customer Id
     pub connector_customer_id: String,
+    /// Details of the card of the payment processor token
+    #[serde(default)]
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
 }
@@ -6,6 +6,9 @@ use std::{
 };
 
 use common_utils::errors::CustomResult;
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
 
 /// Includes functionality for AWS S3 storage operations.
 #[cfg(feature = "aws_s3")]
@@ -39,6 +42,22 @@ impl FileStorageConfig {
         }
     }
 
+    /// Decrypts the credentials of the file storage configuration using the secret management
+    /// client.
+    #[cfg_attr(not(feature = "aws_s3"), allow(unused_variables))]
+    pub async fn decrypt_credentials(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        match self {
+            #[cfg(feature = "aws_s3")]
+            Self::AwsS3 { aws_s3 } => Ok(Self::AwsS3 {
+                aws_s3: aws_s3.decrypt_credentials(secret_management_client).await?,
+            }),
+            Self::FileSystem => Ok(Self::FileSystem),
+        }
+    }
+
     /// Retrieves the appropriate file storage client based on the file storage configuration.
     pub async fn get_file_storage_client(&self) -> Arc<dyn FileStorageInterface> {
         match self {
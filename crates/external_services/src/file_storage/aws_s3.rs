@@ -5,9 +5,13 @@ use aws_sdk_s3::{
     },
     Client,
 };
-use aws_sdk_sts::config::Region;
+use aws_sdk_sts::config::{Credentials, Region};
 use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
 use error_stack::ResultExt;
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
+use masking::{PeekInterface, Secret};
 
 use super::InvalidFileStorageConfig;
 use crate::file_storage::{FileStorageError, FileStorageInterface};
@@ -20,6 +24,14 @@ pub struct AwsFileStorageConfig {
     region: String,
     /// The AWS s3 bucket to send file uploads
     bucket_name: String,
+    /// The endpoint of an S3 compatible storage, the AWS S3 endpoint of the region is used when
+    /// not set
+    endpoint_url: Option<String>,
+    /// The access key ID of the static credentials, the default credentials provider chain is
+    /// used when not set
+    access_key_id: Option<Secret<String>>,
+    /// The secret access key of the static credentials
+    secret_access_key: Option<Secret<String>>,
 }
 
 impl AwsFileStorageConfig {
@@ -35,6 +47,40 @@ impl AwsFileStorageConfig {
             Err(InvalidFileStorageConfig(
                 "aws s3 bucket name must not be empty",
             ))
+        })?;
+
+        when(
+            self.access_key_id.is_some() != self.secret_access_key.is_some(),
+            || {
+                Err(InvalidFileStorageConfig(
+                    "aws s3 access key ID and secret access key must be set together",
+                ))
+            },
+        )
+    }
+
+    /// Decrypts the static credentials using the secret management client.
+    pub(super) async fn decrypt_credentials(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let access_key_id = match self.access_key_id {
+            Some(access_key_id) => Some(secret_management_client.get_secret(access_key_id).await?),
+            None => None,
+        };
+        let secret_access_key = match self.secret_access_key {
+            Some(secret_access_key) => Some(
+                secret_management_client
+                    .get_secret(secret_access_key)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            access_key_id,
+            secret_access_key,
+            ..self
         })
     }
 }
@@ -52,9 +98,31 @@ impl AwsFileStorageClient {
     /// Creates a new AWS S3 file storage client.
     pub(super) async fn new(config: &AwsFileStorageConfig) -> Self {
         let region_provider = RegionProviderChain::first_try(Region::new(config.region.clone()));
-        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+        let mut config_loader = aws_config::from_env().region(region_provider);
+        if let Some((access_key_id, secret_access_key)) = config
+            .access_key_id
+            .as_ref()
+            .zip(config.secret_access_key.as_ref())
+        {
+            config_loader = config_loader.credentials_provider(Credentials::new(
+                access_key_id.peek(),
+                secret_access_key.peek(),
+                None,
+                None,
+                "file_storage_config",
+            ));
+        }
+        let sdk_config = config_loader.load().await;
+
+        let mut s3_config = aws_sdk_s3::config::Builder::from(&sdk_config);
+        if let Some(endpoint_url) = &config.endpoint_url {
+            // S3 compatible storages usually address the buckets by the path rather than by the
+            // host
+            s3_config = s3_config.endpoint_url(endpoint_url).force_path_style(true);
+        }
+
         Self {
-            inner_client: Client::new(&sdk_config),
+            inner_client: Client::from_conf(s3_config.build()),
             bucket_name: config.bucket_name.clone(),
         }
     }
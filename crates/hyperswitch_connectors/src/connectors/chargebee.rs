@@ -13,10 +13,15 @@ use common_utils::{
 use error_stack::{report, ResultExt};
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 use hyperswitch_domain_models::{
-    revenue_recovery, router_flow_types::revenue_recovery::RecoveryRecordBack,
-    router_request_types::revenue_recovery::RevenueRecoveryRecordBackRequest,
-    router_response_types::revenue_recovery::RevenueRecoveryRecordBackResponse,
-    types::RevenueRecoveryRecordBackRouterData,
+    revenue_recovery,
+    router_flow_types::revenue_recovery::{self as recovery_router_flows, RecoveryRecordBack},
+    router_request_types::revenue_recovery::{
+        self as recovery_request_types, RevenueRecoveryRecordBackRequest,
+    },
+    router_response_types::revenue_recovery::{
+        self as recovery_response_types, RevenueRecoveryRecordBackResponse,
+    },
+    types::{self as recovery_router_data_types, RevenueRecoveryRecordBackRouterData},
 };
 use hyperswitch_domain_models::{
     router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
@@ -81,6 +86,8 @@ impl api::RefundSync for Chargebee {}
 impl api::PaymentToken for Chargebee {}
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 impl api::revenue_recovery::RevenueRecoveryRecordBack for Chargebee {}
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl api::revenue_recovery::BillingConnectorPaymentsSyncIntegration for Chargebee {}
 
 impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
     for Chargebee
@@ -142,25 +149,36 @@ impl ConnectorCommon for Chargebee {
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        let response: chargebee::ChargebeeErrorResponse = res
-            .response
-            .parse_struct("ChargebeeErrorResponse")
-            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-
-        event_builder.map(|i| i.set_response_body(&response));
-        router_env::logger::info!(connector_response=?response);
-
-        Ok(ErrorResponse {
-            status_code: res.status_code,
-            code: response.api_error_code.clone(),
-            message: response.api_error_code.clone(),
-            reason: Some(response.message),
-            attempt_status: None,
-            connector_transaction_id: None,
-            network_advice_code: None,
-            network_decline_code: None,
-            network_error_message: None,
-        })
+        let response: Result<
+            chargebee::ChargebeeErrorResponse,
+            error_stack::Report<common_utils::errors::ParsingError>,
+        > = res.response.parse_struct("ChargebeeErrorResponse");
+
+        match response {
+            Ok(response) => {
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                Ok(ErrorResponse {
+                    status_code: res.status_code,
+                    code: response.api_error_code.clone(),
+                    message: response.api_error_code.clone(),
+                    reason: Some(response.message),
+                    attempt_status: None,
+                    connector_transaction_id: None,
+                    network_advice_code: None,
+                    network_decline_code: None,
+                    network_error_message: None,
+                })
+            }
+            // The errors which are not sent in the error envelope of chargebee, ex: the errors of
+            // the load balancer in front of the site
+            Err(error_msg) => {
+                event_builder.map(|event| event.set_error(serde_json::json!({"error": res.response.escape_ascii().to_string(), "status_code": res.status_code})));
+                router_env::logger::error!(deserialization_error =? error_msg);
+                utils::handle_json_response_deserialization_failure(res, "chargebee")
+            }
+        }
     }
 }
 
@@ -664,6 +682,121 @@ impl
     }
 }
 
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    ConnectorIntegration<
+        recovery_router_flows::BillingConnectorPaymentsSync,
+        recovery_request_types::BillingConnectorPaymentsSyncRequest,
+        recovery_response_types::BillingConnectorPaymentsSyncResponse,
+    > for Chargebee
+{
+    fn get_headers(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let metadata: chargebee::ChargebeeMetadata =
+            utils::to_connector_meta_from_secret(req.connector_meta_data.clone())?;
+        let url = self
+            .base_url(connectors)
+            .to_string()
+            .replace("$", metadata.site.peek());
+        match &req.request.billing_connector_psync_id {
+            recovery_request_types::BillingConnectorPaymentsSyncId::TransactionId(
+                transaction_id,
+            ) => Ok(format!("{url}v2/transactions/{transaction_id}")),
+            recovery_request_types::BillingConnectorPaymentsSyncId::InvoiceId(invoice_id) => {
+                Ok(format!("{url}v2/invoices/{invoice_id}/payments"))
+            }
+        }
+    }
+
+    fn build_request(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let request = RequestBuilder::new()
+            .method(Method::Get)
+            .url(&types::BillingConnectorPaymentsSyncType::get_url(
+                self, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(types::BillingConnectorPaymentsSyncType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<
+        recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        errors::ConnectorError,
+    > {
+        match data.request.billing_connector_psync_id {
+            recovery_request_types::BillingConnectorPaymentsSyncId::TransactionId(_) => {
+                let response: chargebee::ChargebeeTransactionResponse = res
+                    .response
+                    .parse_struct("chargebee ChargebeeTransactionResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                recovery_router_data_types::BillingConnectorPaymentsSyncRouterData::try_from(
+                    ResponseRouterData {
+                        response,
+                        data: data.clone(),
+                        http_code: res.status_code,
+                    },
+                )
+            }
+            recovery_request_types::BillingConnectorPaymentsSyncId::InvoiceId(_) => {
+                let response: chargebee::ChargebeeInvoicePaymentsResponse = res
+                    .response
+                    .parse_struct("chargebee ChargebeeInvoicePaymentsResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                recovery_router_data_types::BillingConnectorPaymentsSyncRouterData::try_from(
+                    ResponseRouterData {
+                        response,
+                        data: data.clone(),
+                        http_code: res.status_code,
+                    },
+                )
+            }
+        }
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 #[async_trait::async_trait]
 impl webhooks::IncomingWebhook for Chargebee {
     fn get_webhook_source_verification_signature(
@@ -722,11 +855,42 @@ impl webhooks::IncomingWebhook for Chargebee {
         &self,
         request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        let webhook =
-            chargebee::ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(request.body)
+        let event = chargebee::ChargebeeEventBody::get_event_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        let invoice_id_type = match event.event_type {
+            chargebee::ChargebeeEventType::SubscriptionCancelled => {
+                let webhook =
+                    chargebee::ChargebeeSubscriptionBody::get_subscription_webhook_data_from_body(
+                        request.body,
+                    )
+                    .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+                api_models::webhooks::InvoiceIdType::ConnectorSubscriptionId(
+                    webhook.content.subscription.id,
+                )
+            }
+            chargebee::ChargebeeEventType::PaymentDisputeCreated
+            | chargebee::ChargebeeEventType::PaymentDisputeWon
+            | chargebee::ChargebeeEventType::PaymentDisputeLost => {
+                let webhook = chargebee::ChargebeeDisputeBody::get_dispute_webhook_data_from_body(
+                    request.body,
+                )
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+                api_models::webhooks::InvoiceIdType::ConnectorCustomerId(
+                    webhook.content.customer.id,
+                )
+            }
+            chargebee::ChargebeeEventType::PaymentSucceeded
+            | chargebee::ChargebeeEventType::PaymentFailed
+            | chargebee::ChargebeeEventType::InvoiceDeleted => {
+                let webhook = chargebee::ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(
+                    request.body,
+                )
                 .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+                api_models::webhooks::InvoiceIdType::ConnectorInvoiceId(webhook.content.invoice.id)
+            }
+        };
         Ok(api_models::webhooks::ObjectReferenceId::InvoiceId(
-            api_models::webhooks::InvoiceIdType::ConnectorInvoiceId(webhook.content.invoice.id),
+            invoice_id_type,
         ))
     }
     #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
@@ -741,9 +905,8 @@ impl webhooks::IncomingWebhook for Chargebee {
         &self,
         request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
-        let webhook =
-            chargebee::ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(request.body)
-                .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        let webhook = chargebee::ChargebeeEventBody::get_event_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
         let event = api_models::webhooks::IncomingWebhookEvent::from(webhook.event_type);
         Ok(event)
     }
@@ -759,10 +922,36 @@ impl webhooks::IncomingWebhook for Chargebee {
         &self,
         request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        let webhook =
-            chargebee::ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(request.body)
+        let event = chargebee::ChargebeeEventBody::get_event_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        match event.event_type {
+            chargebee::ChargebeeEventType::SubscriptionCancelled => {
+                let webhook =
+                    chargebee::ChargebeeSubscriptionBody::get_subscription_webhook_data_from_body(
+                        request.body,
+                    )
+                    .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+                Ok(Box::new(webhook))
+            }
+            chargebee::ChargebeeEventType::PaymentDisputeCreated
+            | chargebee::ChargebeeEventType::PaymentDisputeWon
+            | chargebee::ChargebeeEventType::PaymentDisputeLost => {
+                let webhook = chargebee::ChargebeeDisputeBody::get_dispute_webhook_data_from_body(
+                    request.body,
+                )
                 .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
-        Ok(Box::new(webhook))
+                Ok(Box::new(webhook))
+            }
+            chargebee::ChargebeeEventType::PaymentSucceeded
+            | chargebee::ChargebeeEventType::PaymentFailed
+            | chargebee::ChargebeeEventType::InvoiceDeleted => {
+                let webhook = chargebee::ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(
+                    request.body,
+                )
+                .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+                Ok(Box::new(webhook))
+            }
+        }
     }
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     fn get_revenue_recovery_attempt_details(
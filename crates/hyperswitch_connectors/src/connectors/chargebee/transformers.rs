@@ -4,8 +4,6 @@ use std::str::FromStr;
 use common_enums::enums;
 use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt, pii, types::MinorUnit};
 use error_stack::ResultExt;
-#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
-use hyperswitch_domain_models::revenue_recovery;
 use hyperswitch_domain_models::{
     payment_method_data::PaymentMethodData,
     router_data::{ConnectorAuthType, RouterData},
@@ -20,6 +18,13 @@ use hyperswitch_domain_models::{
     },
     types::{PaymentsAuthorizeRouterData, RefundsRouterData, RevenueRecoveryRecordBackRouterData},
 };
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+use hyperswitch_domain_models::{
+    revenue_recovery, router_flow_types::revenue_recovery as recovery_router_flows,
+    router_request_types::revenue_recovery as recovery_request_types,
+    router_response_types::revenue_recovery as recovery_response_types,
+    types as recovery_router_data_types,
+};
 use hyperswitch_interfaces::errors;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
@@ -249,6 +254,10 @@ impl TryFrom<RefundsResponseRouterData<RSync, RefundResponse>> for RefundsRouter
 pub struct ChargebeeErrorResponse {
     pub api_error_code: String,
     pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    // the parameter of the request which caused the error
+    pub param: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -257,6 +266,45 @@ pub struct ChargebeeWebhookBody {
     pub event_type: ChargebeeEventType,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeEventBody {
+    pub event_type: ChargebeeEventType,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeSubscriptionBody {
+    pub content: ChargebeeSubscriptionContent,
+    pub event_type: ChargebeeEventType,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeSubscriptionContent {
+    pub subscription: ChargebeeSubscriptionData,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeSubscriptionData {
+    // subscription id
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeDisputeBody {
+    pub content: ChargebeeDisputeContent,
+    pub event_type: ChargebeeEventType,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeDisputeContent {
+    pub customer: ChargebeeDisputeCustomer,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeDisputeCustomer {
+    // customer id
+    pub id: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ChargebeeInvoiceBody {
     pub content: ChargebeeInvoiceContent,
@@ -266,6 +314,14 @@ pub struct ChargebeeInvoiceBody {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ChargebeeInvoiceContent {
     pub invoice: ChargebeeInvoiceData,
+    pub customer: Option<ChargebeeInvoiceCustomer>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChargebeeInvoiceCustomer {
+    pub id: String,
+    pub email: Option<pii::Email>,
+    pub locale: Option<Secret<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -282,6 +338,10 @@ pub enum ChargebeeEventType {
     PaymentSucceeded,
     PaymentFailed,
     InvoiceDeleted,
+    SubscriptionCancelled,
+    PaymentDisputeCreated,
+    PaymentDisputeWon,
+    PaymentDisputeLost,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -290,6 +350,7 @@ pub struct ChargebeeInvoiceData {
     pub id: String,
     pub total: MinorUnit,
     pub currency_code: enums::Currency,
+    pub subscription_id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -321,6 +382,9 @@ pub struct ChargebeePaymentMethodDetails {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ChargebeeCardDetails {
     funding_type: ChargebeeFundingType,
+    last4: Option<String>,
+    expiry_month: Option<u8>,
+    expiry_year: Option<u16>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -346,6 +410,8 @@ pub enum ChargebeeTranasactionStatus {
     LateFailure,
     // Connection with Gateway got terminated abruptly. So, status of this transaction needs to be resolved manually
     NeedsAttention,
+    // The transaction is voided or the authorization has expired at the payment gateway.
+    Voided,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -375,6 +441,37 @@ impl ChargebeeWebhookBody {
     }
 }
 
+impl ChargebeeEventBody {
+    pub fn get_event_from_body(body: &[u8]) -> CustomResult<Self, errors::ConnectorError> {
+        let event_body = body
+            .parse_struct::<Self>("ChargebeeEventBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(event_body)
+    }
+}
+
+impl ChargebeeSubscriptionBody {
+    pub fn get_subscription_webhook_data_from_body(
+        body: &[u8],
+    ) -> CustomResult<Self, errors::ConnectorError> {
+        let webhook_body = body
+            .parse_struct::<Self>("ChargebeeSubscriptionBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(webhook_body)
+    }
+}
+
+impl ChargebeeDisputeBody {
+    pub fn get_dispute_webhook_data_from_body(
+        body: &[u8],
+    ) -> CustomResult<Self, errors::ConnectorError> {
+        let webhook_body = body
+            .parse_struct::<Self>("ChargebeeDisputeBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(webhook_body)
+    }
+}
+
 impl ChargebeeInvoiceBody {
     pub fn get_invoice_webhook_data_from_body(
         body: &[u8],
@@ -392,24 +489,34 @@ pub struct ChargebeeMandateDetails {
 }
 
 impl ChargebeeCustomer {
-    // the logic to find connector customer id & mandate id is different for different gateways, reference : https://apidocs.chargebee.com/docs/api/customers?prod_cat_ver=2#customer_payment_method_reference_id .
     pub fn find_connector_ids(&self) -> Result<ChargebeeMandateDetails, errors::ConnectorError> {
-        match self.payment_method.gateway {
-            ChargebeeGateway::Stripe | ChargebeeGateway::Braintree => {
-                let mut parts = self.payment_method.reference_id.split('/');
-                let customer_id = parts
-                    .next()
-                    .ok_or(errors::ConnectorError::WebhookBodyDecodingFailed)?
-                    .to_string();
-                let mandate_id = parts
-                    .last()
-                    .ok_or(errors::ConnectorError::WebhookBodyDecodingFailed)?
-                    .to_string();
-                Ok(ChargebeeMandateDetails {
-                    customer_id,
-                    mandate_id,
-                })
-            }
+        find_connector_ids(
+            &self.payment_method.gateway,
+            &self.payment_method.reference_id,
+        )
+    }
+}
+
+// the logic to find connector customer id & mandate id is different for different gateways, reference : https://apidocs.chargebee.com/docs/api/customers?prod_cat_ver=2#customer_payment_method_reference_id .
+fn find_connector_ids(
+    gateway: &ChargebeeGateway,
+    reference_id: &str,
+) -> Result<ChargebeeMandateDetails, errors::ConnectorError> {
+    match gateway {
+        ChargebeeGateway::Stripe | ChargebeeGateway::Braintree => {
+            let mut parts = reference_id.split('/');
+            let customer_id = parts
+                .next()
+                .ok_or(errors::ConnectorError::WebhookBodyDecodingFailed)?
+                .to_string();
+            let mandate_id = parts
+                .last()
+                .ok_or(errors::ConnectorError::WebhookBodyDecodingFailed)?
+                .to_string();
+            Ok(ChargebeeMandateDetails {
+                customer_id,
+                mandate_id,
+            })
         }
     }
 }
@@ -466,6 +573,184 @@ impl TryFrom<ChargebeeWebhookBody> for revenue_recovery::RevenueRecoveryAttemptD
             network_advice_code: None,
             network_decline_code: None,
             network_error_message: None,
+            processor_response_details: None,
+            card_issuing_country: None,
+            card_details: None,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChargebeeTransactionResponse {
+    pub transaction: ChargebeePaymentsSyncTransaction,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChargebeeInvoicePaymentsResponse {
+    pub list: Vec<ChargebeeTransactionResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChargebeePaymentsSyncTransaction {
+    // transaction id
+    pub id: String,
+    pub subscription_id: Option<String>,
+    pub gateway: ChargebeeGateway,
+    // reference of the payment method at the payment gateway, ex: cus_123/card_456
+    pub reference_number: Option<String>,
+    #[serde(default)]
+    pub linked_invoices: Vec<ChargebeeLinkedInvoice>,
+    #[serde(flatten)]
+    pub details: ChargebeeTransactionData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChargebeeLinkedInvoice {
+    pub invoice_id: String,
+}
+
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+impl TryFrom<ChargebeePaymentsSyncTransaction>
+    for recovery_response_types::BillingConnectorPaymentsSyncResponse
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: ChargebeePaymentsSyncTransaction) -> Result<Self, Self::Error> {
+        // A transaction can be applied to multiple invoices, the first invoice is used as the
+        // reference for the transaction
+        let invoice_id = item
+            .linked_invoices
+            .first()
+            .map(|linked_invoice| linked_invoice.invoice_id.as_str())
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "linked_invoices",
+            })?;
+        let merchant_reference_id = common_utils::id_type::PaymentReferenceId::from_str(invoice_id)
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let connector_mandate_details = item
+            .reference_number
+            .as_deref()
+            .map(|reference_number| find_connector_ids(&item.gateway, reference_number))
+            .transpose()?
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "reference_number",
+            })?;
+        let details = item.details;
+        let payment_method_details: ChargebeePaymentMethodDetails =
+            serde_json::from_str(&details.payment_method_details)
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let payment_method_sub_type =
+            enums::PaymentMethodType::from(payment_method_details.card.funding_type);
+        let card_details = common_types::payments::RevenueRecoveryCardDetails {
+            card_network: None,
+            last4: payment_method_details.card.last4,
+            card_exp_month: payment_method_details.card.expiry_month,
+            card_exp_year: payment_method_details.card.expiry_year,
+            card_funding: Some(payment_method_sub_type),
+        };
+
+        Ok(Self {
+            status: enums::AttemptStatus::from(details.status),
+            amount: details.amount,
+            currency: details.currency_code,
+            merchant_reference_id,
+            connector_account_reference_id: details.gateway_account_id,
+            connector_transaction_id: details
+                .id_at_gateway
+                .map(common_utils::types::ConnectorTransactionId::TxnId),
+            error_code: details.error_code,
+            error_message: details.error_text,
+            processor_payment_method_token: connector_mandate_details.mandate_id,
+            connector_customer_id: connector_mandate_details.customer_id,
+            transaction_created_at: details.date,
+            payment_method_sub_type,
+            payment_method_type: enums::PaymentMethod::from(details.payment_method),
+            network_advice_code: None,
+            network_decline_code: None,
+            network_error_message: None,
+            processor_response_details: None,
+            card_issuing_country: None,
+            card_details: Some(card_details),
+            billing_connector_subscription_id: item.subscription_id,
+            invoice_transactions: Vec::new(),
+        })
+    }
+}
+
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+impl
+    TryFrom<
+        ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            ChargebeeTransactionResponse,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    > for recovery_router_data_types::BillingConnectorPaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            ChargebeeTransactionResponse,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(
+                recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(
+                    item.response.transaction,
+                )?,
+            ),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+impl
+    TryFrom<
+        ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            ChargebeeInvoicePaymentsResponse,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    > for recovery_router_data_types::BillingConnectorPaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            ChargebeeInvoicePaymentsResponse,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let mut transactions = item
+            .response
+            .list
+            .into_iter()
+            .map(|payment| {
+                recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(
+                    payment.transaction,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // The latest payment of the invoice is returned, the rest are sent along with it
+        transactions.sort_by_key(|transaction| transaction.transaction_created_at);
+        let latest_transaction = transactions
+            .pop()
+            .ok_or(errors::ConnectorError::MissingRequiredField { field_name: "list" })?;
+
+        Ok(Self {
+            response: Ok(
+                recovery_response_types::BillingConnectorPaymentsSyncResponse {
+                    invoice_transactions: transactions,
+                    ..latest_transaction
+                },
+            ),
+            ..item.data
         })
     }
 }
@@ -479,6 +764,7 @@ impl From<ChargebeeTranasactionStatus> for enums::AttemptStatus {
             ChargebeeTranasactionStatus::Failure
             | ChargebeeTranasactionStatus::Timeout
             | ChargebeeTranasactionStatus::LateFailure => Self::Failure,
+            ChargebeeTranasactionStatus::Voided => Self::Voided,
         }
     }
 }
@@ -506,6 +792,12 @@ impl From<ChargebeeEventType> for api_models::webhooks::IncomingWebhookEvent {
             ChargebeeEventType::PaymentSucceeded => Self::RecoveryPaymentSuccess,
             ChargebeeEventType::PaymentFailed => Self::RecoveryPaymentFailure,
             ChargebeeEventType::InvoiceDeleted => Self::RecoveryInvoiceCancel,
+            ChargebeeEventType::SubscriptionCancelled => Self::RecoverySubscriptionCancel,
+            ChargebeeEventType::PaymentDisputeCreated => Self::RecoveryDisputeOpened,
+            // The retries are resumed once the dispute is closed, irrespective of its outcome
+            ChargebeeEventType::PaymentDisputeWon | ChargebeeEventType::PaymentDisputeLost => {
+                Self::RecoveryDisputeClosed
+            }
         }
     }
 }
@@ -517,10 +809,19 @@ impl TryFrom<ChargebeeInvoiceBody> for revenue_recovery::RevenueRecoveryInvoiceD
         let merchant_reference_id =
             common_utils::id_type::PaymentReferenceId::from_str(&item.content.invoice.id)
                 .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let customer_details = item.content.customer.map(|customer| {
+            revenue_recovery::RevenueRecoveryCustomerDetails {
+                billing_connector_customer_id: customer.id,
+                email: customer.email,
+                locale: customer.locale,
+            }
+        });
         Ok(Self {
             amount: item.content.invoice.total,
             currency: item.content.invoice.currency_code,
             merchant_reference_id,
+            customer_details,
+            billing_connector_subscription_id: item.content.invoice.subscription_id,
         })
     }
 }
@@ -648,3 +949,283 @@ impl
         })
     }
 }
+
+#[cfg(all(test, feature = "revenue_recovery", feature = "v2"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::PeekInterface;
+
+    use super::*;
+
+    const INVOICE_WEBHOOK_BODY: &str = r#"{
+        "event_type": "payment_failed",
+        "content": {
+            "invoice": {
+                "id": "inv_12345",
+                "total": 1000,
+                "currency_code": "USD",
+                "subscription_id": "sub_12345"
+            },
+            "customer": {
+                "id": "cust_12345",
+                "email": "dunning.contact@example.com",
+                "locale": "fr-FR"
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_invoice_details_with_customer_contact_data() {
+        let invoice_body = ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(
+            INVOICE_WEBHOOK_BODY.as_bytes(),
+        )
+        .unwrap();
+        let invoice_details =
+            revenue_recovery::RevenueRecoveryInvoiceData::try_from(invoice_body).unwrap();
+        let customer_details = invoice_details.customer_details.unwrap();
+
+        assert_eq!(customer_details.billing_connector_customer_id, "cust_12345");
+        assert_eq!(
+            customer_details.email.unwrap().peek(),
+            "dunning.contact@example.com"
+        );
+        assert_eq!(customer_details.locale.unwrap().peek(), "fr-FR");
+        assert_eq!(
+            invoice_details.billing_connector_subscription_id.as_deref(),
+            Some("sub_12345")
+        );
+    }
+
+    #[test]
+    fn test_invoice_details_without_customer() {
+        let invoice_body = ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(
+            br#"{
+                "event_type": "payment_failed",
+                "content": {
+                    "invoice": { "id": "inv_12345", "total": 1000, "currency_code": "USD" }
+                }
+            }"#,
+        )
+        .unwrap();
+        let invoice_details =
+            revenue_recovery::RevenueRecoveryInvoiceData::try_from(invoice_body).unwrap();
+
+        assert!(invoice_details.customer_details.is_none());
+        assert!(invoice_details.billing_connector_subscription_id.is_none());
+    }
+
+    #[test]
+    fn test_subscription_cancelled_webhook() {
+        let body = br#"{
+            "event_type": "subscription_cancelled",
+            "content": {
+                "subscription": { "id": "sub_12345", "status": "cancelled" }
+            }
+        }"#;
+        let event = ChargebeeEventBody::get_event_from_body(body).unwrap();
+        assert_eq!(
+            api_models::webhooks::IncomingWebhookEvent::from(event.event_type),
+            api_models::webhooks::IncomingWebhookEvent::RecoverySubscriptionCancel
+        );
+
+        let subscription_body =
+            ChargebeeSubscriptionBody::get_subscription_webhook_data_from_body(body).unwrap();
+        assert_eq!(subscription_body.content.subscription.id, "sub_12345");
+    }
+
+    #[test]
+    fn test_dispute_webhooks() {
+        let body = br#"{
+            "event_type": "payment_dispute_created",
+            "content": {
+                "customer": { "id": "cust_12345" },
+                "transaction": { "id": "txn_12345" }
+            }
+        }"#;
+        let event = ChargebeeEventBody::get_event_from_body(body).unwrap();
+        assert_eq!(
+            api_models::webhooks::IncomingWebhookEvent::from(event.event_type),
+            api_models::webhooks::IncomingWebhookEvent::RecoveryDisputeOpened
+        );
+
+        let dispute_body = ChargebeeDisputeBody::get_dispute_webhook_data_from_body(body).unwrap();
+        assert_eq!(dispute_body.content.customer.id, "cust_12345");
+
+        for event_type in ["payment_dispute_won", "payment_dispute_lost"] {
+            let body = format!(
+                r#"{{"event_type": "{event_type}", "content": {{"customer": {{"id": "cust_12345"}}}}}}"#
+            );
+            let event = ChargebeeEventBody::get_event_from_body(body.as_bytes()).unwrap();
+            assert_eq!(
+                api_models::webhooks::IncomingWebhookEvent::from(event.event_type),
+                api_models::webhooks::IncomingWebhookEvent::RecoveryDisputeClosed
+            );
+        }
+    }
+
+    const TRANSACTION_RESPONSE: &str = r#"{
+        "transaction": {
+            "id": "txn_12345",
+            "customer_id": "cust_12345",
+            "subscription_id": "sub_12345",
+            "gateway": "stripe",
+            "gateway_account_id": "gw_12345",
+            "reference_number": "cus_stripe123/card_stripe456",
+            "payment_method": "card",
+            "payment_method_details": "{\"card\":{\"funding_type\":\"credit\",\"last4\":\"4242\",\"expiry_month\":12,\"expiry_year\":2030}}",
+            "type": "payment",
+            "date": 1745234940,
+            "amount": 1000,
+            "currency_code": "USD",
+            "id_at_gateway": "ch_12345",
+            "status": "failure",
+            "error_code": "card_declined",
+            "error_text": "Your card was declined.",
+            "linked_invoices": [
+                { "invoice_id": "inv_12345", "applied_amount": 1000 }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_payments_sync_of_transaction() {
+        let response: ChargebeeTransactionResponse =
+            serde_json::from_str(TRANSACTION_RESPONSE).unwrap();
+        let payments_sync_response =
+            recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(
+                response.transaction,
+            )
+            .unwrap();
+
+        assert_eq!(payments_sync_response.status, enums::AttemptStatus::Failure);
+        assert_eq!(payments_sync_response.amount, MinorUnit::new(1000));
+        assert_eq!(
+            payments_sync_response
+                .merchant_reference_id
+                .get_string_repr(),
+            "inv_12345"
+        );
+        assert_eq!(
+            payments_sync_response.connector_account_reference_id,
+            "gw_12345"
+        );
+        assert_eq!(
+            payments_sync_response
+                .connector_transaction_id
+                .unwrap()
+                .get_id(),
+            "ch_12345"
+        );
+        assert_eq!(
+            payments_sync_response.error_code.as_deref(),
+            Some("card_declined")
+        );
+        assert_eq!(
+            payments_sync_response.processor_payment_method_token,
+            "card_stripe456"
+        );
+        assert_eq!(
+            payments_sync_response.connector_customer_id,
+            "cus_stripe123"
+        );
+        assert_eq!(
+            payments_sync_response.payment_method_sub_type,
+            enums::PaymentMethodType::Credit
+        );
+        assert_eq!(
+            payments_sync_response
+                .card_details
+                .unwrap()
+                .last4
+                .as_deref(),
+            Some("4242")
+        );
+        assert_eq!(
+            payments_sync_response
+                .billing_connector_subscription_id
+                .as_deref(),
+            Some("sub_12345")
+        );
+    }
+
+    #[test]
+    fn test_payments_sync_of_invoice() {
+        let response: ChargebeeInvoicePaymentsResponse = serde_json::from_str(
+            r#"{
+                "list": [
+                    {
+                        "transaction": {
+                            "id": "txn_1", "gateway": "stripe", "gateway_account_id": "gw_12345",
+                            "reference_number": "cus_stripe123/card_stripe456",
+                            "payment_method": "card",
+                            "payment_method_details": "{\"card\":{\"funding_type\":\"debit\"}}",
+                            "date": 1745234940, "amount": 1000, "currency_code": "USD",
+                            "status": "voided",
+                            "linked_invoices": [{ "invoice_id": "inv_12345" }]
+                        }
+                    }
+                ],
+                "next_offset": null
+            }"#,
+        )
+        .unwrap();
+        let transaction = response.list.into_iter().next().unwrap().transaction;
+        let payments_sync_response =
+            recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(transaction)
+                .unwrap();
+
+        assert_eq!(payments_sync_response.status, enums::AttemptStatus::Voided);
+        assert!(payments_sync_response.connector_transaction_id.is_none());
+        assert!(payments_sync_response
+            .billing_connector_subscription_id
+            .is_none());
+    }
+
+    #[test]
+    fn test_payments_sync_without_linked_invoice_is_rejected() {
+        let mut response: serde_json::Value = serde_json::from_str(TRANSACTION_RESPONSE).unwrap();
+        response["transaction"]["linked_invoices"] = serde_json::json!([]);
+        let response: ChargebeeTransactionResponse = serde_json::from_value(response).unwrap();
+
+        assert!(
+            recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(
+                response.transaction
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_error_envelope() {
+        let response: ChargebeeErrorResponse = serde_json::from_str(
+            r#"{
+                "message": "Sorry, we couldn't find that resource",
+                "type": "invalid_request",
+                "api_error_code": "resource_not_found",
+                "param": "id",
+                "error_code": "referenced_resource_not_found",
+                "error_msg": "Sorry, we couldn't find that resource",
+                "http_status_code": 404
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.api_error_code, "resource_not_found");
+        assert_eq!(response.error_type.as_deref(), Some("invalid_request"));
+        assert_eq!(response.param.as_deref(), Some("id"));
+    }
+
+    #[test]
+    fn test_customer_contact_data_is_masked_in_logs() {
+        let invoice_body = ChargebeeInvoiceBody::get_invoice_webhook_data_from_body(
+            INVOICE_WEBHOOK_BODY.as_bytes(),
+        )
+        .unwrap();
+        let invoice_details =
+            revenue_recovery::RevenueRecoveryInvoiceData::try_from(invoice_body).unwrap();
+        let logged_details = format!("{invoice_details:?}");
+
+        assert!(!logged_details.contains("dunning.contact"));
+        assert!(!logged_details.contains("fr-FR"));
+    }
+}
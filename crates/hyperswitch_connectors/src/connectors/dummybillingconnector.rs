@@ -0,0 +1,559 @@
+//! Billing connector with deterministic responses, for exercising the revenue recovery flow
+//! without an account at a billing provider.
+//!
+//! The recovery data is carried by the webhooks, whose payload chooses the outcome and the decline
+//! code of the transaction. The payments sync and the record back calls are served by the mock
+//! routes of the dummy connector, which return the fixture transactions. The connector can be
+//! created only when the `dummy_connector` feature of the router is enabled.
+
+pub mod transformers;
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use common_utils::request::{Method, Request, RequestBuilder, RequestContent};
+use common_utils::{errors::CustomResult, ext_traits::BytesExt};
+use error_stack::ResultExt;
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use hyperswitch_domain_models::revenue_recovery;
+use hyperswitch_domain_models::{
+    router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
+    router_flow_types::{
+        access_token_auth::AccessTokenAuth,
+        payments::{Authorize, Capture, PSync, PaymentMethodToken, Session, SetupMandate, Void},
+        refunds::{Execute, RSync},
+    },
+    router_request_types::{
+        AccessTokenRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
+        PaymentsCancelData, PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData,
+        RefundsData, SetupMandateRequestData,
+    },
+    router_response_types::{PaymentsResponseData, RefundsResponseData},
+};
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use hyperswitch_domain_models::{
+    router_flow_types::revenue_recovery as recovery_router_flows,
+    router_request_types::revenue_recovery as recovery_request_types,
+    router_response_types::revenue_recovery as recovery_response_types,
+    types as recovery_router_data_types,
+};
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use hyperswitch_interfaces::types;
+use hyperswitch_interfaces::{
+    api::{
+        self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorSpecifications,
+        ConnectorValidation,
+    },
+    configs::Connectors,
+    errors,
+    events::connector_api_logs::ConnectorEvent,
+    types::Response,
+    webhooks,
+};
+use masking::{Mask, PeekInterface};
+use transformers as dummybillingconnector;
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use crate::types::ResponseRouterData;
+use crate::{constants::headers, utils};
+
+#[derive(Clone)]
+pub struct DummyBillingConnector;
+
+impl DummyBillingConnector {
+    pub fn new() -> &'static Self {
+        &Self
+    }
+}
+
+impl api::Payment for DummyBillingConnector {}
+impl api::PaymentSession for DummyBillingConnector {}
+impl api::ConnectorAccessToken for DummyBillingConnector {}
+impl api::MandateSetup for DummyBillingConnector {}
+impl api::PaymentAuthorize for DummyBillingConnector {}
+impl api::PaymentSync for DummyBillingConnector {}
+impl api::PaymentCapture for DummyBillingConnector {}
+impl api::PaymentVoid for DummyBillingConnector {}
+impl api::Refund for DummyBillingConnector {}
+impl api::RefundExecute for DummyBillingConnector {}
+impl api::RefundSync for DummyBillingConnector {}
+impl api::PaymentToken for DummyBillingConnector {}
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl api::revenue_recovery::RevenueRecoveryRecordBack for DummyBillingConnector {}
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl api::revenue_recovery::BillingConnectorPaymentsSyncIntegration for DummyBillingConnector {}
+
+impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
+    for DummyBillingConnector
+{
+}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for DummyBillingConnector
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &RouterData<Flow, Request, Response>,
+        _connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string().into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for DummyBillingConnector {
+    fn id(&self) -> &'static str {
+        "dummybillingconnector"
+    }
+
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str {
+        connectors.dummybillingconnector.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let auth = dummybillingconnector::DummyBillingConnectorAuthType::try_from(auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            auth.api_key.peek().to_string().into_masked(),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: Result<dummybillingconnector::DummyBillingConnectorErrorResponse, _> = res
+            .response
+            .parse_struct("DummyBillingConnectorErrorResponse");
+
+        match response {
+            Ok(response) => {
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                Ok(ErrorResponse {
+                    status_code: res.status_code,
+                    code: response.code,
+                    message: response.message,
+                    reason: response.reason,
+                    attempt_status: None,
+                    connector_transaction_id: None,
+                    network_advice_code: None,
+                    network_decline_code: None,
+                    network_error_message: None,
+                })
+            }
+            Err(error_msg) => {
+                event_builder.map(|event| event.set_error(serde_json::json!({"error": res.response.escape_ascii().to_string(), "status_code": res.status_code})));
+                router_env::logger::error!(deserialization_error =? error_msg);
+                utils::handle_json_response_deserialization_failure(res, "dummybillingconnector")
+            }
+        }
+    }
+}
+
+impl ConnectorValidation for DummyBillingConnector {}
+
+impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData>
+    for DummyBillingConnector
+{
+}
+
+impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, AccessToken>
+    for DummyBillingConnector
+{
+}
+
+impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData>
+    for DummyBillingConnector
+{
+}
+
+impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData>
+    for DummyBillingConnector
+{
+}
+
+impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for DummyBillingConnector {}
+
+impl ConnectorIntegration<Capture, PaymentsCaptureData, PaymentsResponseData>
+    for DummyBillingConnector
+{
+}
+
+impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData>
+    for DummyBillingConnector
+{
+}
+
+impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for DummyBillingConnector {}
+
+impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for DummyBillingConnector {}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    ConnectorIntegration<
+        recovery_router_flows::BillingConnectorPaymentsSync,
+        recovery_request_types::BillingConnectorPaymentsSyncRequest,
+        recovery_response_types::BillingConnectorPaymentsSyncResponse,
+    > for DummyBillingConnector
+{
+    fn get_headers(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = self.build_headers(req, connectors)?;
+        if let Some(recovery_correlation_id) = &req.request.recovery_correlation_id {
+            header.push((
+                headers::CORRELATION_ID.to_string(),
+                recovery_correlation_id.clone().into(),
+            ));
+        }
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        match &req.request.billing_connector_psync_id {
+            recovery_request_types::BillingConnectorPaymentsSyncId::TransactionId(
+                transaction_id,
+            ) => Ok(format!(
+                "{}transactions/{transaction_id}",
+                self.base_url(connectors)
+            )),
+            recovery_request_types::BillingConnectorPaymentsSyncId::InvoiceId(invoice_id) => {
+                Ok(format!(
+                    "{}invoices/{invoice_id}/transactions",
+                    self.base_url(connectors)
+                ))
+            }
+        }
+    }
+
+    fn build_request(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let request = RequestBuilder::new()
+            .method(Method::Get)
+            .url(&types::BillingConnectorPaymentsSyncType::get_url(
+                self, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(types::BillingConnectorPaymentsSyncType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<
+        recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
+        errors::ConnectorError,
+    > {
+        match &data.request.billing_connector_psync_id {
+            recovery_request_types::BillingConnectorPaymentsSyncId::TransactionId(_) => {
+                let response: dummybillingconnector::DummyBillingConnectorTransaction = res
+                    .response
+                    .parse_struct("DummyBillingConnectorTransaction")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                recovery_router_data_types::BillingConnectorPaymentsSyncRouterData::try_from(
+                    ResponseRouterData {
+                        response,
+                        data: data.clone(),
+                        http_code: res.status_code,
+                    },
+                )
+            }
+            recovery_request_types::BillingConnectorPaymentsSyncId::InvoiceId(_) => {
+                let response: dummybillingconnector::DummyBillingConnectorInvoiceTransactions = res
+                    .response
+                    .parse_struct("DummyBillingConnectorInvoiceTransactions")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                recovery_router_data_types::BillingConnectorPaymentsSyncRouterData::try_from(
+                    ResponseRouterData {
+                        response,
+                        data: data.clone(),
+                        http_code: res.status_code,
+                    },
+                )
+            }
+        }
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    ConnectorIntegration<
+        recovery_router_flows::RecoveryRecordBack,
+        recovery_request_types::RevenueRecoveryRecordBackRequest,
+        recovery_response_types::RevenueRecoveryRecordBackResponse,
+    > for DummyBillingConnector
+{
+    fn get_headers(
+        &self,
+        req: &recovery_router_data_types::RevenueRecoveryRecordBackRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &recovery_router_data_types::RevenueRecoveryRecordBackRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}invoices/{}/record_back",
+            self.base_url(connectors),
+            req.request.merchant_reference_id.get_string_repr()
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &recovery_router_data_types::RevenueRecoveryRecordBackRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req =
+            dummybillingconnector::DummyBillingConnectorRecordBackRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &recovery_router_data_types::RevenueRecoveryRecordBackRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&types::RevenueRecoveryRecordBackType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::RevenueRecoveryRecordBackType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::RevenueRecoveryRecordBackType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &recovery_router_data_types::RevenueRecoveryRecordBackRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<
+        recovery_router_data_types::RevenueRecoveryRecordBackRouterData,
+        errors::ConnectorError,
+    > {
+        let response: dummybillingconnector::DummyBillingConnectorRecordBackResponse = res
+            .response
+            .parse_struct("DummyBillingConnectorRecordBackResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        recovery_router_data_types::RevenueRecoveryRecordBackRouterData::try_from(
+            ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        )
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[async_trait::async_trait]
+impl webhooks::IncomingWebhook for DummyBillingConnector {
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn common_utils::crypto::VerifySignature + Send>, errors::ConnectorError>
+    {
+        Ok(Box::new(common_utils::crypto::HmacSha256))
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = utils::get_header_key_value(
+            dummybillingconnector::auth_headers::WEBHOOK_SIGNATURE,
+            request.headers,
+        )
+        .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+        hex::decode(signature).change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        dummybillingconnector::DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+            request.body,
+        )
+        .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?
+        .get_object_reference_id()
+    }
+
+    #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
+    fn get_webhook_object_reference_id(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented.into())
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_webhook_event_type(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
+        dummybillingconnector::DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+            request.body,
+        )
+        .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?
+        .get_event_type()
+    }
+
+    #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
+    fn get_webhook_event_type(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented.into())
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_webhook_resource_object(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        let webhook =
+            dummybillingconnector::DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+                request.body,
+            )
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(Box::new(webhook))
+    }
+
+    #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
+    fn get_webhook_resource_object(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented.into())
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_revenue_recovery_attempt_details(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<revenue_recovery::RevenueRecoveryAttemptData, errors::ConnectorError> {
+        let webhook =
+            dummybillingconnector::DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+                request.body,
+            )?;
+        revenue_recovery::RevenueRecoveryAttemptData::try_from(webhook)
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_revenue_recovery_invoice_details(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<revenue_recovery::RevenueRecoveryInvoiceData, errors::ConnectorError> {
+        let webhook =
+            dummybillingconnector::DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+                request.body,
+            )?;
+        revenue_recovery::RevenueRecoveryInvoiceData::try_from(webhook.invoice)
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_revenue_recovery_event_timestamp(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        let webhook =
+            dummybillingconnector::DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+                request.body,
+            )?;
+        Ok(Some(webhook.occurred_at))
+    }
+}
+
+impl ConnectorSpecifications for DummyBillingConnector {}
@@ -0,0 +1,560 @@
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use std::str::FromStr;
+
+use common_enums::enums;
+use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt, pii, types::MinorUnit};
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use common_utils::{id_type, types::ConnectorTransactionId};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::router_data::ConnectorAuthType;
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use hyperswitch_domain_models::{
+    revenue_recovery, router_flow_types::revenue_recovery as recovery_router_flows,
+    router_request_types::revenue_recovery as recovery_request_types,
+    router_response_types::revenue_recovery as recovery_response_types,
+    types as recovery_router_data_types,
+};
+use hyperswitch_interfaces::errors;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+use crate::types::ResponseRouterData;
+
+pub mod auth_headers {
+    pub const WEBHOOK_SIGNATURE: &str = "x-dummy-billing-signature";
+}
+
+pub struct DummyBillingConnectorAuthType {
+    pub(super) api_key: Secret<String>,
+}
+
+impl TryFrom<&ConnectorAuthType> for DummyBillingConnectorAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DummyBillingConnectorErrorResponse {
+    pub code: String,
+    pub message: String,
+    pub reason: Option<String>,
+}
+
+/// Outcome of a transaction, chosen by the webhook or the fixture which carries the transaction
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyBillingConnectorOutcome {
+    Succeeded,
+    Failed,
+    Pending,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyBillingConnectorEventType {
+    /// The transaction of the webhook was made against the invoice, its outcome decides the event
+    TransactionUpdated,
+    InvoiceVoided,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DummyBillingConnectorWebhookBody {
+    pub event_type: DummyBillingConnectorEventType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub occurred_at: PrimitiveDateTime,
+    pub invoice: DummyBillingConnectorInvoice,
+    pub transaction: Option<DummyBillingConnectorTransaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DummyBillingConnectorInvoice {
+    pub id: String,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub customer_id: Option<String>,
+    pub customer_email: Option<pii::Email>,
+    pub subscription_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DummyBillingConnectorTransaction {
+    pub id: String,
+    pub invoice_id: String,
+    pub outcome: DummyBillingConnectorOutcome,
+    pub decline_code: Option<String>,
+    pub decline_message: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    /// Payment connector account at the billing connector through which the transaction was made
+    pub gateway_account_id: String,
+    pub gateway_transaction_id: Option<String>,
+    pub gateway_customer_id: String,
+    /// Mandate of the customer at the payment connector
+    pub payment_method_token: String,
+    pub card: DummyBillingConnectorCard,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DummyBillingConnectorCard {
+    pub network: Option<common_enums::CardNetwork>,
+    pub last4: Option<String>,
+    pub exp_month: Option<u8>,
+    pub exp_year: Option<u16>,
+    pub funding: DummyBillingConnectorCardFunding,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyBillingConnectorCardFunding {
+    Credit,
+    Debit,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DummyBillingConnectorInvoiceTransactions {
+    pub transactions: Vec<DummyBillingConnectorTransaction>,
+}
+
+impl DummyBillingConnectorWebhookBody {
+    pub fn get_webhook_object_from_body(body: &[u8]) -> CustomResult<Self, errors::ConnectorError> {
+        body.parse_struct::<Self>("DummyBillingConnectorWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl DummyBillingConnectorWebhookBody {
+    pub fn get_object_reference_id(
+        &self,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        match (self.event_type, &self.transaction) {
+            (DummyBillingConnectorEventType::TransactionUpdated, Some(transaction)) => {
+                Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+                    api_models::payments::PaymentIdType::ConnectorTransactionId(
+                        transaction.id.clone(),
+                    ),
+                ))
+            }
+            (DummyBillingConnectorEventType::TransactionUpdated, None) => {
+                Err(errors::ConnectorError::WebhookReferenceIdNotFound.into())
+            }
+            (DummyBillingConnectorEventType::InvoiceVoided, _) => {
+                Ok(api_models::webhooks::ObjectReferenceId::InvoiceId(
+                    api_models::webhooks::InvoiceIdType::ConnectorInvoiceId(
+                        self.invoice.id.clone(),
+                    ),
+                ))
+            }
+        }
+    }
+
+    pub fn get_event_type(
+        &self,
+    ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
+        match (self.event_type, &self.transaction) {
+            (DummyBillingConnectorEventType::TransactionUpdated, Some(transaction)) => {
+                Ok(match transaction.outcome {
+                    DummyBillingConnectorOutcome::Succeeded => {
+                        api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess
+                    }
+                    DummyBillingConnectorOutcome::Failed => {
+                        api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentFailure
+                    }
+                    DummyBillingConnectorOutcome::Pending => {
+                        api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentPending
+                    }
+                })
+            }
+            (DummyBillingConnectorEventType::TransactionUpdated, None) => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
+            (DummyBillingConnectorEventType::InvoiceVoided, _) => {
+                Ok(api_models::webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel)
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl TryFrom<DummyBillingConnectorInvoice> for revenue_recovery::RevenueRecoveryInvoiceData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(invoice: DummyBillingConnectorInvoice) -> Result<Self, Self::Error> {
+        let merchant_reference_id = id_type::PaymentReferenceId::from_str(&invoice.id)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(Self {
+            amount: invoice.amount,
+            currency: invoice.currency,
+            merchant_reference_id,
+            customer_details: invoice.customer_id.map(|billing_connector_customer_id| {
+                revenue_recovery::RevenueRecoveryCustomerDetails {
+                    billing_connector_customer_id,
+                    email: invoice.customer_email,
+                    locale: None,
+                }
+            }),
+            billing_connector_subscription_id: invoice.subscription_id,
+        })
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl TryFrom<DummyBillingConnectorTransaction>
+    for recovery_response_types::BillingConnectorPaymentsSyncResponse
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(transaction: DummyBillingConnectorTransaction) -> Result<Self, Self::Error> {
+        let merchant_reference_id = id_type::PaymentReferenceId::from_str(&transaction.invoice_id)
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let card_funding = common_enums::PaymentMethodType::from(transaction.card.funding);
+
+        Ok(Self {
+            amount: transaction.amount,
+            currency: transaction.currency,
+            merchant_reference_id,
+            connector_transaction_id: transaction
+                .gateway_transaction_id
+                .map(ConnectorTransactionId::from),
+            error_code: transaction.decline_code.clone(),
+            error_message: transaction.decline_message,
+            processor_payment_method_token: transaction.payment_method_token,
+            connector_customer_id: transaction.gateway_customer_id,
+            connector_account_reference_id: transaction.gateway_account_id,
+            transaction_created_at: Some(transaction.created_at),
+            status: enums::AttemptStatus::from(transaction.outcome),
+            payment_method_type: common_enums::PaymentMethod::Card,
+            payment_method_sub_type: card_funding,
+            network_advice_code: None,
+            network_decline_code: transaction.decline_code,
+            network_error_message: None,
+            processor_response_details: None,
+            card_issuing_country: None,
+            card_details: Some(common_types::payments::RevenueRecoveryCardDetails {
+                card_network: transaction.card.network,
+                last4: transaction.card.last4,
+                card_exp_month: transaction.card.exp_month,
+                card_exp_year: transaction.card.exp_year,
+                card_funding: Some(card_funding),
+            }),
+            billing_connector_subscription_id: None,
+            invoice_transactions: Vec::new(),
+        })
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl TryFrom<DummyBillingConnectorWebhookBody> for revenue_recovery::RevenueRecoveryAttemptData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: DummyBillingConnectorWebhookBody) -> Result<Self, Self::Error> {
+        let transaction = webhook
+            .transaction
+            .ok_or(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        let payments_sync_response =
+            recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(transaction)
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(Self::from(&payments_sync_response))
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    TryFrom<
+        ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            DummyBillingConnectorTransaction,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    > for recovery_router_data_types::BillingConnectorPaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            DummyBillingConnectorTransaction,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(
+                recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(
+                    item.response,
+                )?,
+            ),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    TryFrom<
+        ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            DummyBillingConnectorInvoiceTransactions,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    > for recovery_router_data_types::BillingConnectorPaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            DummyBillingConnectorInvoiceTransactions,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let mut transactions = item
+            .response
+            .transactions
+            .into_iter()
+            .map(recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        // The latest transaction of the invoice is returned, the rest are sent along with it
+        transactions.sort_by_key(|transaction| transaction.transaction_created_at);
+        let latest_transaction =
+            transactions
+                .pop()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "transactions",
+                })?;
+
+        Ok(Self {
+            response: Ok(
+                recovery_response_types::BillingConnectorPaymentsSyncResponse {
+                    invoice_transactions: transactions,
+                    ..latest_transaction
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DummyBillingConnectorRecordBackRequest {
+    pub outcome: DummyBillingConnectorOutcome,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub gateway_transaction_id: Option<String>,
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl TryFrom<&recovery_router_data_types::RevenueRecoveryRecordBackRouterData>
+    for DummyBillingConnectorRecordBackRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &recovery_router_data_types::RevenueRecoveryRecordBackRouterData,
+    ) -> Result<Self, Self::Error> {
+        let outcome = match item.request.attempt_status {
+            enums::AttemptStatus::Charged => DummyBillingConnectorOutcome::Succeeded,
+            enums::AttemptStatus::Failure => DummyBillingConnectorOutcome::Failed,
+            _ => {
+                return Err(errors::ConnectorError::NotSupported {
+                    message: "Record back flow is only supported for terminal status".to_string(),
+                    connector: "dummybillingconnector",
+                }
+                .into())
+            }
+        };
+        Ok(Self {
+            outcome,
+            amount: item.request.amount,
+            currency: item.request.currency,
+            gateway_transaction_id: item
+                .request
+                .connector_transaction_id
+                .as_ref()
+                .map(|connector_transaction_id| connector_transaction_id.get_id().clone()),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DummyBillingConnectorRecordBackResponse {
+    pub invoice_id: String,
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    TryFrom<
+        ResponseRouterData<
+            recovery_router_flows::RecoveryRecordBack,
+            DummyBillingConnectorRecordBackResponse,
+            recovery_request_types::RevenueRecoveryRecordBackRequest,
+            recovery_response_types::RevenueRecoveryRecordBackResponse,
+        >,
+    > for recovery_router_data_types::RevenueRecoveryRecordBackRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            recovery_router_flows::RecoveryRecordBack,
+            DummyBillingConnectorRecordBackResponse,
+            recovery_request_types::RevenueRecoveryRecordBackRequest,
+            recovery_response_types::RevenueRecoveryRecordBackResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let merchant_reference_id =
+            id_type::PaymentReferenceId::from_str(&item.response.invoice_id)
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(Self {
+            response: Ok(recovery_response_types::RevenueRecoveryRecordBackResponse {
+                merchant_reference_id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl From<DummyBillingConnectorOutcome> for enums::AttemptStatus {
+    fn from(outcome: DummyBillingConnectorOutcome) -> Self {
+        match outcome {
+            DummyBillingConnectorOutcome::Succeeded => Self::Charged,
+            DummyBillingConnectorOutcome::Failed => Self::Failure,
+            DummyBillingConnectorOutcome::Pending => Self::Pending,
+        }
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl From<DummyBillingConnectorCardFunding> for common_enums::PaymentMethodType {
+    fn from(funding: DummyBillingConnectorCardFunding) -> Self {
+        match funding {
+            DummyBillingConnectorCardFunding::Credit => Self::Credit,
+            DummyBillingConnectorCardFunding::Debit => Self::Debit,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "revenue_recovery", feature = "v2"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_transaction_webhook(
+        outcome: &str,
+        decline_code: &str,
+    ) -> DummyBillingConnectorWebhookBody {
+        DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+            format!(
+                r#"{{
+                    "event_type": "transaction_updated",
+                    "occurred_at": "2025-04-21T11:29:00Z",
+                    "invoice": {{
+                        "id": "dummy_inv_1",
+                        "amount": 1000,
+                        "currency": "USD",
+                        "customer_id": "dummy_cus_1",
+                        "customer_email": "guest@example.com",
+                        "subscription_id": "dummy_sub_1"
+                    }},
+                    "transaction": {{
+                        "id": "dummy_txn_1",
+                        "invoice_id": "dummy_inv_1",
+                        "outcome": "{outcome}",
+                        "decline_code": {decline_code},
+                        "decline_message": null,
+                        "amount": 1000,
+                        "currency": "USD",
+                        "gateway_account_id": "dummy_gw_1",
+                        "gateway_transaction_id": "pi_dummy_1",
+                        "gateway_customer_id": "cus_dummy_1",
+                        "payment_method_token": "pm_dummy_1",
+                        "card": {{
+                            "network": "Visa",
+                            "last4": "4242",
+                            "exp_month": 12,
+                            "exp_year": 2030,
+                            "funding": "credit"
+                        }},
+                        "created_at": "2025-04-21T11:28:00Z"
+                    }}
+                }}"#
+            )
+            .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_transaction_outcome_selects_the_event() {
+        let webhook = get_transaction_webhook("failed", r#""insufficient_funds""#);
+        assert_eq!(
+            webhook.get_event_type().unwrap(),
+            api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentFailure
+        );
+        assert!(matches!(
+            webhook.get_object_reference_id().unwrap(),
+            api_models::webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(transaction_id)
+            ) if transaction_id == "dummy_txn_1"
+        ));
+
+        let attempt = revenue_recovery::RevenueRecoveryAttemptData::try_from(webhook).unwrap();
+        assert_eq!(attempt.status, enums::AttemptStatus::Failure);
+        assert_eq!(attempt.error_code.as_deref(), Some("insufficient_funds"));
+        assert_eq!(attempt.processor_payment_method_token, "pm_dummy_1");
+        assert_eq!(attempt.connector_account_reference_id, "dummy_gw_1");
+
+        let webhook = get_transaction_webhook("succeeded", "null");
+        assert_eq!(
+            webhook.get_event_type().unwrap(),
+            api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess
+        );
+
+        let webhook = get_transaction_webhook("pending", "null");
+        assert_eq!(
+            webhook.get_event_type().unwrap(),
+            api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentPending
+        );
+    }
+
+    #[test]
+    fn test_invoice_voided_webhook() {
+        let webhook = DummyBillingConnectorWebhookBody::get_webhook_object_from_body(
+            br#"{
+                "event_type": "invoice_voided",
+                "occurred_at": "2025-04-21T11:29:00Z",
+                "invoice": {
+                    "id": "dummy_inv_1",
+                    "amount": 1000,
+                    "currency": "USD",
+                    "customer_id": null,
+                    "customer_email": null,
+                    "subscription_id": null
+                },
+                "transaction": null
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            webhook.get_event_type().unwrap(),
+            api_models::webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel
+        );
+        assert!(matches!(
+            webhook.get_object_reference_id().unwrap(),
+            api_models::webhooks::ObjectReferenceId::InvoiceId(
+                api_models::webhooks::InvoiceIdType::ConnectorInvoiceId(invoice_id)
+            ) if invoice_id == "dummy_inv_1"
+        ));
+        assert!(revenue_recovery::RevenueRecoveryAttemptData::try_from(webhook).is_err());
+    }
+}
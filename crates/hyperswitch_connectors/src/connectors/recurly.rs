@@ -107,6 +107,8 @@ impl api::PaymentToken for Recurly {}
 impl api::revenue_recovery::RevenueRecoveryRecordBack for Recurly {}
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 impl api::revenue_recovery::BillingConnectorPaymentsSyncIntegration for Recurly {}
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl api::revenue_recovery::BillingConnectorInvoiceSyncIntegration for Recurly {}
 
 impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
     for Recurly
@@ -620,11 +622,16 @@ impl
         req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
         connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        let transaction_uuid = &req.request.billing_connector_psync_id;
-        Ok(format!(
-            "{}/transactions/uuid-{transaction_uuid}",
-            self.base_url(connectors),
-        ))
+        let base_url = recurly::RecurlyMetadata::try_from(&req.connector_meta_data)?
+            .get_site_scoped_base_url(self.base_url(connectors));
+        match &req.request.billing_connector_psync_id {
+            recovery_request_types::BillingConnectorPaymentsSyncId::TransactionId(
+                transaction_uuid,
+            ) => Ok(format!("{base_url}/transactions/uuid-{transaction_uuid}")),
+            recovery_request_types::BillingConnectorPaymentsSyncId::InvoiceId(invoice_id) => {
+                Ok(format!("{base_url}/invoices/{invoice_id}/transactions"))
+            }
+        }
     }
 
     fn build_request(
@@ -654,15 +661,124 @@ impl
         recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
         errors::ConnectorError,
     > {
-        let response: RecurlyRecoveryDetailsData = res
+        match data.request.billing_connector_psync_id {
+            recovery_request_types::BillingConnectorPaymentsSyncId::TransactionId(_) => {
+                let response: RecurlyRecoveryDetailsData = res
+                    .response
+                    .parse_struct::<RecurlyRecoveryDetailsData>("RecurlyRecoveryDetailsData")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                recovery_router_data_types::BillingConnectorPaymentsSyncRouterData::try_from(
+                    ResponseRouterData {
+                        response,
+                        data: data.clone(),
+                        http_code: res.status_code,
+                    },
+                )
+            }
+            recovery_request_types::BillingConnectorPaymentsSyncId::InvoiceId(_) => {
+                let response: recurly::RecurlyInvoiceTransactionsData = res
+                    .response
+                    .parse_struct::<recurly::RecurlyInvoiceTransactionsData>(
+                        "RecurlyInvoiceTransactionsData",
+                    )
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+                event_builder.map(|i| i.set_response_body(&response));
+                router_env::logger::info!(connector_response=?response);
+
+                recovery_router_data_types::BillingConnectorPaymentsSyncRouterData::try_from(
+                    ResponseRouterData {
+                        response,
+                        data: data.clone(),
+                        http_code: res.status_code,
+                    },
+                )
+            }
+        }
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    ConnectorIntegration<
+        recovery_router_flows::BillingConnectorInvoiceSync,
+        recovery_request_types::BillingConnectorInvoiceSyncRequest,
+        recovery_response_types::BillingConnectorInvoiceSyncResponse,
+    > for Recurly
+{
+    fn get_headers(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorInvoiceSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorInvoiceSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let base_url = recurly::RecurlyMetadata::try_from(&req.connector_meta_data)?
+            .get_site_scoped_base_url(self.base_url(connectors));
+        Ok(format!(
+            "{base_url}/invoices/{}",
+            req.request.billing_connector_invoice_id,
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &recovery_router_data_types::BillingConnectorInvoiceSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let request = RequestBuilder::new()
+            .method(Method::Get)
+            .url(&types::BillingConnectorInvoiceSyncType::get_url(
+                self, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(types::BillingConnectorInvoiceSyncType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &recovery_router_data_types::BillingConnectorInvoiceSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<
+        recovery_router_data_types::BillingConnectorInvoiceSyncRouterData,
+        errors::ConnectorError,
+    > {
+        let response: recurly::RecurlyInvoiceData = res
             .response
-            .parse_struct::<RecurlyRecoveryDetailsData>("RecurlyRecoveryDetailsData")
+            .parse_struct::<recurly::RecurlyInvoiceData>("RecurlyInvoiceData")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
 
-        recovery_router_data_types::BillingConnectorPaymentsSyncRouterData::try_from(
+        recovery_router_data_types::BillingConnectorInvoiceSyncRouterData::try_from(
             ResponseRouterData {
                 response,
                 data: data.clone(),
@@ -784,6 +900,50 @@ impl webhooks::IncomingWebhook for Recurly {
         Ok(Box::new(common_utils::crypto::HmacSha256))
     }
 
+    async fn verify_webhook_source(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_webhook_details: Option<common_utils::pii::SecretSerdeValue>,
+        _connector_account_details: common_utils::crypto::Encryptable<
+            masking::Secret<serde_json::Value>,
+        >,
+        connector_name: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let connector_webhook_secrets = self
+            .get_webhook_source_verification_merchant_secret(
+                merchant_id,
+                connector_name,
+                connector_webhook_details,
+            )
+            .await
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let message = self
+            .get_webhook_source_verification_message(
+                request,
+                merchant_id,
+                &connector_webhook_secrets,
+            )
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let header_values = Self::get_signature_elements_from_header(request.headers)?;
+
+        // The webhook is signed with each of the active secret keys while a secret key is being
+        // regenerated, any one of the signatures matching verifies the webhook
+        Ok(header_values
+            .iter()
+            .skip(1)
+            .filter_map(|signature| hex::decode(signature).ok())
+            .any(|signature| {
+                common_utils::crypto::VerifySignature::verify_signature(
+                    &common_utils::crypto::HmacSha256,
+                    &connector_webhook_secrets.secret,
+                    &signature,
+                    &message,
+                )
+                .unwrap_or(false)
+            }))
+    }
+
     fn get_webhook_source_verification_signature(
         &self,
         request: &webhooks::IncomingWebhookRequestDetails<'_>,
@@ -822,9 +982,7 @@ impl webhooks::IncomingWebhook for Recurly {
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
         let webhook = RecurlyWebhookBody::get_webhook_object_from_body(request.body)
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
-        Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
-            api_models::payments::PaymentIdType::ConnectorTransactionId(webhook.uuid),
-        ))
+        Ok(webhook.object.get_object_reference_id())
     }
 
     #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
@@ -842,15 +1000,7 @@ impl webhooks::IncomingWebhook for Recurly {
     ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
         let webhook = RecurlyWebhookBody::get_webhook_object_from_body(request.body)
             .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
-        let event = match webhook.event_type {
-            transformers::RecurlyPaymentEventType::PaymentSucceeded => {
-                api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess
-            }
-            transformers::RecurlyPaymentEventType::PaymentFailed => {
-                api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentFailure
-            }
-        };
-        Ok(event)
+        Ok(webhook.object.get_event_type())
     }
 
     #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
@@ -869,6 +1019,15 @@ impl webhooks::IncomingWebhook for Recurly {
             .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
         Ok(Box::new(webhook))
     }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_revenue_recovery_event_timestamp(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        let webhook = RecurlyWebhookBody::get_webhook_object_from_body(request.body)?;
+        Ok(webhook.event_time)
+    }
 }
 
 impl ConnectorSpecifications for Recurly {}
@@ -7,7 +7,7 @@ use common_utils::types::{ConnectorTransactionId, FloatMajorUnitForConnector};
 use common_utils::{
     errors::CustomResult,
     ext_traits::ByteSliceExt,
-    id_type,
+    id_type, pii,
     types::{FloatMajorUnit, StringMinorUnit},
 };
 use error_stack::ResultExt;
@@ -27,15 +27,13 @@ use hyperswitch_domain_models::{
     types as recovery_router_data_types,
 };
 use hyperswitch_interfaces::errors;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
-use crate::utils;
 use crate::{
     types::{RefundsResponseRouterData, ResponseRouterData},
-    utils::PaymentsAuthorizeRequestData,
+    utils::{self, PaymentsAuthorizeRequestData},
 };
 
 //TODO: Fill the struct with respective fields
@@ -111,6 +109,48 @@ impl TryFrom<&ConnectorAuthType> for RecurlyAuthType {
         }
     }
 }
+
+/// Metadata of the recurly account, which is optional unless the api key of the account has access
+/// to several sites
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RecurlyMetadata {
+    /// Site to which the requests are scoped. ex: subdomain-mysite
+    pub(super) site_id: Option<Secret<String>>,
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for RecurlyMetadata {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        let Some(meta_data) = meta_data else {
+            return Ok(Self::default());
+        };
+        let metadata: Self = utils::to_connector_meta_from_secret::<Self>(Some(meta_data.clone()))
+            .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata",
+            })?;
+        if metadata
+            .site_id
+            .as_ref()
+            .is_some_and(|site_id| site_id.peek().trim().is_empty())
+        {
+            return Err(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.site_id",
+            }
+            .into());
+        }
+        Ok(metadata)
+    }
+}
+
+impl RecurlyMetadata {
+    /// Base url of the requests, scoped to the site of the account when it is configured
+    pub fn get_site_scoped_base_url(&self, base_url: &str) -> String {
+        match &self.site_id {
+            Some(site_id) => format!("{base_url}/sites/{}", site_id.peek()),
+            None => base_url.to_string(),
+        }
+    }
+}
 // PaymentsResponse
 //TODO: Append the remaining status flags
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Copy)]
@@ -250,9 +290,27 @@ pub struct RecurlyErrorResponse {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RecurlyWebhookBody {
-    // Transaction uuid
-    pub uuid: String,
-    pub event_type: RecurlyPaymentEventType,
+    #[serde(flatten)]
+    pub object: RecurlyWebhookObject,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub event_time: Option<PrimitiveDateTime>,
+}
+
+// The event types are scoped to the type of the object of the webhook, ex: `failed` is sent for
+// both the payments and the invoices
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "object_type", rename_all = "snake_case")]
+pub enum RecurlyWebhookObject {
+    Payment {
+        // Transaction uuid
+        uuid: String,
+        event_type: RecurlyPaymentEventType,
+    },
+    ChargeInvoice {
+        // Invoice id
+        id: String,
+        event_type: RecurlyInvoiceEventType,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy)]
@@ -263,6 +321,51 @@ pub enum RecurlyPaymentEventType {
     PaymentFailed,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RecurlyInvoiceEventType {
+    Voided,
+    // The other events of the invoice, ex: created, past_due, paid, are not consumed as the
+    // payments of the invoice are sent by the payment webhooks
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl RecurlyWebhookObject {
+    pub fn get_object_reference_id(&self) -> api_models::webhooks::ObjectReferenceId {
+        match self {
+            Self::Payment { uuid, .. } => api_models::webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(uuid.clone()),
+            ),
+            Self::ChargeInvoice { id, .. } => api_models::webhooks::ObjectReferenceId::InvoiceId(
+                api_models::webhooks::InvoiceIdType::ConnectorInvoiceId(id.clone()),
+            ),
+        }
+    }
+
+    pub fn get_event_type(&self) -> api_models::webhooks::IncomingWebhookEvent {
+        match self {
+            Self::Payment {
+                event_type: RecurlyPaymentEventType::PaymentSucceeded,
+                ..
+            } => api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess,
+            Self::Payment {
+                event_type: RecurlyPaymentEventType::PaymentFailed,
+                ..
+            } => api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentFailure,
+            Self::ChargeInvoice {
+                event_type: RecurlyInvoiceEventType::Voided,
+                ..
+            } => api_models::webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel,
+            Self::ChargeInvoice {
+                event_type: RecurlyInvoiceEventType::Other,
+                ..
+            } => api_models::webhooks::IncomingWebhookEvent::EventNotSupported,
+        }
+    }
+}
+
 impl RecurlyWebhookBody {
     pub fn get_webhook_object_from_body(body: &[u8]) -> CustomResult<Self, errors::ConnectorError> {
         let webhook_body = body
@@ -292,6 +395,23 @@ pub enum RecurlyPaymentObject {
     CreditCard,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum RecurlyCardType {
+    Visa,
+    MasterCard,
+    #[serde(rename = "American Express")]
+    AmericanExpress,
+    Discover,
+    #[serde(rename = "Diners Club")]
+    DinersClub,
+    #[serde(rename = "JCB")]
+    Jcb,
+    #[serde(rename = "Union Pay")]
+    UnionPay,
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RecurlyRecoveryDetailsData {
     pub amount: FloatMajorUnit,
@@ -306,6 +426,12 @@ pub struct RecurlyRecoveryDetailsData {
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
     pub status: RecurlyChargeStatus,
+    pub gateway_response_code: Option<String>,
+    pub gateway_message: Option<String>,
+    pub avs_check: Option<String>,
+    pub cvv_check: Option<String>,
+    #[serde(default)]
+    pub subscription_ids: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -313,6 +439,10 @@ pub struct PaymentMethod {
     pub gateway_token: String,
     pub funding_source: RecurlyFundingTypes,
     pub object: RecurlyPaymentObject,
+    pub card_type: Option<RecurlyCardType>,
+    pub last_four: Option<String>,
+    pub exp_month: Option<u8>,
+    pub exp_year: Option<u16>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -330,6 +460,88 @@ pub struct PaymentGateway {
     pub id: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurlyInvoiceTransactionsData {
+    pub data: Vec<RecurlyRecoveryDetailsData>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurlyInvoiceData {
+    pub id: String,
+    pub currency: common_enums::Currency,
+    pub total: FloatMajorUnit,
+    pub account: RecurlyInvoiceAccount,
+    #[serde(default)]
+    pub subscription_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecurlyInvoiceAccount {
+    pub id: String,
+    pub email: Option<pii::Email>,
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl TryFrom<RecurlyRecoveryDetailsData>
+    for recovery_response_types::BillingConnectorPaymentsSyncResponse
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: RecurlyRecoveryDetailsData) -> Result<Self, Self::Error> {
+        let merchant_reference_id = id_type::PaymentReferenceId::from_str(&item.invoice.id)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let connector_transaction_id = Some(ConnectorTransactionId::from(item.id));
+        let card_details = common_types::payments::RevenueRecoveryCardDetails {
+            card_network: item
+                .payment_method
+                .card_type
+                .and_then(Option::<common_enums::CardNetwork>::from),
+            last4: item.payment_method.last_four,
+            card_exp_month: item.payment_method.exp_month,
+            card_exp_year: item.payment_method.exp_year,
+            card_funding: Some(common_enums::PaymentMethodType::from(
+                item.payment_method.funding_source,
+            )),
+        };
+
+        Ok(Self {
+            status: item.status.into(),
+            amount: utils::convert_back_amount_to_minor_units(
+                &FloatMajorUnitForConnector,
+                item.amount,
+                item.currency,
+            )?,
+            currency: item.currency,
+            merchant_reference_id,
+            connector_account_reference_id: item.payment_gateway.id,
+            connector_transaction_id,
+            error_code: item.status_code,
+            error_message: item.status_message,
+            processor_payment_method_token: item.payment_method.gateway_token,
+            connector_customer_id: item.account.id,
+            transaction_created_at: Some(item.created_at),
+            payment_method_sub_type: common_enums::PaymentMethodType::from(
+                item.payment_method.funding_source,
+            ),
+            payment_method_type: common_enums::PaymentMethod::from(item.payment_method.object),
+            network_advice_code: None,
+            network_decline_code: None,
+            network_error_message: None,
+            processor_response_details: Some(common_types::payments::ProcessorResponseDetails {
+                response_code: item.gateway_response_code,
+                response_message: item.gateway_message,
+                avs_result: item.avs_check,
+                cvv_result: item.cvv_check,
+            }),
+            card_issuing_country: None,
+            card_details: Some(card_details),
+            // An invoice can belong to multiple subscriptions in case of consolidated billing,
+            // the first subscription is used as the reference for the invoice
+            billing_connector_subscription_id: item.subscription_ids.into_iter().next(),
+            invoice_transactions: Vec::new(),
+        })
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 impl
     TryFrom<
@@ -350,35 +562,105 @@ impl
             recovery_response_types::BillingConnectorPaymentsSyncResponse,
         >,
     ) -> Result<Self, Self::Error> {
-        let merchant_reference_id =
-            id_type::PaymentReferenceId::from_str(&item.response.invoice.id)
-                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
-        let connector_transaction_id = Some(ConnectorTransactionId::from(item.response.id));
+        Ok(Self {
+            response: Ok(
+                recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from(
+                    item.response,
+                )?,
+            ),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    TryFrom<
+        ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            RecurlyInvoiceTransactionsData,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    > for recovery_router_data_types::BillingConnectorPaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            recovery_router_flows::BillingConnectorPaymentsSync,
+            RecurlyInvoiceTransactionsData,
+            recovery_request_types::BillingConnectorPaymentsSyncRequest,
+            recovery_response_types::BillingConnectorPaymentsSyncResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let mut transactions = item
+            .response
+            .data
+            .into_iter()
+            .map(recovery_response_types::BillingConnectorPaymentsSyncResponse::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        // The latest transaction of the invoice is returned, the rest are sent along with it
+        transactions.sort_by_key(|transaction| transaction.transaction_created_at);
+        let latest_transaction = transactions
+            .pop()
+            .ok_or(errors::ConnectorError::MissingRequiredField { field_name: "data" })?;
 
         Ok(Self {
             response: Ok(
                 recovery_response_types::BillingConnectorPaymentsSyncResponse {
-                    status: item.response.status.into(),
+                    invoice_transactions: transactions,
+                    ..latest_transaction
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl
+    TryFrom<
+        ResponseRouterData<
+            recovery_router_flows::BillingConnectorInvoiceSync,
+            RecurlyInvoiceData,
+            recovery_request_types::BillingConnectorInvoiceSyncRequest,
+            recovery_response_types::BillingConnectorInvoiceSyncResponse,
+        >,
+    > for recovery_router_data_types::BillingConnectorInvoiceSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            recovery_router_flows::BillingConnectorInvoiceSync,
+            RecurlyInvoiceData,
+            recovery_request_types::BillingConnectorInvoiceSyncRequest,
+            recovery_response_types::BillingConnectorInvoiceSyncResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let invoice = item.response;
+        let merchant_reference_id = id_type::PaymentReferenceId::from_str(&invoice.id)
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(Self {
+            response: Ok(
+                recovery_response_types::BillingConnectorInvoiceSyncResponse {
                     amount: utils::convert_back_amount_to_minor_units(
                         &FloatMajorUnitForConnector,
-                        item.response.amount,
-                        item.response.currency,
+                        invoice.total,
+                        invoice.currency,
                     )?,
-                    currency: item.response.currency,
+                    currency: invoice.currency,
                     merchant_reference_id,
-                    connector_account_reference_id: item.response.payment_gateway.id,
-                    connector_transaction_id,
-                    error_code: item.response.status_code,
-                    error_message: item.response.status_message,
-                    processor_payment_method_token: item.response.payment_method.gateway_token,
-                    connector_customer_id: item.response.account.id,
-                    transaction_created_at: Some(item.response.created_at),
-                    payment_method_sub_type: common_enums::PaymentMethodType::from(
-                        item.response.payment_method.funding_source,
-                    ),
-                    payment_method_type: common_enums::PaymentMethod::from(
-                        item.response.payment_method.object,
+                    customer_details: Some(
+                        recovery_response_types::BillingConnectorInvoiceCustomerDetails {
+                            billing_connector_customer_id: invoice.account.id,
+                            email: invoice.account.email,
+                            locale: None,
+                        },
                     ),
+                    // An invoice can belong to multiple subscriptions in case of consolidated
+                    // billing, the first subscription is used as the reference for the invoice
+                    billing_connector_subscription_id: invoice.subscription_ids.into_iter().next(),
                 },
             ),
             ..item.data
@@ -405,6 +687,21 @@ impl From<RecurlyFundingTypes> for common_enums::PaymentMethodType {
     }
 }
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl From<RecurlyCardType> for Option<common_enums::CardNetwork> {
+    fn from(card_type: RecurlyCardType) -> Self {
+        match card_type {
+            RecurlyCardType::Visa => Some(common_enums::CardNetwork::Visa),
+            RecurlyCardType::MasterCard => Some(common_enums::CardNetwork::Mastercard),
+            RecurlyCardType::AmericanExpress => Some(common_enums::CardNetwork::AmericanExpress),
+            RecurlyCardType::Discover => Some(common_enums::CardNetwork::Discover),
+            RecurlyCardType::DinersClub => Some(common_enums::CardNetwork::DinersClub),
+            RecurlyCardType::Jcb => Some(common_enums::CardNetwork::JCB),
+            RecurlyCardType::UnionPay => Some(common_enums::CardNetwork::UnionPay),
+            RecurlyCardType::Unknown => None,
+        }
+    }
+}
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 impl From<RecurlyPaymentObject> for common_enums::PaymentMethod {
     fn from(funding: RecurlyPaymentObject) -> Self {
         match funding {
@@ -494,3 +791,76 @@ impl
         })
     }
 }
+
+#[cfg(all(test, feature = "revenue_recovery", feature = "v2"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_payment_webhook() {
+        let webhook = RecurlyWebhookBody::get_webhook_object_from_body(
+            br#"{
+                "id": "rmbqcgrtwsdp",
+                "object_type": "payment",
+                "site_id": "hyperswitch",
+                "event_type": "failed",
+                "event_time": "2025-04-21T11:29:00Z",
+                "uuid": "7b6a2f3e1c8d4e5f9a0b1c2d3e4f5a6b",
+                "account_code": "cust_12345"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            webhook.object.get_event_type(),
+            api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentFailure
+        );
+        assert!(matches!(
+            webhook.object.get_object_reference_id(),
+            api_models::webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(uuid)
+            ) if uuid == "7b6a2f3e1c8d4e5f9a0b1c2d3e4f5a6b"
+        ));
+        assert!(webhook.event_time.is_some());
+    }
+
+    #[test]
+    fn test_invoice_webhooks() {
+        let get_invoice_webhook = |event_type: &str| {
+            RecurlyWebhookBody::get_webhook_object_from_body(
+                format!(
+                    r#"{{
+                        "id": "ow6mnd7e1xz4",
+                        "object_type": "charge_invoice",
+                        "site_id": "hyperswitch",
+                        "event_type": "{event_type}",
+                        "event_time": "2025-04-21T11:29:00Z"
+                    }}"#
+                )
+                .as_bytes(),
+            )
+            .unwrap()
+        };
+
+        // `failed` of an invoice is not the failure of its payment
+        let webhook = get_invoice_webhook("failed");
+        assert_eq!(
+            webhook.object.get_event_type(),
+            api_models::webhooks::IncomingWebhookEvent::EventNotSupported
+        );
+
+        let webhook = get_invoice_webhook("voided");
+        assert_eq!(
+            webhook.object.get_event_type(),
+            api_models::webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel
+        );
+        assert!(matches!(
+            webhook.object.get_object_reference_id(),
+            api_models::webhooks::ObjectReferenceId::InvoiceId(
+                api_models::webhooks::InvoiceIdType::ConnectorInvoiceId(invoice_id)
+            ) if invoice_id == "ow6mnd7e1xz4"
+        ));
+    }
+}
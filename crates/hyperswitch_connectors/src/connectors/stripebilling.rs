@@ -598,11 +598,18 @@ impl
         req: &recovery_router_data_types::BillingConnectorPaymentsSyncRouterData,
         connectors: &Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!(
-            "{}v1/charges/{}",
-            self.base_url(connectors),
-            req.request.billing_connector_psync_id
-        ))
+        match &req.request.billing_connector_psync_id {
+            recovery_request_types::BillingConnectorPaymentsSyncId::TransactionId(charge_id) => Ok(
+                format!("{}v1/charges/{charge_id}", self.base_url(connectors)),
+            ),
+            recovery_request_types::BillingConnectorPaymentsSyncId::InvoiceId(_) => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Billing connector payments sync using invoice id".to_string(),
+                    connector: "stripebilling",
+                }
+                .into())
+            }
+        }
     }
 
     fn build_request(
@@ -803,12 +810,21 @@ impl webhooks::IncomingWebhook for Stripebilling {
         request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
         //  For Stripe billing, we need an additional call to fetch the required recovery data. So, instead of the Invoice ID, we send the Charge ID.
+        // The invoices voided before any attempt have no charge, so they are referred to by the Invoice ID.
         let webhook =
             stripebilling::StripebillingWebhookBody::get_webhook_object_from_body(request.body)
                 .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
-        Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
-            api_models::payments::PaymentIdType::ConnectorTransactionId(webhook.data.object.charge),
-        ))
+        let object_reference_id = match webhook.data.object.charge {
+            Some(charge_id) => api_models::webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(charge_id),
+            ),
+            None => api_models::webhooks::ObjectReferenceId::InvoiceId(
+                api_models::webhooks::InvoiceIdType::ConnectorInvoiceId(
+                    webhook.data.object.invoice_id,
+                ),
+            ),
+        };
+        Ok(object_reference_id)
     }
 
     #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
@@ -828,18 +844,9 @@ impl webhooks::IncomingWebhook for Stripebilling {
             stripebilling::StripebillingWebhookBody::get_webhook_object_from_body(request.body)
                 .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
 
-        let event = match webhook.event_type {
-            stripebilling::StripebillingEventType::PaymentSucceeded => {
-                api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess
-            }
-            stripebilling::StripebillingEventType::PaymentFailed => {
-                api_models::webhooks::IncomingWebhookEvent::RecoveryPaymentFailure
-            }
-            stripebilling::StripebillingEventType::InvoiceDeleted => {
-                api_models::webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel
-            }
-        };
-        Ok(event)
+        Ok(api_models::webhooks::IncomingWebhookEvent::from(
+            webhook.event_type,
+        ))
     }
 
     #[cfg(any(feature = "v1", not(all(feature = "revenue_recovery", feature = "v2"))))]
@@ -873,10 +880,14 @@ impl webhooks::IncomingWebhook for Stripebilling {
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     fn get_revenue_recovery_attempt_details(
         &self,
-        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<revenue_recovery::RevenueRecoveryAttemptData, errors::ConnectorError> {
-        // since stripe requires an additional call we dont need to implement this function because we get the recovery data from additional call itself
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        // The recovery data is fetched from the charge by the payments sync, the invoice of the
+        // webhook is used when the payments sync is not required for the account, or the billing
+        // connector is unavailable
+        let webhook =
+            stripebilling::StripebillingWebhookBody::get_webhook_object_from_body(request.body)?;
+        revenue_recovery::RevenueRecoveryAttemptData::try_from(webhook)
     }
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     fn get_revenue_recovery_invoice_details(
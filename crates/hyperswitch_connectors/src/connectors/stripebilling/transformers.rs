@@ -4,7 +4,7 @@ use std::str::FromStr;
 use common_enums::enums;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 use common_utils::id_type;
-use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt, types::StringMinorUnit};
+use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt, pii, types::StringMinorUnit};
 use error_stack::ResultExt;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 use hyperswitch_domain_models::revenue_recovery;
@@ -255,6 +255,8 @@ pub struct StripebillingErrorResponse {
 pub struct StripebillingWebhookBody {
     #[serde(rename = "type")]
     pub event_type: StripebillingEventType,
+    #[serde(default, with = "common_utils::custom_serde::timestamp::option")]
+    pub created: Option<PrimitiveDateTime>,
     pub data: StripebillingWebhookData,
 }
 
@@ -276,6 +278,17 @@ pub enum StripebillingEventType {
     InvoiceDeleted,
 }
 
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+impl From<StripebillingEventType> for api_models::webhooks::IncomingWebhookEvent {
+    fn from(event: StripebillingEventType) -> Self {
+        match event {
+            StripebillingEventType::PaymentSucceeded => Self::RecoveryPaymentSuccess,
+            StripebillingEventType::PaymentFailed => Self::RecoveryPaymentFailure,
+            StripebillingEventType::InvoiceDeleted => Self::RecoveryInvoiceCancel,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct StripebillingWebhookData {
     pub object: StripebillingWebhookObject,
@@ -293,9 +306,32 @@ pub struct StripebillingWebhookObject {
     #[serde(deserialize_with = "convert_uppercase")]
     pub currency: enums::Currency,
     pub customer: String,
+    pub customer_email: Option<pii::Email>,
     #[serde(rename = "amount_remaining")]
     pub amount: common_utils::types::MinorUnit,
-    pub charge: String,
+    /// Amount attempted to be collected for the invoice, which is not reduced by the payment
+    pub amount_due: Option<common_utils::types::MinorUnit>,
+    /// Latest charge of the invoice, not present for the invoices voided before any attempt
+    pub charge: Option<String>,
+    pub subscription: Option<String>,
+    pub default_payment_method: Option<String>,
+    pub last_finalization_error: Option<StripebillingInvoiceError>,
+}
+
+/// Error of the last attempt of the invoice, as shared by Stripe in the invoice
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StripebillingInvoiceError {
+    pub code: Option<String>,
+    pub decline_code: Option<String>,
+    pub message: Option<String>,
+    pub network_advice_code: Option<String>,
+    pub network_decline_code: Option<String>,
+    pub payment_method: Option<StripebillingInvoiceErrorPaymentMethod>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StripebillingInvoiceErrorPaymentMethod {
+    pub id: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -336,10 +372,88 @@ impl TryFrom<StripebillingInvoiceBody> for revenue_recovery::RevenueRecoveryInvo
         let merchant_reference_id =
             id_type::PaymentReferenceId::from_str(&item.data.object.invoice_id)
                 .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        // Stripe does not share the preferred locale of the customer in the invoice
+        let customer_details = revenue_recovery::RevenueRecoveryCustomerDetails {
+            billing_connector_customer_id: item.data.object.customer,
+            email: item.data.object.customer_email,
+            locale: None,
+        };
         Ok(Self {
             amount: item.data.object.amount,
             currency: item.data.object.currency,
             merchant_reference_id,
+            customer_details: Some(customer_details),
+            billing_connector_subscription_id: item.data.object.subscription,
+        })
+    }
+}
+
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+impl TryFrom<StripebillingWebhookBody> for revenue_recovery::RevenueRecoveryAttemptData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: StripebillingWebhookBody) -> Result<Self, Self::Error> {
+        let status = match item.event_type {
+            StripebillingEventType::PaymentSucceeded => enums::AttemptStatus::Charged,
+            StripebillingEventType::PaymentFailed => enums::AttemptStatus::Failure,
+            StripebillingEventType::InvoiceDeleted => {
+                return Err(errors::ConnectorError::WebhookEventTypeNotFound)
+                    .attach_printable("Voided invoice does not carry a transaction");
+            }
+        };
+        let invoice = item.data.object;
+        let merchant_reference_id = id_type::PaymentReferenceId::from_str(&invoice.invoice_id)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let connector_transaction_id = invoice
+            .charge
+            .map(common_utils::types::ConnectorTransactionId::from)
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "charge",
+            })?;
+        let error = invoice.last_finalization_error;
+        // The payment method of the failed attempt is used when the invoice has no default
+        // payment method of its own
+        let processor_payment_method_token = invoice
+            .default_payment_method
+            .or(error
+                .as_ref()
+                .and_then(|error| error.payment_method.as_ref())
+                .map(|payment_method| payment_method.id.clone()))
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "default_payment_method",
+            })?;
+        let (error_code, error_message, network_advice_code, network_decline_code) = match error {
+            Some(error) => (
+                error.decline_code.or(error.code),
+                error.message,
+                error.network_advice_code,
+                error.network_decline_code,
+            ),
+            None => (None, None, None, None),
+        };
+
+        Ok(Self {
+            amount: invoice.amount_due.unwrap_or(invoice.amount),
+            currency: invoice.currency,
+            merchant_reference_id,
+            connector_transaction_id: Some(connector_transaction_id),
+            error_code,
+            error_message,
+            processor_payment_method_token,
+            connector_customer_id: invoice.customer,
+            connector_account_reference_id:
+                MCA_ID_IDENTIFIER_FOR_STRIPE_IN_STRIPEBILLING_MCA_FEAATURE_METADATA.to_string(),
+            transaction_created_at: item.created,
+            status,
+            payment_method_type: enums::PaymentMethod::Card,
+            // The funding of the card is not shared in the invoice, it is known only from the
+            // charge of the invoice
+            payment_method_sub_type: enums::PaymentMethodType::Card,
+            network_advice_code,
+            network_decline_code,
+            network_error_message: None,
+            processor_response_details: None,
+            card_issuing_country: None,
+            card_details: None,
         })
     }
 }
@@ -361,6 +475,15 @@ pub struct StripebillingRecoveryDetailsData {
     pub payment_method_details: StripePaymentMethodDetails,
     #[serde(rename = "invoice")]
     pub invoice_id: String,
+    pub outcome: Option<StripebillingChargeOutcome>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StripebillingChargeOutcome {
+    pub network_advice_code: Option<String>,
+    pub network_decline_code: Option<String>,
+    pub reason: Option<String>,
+    pub seller_message: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -380,6 +503,34 @@ pub enum StripebillingPaymentMethod {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StripeCardFundingTypeDetails {
     pub funding: StripebillingFundingTypes,
+    pub checks: Option<StripebillingCardChecks>,
+    /// Two-letter ISO code of the country of the card issuer
+    pub country: Option<String>,
+    pub brand: Option<StripebillingCardBrand>,
+    pub last4: Option<String>,
+    pub exp_month: Option<u8>,
+    pub exp_year: Option<u16>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum StripebillingCardBrand {
+    Amex,
+    Diners,
+    Discover,
+    Jcb,
+    Mastercard,
+    Unionpay,
+    Visa,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StripebillingCardChecks {
+    pub address_line1_check: Option<String>,
+    pub address_postal_code_check: Option<String>,
+    pub cvc_check: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy)]
@@ -433,6 +584,56 @@ impl
         let connector_transaction_id = Some(common_utils::types::ConnectorTransactionId::from(
             item.response.charge_id,
         ));
+        let processor_response_details = common_types::payments::ProcessorResponseDetails {
+            response_code: item
+                .response
+                .outcome
+                .as_ref()
+                .and_then(|outcome| outcome.reason.clone()),
+            response_message: item
+                .response
+                .outcome
+                .as_ref()
+                .and_then(|outcome| outcome.seller_message.clone()),
+            avs_result: item
+                .response
+                .payment_method_details
+                .card_funding_type
+                .checks
+                .as_ref()
+                .and_then(|checks| {
+                    checks
+                        .address_line1_check
+                        .clone()
+                        .or(checks.address_postal_code_check.clone())
+                }),
+            cvv_result: item
+                .response
+                .payment_method_details
+                .card_funding_type
+                .checks
+                .as_ref()
+                .and_then(|checks| checks.cvc_check.clone()),
+        };
+
+        // An unknown country is not a reason to fail the sync, it is only used for scheduling
+        let card_issuing_country = item
+            .response
+            .payment_method_details
+            .card_funding_type
+            .country
+            .as_deref()
+            .and_then(|country| enums::CountryAlpha2::from_str(country).ok());
+        let card = &item.response.payment_method_details.card_funding_type;
+        let card_details = common_types::payments::RevenueRecoveryCardDetails {
+            card_network: card
+                .brand
+                .and_then(Option::<common_enums::CardNetwork>::from),
+            last4: card.last4.clone(),
+            card_exp_month: card.exp_month,
+            card_exp_year: card.exp_year,
+            card_funding: Some(common_enums::PaymentMethodType::from(card.funding)),
+        };
 
         Ok(Self {
             response: Ok(
@@ -459,6 +660,22 @@ impl
                     payment_method_type: common_enums::PaymentMethod::from(
                         item.response.payment_method_details.type_of_payment_method,
                     ),
+                    network_advice_code: item
+                        .response
+                        .outcome
+                        .as_ref()
+                        .and_then(|outcome| outcome.network_advice_code.clone()),
+                    network_decline_code: item
+                        .response
+                        .outcome
+                        .and_then(|outcome| outcome.network_decline_code),
+                    network_error_message: None,
+                    processor_response_details: Some(processor_response_details),
+                    card_issuing_country,
+                    card_details: Some(card_details),
+                    // The charge object does not refer to the subscription of the invoice
+                    billing_connector_subscription_id: None,
+                    invoice_transactions: Vec::new(),
                 },
             ),
             ..item.data
@@ -466,6 +683,22 @@ impl
     }
 }
 
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+impl From<StripebillingCardBrand> for Option<common_enums::CardNetwork> {
+    fn from(brand: StripebillingCardBrand) -> Self {
+        match brand {
+            StripebillingCardBrand::Amex => Some(common_enums::CardNetwork::AmericanExpress),
+            StripebillingCardBrand::Diners => Some(common_enums::CardNetwork::DinersClub),
+            StripebillingCardBrand::Discover => Some(common_enums::CardNetwork::Discover),
+            StripebillingCardBrand::Jcb => Some(common_enums::CardNetwork::JCB),
+            StripebillingCardBrand::Mastercard => Some(common_enums::CardNetwork::Mastercard),
+            StripebillingCardBrand::Unionpay => Some(common_enums::CardNetwork::UnionPay),
+            StripebillingCardBrand::Visa => Some(common_enums::CardNetwork::Visa),
+            StripebillingCardBrand::Unknown => None,
+        }
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 impl From<StripebillingChargeStatus> for enums::AttemptStatus {
     fn from(status: StripebillingChargeStatus) -> Self {
@@ -533,3 +766,166 @@ impl
         })
     }
 }
+
+#[cfg(all(test, feature = "revenue_recovery", feature = "v2"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::webhooks::IncomingWebhookEvent;
+
+    use super::*;
+
+    const INVOICE_PAYMENT_FAILED_WEBHOOK_BODY: &str = r#"{
+        "id": "evt_1Nb2Xy2eZvKYlo2C",
+        "type": "invoice.payment_failed",
+        "created": 1690000000,
+        "data": {
+            "object": {
+                "id": "in_1Nb2Xy2eZvKYlo2C",
+                "currency": "usd",
+                "customer": "cus_OO3nHgf0ZRC4Ac",
+                "customer_email": "jenny.rosen@example.com",
+                "amount_due": 2000,
+                "amount_remaining": 2000,
+                "charge": "ch_3Nb2Xz2eZvKYlo2C",
+                "subscription": "sub_1Nb2Xy2eZvKYlo2C",
+                "default_payment_method": null,
+                "last_finalization_error": {
+                    "code": "card_declined",
+                    "decline_code": "insufficient_funds",
+                    "message": "Your card has insufficient funds.",
+                    "network_advice_code": "01",
+                    "network_decline_code": "51",
+                    "payment_method": { "id": "pm_1Nb2Xw2eZvKYlo2C" }
+                }
+            }
+        }
+    }"#;
+
+    const INVOICE_PAID_WEBHOOK_BODY: &str = r#"{
+        "id": "evt_1Nb3Ab2eZvKYlo2C",
+        "type": "invoice.paid",
+        "created": 1690086400,
+        "data": {
+            "object": {
+                "id": "in_1Nb2Xy2eZvKYlo2C",
+                "currency": "usd",
+                "customer": "cus_OO3nHgf0ZRC4Ac",
+                "customer_email": "jenny.rosen@example.com",
+                "amount_due": 2000,
+                "amount_remaining": 0,
+                "charge": "ch_3Nb3Ac2eZvKYlo2C",
+                "subscription": "sub_1Nb2Xy2eZvKYlo2C",
+                "default_payment_method": "pm_1Nb3Aa2eZvKYlo2C",
+                "last_finalization_error": null
+            }
+        }
+    }"#;
+
+    const INVOICE_VOIDED_WEBHOOK_BODY: &str = r#"{
+        "id": "evt_1Nb4Cd2eZvKYlo2C",
+        "type": "invoice.voided",
+        "created": 1690172800,
+        "data": {
+            "object": {
+                "id": "in_1Nb2Xy2eZvKYlo2C",
+                "currency": "usd",
+                "customer": "cus_OO3nHgf0ZRC4Ac",
+                "customer_email": null,
+                "amount_due": 2000,
+                "amount_remaining": 2000,
+                "charge": null,
+                "subscription": "sub_1Nb2Xy2eZvKYlo2C"
+            }
+        }
+    }"#;
+
+    fn get_webhook(body: &str) -> StripebillingWebhookBody {
+        StripebillingWebhookBody::get_webhook_object_from_body(body.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_invoice_payment_failed_webhook() {
+        let webhook = get_webhook(INVOICE_PAYMENT_FAILED_WEBHOOK_BODY);
+        let event = IncomingWebhookEvent::from(webhook.event_type);
+        assert_eq!(event, IncomingWebhookEvent::RecoveryPaymentFailure);
+        assert!(event.is_recovery_transaction_event());
+
+        let attempt = revenue_recovery::RevenueRecoveryAttemptData::try_from(webhook).unwrap();
+        assert_eq!(attempt.status, enums::AttemptStatus::Failure);
+        assert_eq!(
+            attempt.connector_transaction_id,
+            Some(common_utils::types::ConnectorTransactionId::from(
+                "ch_3Nb2Xz2eZvKYlo2C".to_string()
+            ))
+        );
+        // The invoice has no default payment method, so the one of the failed attempt is used
+        assert_eq!(
+            attempt.processor_payment_method_token,
+            "pm_1Nb2Xw2eZvKYlo2C"
+        );
+        assert_eq!(attempt.connector_customer_id, "cus_OO3nHgf0ZRC4Ac");
+        assert_eq!(attempt.error_code.as_deref(), Some("insufficient_funds"));
+        assert_eq!(
+            attempt.error_message.as_deref(),
+            Some("Your card has insufficient funds.")
+        );
+        assert_eq!(attempt.network_advice_code.as_deref(), Some("01"));
+        assert_eq!(attempt.network_decline_code.as_deref(), Some("51"));
+        assert_eq!(attempt.amount, common_utils::types::MinorUnit::new(2000));
+        assert!(attempt.transaction_created_at.is_some());
+
+        let invoice = revenue_recovery::RevenueRecoveryInvoiceData::try_from(
+            StripebillingInvoiceBody::get_invoice_webhook_data_from_body(
+                INVOICE_PAYMENT_FAILED_WEBHOOK_BODY.as_bytes(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(invoice.amount, common_utils::types::MinorUnit::new(2000));
+        assert_eq!(
+            invoice.billing_connector_subscription_id.as_deref(),
+            Some("sub_1Nb2Xy2eZvKYlo2C")
+        );
+    }
+
+    #[test]
+    fn test_invoice_paid_webhook() {
+        let webhook = get_webhook(INVOICE_PAID_WEBHOOK_BODY);
+        let event = IncomingWebhookEvent::from(webhook.event_type);
+        assert_eq!(event, IncomingWebhookEvent::RecoveryPaymentSuccess);
+        assert!(event.is_recovery_transaction_event());
+
+        let attempt = revenue_recovery::RevenueRecoveryAttemptData::try_from(webhook).unwrap();
+        assert_eq!(attempt.status, enums::AttemptStatus::Charged);
+        // The amount remaining of a paid invoice is zero, the amount due is the amount collected
+        assert_eq!(attempt.amount, common_utils::types::MinorUnit::new(2000));
+        assert_eq!(
+            attempt.processor_payment_method_token,
+            "pm_1Nb3Aa2eZvKYlo2C"
+        );
+        assert!(attempt.error_code.is_none());
+        assert!(attempt.network_decline_code.is_none());
+    }
+
+    #[test]
+    fn test_invoice_voided_webhook() {
+        let webhook = get_webhook(INVOICE_VOIDED_WEBHOOK_BODY);
+        let event = IncomingWebhookEvent::from(webhook.event_type);
+        assert_eq!(event, IncomingWebhookEvent::RecoveryInvoiceCancel);
+        assert!(!event.is_recovery_transaction_event());
+        assert!(webhook.data.object.charge.is_none());
+        assert!(revenue_recovery::RevenueRecoveryAttemptData::try_from(webhook).is_err());
+
+        let invoice = revenue_recovery::RevenueRecoveryInvoiceData::try_from(
+            StripebillingInvoiceBody::get_invoice_webhook_data_from_body(
+                INVOICE_VOIDED_WEBHOOK_BODY.as_bytes(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            invoice.merchant_reference_id.get_string_repr(),
+            "in_1Nb2Xy2eZvKYlo2C"
+        );
+    }
+}
@@ -139,6 +139,7 @@ default_imp_for_authorize_session_token!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -238,6 +239,7 @@ default_imp_for_calculate_tax!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -337,6 +339,7 @@ default_imp_for_session_update!(
     connectors::Datatrans,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -437,6 +440,7 @@ default_imp_for_post_session_tokens!(
     connectors::Datatrans,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -532,6 +536,7 @@ default_imp_for_complete_authorize!(
     connectors::Cryptopay,
     connectors::Datatrans,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -620,6 +625,7 @@ default_imp_for_incremental_authorization!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -721,6 +727,7 @@ default_imp_for_create_customer!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -816,6 +823,7 @@ default_imp_for_connector_redirect_response!(
     connectors::Datatrans,
     connectors::Deutschebank,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -902,6 +910,7 @@ default_imp_for_pre_processing_steps!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -995,6 +1004,7 @@ default_imp_for_post_processing_steps!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1097,6 +1107,7 @@ default_imp_for_approve!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1199,6 +1210,7 @@ default_imp_for_reject!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1301,6 +1313,7 @@ default_imp_for_webhook_source_verification!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1401,6 +1414,7 @@ default_imp_for_accept_dispute!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1501,6 +1515,7 @@ default_imp_for_submit_evidence!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1601,6 +1616,7 @@ default_imp_for_defend_dispute!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1710,6 +1726,7 @@ default_imp_for_file_upload!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1803,6 +1820,7 @@ default_imp_for_payouts!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1903,6 +1921,7 @@ default_imp_for_payouts_create!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2005,6 +2024,7 @@ default_imp_for_payouts_retrieve!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2106,6 +2126,7 @@ default_imp_for_payouts_eligibility!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2207,6 +2228,7 @@ default_imp_for_payouts_fulfill!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2308,6 +2330,7 @@ default_imp_for_payouts_cancel!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2411,6 +2434,7 @@ default_imp_for_payouts_quote!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2514,6 +2538,7 @@ default_imp_for_payouts_recipient!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2617,6 +2642,7 @@ default_imp_for_payouts_recipient_account!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2720,6 +2746,7 @@ default_imp_for_frm_sale!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2824,6 +2851,7 @@ default_imp_for_frm_checkout!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2928,6 +2956,7 @@ default_imp_for_frm_transaction!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3032,6 +3061,7 @@ default_imp_for_frm_fulfillment!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3136,6 +3166,7 @@ default_imp_for_frm_record_return!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3235,6 +3266,7 @@ default_imp_for_revoking_mandates!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3336,6 +3368,7 @@ default_imp_for_uas_pre_authentication!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3435,6 +3468,7 @@ default_imp_for_uas_post_authentication!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3535,6 +3569,7 @@ default_imp_for_uas_authentication_confirmation!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3627,6 +3662,7 @@ default_imp_for_connector_request_id!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3721,6 +3757,7 @@ default_imp_for_fraud_check!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3847,6 +3884,7 @@ default_imp_for_connector_authentication!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -3947,6 +3985,7 @@ default_imp_for_uas_authentication!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -4039,6 +4078,7 @@ default_imp_for_revenue_recovery! {
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -4117,6 +4157,106 @@ macro_rules! default_imp_for_billing_connector_payment_sync {
 
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 default_imp_for_billing_connector_payment_sync!(
+    connectors::Aci,
+    connectors::Adyen,
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Authorizedotnet,
+    connectors::Bambora,
+    connectors::Bamboraapac,
+    connectors::Bankofamerica,
+    connectors::Billwerk,
+    connectors::Bluesnap,
+    connectors::Bitpay,
+    connectors::Braintree,
+    connectors::Boku,
+    connectors::Cashtocode,
+    connectors::Checkout,
+    connectors::Coinbase,
+    connectors::Coingate,
+    connectors::Cryptopay,
+    connectors::CtpMastercard,
+    connectors::Cybersource,
+    connectors::Datatrans,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Facilitapay,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Getnet,
+    connectors::Globalpay,
+    connectors::Globepay,
+    connectors::Gocardless,
+    connectors::Helcim,
+    connectors::Hipay,
+    connectors::Iatapay,
+    connectors::Inespay,
+    connectors::Itaubank,
+    connectors::Juspaythreedsserver,
+    connectors::Jpmorgan,
+    connectors::Klarna,
+    connectors::Nomupay,
+    connectors::Noon,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Nuvei,
+    connectors::Opayo,
+    connectors::Opennode,
+    connectors::Payeezy,
+    connectors::Paystack,
+    connectors::Payu,
+    connectors::Paypal,
+    connectors::Powertranz,
+    connectors::Prophetpay,
+    connectors::Mifinity,
+    connectors::Mollie,
+    connectors::Moneris,
+    connectors::Multisafepay,
+    connectors::Paybox,
+    connectors::Payme,
+    connectors::Placetopay,
+    connectors::Rapyd,
+    connectors::Razorpay,
+    connectors::Redsys,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Trustpay,
+    connectors::Tsys,
+    connectors::UnifiedAuthenticationService,
+    connectors::Worldline,
+    connectors::Worldpay,
+    connectors::Wellsfargo,
+    connectors::Volt,
+    connectors::Xendit,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+macro_rules! default_imp_for_billing_connector_invoice_sync {
+    ($($path:ident::$connector:ident),*) => {
+        $(  impl recovery_traits::BillingConnectorInvoiceSyncIntegration for $path::$connector {}
+            impl
+                ConnectorIntegration<
+                recovery_router_flows::BillingConnectorInvoiceSync,
+                recovery_request::BillingConnectorInvoiceSyncRequest,
+                recovery_response::BillingConnectorInvoiceSyncResponse
+            > for $path::$connector
+            {}
+        )*
+    };
+}
+
+#[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+default_imp_for_billing_connector_invoice_sync!(
     connectors::Aci,
     connectors::Adyen,
     connectors::Airwallex,
@@ -4142,6 +4282,7 @@ default_imp_for_billing_connector_payment_sync!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -4187,6 +4328,7 @@ default_imp_for_billing_connector_payment_sync!(
     connectors::Shift4,
     connectors::Stax,
     connectors::Square,
+    connectors::Stripebilling,
     connectors::Taxjar,
     connectors::Thunes,
     connectors::Trustpay,
@@ -2,9 +2,9 @@ use hyperswitch_domain_models::{
     router_data::AccessToken,
     router_data_v2::{
         flow_common_types::{
-            BillingConnectorPaymentsSyncFlowData, DisputesFlowData, MandateRevokeFlowData,
-            PaymentFlowData, RefundFlowData, RevenueRecoveryRecordBackData,
-            WebhookSourceVerifyData,
+            BillingConnectorInvoiceSyncFlowData, BillingConnectorPaymentsSyncFlowData,
+            DisputesFlowData, MandateRevokeFlowData, PaymentFlowData, RefundFlowData,
+            RevenueRecoveryRecordBackData, WebhookSourceVerifyData,
         },
         AccessTokenFlowData, ExternalAuthenticationFlowData, FilesFlowData,
     },
@@ -22,13 +22,18 @@ use hyperswitch_domain_models::{
             SetupMandate, Void,
         },
         refunds::{Execute, RSync},
-        revenue_recovery::{BillingConnectorPaymentsSync, RecoveryRecordBack},
+        revenue_recovery::{
+            BillingConnectorInvoiceSync, BillingConnectorPaymentsSync, RecoveryRecordBack,
+        },
         webhooks::VerifyWebhookSource,
         AccessTokenAuth,
     },
     router_request_types::{
         authentication,
-        revenue_recovery::{BillingConnectorPaymentsSyncRequest, RevenueRecoveryRecordBackRequest},
+        revenue_recovery::{
+            BillingConnectorInvoiceSyncRequest, BillingConnectorPaymentsSyncRequest,
+            RevenueRecoveryRecordBackRequest,
+        },
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
         CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
         MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
@@ -41,7 +46,8 @@ use hyperswitch_domain_models::{
     },
     router_response_types::{
         revenue_recovery::{
-            BillingConnectorPaymentsSyncResponse, RevenueRecoveryRecordBackResponse,
+            BillingConnectorInvoiceSyncResponse, BillingConnectorPaymentsSyncResponse,
+            RevenueRecoveryRecordBackResponse,
         },
         AcceptDisputeResponse, AuthenticationResponseData, DefendDisputeResponse,
         MandateRevokeResponseData, PaymentsResponseData, RefundsResponseData, RetrieveFileResponse,
@@ -96,8 +102,8 @@ use hyperswitch_interfaces::{
         },
         refunds_v2::{RefundExecuteV2, RefundSyncV2, RefundV2},
         revenue_recovery_v2::{
-            BillingConnectorPaymentsSyncIntegrationV2, RevenueRecoveryRecordBackV2,
-            RevenueRecoveryV2,
+            BillingConnectorInvoiceSyncIntegrationV2, BillingConnectorPaymentsSyncIntegrationV2,
+            RevenueRecoveryRecordBackV2, RevenueRecoveryV2,
         },
         ConnectorAccessTokenV2, ConnectorMandateRevokeV2, ConnectorVerifyWebhookSourceV2,
     },
@@ -247,6 +253,7 @@ default_imp_for_new_connector_integration_payment!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -350,6 +357,7 @@ default_imp_for_new_connector_integration_refund!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -448,6 +456,7 @@ default_imp_for_new_connector_integration_connector_access_token!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -551,6 +560,7 @@ default_imp_for_new_connector_integration_accept_dispute!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -653,6 +663,7 @@ default_imp_for_new_connector_integration_submit_evidence!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -756,6 +767,7 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -869,6 +881,7 @@ default_imp_for_new_connector_integration_file_upload!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -974,6 +987,7 @@ default_imp_for_new_connector_integration_payouts_create!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1079,6 +1093,7 @@ default_imp_for_new_connector_integration_payouts_eligibility!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1184,6 +1199,7 @@ default_imp_for_new_connector_integration_payouts_fulfill!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1289,6 +1305,7 @@ default_imp_for_new_connector_integration_payouts_cancel!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1394,6 +1411,7 @@ default_imp_for_new_connector_integration_payouts_quote!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1499,6 +1517,7 @@ default_imp_for_new_connector_integration_payouts_recipient!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1604,6 +1623,7 @@ default_imp_for_new_connector_integration_payouts_sync!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1709,6 +1729,7 @@ default_imp_for_new_connector_integration_payouts_recipient_account!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1812,6 +1833,7 @@ default_imp_for_new_connector_integration_webhook_source_verification!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -1917,6 +1939,7 @@ default_imp_for_new_connector_integration_frm_sale!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2022,6 +2045,7 @@ default_imp_for_new_connector_integration_frm_checkout!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2127,6 +2151,7 @@ default_imp_for_new_connector_integration_frm_transaction!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2232,6 +2257,7 @@ default_imp_for_new_connector_integration_frm_fulfillment!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2337,6 +2363,7 @@ default_imp_for_new_connector_integration_frm_record_return!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2439,6 +2466,7 @@ default_imp_for_new_connector_integration_revoking_mandates!(
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
+    connectors::DummyBillingConnector,
     connectors::Elavon,
     connectors::Facilitapay,
     connectors::Fiserv,
@@ -2676,6 +2704,7 @@ macro_rules! default_imp_for_new_connector_integration_revenue_recovery {
     ($($path:ident::$connector:ident),*) => {
         $(  impl RevenueRecoveryV2 for $path::$connector {}
             impl BillingConnectorPaymentsSyncIntegrationV2 for $path::$connector {}
+            impl BillingConnectorInvoiceSyncIntegrationV2 for $path::$connector {}
             impl RevenueRecoveryRecordBackV2 for $path::$connector {}
             impl
             ConnectorIntegrationV2<
@@ -2693,6 +2722,14 @@ macro_rules! default_imp_for_new_connector_integration_revenue_recovery {
                 BillingConnectorPaymentsSyncResponse,
             > for $path::$connector
             {}
+            impl
+                ConnectorIntegrationV2<
+                BillingConnectorInvoiceSync,
+                BillingConnectorInvoiceSyncFlowData,
+                BillingConnectorInvoiceSyncRequest,
+                BillingConnectorInvoiceSyncResponse,
+            > for $path::$connector
+            {}
     )*
     };
 }
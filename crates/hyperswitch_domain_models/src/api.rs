@@ -16,10 +16,37 @@ pub enum ApplicationResponse<R> {
     Form(Box<RedirectionFormData>),
     PaymentLinkForm(Box<PaymentLinkAction>),
     FileData((Vec<u8>, mime::Mime)),
+    FileStream(Box<FileStreamData>),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
 }
 
+/// A file streamed to the client in chunks, so that the file is never held in memory in full
+pub struct FileStreamData {
+    pub content_type: mime::Mime,
+    /// The name under which the client is suggested to save the file
+    pub file_name: String,
+    pub stream: futures::stream::BoxStream<'static, Result<actix_web::web::Bytes, std::io::Error>>,
+}
+
+impl std::fmt::Debug for FileStreamData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStreamData")
+            .field("content_type", &self.content_type)
+            .field("file_name", &self.file_name)
+            .finish_non_exhaustive()
+    }
+}
+
+// A stream cannot be compared without being consumed, so only the details of the file are compared
+impl PartialEq for FileStreamData {
+    fn eq(&self, other: &Self) -> bool {
+        self.content_type == other.content_type && self.file_name == other.file_name
+    }
+}
+
+impl Eq for FileStreamData {}
+
 impl<R> ApplicationResponse<R> {
     /// Get the json response from response
     #[inline]
@@ -33,6 +60,7 @@ impl<R> ApplicationResponse<R> {
             | Self::Form(_)
             | Self::PaymentLinkForm(_)
             | Self::FileData(_)
+            | Self::FileStream(_)
             | Self::GenericLinkForm(_)
             | Self::StatusOk => Err(common_utils::errors::ValidationError::InvalidValue {
                 message: "expected either Json or JsonWithHeaders Response".to_string(),
@@ -258,6 +258,9 @@ pub enum ProfileUpdate {
     CardTestingSecretKeyUpdate {
         card_testing_secret_key: OptionalEncryptableName,
     },
+    WebhookDetailsUpdate {
+        webhook_details: WebhookDetails,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -631,6 +634,50 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
             },
+            ProfileUpdate::WebhookDetailsUpdate { webhook_details } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: Some(webhook_details),
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: false,
+                merchant_business_country: None,
+            },
         }
     }
 }
@@ -901,6 +948,14 @@ pub struct Profile {
     pub is_clear_pan_retries_enabled: bool,
     pub is_debit_routing_enabled: bool,
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
+    pub default_recovery_payment_connector_id:
+        Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
 }
 
 #[cfg(feature = "v2")]
@@ -951,6 +1006,14 @@ pub struct ProfileSetter {
     pub is_clear_pan_retries_enabled: bool,
     pub is_debit_routing_enabled: bool,
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
+    pub default_recovery_payment_connector_id:
+        Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
 }
 
 #[cfg(feature = "v2")]
@@ -1006,6 +1069,10 @@ impl From<ProfileSetter> for Profile {
             is_clear_pan_retries_enabled: value.is_clear_pan_retries_enabled,
             is_debit_routing_enabled: value.is_debit_routing_enabled,
             merchant_business_country: value.merchant_business_country,
+            default_recovery_payment_connector_id: value.default_recovery_payment_connector_id,
+            revenue_recovery_connector_fallback: value.revenue_recovery_connector_fallback,
+            revenue_recovery_retry_notification: value.revenue_recovery_retry_notification,
+            revenue_recovery_blackout_calendar: value.revenue_recovery_blackout_calendar,
         }
     }
 }
@@ -1064,6 +1131,14 @@ pub struct ProfileGeneralUpdate {
     pub card_testing_secret_key: OptionalEncryptableName,
     pub is_debit_routing_enabled: bool,
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
+    pub default_recovery_payment_connector_id:
+        Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub revenue_recovery_connector_fallback:
+        Option<common_types::payments::RevenueRecoveryConnectorFallback>,
+    pub revenue_recovery_retry_notification:
+        Option<common_types::payments::RevenueRecoveryRetryNotification>,
+    pub revenue_recovery_blackout_calendar:
+        Option<common_types::payments::RevenueRecoveryBlackoutCalendar>,
 }
 
 #[cfg(feature = "v2")]
@@ -1095,6 +1170,9 @@ pub enum ProfileUpdate {
     CardTestingSecretKeyUpdate {
         card_testing_secret_key: OptionalEncryptableName,
     },
+    WebhookDetailsUpdate {
+        webhook_details: WebhookDetails,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -1135,6 +1213,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     card_testing_secret_key,
                     is_debit_routing_enabled,
                     merchant_business_country,
+                    default_recovery_payment_connector_id,
+                    revenue_recovery_connector_fallback,
+                    revenue_recovery_retry_notification,
+                    revenue_recovery_blackout_calendar,
                 } = *update;
                 Self {
                     profile_name,
@@ -1181,6 +1263,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_clear_pan_retries_enabled: None,
                     is_debit_routing_enabled,
                     merchant_business_country,
+                    default_recovery_payment_connector_id,
+                    revenue_recovery_connector_fallback,
+                    revenue_recovery_retry_notification,
+                    revenue_recovery_blackout_calendar,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -1230,6 +1316,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -1277,6 +1367,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -1324,6 +1418,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
             ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1371,6 +1469,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1418,6 +1520,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
             ProfileUpdate::CollectCvvDuringPaymentUpdate {
                 should_collect_cvv_during_payment,
@@ -1465,6 +1571,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
             ProfileUpdate::DecisionManagerRecordUpdate {
                 three_ds_decision_manager_config,
@@ -1512,6 +1622,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -1559,6 +1673,59 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_clear_pan_retries_enabled: None,
                 is_debit_routing_enabled: false,
                 merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
+            },
+            ProfileUpdate::WebhookDetailsUpdate { webhook_details } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: Some(webhook_details),
+                metadata: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                routing_algorithm_id: None,
+                payout_routing_algorithm_id: None,
+                order_fulfillment_time: None,
+                order_fulfillment_time_origin: None,
+                frm_routing_algorithm_id: None,
+                default_fallback_routing: None,
+                should_collect_cvv_during_payment: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                three_ds_decision_manager_config: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                is_debit_routing_enabled: false,
+                merchant_business_country: None,
+                default_recovery_payment_connector_id: None,
+                revenue_recovery_connector_fallback: None,
+                revenue_recovery_retry_notification: None,
+                revenue_recovery_blackout_calendar: None,
             },
         }
     }
@@ -1628,6 +1795,10 @@ impl super::behaviour::Conversion for Profile {
             force_3ds_challenge: None,
             is_debit_routing_enabled: self.is_debit_routing_enabled,
             merchant_business_country: self.merchant_business_country,
+            default_recovery_payment_connector_id: self.default_recovery_payment_connector_id,
+            revenue_recovery_connector_fallback: self.revenue_recovery_connector_fallback,
+            revenue_recovery_retry_notification: self.revenue_recovery_retry_notification,
+            revenue_recovery_blackout_calendar: self.revenue_recovery_blackout_calendar,
         })
     }
 
@@ -1717,6 +1888,10 @@ impl super::behaviour::Conversion for Profile {
                 is_clear_pan_retries_enabled: item.is_clear_pan_retries_enabled,
                 is_debit_routing_enabled: item.is_debit_routing_enabled,
                 merchant_business_country: item.merchant_business_country,
+                default_recovery_payment_connector_id: item.default_recovery_payment_connector_id,
+                revenue_recovery_connector_fallback: item.revenue_recovery_connector_fallback,
+                revenue_recovery_retry_notification: item.revenue_recovery_retry_notification,
+                revenue_recovery_blackout_calendar: item.revenue_recovery_blackout_calendar,
             })
         }
         .await
@@ -1780,6 +1955,10 @@ impl super::behaviour::Conversion for Profile {
             is_clear_pan_retries_enabled: Some(self.is_clear_pan_retries_enabled),
             is_debit_routing_enabled: self.is_debit_routing_enabled,
             merchant_business_country: self.merchant_business_country,
+            default_recovery_payment_connector_id: self.default_recovery_payment_connector_id,
+            revenue_recovery_connector_fallback: self.revenue_recovery_connector_fallback,
+            revenue_recovery_retry_notification: self.revenue_recovery_retry_notification,
+            revenue_recovery_blackout_calendar: self.revenue_recovery_blackout_calendar,
         })
     }
 }
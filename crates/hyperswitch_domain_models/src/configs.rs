@@ -38,6 +38,7 @@ pub struct Connectors {
     pub dlocal: ConnectorParams,
     #[cfg(feature = "dummy_connector")]
     pub dummyconnector: ConnectorParams,
+    pub dummybillingconnector: ConnectorParams,
     pub ebanx: ConnectorParams,
     pub elavon: ConnectorParams,
     pub facilitapay: ConnectorParams,
@@ -17,6 +17,7 @@ pub enum ErrorType {
     ValidationError,
     ConnectorError,
     LockTimeout,
+    RateLimitExceeded,
 }
 
 // CE	Connector Error	Errors originating from connector's end
@@ -160,6 +161,8 @@ pub enum ApiErrorResponse {
     InvalidTenant { tenant_id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_06", message = "Failed to convert amount to {amount_type} type")]
     AmountConversionFailed { amount_type: &'static str },
+    #[error(error_type = ErrorType::RateLimitExceeded, code = "HE_07", message = "Too many requests, retry after {retry_after} seconds")]
+    TooManyRequests { retry_after: u64 },
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IR_00", message = "{message:?}")]
     NotImplemented { message: NotImplementedMessage },
     #[error(
@@ -295,6 +298,10 @@ pub enum ApiErrorResponse {
     WebhookUnprocessableEntity,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_06", message = "Merchant Secret set my merchant for webhook source verification is invalid")]
     WebhookInvalidMerchantSecret,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "WE_07", message = "Webhook body is larger than the limit of {max_size_in_bytes} bytes")]
+    WebhookPayloadTooLarge { max_size_in_bytes: u32 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "WE_08", message = "Content type of the webhook is not accepted")]
+    WebhookUnsupportedContentType,
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IE", message = "{reason} as data mismatched for {field_names}")]
     IntegrityCheckFailed {
         reason: String,
@@ -495,6 +502,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::AmountConversionFailed { amount_type }  => {
                 AER::InternalServerError(ApiError::new("HE", 6, format!("Failed to convert amount to {amount_type} type"), None))
             }
+            Self::TooManyRequests { retry_after } => {
+                AER::TooManyRequests(ApiError::new("HE", 7, format!("Too many requests, retry after {retry_after} seconds"), None), *retry_after)
+            }
 
             Self::NotImplemented { message } => {
                 AER::NotImplemented(ApiError::new("IR", 0, format!("{message:?}"), None))
@@ -657,6 +667,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::WebhookInvalidMerchantSecret => {
                 AER::BadRequest(ApiError::new("WE", 6, "Merchant Secret set for webhook source verification is invalid", None))
             }
+            Self::WebhookPayloadTooLarge { max_size_in_bytes } => {
+                AER::PayloadTooLarge(ApiError::new("WE", 7, format!("Webhook body is larger than the limit of {max_size_in_bytes} bytes"), None))
+            }
+            Self::WebhookUnsupportedContentType => {
+                AER::UnsupportedMediaType(ApiError::new("WE", 8, "Content type of the webhook is not accepted", None))
+            }
             Self::IntegrityCheckFailed {
                 reason,
                 field_names,
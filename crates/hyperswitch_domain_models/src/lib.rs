@@ -53,13 +53,19 @@ use api_models::payments::{
 use api_models::payments::{
     BillingConnectorPaymentDetails as ApiBillingConnectorPaymentDetails,
     PaymentRevenueRecoveryMetadata as ApiRevenueRecoveryMetadata,
+    RevenueRecoveryDisputeHold as ApiRevenueRecoveryDisputeHold,
+    RevenueRecoveryManualCancellation as ApiRevenueRecoveryManualCancellation,
+    RevenueRecoveryRetryOverride as ApiRevenueRecoveryRetryOverride,
 };
 use diesel_models::types::{
     ApplePayRecurringDetails, ApplePayRegularBillingDetails, FeatureMetadata,
     OrderDetailsWithAmount, RecurringPaymentIntervalUnit, RedirectResponse,
 };
 #[cfg(feature = "v2")]
-use diesel_models::types::{BillingConnectorPaymentDetails, PaymentRevenueRecoveryMetadata};
+use diesel_models::types::{
+    BillingConnectorPaymentDetails, PaymentRevenueRecoveryMetadata, RevenueRecoveryDisputeHold,
+    RevenueRecoveryManualCancellation, RevenueRecoveryRetryOverride,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub enum RemoteStorageObject<T: ForeignIDRef> {
@@ -136,6 +142,7 @@ impl ApiModelToDieselModelConvertor<ApiFeatureMetadata> for FeatureMetadata {
             search_tags,
             apple_pay_recurring_details,
             payment_revenue_recovery_metadata,
+            billing_connector_subscription_id,
         } = from;
 
         Self {
@@ -145,6 +152,7 @@ impl ApiModelToDieselModelConvertor<ApiFeatureMetadata> for FeatureMetadata {
                 .map(ApplePayRecurringDetails::convert_from),
             payment_revenue_recovery_metadata: payment_revenue_recovery_metadata
                 .map(PaymentRevenueRecoveryMetadata::convert_from),
+            billing_connector_subscription_id,
         }
     }
 
@@ -154,6 +162,7 @@ impl ApiModelToDieselModelConvertor<ApiFeatureMetadata> for FeatureMetadata {
             search_tags,
             apple_pay_recurring_details,
             payment_revenue_recovery_metadata,
+            billing_connector_subscription_id,
         } = self;
 
         ApiFeatureMetadata {
@@ -164,6 +173,7 @@ impl ApiModelToDieselModelConvertor<ApiFeatureMetadata> for FeatureMetadata {
                 .map(|value| value.convert_back()),
             payment_revenue_recovery_metadata: payment_revenue_recovery_metadata
                 .map(|value| value.convert_back()),
+            billing_connector_subscription_id,
         }
     }
 }
@@ -277,6 +287,18 @@ impl ApiModelToDieselModelConvertor<ApiRevenueRecoveryMetadata> for PaymentReven
             payment_method_type: from.payment_method_type,
             payment_method_subtype: from.payment_method_subtype,
             connector: from.connector,
+            retry_override: from
+                .retry_override
+                .map(RevenueRecoveryRetryOverride::convert_from),
+            dispute_hold: from
+                .dispute_hold
+                .map(RevenueRecoveryDisputeHold::convert_from),
+            recovery_correlation_id: from.recovery_correlation_id,
+            manual_cancellation: from
+                .manual_cancellation
+                .map(RevenueRecoveryManualCancellation::convert_from),
+            connector_rotation: from.connector_rotation,
+            record_back_status: from.record_back_status,
         }
     }
 
@@ -292,6 +314,83 @@ impl ApiModelToDieselModelConvertor<ApiRevenueRecoveryMetadata> for PaymentReven
             payment_method_type: self.payment_method_type,
             payment_method_subtype: self.payment_method_subtype,
             connector: self.connector,
+            retry_override: self
+                .retry_override
+                .map(RevenueRecoveryRetryOverride::convert_back),
+            dispute_hold: self
+                .dispute_hold
+                .map(RevenueRecoveryDisputeHold::convert_back),
+            recovery_correlation_id: self.recovery_correlation_id,
+            manual_cancellation: self
+                .manual_cancellation
+                .map(RevenueRecoveryManualCancellation::convert_back),
+            connector_rotation: self.connector_rotation,
+            record_back_status: self.record_back_status,
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl ApiModelToDieselModelConvertor<ApiRevenueRecoveryRetryOverride>
+    for RevenueRecoveryRetryOverride
+{
+    fn convert_from(from: ApiRevenueRecoveryRetryOverride) -> Self {
+        Self {
+            additional_retries: from.additional_retries,
+            schedule: from.schedule,
+            retry_count_at_override: from.retry_count_at_override,
+            reason: from.reason,
+            created_by: from.created_by,
+            created_at: from.created_at,
+        }
+    }
+
+    fn convert_back(self) -> ApiRevenueRecoveryRetryOverride {
+        ApiRevenueRecoveryRetryOverride {
+            additional_retries: self.additional_retries,
+            schedule: self.schedule,
+            retry_count_at_override: self.retry_count_at_override,
+            reason: self.reason,
+            created_by: self.created_by,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl ApiModelToDieselModelConvertor<ApiRevenueRecoveryDisputeHold> for RevenueRecoveryDisputeHold {
+    fn convert_from(from: ApiRevenueRecoveryDisputeHold) -> Self {
+        Self {
+            held_at: from.held_at,
+            expires_at: from.expires_at,
+        }
+    }
+
+    fn convert_back(self) -> ApiRevenueRecoveryDisputeHold {
+        ApiRevenueRecoveryDisputeHold {
+            held_at: self.held_at,
+            expires_at: self.expires_at,
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl ApiModelToDieselModelConvertor<ApiRevenueRecoveryManualCancellation>
+    for RevenueRecoveryManualCancellation
+{
+    fn convert_from(from: ApiRevenueRecoveryManualCancellation) -> Self {
+        Self {
+            task_id: from.task_id,
+            cancelled_by: from.cancelled_by,
+            cancelled_at: from.cancelled_at,
+        }
+    }
+
+    fn convert_back(self) -> ApiRevenueRecoveryManualCancellation {
+        ApiRevenueRecoveryManualCancellation {
+            task_id: self.task_id,
+            cancelled_by: self.cancelled_by,
+            cancelled_at: self.cancelled_at,
         }
     }
 }
@@ -304,6 +403,7 @@ impl ApiModelToDieselModelConvertor<ApiBillingConnectorPaymentDetails>
         Self {
             payment_processor_token: from.payment_processor_token,
             connector_customer_id: from.connector_customer_id,
+            card_details: from.card_details,
         }
     }
 
@@ -311,6 +411,7 @@ impl ApiModelToDieselModelConvertor<ApiBillingConnectorPaymentDetails>
         ApiBillingConnectorPaymentDetails {
             payment_processor_token: self.payment_processor_token,
             connector_customer_id: self.connector_customer_id,
+            card_details: self.card_details,
         }
     }
 }
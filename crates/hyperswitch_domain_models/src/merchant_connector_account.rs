@@ -17,6 +17,7 @@ use diesel_models::merchant_connector_account::{
     BillingAccountReference as DieselBillingAccountReference,
     MerchantConnectorAccountFeatureMetadata as DieselMerchantConnectorAccountFeatureMetadata,
     RevenueRecoveryMetadata as DieselRevenueRecoveryMetadata,
+    RevenueRecoveryPaymentsSyncPolling as DieselRevenueRecoveryPaymentsSyncPolling,
 };
 use diesel_models::{enums, merchant_connector_account::MerchantConnectorAccountUpdateInternal};
 use error_stack::ResultExt;
@@ -131,6 +132,36 @@ impl MerchantConnectorAccount {
             .map(|recovery| recovery.billing_connector_retry_threshold)
     }
 
+    pub fn is_recovery_reconciliation_enabled(&self) -> bool {
+        self.feature_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.revenue_recovery.as_ref())
+            .is_some_and(|recovery| recovery.reconciliation_enabled)
+    }
+
+    pub fn get_recovery_payments_sync_polling(
+        &self,
+    ) -> Option<&RevenueRecoveryPaymentsSyncPolling> {
+        self.feature_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.revenue_recovery.as_ref())
+            .and_then(|recovery| recovery.payments_sync_polling.as_ref())
+    }
+
+    pub fn get_recovery_payments_sync_timeout_in_secs(&self) -> Option<u16> {
+        self.feature_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.revenue_recovery.as_ref())
+            .and_then(|recovery| recovery.payments_sync_timeout_in_secs)
+    }
+
+    pub fn get_recovery_payments_sync_required(&self) -> Option<bool> {
+        self.feature_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.revenue_recovery.as_ref())
+            .and_then(|recovery| recovery.payments_sync_required)
+    }
+
     pub fn get_id(&self) -> id_type::MerchantConnectorAccountId {
         self.id.clone()
     }
@@ -205,6 +236,17 @@ pub struct RevenueRecoveryMetadata {
     pub max_retry_count: u16,
     pub billing_connector_retry_threshold: u16,
     pub mca_reference: AccountReferenceMap,
+    pub reconciliation_enabled: bool,
+    pub payments_sync_polling: Option<RevenueRecoveryPaymentsSyncPolling>,
+    pub payments_sync_timeout_in_secs: Option<u16>,
+    pub payments_sync_required: Option<bool>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone)]
+pub struct RevenueRecoveryPaymentsSyncPolling {
+    pub quiet_period_in_hours: u16,
+    pub max_empty_polls: u16,
 }
 
 #[cfg(feature = "v2")]
@@ -829,6 +871,15 @@ impl From<MerchantConnectorAccountFeatureMetadata>
                 billing_account_reference: DieselBillingAccountReference(
                     recovery_metadata.mca_reference.recovery_to_billing,
                 ),
+                reconciliation_enabled: recovery_metadata.reconciliation_enabled,
+                payments_sync_polling: recovery_metadata.payments_sync_polling.map(|polling| {
+                    DieselRevenueRecoveryPaymentsSyncPolling {
+                        quiet_period_in_hours: polling.quiet_period_in_hours,
+                        max_empty_polls: polling.max_empty_polls,
+                    }
+                }),
+                payments_sync_timeout_in_secs: recovery_metadata.payments_sync_timeout_in_secs,
+                payments_sync_required: recovery_metadata.payments_sync_required,
             }
         });
         Self { revenue_recovery }
@@ -853,8 +904,118 @@ impl From<DieselMerchantConnectorAccountFeatureMetadata>
                     recovery_to_billing: recovery_metadata.billing_account_reference.0,
                     billing_to_recovery,
                 },
+                reconciliation_enabled: recovery_metadata.reconciliation_enabled,
+                payments_sync_polling: recovery_metadata.payments_sync_polling.map(|polling| {
+                    RevenueRecoveryPaymentsSyncPolling {
+                        quiet_period_in_hours: polling.quiet_period_in_hours,
+                        max_empty_polls: polling.max_empty_polls,
+                    }
+                }),
+                payments_sync_timeout_in_secs: recovery_metadata.payments_sync_timeout_in_secs,
+                payments_sync_required: recovery_metadata.payments_sync_required,
             }
         });
         Self { revenue_recovery }
     }
 }
+
+#[cfg(all(test, feature = "v2"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_billing_connector_account(
+        account_reference: HashMap<id_type::MerchantConnectorAccountId, String>,
+    ) -> MerchantConnectorAccount {
+        let now = date_time::now();
+        MerchantConnectorAccount {
+            id: id_type::MerchantConnectorAccountId::wrap(String::from("mca_recurly")).unwrap(),
+            merchant_id: id_type::MerchantId::default(),
+            connector_name: common_enums::connector_enums::Connector::Recurly,
+            connector_account_details: Encryptable::new(
+                Secret::new(serde_json::json!({ "auth_type": "HeaderKey", "api_key": "key" })),
+                Secret::new(Vec::new()),
+            ),
+            disabled: None,
+            payment_methods_enabled: None,
+            connector_type: enums::ConnectorType::BillingProcessor,
+            metadata: None,
+            frm_configs: None,
+            connector_label: None,
+            created_at: now,
+            modified_at: now,
+            connector_webhook_details: None,
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap(),
+            applepay_verified_domains: None,
+            pm_auth_config: None,
+            status: enums::ConnectorStatus::Active,
+            connector_wallets_details: None,
+            additional_merchant_data: None,
+            version: common_enums::ApiVersion::V2,
+            feature_metadata: Some(MerchantConnectorAccountFeatureMetadata {
+                revenue_recovery: Some(RevenueRecoveryMetadata {
+                    max_retry_count: 15,
+                    billing_connector_retry_threshold: 3,
+                    mca_reference: AccountReferenceMap::new(account_reference).unwrap(),
+                    reconciliation_enabled: false,
+                    payments_sync_polling: None,
+                    payments_sync_timeout_in_secs: None,
+                    payments_sync_required: None,
+                }),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_payment_connector_account_of_recurly_gateway_code() {
+        let stripe_account_id =
+            id_type::MerchantConnectorAccountId::wrap(String::from("mca_stripe")).unwrap();
+        let adyen_account_id =
+            id_type::MerchantConnectorAccountId::wrap(String::from("mca_adyen")).unwrap();
+        // The payment gateways of Recurly are referred to by their gateway codes
+        let billing_connector_account = get_billing_connector_account(HashMap::from([
+            (stripe_account_id.clone(), String::from("ow6mnd7e1xz4")),
+            (adyen_account_id.clone(), String::from("qzv8ij2n3rtd")),
+        ]));
+
+        assert_eq!(
+            billing_connector_account
+                .get_payment_merchant_connector_account_id_using_account_reference_id(
+                    String::from("ow6mnd7e1xz4")
+                ),
+            Some(stripe_account_id)
+        );
+        assert_eq!(
+            billing_connector_account
+                .get_payment_merchant_connector_account_id_using_account_reference_id(
+                    String::from("qzv8ij2n3rtd")
+                ),
+            Some(adyen_account_id)
+        );
+        // The gateways which are not mapped to any payment connector account are not recovered
+        assert_eq!(
+            billing_connector_account
+                .get_payment_merchant_connector_account_id_using_account_reference_id(
+                    String::from("unmapped_gateway")
+                ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gateway_code_mapped_to_multiple_payment_connector_accounts_is_rejected() {
+        let account_reference = HashMap::from([
+            (
+                id_type::MerchantConnectorAccountId::wrap(String::from("mca_stripe")).unwrap(),
+                String::from("ow6mnd7e1xz4"),
+            ),
+            (
+                id_type::MerchantConnectorAccountId::wrap(String::from("mca_adyen")).unwrap(),
+                String::from("ow6mnd7e1xz4"),
+            ),
+        ]);
+
+        assert!(AccountReferenceMap::new(account_reference).is_err());
+    }
+}
@@ -118,6 +118,8 @@ pub struct PaymentIntent {
     pub platform_merchant_id: Option<id_type::MerchantId>,
     pub force_3ds_challenge: Option<bool>,
     pub force_3ds_challenge_trigger: Option<bool>,
+    /// Incremented on every update of the status of the payment
+    pub entity_version: i64,
 }
 
 impl PaymentIntent {
@@ -475,6 +477,8 @@ pub struct PaymentIntent {
 
     pub force_3ds_challenge: Option<bool>,
     pub force_3ds_challenge_trigger: Option<bool>,
+    /// Incremented on every update of the status of the payment
+    pub entity_version: i64,
 }
 
 #[cfg(feature = "v2")]
@@ -653,6 +657,7 @@ impl PaymentIntent {
             split_payments: None,
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         })
     }
 
@@ -823,6 +828,8 @@ pub struct RevenueRecoveryData {
     pub billing_connector_id: id_type::MerchantConnectorAccountId,
     pub processor_payment_method_token: String,
     pub connector_customer_id: String,
+    pub recovery_correlation_id: Option<String>,
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
 }
 
 #[cfg(feature = "v2")]
@@ -830,6 +837,33 @@ impl<F> PaymentAttemptRecordData<F>
 where
     F: Clone,
 {
+    /// Records the processor payment token of the attempt for its payment connector, so that the
+    /// retries can be switched back to the payment connector later. The soft declines counted on
+    /// the previous payment connector are not carried over to another payment connector.
+    fn get_updated_connector_rotation(
+        &self,
+        revenue_recovery: Option<&diesel_models::types::PaymentRevenueRecoveryMetadata>,
+        active_attempt_payment_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> common_types::payments::RevenueRecoveryConnectorRotation {
+        let mut connector_rotation = revenue_recovery
+            .and_then(|data| data.connector_rotation.clone())
+            .unwrap_or_default();
+
+        if revenue_recovery.is_some_and(|data| {
+            data.active_attempt_payment_connector_id != *active_attempt_payment_connector_id
+        }) {
+            connector_rotation.soft_decline_count = 0;
+        }
+        connector_rotation.set_processor_payment_token(
+            active_attempt_payment_connector_id,
+            self.revenue_recovery_data
+                .processor_payment_method_token
+                .clone(),
+        );
+
+        connector_rotation
+    }
+
     pub fn get_updated_feature_metadata(
         &self,
     ) -> CustomResult<Option<FeatureMetadata>, errors::api_error_response::ApiErrorResponse> {
@@ -837,6 +871,9 @@ where
 
         let revenue_recovery = self.payment_intent.get_revenue_recovery_metadata();
         let payment_attempt_connector = self.payment_attempt.connector.clone();
+        let active_attempt_payment_connector_id = self
+            .payment_attempt
+            .get_attempt_merchant_connector_account_id()?;
         let payment_revenue_recovery_metadata = match payment_attempt_connector {
             Some(connector) => Some(diesel_models::types::PaymentRevenueRecoveryMetadata {
                 // Update retry count by one.
@@ -847,9 +884,11 @@ where
                 payment_connector_transmission:
                     common_enums::PaymentConnectorTransmission::ConnectorCallUnsuccessful,
                 billing_connector_id: self.revenue_recovery_data.billing_connector_id.clone(),
-                active_attempt_payment_connector_id: self
-                    .payment_attempt
-                    .get_attempt_merchant_connector_account_id()?,
+                connector_rotation: Some(self.get_updated_connector_rotation(
+                    revenue_recovery.as_ref(),
+                    &active_attempt_payment_connector_id,
+                )),
+                active_attempt_payment_connector_id,
                 billing_connector_payment_details:
                     diesel_models::types::BillingConnectorPaymentDetails {
                         payment_processor_token: self
@@ -860,6 +899,7 @@ where
                             .revenue_recovery_data
                             .connector_customer_id
                             .clone(),
+                        card_details: self.revenue_recovery_data.card_details.clone(),
                     },
                 payment_method_type: self.payment_attempt.payment_method_type,
                 payment_method_subtype: self.payment_attempt.payment_method_subtype,
@@ -867,6 +907,26 @@ where
                     router_env::logger::error!(?err, "Failed to parse connector string to enum");
                     errors::api_error_response::ApiErrorResponse::InternalServerError
                 })?,
+                retry_override: revenue_recovery
+                    .as_ref()
+                    .and_then(|data| data.retry_override.clone()),
+                dispute_hold: revenue_recovery
+                    .as_ref()
+                    .and_then(|data| data.dispute_hold.clone()),
+                manual_cancellation: revenue_recovery
+                    .as_ref()
+                    .and_then(|data| data.manual_cancellation.clone()),
+                recovery_correlation_id: self
+                    .revenue_recovery_data
+                    .recovery_correlation_id
+                    .clone()
+                    .or_else(|| {
+                        revenue_recovery
+                            .as_ref()
+                            .and_then(|data| data.recovery_correlation_id.clone())
+                    }),
+                // The outcome of the new attempt is yet to be recorded back to the billing connector
+                record_back_status: None,
             }),
             None => Err(errors::api_error_response::ApiErrorResponse::InternalServerError)
                 .attach_printable("Connector not found in payment attempt")?,
@@ -882,6 +942,9 @@ where
                 .as_ref()
                 .and_then(|data| data.apple_pay_recurring_details.clone()),
             payment_revenue_recovery_metadata,
+            billing_connector_subscription_id: payment_intent_feature_metadata
+                .as_ref()
+                .and_then(|data| data.billing_connector_subscription_id.clone()),
         }))
     }
 }
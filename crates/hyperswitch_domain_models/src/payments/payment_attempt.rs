@@ -170,6 +170,16 @@ pub trait PaymentAttemptInterface {
         storage_scheme: common_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, Self::Error>;
 
+    #[cfg(feature = "v2")]
+    async fn find_payment_attempts_by_profile_id_time_range(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &id_type::ProfileId,
+        time_range: &common_utils::types::TimeRange,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: common_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, Self::Error>;
+
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_preprocessing_id_merchant_id(
         &self,
@@ -692,11 +702,27 @@ impl PaymentAttempt {
             .transaction_created_at
             .unwrap_or(common_utils::date_time::now());
 
+        let request_recovery_data = request
+            .feature_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.revenue_recovery.as_ref());
+        let processor_response_details =
+            request_recovery_data.and_then(|recovery| recovery.processor_response_details.clone());
+        let data_provenance =
+            request_recovery_data.and_then(|recovery| recovery.data_provenance.clone());
+        let recovery_correlation_id =
+            request_recovery_data.and_then(|recovery| recovery.recovery_correlation_id.clone());
+
         // This function is called in the record attempt flow, which tells us that this is a payment attempt created by an external system.
         let feature_metadata = PaymentAttemptFeatureMetadata {
             revenue_recovery: Some({
                 PaymentAttemptRevenueRecoveryData {
                     attempt_triggered_by: common_enums::TriggeredBy::External,
+                    processor_response_details,
+                    schedule_adjustments: None,
+                    data_provenance,
+                    recovery_correlation_id,
+                    connector_switch: None,
                 }
             }),
         };
@@ -1741,6 +1767,11 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         connector_payment_id: Option<String>,
     },
+    /// Update the feature metadata of the payment attempt
+    FeatureMetadataUpdate {
+        feature_metadata: PaymentAttemptFeatureMetadata,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -2608,6 +2639,35 @@ impl From<PaymentAttemptUpdate> for diesel_models::PaymentAttemptUpdateInternal
                 network_decline_code: None,
                 network_error_message: None,
             },
+            PaymentAttemptUpdate::FeatureMetadataUpdate {
+                feature_metadata,
+                updated_by,
+            } => Self {
+                status: None,
+                amount_capturable: None,
+                amount_to_capture: None,
+                error_message: None,
+                error_code: None,
+                modified_at: common_utils::date_time::now(),
+                browser_info: None,
+                error_reason: None,
+                updated_by,
+                merchant_connector_id: None,
+                unified_code: None,
+                unified_message: None,
+                connector_payment_id: None,
+                connector: None,
+                redirection_data: None,
+                connector_metadata: None,
+                connector_token_details: None,
+                authentication_type: None,
+                feature_metadata: Some(DieselPaymentAttemptFeatureMetadata::from(
+                    &feature_metadata,
+                )),
+                network_advice_code: None,
+                network_decline_code: None,
+                network_error_message: None,
+            },
             PaymentAttemptUpdate::PreCaptureUpdate {
                 amount_to_capture,
                 updated_by,
@@ -2648,6 +2708,12 @@ pub struct PaymentAttemptFeatureMetadata {
 #[derive(Debug, Clone, serde::Serialize, PartialEq)]
 pub struct PaymentAttemptRevenueRecoveryData {
     pub attempt_triggered_by: common_enums::TriggeredBy,
+    pub processor_response_details: Option<common_types::payments::ProcessorResponseDetails>,
+    pub schedule_adjustments: Option<Vec<common_enums::RecoveryScheduleAdjustment>>,
+    pub data_provenance:
+        Option<std::collections::BTreeMap<String, common_enums::RecoveryDataSource>>,
+    pub recovery_correlation_id: Option<String>,
+    pub connector_switch: Option<common_types::payments::RevenueRecoveryConnectorSwitch>,
 }
 
 #[cfg(feature = "v2")]
@@ -2658,6 +2724,11 @@ impl From<&PaymentAttemptFeatureMetadata> for DieselPaymentAttemptFeatureMetadat
                 .as_ref()
                 .map(|recovery_data| DieselPassiveChurnRecoveryData {
                     attempt_triggered_by: recovery_data.attempt_triggered_by,
+                    processor_response_details: recovery_data.processor_response_details.clone(),
+                    schedule_adjustments: recovery_data.schedule_adjustments.clone(),
+                    data_provenance: recovery_data.data_provenance.clone(),
+                    recovery_correlation_id: recovery_data.recovery_correlation_id.clone(),
+                    connector_switch: recovery_data.connector_switch.clone(),
                 });
         Self { revenue_recovery }
     }
@@ -2670,6 +2741,11 @@ impl From<DieselPaymentAttemptFeatureMetadata> for PaymentAttemptFeatureMetadata
             item.revenue_recovery
                 .map(|recovery_data| PaymentAttemptRevenueRecoveryData {
                     attempt_triggered_by: recovery_data.attempt_triggered_by,
+                    processor_response_details: recovery_data.processor_response_details,
+                    schedule_adjustments: recovery_data.schedule_adjustments,
+                    data_provenance: recovery_data.data_provenance,
+                    recovery_correlation_id: recovery_data.recovery_correlation_id,
+                    connector_switch: recovery_data.connector_switch,
                 });
         Self { revenue_recovery }
     }
@@ -84,6 +84,45 @@ pub trait PaymentIntentInterface {
         storage_scheme: &common_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, Self::Error>;
 
+    #[cfg(feature = "v2")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_intents_by_billing_connector_subscription_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_subscription_id: &str,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: &common_enums::MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, Self::Error>;
+
+    #[cfg(feature = "v2")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_open_recovery_intents_by_billing_connector_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_id: &id_type::MerchantConnectorAccountId,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: &common_enums::MerchantStorageScheme,
+        starting_after: Option<&id_type::GlobalPaymentId>,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, Self::Error>;
+
+    #[cfg(feature = "v2")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_intents_by_billing_connector_customer_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_customer_id: &str,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: &common_enums::MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, Self::Error>;
+
     #[cfg(feature = "v2")]
     async fn find_payment_intent_by_id(
         &self,
@@ -1607,6 +1646,7 @@ impl behaviour::Conversion for PaymentIntent {
             split_payments,
             force_3ds_challenge,
             force_3ds_challenge_trigger,
+            entity_version,
         } = self;
         Ok(DieselPaymentIntent {
             skip_external_tax_calculation: Some(amount_details.get_external_tax_action_as_bool()),
@@ -1690,6 +1730,7 @@ impl behaviour::Conversion for PaymentIntent {
             split_payments,
             force_3ds_challenge,
             force_3ds_challenge_trigger,
+            entity_version,
         })
     }
     async fn convert_back(
@@ -1826,6 +1867,7 @@ impl behaviour::Conversion for PaymentIntent {
                 split_payments: storage_model.split_payments,
                 force_3ds_challenge: storage_model.force_3ds_challenge,
                 force_3ds_challenge_trigger: storage_model.force_3ds_challenge_trigger,
+                entity_version: storage_model.entity_version,
             })
         }
         .await
@@ -1910,6 +1952,7 @@ impl behaviour::Conversion for PaymentIntent {
             platform_merchant_id: self.platform_merchant_id,
             force_3ds_challenge: self.force_3ds_challenge,
             force_3ds_challenge_trigger: self.force_3ds_challenge_trigger,
+            entity_version: self.entity_version,
         })
     }
 }
@@ -1980,6 +2023,7 @@ impl behaviour::Conversion for PaymentIntent {
             platform_merchant_id: self.platform_merchant_id,
             force_3ds_challenge: self.force_3ds_challenge,
             force_3ds_challenge_trigger: self.force_3ds_challenge_trigger,
+            entity_version: self.entity_version,
         })
     }
 
@@ -2072,6 +2116,7 @@ impl behaviour::Conversion for PaymentIntent {
                 platform_merchant_id: storage_model.platform_merchant_id,
                 force_3ds_challenge: storage_model.force_3ds_challenge,
                 force_3ds_challenge_trigger: storage_model.force_3ds_challenge_trigger,
+                entity_version: storage_model.entity_version,
             })
         }
         .await
@@ -2140,6 +2185,7 @@ impl behaviour::Conversion for PaymentIntent {
             platform_merchant_id: self.platform_merchant_id,
             force_3ds_challenge: self.force_3ds_challenge,
             force_3ds_challenge_trigger: self.force_3ds_challenge_trigger,
+            entity_version: self.entity_version,
         })
     }
 }
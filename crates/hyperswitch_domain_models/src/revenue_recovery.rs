@@ -1,12 +1,17 @@
+use std::collections::BTreeMap;
+
 use api_models::{payments as api_payments, webhooks};
 use common_enums::enums as common_enums;
-use common_utils::{id_type, types as util_types};
+use common_utils::{id_type, pii, types as util_types};
+use masking::Secret;
 use time::PrimitiveDateTime;
 
-use crate::router_response_types::revenue_recovery::BillingConnectorPaymentsSyncResponse;
+use crate::router_response_types::revenue_recovery::{
+    BillingConnectorInvoiceSyncResponse, BillingConnectorPaymentsSyncResponse,
+};
 
 /// Recovery payload is unified struct constructed from billing connectors
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct RevenueRecoveryAttemptData {
     /// transaction amount against invoice, accepted in minor unit.
     pub amount: util_types::MinorUnit,
@@ -27,6 +32,7 @@ pub struct RevenueRecoveryAttemptData {
     /// Payment gateway identifier id at billing processor.
     pub connector_account_reference_id: String,
     /// timestamp at which transaction has been created at billing connector
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub transaction_created_at: Option<PrimitiveDateTime>,
     /// transaction status at billing connector equivalent to payment attempt status.
     pub status: common_enums::AttemptStatus,
@@ -42,6 +48,12 @@ pub struct RevenueRecoveryAttemptData {
     pub network_decline_code: Option<String>,
     /// A string indicating how to proceed with an network error if payment gateway provide one. This is used to understand the network error code better.
     pub network_error_message: Option<String>,
+    /// Raw response details of the payment processor, like response code and avs / cvv check results.
+    pub processor_response_details: Option<common_types::payments::ProcessorResponseDetails>,
+    /// country of the issuer of the card, derived from the card BIN by the billing connector.
+    pub card_issuing_country: Option<common_enums::CountryAlpha2>,
+    /// details of the card of the transaction, like the card network, expiry and funding type.
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
 }
 
 /// This is unified struct for Revenue Recovery Invoice Data and it is constructed from billing connectors
@@ -53,6 +65,83 @@ pub struct RevenueRecoveryInvoiceData {
     pub currency: common_enums::Currency,
     /// merchant reference id at billing connector. ex: invoice_id
     pub merchant_reference_id: id_type::PaymentReferenceId,
+    /// contact details of the customer at billing connector, if shared in the invoice.
+    pub customer_details: Option<RevenueRecoveryCustomerDetails>,
+    /// subscription id at billing connector, to which the invoice belongs.
+    pub billing_connector_subscription_id: Option<String>,
+}
+
+/// Contact details of the customer at billing connector, used for sending dunning notifications
+#[derive(Debug, Clone)]
+pub struct RevenueRecoveryCustomerDetails {
+    /// customer id at billing connector
+    pub billing_connector_customer_id: String,
+    /// email of the customer
+    pub email: Option<pii::Email>,
+    /// locale in which the customer prefers to be notified. ex: en-US
+    pub locale: Option<Secret<String>>,
+}
+
+/// Source of each field of the recovery data, keyed by the name of the field
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct RecoveryDataProvenance(BTreeMap<&'static str, common_enums::RecoveryDataSource>);
+
+impl RecoveryDataProvenance {
+    pub fn get(&self, field: &str) -> Option<common_enums::RecoveryDataSource> {
+        self.0.get(field).copied()
+    }
+
+    pub fn get_field_sources(&self) -> BTreeMap<String, common_enums::RecoveryDataSource> {
+        self.0
+            .iter()
+            .map(|(field, source)| (field.to_string(), *source))
+            .collect()
+    }
+
+    fn required<T>(
+        &mut self,
+        field: &'static str,
+        value: T,
+        source: common_enums::RecoveryDataSource,
+    ) -> T {
+        self.0.insert(field, source);
+        value
+    }
+
+    /// Prefers the value of the primary source, the fallback value is always parsed from the webhook
+    fn optional<T>(
+        &mut self,
+        field: &'static str,
+        value: Option<T>,
+        source: common_enums::RecoveryDataSource,
+        fallback: Option<T>,
+    ) -> Option<T> {
+        let (value, source) = match (value, fallback) {
+            (Some(value), _) => (Some(value), source),
+            (None, Some(fallback)) => (Some(fallback), common_enums::RecoveryDataSource::Webhook),
+            (None, None) => (None, common_enums::RecoveryDataSource::Absent),
+        };
+        self.0.insert(field, source);
+        value
+    }
+
+    /// Billing connectors send empty strings for the identifiers they do not have, such values
+    /// are treated as absent
+    fn non_empty(
+        &mut self,
+        field: &'static str,
+        value: String,
+        source: common_enums::RecoveryDataSource,
+        fallback: Option<String>,
+    ) -> String {
+        self.optional(
+            field,
+            Some(value).filter(|value| !value.is_empty()),
+            source,
+            fallback.filter(|fallback| !fallback.is_empty()),
+        )
+        .unwrap_or_default()
+    }
 }
 
 /// type of action that needs to taken after consuming recovery payload
@@ -60,6 +149,12 @@ pub struct RevenueRecoveryInvoiceData {
 pub enum RecoveryAction {
     /// Stops the process tracker and update the payment intent.
     CancelInvoice,
+    /// Stops the process trackers and cancels the payment intents of all the open invoices of the subscription.
+    CancelSubscription,
+    /// Pauses the retries of all the open invoices of the customer, until the dispute is resolved.
+    HoldForDispute,
+    /// Resumes the retries of all the open invoices of the customer, once the dispute is resolved.
+    ReleaseDisputeHold,
     /// Records the external transaction against payment intent.
     ScheduleFailedPayment,
     /// Records the external payment and stops the internal process tracker.
@@ -78,10 +173,27 @@ pub struct RecoveryPaymentIntent {
     pub feature_metadata: Option<api_payments::FeatureMetadata>,
 }
 
+impl RecoveryPaymentIntent {
+    pub fn get_retry_override(&self) -> Option<&api_payments::RevenueRecoveryRetryOverride> {
+        self.feature_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.payment_revenue_recovery_metadata.as_ref())
+            .and_then(|recovery_metadata| recovery_metadata.retry_override.as_ref())
+    }
+}
+
 pub struct RecoveryPaymentAttempt {
     pub attempt_id: id_type::GlobalAttemptId,
     pub attempt_status: common_enums::AttemptStatus,
     pub feature_metadata: Option<api_payments::PaymentAttemptFeatureMetadata>,
+    /// mandate token at payment processor end, used for scheduling the retries of this attempt
+    pub processor_payment_method_token: String,
+    /// error code of the attempt, the unified code when it is available, used for deciding the
+    /// delay of the retries of this attempt
+    pub error_code: Option<String>,
+    /// country of the issuer of the card, used for deciding the local time of the retries of this
+    /// attempt
+    pub card_issuing_country: Option<common_enums::CountryAlpha2>,
 }
 
 impl RecoveryPaymentAttempt {
@@ -147,6 +259,9 @@ impl RecoveryAction {
             },
             webhooks::IncomingWebhookEvent::RecoveryPaymentPending => Self::PendingPayment,
             webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel => Self::CancelInvoice,
+            webhooks::IncomingWebhookEvent::RecoverySubscriptionCancel => Self::CancelSubscription,
+            webhooks::IncomingWebhookEvent::RecoveryDisputeOpened => Self::HoldForDispute,
+            webhooks::IncomingWebhookEvent::RecoveryDisputeClosed => Self::ReleaseDisputeHold,
         }
     }
 }
@@ -191,7 +306,17 @@ impl From<&RevenueRecoveryInvoiceData> for api_payments::PaymentsCreateIntentReq
             allowed_payment_method_types: None,
             metadata: None,
             connector_metadata: None,
-            feature_metadata: None,
+            // The subscription id is persisted, so that the payment can be looked up when the
+            // subscription is cancelled at the billing connector.
+            feature_metadata: data.billing_connector_subscription_id.clone().map(
+                |billing_connector_subscription_id| api_payments::FeatureMetadata {
+                    redirect_response: None,
+                    search_tags: None,
+                    apple_pay_recurring_details: None,
+                    payment_revenue_recovery_metadata: None,
+                    billing_connector_subscription_id: Some(billing_connector_subscription_id),
+                },
+            ),
             payment_link_enabled: None,
             payment_link_config: None,
             request_incremental_authorization: None,
@@ -209,6 +334,26 @@ impl From<&BillingConnectorPaymentsSyncResponse> for RevenueRecoveryInvoiceData
             amount: data.amount,
             currency: data.currency,
             merchant_reference_id: data.merchant_reference_id.clone(),
+            customer_details: None,
+            billing_connector_subscription_id: data.billing_connector_subscription_id.clone(),
+        }
+    }
+}
+
+impl From<BillingConnectorInvoiceSyncResponse> for RevenueRecoveryInvoiceData {
+    fn from(data: BillingConnectorInvoiceSyncResponse) -> Self {
+        Self {
+            amount: data.amount,
+            currency: data.currency,
+            merchant_reference_id: data.merchant_reference_id,
+            customer_details: data.customer_details.map(|customer_details| {
+                RevenueRecoveryCustomerDetails {
+                    billing_connector_customer_id: customer_details.billing_connector_customer_id,
+                    email: customer_details.email,
+                    locale: customer_details.locale,
+                }
+            }),
+            billing_connector_subscription_id: data.billing_connector_subscription_id,
         }
     }
 }
@@ -229,9 +374,239 @@ impl From<&BillingConnectorPaymentsSyncResponse> for RevenueRecoveryAttemptData
             status: data.status,
             payment_method_type: data.payment_method_type,
             payment_method_sub_type: data.payment_method_sub_type,
-            network_advice_code: None,
-            network_decline_code: None,
-            network_error_message: None,
+            network_advice_code: data.network_advice_code.clone(),
+            network_decline_code: data.network_decline_code.clone(),
+            network_error_message: data.network_error_message.clone(),
+            processor_response_details: data.processor_response_details.clone(),
+            card_issuing_country: data.card_issuing_country,
+            card_details: data.card_details.clone(),
+        }
+    }
+}
+
+impl RevenueRecoveryInvoiceData {
+    /// Merges the invoice data fetched using the payments sync call with the data parsed from the
+    /// webhook. Fields present in the synced data take precedence over the webhook data.
+    pub fn merge(
+        sync_data: Option<Self>,
+        webhook_data: Option<Self>,
+    ) -> Option<(Self, RecoveryDataProvenance)> {
+        let (data, source, mut fallback) = match (sync_data, webhook_data) {
+            (Some(sync_data), webhook_data) => (
+                sync_data,
+                common_enums::RecoveryDataSource::Sync,
+                webhook_data,
+            ),
+            (None, Some(webhook_data)) => (
+                webhook_data,
+                common_enums::RecoveryDataSource::Webhook,
+                None,
+            ),
+            (None, None) => return None,
+        };
+
+        let mut provenance = RecoveryDataProvenance::default();
+        let merged_data = Self {
+            amount: provenance.required("amount", data.amount, source),
+            currency: provenance.required("currency", data.currency, source),
+            merchant_reference_id: provenance.required(
+                "merchant_reference_id",
+                data.merchant_reference_id,
+                source,
+            ),
+            customer_details: provenance.optional(
+                "customer_details",
+                data.customer_details,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.customer_details.take()),
+            ),
+            billing_connector_subscription_id: provenance.optional(
+                "billing_connector_subscription_id",
+                data.billing_connector_subscription_id,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.billing_connector_subscription_id.take()),
+            ),
+        };
+
+        Some((merged_data, provenance))
+    }
+}
+
+impl RevenueRecoveryAttemptData {
+    /// Merges the attempt data fetched using the payments sync call with the data parsed from the
+    /// webhook. Fields present in the synced data take precedence over the webhook data.
+    pub fn merge(
+        sync_data: Option<Self>,
+        webhook_data: Option<Self>,
+    ) -> Option<(Self, RecoveryDataProvenance)> {
+        let (data, source, mut fallback) = match (sync_data, webhook_data) {
+            (Some(sync_data), webhook_data) => (
+                sync_data,
+                common_enums::RecoveryDataSource::Sync,
+                webhook_data,
+            ),
+            (None, Some(webhook_data)) => (
+                webhook_data,
+                common_enums::RecoveryDataSource::Webhook,
+                None,
+            ),
+            (None, None) => return None,
+        };
+
+        let mut provenance = RecoveryDataProvenance::default();
+        let merged_data = Self {
+            amount: provenance.required("amount", data.amount, source),
+            currency: provenance.required("currency", data.currency, source),
+            merchant_reference_id: provenance.required(
+                "merchant_reference_id",
+                data.merchant_reference_id,
+                source,
+            ),
+            connector_transaction_id: provenance.optional(
+                "connector_transaction_id",
+                data.connector_transaction_id,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.connector_transaction_id.take()),
+            ),
+            error_code: provenance.optional(
+                "error_code",
+                data.error_code,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.error_code.take()),
+            ),
+            error_message: provenance.optional(
+                "error_message",
+                data.error_message,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.error_message.take()),
+            ),
+            processor_payment_method_token: provenance.non_empty(
+                "processor_payment_method_token",
+                data.processor_payment_method_token,
+                source,
+                fallback
+                    .as_mut()
+                    .map(|fallback| std::mem::take(&mut fallback.processor_payment_method_token)),
+            ),
+            connector_customer_id: provenance.non_empty(
+                "connector_customer_id",
+                data.connector_customer_id,
+                source,
+                fallback
+                    .as_mut()
+                    .map(|fallback| std::mem::take(&mut fallback.connector_customer_id)),
+            ),
+            connector_account_reference_id: provenance.non_empty(
+                "connector_account_reference_id",
+                data.connector_account_reference_id,
+                source,
+                fallback
+                    .as_mut()
+                    .map(|fallback| std::mem::take(&mut fallback.connector_account_reference_id)),
+            ),
+            transaction_created_at: provenance.optional(
+                "transaction_created_at",
+                data.transaction_created_at,
+                source,
+                fallback
+                    .as_ref()
+                    .and_then(|fallback| fallback.transaction_created_at),
+            ),
+            status: provenance.required("status", data.status, source),
+            payment_method_type: provenance.required(
+                "payment_method_type",
+                data.payment_method_type,
+                source,
+            ),
+            payment_method_sub_type: provenance.required(
+                "payment_method_sub_type",
+                data.payment_method_sub_type,
+                source,
+            ),
+            network_advice_code: provenance.optional(
+                "network_advice_code",
+                data.network_advice_code,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.network_advice_code.take()),
+            ),
+            network_decline_code: provenance.optional(
+                "network_decline_code",
+                data.network_decline_code,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.network_decline_code.take()),
+            ),
+            network_error_message: provenance.optional(
+                "network_error_message",
+                data.network_error_message,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.network_error_message.take()),
+            ),
+            processor_response_details: provenance.optional(
+                "processor_response_details",
+                data.processor_response_details,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.processor_response_details.take()),
+            ),
+            card_issuing_country: provenance.optional(
+                "card_issuing_country",
+                data.card_issuing_country,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.card_issuing_country.take()),
+            ),
+            card_details: provenance.optional(
+                "card_details",
+                data.card_details,
+                source,
+                fallback
+                    .as_mut()
+                    .and_then(|fallback| fallback.card_details.take()),
+            ),
+        };
+
+        Some((merged_data, provenance))
+    }
+
+    /// Payment method and payment method subtype of the attempt. The funding type of the card
+    /// reported by the billing connector is preferred over the subtype inferred from the webhook,
+    /// which the billing connectors do not always tell apart.
+    pub fn get_payment_method_and_sub_type(
+        &self,
+    ) -> (common_enums::PaymentMethod, common_enums::PaymentMethodType) {
+        let card_funding = self
+            .card_details
+            .as_ref()
+            .and_then(|card_details| card_details.card_funding)
+            .filter(|card_funding| {
+                matches!(
+                    card_funding,
+                    common_enums::PaymentMethodType::Credit
+                        | common_enums::PaymentMethodType::Debit
+                )
+            });
+
+        match card_funding {
+            Some(card_funding) => (common_enums::PaymentMethod::Card, card_funding),
+            None => (self.payment_method_type, self.payment_method_sub_type),
         }
     }
 }
@@ -264,3 +639,212 @@ impl From<&RevenueRecoveryAttemptData> for Option<api_payments::RecordAttemptErr
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::router_response_types::revenue_recovery::BillingConnectorInvoiceCustomerDetails;
+
+    fn get_attempt_data(
+        processor_payment_method_token: &str,
+        error_code: Option<&str>,
+        network_advice_code: Option<&str>,
+    ) -> RevenueRecoveryAttemptData {
+        RevenueRecoveryAttemptData {
+            amount: util_types::MinorUnit::new(1000),
+            currency: common_enums::Currency::USD,
+            merchant_reference_id: id_type::PaymentReferenceId::from_str("inv_123").unwrap(),
+            connector_transaction_id: None,
+            error_code: error_code.map(String::from),
+            error_message: None,
+            processor_payment_method_token: processor_payment_method_token.to_string(),
+            connector_customer_id: String::from("cus_123"),
+            connector_account_reference_id: String::from("gw_123"),
+            transaction_created_at: None,
+            status: common_enums::AttemptStatus::Failure,
+            payment_method_type: common_enums::PaymentMethod::Card,
+            payment_method_sub_type: common_enums::PaymentMethodType::Credit,
+            network_advice_code: network_advice_code.map(String::from),
+            network_decline_code: None,
+            network_error_message: None,
+            processor_response_details: None,
+            card_issuing_country: None,
+            card_details: None,
+        }
+    }
+
+    #[test]
+    fn test_attempt_data_merge_prefers_sync_data() {
+        let sync_data = get_attempt_data("pm_sync", Some("card_declined"), None);
+        let webhook_data = get_attempt_data("pm_webhook", Some("do_not_honor"), None);
+
+        let (merged_data, provenance) =
+            RevenueRecoveryAttemptData::merge(Some(sync_data), Some(webhook_data)).unwrap();
+
+        assert_eq!(merged_data.processor_payment_method_token, "pm_sync");
+        assert_eq!(merged_data.error_code.as_deref(), Some("card_declined"));
+        assert_eq!(
+            provenance.get("processor_payment_method_token"),
+            Some(common_enums::RecoveryDataSource::Sync)
+        );
+        assert_eq!(
+            provenance.get("error_code"),
+            Some(common_enums::RecoveryDataSource::Sync)
+        );
+        assert_eq!(
+            provenance.get("network_advice_code"),
+            Some(common_enums::RecoveryDataSource::Absent)
+        );
+    }
+
+    #[test]
+    fn test_attempt_data_merge_with_webhook_data_only() {
+        let webhook_data = get_attempt_data("pm_webhook", None, None);
+
+        let (merged_data, provenance) =
+            RevenueRecoveryAttemptData::merge(None, Some(webhook_data)).unwrap();
+
+        assert_eq!(merged_data.processor_payment_method_token, "pm_webhook");
+        assert_eq!(
+            provenance.get("amount"),
+            Some(common_enums::RecoveryDataSource::Webhook)
+        );
+        assert_eq!(
+            provenance.get("processor_payment_method_token"),
+            Some(common_enums::RecoveryDataSource::Webhook)
+        );
+        assert_eq!(
+            provenance.get("error_code"),
+            Some(common_enums::RecoveryDataSource::Absent)
+        );
+    }
+
+    #[test]
+    fn test_attempt_data_merge_fills_missing_sync_fields_from_webhook() {
+        let sync_data = get_attempt_data("", Some("card_declined"), None);
+        let webhook_data = get_attempt_data("pm_webhook", None, Some("01"));
+
+        let (merged_data, provenance) =
+            RevenueRecoveryAttemptData::merge(Some(sync_data), Some(webhook_data)).unwrap();
+
+        assert_eq!(merged_data.processor_payment_method_token, "pm_webhook");
+        assert_eq!(merged_data.network_advice_code.as_deref(), Some("01"));
+        assert_eq!(
+            provenance.get("amount"),
+            Some(common_enums::RecoveryDataSource::Sync)
+        );
+        assert_eq!(
+            provenance.get("error_code"),
+            Some(common_enums::RecoveryDataSource::Sync)
+        );
+        assert_eq!(
+            provenance.get("processor_payment_method_token"),
+            Some(common_enums::RecoveryDataSource::Webhook)
+        );
+        assert_eq!(
+            provenance.get("network_advice_code"),
+            Some(common_enums::RecoveryDataSource::Webhook)
+        );
+    }
+
+    #[test]
+    fn test_funding_type_of_card_details_is_preferred_as_payment_method_sub_type() {
+        let mut attempt_data = get_attempt_data("pm_sync", None, None);
+        assert_eq!(
+            attempt_data.get_payment_method_and_sub_type(),
+            (
+                common_enums::PaymentMethod::Card,
+                common_enums::PaymentMethodType::Credit
+            )
+        );
+
+        attempt_data.card_details = Some(common_types::payments::RevenueRecoveryCardDetails {
+            card_funding: Some(common_enums::PaymentMethodType::Debit),
+            ..Default::default()
+        });
+        assert_eq!(
+            attempt_data.get_payment_method_and_sub_type(),
+            (
+                common_enums::PaymentMethod::Card,
+                common_enums::PaymentMethodType::Debit
+            )
+        );
+    }
+
+    #[test]
+    fn test_attempt_data_merge_without_data() {
+        assert!(RevenueRecoveryAttemptData::merge(None, None).is_none());
+    }
+
+    #[test]
+    fn test_invoice_data_merge_fills_customer_details_from_webhook() {
+        let get_invoice_data = |customer_details| RevenueRecoveryInvoiceData {
+            amount: util_types::MinorUnit::new(1000),
+            currency: common_enums::Currency::USD,
+            merchant_reference_id: id_type::PaymentReferenceId::from_str("inv_123").unwrap(),
+            customer_details,
+            billing_connector_subscription_id: None,
+        };
+        let customer_details = RevenueRecoveryCustomerDetails {
+            billing_connector_customer_id: String::from("cus_123"),
+            email: None,
+            locale: None,
+        };
+
+        let (merged_data, provenance) = RevenueRecoveryInvoiceData::merge(
+            Some(get_invoice_data(None)),
+            Some(get_invoice_data(Some(customer_details))),
+        )
+        .unwrap();
+
+        assert!(merged_data.customer_details.is_some());
+        assert_eq!(
+            provenance.get("amount"),
+            Some(common_enums::RecoveryDataSource::Sync)
+        );
+        assert_eq!(
+            provenance.get("customer_details"),
+            Some(common_enums::RecoveryDataSource::Webhook)
+        );
+        assert_eq!(
+            provenance.get("billing_connector_subscription_id"),
+            Some(common_enums::RecoveryDataSource::Absent)
+        );
+    }
+
+    #[test]
+    fn test_invoice_data_from_invoice_sync_carries_customer_details() {
+        let invoice_sync_response = BillingConnectorInvoiceSyncResponse {
+            amount: util_types::MinorUnit::new(1000),
+            currency: common_enums::Currency::USD,
+            merchant_reference_id: id_type::PaymentReferenceId::from_str("inv_123").unwrap(),
+            customer_details: Some(BillingConnectorInvoiceCustomerDetails {
+                billing_connector_customer_id: String::from("cus_123"),
+                email: None,
+                locale: None,
+            }),
+            billing_connector_subscription_id: Some(String::from("sub_123")),
+        };
+
+        let (merged_data, provenance) = RevenueRecoveryInvoiceData::merge(
+            Some(RevenueRecoveryInvoiceData::from(invoice_sync_response)),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            merged_data
+                .customer_details
+                .map(|customer_details| customer_details.billing_connector_customer_id)
+                .as_deref(),
+            Some("cus_123")
+        );
+        assert_eq!(
+            provenance.get("customer_details"),
+            Some(common_enums::RecoveryDataSource::Sync)
+        );
+    }
+}
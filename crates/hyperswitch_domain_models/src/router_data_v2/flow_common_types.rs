@@ -154,4 +154,11 @@ pub struct UasFlowData {
 }
 
 #[derive(Debug, Clone)]
-pub struct BillingConnectorPaymentsSyncFlowData;
+pub struct BillingConnectorPaymentsSyncFlowData {
+    pub connector_meta_data: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BillingConnectorInvoiceSyncFlowData {
+    pub connector_meta_data: Option<pii::SecretSerdeValue>,
+}
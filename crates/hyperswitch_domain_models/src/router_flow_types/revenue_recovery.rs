@@ -1,4 +1,6 @@
 #[derive(Debug, Clone)]
 pub struct BillingConnectorPaymentsSync;
 #[derive(Debug, Clone)]
+pub struct BillingConnectorInvoiceSync;
+#[derive(Debug, Clone)]
 pub struct RecoveryRecordBack;
@@ -3,7 +3,26 @@ use common_enums::enums;
 #[derive(Debug, Clone)]
 pub struct BillingConnectorPaymentsSyncRequest {
     /// unique id for making billing connector psync call
-    pub billing_connector_psync_id: String,
+    pub billing_connector_psync_id: BillingConnectorPaymentsSyncId,
+    /// correlation id of the recovery which made the call, sent to the billing connectors which
+    /// accept one so that the call can be traced at the billing connector
+    pub recovery_correlation_id: Option<String>,
+}
+
+/// Reference used to look up the transaction at the billing connector
+#[derive(Debug, Clone)]
+pub enum BillingConnectorPaymentsSyncId {
+    /// transaction id at the billing connector
+    TransactionId(String),
+    /// invoice id at the billing connector, sent when the webhook does not carry a transaction id.
+    /// The billing connector returns the transactions made against the invoice.
+    InvoiceId(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct BillingConnectorInvoiceSyncRequest {
+    /// invoice id at the billing connector
+    pub billing_connector_invoice_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +28,53 @@ pub struct BillingConnectorPaymentsSyncResponse {
     pub payment_method_type: common_enums::enums::PaymentMethod,
     /// payment method sub type of the payment attempt.
     pub payment_method_sub_type: common_enums::enums::PaymentMethodType,
+    /// This field can be returned for both approved and refused Mastercard payments.
+    /// This code provides additional information about the type of transaction or the reason why the payment failed.
+    /// If the payment failed, the network advice code gives guidance on if and when you can retry the payment.
+    pub network_advice_code: Option<String>,
+    /// For card errors resulting from a card issuer decline, a brand specific 2, 3, or 4 digit code which indicates the reason the authorization failed.
+    pub network_decline_code: Option<String>,
+    /// A string indicating how to proceed with an network error if payment gateway provide one. This is used to understand the network error code better.
+    pub network_error_message: Option<String>,
+    /// Raw response details of the payment processor, like response code and avs / cvv check results.
+    pub processor_response_details: Option<common_types::payments::ProcessorResponseDetails>,
+    /// country of the issuer of the card, derived from the card BIN by the billing connector.
+    pub card_issuing_country: Option<common_enums::enums::CountryAlpha2>,
+    /// details of the card with which the transaction was made, like the card network and expiry.
+    #[serde(default)]
+    pub card_details: Option<common_types::payments::RevenueRecoveryCardDetails>,
+    /// subscription id at billing connector, to which the invoice belongs.
+    pub billing_connector_subscription_id: Option<String>,
+    /// Other transactions made against the invoice, returned by the billing connectors which send
+    /// all the transactions of the invoice when queried. Only the transaction which the webhook
+    /// refers to is consumed, the rest being dropped once it has been picked.
+    #[serde(default)]
+    pub invoice_transactions: Vec<BillingConnectorPaymentsSyncResponse>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BillingConnectorInvoiceSyncResponse {
+    /// invoice amount at billing connector, accepted in minor unit.
+    pub amount: MinorUnit,
+    /// currency of the invoice
+    pub currency: common_enums::enums::Currency,
+    /// merchant reference id at billing connector. ex: invoice_id
+    pub merchant_reference_id: common_utils::id_type::PaymentReferenceId,
+    /// contact details of the customer at billing connector, to whom the invoice is issued.
+    pub customer_details: Option<BillingConnectorInvoiceCustomerDetails>,
+    /// subscription id at billing connector, to which the invoice belongs.
+    pub billing_connector_subscription_id: Option<String>,
+}
+
+/// Contact details of the customer of the invoice at billing connector
+#[derive(Debug, Clone)]
+pub struct BillingConnectorInvoiceCustomerDetails {
+    /// customer id at billing connector
+    pub billing_connector_customer_id: String,
+    /// email of the customer
+    pub email: Option<common_utils::pii::Email>,
+    /// locale in which the customer prefers to be notified. ex: en-US
+    pub locale: Option<masking::Secret<String>>,
 }
 
 #[derive(Debug, Clone)]
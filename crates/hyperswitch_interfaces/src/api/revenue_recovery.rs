@@ -1,12 +1,16 @@
 //! Revenue Recovery Interface
 
 use hyperswitch_domain_models::{
-    router_flow_types::{BillingConnectorPaymentsSync, RecoveryRecordBack},
+    router_flow_types::{
+        BillingConnectorInvoiceSync, BillingConnectorPaymentsSync, RecoveryRecordBack,
+    },
     router_request_types::revenue_recovery::{
-        BillingConnectorPaymentsSyncRequest, RevenueRecoveryRecordBackRequest,
+        BillingConnectorInvoiceSyncRequest, BillingConnectorPaymentsSyncRequest,
+        RevenueRecoveryRecordBackRequest,
     },
     router_response_types::revenue_recovery::{
-        BillingConnectorPaymentsSyncResponse, RevenueRecoveryRecordBackResponse,
+        BillingConnectorInvoiceSyncResponse, BillingConnectorPaymentsSyncResponse,
+        RevenueRecoveryRecordBackResponse,
     },
 };
 
@@ -17,7 +21,10 @@ use super::ConnectorIntegration;
 /// trait RevenueRecovery
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
 pub trait RevenueRecovery:
-    ConnectorCommon + BillingConnectorPaymentsSyncIntegration + RevenueRecoveryRecordBack
+    ConnectorCommon
+    + BillingConnectorPaymentsSyncIntegration
+    + BillingConnectorInvoiceSyncIntegration
+    + RevenueRecoveryRecordBack
 {
 }
 
@@ -31,6 +38,16 @@ pub trait BillingConnectorPaymentsSyncIntegration:
 {
 }
 
+/// trait BillingConnectorInvoiceSyncIntegration
+pub trait BillingConnectorInvoiceSyncIntegration:
+    ConnectorIntegration<
+    BillingConnectorInvoiceSync,
+    BillingConnectorInvoiceSyncRequest,
+    BillingConnectorInvoiceSyncResponse,
+>
+{
+}
+
 /// trait RevenueRecoveryRecordBack
 pub trait RevenueRecoveryRecordBack:
     ConnectorIntegration<
@@ -2,14 +2,19 @@
 
 use hyperswitch_domain_models::{
     router_data_v2::flow_common_types::{
-        BillingConnectorPaymentsSyncFlowData, RevenueRecoveryRecordBackData,
+        BillingConnectorInvoiceSyncFlowData, BillingConnectorPaymentsSyncFlowData,
+        RevenueRecoveryRecordBackData,
+    },
+    router_flow_types::{
+        BillingConnectorInvoiceSync, BillingConnectorPaymentsSync, RecoveryRecordBack,
     },
-    router_flow_types::{BillingConnectorPaymentsSync, RecoveryRecordBack},
     router_request_types::revenue_recovery::{
-        BillingConnectorPaymentsSyncRequest, RevenueRecoveryRecordBackRequest,
+        BillingConnectorInvoiceSyncRequest, BillingConnectorPaymentsSyncRequest,
+        RevenueRecoveryRecordBackRequest,
     },
     router_response_types::revenue_recovery::{
-        BillingConnectorPaymentsSyncResponse, RevenueRecoveryRecordBackResponse,
+        BillingConnectorInvoiceSyncResponse, BillingConnectorPaymentsSyncResponse,
+        RevenueRecoveryRecordBackResponse,
     },
 };
 
@@ -17,7 +22,9 @@ use crate::connector_integration_v2::ConnectorIntegrationV2;
 
 /// trait RevenueRecoveryV2
 pub trait RevenueRecoveryV2:
-    BillingConnectorPaymentsSyncIntegrationV2 + RevenueRecoveryRecordBackV2
+    BillingConnectorPaymentsSyncIntegrationV2
+    + BillingConnectorInvoiceSyncIntegrationV2
+    + RevenueRecoveryRecordBackV2
 {
 }
 
@@ -32,6 +39,17 @@ pub trait BillingConnectorPaymentsSyncIntegrationV2:
 {
 }
 
+/// trait BillingConnectorInvoiceSyncIntegrationV2
+pub trait BillingConnectorInvoiceSyncIntegrationV2:
+    ConnectorIntegrationV2<
+    BillingConnectorInvoiceSync,
+    BillingConnectorInvoiceSyncFlowData,
+    BillingConnectorInvoiceSyncRequest,
+    BillingConnectorInvoiceSyncResponse,
+>
+{
+}
+
 /// trait RevenueRecoveryRecordBackV2
 pub trait RevenueRecoveryRecordBackV2:
     ConnectorIntegrationV2<
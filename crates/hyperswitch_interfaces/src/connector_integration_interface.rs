@@ -388,6 +388,45 @@ impl IncomingWebhook for ConnectorEnum {
             Self::New(connector) => connector.get_network_txn_id(request),
         }
     }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_revenue_recovery_attempt_details(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<
+        hyperswitch_domain_models::revenue_recovery::RevenueRecoveryAttemptData,
+        errors::ConnectorError,
+    > {
+        match self {
+            Self::Old(connector) => connector.get_revenue_recovery_attempt_details(request),
+            Self::New(connector) => connector.get_revenue_recovery_attempt_details(request),
+        }
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_revenue_recovery_event_timestamp(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_revenue_recovery_event_timestamp(request),
+            Self::New(connector) => connector.get_revenue_recovery_event_timestamp(request),
+        }
+    }
+
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    fn get_revenue_recovery_invoice_details(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<
+        hyperswitch_domain_models::revenue_recovery::RevenueRecoveryInvoiceData,
+        errors::ConnectorError,
+    > {
+        match self {
+            Self::Old(connector) => connector.get_revenue_recovery_invoice_details(request),
+            Self::New(connector) => connector.get_revenue_recovery_invoice_details(request),
+        }
+    }
 }
 
 impl ConnectorRedirectResponse for ConnectorEnum {
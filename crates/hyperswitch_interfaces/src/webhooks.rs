@@ -291,6 +291,14 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         .into())
     }
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+    /// get the time at which the revenue recovery event occurred at the billing connector
+    fn get_revenue_recovery_event_timestamp(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        Ok(None)
+    }
+    #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     /// get revenue recovery transaction details
     fn get_revenue_recovery_invoice_details(
         &self,
@@ -196,8 +196,28 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
+        routes::webhook_events::retrieve_webhook_event,
+        routes::webhook_events::retrieve_webhook_event_with_jwtauth,
         routes::webhook_events::list_webhook_delivery_attempts,
+        routes::webhook_events::list_webhook_delivery_attempts_with_jwtauth,
+        routes::webhook_events::retrieve_webhook_delivery_attempt_payload,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::retry_webhook_delivery_attempt_with_jwtauth,
+        routes::webhook_events::bulk_retry_webhook_deliveries,
+        routes::webhook_events::retrieve_webhook_bulk_retry_job,
+        routes::webhook_events::export_webhook_events,
+        routes::webhook_events::schedule_events_retention,
+        routes::webhook_events::list_events_archives,
+        routes::webhook_events::get_webhook_delivery_stats,
+        routes::webhook_events::get_webhook_delivery_stats_with_jwtauth,
+        routes::webhook_events::confirm_webhook_url_change_with_jwtauth,
+        routes::webhook_events::force_confirm_webhook_url_change,
+        routes::webhook_events::update_event_subscription,
+        routes::webhook_events::update_event_subscription_with_jwtauth,
+        routes::webhook_events::preview_retry_policy_with_jwtauth,
+        routes::webhook_events::scrub_event_payload_with_jwtauth,
+        routes::webhook_events::list_event_types,
+        routes::webhook_events::list_event_types_with_jwtauth,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -624,6 +644,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::PendingWebhookUrlChange,
+        api_models::admin::WebhookDigestConfig,
+        api_models::admin::WebhookSecondaryNotificationConfig,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
@@ -682,11 +705,59 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::BlocklistDataKind,
         api_models::enums::ErrorCategory,
         api_models::webhook_events::EventListItemResponse,
+        api_models::webhook_events::EventExportFormat,
+        api_models::webhook_events::EventExportRecord,
         api_models::webhook_events::EventRetrieveResponse,
+        api_models::webhook_events::EventDeliveryAttemptResponse,
+        api_models::webhook_events::EventRetrieveExpand,
+        api_models::webhook_events::EventDetailsResponse,
+        api_models::webhook_events::EventLatestDeliveryAttempt,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
-        api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::OutgoingWebhookRedirect,
+        api_models::pagination::EventListResponse,
+        api_models::pagination::EventDeliveryAttemptListResponse,
+        api_models::webhook_events::WebhookUrlChangeConfirmRequest,
+        api_models::webhook_events::WebhookUrlChangeConfirmResponse,
+        api_models::webhook_events::WebhookEventSubscriptionUpdateRequest,
+        api_models::webhook_events::WebhookEventSubscriptionResponse,
+        api_models::webhook_events::WebhookDeliveryRetryPolicy,
+        api_models::webhook_events::WebhookDeliveryRetryInterval,
+        api_models::webhook_events::WebhookRetryPolicyPreviewRequest,
+        api_models::webhook_events::WebhookRetryPolicyPreviewResponse,
+        api_models::webhook_events::WebhookDeliveryAttemptPreview,
+        api_models::webhook_events::EventPayloadScrubResponse,
+        api_models::webhook_events::ScrubbedEventPayload,
+        api_models::webhook_events::WebhookBulkRetryRequest,
+        api_models::webhook_events::WebhookDeliveryRetryRequest,
+        api_models::webhook_events::WebhookBulkRetryJobResponse,
+        api_models::webhook_events::WebhookDeliveryStatsRequest,
+        api_models::webhook_events::WebhookDeliveryStatsResponse,
+        api_models::webhook_events::WebhookDeliveryOutcomeCounts,
+        api_models::webhook_events::WebhookDeliveryEventTypeStats,
+        api_models::webhook_events::WebhookDeliveryDailyStats,
+        api_models::webhook_events::WebhookDeliveryLatencyStats,
+        api_models::webhook_events::EventTypeCatalogItem,
+        api_models::webhook_events::EventTypeCatalogResponse,
+        api_models::webhook_events::OutgoingWebhookUrlVerification,
+        api_models::webhook_events::OutgoingWebhookDigest,
+        api_models::webhook_events::WebhookDigestEventTypeCount,
+        api_models::webhook_events::WebhookDigestStatusCodeCount,
+        api_models::webhook_events::WebhookMetaNotification,
+        api_models::webhook_events::WebhookMetaNotificationDetails,
+        api_models::webhook_events::EventsRetentionScheduleResponse,
+        api_models::webhook_events::EventsArchiveListConstraints,
+        api_models::webhook_events::EventsArchiveResponse,
+        api_models::pagination::EventsArchiveListResponse,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::WebhookUrlChangeStatus,
+        api_models::enums::WebhookBulkRetryJobStatus,
+        api_models::enums::WebhookRedirectPolicy,
+        api_models::enums::WebhookDigestChannel,
+        api_models::enums::WebhookMetaNotificationKind,
+        api_models::enums::WebhookDeliveryFailureReason,
+        api_models::enums::WebhookDeliveryStatusCodeClass,
+        api_models::enums::EventDeliveryState,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::CustomerDetailsResponse,
@@ -150,7 +150,32 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_create,
 
         // Routes for Revenue Recovery flow under Process Tracker
-        routes::revenue_recovery::revenue_recovery_pt_retrieve_api
+        routes::revenue_recovery::revenue_recovery_pt_retrieve_api,
+        routes::revenue_recovery::revenue_recovery_analytics_api,
+        routes::revenue_recovery::revenue_recovery_retry_override_api,
+        routes::revenue_recovery::revenue_recovery_cancel_api,
+        routes::revenue_recovery::revenue_recovery_reschedule_api,
+        routes::revenue_recovery::revenue_recovery_status_api,
+        routes::revenue_recovery::revenue_recovery_decisions_api,
+        routes::revenue_recovery::revenue_recovery_collections_list_api,
+        routes::revenue_recovery::revenue_recovery_collection_assign_api,
+        routes::revenue_recovery::revenue_recovery_collection_resolve_api,
+        routes::revenue_recovery::revenue_recovery_tasks_list_api,
+        routes::revenue_recovery::revenue_recovery_review_tasks_list_api,
+        routes::revenue_recovery::revenue_recovery_review_task_requeue_api,
+        routes::revenue_recovery::revenue_recovery_tasks_bulk_requeue_api,
+        routes::revenue_recovery::revenue_recovery_kill_switch_retrieve_api,
+        routes::revenue_recovery::revenue_recovery_kill_switch_update_api,
+        routes::revenue_recovery::revenue_recovery_retry_schedule_create_api,
+        routes::revenue_recovery::revenue_recovery_retry_schedule_retrieve_api,
+        routes::revenue_recovery::revenue_recovery_retry_schedule_update_api,
+        routes::revenue_recovery::revenue_recovery_retry_schedule_delete_api,
+        routes::revenue_recovery::revenue_recovery_reconciliation_reports_api,
+        routes::revenue_recovery::revenue_recovery_overdue_tasks_api,
+        routes::revenue_recovery::revenue_recovery_schedule_preview_api,
+        routes::revenue_recovery::revenue_recovery_overview_api,
+        routes::revenue_recovery::revenue_recovery_task_snapshot_api,
+        routes::revenue_recovery::revenue_recovery_billing_sync_debug_api
     ),
     components(schemas(
         common_utils::types::MinorUnit,
@@ -176,6 +201,15 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::XenditChargeResponseData,
         common_types::payments::XenditMultipleSplitResponse,
         common_types::payments::XenditMultipleSplitRequest,
+        common_types::payments::ProcessorResponseDetails,
+        common_types::payments::RevenueRecoveryCardDetails,
+        common_types::payments::RevenueRecoveryConnectorFallback,
+        common_types::payments::RevenueRecoveryRetryNotification,
+        common_types::payments::RevenueRecoveryBlackoutCalendar,
+        common_types::payments::RevenueRecoveryBlackout,
+        common_types::payments::RevenueRecoveryConnectorRotation,
+        common_types::payments::RevenueRecoveryProcessorPaymentToken,
+        common_types::payments::RevenueRecoveryConnectorSwitch,
         common_types::domain::XenditSplitSubMerchantData,
         common_types::domain::AdyenSplitItem,
         common_types::refunds::StripeSplitRefundRequest,
@@ -209,6 +243,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::BusinessPayoutLinkConfig,
         api_models::admin::MerchantConnectorAccountFeatureMetadata,
         api_models::admin::RevenueRecoveryMetadata,
+        api_models::admin::RevenueRecoveryPaymentsSyncPolling,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerUpdateRequest,
         api_models::customers::CustomerDeleteResponse,
@@ -314,6 +349,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::ProfileCreate,
         api_models::admin::ProfileResponse,
+        api_models::admin::ProfileCapabilities,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkBackgroundImageConfig,
         api_models::admin::PaymentLinkConfigRequest,
@@ -495,9 +531,20 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentRevenueRecoveryMetadata,
+        api_models::payments::RevenueRecoveryRetryOverride,
+        api_models::payments::RevenueRecoveryDisputeHold,
+        api_models::payments::RevenueRecoveryManualCancellation,
         api_models::payments::BillingConnectorPaymentDetails,
         api_models::enums::PaymentConnectorTransmission,
         api_models::enums::TriggeredBy,
+        api_models::enums::RecoveryScheduleAdjustment,
+        api_models::enums::RecoveryScheduleAlgorithm,
+        api_models::enums::RecoveryReconciliationDriftKind,
+        api_models::enums::RecoveryDataSource,
+        api_models::enums::RecoveryConnectorSwitchReason,
+        api_models::enums::RecoveryCollectionStatus,
+        api_models::enums::RecoveryCollectionOutcome,
+        api_models::enums::RecoveryRecordBackStatus,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::PaymentAttemptRecordResponse,
         api_models::payments::PaymentAttemptAmountDetails,
@@ -610,6 +657,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::PendingWebhookUrlChange,
+        api_models::admin::WebhookDigestConfig,
+        api_models::admin::WebhookSecondaryNotificationConfig,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
@@ -660,7 +710,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::OutgoingWebhookRedirect,
+        api_models::webhook_events::OutgoingRecoveryRetryUpcoming,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::WebhookUrlChangeStatus,
+        api_models::enums::WebhookRedirectPolicy,
+        api_models::enums::WebhookDigestChannel,
+        api_models::enums::WebhookMetaNotificationKind,
+        api_models::enums::WebhookDeliveryFailureReason,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::CustomerDetailsResponse,
@@ -718,6 +775,53 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::PaymentMethodSessionResponse,
         api_models::payment_methods::AuthenticationDetails,
         api_models::process_tracker::revenue_recovery::RevenueRecoveryResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryCustomerDetails,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryAnalyticsResponse,
+        api_models::process_tracker::revenue_recovery::ScheduleAdjustmentBreakdown,
+        api_models::process_tracker::revenue_recovery::RetryOutcomeSummary,
+        api_models::process_tracker::revenue_recovery::PaymentConnectorBreakdown,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryAnalyticsGroupBy,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryOverrideRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryOverrideResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryCancelResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRescheduleRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRescheduleResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryStatusResponse,
+        api_models::process_tracker::ProcessTaskResponse,
+        api_models::process_tracker::ProcessTaskTrackingData,
+        api_models::process_tracker::revenue_recovery::RecoveryTaskResponse,
+        api_models::process_tracker::revenue_recovery::RecoveryTaskTrackingData,
+        api_models::process_tracker::revenue_recovery::RecoveryUnresolvedConnectorTrackingData,
+        api_models::process_tracker::revenue_recovery::RecoveryScheduleDecision,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryDecisionListResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryDecisionResponse,
+        api_models::pagination::RecoveryCollectionItemListResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryCollectionItemResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryCollectionAssignRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryCollectionResolveRequest,
+        api_models::pagination::RecoveryTaskListResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryTaskBulkRequeueRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryTaskBulkRequeueResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryKillSwitchRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryKillSwitchResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryKillSwitchChange,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryScheduleRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryScheduleResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryScheduleDeleteResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryReconciliationReportsResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryReconciliationReport,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryReconciliationDrift,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryOverdueTasksResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoverySchedulePreviewRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoverySchedulePreviewResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryOverviewResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryTaskSnapshotResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryPaymentSnapshot,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryTaskSnapshotPart,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryBillingSyncRequest,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryBillingSyncResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryBillingSyncTransaction,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryBillingSyncAttempt,
         api_models::enums::ProcessTrackerStatus,
         routes::payments::ForceSync,
     )),
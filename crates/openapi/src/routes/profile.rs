@@ -294,6 +294,10 @@ pub async fn routing_update_default_config() {}
             description = "Merchant ID of the profile.",
             example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
         ),
+        (
+            "include_capabilities" = Option<bool>, Query,
+            description = "Whether the capabilities of the profile are included in the response"
+        ),
     ),
     responses(
         (status = 200, description = "Profile Updated", body = ProfileResponse),
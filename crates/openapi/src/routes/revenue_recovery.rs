@@ -20,3 +20,711 @@
    security(("jwt_key" = []))
 )]
 pub async fn revenue_recovery_pt_retrieve_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Analytics
+///
+/// Retrieve the outcome of the revenue recovery retries, broken down by the schedule adjustments applied on them and optionally by the payment connector through which they were made
+#[utoipa::path(
+    get,
+    path = "/v2/process_tracker/revenue_recovery_workflow/analytics",
+    params(
+        ("start_time" = String, Query, description = "The start time of the window in which the retry attempts were created"),
+        ("end_time" = Option<String>, Query, description = "The end time of the window in which the retry attempts were created"),
+        ("group_by" = Option<RevenueRecoveryAnalyticsGroupBy>, Query, description = "Dimension by which the outcome of the retry attempts is additionally broken down"),
+        ("decline_class" = Option<String>, Query, description = "Only consider the retry attempts made after a decline of this decline class"),
+        ("card_network" = Option<CardNetwork>, Query, description = "Only consider the retry attempts made with a card of this card network"),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Analytics Retrieved Successfully", body = RevenueRecoveryAnalyticsResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Analytics",
+   security(("jwt_key" = []))
+)]
+pub async fn revenue_recovery_analytics_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Override Retries
+///
+/// Override the retry threshold and schedule of the revenue recovery retries for a specific payment
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}/recovery/overrides",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the Payment Intent"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body(
+        content = RevenueRecoveryRetryOverrideRequest,
+        examples(
+            (
+                "Allow 3 more retries, one every day" = (
+                    value = json!({
+                        "schedule": ["2025-01-01T10:00:00.000Z", "2025-01-02T10:00:00.000Z", "2025-01-03T10:00:00.000Z"],
+                        "reason": "High value invoice, retry daily"
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Retry override applied", body = RevenueRecoveryRetryOverrideResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Payment Intent Not Found"),
+        (status = 412, description = "Revenue recovery is not in progress for the payment"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Override Revenue Recovery Retries",
+   security(("admin_api_key" = []), ("jwt_key" = []))
+)]
+pub async fn revenue_recovery_retry_override_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Cancel Retry
+///
+/// Cancel the scheduled revenue recovery retry of a specific payment. Cancelling a retry which has already finished does not change it
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}/recovery/cancel",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the Payment Intent"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Scheduled retry cancelled, or already finished", body = RevenueRecoveryCancelResponse),
+        (status = 404, description = "Payment Intent or the scheduled retry Not Found"),
+        (status = 412, description = "Revenue recovery is not in progress for the payment"),
+        (status = 422, description = "The retry is being made right now, the request can be retried later"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Cancel Revenue Recovery Retry",
+   security(("admin_api_key" = []), ("jwt_key" = []))
+)]
+pub async fn revenue_recovery_cancel_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Reschedule Retry
+///
+/// Move the next revenue recovery retry of a specific payment to the given time. A payment which is eligible for a retry but has none scheduled is scheduled afresh
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}/recovery/reschedule",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the Payment Intent"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body(
+        content = RevenueRecoveryRescheduleRequest,
+        examples(
+            (
+                "Retry at the start of the next business day" = (
+                    value = json!({
+                        "schedule_time": "2025-01-02T09:00:00.000Z"
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Retry rescheduled", body = RevenueRecoveryRescheduleResponse),
+        (status = 400, description = "The time is in the past or beyond the max recovery window of the merchant"),
+        (status = 404, description = "Payment Intent Not Found"),
+        (status = 412, description = "Revenue recovery is not in progress for the payment, or the payment is not eligible for a retry"),
+        (status = 422, description = "The retry is being made right now, the request can be retried later"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Reschedule Revenue Recovery Retry",
+   security(("admin_api_key" = []), ("jwt_key" = []))
+)]
+pub async fn revenue_recovery_reschedule_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Payment Status
+///
+/// Retrieve what revenue recovery is doing for a specific payment, like the attempts made so far and the next scheduled retry
+#[utoipa::path(
+    get,
+    path = "/v2/payments/{id}/recovery",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the Payment Intent"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Status Retrieved Successfully", body = RevenueRecoveryStatusResponse),
+        (status = 404, description = "Payment Intent Not Found or not being recovered"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Status",
+   security(("admin_api_key" = []), ("jwt_key" = []))
+)]
+pub async fn revenue_recovery_status_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - List Decisions
+///
+/// List the decisions taken by revenue recovery for every recovery webhook processed for a payment, latest first
+#[utoipa::path(
+    get,
+    path = "/v2/payments/{id}/recovery/decisions",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the Payment Intent"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of decisions to include in the response, defaults to 10 and cannot exceed 100"),
+        ("offset" = Option<u16>, Query, description = "The number of decisions to skip"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Decisions Retrieved Successfully", body = RevenueRecoveryDecisionListResponse),
+        (status = 400, description = "Invalid pagination parameters"),
+        (status = 404, description = "Payment Intent Not Found"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "List Revenue Recovery Decisions",
+   security(("admin_api_key" = []), ("jwt_key" = []))
+)]
+pub async fn revenue_recovery_decisions_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - List Manual Collections
+///
+/// List the invoices handed off to the manual collection queue after revenue recovery exhausted its retries, latest first. The items are also returned under the deprecated `data` field, along with their number under the deprecated `count` field
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/collections",
+    params(
+        ("status" = Option<RecoveryCollectionStatus>, Query, description = "Only include the collection items in this status"),
+        ("assignee" = Option<String>, Query, description = "Only include the collection items assigned to this user"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of collection items to include in the response, defaults to 10 and cannot exceed 100"),
+        ("offset" = Option<u16>, Query, description = "The number of collection items to skip"),
+    ),
+    responses(
+        (status = 200, description = "Manual Collection Items Retrieved Successfully", body = RecoveryCollectionItemListResponse),
+        (status = 400, description = "Invalid pagination parameters"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "List Revenue Recovery Manual Collections",
+   security(("jwt_key" = []))
+)]
+pub async fn revenue_recovery_collections_list_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Assign Manual Collection
+///
+/// Assign an open manual collection item to a user of the dunning team
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/collections/{id}/assign",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the collection item"),
+    ),
+    request_body(
+        content = RevenueRecoveryCollectionAssignRequest,
+        examples(
+            (
+                "Assign to a dunning agent" = (
+                    value = json!({
+                        "assignee": "agent@example.com"
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Collection item assigned", body = RevenueRecoveryCollectionItemResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Collection item not found"),
+        (status = 412, description = "Collection item is already resolved"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Assign Revenue Recovery Manual Collection",
+   security(("jwt_key" = []))
+)]
+pub async fn revenue_recovery_collection_assign_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Resolve Manual Collection
+///
+/// Resolve an open manual collection item. Resolving it as paid externally records a successful attempt against the payment
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/collections/{id}/resolve",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the collection item"),
+    ),
+    request_body(
+        content = RevenueRecoveryCollectionResolveRequest,
+        examples(
+            (
+                "Customer paid by bank transfer" = (
+                    value = json!({
+                        "outcome": "paid_externally"
+                    })
+                )
+            ),
+            (
+                "Write the invoice off" = (
+                    value = json!({
+                        "outcome": "uncollectible"
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Collection item resolved", body = RevenueRecoveryCollectionItemResponse),
+        (status = 404, description = "Collection item not found"),
+        (status = 412, description = "Collection item is already resolved"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Resolve Revenue Recovery Manual Collection",
+   security(("jwt_key" = []))
+)]
+pub async fn revenue_recovery_collection_resolve_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - List Tasks
+///
+/// List the process tracker tasks which retry the payments of the merchant, in the order of their schedule time. The failed payments held in review since their payment connector could not be resolved are listed as well. The next page is fetched by passing the `next_cursor` of the page as `starting_after`. The tasks are also returned under the deprecated `data` field, along with their number under the deprecated `count` field
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/tasks/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("profile_id" = Option<String>, Query, description = "Only include the tasks of the payments of this profile"),
+        ("status" = Option<ProcessTrackerStatus>, Query, description = "Only include the tasks in this status"),
+        ("schedule_time_start" = Option<PrimitiveDateTime>, Query, description = "Only include the tasks scheduled at or after this time"),
+        ("schedule_time_end" = Option<PrimitiveDateTime>, Query, description = "Only include the tasks scheduled at or before this time"),
+        ("billing_connector" = Option<Connector>, Query, description = "Only include the tasks of the payments of this billing connector. The tasks created before the tasks were tagged with their billing connector are not included"),
+        ("starting_after" = Option<String>, Query, description = "The identifier for the last task of the previous page"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of tasks to include in the response, defaults to 10 and cannot exceed 100"),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Tasks Retrieved Successfully", body = RecoveryTaskListResponse),
+        (status = 400, description = "Invalid pagination parameters"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "List Revenue Recovery Tasks",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_tasks_list_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - List Tasks in Review
+///
+/// List the process tracker tasks of the merchant which were moved to review, since their executions failed with an error too many times in a row. The tasks in review are not picked up until they are requeued. The tasks are also returned under the deprecated `data` field, along with their number under the deprecated `count` field
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/tasks/{merchant_id}/review",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("profile_id" = Option<String>, Query, description = "Only include the tasks of the payments of this profile"),
+        ("starting_after" = Option<String>, Query, description = "The identifier for the last task of the previous page"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of tasks to include in the response, defaults to 10 and cannot exceed 100"),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Tasks in Review Retrieved Successfully", body = RecoveryTaskListResponse),
+        (status = 400, description = "Invalid pagination parameters"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "List Revenue Recovery Tasks in Review",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_review_tasks_list_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Requeue Task in Review
+///
+/// Requeue a task which was moved to review after its executions failed, once the cause of the failures has been fixed. The task is picked up right away with its failed executions reset. For a payment held in review since the payment connector of its failed attempt could not be resolved, the attempt is recorded once the payment connector is mapped and the task scheduled for the retry of the payment is returned
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/tasks/{merchant_id}/{task_id}/requeue",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("task_id" = String, Path, description = "The identifier for the task"),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Task Requeued Successfully", body = RecoveryTaskResponse),
+        (status = 404, description = "Task not found"),
+        (status = 412, description = "Task was not moved to review after failed executions, or the payment connector of the payment is still not mapped"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Requeue Revenue Recovery Task in Review",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_review_task_requeue_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Requeue Stuck Tasks
+///
+/// Requeue the tasks of the merchant left stuck after an incident, which are either locked by a scheduler worker without being executed or moved to review after failed executions. The matching tasks are moved back to pending without consuming a retry of the payment, so the request can be made again until `has_more` is false. A dry run only counts the tasks which would be requeued
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/tasks/{merchant_id}/requeue",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+    ),
+    request_body(
+        content = RevenueRecoveryTaskBulkRequeueRequest,
+        examples(
+            (
+                "Count the locked tasks of a billing connector" = (
+                    value = json!({
+                        "status": "processing",
+                        "billing_connector": "chargebee",
+                        "schedule_time_start": "2025-01-01T10:00:00.000Z",
+                        "dry_run": true
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Stuck Revenue Recovery Tasks Requeued Successfully", body = RevenueRecoveryTaskBulkRequeueResponse),
+        (status = 400, description = "Invalid data"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Requeue Stuck Revenue Recovery Tasks",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_tasks_bulk_requeue_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Retrieve Kill Switch
+///
+/// Retrieve the global kill switch of revenue recovery along with the changes made to it. The retries of all the merchants are halted while the switch is active
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/kill_switch",
+    responses(
+        (status = 200, description = "Kill Switch Retrieved Successfully", body = RevenueRecoveryKillSwitchResponse),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Kill Switch",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_kill_switch_retrieve_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Update Kill Switch
+///
+/// Turn the global kill switch of revenue recovery on or off. The retries halted while the switch is active are made once it is turned off
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/kill_switch",
+    request_body(
+        content = RevenueRecoveryKillSwitchRequest,
+        examples(
+            (
+                "Halt all the retries" = (
+                    value = json!({
+                        "is_active": true,
+                        "reason": "Retries are charging the customers twice",
+                        "actor": "oncall@example.com"
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Kill Switch Updated Successfully", body = RevenueRecoveryKillSwitchResponse),
+        (status = 400, description = "Invalid data"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Update Revenue Recovery Kill Switch",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_kill_switch_update_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Create Retry Schedule
+///
+/// Create the retry schedule of a merchant, used in place of the default retry configuration for scheduling the retries of the merchant. The retries which are already scheduled are not moved
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/retry_schedules/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+    ),
+    request_body(
+        content = RevenueRecoveryRetryScheduleRequest,
+        examples(
+            (
+                "Retry after 1 hour, 1 day and 3 days, then weekly" = (
+                    value = json!({
+                        "name": "weekly_backoff",
+                        "delays_in_secs": [3600, 86400, 259200],
+                        "default_delay_in_secs": 604800,
+                        "max_retries": 8
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Retry Schedule Created Successfully", body = RevenueRecoveryRetryScheduleResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 409, description = "Retry schedule already exists for the merchant"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Create Revenue Recovery Retry Schedule",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_retry_schedule_create_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Retrieve Retry Schedule
+///
+/// Retrieve the retry schedule of a merchant
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/retry_schedules/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+    ),
+    responses(
+        (status = 200, description = "Retry Schedule Retrieved Successfully", body = RevenueRecoveryRetryScheduleResponse),
+        (status = 404, description = "Retry schedule not found for the merchant"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Retry Schedule",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_retry_schedule_retrieve_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Update Retry Schedule
+///
+/// Replace the retry schedule of a merchant. The schedule is applied to the retries scheduled after the update, the retries which are already scheduled are not moved
+#[utoipa::path(
+    put,
+    path = "/v2/recovery/retry_schedules/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+    ),
+    request_body(
+        content = RevenueRecoveryRetryScheduleRequest,
+        examples(
+            (
+                "Retry after 1 hour, 1 day and 3 days, then weekly" = (
+                    value = json!({
+                        "name": "weekly_backoff",
+                        "delays_in_secs": [3600, 86400, 259200],
+                        "default_delay_in_secs": 604800,
+                        "max_retries": 8
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Retry Schedule Updated Successfully", body = RevenueRecoveryRetryScheduleResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Retry schedule not found for the merchant"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Update Revenue Recovery Retry Schedule",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_retry_schedule_update_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Delete Retry Schedule
+///
+/// Delete the retry schedule of a merchant, the retries of the merchant scheduled after it are scheduled as per the default retry configuration
+#[utoipa::path(
+    delete,
+    path = "/v2/recovery/retry_schedules/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+    ),
+    responses(
+        (status = 200, description = "Retry Schedule Deleted Successfully", body = RevenueRecoveryRetryScheduleDeleteResponse),
+        (status = 404, description = "Retry schedule not found for the merchant"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Delete Revenue Recovery Retry Schedule",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_retry_schedule_delete_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Retrieve Reconciliation Reports
+///
+/// Retrieve the reports of the latest nightly reconciliations of the open invoices of a billing connector account, the latest report first. The invoices found paid or voided at the billing connector are listed in the report, along with whether their recovery was stopped
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/reconciliation/{merchant_id}/{merchant_connector_account_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+        ("merchant_connector_account_id" = String, Path, description = "The identifier for the billing connector account"),
+    ),
+    responses(
+        (status = 200, description = "Reconciliation Reports Retrieved Successfully", body = RevenueRecoveryReconciliationReportsResponse),
+        (status = 404, description = "Billing connector account not found for the merchant"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Reconciliation Reports",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_reconciliation_reports_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Overdue Tasks
+///
+/// Retrieve the number of recovery tasks of a merchant which have not been picked up by the scheduler although their schedule time was more than the configured threshold ago. Meant for debugging the delays in the execution of the retries
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/tasks/{merchant_id}/overdue",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+    ),
+    responses(
+        (status = 200, description = "Overdue Tasks Counted Successfully", body = RevenueRecoveryOverdueTasksResponse),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Overdue Tasks",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_overdue_tasks_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Preview Retry Schedule
+///
+/// Preview the time at which the next retry of a payment would be made after a failed attempt, as per the retry configuration of the merchant. The delay of a decline class with a delay profile is picked at random within the bounds of the profile, the same as for the actual retries. Nothing is scheduled by the preview
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/schedule/preview",
+    request_body(
+        content = RevenueRecoverySchedulePreviewRequest,
+        examples(
+            (
+                "Third attempt failed due to insufficient funds" = (
+                    value = json!({
+                        "merchant_id": "merchant_1234",
+                        "retry_count": 3,
+                        "decline_code": "insufficient_funds",
+                        "failed_at": "2025-01-01T10:00:00.000Z"
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Retry Schedule Previewed Successfully", body = RevenueRecoverySchedulePreviewResponse),
+        (status = 400, description = "Invalid data"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Preview Revenue Recovery Retry Schedule",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_schedule_preview_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Overview
+///
+/// Retrieve a snapshot of the recovery of the failed payments of a merchant: the payments being recovered, the recovery tasks which are pending, overdue or held in review, the attempts recorded in the last 24 hours by whom they were triggered and the payments whose retries were exhausted in the last 7 days
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/overview/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+        ("profile_id" = Option<String>, Query, description = "Only include the payments of this profile"),
+    ),
+    responses(
+        (status = 200, description = "Recovery Overview Retrieved Successfully", body = RevenueRecoveryOverviewResponse),
+        (status = 422, description = "The recovery overview could not be counted in time, the request can be retried later"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Overview",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_overview_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Task Snapshot
+///
+/// Retrieve a snapshot of a recovery task for debugging a stuck recovery: the task with its tracking data, the payment it recovers, the attempts of the payment and the latest decisions taken on the billing connector webhooks of the payment. The tokens of the payment are redacted, and the parts which could not be fetched are listed instead of failing the request
+#[utoipa::path(
+    get,
+    path = "/v2/recovery/tasks/{merchant_id}/{task_id}/snapshot",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+        ("task_id" = String, Path, description = "The identifier for the task"),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Task Snapshot Retrieved Successfully", body = RevenueRecoveryTaskSnapshotResponse),
+        (status = 404, description = "Task not found"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Task Snapshot",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_task_snapshot_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Billing Connector Payments Sync
+///
+/// Sync a transaction or the latest transaction of an invoice from the billing connector, for debugging the recovery without replaying the webhook of the billing connector. Returns the transaction parsed from the response of the billing connector and the attempt derived from it, with the payment method token and the customer id at the payment connector masked. No attempt is recorded and no recovery task is created
+#[utoipa::path(
+    post,
+    path = "/v2/recovery/debug/{merchant_id}/billing-sync",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant account"),
+    ),
+    request_body(
+        content = RevenueRecoveryBillingSyncRequest,
+        examples(
+            (
+                "Sync a transaction" = (
+                    value = json!({
+                        "billing_connector_id": "mca_1234",
+                        "transaction_id": "txn_1234"
+                    })
+                )
+            ),
+            (
+                "Sync the latest transaction of an invoice" = (
+                    value = json!({
+                        "billing_connector_id": "mca_1234",
+                        "invoice_id": "inv_1234"
+                    })
+                )
+            ),
+        ),
+    ),
+    responses(
+        (status = 200, description = "Transaction Synced from the Billing Connector", body = RevenueRecoveryBillingSyncResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Billing connector account not found"),
+        (status = 422, description = "Payments sync with the billing connector failed"),
+        (status = 429, description = "Too many requests"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Sync Revenue Recovery Billing Connector Payment",
+   security(("admin_api_key" = []))
+)]
+pub async fn revenue_recovery_billing_sync_debug_api() {}
@@ -1,6 +1,6 @@
 /// Events - List
 ///
-/// List all Events associated with a Merchant Account or Profile.
+/// List all Events associated with a Merchant Account or Profile, along with the total number of Events matching the constraints. Each Event includes the number of its delivery attempts and the time of its latest attempt. The events are also returned under the deprecated `events` field.
 #[utoipa::path(
     get,
     path = "/events/{merchant_id}",
@@ -14,31 +14,38 @@
             "created_after" = Option<PrimitiveDateTime>,
             Query,
             description = "Only include Events created after the specified time. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "created_before" = Option<PrimitiveDateTime>,
             Query,
             description = "Only include Events created before the specified time. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "limit" = Option<i64>,
             Query,
             description = "The maximum number of Events to include in the response. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "offset" = Option<i64>,
             Query,
             description = "The number of Events to skip when retrieving the list of Events.
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
+        ),
+        (
+            "cursor" = Option<String>,
+            Query,
+            description = "The `next_cursor` of the previous page, to retrieve the Events of the page after it. \
+                           Cannot be combined with `offset`. \
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "object_id" = Option<String>,
             Query,
             description = "Only include Events associated with the specified object (Payment Intent ID, Refund ID, etc.). \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "profile_id" = Option<String>,
@@ -50,9 +57,31 @@
             Query,
             description = "Only include Events which are ultimately delivered to the merchant."
         ),
+        (
+            "delivery_state" = Option<EventDeliveryState>,
+            Query,
+            description = "Only include Events in the specified delivery state. \
+                           The `pending_first_attempt` state includes the Events which were never sent to the merchant, unlike the `failed` state."
+        ),
+        (
+            "event_types" = Option<Vec<EventType>>,
+            Query,
+            description = "Only include Events of any of the specified event types. \
+                           The parameter can be repeated, or the event types can be provided as a comma separated list."
+        ),
+        (
+            "event_class" = Option<EventClass>,
+            Query,
+            description = "Only include Events of the specified event class."
+        ),
+        (
+            "delivery_status_code_class" = Option<WebhookDeliveryStatusCodeClass>,
+            Query,
+            description = "Only include Events whose latest delivery attempt received a response status code of the specified class, or failed with the specified kind of error."
+        ),
     ),
     responses(
-        (status = 200, description = "List of Events retrieved successfully", body = TotalEventsResponse),
+        (status = 200, description = "List of Events retrieved successfully", body = EventListResponse),
     ),
     tag = "Event",
     operation_id = "List all Events associated with a Merchant Account or Profile",
@@ -62,7 +91,7 @@ pub fn list_initial_webhook_delivery_attempts() {}
 
 /// Events - List
 ///
-/// List all Events associated with a Profile.
+/// List all Events associated with a Profile, along with the total number of Events matching the constraints. Each Event includes the number of its delivery attempts and the time of its latest attempt. The events are also returned under the deprecated `events` field.
 #[utoipa::path(
     get,
     path = "/events/profile/list",
@@ -71,35 +100,64 @@ pub fn list_initial_webhook_delivery_attempts() {}
             "created_after" = Option<PrimitiveDateTime>,
             Query,
             description = "Only include Events created after the specified time. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "created_before" = Option<PrimitiveDateTime>,
             Query,
             description = "Only include Events created before the specified time. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "limit" = Option<i64>,
             Query,
             description = "The maximum number of Events to include in the response. \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "offset" = Option<i64>,
             Query,
             description = "The number of Events to skip when retrieving the list of Events.
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
+        ),
+        (
+            "cursor" = Option<String>,
+            Query,
+            description = "The `next_cursor` of the previous page, to retrieve the Events of the page after it. \
+                           Cannot be combined with `offset`. \
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
         ),
         (
             "object_id" = Option<String>,
             Query,
             description = "Only include Events associated with the specified object (Payment Intent ID, Refund ID, etc.). \
-                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified."
+        ),
+        (
+            "delivery_state" = Option<EventDeliveryState>,
+            Query,
+            description = "Only include Events in the specified delivery state. \
+                           The `pending_first_attempt` state includes the Events which were never sent to the merchant, unlike the `failed` state."
+        ),
+        (
+            "event_types" = Option<Vec<EventType>>,
+            Query,
+            description = "Only include Events of any of the specified event types. \
+                           The parameter can be repeated, or the event types can be provided as a comma separated list."
+        ),
+        (
+            "event_class" = Option<EventClass>,
+            Query,
+            description = "Only include Events of the specified event class."
+        ),
+        (
+            "delivery_status_code_class" = Option<WebhookDeliveryStatusCodeClass>,
+            Query,
+            description = "Only include Events whose latest delivery attempt received a response status code of the specified class, or failed with the specified kind of error."
         ),
     ),
     responses(
-        (status = 200, description = "List of Events retrieved successfully", body = Vec<EventListItemResponse>),
+        (status = 200, description = "List of Events retrieved successfully", body = EventListResponse),
     ),
     tag = "Event",
     operation_id = "List all Events associated with a Profile",
@@ -107,18 +165,79 @@ pub fn list_initial_webhook_delivery_attempts() {}
 )]
 pub fn list_initial_webhook_delivery_attempts_with_jwtauth() {}
 
+/// Events - Retrieve
+///
+/// Retrieve an Event along with the outcome of its latest delivery attempt. Events of other Merchant Accounts are reported as not found.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/{event_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+        (
+            "expand" = Option<EventRetrieveExpand>,
+            Query,
+            description = "Include the specified details of the Event. With `payload`, the request and response of the initial delivery attempt are included."
+        ),
+    ),
+    responses(
+        (status = 200, description = "The Event was retrieved", body = EventDetailsResponse),
+        (status = 404, description = "The Event was not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_event() {}
+
+/// Events - Retrieve
+///
+/// Retrieve an Event of the Profile along with the outcome of its latest delivery attempt. Events of other Profiles are reported as not found.
+#[utoipa::path(
+    get,
+    path = "/events/profile/{event_id}",
+    params(
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+        (
+            "expand" = Option<EventRetrieveExpand>,
+            Query,
+            description = "Include the specified details of the Event. With `payload`, the request and response of the initial delivery attempt are included."
+        ),
+    ),
+    responses(
+        (status = 200, description = "The Event was retrieved", body = EventDetailsResponse),
+        (status = 404, description = "The Event was not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve an Event of a Profile",
+    security(("jwt_key" = []))
+)]
+pub fn retrieve_webhook_event_with_jwtauth() {}
+
 /// Events - Delivery Attempt List
 ///
-/// List all delivery attempts for the specified Event.
+/// List all delivery attempts for the specified Event. The request and response bodies of each attempt are previewed, the complete request and response of an attempt can be retrieved using the Delivery Attempt Payload API. The delivery attempts are returned as an array, or in the `EventDeliveryAttemptListResponse` envelope when `paginated` is set.
 #[utoipa::path(
     get,
     path = "/events/{merchant_id}/{event_id}/attempts",
     params(
         ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
         ("event_id" = String, Path, description = "The unique identifier for the Event"),
+        (
+            "include_payloads" = Option<bool>,
+            Query,
+            deprecated,
+            description = "Include the complete request and response of each delivery attempt instead of the previews of their bodies. \
+                           To be removed in the next release, use the Delivery Attempt Payload API instead."
+        ),
+        (
+            "paginated" = Option<bool>,
+            Query,
+            description = "Return the delivery attempts in the `EventDeliveryAttemptListResponse` envelope instead of as an array."
+        ),
     ),
     responses(
-        (status = 200, description = "List of delivery attempts retrieved successfully", body = Vec<EventRetrieveResponse>),
+        (status = 200, description = "List of delivery attempts retrieved successfully", body = Vec<EventDeliveryAttemptResponse>),
     ),
     tag = "Event",
     operation_id = "List all delivery attempts for an Event",
@@ -126,9 +245,62 @@ pub fn list_initial_webhook_delivery_attempts_with_jwtauth() {}
 )]
 pub fn list_webhook_delivery_attempts() {}
 
+/// Events - Delivery Attempt List
+///
+/// List all delivery attempts for the specified Event of the Profile. The request and response bodies of each attempt are previewed. Events of other Profiles are reported as not found. The delivery attempts are returned as an array, or in the `EventDeliveryAttemptListResponse` envelope when `paginated` is set.
+#[utoipa::path(
+    get,
+    path = "/events/profile/{event_id}/attempts",
+    params(
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+        (
+            "include_payloads" = Option<bool>,
+            Query,
+            deprecated,
+            description = "Include the complete request and response of each delivery attempt instead of the previews of their bodies. \
+                           To be removed in the next release, use the Delivery Attempt Payload API instead."
+        ),
+        (
+            "paginated" = Option<bool>,
+            Query,
+            description = "Return the delivery attempts in the `EventDeliveryAttemptListResponse` envelope instead of as an array."
+        ),
+    ),
+    responses(
+        (status = 200, description = "List of delivery attempts retrieved successfully", body = Vec<EventDeliveryAttemptResponse>),
+        (status = 404, description = "The Event was not found"),
+    ),
+    tag = "Event",
+    operation_id = "List all delivery attempts for an Event of a Profile",
+    security(("jwt_key" = []))
+)]
+pub fn list_webhook_delivery_attempts_with_jwtauth() {}
+
+/// Events - Delivery Attempt Payload
+///
+/// Retrieve the complete request and response, headers and bodies, of a delivery attempt of the specified Event.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/{event_id}/attempts/{attempt_id}/payload",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+        ("attempt_id" = String, Path, description = "The unique identifier for the delivery attempt of the Event"),
+    ),
+    responses(
+        (status = 200, description = "The payload of the delivery attempt was retrieved", body = EventRetrieveResponse),
+        (status = 404, description = "The delivery attempt does not exist, or is not an attempt of the Event"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the payload of a delivery attempt of an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_delivery_attempt_payload() {}
+
 /// Events - Manual Retry
 ///
-/// Manually retry the delivery of the specified Event.
+/// Manually retry the delivery of the specified Event. The delivery can optionally be sent to an
+/// override URL instead of the webhook URL configured for the profile, which is left unchanged.
 #[utoipa::path(
     post,
     path = "/events/{merchant_id}/{event_id}/retry",
@@ -136,6 +308,10 @@ pub fn list_webhook_delivery_attempts() {}
         ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
         ("event_id" = String, Path, description = "The unique identifier for the Event"),
     ),
+    request_body(
+        content = WebhookDeliveryRetryRequest,
+        description = "Optional. Omit the body to deliver the Event to the configured webhook URL."
+    ),
     responses(
         (
             status = 200,
@@ -149,3 +325,393 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Manual Retry
+///
+/// Manually retry the delivery of the specified Event of the Profile. The delivery can optionally be sent to an
+/// override URL instead of the webhook URL configured for the profile. Events of other Profiles are reported as not found.
+#[utoipa::path(
+    post,
+    path = "/events/profile/{event_id}/retry",
+    params(
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+    ),
+    request_body(
+        content = WebhookDeliveryRetryRequest,
+        description = "Optional. Omit the body to deliver the Event to the configured webhook URL."
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The delivery of the Event was attempted. \
+                           Check the `response` field in the response payload to identify the status of the delivery attempt.",
+            body = EventRetrieveResponse
+        ),
+        (status = 404, description = "The Event was not found"),
+    ),
+    tag = "Event",
+    operation_id = "Manually retry the delivery of an Event of a Profile",
+    security(("jwt_key" = []))
+)]
+pub fn retry_webhook_delivery_attempt_with_jwtauth() {}
+
+/// Events - Bulk Retry
+///
+/// Retry the delivery of the Events of the Merchant Account which were not delivered, over a time range. The Events are redelivered asynchronously, the progress of the redelivery can be checked with the returned job ID. Events which were delivered in the meantime are skipped.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/retry",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body = WebhookBulkRetryRequest,
+    responses(
+        (status = 200, description = "The bulk retry job was created", body = WebhookBulkRetryJobResponse),
+        (status = 400, description = "Invalid time range or maximum count"),
+    ),
+    tag = "Event",
+    operation_id = "Retry the failed deliveries of the Events of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn bulk_retry_webhook_deliveries() {}
+
+/// Events - Retrieve Bulk Retry Job
+///
+/// Retrieve the progress of a bulk retry job, as the number of the Events queued, redelivered successfully, failed and skipped.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/retry_jobs/{job_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("job_id" = String, Path, description = "The unique identifier for the bulk retry job"),
+    ),
+    responses(
+        (status = 200, description = "The bulk retry job was retrieved", body = WebhookBulkRetryJobResponse),
+        (status = 404, description = "The bulk retry job does not exist"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve a bulk retry job of Event deliveries",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_bulk_retry_job() {}
+
+/// Events - Export
+///
+/// Export the Events associated with a Merchant Account or Profile as a file, streamed as the Events are read, latest Event first. The Events are filtered as when listing them, within the past 90 days. Exports including more Events than the configured maximum are rejected.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/export",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        (
+            "format" = EventExportFormat,
+            Query,
+            description = "The format of the exported file."
+        ),
+        (
+            "created_after" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include Events created after the specified time. Defaults to 90 days before the current day."
+        ),
+        (
+            "created_before" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include Events created before the specified time. Defaults to the current time."
+        ),
+        (
+            "profile_id" = Option<String>,
+            Query,
+            description = "Only include Events associated with the Profile identified by the specified Profile ID."
+        ),
+        (
+            "is_delivered" = Option<bool>,
+            Query,
+            description = "Only include Events which are ultimately delivered to the merchant."
+        ),
+        (
+            "delivery_state" = Option<EventDeliveryState>,
+            Query,
+            description = "Only include Events in the specified delivery state. \
+                           The `pending_first_attempt` state includes the Events which were never sent to the merchant, unlike the `failed` state."
+        ),
+        (
+            "event_types" = Option<Vec<EventType>>,
+            Query,
+            description = "Only include Events of any of the specified event types. \
+                           The parameter can be repeated, or the event types can be provided as a comma separated list."
+        ),
+        (
+            "event_class" = Option<EventClass>,
+            Query,
+            description = "Only include Events of the specified event class."
+        ),
+        (
+            "delivery_status_code_class" = Option<WebhookDeliveryStatusCodeClass>,
+            Query,
+            description = "Only include Events whose latest delivery attempt received a response status code of the specified class, or failed with the specified kind of error."
+        ),
+        (
+            "include_payload" = Option<bool>,
+            Query,
+            description = "Include the request and response bodies of the initial delivery attempt of each Event. Defaults to `false`."
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "The Events are exported, one Event per line or row",
+            content(
+                ("application/x-ndjson" = EventExportRecord),
+                ("text/csv" = String)
+            )
+        ),
+        (status = 400, description = "Invalid constraints, or too many Events to export"),
+    ),
+    tag = "Event",
+    operation_id = "Export Events",
+    security(("admin_api_key" = []))
+)]
+pub fn export_webhook_events() {}
+
+/// Events - Schedule Retention
+///
+/// Schedule the retention job of the Events associated with a Merchant Account to run now. The job then runs daily, deleting the Events created more than 90 days before the current day along with their delivery attempts. When the Events archive is enabled, the undelivered Events are archived to object storage before they are deleted. A job which is already scheduled is left unchanged.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/retention",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    responses(
+        (status = 200, description = "The retention job was scheduled", body = EventsRetentionScheduleResponse),
+        (status = 404, description = "The Merchant Account does not exist"),
+    ),
+    tag = "Event",
+    operation_id = "Schedule the retention of the Events of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn schedule_events_retention() {}
+
+/// Events - List Archives
+///
+/// List the archives of the undelivered Events of a Merchant Account written by the retention job, latest archive first. Each archive is an NDJSON object in the archive storage, one Event per line along with the summary of its delivery attempts.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/archives",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("limit" = Option<u16>, Query, description = "The maximum number of archives to include in the response. Defaults to 20, and cannot exceed 100."),
+        ("offset" = Option<u16>, Query, description = "The number of archives to skip when retrieving the list of archives."),
+    ),
+    responses(
+        (status = 200, description = "List of the archives retrieved successfully", body = EventsArchiveListResponse),
+        (status = 400, description = "Invalid list constraints"),
+    ),
+    tag = "Event",
+    operation_id = "List the archives of the Events of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn list_events_archives() {}
+
+/// Events - Delivery Statistics
+///
+/// Retrieve the number of the Events of the Merchant Account which were delivered, are pending delivery and failed to be delivered, by event type and by day, along with the latency of the initial delivery attempts. The time range must not be longer than 90 days.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/stats",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        (
+            "created_after" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Include the Events created after the specified time. Defaults to 30 days before `created_before`."
+        ),
+        (
+            "created_before" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Include the Events created before the specified time. Defaults to the current time."
+        ),
+        (
+            "profile_id" = Option<String>,
+            Query,
+            description = "Include only the Events associated with the Profile identified by the specified Profile ID."
+        ),
+    ),
+    responses(
+        (status = 200, description = "The webhook delivery statistics were retrieved", body = WebhookDeliveryStatsResponse),
+        (status = 400, description = "Invalid time range"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the webhook delivery statistics of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn get_webhook_delivery_stats() {}
+
+/// Events - Delivery Statistics
+///
+/// Retrieve the number of the Events of the Profile which were delivered, are pending delivery and failed to be delivered, by event type and by day, along with the latency of the initial delivery attempts. The time range must not be longer than 90 days.
+#[utoipa::path(
+    get,
+    path = "/events/profile/stats",
+    params(
+        (
+            "created_after" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Include the Events created after the specified time. Defaults to 30 days before `created_before`."
+        ),
+        (
+            "created_before" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Include the Events created before the specified time. Defaults to the current time."
+        ),
+    ),
+    responses(
+        (status = 200, description = "The webhook delivery statistics were retrieved", body = WebhookDeliveryStatsResponse),
+        (status = 400, description = "Invalid time range"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the webhook delivery statistics of a Profile",
+    security(("jwt_key" = []))
+)]
+pub fn get_webhook_delivery_stats_with_jwtauth() {}
+
+/// Events - Confirm Webhook URL Change
+///
+/// Confirm a pending change of the outgoing webhook URL for the Profile, using the verification token delivered to the new webhook URL.
+#[utoipa::path(
+    post,
+    path = "/events/profile/webhook_url/confirm",
+    request_body = WebhookUrlChangeConfirmRequest,
+    responses(
+        (status = 200, description = "The webhook URL change was confirmed", body = WebhookUrlChangeConfirmResponse),
+        (status = 400, description = "Invalid verification token"),
+        (status = 412, description = "No pending webhook URL change found, or the change has expired"),
+    ),
+    tag = "Event",
+    operation_id = "Confirm the webhook URL change of a Profile",
+    security(("jwt_key" = []))
+)]
+pub fn confirm_webhook_url_change_with_jwtauth() {}
+
+/// Events - Force Confirm Webhook URL Change
+///
+/// Confirm a pending change of the outgoing webhook URL for the Profile without verifying the token.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/profile/{profile_id}/webhook_url/force_confirm",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile"),
+    ),
+    responses(
+        (status = 200, description = "The webhook URL change was confirmed", body = WebhookUrlChangeConfirmResponse),
+        (status = 412, description = "No pending webhook URL change found"),
+    ),
+    tag = "Event",
+    operation_id = "Force confirm the webhook URL change of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn force_confirm_webhook_url_change() {}
+
+/// Events - Update Event Subscription
+///
+/// Update the event types whose webhooks are sent to the Profile. The webhooks of all the event types are sent if the list is empty. The events of the other event types are still recorded with the `skipped_not_subscribed` delivery status, without being sent. The events created before the update are not affected.
+#[utoipa::path(
+    put,
+    path = "/events/{merchant_id}/profile/{profile_id}/subscription",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("profile_id" = String, Path, description = "The unique identifier for the Profile"),
+    ),
+    request_body = WebhookEventSubscriptionUpdateRequest,
+    responses(
+        (status = 200, description = "The event subscription of the Profile was updated", body = WebhookEventSubscriptionResponse),
+        (status = 400, description = "An event type cannot be subscribed to"),
+    ),
+    tag = "Event",
+    operation_id = "Update the event subscription of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn update_event_subscription() {}
+
+/// Events - Update Event Subscription
+///
+/// Update the event types whose webhooks are sent to the Profile. The webhooks of all the event types are sent if the list is empty. The events of the other event types are still recorded with the `skipped_not_subscribed` delivery status, without being sent. The events created before the update are not affected.
+#[utoipa::path(
+    put,
+    path = "/events/profile/subscription",
+    request_body = WebhookEventSubscriptionUpdateRequest,
+    responses(
+        (status = 200, description = "The event subscription of the Profile was updated", body = WebhookEventSubscriptionResponse),
+        (status = 400, description = "An event type cannot be subscribed to"),
+    ),
+    tag = "Event",
+    operation_id = "Update the event subscription of the Profile",
+    security(("jwt_key" = []))
+)]
+pub fn update_event_subscription_with_jwtauth() {}
+
+/// Events - Preview Retry Policy
+///
+/// Preview the delivery attempts of an event produced by a candidate webhook delivery retry policy, or by the retry policy currently in use.
+#[utoipa::path(
+    post,
+    path = "/events/profile/retry_policy/preview",
+    request_body = WebhookRetryPolicyPreviewRequest,
+    responses(
+        (status = 200, description = "The delivery attempts produced by the retry policy", body = WebhookRetryPolicyPreviewResponse),
+        (status = 400, description = "Invalid retry policy"),
+    ),
+    tag = "Event",
+    operation_id = "Preview the webhook delivery retry policy",
+    security(("jwt_key" = []))
+)]
+pub fn preview_retry_policy_with_jwtauth() {}
+
+/// Events - Scrub Payload
+///
+/// Replace the stored request and response bodies of all the delivery attempts of an event with a placeholder, keeping the rest of the delivery history of the event. The event can no longer be retried once scrubbed. Scrubbing an event again has no further effect.
+#[utoipa::path(
+    post,
+    path = "/events/profile/{event_id}/scrub",
+    params(
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+    ),
+    responses(
+        (status = 200, description = "The payload of the event was scrubbed", body = EventPayloadScrubResponse),
+        (status = 404, description = "The event was not found"),
+    ),
+    tag = "Event",
+    operation_id = "Scrub the payload of an Event",
+    security(("jwt_key" = []))
+)]
+pub fn scrub_event_payload_with_jwtauth() {}
+
+/// Events - List Event Types
+///
+/// List the event types sent to the webhook URL, along with the JSON schema and an example of the payload of each event type.
+#[utoipa::path(
+    get,
+    path = "/events/types",
+    responses(
+        (status = 200, description = "The event types", body = EventTypeCatalogResponse),
+    ),
+    tag = "Event",
+    operation_id = "List the event types",
+)]
+pub fn list_event_types() {}
+
+/// Events - List Event Types for Profile
+///
+/// List the event types sent to the webhook URL, along with whether each event type is sent to the webhook URL of the Profile.
+#[utoipa::path(
+    get,
+    path = "/events/profile/types",
+    responses(
+        (status = 200, description = "The event types, along with their enablement for the Profile", body = EventTypeCatalogResponse),
+    ),
+    tag = "Event",
+    operation_id = "List the event types for a Profile",
+    security(("jwt_key" = []))
+)]
+pub fn list_event_types_with_jwtauth() {}
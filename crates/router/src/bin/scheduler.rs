@@ -92,6 +92,17 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
+    // The overdue recovery tasks are counted by the producer alone, as there is a single producer
+    #[cfg(feature = "v2")]
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer) {
+        let _task_handle = tokio::spawn(
+            router::core::revenue_recovery::scheduler_lag::start_overdue_tasks_monitor(
+                state.clone(),
+            )
+            .in_current_span(),
+        );
+    }
+
     start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
 
     logger::error!("Scheduler shut down");
@@ -324,6 +335,21 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::WebhookDigestWorkflow => {
+                    Ok(Box::new(workflows::webhook_digest::WebhookDigestWorkflow))
+                }
+                storage::ProcessTrackerRunner::RevenueRecoveryReconciliationWorkflow => Ok(Box::new(
+                    workflows::revenue_recovery_reconciliation::RevenueRecoveryReconciliationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::RevenueRecoveryPaymentsSyncPollingWorkflow => Ok(Box::new(
+                    workflows::revenue_recovery_payments_sync_polling::RevenueRecoveryPaymentsSyncPollingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::WebhookBulkRetryWorkflow => Ok(Box::new(
+                    workflows::webhook_bulk_retry::WebhookBulkRetryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::WebhookEventsRetentionWorkflow => Ok(Box::new(
+                    workflows::webhook_events_retention::WebhookEventsRetentionWorkflow,
+                )),
             }
         };
 
@@ -282,6 +282,8 @@ pub enum StripeErrorCode {
     PlatformBadRequest,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Platform Unauthorized Request")]
     PlatformUnauthorizedRequest,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimit,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -390,7 +392,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -649,7 +650,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             | errors::ApiErrorResponse::WebhookProcessingFailure
             | errors::ApiErrorResponse::WebhookAuthenticationFailed
             | errors::ApiErrorResponse::WebhookUnprocessableEntity
-            | errors::ApiErrorResponse::WebhookInvalidMerchantSecret => {
+            | errors::ApiErrorResponse::WebhookInvalidMerchantSecret
+            | errors::ApiErrorResponse::WebhookPayloadTooLarge { .. }
+            | errors::ApiErrorResponse::WebhookUnsupportedContentType => {
                 Self::WebhookProcessingError
             }
             errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration => {
@@ -684,6 +687,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::PlatformAccountAuthNotSupported => Self::PlatformBadRequest,
             errors::ApiErrorResponse::InvalidPlatformOperation => Self::PlatformUnauthorizedRequest,
+            errors::ApiErrorResponse::TooManyRequests { .. } => Self::RateLimit,
         }
     }
 }
@@ -777,6 +781,7 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::RateLimit => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
@@ -296,6 +296,9 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::WebhookUrlVerification => "webhook_url.verification",
+        api_models::enums::EventType::DigestDaily => "digest.daily",
+        api_models::enums::EventType::RecoveryRetryUpcoming => "recovery.retry_upcoming",
     }
 }
 
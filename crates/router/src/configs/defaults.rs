@@ -110,6 +110,149 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::BillingConnectorsTransactionTime {
+    fn default() -> Self {
+        Self {
+            // 5 minutes
+            max_future_skew_in_secs: 300,
+            utc_offsets_in_mins: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::RevenueRecoverySettings {
+    fn default() -> Self {
+        Self {
+            // 1 hour
+            processor_token_exclusion_window_in_secs: 3600,
+            // Visa allows at most 15 retries for a declined transaction
+            network_retry_limit: 15,
+            // 90 days
+            dispute_hold_max_duration_in_secs: 7_776_000,
+            record_data_provenance: false,
+            // 5 minutes
+            kill_switch_recheck_interval_in_secs: 300,
+            // 6 hours
+            kill_switch_resume_window_in_secs: 21600,
+            kill_switch_resume_max_jitter_in_secs: 60,
+            // 1 minute
+            connector_timeout_requeue_delay_in_secs: 60,
+            execution_concurrency_limit: super::settings::RecoveryExecutionConcurrencyLimit {
+                max_concurrent_executions: 100,
+                max_concurrent_executions_per_payment_connector: 0,
+            },
+            // 5 minutes
+            execution_slot_ttl_in_secs: 300,
+            max_throttle_delay_in_secs: 60,
+            reconciliation: super::settings::RecoveryReconciliationSettings::default(),
+            scheduler_lag: super::settings::RecoverySchedulerLagSettings::default(),
+        }
+    }
+}
+
+impl Default for super::settings::RecoveryReconciliationSettings {
+    fn default() -> Self {
+        Self {
+            hour_of_day: 2,
+            batch_size: 50,
+            max_invoices_per_run: 1000,
+            min_interval_between_calls_in_ms: 200,
+        }
+    }
+}
+
+impl Default for super::settings::RecoverySchedulerLagSettings {
+    fn default() -> Self {
+        Self {
+            // 15 minutes
+            overdue_threshold_in_secs: 900,
+            monitor_interval_in_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::ApiRateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            events_list: super::settings::RateLimitBucket {
+                capacity: 20,
+                refill_window_in_secs: 10,
+            },
+            export: super::settings::RateLimitBucket {
+                capacity: 5,
+                refill_window_in_secs: 60,
+            },
+            search: super::settings::RateLimitBucket {
+                capacity: 30,
+                refill_window_in_secs: 60,
+            },
+            analytics: super::settings::RateLimitBucket {
+                capacity: 60,
+                refill_window_in_secs: 60,
+            },
+            recovery_debug: super::settings::RateLimitBucket {
+                capacity: 10,
+                refill_window_in_secs: 60,
+            },
+        }
+    }
+}
+
+impl Default for super::settings::WebhookDigestSettings {
+    fn default() -> Self {
+        Self {
+            failed_attempts_sample_limit: 1000,
+            top_status_codes_count: 5,
+            // 3 days, same as the retries of the outgoing webhooks
+            backlog_window_in_hours: 72,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookMetaNotificationSettings {
+    fn default() -> Self {
+        Self {
+            // 6 hours
+            dedup_window_in_secs: 21_600,
+            max_delivery_attempts: 3,
+            retry_delay_in_millis: 1000,
+            request_timeout_in_secs: 5,
+            default_backlog_threshold: 1000,
+            // 5 minutes
+            backlog_check_interval_in_secs: 300,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookEventExportSettings {
+    fn default() -> Self {
+        Self {
+            max_row_count: 1_000_000,
+            chunk_size: 1000,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookEventRetentionSettings {
+    fn default() -> Self {
+        Self {
+            batch_size: 500,
+            max_batches_per_run: 20,
+        }
+    }
+}
+
+impl Default for super::settings::IncomingWebhookSettings {
+    fn default() -> Self {
+        Self {
+            // 1 MiB
+            max_body_size_in_bytes: 1_048_576,
+            accepted_content_types: Vec::new(),
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
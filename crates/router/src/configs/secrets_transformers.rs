@@ -317,6 +317,26 @@ impl SecretsHandler for settings::UserAuthMethodSettings {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::EventsArchiveSettings {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let storage = value
+            .get_inner()
+            .storage
+            .clone()
+            .decrypt_credentials(secret_management_client)
+            .await?;
+
+        Ok(value.transition_state(|events_archive| Self {
+            storage,
+            ..events_archive
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::NetworkTokenizationService {
     async fn convert_to_raw_secret(
@@ -465,6 +485,14 @@ pub(crate) async fn fetch_raw_secrets(
         })
         .await;
 
+    #[allow(clippy::expect_used)]
+    let events_archive = settings::EventsArchiveSettings::convert_to_raw_secret(
+        conf.events_archive,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt events archive configs");
+
     Settings {
         server: conf.server,
         master_database,
@@ -508,6 +536,10 @@ pub(crate) async fn fetch_raw_secrets(
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
         billing_connectors_payment_sync: conf.billing_connectors_payment_sync,
+        billing_connectors_transaction_time: conf.billing_connectors_transaction_time,
+        revenue_recovery: conf.revenue_recovery,
+        #[cfg(feature = "recovery_fault_injection")]
+        recovery_fault_injection: conf.recovery_fault_injection,
         payment_method_auth,
         connector_request_reference_id_config: conf.connector_request_reference_id_config,
         #[cfg(feature = "payouts")]
@@ -518,6 +550,7 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        api_rate_limit: conf.api_rate_limit,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
@@ -550,5 +583,6 @@ pub(crate) async fn fetch_raw_secrets(
         network_tokenization_supported_connectors: conf.network_tokenization_supported_connectors,
         theme: conf.theme,
         platform: conf.platform,
+        events_archive,
     }
 }
@@ -27,6 +27,7 @@ use hyperswitch_interfaces::secrets_interface::secret_state::{
 use masking::Secret;
 use redis_interface::RedisSettings;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
+use router_env::types::RateLimitedApi;
 use rust_decimal::Decimal;
 use scheduler::SchedulerSettings;
 use serde::Deserialize;
@@ -98,6 +99,10 @@ pub struct Settings<S: SecretState> {
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
     pub billing_connectors_payment_sync: BillingConnectorPaymentsSyncCall,
+    pub billing_connectors_transaction_time: BillingConnectorsTransactionTime,
+    pub revenue_recovery: RevenueRecoverySettings,
+    #[cfg(feature = "recovery_fault_injection")]
+    pub recovery_fault_injection: RecoveryFaultInjection,
     pub payment_method_auth: SecretStateContainer<PaymentMethodAuth, S>,
     pub connector_request_reference_id_config: ConnectorRequestReferenceIdConfig,
     #[cfg(feature = "payouts")]
@@ -109,6 +114,7 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub api_rate_limit: ApiRateLimitSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -139,6 +145,7 @@ pub struct Settings<S: SecretState> {
     pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
     pub theme: ThemeSettings,
     pub platform: Platform,
+    pub events_archive: SecretStateContainer<EventsArchiveSettings, S>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -813,6 +820,92 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    pub digest: WebhookDigestSettings,
+    pub incoming: IncomingWebhookSettings,
+    pub meta_notifications: WebhookMetaNotificationSettings,
+    pub export: WebhookEventExportSettings,
+    pub retention: WebhookEventRetentionSettings,
+}
+
+/// Limits applied on the incoming webhooks before their body is parsed. The limits can be
+/// overridden in the webhook details of the connector account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookSettings {
+    /// Maximum size of the body of an incoming webhook in bytes
+    pub max_body_size_in_bytes: u32,
+    /// Content types accepted for the incoming webhooks, any content type is accepted when empty
+    pub accepted_content_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookDigestSettings {
+    /// Maximum number of the failed delivery attempts of a day whose responses are read for
+    /// finding the most frequent failure status codes
+    pub failed_attempts_sample_limit: i64,
+    /// Number of the most frequent failure status codes included in a digest
+    pub top_status_codes_count: usize,
+    /// Number of hours over which the undelivered webhooks are counted as the backlog
+    pub backlog_window_in_hours: u16,
+}
+
+/// Delivery of the notifications about the webhook delivery of a profile, sent through the
+/// secondary notification channel of the profile
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookMetaNotificationSettings {
+    /// Number of seconds for which a notification of the same kind is not sent again to a profile
+    pub dedup_window_in_secs: u32,
+    /// Maximum number of attempts made to deliver a notification to the notification URL
+    pub max_delivery_attempts: u8,
+    /// Delay before the first retry of the delivery, doubled for every subsequent retry
+    pub retry_delay_in_millis: u64,
+    /// Timeout of a delivery attempt to the notification URL
+    pub request_timeout_in_secs: u64,
+    /// Number of the webhooks pending delivery above which a profile is notified, if the profile
+    /// does not configure a threshold
+    pub default_backlog_threshold: i64,
+    /// Minimum number of seconds between two checks of the backlog of a profile, which are made
+    /// on the failed deliveries of its webhooks
+    pub backlog_check_interval_in_secs: u32,
+}
+
+/// Limits of the exports of the webhook events of a merchant
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookEventExportSettings {
+    /// Maximum number of the events included in an export, larger exports are rejected
+    pub max_row_count: i64,
+    /// Number of the events read from the database at a time while streaming an export
+    pub chunk_size: i64,
+}
+
+/// Limits of the runs of the job deleting the webhook events of a merchant which are older than the
+/// retention period
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookEventRetentionSettings {
+    /// Number of the initial events deleted at a time, along with their retry attempts
+    pub batch_size: i64,
+    /// Maximum number of the batches deleted in a run, the remaining events are deleted in the
+    /// following runs
+    pub max_batches_per_run: u32,
+}
+
+/// Object storage to which the undelivered webhook events are archived by the retention job before
+/// they are deleted
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct EventsArchiveSettings {
+    /// Whether the undelivered events are archived, the events are deleted without being archived
+    /// otherwise
+    pub enabled: bool,
+    /// Prefix of the keys of the archive objects
+    pub prefix: String,
+    /// Storage of the archive objects, the credentials of which are decrypted using the secrets
+    /// management client
+    pub storage: FileStorageConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -859,6 +952,143 @@ pub struct WebhookSourceVerificationCall {
 pub struct BillingConnectorPaymentsSyncCall {
     #[serde(deserialize_with = "deserialize_hashset")]
     pub billing_connectors_which_require_payment_sync: HashSet<enums::Connector>,
+    /// Number of seconds for which the payments sync response of a transaction is cached, so that
+    /// the repeated webhooks of the transaction do not call the billing connector again. The
+    /// responses are not cached when it is zero.
+    #[serde(default)]
+    pub cache_ttl_in_secs: u32,
+    /// Number of times a payments sync which failed with a timeout or a gateway error is retried
+    /// within the request, before the failure is returned
+    #[serde(default)]
+    pub max_retries: u8,
+    /// Delay before the first retry of a failed payments sync in milliseconds, doubled for every
+    /// retry after it
+    #[serde(default)]
+    pub retry_base_delay_in_millis: u32,
+    /// Number of seconds after which a payments sync call is timed out, unless overridden for the
+    /// billing connector account. The default request timeout is used when not configured.
+    #[serde(default)]
+    pub timeout_in_secs: Option<u64>,
+    /// Number of consecutive failures of the payments sync through a billing connector account,
+    /// within the failure window, after which the calls through the account are paused. The
+    /// circuit breaker is disabled when it is zero.
+    #[serde(default)]
+    pub circuit_breaker_failure_threshold: u16,
+    /// Number of seconds within which the consecutive failures are counted
+    #[serde(default)]
+    pub circuit_breaker_failure_window_in_secs: u32,
+    /// Number of seconds for which the calls are paused, before a single call is let through to
+    /// check whether the billing connector has recovered
+    #[serde(default)]
+    pub circuit_breaker_cool_down_in_secs: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BillingConnectorsTransactionTime {
+    /// Number of seconds the transaction time sent by a billing connector is allowed to be ahead
+    /// of the current time, beyond which it is clamped to the current time
+    pub max_future_skew_in_secs: u32,
+    /// UTC offsets (in minutes) of the billing connectors which send local timestamps without offsets
+    pub utc_offsets_in_mins: HashMap<String, i16>,
+}
+
+impl BillingConnectorsTransactionTime {
+    pub fn get_utc_offset(&self, connector_name: &str) -> Option<time::UtcOffset> {
+        self.utc_offsets_in_mins
+            .get(connector_name)
+            .and_then(|offset| time::UtcOffset::from_whole_seconds(i32::from(*offset) * 60).ok())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RevenueRecoverySettings {
+    /// Minimum number of seconds between two retries scheduled for the same processor payment
+    /// method token, set to 0 to disable the exclusion window
+    pub processor_token_exclusion_window_in_secs: u32,
+    /// Maximum number of retries allowed for a payment by the card network rules, the retries
+    /// granted by a retry override are capped at this limit
+    pub network_retry_limit: u16,
+    /// Maximum number of seconds for which the retries of a customer are paused while a dispute
+    /// raised by the customer is open, after which the retries are resumed
+    pub dispute_hold_max_duration_in_secs: u32,
+    /// Records the source (webhook or billing connector payments sync) of each field of the
+    /// recovery data in the webhook outcome log and the metadata of the recorded attempt
+    pub record_data_provenance: bool,
+    /// Number of seconds after which a task halted by the global kill switch is picked up again,
+    /// to check whether the switch has been turned off
+    pub kill_switch_recheck_interval_in_secs: u32,
+    /// Number of seconds over which the tasks halted by the global kill switch are spread out once
+    /// the switch is turned off, in the order of their schedule times
+    pub kill_switch_resume_window_in_secs: u32,
+    /// Maximum number of seconds of the random delay added to the schedule time of a resumed task,
+    /// so that the tasks which were scheduled for the same time are not resumed all at once
+    pub kill_switch_resume_max_jitter_in_secs: u32,
+    /// Number of seconds after which a retry which timed out at the payment connector is synced,
+    /// before the payment is retried again
+    pub connector_timeout_requeue_delay_in_secs: u32,
+    /// Limits on the retries of a merchant executed at the same time, which can be overridden for
+    /// each merchant
+    pub execution_concurrency_limit: RecoveryExecutionConcurrencyLimit,
+    /// Number of seconds after which the slot held by an executing retry is freed, if it has not
+    /// been released by then
+    pub execution_slot_ttl_in_secs: u32,
+    /// Maximum number of seconds by which a task throttled by the concurrency limits is pushed
+    /// forward, the delay being picked at random so that the throttled tasks are spread out
+    pub max_throttle_delay_in_secs: u32,
+    /// Nightly reconciliation of the open invoices of the billing connectors which have opted in
+    pub reconciliation: RecoveryReconciliationSettings,
+    /// Monitoring of the recovery tasks which the scheduler is late in picking up
+    pub scheduler_lag: RecoverySchedulerLagSettings,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RecoveryReconciliationSettings {
+    /// Hour of the day, in UTC, at which the nightly reconciliation of a billing connector starts
+    pub hour_of_day: u8,
+    /// Number of invoices checked by a single execution of the reconciliation task, the task is
+    /// picked up again for the next batch until all the open invoices have been checked
+    pub batch_size: u16,
+    /// Upper bound on the number of invoices checked by a single reconciliation run
+    pub max_invoices_per_run: u32,
+    /// Minimum number of milliseconds between two calls made to the billing connector, so that the
+    /// reconciliation stays within the rate limits of the billing connector
+    pub min_interval_between_calls_in_ms: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RecoverySchedulerLagSettings {
+    /// Number of seconds after its schedule time beyond which a recovery task which has not been
+    /// picked up by the scheduler is counted as overdue
+    pub overdue_threshold_in_secs: u32,
+    /// Number of seconds between two counts of the overdue recovery tasks made by the producer of
+    /// the scheduler
+    pub monitor_interval_in_secs: u32,
+}
+
+/// Maximum number of retries executed at the same time, 0 meaning that there is no limit
+#[derive(Debug, Deserialize, serde::Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct RecoveryExecutionConcurrencyLimit {
+    /// Limit on the retries of the merchant
+    pub max_concurrent_executions: u32,
+    /// Limit on the retries of the merchant made through the same payment connector
+    pub max_concurrent_executions_per_payment_connector: u32,
+}
+
+/// Failures to be injected in the revenue recovery flow, for reproducing partial failures of the
+/// flow in staging environments
+#[cfg(feature = "recovery_fault_injection")]
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RecoveryFaultInjection {
+    pub faults: HashMap<
+        crate::utils::fault_injection::RecoveryFaultPoint,
+        crate::utils::fault_injection::RecoveryFaultTrigger,
+    >,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -1006,6 +1236,13 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.api_rate_limit.validate()?;
+        self.webhooks.incoming.validate()?;
+        self.webhooks.export.validate()?;
+        self.webhooks.retention.validate()?;
+        self.events_archive.get_inner().validate()?;
+        #[cfg(feature = "recovery_fault_injection")]
+        self.recovery_fault_injection.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -1039,6 +1276,7 @@ impl Settings<SecuredSecret> {
             .transpose()?;
 
         self.key_manager.get_inner().validate()?;
+        self.billing_connectors_transaction_time.validate()?;
         #[cfg(feature = "email")]
         self.email
             .validate()
@@ -1102,6 +1340,44 @@ impl<'de> Deserialize<'de> for LockSettings {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ApiRateLimitSettings {
+    /// Enables the per credential rate limiting of the heavy read APIs
+    pub enabled: bool,
+    pub events_list: RateLimitBucket,
+    pub export: RateLimitBucket,
+    pub search: RateLimitBucket,
+    pub analytics: RateLimitBucket,
+    pub recovery_debug: RateLimitBucket,
+}
+
+impl ApiRateLimitSettings {
+    pub fn get_bucket(&self, rate_limited_api: RateLimitedApi) -> &RateLimitBucket {
+        match rate_limited_api {
+            RateLimitedApi::EventsList => &self.events_list,
+            RateLimitedApi::Export => &self.export,
+            RateLimitedApi::Search => &self.search,
+            RateLimitedApi::Analytics => &self.analytics,
+            RateLimitedApi::RecoveryDebug => &self.recovery_debug,
+        }
+    }
+}
+
+/// Token bucket holding `capacity` requests, which is refilled completely over
+/// `refill_window_in_secs` seconds
+#[derive(Debug, Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitBucket {
+    pub capacity: u32,
+    pub refill_window_in_secs: u32,
+}
+
+impl RateLimitBucket {
+    pub fn is_valid(&self) -> bool {
+        self.capacity > 0 && self.refill_window_in_secs > 0
+    }
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
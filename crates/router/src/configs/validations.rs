@@ -121,6 +121,22 @@ impl super::settings::CorsSettings {
     }
 }
 
+impl super::settings::BillingConnectorsTransactionTime {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.utc_offsets_in_mins
+            .iter()
+            .try_for_each(|(connector_name, offset)| {
+                time::UtcOffset::from_whole_seconds(i32::from(*offset) * 60)
+                    .map(|_| ())
+                    .map_err(|_| {
+                        ApplicationError::InvalidConfigurationValueError(format!(
+                            "invalid UTC offset configured for billing connector {connector_name}"
+                        ))
+                    })
+            })
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl super::settings::DrainerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
@@ -153,6 +169,46 @@ impl super::settings::ApiKeys {
     }
 }
 
+impl super::settings::ApiRateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        [
+            ("events_list", &self.events_list),
+            ("export", &self.export),
+            ("search", &self.search),
+            ("analytics", &self.analytics),
+            ("recovery_debug", &self.recovery_debug),
+        ]
+        .into_iter()
+        .try_for_each(|(rate_limited_api, bucket)| {
+            common_utils::fp_utils::when(!bucket.is_valid(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "capacity and refill_window_in_secs of the {rate_limited_api} rate limit must not be 0"
+                )))
+            })
+        })
+    }
+}
+
+#[cfg(feature = "recovery_fault_injection")]
+impl super::settings::RecoveryFaultInjection {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use crate::utils::fault_injection::RecoveryFaultTrigger;
+
+        self.faults
+            .iter()
+            .try_for_each(|(point, trigger)| match trigger {
+                RecoveryFaultTrigger::Probability(probability)
+                    if !(0.0..=1.0).contains(probability) =>
+                {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "probability of the {point} recovery fault must be between 0 and 1"
+                    )))
+                }
+                RecoveryFaultTrigger::Probability(_) | RecoveryFaultTrigger::Countdown(_) => Ok(()),
+            })
+    }
+}
+
 impl super::settings::LockSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -272,6 +328,74 @@ impl super::settings::GooglePayDecryptConfig {
     }
 }
 
+impl super::settings::IncomingWebhookSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_body_size_in_bytes == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks incoming max_body_size_in_bytes must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhookEventExportSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_row_count <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks export max_row_count must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.chunk_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks export chunk_size must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhookEventRetentionSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks retention batch_size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.max_batches_per_run == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks retention max_batches_per_run must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::EventsArchiveSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.prefix.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "events archive prefix must not be empty".into(),
+            ))
+        })?;
+
+        self.storage
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))
+    }
+}
+
 impl super::settings::KeyManagerConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
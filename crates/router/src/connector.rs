@@ -25,7 +25,8 @@ pub use hyperswitch_connectors::connectors::{
     coinbase::Coinbase, coingate, coingate::Coingate, cryptopay, cryptopay::Cryptopay,
     ctp_mastercard, ctp_mastercard::CtpMastercard, cybersource, cybersource::Cybersource,
     datatrans, datatrans::Datatrans, deutschebank, deutschebank::Deutschebank, digitalvirgo,
-    digitalvirgo::Digitalvirgo, dlocal, dlocal::Dlocal, elavon, elavon::Elavon, facilitapay,
+    digitalvirgo::Digitalvirgo, dlocal, dlocal::Dlocal, dummybillingconnector,
+    dummybillingconnector::DummyBillingConnector, elavon, elavon::Elavon, facilitapay,
     facilitapay::Facilitapay, fiserv, fiserv::Fiserv, fiservemea, fiservemea::Fiservemea, fiuu,
     fiuu::Fiuu, forte, forte::Forte, getnet, getnet::Getnet, globalpay, globalpay::Globalpay,
     globepay, globepay::Globepay, gocardless, gocardless::Gocardless, helcim, helcim::Helcim,
@@ -1,3 +1,6 @@
+#[cfg(all(feature = "olap", feature = "v2"))]
+mod profile_capabilities;
+
 use std::str::FromStr;
 
 use api_models::{
@@ -23,6 +26,8 @@ use pm_auth::{connector::plaid::transformers::PlaidAuthType, types as pm_auth_ty
 use regex::Regex;
 use uuid::Uuid;
 
+#[cfg(feature = "v2")]
+use crate::core::revenue_recovery;
 #[cfg(any(feature = "v1", feature = "v2"))]
 use crate::types::transformers::ForeignFrom;
 use crate::{
@@ -33,7 +38,7 @@ use crate::{
         payment_methods::{cards, transformers},
         payments::helpers,
         pm_auth::helpers::PaymentAuthConnectorDataExt,
-        routing, utils as core_utils,
+        routing, utils as core_utils, webhooks,
     },
     db::{AccountsStorageInterface, StorageInterface},
     routes::{metrics, SessionState},
@@ -1256,6 +1261,13 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
                 dummyconnector::transformers::DummyConnectorAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            #[cfg(feature = "dummy_connector")]
+            api_enums::Connector::DummyBillingConnector => {
+                dummybillingconnector::transformers::DummyBillingConnectorAuthType::try_from(
+                    self.auth_type,
+                )?;
+                Ok(())
+            }
             api_enums::Connector::Aci => {
                 aci::transformers::AciAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -2194,6 +2206,13 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
 
         pm_auth_config_validation.validate_pm_auth_config().await?;
 
+        validate_recovery_payments_sync_required(mca.connector_name, &self.feature_metadata)?;
+        validate_billing_connector_proxy(
+            state,
+            mca.connector_type,
+            &self.connector_account_details,
+        )?;
+
         let merchant_recipient_data = if let Some(data) = &self.additional_merchant_data {
             Some(
                 process_open_banking_connectors(
@@ -2925,6 +2944,11 @@ pub async fn create_connector(
     };
     pm_auth_config_validation.validate_pm_auth_config().await?;
 
+    #[cfg(feature = "v2")]
+    validate_recovery_payments_sync_required(req.connector_name, &req.feature_metadata)?;
+    #[cfg(feature = "v2")]
+    validate_billing_connector_proxy(&state, req.connector_type, &req.connector_account_details)?;
+
     let connector_type_and_connector_enum = ConnectorTypeAndConnectorName {
         connector_type: &req.connector_type,
         connector_name: &req.connector_name,
@@ -3006,6 +3030,9 @@ pub async fn create_connector(
         ),
     );
 
+    #[cfg(feature = "v2")]
+    revenue_recovery::reconciliation::schedule_reconciliation_task(&state, &mca).await?;
+
     let mca_response = mca.foreign_try_into()?;
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
@@ -3275,6 +3302,9 @@ pub async fn update_connector(
             )
         })?;
 
+    #[cfg(feature = "v2")]
+    revenue_recovery::reconciliation::schedule_reconciliation_task(&state, &updated_mca).await?;
+
     let response = updated_mca.foreign_try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
@@ -3972,6 +4002,10 @@ impl ProfileCreateBridge for api::ProfileCreate {
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled.unwrap_or_default(),
             is_debit_routing_enabled: self.is_debit_routing_enabled.unwrap_or_default(),
             merchant_business_country: self.merchant_business_country,
+            default_recovery_payment_connector_id: None,
+            revenue_recovery_connector_fallback: None,
+            revenue_recovery_retry_notification: None,
+            revenue_recovery_blackout_calendar: None,
         }))
     }
 }
@@ -3986,6 +4020,23 @@ pub async fn create_profile(
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
+    if let Some(digest_config) = request
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.digest_config.as_ref())
+    {
+        webhooks::webhook_digest::validate_digest_config(digest_config)?;
+    }
+
+    if let Some(webhook_details) = request.webhook_details.as_ref() {
+        if let Some(notification_config) = webhook_details.secondary_notification_config.as_ref() {
+            webhooks::meta_notifications::validate_secondary_notification_config(
+                notification_config,
+                webhook_details.webhook_url.as_ref(),
+            )?;
+        }
+    }
+
     #[cfg(feature = "v1")]
     let business_profile = request
         .create_domain_model_from_request(&state, &merchant_account, &key_store)
@@ -4009,6 +4060,8 @@ pub async fn create_profile(
         })
         .attach_printable("Failed to insert Business profile because of duplication error")?;
 
+    webhooks::webhook_digest::schedule_webhook_digest_task(&state, None, &business_profile).await?;
+
     #[cfg(feature = "v1")]
     if merchant_account.default_profile.is_some() {
         let unset_default_profile = domain::MerchantAccountUpdate::UnsetDefaultProfile;
@@ -4061,6 +4114,7 @@ pub async fn list_profile(
     Ok(service_api::ApplicationResponse::Json(business_profiles))
 }
 
+#[cfg(feature = "v1")]
 pub async fn retrieve_profile(
     state: SessionState,
     profile_id: id_type::ProfileId,
@@ -4082,6 +4136,43 @@ pub async fn retrieve_profile(
     ))
 }
 
+#[cfg(all(feature = "olap", feature = "v2"))]
+pub async fn retrieve_profile(
+    state: SessionState,
+    profile_id: id_type::ProfileId,
+    key_store: domain::MerchantKeyStore,
+    include_capabilities: bool,
+) -> RouterResponse<api_models::admin::ProfileResponse> {
+    let db = state.store.as_ref();
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&(&state).into(), &key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let capabilities = if include_capabilities {
+        Some(
+            profile_capabilities::get_profile_capabilities(&state, &key_store, &business_profile)
+                .await?,
+        )
+    } else {
+        None
+    };
+
+    let profile_response = api_models::admin::ProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse business profile details")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::ProfileResponse {
+            capabilities,
+            ..profile_response
+        },
+    ))
+}
+
 pub async fn delete_profile(
     state: SessionState,
     profile_id: id_type::ProfileId,
@@ -4126,7 +4217,11 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        let webhook_details = webhooks::webhook_url_change::get_webhook_details_for_update(
+            business_profile.webhook_details.as_ref(),
+            self.webhook_details.map(ForeignInto::foreign_into),
+            date_time::now(),
+        );
 
         if let Some(ref routing_algorithm) = self.routing_algorithm {
             let _: api_models::routing::RoutingAlgorithm = routing_algorithm
@@ -4279,7 +4374,40 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        if let Some(merchant_connector_id) = &self.default_recovery_payment_connector_id {
+            validate_recovery_payment_connector(
+                state,
+                key_store,
+                business_profile.get_id(),
+                merchant_connector_id,
+                "default_recovery_payment_connector_id",
+            )
+            .await?;
+        }
+
+        if let Some(connector_fallback) = &self.revenue_recovery_connector_fallback {
+            validate_revenue_recovery_connector_fallback(
+                state,
+                key_store,
+                business_profile.get_id(),
+                connector_fallback,
+            )
+            .await?;
+        }
+
+        if let Some(retry_notification) = &self.revenue_recovery_retry_notification {
+            validate_revenue_recovery_retry_notification(retry_notification)?;
+        }
+
+        if let Some(blackout_calendar) = &self.revenue_recovery_blackout_calendar {
+            validate_revenue_recovery_blackout_calendar(blackout_calendar)?;
+        }
+
+        let webhook_details = webhooks::webhook_url_change::get_webhook_details_for_update(
+            business_profile.webhook_details.as_ref(),
+            self.webhook_details.map(ForeignInto::foreign_into),
+            date_time::now(),
+        );
 
         let payment_link_config = self
             .payment_link_config
@@ -4395,11 +4523,239 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 card_testing_secret_key,
                 is_debit_routing_enabled: self.is_debit_routing_enabled.unwrap_or_default(),
                 merchant_business_country: self.merchant_business_country,
+                default_recovery_payment_connector_id: self.default_recovery_payment_connector_id,
+                revenue_recovery_connector_fallback: self.revenue_recovery_connector_fallback,
+                revenue_recovery_retry_notification: self.revenue_recovery_retry_notification,
+                revenue_recovery_blackout_calendar: self.revenue_recovery_blackout_calendar,
             },
         )))
     }
 }
 
+/// Upper limit of the lead time of the revenue recovery retry notification, since every retry is
+/// delayed until the lead time has passed after its notification
+#[cfg(all(feature = "olap", feature = "v2"))]
+const MAX_RECOVERY_RETRY_NOTIFICATION_LEAD_TIME_IN_SECS: u32 = 7 * 24 * 60 * 60;
+
+/// The payments sync can be required for the billing connector account only if the integration of
+/// the billing connector implements it
+#[cfg(feature = "v2")]
+fn validate_recovery_payments_sync_required(
+    connector_name: api_enums::Connector,
+    feature_metadata: &Option<api_models::admin::MerchantConnectorAccountFeatureMetadata>,
+) -> RouterResult<()> {
+    let is_payments_sync_required = feature_metadata
+        .as_ref()
+        .and_then(|feature_metadata| feature_metadata.revenue_recovery.as_ref())
+        .and_then(|recovery_metadata| recovery_metadata.payments_sync_required)
+        .unwrap_or(false);
+
+    fp_utils::when(
+        is_payments_sync_required && !connector_name.supports_billing_connector_payments_sync(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "revenue_recovery.payments_sync_required cannot be enabled for {connector_name}"
+                ),
+            })
+        },
+    )
+}
+
+/// The outbound proxy of the billing connector account is validated when the account is created or
+/// its connector account details are updated, rather than on the first call to the billing connector
+#[cfg(feature = "v2")]
+fn validate_billing_connector_proxy(
+    state: &SessionState,
+    connector_type: api_enums::ConnectorType,
+    connector_account_details: &Option<pii::SecretSerdeValue>,
+) -> RouterResult<()> {
+    match (connector_type, connector_account_details) {
+        (api_enums::ConnectorType::BillingProcessor, Some(connector_account_details)) => {
+            revenue_recovery::billing_connector_proxy::validate_billing_connector_proxy(
+                state,
+                connector_account_details.peek().clone(),
+            )
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid connector_account_details.outbound_proxy".to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+fn validate_revenue_recovery_retry_notification(
+    retry_notification: &common_types::payments::RevenueRecoveryRetryNotification,
+) -> RouterResult<()> {
+    fp_utils::when(
+        retry_notification.lead_time_in_secs == 0
+            || retry_notification.lead_time_in_secs
+                > MAX_RECOVERY_RETRY_NOTIFICATION_LEAD_TIME_IN_SECS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "revenue_recovery_retry_notification.lead_time_in_secs must be between 1 and {}",
+                    MAX_RECOVERY_RETRY_NOTIFICATION_LEAD_TIME_IN_SECS
+                ),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Upper limit of the total number of days covered by the blackouts of the revenue recovery
+/// blackout calendar, so that the retries of the profile are not held back for most of the year
+#[cfg(all(feature = "olap", feature = "v2"))]
+const MAX_RECOVERY_BLACKOUT_CALENDAR_LENGTH_IN_DAYS: i64 = 60;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+fn validate_revenue_recovery_blackout_calendar(
+    blackout_calendar: &common_types::payments::RevenueRecoveryBlackoutCalendar,
+) -> RouterResult<()> {
+    blackout_calendar
+        .blackouts
+        .iter()
+        .try_for_each(|blackout| {
+            fp_utils::when(blackout.end_date < blackout.start_date, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "end_date of the blackout starting on {} must not be before its start_date",
+                        blackout.start_date
+                    ),
+                })
+            })?;
+
+            fp_utils::when(
+                utils::timezone::parse_timezone(&blackout.timezone).is_none(),
+                || {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "timezone `{}` of the blackout is not a valid IANA timezone",
+                            blackout.timezone
+                        ),
+                    })
+                },
+            )
+        })?;
+
+    let total_length_in_days = blackout_calendar
+        .blackouts
+        .iter()
+        .map(|blackout| blackout.get_length_in_days())
+        .fold(0_i64, i64::saturating_add);
+    fp_utils::when(
+        total_length_in_days > MAX_RECOVERY_BLACKOUT_CALENDAR_LENGTH_IN_DAYS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "revenue_recovery_blackout_calendar.blackouts must not cover more than {} days",
+                    MAX_RECOVERY_BLACKOUT_CALENDAR_LENGTH_IN_DAYS
+                ),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Validates the connectors of the revenue recovery connector fallback, which are switched to in the
+/// order in which they are listed
+#[cfg(all(feature = "olap", feature = "v2"))]
+async fn validate_revenue_recovery_connector_fallback(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    connector_fallback: &common_types::payments::RevenueRecoveryConnectorFallback,
+) -> RouterResult<()> {
+    fp_utils::when(connector_fallback.payment_connector_ids.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "revenue_recovery_connector_fallback.payment_connector_ids must not be empty"
+                .to_string(),
+        })
+    })?;
+
+    fp_utils::when(connector_fallback.soft_declines_before_switch == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message:
+                "revenue_recovery_connector_fallback.soft_declines_before_switch must be greater than 0"
+                    .to_string(),
+        })
+    })?;
+
+    let unique_connector_ids = connector_fallback
+        .payment_connector_ids
+        .iter()
+        .collect::<std::collections::HashSet<_>>();
+    fp_utils::when(
+        unique_connector_ids.len() != connector_fallback.payment_connector_ids.len(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message:
+                    "revenue_recovery_connector_fallback.payment_connector_ids must not have duplicates"
+                        .to_string(),
+            })
+        },
+    )?;
+
+    for merchant_connector_id in &connector_fallback.payment_connector_ids {
+        validate_recovery_payment_connector(
+            state,
+            key_store,
+            profile_id,
+            merchant_connector_id,
+            "revenue_recovery_connector_fallback.payment_connector_ids",
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Ensures that the recovery payment connector is a payment processor configured under the same
+/// profile, since revenue recovery retries are routed through it
+#[cfg(all(feature = "olap", feature = "v2"))]
+async fn validate_recovery_payment_connector(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    field_name: &str,
+) -> RouterResult<()> {
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_id(&state.into(), merchant_connector_id, key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    fp_utils::when(merchant_connector_account.profile_id != *profile_id, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{field_name} {} does not belong to the profile {}",
+                merchant_connector_id.get_string_repr(),
+                profile_id.get_string_repr()
+            ),
+        })
+    })?;
+
+    fp_utils::when(
+        merchant_connector_account.connector_type != api_enums::ConnectorType::PaymentProcessor,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{field_name} {} is not a payment processor",
+                    merchant_connector_id.get_string_repr()
+                ),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
 #[cfg(feature = "olap")]
 pub async fn update_profile(
     state: SessionState,
@@ -4417,10 +4773,32 @@ pub async fn update_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    if let Some(digest_config) = request
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.digest_config.as_ref())
+    {
+        webhooks::webhook_digest::validate_digest_config(digest_config)?;
+    }
+
+    if let Some(webhook_details) = request.webhook_details.as_ref() {
+        if let Some(notification_config) = webhook_details.secondary_notification_config.as_ref() {
+            webhooks::meta_notifications::validate_secondary_notification_config(
+                notification_config,
+                webhook_details.webhook_url.as_ref().or(business_profile
+                    .webhook_details
+                    .as_ref()
+                    .and_then(|webhook_details| webhook_details.webhook_url.as_ref())),
+            )?;
+        }
+    }
+
     let profile_update = request
         .get_update_profile_object(&state, &key_store, &business_profile)
         .await?;
 
+    let previous_webhook_details = business_profile.webhook_details.clone();
+
     let updated_business_profile = db
         .update_profile_by_profile_id(
             key_manager_state,
@@ -4433,6 +4811,23 @@ pub async fn update_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    webhooks::webhook_url_change::trigger_webhook_url_verification(
+        &state,
+        previous_webhook_details.as_ref(),
+        &updated_business_profile,
+    )
+    .await;
+
+    webhooks::webhook_digest::schedule_webhook_digest_task(
+        &state,
+        previous_webhook_details
+            .as_ref()
+            .and_then(|webhook_details| webhook_details.digest_config.as_ref())
+            .filter(|digest_config| digest_config.enabled),
+        &updated_business_profile,
+    )
+    .await?;
+
     Ok(service_api::ApplicationResponse::Json(
         api_models::admin::ProfileResponse::foreign_try_from(updated_business_profile)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -0,0 +1,216 @@
+use api_models::{admin::ProfileCapabilities, enums as api_enums};
+use diesel_models::business_profile::WebhookDetails;
+use error_stack::ResultExt;
+use router_env::logger;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        webhooks::webhook_events,
+    },
+    db,
+    routes::SessionState,
+    types::domain,
+};
+
+/// The billing connector accounts of a profile are cached for a few minutes, so a connector
+/// account created or disabled in the meanwhile is reflected in the capabilities only once the
+/// cached summary expires.
+const BILLING_CONNECTOR_ACCOUNTS_SUMMARY_TTL_IN_SECS: i64 = 5 * 60;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+struct BillingConnectorAccountsSummary {
+    accounts_count: usize,
+    enabled_accounts_count: usize,
+}
+
+impl BillingConnectorAccountsSummary {
+    fn from_connector_accounts(connector_accounts: &[domain::MerchantConnectorAccount]) -> Self {
+        connector_accounts
+            .iter()
+            .filter(|account| account.connector_type == api_enums::ConnectorType::BillingProcessor)
+            .fold(Self::default(), |summary, account| Self {
+                accounts_count: summary.accounts_count + 1,
+                enabled_accounts_count: summary.enabled_accounts_count
+                    + usize::from(!account.is_disabled()),
+            })
+    }
+}
+
+fn get_billing_connector_accounts_summary_key(
+    profile_id: &common_utils::id_type::ProfileId,
+) -> String {
+    format!(
+        "billing_connector_accounts_summary_{}",
+        profile_id.get_string_repr()
+    )
+}
+
+pub async fn get_profile_capabilities(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile: &domain::Profile,
+) -> RouterResult<ProfileCapabilities> {
+    let billing_connector_accounts_summary =
+        get_billing_connector_accounts_summary(state, key_store, profile.get_id()).await?;
+
+    Ok(build_profile_capabilities(
+        profile.webhook_details.as_ref(),
+        billing_connector_accounts_summary,
+        !state.conf.webhooks.outgoing_enabled,
+    ))
+}
+
+/// Reads the summary from redis, and computes it from the connector accounts of the profile when
+/// it is not cached. The errors of redis are logged and not returned, as the summary can always
+/// be computed from the database.
+async fn get_billing_connector_accounts_summary(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<BillingConnectorAccountsSummary> {
+    let db = state.store.as_ref();
+    let key = get_billing_connector_accounts_summary_key(profile_id);
+
+    match db::get_and_deserialize_key::<BillingConnectorAccountsSummary>(
+        db,
+        &key,
+        "BillingConnectorAccountsSummary",
+    )
+    .await
+    {
+        Ok(summary) => return Ok(summary),
+        Err(error) => logger::debug!(
+            ?error,
+            "Billing connector accounts summary of the profile is not cached"
+        ),
+    }
+
+    let connector_accounts = db
+        .list_connector_account_by_profile_id(&state.into(), profile_id, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the connector accounts of the profile")?;
+    let summary = BillingConnectorAccountsSummary::from_connector_accounts(&connector_accounts);
+
+    let cache_result = match db.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &key.as_str().into(),
+                    summary,
+                    BILLING_CONNECTOR_ACCOUNTS_SUMMARY_TTL_IN_SECS,
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+    if let Err(error) = cache_result {
+        logger::error!(
+            ?error,
+            "Failed to cache the billing connector accounts summary of the profile"
+        );
+    }
+
+    Ok(summary)
+}
+
+fn build_profile_capabilities(
+    webhook_details: Option<&WebhookDetails>,
+    billing_connector_accounts_summary: BillingConnectorAccountsSummary,
+    is_webhook_delivery_disabled: bool,
+) -> ProfileCapabilities {
+    let webhook_endpoints_count = webhook_details
+        .map(|webhook_details| {
+            let has_secondary_notification_url = webhook_details
+                .secondary_notification_config
+                .as_ref()
+                .is_some_and(|config| config.enabled && config.notification_url.is_some());
+
+            usize::from(webhook_details.webhook_url.is_some())
+                + usize::from(has_secondary_notification_url)
+        })
+        .unwrap_or_default();
+
+    ProfileCapabilities {
+        is_revenue_recovery_enabled: billing_connector_accounts_summary.enabled_accounts_count > 0,
+        billing_connector_accounts_count: billing_connector_accounts_summary.accounts_count,
+        webhook_endpoints_count,
+        is_webhook_delivery_disabled,
+        events_retention_days: u16::try_from(
+            webhook_events::INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS,
+        )
+        .unwrap_or(u16::MAX),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_webhook_details(value: serde_json::Value) -> WebhookDetails {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_capabilities_of_profile_with_recovery() {
+        let webhook_details = get_webhook_details(serde_json::json!({
+            "webhook_url": "https://merchant.example.com/webhooks",
+            "secondary_notification_config": {
+                "enabled": true,
+                "notification_url": "https://merchant.example.com/webhook-alerts",
+            },
+        }));
+        let summary = BillingConnectorAccountsSummary {
+            accounts_count: 2,
+            enabled_accounts_count: 1,
+        };
+
+        assert_eq!(
+            build_profile_capabilities(Some(&webhook_details), summary, false),
+            ProfileCapabilities {
+                is_revenue_recovery_enabled: true,
+                billing_connector_accounts_count: 2,
+                webhook_endpoints_count: 2,
+                is_webhook_delivery_disabled: false,
+                events_retention_days: 90,
+            }
+        );
+    }
+
+    #[test]
+    fn test_capabilities_of_profile_without_recovery() {
+        let webhook_details = get_webhook_details(serde_json::json!({
+            "webhook_url": "https://merchant.example.com/webhooks",
+            "secondary_notification_config": {
+                "enabled": false,
+                "notification_url": "https://merchant.example.com/webhook-alerts",
+            },
+        }));
+
+        // The billing connector of the profile is disabled
+        let summary = BillingConnectorAccountsSummary {
+            accounts_count: 1,
+            enabled_accounts_count: 0,
+        };
+        assert_eq!(
+            build_profile_capabilities(Some(&webhook_details), summary, true),
+            ProfileCapabilities {
+                is_revenue_recovery_enabled: false,
+                billing_connector_accounts_count: 1,
+                webhook_endpoints_count: 1,
+                is_webhook_delivery_disabled: true,
+                events_retention_days: 90,
+            }
+        );
+
+        let capabilities =
+            build_profile_capabilities(None, BillingConnectorAccountsSummary::default(), false);
+        assert!(!capabilities.is_revenue_recovery_enabled);
+        assert_eq!(capabilities.billing_connector_accounts_count, 0);
+        assert_eq!(capabilities.webhook_endpoints_count, 0);
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use common_utils::{errors::CustomResult, ext_traits::StringExt};
+use error_stack::report;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+use router_env::{instrument, logger, tracing, types::RateLimitedApi};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    configs::settings::RateLimitBucket,
+    routes::{metrics, SessionState},
+    services::authentication as auth,
+};
+
+pub const API_RATE_LIMIT_PREFIX: &str = "API_RATE_LIMIT";
+
+/// Refills the token bucket stored at `KEYS[1]` for the time elapsed since it was last refilled,
+/// and consumes a token from it. Returns the number of seconds after which a token would be
+/// available if the bucket is empty, and 0 if the token was consumed.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local refill_window = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'refilled_at')
+local tokens = tonumber(bucket[1]) or capacity
+local refilled_at = tonumber(bucket[2]) or now
+
+tokens = math.min(capacity, tokens + math.max(0, now - refilled_at) * capacity / refill_window)
+
+local retry_after = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+else
+    retry_after = math.ceil((1 - tokens) * refill_window / capacity)
+end
+
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'refilled_at', now)
+redis.call('EXPIRE', KEYS[1], refill_window)
+
+return retry_after
+"#;
+
+/// Consumes a token from the bucket of the credential for the rate limited API, failing with
+/// `TooManyRequests` once the bucket is empty. Requests are allowed if the bucket cannot be
+/// checked, so that an unavailable redis does not take the APIs down with it.
+#[instrument(skip_all)]
+pub async fn check_rate_limit(
+    state: &SessionState,
+    rate_limited_api: Option<RateLimitedApi>,
+    auth_type: &auth::AuthenticationType,
+) -> RouterResult<()> {
+    let Some(rate_limited_api) = rate_limited_api.filter(|_| state.conf.api_rate_limit.enabled)
+    else {
+        return Ok(());
+    };
+    let Some(rate_limit_key) = auth_type.get_rate_limit_key() else {
+        return Ok(());
+    };
+
+    let bucket = get_rate_limit_bucket(state, rate_limited_api, auth_type).await;
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the API rate limit"
+            );
+            return Ok(());
+        }
+    };
+
+    match consume_token(
+        &redis_conn,
+        &format!("{API_RATE_LIMIT_PREFIX}_{rate_limited_api}_{rate_limit_key}"),
+        &bucket,
+        common_utils::date_time::now_unix_timestamp(),
+    )
+    .await
+    {
+        Ok(None) => Ok(()),
+        Ok(Some(retry_after)) => {
+            logger::warn!(
+                %rate_limited_api,
+                %rate_limit_key,
+                retry_after,
+                "Request throttled by the API rate limit"
+            );
+            metrics::API_RATE_LIMITED.add(
+                1,
+                router_env::metric_attributes!(
+                    ("api", rate_limited_api.to_string()),
+                    ("key", rate_limit_key),
+                ),
+            );
+            Err(report!(errors::ApiErrorResponse::TooManyRequests {
+                retry_after
+            }))
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to check the API rate limit");
+            Ok(())
+        }
+    }
+}
+
+/// Limits of the rate limited API for the merchant, the per merchant override taking precedence
+/// over the limits configured for the deployment
+async fn get_rate_limit_bucket(
+    state: &SessionState,
+    rate_limited_api: RateLimitedApi,
+    auth_type: &auth::AuthenticationType,
+) -> RateLimitBucket {
+    let default_bucket = *state.conf.api_rate_limit.get_bucket(rate_limited_api);
+    let Some(merchant_id) = auth_type.get_merchant_id() else {
+        return default_bucket;
+    };
+
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_api_rate_limit_override_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch the API rate limit override"))
+        .ok()
+        .and_then(|config| {
+            StringExt::<HashMap<RateLimitedApi, RateLimitBucket>>::parse_struct(
+                &config.config,
+                "ApiRateLimitOverride",
+            )
+            .map_err(|error| logger::error!(?error, "Failed to parse the API rate limit override"))
+            .ok()
+        })
+        .and_then(|mut overrides| overrides.remove(&rate_limited_api))
+        .filter(RateLimitBucket::is_valid)
+        .unwrap_or(default_bucket)
+}
+
+/// Consumes a token from the bucket stored at `key`, returning the number of seconds after which
+/// the request can be retried if the bucket is empty
+async fn consume_token(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    bucket: &RateLimitBucket,
+    now: i64,
+) -> CustomResult<Option<u64>, RedisError> {
+    let retry_after: u64 = redis_conn
+        .evaluate_redis_script(
+            TOKEN_BUCKET_SCRIPT,
+            vec![redis_conn.add_prefix(key)],
+            vec![
+                bucket.capacity.to_string(),
+                bucket.refill_window_in_secs.to_string(),
+                now.to_string(),
+            ],
+        )
+        .await?;
+
+    Ok((retry_after > 0).then_some(retry_after))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use redis_interface::{RedisConnectionPool, RedisSettings};
+    use router_env::{types::FlowMetric, Flow};
+
+    use super::{consume_token, RateLimitedApi};
+    use crate::configs::settings::RateLimitBucket;
+
+    #[test]
+    fn test_heavy_read_flows_are_rate_limited() {
+        for (flow, rate_limited_api) in [
+            (
+                Flow::WebhookEventInitialDeliveryAttemptList,
+                Some(RateLimitedApi::EventsList),
+            ),
+            (Flow::WebhookEventRetrieve, Some(RateLimitedApi::EventsList)),
+            (Flow::WebhookEventExport, Some(RateLimitedApi::Export)),
+            (Flow::WebhookDeliveryStats, Some(RateLimitedApi::Analytics)),
+            (Flow::WebhookEventDeliveryRetry, None),
+        ] {
+            assert_eq!(flow.get_rate_limited_api(), rate_limited_api, "{flow:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_token_bucket_throttles_until_refilled() {
+        let redis_conn = RedisConnectionPool::new(&RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        let key = common_utils::generate_id_with_default_len("test_api_rate_limit");
+        let bucket = RateLimitBucket {
+            capacity: 2,
+            refill_window_in_secs: 10,
+        };
+        let now = common_utils::date_time::now_unix_timestamp();
+        let consume = |now| consume_token(&redis_conn, &key, &bucket, now);
+
+        assert_eq!(consume(now).await.unwrap(), None);
+        assert_eq!(consume(now).await.unwrap(), None);
+
+        // The bucket is empty, a token is refilled every 5 seconds
+        assert_eq!(consume(now).await.unwrap(), Some(5));
+        assert_eq!(consume(now + 3).await.unwrap(), Some(2));
+
+        // The bucket is full again once the refill window has elapsed
+        assert_eq!(consume(now + 13).await.unwrap(), None);
+        assert_eq!(consume(now + 13).await.unwrap(), None);
+        assert_eq!(consume(now + 13).await.unwrap(), Some(5));
+    }
+}
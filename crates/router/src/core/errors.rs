@@ -488,6 +488,8 @@ pub enum RevenueRecoveryError {
     TransactionWebhookProcessingFailed,
     #[error("Failed to create payment intent")]
     PaymentIntentCreateFailed,
+    #[error("Failed to update payment intent")]
+    PaymentIntentUpdateFailed,
     #[error("Source verification failed for billing connector")]
     WebhookAuthenticationFailed,
     #[error("Payment merchant connector account not found using account reference id")]
@@ -500,8 +502,26 @@ pub enum RevenueRecoveryError {
     ProcessTrackerResponseError,
     #[error("Billing connector psync call failed")]
     BillingConnectorPaymentsSyncFailed,
+    #[error("Billing connector psync call timed out")]
+    BillingConnectorPaymentsSyncTimedOut,
+    #[error(
+        "Billing connector psync call rate limited, retry after {retry_after_in_secs} seconds"
+    )]
+    BillingConnectorPaymentsSyncRateLimited { retry_after_in_secs: u64 },
+    #[error("Billing connector psync call skipped as the billing connector is unavailable")]
+    BillingConnectorPaymentsSyncCircuitOpen,
+    #[error("Billing connector invoice sync call failed")]
+    BillingConnectorInvoiceSyncFailed,
+    #[error("Billing connector account is configured with invalid {config}")]
+    BillingConnectorAccountMisconfigured { config: String },
+    #[error("Billing connector account is configured with an invalid outbound proxy")]
+    BillingConnectorProxyMisconfigured,
     #[error("Failed to get the retry count for payment intent")]
     RetryCountFetchFailed,
     #[error("Failed to get the billing threshold retry count")]
     BillingThresholdRetryCountFetchFailed,
+    #[error("Failed to update the customer details")]
+    CustomerUpdateFailed,
+    #[error("Invalid invoice or transaction details received from the billing connector")]
+    InvalidBillingConnectorData,
 }
@@ -1,4 +1,4 @@
-use router_env::{counter_metric, global_meter};
+use router_env::{counter_metric, global_meter, histogram_metric_f64};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
@@ -39,10 +39,15 @@ counter_metric!(
 
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_INCOMING_REJECTED_COUNT, GLOBAL_METER); // No. of incoming webhooks rejected before parsing, for their body size or content type
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_OUTGOING_REDIRECT_NOT_FOLLOWED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_OUTGOING_PENDING_EVENT_FLUSHED_COUNT, GLOBAL_METER); // No. of outgoing webhook events created after the flow which raised them ended
+counter_metric!(WEBHOOK_OUTGOING_PENDING_EVENT_OF_FAILED_FLOW_COUNT, GLOBAL_METER); // No. of outgoing webhook events raised by a flow which failed after committing the state they report
+histogram_metric_f64!(WEBHOOK_OUTGOING_PENDING_EVENT_LAG, GLOBAL_METER); // Time in seconds from an outgoing webhook event being raised to it being created
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
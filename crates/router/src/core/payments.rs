@@ -88,6 +88,8 @@ use super::{
 use crate::core::fraud_check as frm_core;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
+#[cfg(feature = "v1")]
+use crate::core::webhooks::pending_events;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::types::api::convert_connector_data_to_routable_connectors;
 use crate::{
@@ -1554,34 +1556,38 @@ where
             .flat_map(|c| c.foreign_try_into())
             .collect()
     });
-    let (payment_data, _req, customer, connector_http_status_code, external_latency) =
-        payments_operation_core::<_, _, _, _, _>(
-            &state,
-            req_state,
-            merchant_account,
-            profile_id,
-            key_store,
-            operation.clone(),
-            req,
-            call_connector_action,
+    // The outgoing webhook events raised by the payment are created once the payment flow ends
+    pending_events::create_events_after_commit(async {
+        let (payment_data, _req, customer, connector_http_status_code, external_latency) =
+            payments_operation_core::<_, _, _, _, _>(
+                &state,
+                req_state,
+                merchant_account,
+                profile_id,
+                key_store,
+                operation.clone(),
+                req,
+                call_connector_action,
+                auth_flow,
+                eligible_routable_connectors,
+                header_payload.clone(),
+                platform_merchant_account,
+            )
+            .await?;
+
+        Res::generate_response(
+            payment_data,
+            customer,
             auth_flow,
-            eligible_routable_connectors,
-            header_payload.clone(),
-            platform_merchant_account,
+            &state.base_url,
+            operation,
+            &state.conf.connector_request_reference_id_config,
+            connector_http_status_code,
+            external_latency,
+            header_payload.x_hs_latency,
         )
-        .await?;
-
-    Res::generate_response(
-        payment_data,
-        customer,
-        auth_flow,
-        &state.base_url,
-        operation,
-        &state.conf.connector_request_reference_id_config,
-        connector_http_status_code,
-        external_latency,
-        header_payload.x_hs_latency,
-    )
+    })
+    .await
 }
 
 #[cfg(feature = "v1")]
@@ -1617,33 +1623,37 @@ where
     // To perform router related operation for PaymentResponse
     PaymentResponse: Operation<F, FData, Data = D>,
 {
-    let (payment_data, _req, customer, connector_http_status_code, external_latency) =
-        proxy_for_payments_operation_core::<_, _, _, _, _>(
-            &state,
-            req_state,
-            merchant_account,
-            profile_id,
-            key_store,
-            operation.clone(),
-            req,
-            call_connector_action,
+    // The outgoing webhook events raised by the payment are created once the payment flow ends
+    pending_events::create_events_after_commit(async {
+        let (payment_data, _req, customer, connector_http_status_code, external_latency) =
+            proxy_for_payments_operation_core::<_, _, _, _, _>(
+                &state,
+                req_state,
+                merchant_account,
+                profile_id,
+                key_store,
+                operation.clone(),
+                req,
+                call_connector_action,
+                auth_flow,
+                header_payload.clone(),
+                platform_merchant_account,
+            )
+            .await?;
+
+        Res::generate_response(
+            payment_data,
+            customer,
             auth_flow,
-            header_payload.clone(),
-            platform_merchant_account,
+            &state.base_url,
+            operation,
+            &state.conf.connector_request_reference_id_config,
+            connector_http_status_code,
+            external_latency,
+            header_payload.x_hs_latency,
         )
-        .await?;
-
-    Res::generate_response(
-        payment_data,
-        customer,
-        auth_flow,
-        &state.base_url,
-        operation,
-        &state.conf.connector_request_reference_id_config,
-        connector_http_status_code,
-        external_latency,
-        header_payload.x_hs_latency,
-    )
+    })
+    .await
 }
 
 #[cfg(feature = "v2")]
@@ -3843,6 +3843,7 @@ mod tests {
             platform_merchant_id: None,
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3917,6 +3918,7 @@ mod tests {
             platform_merchant_id: None,
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3989,6 +3991,7 @@ mod tests {
             platform_merchant_id: None,
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
@@ -192,6 +192,12 @@ impl<F: Send + Clone + Sync>
             billing_connector_id: request.billing_connector_id.clone(),
             processor_payment_method_token: request.processor_payment_method_token.clone(),
             connector_customer_id: request.connector_customer_id.clone(),
+            recovery_correlation_id: request
+                .feature_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.revenue_recovery.as_ref())
+                .and_then(|recovery| recovery.recovery_correlation_id.clone()),
+            card_details: request.card_details.clone(),
         };
 
         let payment_data = PaymentAttemptRecordData {
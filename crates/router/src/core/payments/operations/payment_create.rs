@@ -1583,6 +1583,7 @@ impl PaymentCreate {
                 .map(|platform_merchant_account| platform_merchant_account.get_id().to_owned()),
             force_3ds_challenge: request.force_3ds_challenge,
             force_3ds_challenge_trigger: Some(force_3ds_challenge_trigger),
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         })
     }
 
@@ -2623,6 +2623,7 @@ where
             force_3ds_challenge_trigger: payment_intent.force_3ds_challenge_trigger,
             issuer_error_code: payment_attempt.issuer_error_code,
             issuer_error_message: payment_attempt.issuer_error_message,
+            entity_version: Some(payment_intent.entity_version),
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -2915,6 +2916,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             force_3ds_challenge_trigger: pi.force_3ds_challenge_trigger,
             issuer_error_code: pa.issuer_error_code,
             issuer_error_message: pa.issuer_error_message,
+            entity_version: Some(pi.entity_version),
         }
     }
 }
@@ -4490,6 +4492,50 @@ impl ForeignFrom<&diesel_models::types::BillingConnectorPaymentDetails>
         Self {
             payment_processor_token: metadata.payment_processor_token.clone(),
             connector_customer_id: metadata.connector_customer_id.clone(),
+            card_details: metadata.card_details.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl ForeignFrom<&diesel_models::types::RevenueRecoveryRetryOverride>
+    for api_models::payments::RevenueRecoveryRetryOverride
+{
+    fn foreign_from(retry_override: &diesel_models::types::RevenueRecoveryRetryOverride) -> Self {
+        Self {
+            additional_retries: retry_override.additional_retries,
+            schedule: retry_override.schedule.clone(),
+            retry_count_at_override: retry_override.retry_count_at_override,
+            reason: retry_override.reason.clone(),
+            created_by: retry_override.created_by.clone(),
+            created_at: retry_override.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl ForeignFrom<&diesel_models::types::RevenueRecoveryDisputeHold>
+    for api_models::payments::RevenueRecoveryDisputeHold
+{
+    fn foreign_from(dispute_hold: &diesel_models::types::RevenueRecoveryDisputeHold) -> Self {
+        Self {
+            held_at: dispute_hold.held_at,
+            expires_at: dispute_hold.expires_at,
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl ForeignFrom<&diesel_models::types::RevenueRecoveryManualCancellation>
+    for api_models::payments::RevenueRecoveryManualCancellation
+{
+    fn foreign_from(
+        manual_cancellation: &diesel_models::types::RevenueRecoveryManualCancellation,
+    ) -> Self {
+        Self {
+            task_id: manual_cancellation.task_id.clone(),
+            cancelled_by: manual_cancellation.cancelled_by.clone(),
+            cancelled_at: manual_cancellation.cancelled_at,
         }
     }
 }
@@ -4525,6 +4571,11 @@ impl
         let revenue_recovery = feature_metadata.revenue_recovery.as_ref().map(|recovery| {
             api_models::payments::PaymentAttemptRevenueRecoveryData {
                 attempt_triggered_by: recovery.attempt_triggered_by,
+                processor_response_details: recovery.processor_response_details.clone(),
+                schedule_adjustments: recovery.schedule_adjustments.clone(),
+                data_provenance: recovery.data_provenance.clone(),
+                recovery_correlation_id: recovery.recovery_correlation_id.clone(),
+                connector_switch: recovery.connector_switch.clone(),
             }
         });
         Self { revenue_recovery }
@@ -4557,6 +4608,25 @@ impl ForeignFrom<&diesel_models::types::FeatureMetadata> for api_models::payment
                         api_models::payments::BillingConnectorPaymentDetails::foreign_from(
                             &payment_revenue_recovery_metadata.billing_connector_payment_details,
                         ),
+                    retry_override: payment_revenue_recovery_metadata
+                        .retry_override
+                        .as_ref()
+                        .map(api_models::payments::RevenueRecoveryRetryOverride::foreign_from),
+                    dispute_hold: payment_revenue_recovery_metadata
+                        .dispute_hold
+                        .as_ref()
+                        .map(api_models::payments::RevenueRecoveryDisputeHold::foreign_from),
+                    recovery_correlation_id: payment_revenue_recovery_metadata
+                        .recovery_correlation_id
+                        .clone(),
+                    manual_cancellation: payment_revenue_recovery_metadata
+                        .manual_cancellation
+                        .as_ref()
+                        .map(api_models::payments::RevenueRecoveryManualCancellation::foreign_from),
+                    connector_rotation: payment_revenue_recovery_metadata
+                        .connector_rotation
+                        .clone(),
+                    record_back_status: payment_revenue_recovery_metadata.record_back_status,
                 }
             });
         let apple_pay_details = feature_metadata
@@ -4572,6 +4642,9 @@ impl ForeignFrom<&diesel_models::types::FeatureMetadata> for api_models::payment
             apple_pay_recurring_details: apple_pay_details,
             redirect_response: redirect_res,
             search_tags: feature_metadata.search_tags.clone(),
+            billing_connector_subscription_id: feature_metadata
+                .billing_connector_subscription_id
+                .clone(),
         }
     }
 }
@@ -27,7 +27,7 @@ use crate::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token, helpers},
         refunds::transformers::SplitRefundInput,
-        utils as core_utils,
+        utils as core_utils, webhooks,
     },
     db, logger,
     routes::{metrics, SessionState},
@@ -120,7 +120,8 @@ pub async fn refund_create_core(
         .await
         .transpose()?;
 
-    Box::pin(validate_and_create_refund(
+    // The outgoing webhook event of the refund is created once the refund flow ends
+    webhooks::pending_events::create_events_after_commit(Box::pin(validate_and_create_refund(
         &state,
         &merchant_account,
         &key_store,
@@ -129,7 +130,7 @@ pub async fn refund_create_core(
         amount,
         req,
         creds_identifier,
-    ))
+    )))
     .await
     .map(services::ApplicationResponse::Json)
 }
@@ -468,9 +469,15 @@ where
     T: ForeignInto<refunds::RefundResponse>,
 {
     Ok(services::ApplicationResponse::Json(
-        f(state, merchant_account, profile_id, key_store, request)
-            .await?
-            .foreign_into(),
+        webhooks::pending_events::create_events_after_commit(f(
+            state,
+            merchant_account,
+            profile_id,
+            key_store,
+            request,
+        ))
+        .await?
+        .foreign_into(),
     ))
 }
 
@@ -1279,6 +1286,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             unified_message: refund.unified_message,
             issuer_error_code: refund.issuer_error_code,
             issuer_error_message: refund.issuer_error_message,
+            entity_version: Some(refund.entity_version),
         }
     }
 }
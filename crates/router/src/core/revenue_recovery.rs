@@ -1,14 +1,43 @@
+pub mod billing_connector_proxy;
+pub mod billing_sync_debug;
+pub mod blackout_calendar;
+pub mod bulk_requeue;
+pub mod cancellation;
+pub mod concurrency_limit;
+pub mod connector_resolution;
+pub mod dead_letter;
+pub mod kill_switch;
+pub mod normalization;
+pub mod overview;
+pub mod payments_sync_auth;
+pub mod payments_sync_circuit;
+pub mod payments_sync_override;
+pub mod payments_sync_polling;
+pub mod payments_sync_rate_limit;
+pub mod reconciliation;
+pub mod record_back;
+pub mod retry_notification;
+pub mod retry_schedule;
+pub mod schedule_preview;
+pub mod scheduler_lag;
+pub mod task_snapshot;
 pub mod transformers;
 pub mod types;
+use std::collections::HashMap;
+
 use api_models::{
-    payments::{PaymentRevenueRecoveryMetadata, PaymentsRetrieveRequest},
+    pagination::{LegacyListResponse, PaginatedListResponse},
+    payments::{
+        PaymentRevenueRecoveryMetadata, PaymentsRetrieveRequest, RevenueRecoveryDisputeHold,
+        RevenueRecoveryManualCancellation, RevenueRecoveryRetryOverride,
+    },
     process_tracker::revenue_recovery,
 };
 use common_utils::{
     self,
     errors::CustomResult,
     ext_traits::{OptionExt, ValueExt},
-    id_type,
+    fp_utils, id_type, pii,
     types::keymanager::KeyManagerState,
 };
 use diesel_models::process_tracker::business_status;
@@ -18,9 +47,10 @@ use hyperswitch_domain_models::{
     behaviour::ReverseConversion,
     errors::api_error_response,
     merchant_connector_account,
-    payments::{PaymentIntent, PaymentStatusData},
+    payments::{payment_attempt::PaymentAttempt, PaymentIntent, PaymentStatusData},
     ApiModelToDieselModelConvertor,
 };
+use masking::{PeekInterface, Secret};
 use scheduler::errors as sch_errors;
 
 use crate::{
@@ -30,17 +60,138 @@ use crate::{
         revenue_recovery::types as pcr_types,
     },
     db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
     logger,
     routes::{metrics, SessionState},
+    services::{
+        authentication,
+        authorization::{permissions::Permission, roles},
+    },
     types::{
-        api,
+        api, domain,
         storage::{self, revenue_recovery as pcr},
-        transformers::ForeignInto,
+        transformers::{ForeignFrom, ForeignInto},
     },
+    utils::fault_injection::inject_recovery_fault,
+    workflows::revenue_recovery as revenue_recovery_flow,
 };
 
+pub const CALCULATE_WORKFLOW: &str = "CALCULATE_WORKFLOW";
 pub const EXECUTE_WORKFLOW: &str = "EXECUTE_WORKFLOW";
 pub const PSYNC_WORKFLOW: &str = "PSYNC_WORKFLOW";
+pub const NOTIFY_WORKFLOW: &str = "NOTIFY_WORKFLOW";
+pub const RECORD_BACK_WORKFLOW: &str = "RECORD_BACK_WORKFLOW";
+/// Key of the customer metadata in which the locale shared by the billing connector is stored
+pub const CUSTOMER_LOCALE_METADATA_KEY: &str = "locale";
+
+/// Id of the process tracker task which retries the payment
+pub(crate) fn get_execute_pcr_task_id(
+    payment_id: &id_type::GlobalPaymentId,
+    runner: storage::ProcessTrackerRunner,
+) -> String {
+    format!(
+        "{runner}_{EXECUTE_WORKFLOW}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+/// Id of the process tracker task which computes the schedule time of the next retry of the
+/// payment
+pub(crate) fn get_calculate_pcr_task_id(
+    payment_id: &id_type::GlobalPaymentId,
+    runner: storage::ProcessTrackerRunner,
+) -> String {
+    format!(
+        "{runner}_{CALCULATE_WORKFLOW}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+/// Inserts the pcr task, or updates the existing task of the payment if a task with the same id
+/// was inserted already, as happens for every failed payment of the invoice after the first one.
+/// A task which is no longer picked up by the scheduler is revived, while the status of a task yet
+/// to be finished is left as is.
+pub(crate) async fn insert_or_update_pcr_task(
+    db: &dyn StorageInterface,
+    process_tracker_entry: storage::ProcessTrackerNew,
+) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
+    let insert_error = match db.insert_process(process_tracker_entry.clone()).await {
+        Ok(process) => return Ok(process),
+        Err(error) if error.current_context().is_db_unique_violation() => error,
+        Err(error) => return Err(error),
+    };
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_entry.id)
+        .await?
+        .ok_or(insert_error)
+        .attach_printable("Task with the duplicate id of the pcr task not found")?;
+    logger::info!(
+        task_id = %existing_process.id,
+        status = ?existing_process.status,
+        "Updating the existing pcr task in place of inserting a duplicate one"
+    );
+
+    let task_update = get_duplicate_pcr_task_update(
+        &existing_process,
+        process_tracker_entry,
+        common_utils::date_time::now(),
+    );
+    db.as_scheduler()
+        .update_process(existing_process, task_update)
+        .await
+}
+
+fn get_duplicate_pcr_task_update(
+    existing_process: &storage::ProcessTracker,
+    process_tracker_entry: storage::ProcessTrackerNew,
+    current_time: time::PrimitiveDateTime,
+) -> storage::ProcessTrackerUpdate {
+    let is_finished = matches!(
+        existing_process.status,
+        common_enums::ProcessTrackerStatus::Finish | common_enums::ProcessTrackerStatus::Review
+    );
+
+    storage::ProcessTrackerUpdate::Update {
+        name: process_tracker_entry.name,
+        retry_count: Some(
+            process_tracker_entry
+                .retry_count
+                .max(existing_process.retry_count.saturating_add(1)),
+        ),
+        schedule_time: process_tracker_entry.schedule_time,
+        tracking_data: Some(process_tracker_entry.tracking_data),
+        business_status: Some(process_tracker_entry.business_status),
+        status: is_finished.then_some(common_enums::ProcessTrackerStatus::New),
+        updated_at: Some(current_time),
+    }
+}
+
+/// Parks the execute task until the dispute hold expires. The task stays pending, so that the
+/// retries are resumed even if the dispute closed webhook is never received.
+pub(crate) async fn park_execute_task_for_dispute_hold(
+    db: &dyn StorageInterface,
+    execute_task_process: storage::ProcessTracker,
+    dispute_hold: &RevenueRecoveryDisputeHold,
+) -> CustomResult<(), errors::StorageError> {
+    db.as_scheduler()
+        .update_process(
+            execute_task_process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(dispute_hold.expires_at),
+                tracking_data: None,
+                business_status: Some(String::from(
+                    business_status::EXECUTE_WORKFLOW_PAUSED_FOR_DISPUTE,
+                )),
+                status: Some(common_enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+    Ok(())
+}
 
 pub async fn perform_execute_payment(
     state: &SessionState,
@@ -60,6 +211,22 @@ pub async fn perform_execute_payment(
         .get_required_value("Payment Revenue Recovery Metadata")?
         .convert_back();
 
+    // The retries are not made while a dispute raised by the customer is open, expired holds are
+    // ignored so that the invoice is not held forever
+    if let Some(dispute_hold) = pcr_metadata
+        .dispute_hold
+        .as_ref()
+        .filter(|dispute_hold| dispute_hold.is_active(common_utils::date_time::now()))
+    {
+        logger::info!(
+            payment_id = ?tracking_data.global_payment_id,
+            expires_at = ?dispute_hold.expires_at,
+            "Revenue recovery retry parked, since the invoice is on dispute hold"
+        );
+        park_execute_task_for_dispute_hold(db, execute_task_process.clone(), dispute_hold).await?;
+        return Ok(());
+    }
+
     let decision = pcr_types::Decision::get_decision_based_on_params(
         state,
         payment_intent.status,
@@ -75,24 +242,83 @@ pub async fn perform_execute_payment(
     // TODO decide if its a global failure or is it requeueable error
     match decision {
         pcr_types::Decision::Execute => {
-            let action = pcr_types::Action::execute_payment(
+            // A retry held back by the switch past the max recovery window of the payment is not
+            // made once the switch is turned off
+            if kill_switch::finish_task_if_recovery_window_expired(
                 state,
+                execute_task_process,
                 pcr_data.merchant_account.get_id(),
-                payment_intent,
+                payment_intent.created_at,
+            )
+            .await?
+            {
+                return Ok(());
+            }
+
+            // The blackouts added to the calendar of the profile after the retry was scheduled are
+            // observed as well
+            if blackout_calendar::defer_retry_if_in_blackout(
+                db,
                 execute_task_process,
-                pcr_data,
-                &pcr_metadata,
+                &pcr_data.profile,
+                common_utils::date_time::now(),
             )
-            .await?;
-            Box::pin(action.execute_payment_task_response_handler(
+            .await?
+            {
+                return Ok(());
+            }
+
+            // The switch is checked right before the payment is retried, so that it also halts
+            // the tasks which were picked up before it was turned on
+            if kill_switch::hold_task_for_kill_switch(
                 state,
-                payment_intent,
                 execute_task_process,
-                pcr_data,
-                &mut pcr_metadata,
-                billing_mca,
-            ))
-            .await?;
+                business_status::EXECUTE_WORKFLOW_HALTED_BY_KILL_SWITCH,
+            )
+            .await?
+            {
+                return Ok(());
+            }
+
+            let Some(execution_slot) = concurrency_limit::acquire_execution_slot_or_throttle_task(
+                state,
+                execute_task_process,
+                pcr_data.merchant_account.get_id(),
+                &pcr_metadata.active_attempt_payment_connector_id,
+            )
+            .await?
+            else {
+                return Ok(());
+            };
+
+            let execution_result = Box::pin(async {
+                let action = pcr_types::Action::execute_payment(
+                    state,
+                    pcr_data.merchant_account.get_id(),
+                    payment_intent,
+                    execute_task_process,
+                    tracking_data,
+                    pcr_data,
+                    &pcr_metadata,
+                )
+                .await?
+                .abort_if_recovery_cancelled(state, payment_intent.get_id())
+                .await;
+                Box::pin(action.execute_payment_task_response_handler(
+                    state,
+                    payment_intent,
+                    execute_task_process,
+                    pcr_data,
+                    &mut pcr_metadata,
+                    billing_mca,
+                ))
+                .await
+            })
+            .await;
+            // The slot is held until the outcome of the retry has been handled, since the handling
+            // may retry the payment through another payment connector
+            execution_slot.release(state).await;
+            execution_result?;
         }
 
         pcr_types::Decision::Psync(attempt_status, attempt_id) => {
@@ -117,12 +343,13 @@ pub async fn perform_execute_payment(
                 None => {
                     // insert new psync task
                     insert_psync_pcr_task(
-                        billing_mca.get_id().clone(),
+                        billing_mca,
                         db,
                         pcr_data.merchant_account.get_id().clone(),
                         payment_intent.get_id().clone(),
                         pcr_data.profile.get_id().clone(),
                         attempt_id.clone(),
+                        tracking_data.recovery_correlation_id.clone(),
                         storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
                     )
                     .await?;
@@ -136,6 +363,25 @@ pub async fn perform_execute_payment(
                 }
             };
         }
+        pcr_types::Decision::ResolvePendingAttempt(payment_attempt) => {
+            let action = pcr_types::Action::resolve_pending_attempt(
+                state,
+                pcr_data.merchant_account.get_id(),
+                execute_task_process,
+                payment_attempt,
+                &pcr_metadata,
+            )
+            .await?;
+            Box::pin(action.execute_payment_task_response_handler(
+                state,
+                payment_intent,
+                execute_task_process,
+                pcr_data,
+                &mut pcr_metadata,
+                billing_mca,
+            ))
+            .await?;
+        }
         pcr_types::Decision::InvalidDecision => {
             db.finish_process_with_business_status(
                 execute_task_process.clone(),
@@ -149,26 +395,305 @@ pub async fn perform_execute_payment(
     Ok(())
 }
 
+/// Computes the schedule time of the next retry of the payment and creates the execute task for
+/// it, or reschedules the execute task left behind by the previous retry.
+pub async fn perform_calculate_workflow(
+    state: &SessionState,
+    calculate_task_process: &storage::ProcessTracker,
+    payment_intent: &PaymentIntent,
+    business_profile: &domain::Profile,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+
+    if kill_switch::hold_task_for_kill_switch(
+        state,
+        calculate_task_process,
+        business_status::CALCULATE_WORKFLOW_HALTED_BY_KILL_SWITCH,
+    )
+    .await?
+    {
+        return Ok(());
+    }
+
+    let pcr::PcrCalculateWorkflowTrackingData {
+        workflow_tracking_data: mut execute_tracking_data,
+        intent_retry_count,
+        processor_token_tag,
+        last_attempt_error_code,
+        card_issuing_country,
+    } = calculate_task_process
+        .tracking_data
+        .clone()
+        .parse_value("PcrCalculateWorkflowTrackingData")?;
+
+    let retry_override = payment_intent
+        .feature_metadata
+        .as_ref()
+        .and_then(|feature_metadata| feature_metadata.payment_revenue_recovery_metadata.as_ref())
+        .and_then(|recovery_metadata| recovery_metadata.retry_override.clone())
+        .map(ApiModelToDieselModelConvertor::convert_back);
+
+    let error_codes = last_attempt_error_code
+        .as_deref()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let retry_schedule = revenue_recovery_flow::get_schedule_time_for_next_retry(
+        db,
+        &execute_tracking_data.merchant_id,
+        (intent_retry_count + 1).into(),
+        intent_retry_count,
+        retry_override.as_ref(),
+        state.conf.revenue_recovery.network_retry_limit,
+        &error_codes,
+    )
+    .await
+    .ok_or(errors::RecoveryError::ValueNotFound)
+    .attach_printable("Failed to get schedule time for pcr workflow")?;
+    let schedule_time = retry_schedule.schedule_time;
+
+    if retry_schedule.decline_class.is_some() {
+        execute_tracking_data
+            .schedule_adjustments
+            .push(common_enums::RecoveryScheduleAdjustment::DeclineClassOverride);
+    }
+    execute_tracking_data.decline_class = retry_schedule.decline_class.clone();
+    // Failed executions of the calculate task are not carried over to the execute task
+    execute_tracking_data.execution_failure_count = 0;
+    execute_tracking_data.last_execution_error = None;
+
+    // The retry is kept the lead time of its notification away, so that the customer can be
+    // notified before the payment method is charged again
+    let enabled_retry_notification =
+        retry_notification::get_enabled_retry_notification(business_profile);
+    let schedule_time = match enabled_retry_notification {
+        Some(notification_config) => {
+            let adjusted_schedule_time = retry_notification::get_schedule_time_after_lead_time(
+                schedule_time,
+                notification_config,
+                common_utils::date_time::now(),
+            );
+            if adjusted_schedule_time != schedule_time {
+                execute_tracking_data
+                    .schedule_adjustments
+                    .push(common_enums::RecoveryScheduleAdjustment::RetryNotificationLeadTime);
+            }
+            adjusted_schedule_time
+        }
+        None => schedule_time,
+    };
+
+    // A retry in the expiry month of the card waits for the renewed card, which the payment
+    // processor is likely to charge successfully
+    let card_details = payment_intent
+        .feature_metadata
+        .as_ref()
+        .and_then(|feature_metadata| feature_metadata.payment_revenue_recovery_metadata.as_ref())
+        .and_then(|recovery_metadata| {
+            recovery_metadata
+                .billing_connector_payment_details
+                .card_details
+                .as_ref()
+        });
+    let schedule_time = match card_details.and_then(|card_details| {
+        pcr_types::get_schedule_time_after_card_renewal(
+            schedule_time,
+            card_details,
+            retry_schedule
+                .retry_window
+                .as_ref()
+                .and_then(|retry_window| retry_window.max_recovery_window_in_secs),
+            payment_intent.created_at,
+        )
+    }) {
+        Some(adjusted_schedule_time) => {
+            logger::info!(
+                ?schedule_time,
+                ?adjusted_schedule_time,
+                "Moved the pcr task past the expiry month of the card"
+            );
+            execute_tracking_data
+                .schedule_adjustments
+                .push(common_enums::RecoveryScheduleAdjustment::CardRenewal);
+            adjusted_schedule_time
+        }
+        None => schedule_time,
+    };
+
+    let schedule_time = match retry_schedule
+        .retry_window
+        .as_ref()
+        .and_then(|retry_window| {
+            pcr_types::get_schedule_time_in_retry_window(
+                schedule_time,
+                retry_window,
+                card_issuing_country,
+                payment_intent.created_at,
+            )
+        }) {
+        Some(adjusted_schedule_time) => {
+            logger::info!(
+                ?schedule_time,
+                ?adjusted_schedule_time,
+                ?card_issuing_country,
+                "Moved the pcr task into the retry window of the cardholder"
+            );
+            execute_tracking_data
+                .schedule_adjustments
+                .push(common_enums::RecoveryScheduleAdjustment::BusinessHoursWindow);
+            adjusted_schedule_time
+        }
+        None => schedule_time,
+    };
+
+    let execute_task_id = get_execute_pcr_task_id(&execute_tracking_data.global_payment_id, runner);
+    let processor_token_exclusion_window = time::Duration::seconds(i64::from(
+        state
+            .conf
+            .revenue_recovery
+            .processor_token_exclusion_window_in_secs,
+    ));
+
+    // Retries for the same processor token are kept apart from each other, since the payment
+    // processors might decline the payments made in quick succession using the same token
+    let schedule_time = match processor_token_tag.as_ref() {
+        Some(token_tag) if processor_token_exclusion_window.is_positive() => {
+            let scheduled_times = db
+                .find_pending_processes_by_tag_runner(
+                    vec![token_tag.clone()],
+                    runner,
+                    schedule_time - processor_token_exclusion_window,
+                )
+                .await?
+                .into_iter()
+                .filter(|process| process.id != execute_task_id)
+                .filter_map(|process| process.schedule_time)
+                .collect::<Vec<_>>();
+
+            match pcr_types::get_schedule_time_outside_exclusion_window(
+                schedule_time,
+                scheduled_times,
+                processor_token_exclusion_window,
+            ) {
+                Some(adjusted_schedule_time) => {
+                    logger::info!(
+                        ?schedule_time,
+                        ?adjusted_schedule_time,
+                        "Moved the pcr task outside the exclusion window of the processor token"
+                    );
+                    execute_tracking_data.schedule_adjustments.push(
+                        common_enums::RecoveryScheduleAdjustment::ProcessorTokenExclusionWindow,
+                    );
+                    adjusted_schedule_time
+                }
+                None => schedule_time,
+            }
+        }
+        _ => schedule_time,
+    };
+
+    // The retries are not made on the dates blacked out by the merchant
+    let schedule_time = match blackout_calendar::get_blackout_calendar(business_profile).and_then(
+        |blackout_calendar| {
+            blackout_calendar::get_schedule_time_after_blackouts(schedule_time, blackout_calendar)
+        },
+    ) {
+        Some(adjusted_schedule_time) => {
+            logger::info!(
+                ?schedule_time,
+                ?adjusted_schedule_time,
+                "Moved the pcr task past the blackouts of the profile"
+            );
+            execute_tracking_data
+                .schedule_adjustments
+                .push(common_enums::RecoveryScheduleAdjustment::BlackoutCalendar);
+            adjusted_schedule_time
+        }
+        None => schedule_time,
+    };
+    execute_tracking_data.schedule_decision =
+        Some(retry_schedule.get_schedule_decision(schedule_time));
+
+    // The billing connector of the payment is carried over from the tags of the calculate task
+    let tag = pcr::get_pcr_task_tags(
+        &execute_tracking_data.merchant_id,
+        pcr::get_billing_connector_from_tags(&calculate_task_process.tag),
+    )
+    .into_iter()
+    .chain(processor_token_tag)
+    .collect::<Vec<_>>();
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        execute_task_id.clone(),
+        EXECUTE_WORKFLOW,
+        runner,
+        tag,
+        execute_tracking_data,
+        Some(intent_retry_count.into()),
+        schedule_time,
+        common_enums::ApiVersion::V2,
+    )
+    .change_context(errors::StorageError::SerializationFailed)
+    .attach_printable("Failed to construct process tracker entry")?;
+
+    // The execute task of a previous failed payment of the invoice is updated in place
+    let execute_task_process = insert_or_update_pcr_task(db, process_tracker_entry).await?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "ExecutePCR")));
+
+    if let Some(notification_config) = enabled_retry_notification {
+        retry_notification::schedule_retry_notification(
+            db,
+            notification_config,
+            &execute_task_process,
+        )
+        .await?;
+    }
+
+    logger::info!(
+        payment_id = ?payment_intent.get_id(),
+        ?schedule_time,
+        %execute_task_id,
+        "Scheduled the next revenue recovery retry of the payment"
+    );
+
+    db.as_scheduler()
+        .finish_process_with_business_status(
+            calculate_task_process.clone(),
+            business_status::CALCULATE_WORKFLOW_COMPLETE,
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn insert_psync_pcr_task(
-    billing_mca_id: id_type::MerchantConnectorAccountId,
+    billing_mca: &merchant_connector_account::MerchantConnectorAccount,
     db: &dyn StorageInterface,
     merchant_id: id_type::MerchantId,
     payment_id: id_type::GlobalPaymentId,
     profile_id: id_type::ProfileId,
     payment_attempt_id: id_type::GlobalAttemptId,
+    recovery_correlation_id: Option<String>,
     runner: storage::ProcessTrackerRunner,
 ) -> RouterResult<storage::ProcessTracker> {
     let task = PSYNC_WORKFLOW;
     let process_tracker_id = payment_attempt_id.get_psync_revenue_recovery_id(task, runner);
     let schedule_time = common_utils::date_time::now();
+    let tag = pcr::get_pcr_task_tags(&merchant_id, Some(&billing_mca.connector_name.to_string()));
     let psync_workflow_tracking_data = pcr::PcrWorkflowTrackingData {
-        billing_mca_id,
+        billing_mca_id: billing_mca.get_id().clone(),
         global_payment_id: payment_id,
         merchant_id,
         profile_id,
         payment_attempt_id,
+        schedule_adjustments: Vec::new(),
+        recovery_correlation_id,
+        decline_class: None,
+        execution_failure_count: 0,
+        last_execution_error: None,
+        schedule_decision: None,
     };
-    let tag = ["PCR"];
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
         task,
@@ -197,6 +722,12 @@ pub async fn call_psync_api(
     global_payment_id: &id_type::GlobalPaymentId,
     pcr_data: &pcr::PcrPaymentData,
 ) -> RouterResult<PaymentStatusData<api::PSync>> {
+    inject_recovery_fault!(
+        state,
+        PaymentsSync,
+        errors::ApiErrorResponse::InternalServerError
+    );
+
     let operation = payments::operations::PaymentGet;
     let req = PaymentsRetrieveRequest {
         force_sync: false,
@@ -243,6 +774,7 @@ pub async fn call_psync_api(
 
 pub async fn retrieve_revenue_recovery_process_tracker(
     state: SessionState,
+    user_from_token: authentication::UserFromToken,
     id: id_type::GlobalPaymentId,
 ) -> RouterResponse<revenue_recovery::RevenueRecoveryResponse> {
     let db = &*state.store;
@@ -284,6 +816,51 @@ pub async fn retrieve_revenue_recovery_process_tracker(
 
     let schedule_time_for_psync = process_tracker_for_psync.and_then(|pt| pt.schedule_time);
 
+    let key_manager_state = &(&state).into();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            key_manager_state,
+            &tracking_data.global_payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let customer_details = get_recovery_customer_details(
+        &state,
+        &user_from_token,
+        &merchant_account,
+        &key_store,
+        &payment_intent,
+    )
+    .await?;
+
+    let retry_override = payment_intent
+        .feature_metadata
+        .and_then(|feature_metadata| feature_metadata.payment_revenue_recovery_metadata)
+        .and_then(|recovery_metadata| recovery_metadata.retry_override)
+        .map(ApiModelToDieselModelConvertor::convert_back);
+
     let response = revenue_recovery::RevenueRecoveryResponse {
         id: process_tracker.id,
         name: process_tracker.name,
@@ -291,6 +868,1704 @@ pub async fn retrieve_revenue_recovery_process_tracker(
         schedule_time_for_psync,
         status: process_tracker.status,
         business_status: process_tracker.business_status,
+        customer_details,
+        retry_override,
+        recovery_correlation_id: tracking_data.recovery_correlation_id,
+        decline_class: tracking_data.decline_class,
     };
     Ok(ApplicationResponse::Json(response))
 }
+
+/// Aggregates the revenue recovery details, attempts and the retry task of a single payment.
+pub async fn retrieve_revenue_recovery_status(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile: domain::Profile,
+    payment_id: id_type::GlobalPaymentId,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryStatusResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.profile_id != *profile.get_id() {
+        return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+    }
+
+    let recovery_metadata = payment_intent
+        .feature_metadata
+        .clone()
+        .and_then(|feature_metadata| feature_metadata.payment_revenue_recovery_metadata)
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Revenue recovery details not found for the payment".to_string(),
+        })?
+        .convert_back();
+
+    let payment_attempts = db
+        .find_payment_attempts_by_payment_intent_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment attempts for the payment")?;
+
+    let execute_task = db
+        .find_process_by_id(&get_execute_pcr_task_id(
+            &payment_id,
+            storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the execute task of the payment")?
+        .map(|process| {
+            revenue_recovery::RecoveryTaskResponse::foreign_from((
+                process,
+                common_utils::date_time::now(),
+            ))
+        });
+
+    let record_back_task = db
+        .find_process_by_id(&record_back::get_record_back_pcr_task_id(
+            &payment_id,
+            storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the record back task of the payment")?
+        .map(|process| {
+            revenue_recovery::RecoveryTaskResponse::foreign_from((
+                process,
+                common_utils::date_time::now(),
+            ))
+        });
+
+    let is_on_dispute_hold = recovery_metadata
+        .dispute_hold
+        .as_ref()
+        .is_some_and(|dispute_hold| dispute_hold.is_active(common_utils::date_time::now()));
+
+    let next_retry_at = execute_task
+        .as_ref()
+        .filter(|execute_task| {
+            matches!(
+                execute_task.task.status,
+                common_enums::ProcessTrackerStatus::New
+                    | common_enums::ProcessTrackerStatus::Pending
+            ) && !is_on_dispute_hold
+        })
+        .and_then(|execute_task| execute_task.task.schedule_time);
+
+    let mut attempts = payment_attempts
+        .iter()
+        .map(api_models::payments::PaymentAttemptResponse::foreign_from)
+        .collect::<Vec<_>>();
+    attempts.sort_by_key(|attempt| attempt.created_at);
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery::RevenueRecoveryStatusResponse {
+            payment_id,
+            status: payment_intent.status,
+            recovery_metadata,
+            next_retry_at,
+            is_on_dispute_hold,
+            execute_task,
+            record_back_task,
+            attempts,
+        },
+    ))
+}
+
+/// Number of records returned by the list apis when the limit is not passed in the request
+const DEFAULT_RECOVERY_LIST_LIMIT: u16 = 10;
+/// Upper bound on the number of records returned by the list apis in a single page
+const MAX_RECOVERY_LIST_LIMIT: u16 = 100;
+/// Prefix of the identifier of the items in the manual collection queue
+const COLLECTION_ITEM_ID_PREFIX: &str = "rci";
+
+fn get_recovery_list_limit(limit: Option<u16>) -> RouterResult<u16> {
+    let limit = limit.unwrap_or(DEFAULT_RECOVERY_LIST_LIMIT);
+    if limit == 0 || limit > MAX_RECOVERY_LIST_LIMIT {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {MAX_RECOVERY_LIST_LIMIT}"),
+        }
+        .into());
+    }
+    Ok(limit)
+}
+
+/// Lists the decisions taken by revenue recovery on consuming the webhooks of a payment, latest first.
+pub async fn list_revenue_recovery_decisions(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile: domain::Profile,
+    payment_id: id_type::GlobalPaymentId,
+    constraints: revenue_recovery::RevenueRecoveryDecisionListConstraints,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryDecisionListResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+
+    let limit = get_recovery_list_limit(constraints.limit)?;
+    let offset = constraints.offset.unwrap_or(0);
+
+    // Decisions are only returned for the payments of the merchant and the profile
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.profile_id != *profile.get_id() {
+        return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+    }
+
+    let decisions = db
+        .list_revenue_recovery_decisions_by_payment_id(
+            &payment_id,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery decisions of the payment")?;
+
+    let total_count = db
+        .count_revenue_recovery_decisions_by_payment_id(&payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the revenue recovery decisions of the payment")?;
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery::RevenueRecoveryDecisionListResponse {
+            payment_id,
+            total_count,
+            decisions: decisions
+                .into_iter()
+                .map(ForeignFrom::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+/// Lists the tasks which retry the payments of the merchant, in the order of their schedule time.
+/// The tasks are paged using the last task of the previous page rather than an offset, so that a
+/// page stays cheap to fetch deep into the queue of a large merchant.
+pub async fn list_revenue_recovery_tasks(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    constraints: revenue_recovery::RevenueRecoveryTaskListConstraints,
+) -> RouterResponse<LegacyListResponse<revenue_recovery::RecoveryTaskResponse>> {
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+
+    let limit = get_recovery_list_limit(constraints.limit)?;
+    let starting_after =
+        get_task_list_starting_after(db, &merchant_id, constraints.starting_after).await?;
+
+    // The tasks are matched on the merchant through their tracking data rather than their tags, so
+    // that the tasks created before the tasks were tagged with their merchant are listed too
+    let tags = std::iter::once(pcr::PCR_TASK_TAG.to_string())
+        .chain(
+            constraints
+                .billing_connector
+                .map(|billing_connector| pcr::get_connector_tag(&billing_connector.to_string())),
+        )
+        .collect();
+
+    let processes = db
+        .list_processes_by_merchant_task_constraints(
+            storage::ProcessTrackerMerchantTaskConstraints {
+                tags,
+                runner,
+                // The payments held in review for their unresolved payment connector are listed
+                // along with the retries, so that the merchant can find the payments to be mapped
+                names: vec![
+                    String::from(EXECUTE_WORKFLOW),
+                    String::from(connector_resolution::UNRESOLVED_CONNECTOR_WORKFLOW),
+                ],
+                merchant_id,
+                profile_id: constraints.profile_id,
+                status: constraints.status,
+                business_status: None,
+                schedule_time_start: constraints.schedule_time_start,
+                schedule_time_end: constraints.schedule_time_end,
+                starting_after,
+                limit: i64::from(limit),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery tasks of the merchant")?;
+
+    let current_time = common_utils::date_time::now();
+    let tasks = processes
+        .into_iter()
+        .map(|process| {
+            revenue_recovery::RecoveryTaskResponse::foreign_from((process, current_time))
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(get_task_list_response(
+        tasks, limit,
+    )))
+}
+
+/// Page of the tasks, along with the cursor of the next page when the page is full
+fn get_task_list_response(
+    tasks: Vec<revenue_recovery::RecoveryTaskResponse>,
+    limit: u16,
+) -> LegacyListResponse<revenue_recovery::RecoveryTaskResponse> {
+    PaginatedListResponse::new(tasks, limit)
+        .with_next_cursor(|task| task.task.id.clone())
+        .with_legacy_fields(revenue_recovery::RECOVERY_LIST_LEGACY_FIELDS)
+}
+
+/// Schedule time and id of the task of the merchant after which the tasks of a page are listed
+async fn get_task_list_starting_after(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    starting_after: Option<String>,
+) -> RouterResult<Option<(time::PrimitiveDateTime, String)>> {
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let Some(task_id) = starting_after else {
+        return Ok(None);
+    };
+
+    let invalid_cursor_error = || errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("starting_after `{task_id}` is not a task of the merchant"),
+    };
+    let task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the task to list the tasks after")?
+        .filter(|task| task.runner.as_deref() == Some(runner.to_string().as_str()))
+        .ok_or_else(invalid_cursor_error)?;
+    let tracking_data = task
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")
+        .change_context(invalid_cursor_error())?;
+    fp_utils::when(tracking_data.merchant_id != *merchant_id, || {
+        Err(invalid_cursor_error())
+    })?;
+    let schedule_time = task.schedule_time.ok_or_else(invalid_cursor_error)?;
+
+    Ok(Some((schedule_time, task.id)))
+}
+
+/// Overrides the retry threshold and the retry schedule of a single recovery invoice.
+pub async fn create_revenue_recovery_retry_override(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile: domain::Profile,
+    payment_id: id_type::GlobalPaymentId,
+    actor: String,
+    request: revenue_recovery::RevenueRecoveryRetryOverrideRequest,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryRetryOverrideResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let current_time = common_utils::date_time::now();
+
+    let (additional_retries, schedule) = validate_retry_override_request(&request, current_time)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.profile_id != *profile.get_id() {
+        return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+    }
+
+    if matches!(
+        payment_intent.status,
+        common_enums::IntentStatus::Succeeded | common_enums::IntentStatus::Cancelled
+    ) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Retries cannot be overridden for a payment in {} status",
+                payment_intent.status
+            ),
+        }
+        .into());
+    }
+
+    let mut feature_metadata = payment_intent.feature_metadata.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment is not being recovered by revenue recovery".to_string(),
+        },
+    )?;
+    let mut recovery_metadata = feature_metadata
+        .payment_revenue_recovery_metadata
+        .clone()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment is not being recovered by revenue recovery".to_string(),
+        })?;
+
+    // The retry budget of the override is counted from the retries made so far, which could be
+    // ahead of the intent when the execute task has already been retried
+    let execute_task_retry_count = db
+        .find_process_by_id(&get_execute_pcr_task_id(
+            &payment_id,
+            storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the execute task of the payment")?
+        .map(|process| u16::try_from(process.retry_count).unwrap_or(u16::MAX))
+        .unwrap_or_default();
+
+    let retry_override = RevenueRecoveryRetryOverride {
+        additional_retries,
+        schedule,
+        retry_count_at_override: recovery_metadata
+            .total_retry_count
+            .max(execute_task_retry_count),
+        reason: request.reason.trim().to_string(),
+        created_by: actor,
+        created_at: current_time,
+    };
+    recovery_metadata.retry_override = Some(
+        diesel_models::types::RevenueRecoveryRetryOverride::convert_from(retry_override.clone()),
+    );
+    feature_metadata.payment_revenue_recovery_metadata = Some(recovery_metadata);
+
+    let payment_intent_update =
+        hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::RecordUpdate {
+            status: payment_intent.status,
+            feature_metadata: Box::new(Some(feature_metadata)),
+            updated_by: merchant_account.storage_scheme.to_string(),
+        };
+
+    db.update_payment_intent(
+        key_manager_state,
+        payment_intent,
+        payment_intent_update,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    logger::info!(
+        payment_id = ?payment_id,
+        additional_retries = retry_override.additional_retries,
+        created_by = %retry_override.created_by,
+        "Revenue recovery retries overridden for the payment"
+    );
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery::RevenueRecoveryRetryOverrideResponse {
+            payment_id,
+            retry_override,
+        },
+    ))
+}
+
+/// Overrides the retries of a recovery invoice on behalf of a dashboard user.
+pub async fn create_revenue_recovery_retry_override_for_user(
+    state: SessionState,
+    user_from_token: authentication::UserFromToken,
+    payment_id: id_type::GlobalPaymentId,
+    request: revenue_recovery::RevenueRecoveryRetryOverrideRequest,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryRetryOverrideResponse> {
+    let (merchant_account, key_store, profile) =
+        get_merchant_context_for_user(&state, &user_from_token).await?;
+
+    create_revenue_recovery_retry_override(
+        state,
+        merchant_account,
+        key_store,
+        profile,
+        payment_id,
+        user_from_token.user_id,
+        request,
+    )
+    .await
+}
+
+/// Cancels the scheduled retry of a recovery invoice, recording the cancellation in the recovery
+/// metadata of the payment. A task which has already finished is left as is.
+pub async fn cancel_revenue_recovery_task(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile: domain::Profile,
+    payment_id: id_type::GlobalPaymentId,
+    actor: String,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryCancelResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.profile_id != *profile.get_id() {
+        return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+    }
+
+    let mut feature_metadata = payment_intent.feature_metadata.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment is not being recovered by revenue recovery".to_string(),
+        },
+    )?;
+    let mut recovery_metadata = feature_metadata
+        .payment_revenue_recovery_metadata
+        .clone()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment is not being recovered by revenue recovery".to_string(),
+        })?;
+
+    let task_id = get_execute_pcr_task_id(
+        &payment_id,
+        storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+    );
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the execute task of the payment")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No retry is scheduled for the payment".to_string(),
+        })?;
+
+    let (status, current_business_status) = (process.status, process.business_status.clone());
+    // The task has already run to completion, there is nothing left to cancel
+    if !finish_execute_task_as_cancelled(db, process).await? {
+        return Ok(ApplicationResponse::Json(
+            revenue_recovery::RevenueRecoveryCancelResponse {
+                payment_id,
+                task_id,
+                status,
+                business_status: current_business_status,
+                manual_cancellation: recovery_metadata
+                    .manual_cancellation
+                    .map(diesel_models::types::RevenueRecoveryManualCancellation::convert_back),
+            },
+        ));
+    }
+
+    let manual_cancellation = RevenueRecoveryManualCancellation {
+        task_id: task_id.clone(),
+        cancelled_by: actor,
+        cancelled_at: common_utils::date_time::now(),
+    };
+
+    recovery_metadata.manual_cancellation = Some(
+        diesel_models::types::RevenueRecoveryManualCancellation::convert_from(
+            manual_cancellation.clone(),
+        ),
+    );
+    feature_metadata.payment_revenue_recovery_metadata = Some(recovery_metadata);
+
+    let payment_intent_update =
+        hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::RecordUpdate {
+            status: payment_intent.status,
+            feature_metadata: Box::new(Some(feature_metadata)),
+            updated_by: merchant_account.storage_scheme.to_string(),
+        };
+
+    db.update_payment_intent(
+        key_manager_state,
+        payment_intent,
+        payment_intent_update,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    logger::info!(
+        payment_id = ?payment_id,
+        task_id = %task_id,
+        cancelled_by = %manual_cancellation.cancelled_by,
+        "Revenue recovery task cancelled for the payment"
+    );
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery::RevenueRecoveryCancelResponse {
+            payment_id,
+            task_id,
+            status: common_enums::ProcessTrackerStatus::Finish,
+            business_status: business_status::EXECUTE_WORKFLOW_CANCELLED_MANUALLY.to_string(),
+            manual_cancellation: Some(manual_cancellation),
+        },
+    ))
+}
+
+/// Finishes the execute task of a payment as cancelled manually, returning whether the task was
+/// cancelled. A task which has already run to completion is left as is. A task locked by a
+/// scheduler worker, which is retrying the payment right now, is left as is as well, and is
+/// reported as busy.
+async fn finish_execute_task_as_cancelled(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+) -> RouterResult<bool> {
+    match process.status {
+        common_enums::ProcessTrackerStatus::New | common_enums::ProcessTrackerStatus::Pending => {
+            db.finish_process_with_business_status(
+                process,
+                business_status::EXECUTE_WORKFLOW_CANCELLED_MANUALLY,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to cancel the execute task of the payment")?;
+            Ok(true)
+        }
+        common_enums::ProcessTrackerStatus::Finish | common_enums::ProcessTrackerStatus::Review => {
+            Ok(false)
+        }
+        common_enums::ProcessTrackerStatus::Processing
+        | common_enums::ProcessTrackerStatus::ProcessStarted => {
+            Err(errors::ApiErrorResponse::ResourceBusy.into())
+        }
+    }
+}
+
+/// Cancels the scheduled retry of a recovery invoice on behalf of a dashboard user.
+pub async fn cancel_revenue_recovery_task_for_user(
+    state: SessionState,
+    user_from_token: authentication::UserFromToken,
+    payment_id: id_type::GlobalPaymentId,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryCancelResponse> {
+    let (merchant_account, key_store, profile) =
+        get_merchant_context_for_user(&state, &user_from_token).await?;
+
+    cancel_revenue_recovery_task(
+        state,
+        merchant_account,
+        key_store,
+        profile,
+        payment_id,
+        user_from_token.user_id,
+    )
+    .await
+}
+
+/// Event type of the decision recorded when the merchant reschedules the next retry of a payment
+const MANUAL_RESCHEDULE_EVENT_TYPE: &str = "manual_reschedule";
+
+/// Moves the next retry of a recovery invoice to the time asked for by the merchant. A payment
+/// which is eligible for a retry but has no pending execute task is scheduled afresh.
+pub async fn reschedule_revenue_recovery_task(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile: domain::Profile,
+    payment_id: id_type::GlobalPaymentId,
+    actor: String,
+    request: revenue_recovery::RevenueRecoveryRescheduleRequest,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryRescheduleResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let current_time = common_utils::date_time::now();
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.profile_id != *profile.get_id() {
+        return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+    }
+
+    let recovery_metadata = payment_intent
+        .feature_metadata
+        .as_ref()
+        .and_then(|feature_metadata| feature_metadata.payment_revenue_recovery_metadata.clone())
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment is not being recovered by revenue recovery".to_string(),
+        })?
+        .convert_back();
+
+    let max_recovery_window_in_secs =
+        revenue_recovery_flow::get_max_recovery_window_in_secs(db, merchant_account.get_id()).await;
+    pcr_types::validate_reschedule_time(
+        request.schedule_time,
+        current_time,
+        payment_intent.created_at,
+        max_recovery_window_in_secs,
+    )?;
+
+    // The execute task parks itself until the hold expires, a retry cannot be made any earlier
+    if recovery_metadata
+        .dispute_hold
+        .as_ref()
+        .is_some_and(|dispute_hold| dispute_hold.is_active(current_time))
+    {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Retries of the payment are on hold for a dispute".to_string(),
+        }
+        .into());
+    }
+
+    let task_id = get_execute_pcr_task_id(&payment_id, runner);
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the execute task of the payment")?;
+
+    let decision_id = common_utils::generate_time_ordered_id("rrd");
+    let manual_schedule_decision = pcr::RecoveryScheduleDecision::new(
+        common_enums::RecoveryScheduleAlgorithm::ManualReschedule,
+        None,
+        current_time,
+        request.schedule_time,
+        request.schedule_time,
+    );
+
+    let (previous_schedule_time, status) = match process {
+        Some(process)
+            if matches!(
+                process.status,
+                common_enums::ProcessTrackerStatus::New
+                    | common_enums::ProcessTrackerStatus::Pending
+            ) =>
+        {
+            let mut tracking_data = process
+                .tracking_data
+                .clone()
+                .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the tracking data of the execute task")?;
+            // The retry is made at the time asked for, the adjustments of the previous schedule
+            // time no longer apply
+            tracking_data.schedule_adjustments =
+                vec![common_enums::RecoveryScheduleAdjustment::ManualReschedule];
+            tracking_data.decline_class = None;
+            tracking_data.recovery_correlation_id = Some(decision_id.clone());
+            tracking_data.schedule_decision = Some(manual_schedule_decision.clone());
+
+            let previous_schedule_time = process.schedule_time;
+            let status = process.status;
+            update_execute_task(
+                db,
+                process,
+                &tracking_data,
+                request.schedule_time,
+                None,
+                status,
+            )
+            .await?;
+
+            (previous_schedule_time, status)
+        }
+        // The task is locked by a scheduler worker, which is retrying the payment right now
+        Some(process)
+            if matches!(
+                process.status,
+                common_enums::ProcessTrackerStatus::Processing
+                    | common_enums::ProcessTrackerStatus::ProcessStarted
+            ) =>
+        {
+            return Err(errors::ApiErrorResponse::ResourceBusy.into());
+        }
+        process => {
+            // Mirrors the decision of the execute workflow, the payment has to have failed without
+            // an attempt in progress
+            let is_eligible_for_retry = payment_intent.status == common_enums::IntentStatus::Failed
+                && recovery_metadata.payment_connector_transmission
+                    == Some(common_enums::PaymentConnectorTransmission::ConnectorCallUnsuccessful)
+                && payment_intent.active_attempt_id.is_none();
+            if !is_eligible_for_retry {
+                return Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "Payment in {} status is not eligible for a retry",
+                        payment_intent.status
+                    ),
+                }
+                .into());
+            }
+
+            let payment_attempt_id = db
+                .find_payment_attempts_by_payment_intent_id(
+                    key_manager_state,
+                    &payment_id,
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment attempts for the payment")?
+                .into_iter()
+                .max_by_key(|payment_attempt| payment_attempt.created_at)
+                .map(|payment_attempt| payment_attempt.id)
+                .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Payment has no attempt to be retried".to_string(),
+                })?;
+
+            let tracking_data = pcr::PcrWorkflowTrackingData {
+                merchant_id: merchant_account.get_id().clone(),
+                profile_id: profile.get_id().clone(),
+                global_payment_id: payment_id.clone(),
+                payment_attempt_id,
+                billing_mca_id: recovery_metadata.billing_connector_id.clone(),
+                schedule_adjustments: vec![
+                    common_enums::RecoveryScheduleAdjustment::ManualReschedule,
+                ],
+                recovery_correlation_id: Some(decision_id.clone()),
+                decline_class: None,
+                execution_failure_count: 0,
+                last_execution_error: None,
+                schedule_decision: Some(manual_schedule_decision),
+            };
+            let retry_count = i32::from(recovery_metadata.total_retry_count);
+
+            match process {
+                Some(process) => {
+                    update_execute_task(
+                        db,
+                        process,
+                        &tracking_data,
+                        request.schedule_time,
+                        Some(retry_count),
+                        common_enums::ProcessTrackerStatus::New,
+                    )
+                    .await?;
+                }
+                None => {
+                    let processor_token_tag = pcr::get_processor_token_tag(
+                        merchant_account.get_id(),
+                        &recovery_metadata
+                            .billing_connector_payment_details
+                            .payment_processor_token,
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed to generate the processor token tag of the payment",
+                    )?;
+                    let billing_mca = db
+                        .find_merchant_connector_account_by_id(
+                            key_manager_state,
+                            &recovery_metadata.billing_connector_id,
+                            &key_store,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to fetch the billing connector of the payment")?;
+                    let tag = pcr::get_pcr_task_tags(
+                        merchant_account.get_id(),
+                        Some(&billing_mca.connector_name.to_string()),
+                    )
+                    .into_iter()
+                    .chain(std::iter::once(processor_token_tag))
+                    .collect::<Vec<_>>();
+
+                    let process_tracker_entry = storage::ProcessTrackerNew::new(
+                        task_id.clone(),
+                        EXECUTE_WORKFLOW,
+                        runner,
+                        tag,
+                        tracking_data,
+                        Some(retry_count),
+                        request.schedule_time,
+                        common_enums::ApiVersion::V2,
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to construct process tracker entry")?;
+
+                    db.insert_process(process_tracker_entry)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to create the execute task of the payment")?;
+                    metrics::TASKS_ADDED_COUNT
+                        .add(1, router_env::metric_attributes!(("flow", "ExecutePCR")));
+                }
+            }
+
+            (None, common_enums::ProcessTrackerStatus::New)
+        }
+    };
+
+    let decision = pcr::RevenueRecoveryDecision {
+        id: decision_id.clone(),
+        payment_id: payment_id.clone(),
+        merchant_id: merchant_account.get_id().clone(),
+        profile_id: profile.get_id().clone(),
+        event_type: MANUAL_RESCHEDULE_EVENT_TYPE.to_string(),
+        recovery_action: Some("reschedule_payment".to_string()),
+        attempt_triggered_by: None,
+        retry_count: i16::try_from(recovery_metadata.total_retry_count).ok(),
+        retry_threshold: None,
+        is_billing_connector_payment_sync_called: false,
+        webhook_action: None,
+        task_id: Some(task_id.clone()),
+        error: None,
+        created_at: current_time,
+    };
+    // The retry has already been rescheduled, failure in recording the decision does not fail the
+    // request
+    if let Err(error) = db.insert_revenue_recovery_decision(decision).await {
+        logger::error!(?error, "Failed to record the revenue recovery decision");
+    }
+
+    logger::info!(
+        payment_id = ?payment_id,
+        task_id = %task_id,
+        schedule_time = ?request.schedule_time,
+        ?previous_schedule_time,
+        rescheduled_by = %actor,
+        "Revenue recovery retry rescheduled for the payment"
+    );
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery::RevenueRecoveryRescheduleResponse {
+            payment_id,
+            task_id,
+            schedule_time: request.schedule_time,
+            previous_schedule_time,
+            status,
+            decision_id,
+        },
+    ))
+}
+
+/// Reschedules the next retry of a recovery invoice on behalf of a dashboard user.
+pub async fn reschedule_revenue_recovery_task_for_user(
+    state: SessionState,
+    user_from_token: authentication::UserFromToken,
+    payment_id: id_type::GlobalPaymentId,
+    request: revenue_recovery::RevenueRecoveryRescheduleRequest,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryRescheduleResponse> {
+    let (merchant_account, key_store, profile) =
+        get_merchant_context_for_user(&state, &user_from_token).await?;
+
+    reschedule_revenue_recovery_task(
+        state,
+        merchant_account,
+        key_store,
+        profile,
+        payment_id,
+        user_from_token.user_id,
+        request,
+    )
+    .await
+}
+
+async fn update_execute_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &pcr::PcrWorkflowTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+    retry_count: Option<i32>,
+    status: common_enums::ProcessTrackerStatus,
+) -> RouterResult<()> {
+    let tracking_data = serde_json::to_value(tracking_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the execute workflow tracking data")?;
+
+    db.as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: Some(EXECUTE_WORKFLOW.to_string()),
+                retry_count,
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(status),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule the execute task of the payment")?;
+    Ok(())
+}
+
+/// Fetches the merchant account, the key store and the profile which the dashboard user is acting on.
+async fn get_merchant_context_for_user(
+    state: &SessionState,
+    user_from_token: &authentication::UserFromToken,
+) -> RouterResult<(
+    domain::MerchantAccount,
+    domain::MerchantKeyStore,
+    domain::Profile,
+)> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &user_from_token.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &user_from_token.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            &user_from_token.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: user_from_token.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok((merchant_account, key_store, profile))
+}
+
+/// Validates the override request, returning the number of additional retries along with the
+/// de-duplicated schedule in the ascending order.
+fn validate_retry_override_request(
+    request: &revenue_recovery::RevenueRecoveryRetryOverrideRequest,
+    current_time: time::PrimitiveDateTime,
+) -> RouterResult<(u16, Vec<time::PrimitiveDateTime>)> {
+    if request.reason.trim().is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "reason must not be empty".to_string(),
+        }
+        .into());
+    }
+
+    let mut schedule = request.schedule.clone();
+    schedule.sort();
+    schedule.dedup();
+
+    if schedule
+        .iter()
+        .any(|schedule_time| *schedule_time <= current_time)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "schedule must only contain times in the future".to_string(),
+        }
+        .into());
+    }
+
+    let additional_retries = match request.additional_retries {
+        Some(0) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "additional_retries must be greater than zero".to_string(),
+        }),
+        Some(additional_retries) if usize::from(additional_retries) < schedule.len() => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "additional_retries must not be less than the number of scheduled retries"
+                    .to_string(),
+            })
+        }
+        Some(additional_retries) => Ok(additional_retries),
+        None if schedule.is_empty() => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Either additional_retries or schedule must be provided".to_string(),
+        }),
+        None => u16::try_from(schedule.len()).map_err(|_| {
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "schedule contains too many retries".to_string(),
+            }
+        }),
+    }?;
+
+    Ok((additional_retries, schedule))
+}
+
+/// Fetches the contact details of the customer linked to the recovery payment, only when the user
+/// is permitted to read the customers of the merchant.
+async fn get_recovery_customer_details(
+    state: &SessionState,
+    user_from_token: &authentication::UserFromToken,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &PaymentIntent,
+) -> RouterResult<Option<revenue_recovery::RevenueRecoveryCustomerDetails>> {
+    let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+        state,
+        &user_from_token.role_id,
+        &user_from_token.org_id,
+        user_from_token
+            .tenant_id
+            .as_ref()
+            .unwrap_or(&state.tenant.tenant_id),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)?;
+
+    if !role_info.check_permission_exists(Permission::MerchantCustomerRead)
+        || *merchant_account.get_id() != user_from_token.merchant_id
+    {
+        return Ok(None);
+    }
+
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+
+    let customer = match payment_intent.customer_id.as_ref() {
+        Some(customer_id) => db
+            .find_customer_by_global_id(
+                key_manager_state,
+                customer_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?,
+        None => return Ok(None),
+    };
+
+    let locale = customer
+        .metadata
+        .as_ref()
+        .and_then(|metadata| {
+            metadata
+                .peek()
+                .get(CUSTOMER_LOCALE_METADATA_KEY)
+                .and_then(|locale| locale.as_str())
+                .map(ToString::to_string)
+        })
+        .map(Secret::new);
+
+    Ok(Some(revenue_recovery::RevenueRecoveryCustomerDetails {
+        email: customer.email.map(pii::Email::from),
+        locale,
+    }))
+}
+
+pub async fn retrieve_revenue_recovery_analytics(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile: domain::Profile,
+    request: revenue_recovery::RevenueRecoveryAnalyticsRequest,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryAnalyticsResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let time_range = common_utils::types::TimeRange {
+        start_time: request.start_time,
+        end_time: request.end_time,
+    };
+
+    let mut payment_attempts = db
+        .find_payment_attempts_by_profile_id_time_range(
+            key_manager_state,
+            profile.get_id(),
+            &time_range,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment attempts for the profile")?;
+
+    let mapping = revenue_recovery_flow::get_pcr_payments_retry_mapping(db).await;
+    payment_attempts.sort_by_key(|payment_attempt| payment_attempt.created_at);
+
+    // Number of internally triggered retries made so far for each payment, along with the attempt
+    // of the payment made last
+    let mut payment_retries: HashMap<&str, (u16, Option<&PaymentAttempt>)> = HashMap::new();
+    let mut retry_outcomes = Vec::new();
+    for payment_attempt in &payment_attempts {
+        let (retry_count, last_attempt) = payment_retries
+            .entry(payment_attempt.payment_id.get_string_repr())
+            .or_default();
+        let preceding_attempt = last_attempt.replace(payment_attempt);
+
+        let Some(revenue_recovery_data) = payment_attempt
+            .feature_metadata
+            .as_ref()
+            .and_then(|feature_metadata| feature_metadata.revenue_recovery.as_ref())
+            .filter(|recovery_data| {
+                recovery_data.attempt_triggered_by == common_enums::TriggeredBy::Internal
+            })
+        else {
+            continue;
+        };
+        *retry_count = retry_count.saturating_add(1);
+
+        // only the retries which have reached a terminal status are considered
+        let is_successful = match payment_attempt.status.foreign_into() {
+            pcr_types::PcrAttemptStatus::Succeeded => true,
+            pcr_types::PcrAttemptStatus::Failed => false,
+            pcr_types::PcrAttemptStatus::Processing
+            | pcr_types::PcrAttemptStatus::InvalidStatus(_) => continue,
+        };
+
+        let decline_class = preceding_attempt.and_then(|preceding_attempt| {
+            scheduler::utils::get_pcr_decline_class(
+                &mapping,
+                &pcr_types::get_attempt_error_codes(preceding_attempt),
+            )
+            .map(str::to_string)
+        });
+
+        let retry_outcome = pcr_types::RetryOutcome {
+            is_successful,
+            schedule_adjustments: revenue_recovery_data
+                .schedule_adjustments
+                .clone()
+                .unwrap_or_default(),
+            merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+            connector: payment_attempt.connector.clone(),
+            retry_number: *retry_count,
+            decline_class,
+            card_network: get_attempt_card_network(payment_attempt),
+        };
+        if retry_outcome.matches_filters(
+            request.decline_class.as_deref(),
+            request.card_network.as_ref(),
+        ) {
+            retry_outcomes.push(retry_outcome);
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        pcr_types::get_retry_outcome_analytics(&retry_outcomes, request.group_by),
+    ))
+}
+
+/// Card network of the card with which the attempt was made, as stored in its payment method data
+fn get_attempt_card_network(payment_attempt: &PaymentAttempt) -> Option<common_enums::CardNetwork> {
+    payment_attempt
+        .payment_method_data
+        .as_ref()
+        .and_then(|payment_method_data| payment_method_data.peek().get("card"))
+        .and_then(|card| card.get("card_network"))
+        .and_then(|card_network| {
+            serde_json::from_value::<common_enums::CardNetwork>(card_network.clone()).ok()
+        })
+}
+
+/// Hands off the invoice to the manual collection queue once the retries of revenue recovery are
+/// exhausted, for the merchants who have enabled it. An invoice is handed off only once while its
+/// collection item is open.
+pub(crate) async fn hand_off_to_manual_collection(
+    state: &SessionState,
+    pcr_data: &pcr::PcrPaymentData,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) -> CustomResult<(), errors::RecoveryError> {
+    let db = &*state.store;
+    let merchant_id = pcr_data.merchant_account.get_id();
+
+    inject_recovery_fault!(
+        state,
+        Redis,
+        errors::RecoveryError::ManualCollectionHandoffFailed
+    );
+
+    let is_handoff_enabled = db
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_recovery_manual_collection_handoff_key(),
+            Some("false".to_string()),
+        )
+        .await
+        .change_context(errors::RecoveryError::ManualCollectionHandoffFailed)
+        .attach_printable("Failed to fetch the manual collection handoff config")?
+        .config
+        == "true";
+
+    if !is_handoff_enabled {
+        return Ok(());
+    }
+
+    let current_time = common_utils::date_time::now();
+    let last_error = payment_attempt.error.as_ref();
+    let collection_item = pcr::RevenueRecoveryCollectionItemNew {
+        id: common_utils::generate_time_ordered_id(COLLECTION_ITEM_ID_PREFIX),
+        payment_id: payment_intent.id.clone(),
+        merchant_id: merchant_id.clone(),
+        profile_id: payment_intent.profile_id.clone(),
+        merchant_reference_id: payment_intent.merchant_reference_id.clone(),
+        amount_outstanding: payment_intent.amount_details.calculate_net_amount(),
+        currency: payment_intent.amount_details.currency,
+        last_error_code: last_error.map(|error| error.code.clone()),
+        last_failure_reason: last_error
+            .map(|error| error.reason.clone().unwrap_or(error.message.clone())),
+        customer_id: payment_intent.customer_id.clone(),
+        status: common_enums::RecoveryCollectionStatus::Open,
+        created_at: current_time,
+        modified_at: current_time,
+    };
+
+    let collection_item = match db
+        .insert_revenue_recovery_collection_item(collection_item)
+        .await
+    {
+        Ok(collection_item) => collection_item,
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::info!(
+                payment_id = ?payment_intent.id,
+                "Payment is already in the manual collection queue"
+            );
+            return Ok(());
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::RecoveryError::ManualCollectionHandoffFailed)
+                .attach_printable("Failed to insert the manual collection item");
+        }
+    };
+
+    logger::info!(
+        payment_id = ?collection_item.payment_id,
+        collection_item_id = %collection_item.id,
+        "Payment handed off to the manual collection queue"
+    );
+
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::RecoveryHandedOff {
+            payment_id: collection_item.payment_id,
+            collection_item_id: collection_item.id,
+            amount_outstanding: collection_item.amount_outstanding,
+            currency: collection_item.currency,
+            recovery_correlation_id: get_recovery_correlation_id(payment_intent, payment_attempt),
+        }))
+        .emit();
+
+    Ok(())
+}
+
+/// Identifier of the billing connector webhook from which the attempt originated, falling back to
+/// the webhook which last recorded an attempt for the payment
+pub(crate) fn get_recovery_correlation_id(
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) -> Option<String> {
+    payment_attempt
+        .feature_metadata
+        .as_ref()
+        .and_then(|feature_metadata| feature_metadata.revenue_recovery.as_ref())
+        .and_then(|recovery_data| recovery_data.recovery_correlation_id.clone())
+        .or_else(|| {
+            payment_intent
+                .feature_metadata
+                .as_ref()
+                .and_then(|feature_metadata| {
+                    feature_metadata.payment_revenue_recovery_metadata.as_ref()
+                })
+                .and_then(|recovery_metadata| recovery_metadata.recovery_correlation_id.clone())
+        })
+}
+
+/// Lists the items of the manual collection queue of the profile, latest first.
+pub async fn list_revenue_recovery_collection_items(
+    state: SessionState,
+    user_from_token: authentication::UserFromToken,
+    constraints: revenue_recovery::RevenueRecoveryCollectionListConstraints,
+) -> RouterResponse<LegacyListResponse<revenue_recovery::RevenueRecoveryCollectionItemResponse>> {
+    let db = &*state.store;
+
+    let limit = get_recovery_list_limit(constraints.limit)?;
+    let offset = constraints.offset.unwrap_or(0);
+
+    let collection_items = db
+        .list_revenue_recovery_collection_items_by_profile_id(
+            &user_from_token.profile_id,
+            constraints.status,
+            constraints.assignee,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the manual collection items of the profile")?;
+
+    // The items are paged by the offset, so no cursor is returned
+    Ok(ApplicationResponse::Json(
+        PaginatedListResponse::new(
+            collection_items
+                .into_iter()
+                .map(ForeignFrom::foreign_from)
+                .collect(),
+            limit,
+        )
+        .with_legacy_fields(revenue_recovery::RECOVERY_LIST_LEGACY_FIELDS),
+    ))
+}
+
+/// Assigns an open item of the manual collection queue to a member of the collections team.
+pub async fn assign_revenue_recovery_collection_item(
+    state: SessionState,
+    user_from_token: authentication::UserFromToken,
+    request: revenue_recovery::RevenueRecoveryCollectionAssignRequest,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryCollectionItemResponse> {
+    let db = &*state.store;
+
+    let assignee = request.assignee.trim();
+    if assignee.is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "assignee must not be empty".to_string(),
+        }
+        .into());
+    }
+
+    let collection_item =
+        get_open_collection_item(&state, &user_from_token, &request.collection_item_id).await?;
+
+    let collection_item = db
+        .update_open_revenue_recovery_collection_item(
+            &collection_item.id,
+            pcr::RevenueRecoveryCollectionItemUpdate::Assign {
+                assignee: assignee.to_string(),
+                assigned_by: user_from_token.user_id,
+            },
+        )
+        .await
+        .to_not_found_response(get_collection_item_resolved_error())?;
+
+    Ok(ApplicationResponse::Json(ForeignFrom::foreign_from(
+        collection_item,
+    )))
+}
+
+/// Resolves an open item of the manual collection queue. The payment is marked as succeeded when
+/// the invoice was paid outside of hyperswitch, the same way as an externally succeeded payment
+/// reported by the billing connector.
+pub async fn resolve_revenue_recovery_collection_item(
+    state: SessionState,
+    user_from_token: authentication::UserFromToken,
+    request: revenue_recovery::RevenueRecoveryCollectionResolveRequest,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryCollectionItemResponse> {
+    let db = &*state.store;
+
+    let collection_item =
+        get_open_collection_item(&state, &user_from_token, &request.collection_item_id).await?;
+
+    if request.outcome == common_enums::RecoveryCollectionOutcome::PaidExternally {
+        let (merchant_account, key_store, profile) =
+            get_merchant_context_for_user(&state, &user_from_token).await?;
+        record_externally_paid_collection_item(
+            &state,
+            merchant_account,
+            key_store,
+            profile,
+            &collection_item,
+        )
+        .await?;
+    }
+
+    let collection_item = db
+        .update_open_revenue_recovery_collection_item(
+            &collection_item.id,
+            pcr::RevenueRecoveryCollectionItemUpdate::Resolve {
+                outcome: request.outcome,
+                resolved_by: user_from_token.user_id,
+            },
+        )
+        .await
+        .to_not_found_response(get_collection_item_resolved_error())?;
+
+    logger::info!(
+        payment_id = ?collection_item.payment_id,
+        collection_item_id = %collection_item.id,
+        outcome = %request.outcome,
+        "Manual collection item resolved"
+    );
+
+    Ok(ApplicationResponse::Json(ForeignFrom::foreign_from(
+        collection_item,
+    )))
+}
+
+async fn get_open_collection_item(
+    state: &SessionState,
+    user_from_token: &authentication::UserFromToken,
+    collection_item_id: &str,
+) -> RouterResult<pcr::RevenueRecoveryCollectionItem> {
+    let collection_item = state
+        .store
+        .find_revenue_recovery_collection_item_by_profile_id_id(
+            &user_from_token.profile_id,
+            collection_item_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Collection item not found".to_string(),
+        })?;
+
+    if collection_item.status != common_enums::RecoveryCollectionStatus::Open {
+        return Err(get_collection_item_resolved_error().into());
+    }
+
+    Ok(collection_item)
+}
+
+fn get_collection_item_resolved_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::PreconditionFailed {
+        message: "Collection item is already resolved".to_string(),
+    }
+}
+
+/// Records a successful external attempt for the outstanding amount of the invoice, which moves the
+/// payment to the succeeded status.
+async fn record_externally_paid_collection_item(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile: domain::Profile,
+    collection_item: &pcr::RevenueRecoveryCollectionItem,
+) -> RouterResult<()> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_id(
+            &state.into(),
+            &collection_item.payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let recovery_metadata = payment_intent
+        .feature_metadata
+        .and_then(|feature_metadata| feature_metadata.payment_revenue_recovery_metadata)
+        .map(ApiModelToDieselModelConvertor::convert_back)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment is not being recovered by revenue recovery".to_string(),
+        })?;
+
+    let request = api_models::payments::PaymentsAttemptRecordRequest {
+        amount_details: api_models::payments::PaymentAttemptAmountDetails {
+            net_amount: collection_item.amount_outstanding,
+            amount_to_capture: None,
+            surcharge_amount: None,
+            tax_on_surcharge: None,
+            amount_capturable: collection_item.amount_outstanding,
+            shipping_cost: None,
+            order_tax_amount: None,
+        },
+        status: common_enums::AttemptStatus::Charged,
+        billing: None,
+        shipping: None,
+        error: None,
+        description: None,
+        connector_transaction_id: None,
+        payment_method_type: recovery_metadata.payment_method_type,
+        connector: Some(recovery_metadata.connector),
+        billing_connector_id: recovery_metadata.billing_connector_id,
+        payment_merchant_connector_id: Some(recovery_metadata.active_attempt_payment_connector_id),
+        payment_method_subtype: recovery_metadata.payment_method_subtype,
+        payment_method_data: None,
+        metadata: None,
+        feature_metadata: Some(api_models::payments::PaymentAttemptFeatureMetadata {
+            revenue_recovery: Some(api_models::payments::PaymentAttemptRevenueRecoveryData {
+                attempt_triggered_by: common_enums::TriggeredBy::External,
+                processor_response_details: None,
+                schedule_adjustments: None,
+                data_provenance: None,
+                recovery_correlation_id: recovery_metadata.recovery_correlation_id,
+                connector_switch: None,
+            }),
+        }),
+        transaction_created_at: Some(common_utils::date_time::now()),
+        processor_payment_method_token: recovery_metadata
+            .billing_connector_payment_details
+            .payment_processor_token,
+        connector_customer_id: recovery_metadata
+            .billing_connector_payment_details
+            .connector_customer_id,
+        card_details: recovery_metadata
+            .billing_connector_payment_details
+            .card_details,
+    };
+
+    Box::pin(payments::record_attempt_core(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        profile,
+        key_store,
+        request,
+        collection_item.payment_id.clone(),
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None,
+    ))
+    .await
+    .attach_printable("Failed to record the external payment of the manual collection item")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::db::MockDb;
+
+    const TASK_ID: &str = "PASSIVE_RECOVERY_WORKFLOW_EXECUTE_WORKFLOW_12345_payment";
+
+    fn get_execute_task(
+        retry_count: i32,
+        schedule_time: time::PrimitiveDateTime,
+    ) -> storage::ProcessTrackerNew {
+        storage::ProcessTrackerNew::new(
+            TASK_ID,
+            EXECUTE_WORKFLOW,
+            storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+            ["PCR"],
+            serde_json::json!({ "intent_retry_count": retry_count }),
+            Some(retry_count),
+            schedule_time,
+            common_enums::ApiVersion::V2,
+        )
+        .expect("valid process tracker entry")
+    }
+
+    async fn get_mock_db() -> MockDb {
+        MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store")
+    }
+
+    #[tokio::test]
+    async fn test_pcr_task_is_inserted_when_not_present() {
+        let db = get_mock_db().await;
+
+        let process =
+            insert_or_update_pcr_task(&db, get_execute_task(1, datetime!(2024-01-15 10:00)))
+                .await
+                .expect("task inserted");
+
+        assert_eq!(process.id, TASK_ID);
+        assert_eq!(process.retry_count, 1);
+        assert_eq!(process.status, common_enums::ProcessTrackerStatus::New);
+    }
+
+    #[tokio::test]
+    async fn test_pending_pcr_task_is_updated_for_duplicate_id() {
+        let db = get_mock_db().await;
+        insert_or_update_pcr_task(&db, get_execute_task(1, datetime!(2024-01-15 10:00)))
+            .await
+            .expect("task inserted");
+
+        let process =
+            insert_or_update_pcr_task(&db, get_execute_task(2, datetime!(2024-01-16 10:00)))
+                .await
+                .expect("task updated");
+
+        assert_eq!(process.retry_count, 2);
+        assert_eq!(process.schedule_time, Some(datetime!(2024-01-16 10:00)));
+        assert_eq!(
+            process.tracking_data,
+            serde_json::json!({ "intent_retry_count": 2 })
+        );
+        assert_eq!(process.status, common_enums::ProcessTrackerStatus::New);
+        assert_eq!(
+            db.find_process_by_id(TASK_ID).await.expect("task readable"),
+            Some(process)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_finished_pcr_task_is_revived_for_duplicate_id() {
+        let db = get_mock_db().await;
+        let process =
+            insert_or_update_pcr_task(&db, get_execute_task(3, datetime!(2024-01-15 10:00)))
+                .await
+                .expect("task inserted");
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::StatusUpdate {
+                    status: common_enums::ProcessTrackerStatus::Finish,
+                    business_status: Some(String::from(business_status::EXECUTE_WORKFLOW_COMPLETE)),
+                },
+            )
+            .await
+            .expect("task finished");
+
+        // The retry count is bumped even if the payment intent lags behind the task
+        let process =
+            insert_or_update_pcr_task(&db, get_execute_task(3, datetime!(2024-01-16 10:00)))
+                .await
+                .expect("task revived");
+
+        assert_eq!(process.retry_count, 4);
+        assert_eq!(process.schedule_time, Some(datetime!(2024-01-16 10:00)));
+        assert_eq!(process.status, common_enums::ProcessTrackerStatus::New);
+        assert_eq!(process.business_status, business_status::PENDING);
+    }
+
+    #[tokio::test]
+    async fn test_execute_task_locked_by_scheduler_is_not_cancelled() {
+        let db = get_mock_db().await;
+        let process =
+            insert_or_update_pcr_task(&db, get_execute_task(1, datetime!(2024-01-15 10:00)))
+                .await
+                .expect("task inserted");
+        // Picked up by a scheduler worker, which is retrying the payment
+        let process = db
+            .as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::StatusUpdate {
+                    status: common_enums::ProcessTrackerStatus::ProcessStarted,
+                    business_status: None,
+                },
+            )
+            .await
+            .expect("task locked");
+
+        let error = finish_execute_task_as_cancelled(&db, process)
+            .await
+            .expect_err("locked task cancelled");
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::ResourceBusy
+        ));
+
+        let process = db
+            .as_scheduler()
+            .find_process_by_id(TASK_ID)
+            .await
+            .expect("task fetched")
+            .expect("task present");
+        assert_eq!(
+            process.status,
+            common_enums::ProcessTrackerStatus::ProcessStarted
+        );
+        assert_eq!(process.business_status, business_status::PENDING);
+        assert_eq!(process.retry_count, 1);
+    }
+}
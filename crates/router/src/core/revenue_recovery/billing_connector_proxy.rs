@@ -0,0 +1,163 @@
+//! Outbound proxy of the billing connector account, used for the payments sync, the invoice sync
+//! and the record back calls to the billing connector in place of the proxy of the deployment.
+//!
+//! The proxy is kept in the connector account details of the billing connector account, under
+//! `outbound_proxy`, so that its credentials are encrypted like the credentials of the billing
+//! connector. ex: `{ "auth_type": "HeaderKey", "api_key": "key", "outbound_proxy": { "host":
+//! "egress.merchant.com", "port": 3128, "username": "user", "password": "pass", "ca_certificate":
+//! "<base64 encoded PEM bundle>" } }`
+
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use error_stack::ResultExt;
+use masking::Secret;
+
+use crate::{core::errors::ApiClientError, routes::SessionState, services::client};
+
+/// Outbound proxy of the billing connector account
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BillingConnectorProxy {
+    host: String,
+    port: u16,
+    username: Option<Secret<String>>,
+    password: Option<Secret<String>>,
+    ca_certificate: Option<Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct BillingConnectorProxyDetails {
+    outbound_proxy: Option<BillingConnectorProxy>,
+}
+
+impl BillingConnectorProxy {
+    fn to_outbound_proxy(&self) -> CustomResult<client::OutboundProxy, ApiClientError> {
+        let proxy_url = url::Url::parse(&format!("http://{}:{}", self.host, self.port))
+            .change_context(ApiClientError::InvalidProxyConfiguration)
+            .attach_printable("Invalid host of the outbound proxy")?;
+
+        // The host is taken as is, so a host carrying a scheme, a path or a port is not valid
+        if proxy_url.host_str() != Some(self.host.as_str())
+            || proxy_url.port_or_known_default() != Some(self.port)
+            || self.port == 0
+        {
+            return Err(ApiClientError::InvalidProxyConfiguration)
+                .attach_printable("Outbound proxy is to be configured as a host and a port");
+        }
+
+        if self.username.is_some() != self.password.is_some() {
+            return Err(ApiClientError::InvalidProxyConfiguration)
+                .attach_printable("Both the username and the password of the outbound proxy are required for its authentication");
+        }
+
+        Ok(client::OutboundProxy {
+            url: proxy_url.to_string(),
+            username: self.username.clone(),
+            password: self.password.clone(),
+            ca_certificate: self.ca_certificate.clone(),
+        })
+    }
+}
+
+/// Outbound proxy configured in the connector account details of the billing connector account
+pub(crate) fn get_billing_connector_proxy(
+    connector_account_details: serde_json::Value,
+) -> CustomResult<Option<client::OutboundProxy>, ApiClientError> {
+    connector_account_details
+        .parse_value::<BillingConnectorProxyDetails>("BillingConnectorProxyDetails")
+        .change_context(ApiClientError::InvalidProxyConfiguration)
+        .attach_printable("Failed to parse the outbound proxy of the billing connector account")?
+        .outbound_proxy
+        .map(|proxy| proxy.to_outbound_proxy())
+        .transpose()
+}
+
+/// Validates the outbound proxy of the billing connector account, by constructing the client which
+/// sends the calls to the billing connector through it
+pub(crate) fn validate_billing_connector_proxy(
+    state: &SessionState,
+    connector_account_details: serde_json::Value,
+) -> CustomResult<(), ApiClientError> {
+    get_billing_connector_proxy(connector_account_details)?
+        .map(|outbound_proxy| {
+            client::create_client_with_outbound_proxy(
+                &state.conf.proxy,
+                &outbound_proxy,
+                None,
+                None,
+            )
+        })
+        .transpose()
+        .map(|_| ())
+}
+
+/// Whether the call to the billing connector failed as the client could not be set up with the
+/// outbound proxy of the billing connector account
+pub(crate) fn is_proxy_misconfigured<C>(error: &error_stack::Report<C>) -> bool {
+    error
+        .frames()
+        .filter_map(|frame| frame.downcast_ref::<ApiClientError>())
+        .any(|error| error == &ApiClientError::InvalidProxyConfiguration)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_outbound_proxy_is_parsed_from_connector_account_details() {
+        let outbound_proxy = get_billing_connector_proxy(serde_json::json!({
+            "auth_type": "HeaderKey",
+            "api_key": "key",
+            "outbound_proxy": {
+                "host": "egress.merchant.com",
+                "port": 3128,
+                "username": "user",
+                "password": "pass"
+            }
+        }))
+        .unwrap()
+        .unwrap();
+        assert_eq!(outbound_proxy.url, "http://egress.merchant.com:3128/");
+
+        let outbound_proxy = get_billing_connector_proxy(
+            serde_json::json!({ "auth_type": "HeaderKey", "api_key": "key" }),
+        )
+        .unwrap();
+        assert!(outbound_proxy.is_none());
+    }
+
+    #[test]
+    fn test_misconfigured_outbound_proxy_is_rejected() {
+        let get_outbound_proxy = |outbound_proxy| {
+            get_billing_connector_proxy(serde_json::json!({
+                "auth_type": "HeaderKey",
+                "api_key": "key",
+                "outbound_proxy": outbound_proxy
+            }))
+        };
+
+        assert!(get_outbound_proxy(serde_json::json!({
+            "host": "https://egress.merchant.com",
+            "port": 3128
+        }))
+        .is_err());
+        assert!(get_outbound_proxy(serde_json::json!({
+            "host": "egress.merchant.com/path",
+            "port": 3128
+        }))
+        .is_err());
+        assert!(get_outbound_proxy(serde_json::json!({
+            "host": "egress.merchant.com",
+            "port": 0
+        }))
+        .is_err());
+        assert!(get_outbound_proxy(serde_json::json!({
+            "host": "egress.merchant.com",
+            "port": 3128,
+            "username": "user"
+        }))
+        .is_err());
+    }
+}
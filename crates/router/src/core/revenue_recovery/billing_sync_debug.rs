@@ -0,0 +1,186 @@
+//! Payments sync with the billing connector made on demand, for seeing what the billing connector
+//! returns for a transaction or an invoice without replaying its webhook.
+//!
+//! The sync is read-only: no attempt is recorded, no recovery task is created and the cached
+//! payments sync response of the transaction is neither used nor replaced. The outcome of the call
+//! still counts towards the payments sync circuit of the billing connector account, as it is a call
+//! made to the billing connector like any other.
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use hyperswitch_domain_models::{
+    api::ApplicationResponse, revenue_recovery::RevenueRecoveryAttemptData,
+    router_request_types::revenue_recovery::BillingConnectorPaymentsSyncId,
+    router_response_types::revenue_recovery::BillingConnectorPaymentsSyncResponse,
+};
+use router_env::{instrument, tracing};
+
+use super::task_snapshot::mask;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        webhooks::{BillingConnectorPaymentsSyncResponseData, PaymentsSyncCacheMode},
+    },
+    routes::SessionState,
+    types::domain,
+};
+
+#[instrument(skip_all)]
+pub async fn sync_billing_connector_payment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: revenue_recovery_api::RevenueRecoveryBillingSyncRequest,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryBillingSyncResponse> {
+    let payments_sync_id = get_payments_sync_id(request.transaction_id, request.invoice_id)?;
+    let billing_connector_id = request.billing_connector_id;
+    let mca_not_found = || errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+        id: billing_connector_id.get_string_repr().to_string(),
+    };
+
+    let billing_connector_account = state
+        .store
+        .find_merchant_connector_account_by_id(&(&state).into(), &billing_connector_id, &key_store)
+        .await
+        .to_not_found_response(mca_not_found())?;
+    if billing_connector_account.merchant_id != *merchant_account.get_id() {
+        return Err(mca_not_found().into());
+    }
+    if billing_connector_account.connector_type != common_enums::ConnectorType::BillingProcessor {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Merchant connector account `{}` is not a billing connector account",
+                billing_connector_id.get_string_repr()
+            ),
+        }
+        .into());
+    }
+
+    let connector = billing_connector_account.connector_name.to_string();
+    let synced_at = common_utils::date_time::now();
+    let payments_sync_response =
+        BillingConnectorPaymentsSyncResponseData::handle_billing_connector_payment_sync_call(
+            &state,
+            &merchant_account,
+            &billing_connector_account,
+            &connector,
+            &payments_sync_id,
+            synced_at,
+            None,
+            PaymentsSyncCacheMode::Bypass,
+            None,
+        )
+        .await
+        .map_err(|error| {
+            let message = error.current_context().to_string();
+            error.change_context(errors::ApiErrorResponse::UnprocessableEntity { message })
+        })?
+        .inner();
+    let attempt_data = RevenueRecoveryAttemptData::from(&payments_sync_response);
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RevenueRecoveryBillingSyncResponse {
+            merchant_id: merchant_account.get_id().clone(),
+            billing_connector_id,
+            connector,
+            payments_sync_response: get_masked_transaction(payments_sync_response),
+            attempt_data: get_masked_attempt(attempt_data),
+            synced_at,
+        },
+    ))
+}
+
+fn get_payments_sync_id(
+    transaction_id: Option<String>,
+    invoice_id: Option<String>,
+) -> Result<BillingConnectorPaymentsSyncId, errors::ApiErrorResponse> {
+    match (transaction_id, invoice_id) {
+        (Some(transaction_id), None) => Ok(BillingConnectorPaymentsSyncId::TransactionId(
+            transaction_id,
+        )),
+        (None, Some(invoice_id)) => Ok(BillingConnectorPaymentsSyncId::InvoiceId(invoice_id)),
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of `transaction_id` and `invoice_id` is to be provided"
+                .to_string(),
+        }),
+    }
+}
+
+fn get_masked_transaction(
+    response: BillingConnectorPaymentsSyncResponse,
+) -> revenue_recovery_api::RevenueRecoveryBillingSyncTransaction {
+    revenue_recovery_api::RevenueRecoveryBillingSyncTransaction {
+        amount: response.amount,
+        currency: response.currency,
+        merchant_reference_id: response.merchant_reference_id,
+        connector_transaction_id: response
+            .connector_transaction_id
+            .map(|connector_transaction_id| connector_transaction_id.get_id().clone()),
+        error_code: response.error_code,
+        error_message: response.error_message,
+        processor_payment_method_token: mask(response.processor_payment_method_token),
+        connector_customer_id: mask(response.connector_customer_id),
+        connector_account_reference_id: response.connector_account_reference_id,
+        transaction_created_at: response.transaction_created_at,
+        status: response.status,
+        payment_method_type: response.payment_method_type,
+        payment_method_sub_type: response.payment_method_sub_type,
+        network_advice_code: response.network_advice_code,
+        network_decline_code: response.network_decline_code,
+        network_error_message: response.network_error_message,
+        processor_response_details: response.processor_response_details,
+        card_issuing_country: response.card_issuing_country,
+        card_details: response.card_details,
+        billing_connector_subscription_id: response.billing_connector_subscription_id,
+    }
+}
+
+fn get_masked_attempt(
+    attempt_data: RevenueRecoveryAttemptData,
+) -> revenue_recovery_api::RevenueRecoveryBillingSyncAttempt {
+    revenue_recovery_api::RevenueRecoveryBillingSyncAttempt {
+        amount: attempt_data.amount,
+        currency: attempt_data.currency,
+        merchant_reference_id: attempt_data.merchant_reference_id,
+        connector_transaction_id: attempt_data
+            .connector_transaction_id
+            .map(|connector_transaction_id| connector_transaction_id.get_id().clone()),
+        error_code: attempt_data.error_code,
+        error_message: attempt_data.error_message,
+        processor_payment_method_token: mask(attempt_data.processor_payment_method_token),
+        connector_customer_id: mask(attempt_data.connector_customer_id),
+        connector_account_reference_id: attempt_data.connector_account_reference_id,
+        transaction_created_at: attempt_data.transaction_created_at,
+        status: attempt_data.status,
+        payment_method_type: attempt_data.payment_method_type,
+        payment_method_sub_type: attempt_data.payment_method_sub_type,
+        network_advice_code: attempt_data.network_advice_code,
+        network_decline_code: attempt_data.network_decline_code,
+        network_error_message: attempt_data.network_error_message,
+        processor_response_details: attempt_data.processor_response_details,
+        card_issuing_country: attempt_data.card_issuing_country,
+        card_details: attempt_data.card_details,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_exactly_one_payments_sync_id_is_accepted() {
+        assert!(matches!(
+            get_payments_sync_id(Some(String::from("txn_1")), None).unwrap(),
+            BillingConnectorPaymentsSyncId::TransactionId(transaction_id) if transaction_id == "txn_1"
+        ));
+        assert!(matches!(
+            get_payments_sync_id(None, Some(String::from("inv_1"))).unwrap(),
+            BillingConnectorPaymentsSyncId::InvoiceId(invoice_id) if invoice_id == "inv_1"
+        ));
+        assert!(get_payments_sync_id(None, None).is_err());
+        assert!(
+            get_payments_sync_id(Some(String::from("txn_1")), Some(String::from("inv_1"))).is_err()
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Blackout calendar of the revenue recovery retries of a profile.
+//!
+//! The merchant marks the dates on which its customers are not to be charged, such as religious
+//! holidays or the outages of regional banks, each range of dates being observed in its own
+//! timezone. A retry falling within a blackout is moved to the start of the day after the last date
+//! of the blackout. The calendar is consulted when the retry is scheduled, and again when the retry
+//! is picked up, so that the blackouts added after the retry was scheduled are observed as well.
+
+use common_types::payments::{RevenueRecoveryBlackout, RevenueRecoveryBlackoutCalendar};
+use common_utils::errors::CustomResult;
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors,
+    db::StorageInterface,
+    logger,
+    types::{domain, storage},
+    utils::timezone,
+};
+
+pub(crate) fn get_blackout_calendar(
+    business_profile: &domain::Profile,
+) -> Option<&RevenueRecoveryBlackoutCalendar> {
+    business_profile
+        .revenue_recovery_blackout_calendar
+        .as_ref()
+        .filter(|blackout_calendar| !blackout_calendar.blackouts.is_empty())
+}
+
+/// UTC times at which the blackout starts and ends, the blackout ending at the start of the day
+/// after its last date in the timezone of the blackout
+fn get_blackout_period(
+    blackout: &RevenueRecoveryBlackout,
+) -> Option<(PrimitiveDateTime, PrimitiveDateTime)> {
+    let Some(timezone) = timezone::parse_timezone(&blackout.timezone) else {
+        logger::warn!(
+            timezone_name = %blackout.timezone,
+            "Invalid timezone configured for the revenue recovery blackout"
+        );
+        return None;
+    };
+    let day_after_end_date = blackout.end_date.next_day()?;
+
+    Some((
+        timezone::to_utc_time(blackout.start_date.midnight(), timezone),
+        timezone::to_utc_time(day_after_end_date.midnight(), timezone),
+    ))
+}
+
+/// Moves the schedule time to the end of the blackout it falls within. A retry moved to the end of
+/// a blackout which falls within another blackout is moved to the end of that blackout as well.
+///
+/// Returns `None` if the schedule time does not fall within any blackout.
+pub(crate) fn get_schedule_time_after_blackouts(
+    schedule_time: PrimitiveDateTime,
+    blackout_calendar: &RevenueRecoveryBlackoutCalendar,
+) -> Option<PrimitiveDateTime> {
+    let blackout_periods = blackout_calendar
+        .blackouts
+        .iter()
+        .filter_map(get_blackout_period)
+        .collect::<Vec<_>>();
+
+    // Every move takes the retry past the end of a blackout, which the retry cannot fall within
+    // again, so the retry is moved at most once for each blackout
+    let mut adjusted_schedule_time = schedule_time;
+    for _ in 0..blackout_periods.len() {
+        let Some(blackout_end) = blackout_periods
+            .iter()
+            .filter(|(start, end)| (*start..*end).contains(&adjusted_schedule_time))
+            .map(|(_, end)| *end)
+            .max()
+        else {
+            break;
+        };
+        adjusted_schedule_time = blackout_end;
+    }
+
+    (adjusted_schedule_time != schedule_time).then_some(adjusted_schedule_time)
+}
+
+/// Defers the retry picked up within a blackout of the profile to the end of the blackout, returning
+/// whether the retry was deferred
+pub(crate) async fn defer_retry_if_in_blackout(
+    db: &dyn StorageInterface,
+    execute_task_process: &storage::ProcessTracker,
+    business_profile: &domain::Profile,
+    current_time: PrimitiveDateTime,
+) -> CustomResult<bool, errors::StorageError> {
+    let Some(deferred_schedule_time) =
+        get_blackout_calendar(business_profile).and_then(|blackout_calendar| {
+            get_schedule_time_after_blackouts(current_time, blackout_calendar)
+        })
+    else {
+        return Ok(false);
+    };
+
+    db.as_scheduler()
+        .update_process(
+            execute_task_process.clone(),
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(deferred_schedule_time),
+                tracking_data: None,
+                business_status: None,
+                status: Some(common_enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(current_time),
+            },
+        )
+        .await?;
+
+    logger::info!(
+        task_id = %execute_task_process.id,
+        ?deferred_schedule_time,
+        "Revenue recovery retry deferred, since it was picked up within a blackout of the profile"
+    );
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::{date, datetime};
+
+    use super::*;
+
+    fn get_blackout(
+        start_date: time::Date,
+        end_date: time::Date,
+        timezone: &str,
+    ) -> RevenueRecoveryBlackout {
+        RevenueRecoveryBlackout {
+            start_date,
+            end_date,
+            timezone: String::from(timezone),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_schedule_time_at_blackout_boundaries() {
+        let blackout_calendar = RevenueRecoveryBlackoutCalendar {
+            blackouts: vec![get_blackout(
+                date!(2024 - 12 - 24),
+                date!(2024 - 12 - 26),
+                "UTC",
+            )],
+        };
+
+        // Right before the start and at the end of the blackout
+        for schedule_time in [datetime!(2024-12-23 23:59:59), datetime!(2024-12-27 00:00)] {
+            assert_eq!(
+                get_schedule_time_after_blackouts(schedule_time, &blackout_calendar),
+                None
+            );
+        }
+        // At the start and within the last date of the blackout
+        for schedule_time in [datetime!(2024-12-24 00:00), datetime!(2024-12-26 23:59:59)] {
+            assert_eq!(
+                get_schedule_time_after_blackouts(schedule_time, &blackout_calendar),
+                Some(datetime!(2024-12-27 00:00))
+            );
+        }
+    }
+
+    #[test]
+    fn test_blackout_is_observed_in_its_timezone() {
+        // The blackout is observed from 2024-12-24 18:30 to 2024-12-25 18:30 UTC
+        let blackout_calendar = RevenueRecoveryBlackoutCalendar {
+            blackouts: vec![get_blackout(
+                date!(2024 - 12 - 25),
+                date!(2024 - 12 - 25),
+                "Asia/Kolkata",
+            )],
+        };
+
+        assert_eq!(
+            get_schedule_time_after_blackouts(datetime!(2024-12-24 18:00), &blackout_calendar),
+            None
+        );
+        assert_eq!(
+            get_schedule_time_after_blackouts(datetime!(2024-12-24 19:00), &blackout_calendar),
+            Some(datetime!(2024-12-25 18:30))
+        );
+        assert_eq!(
+            get_schedule_time_after_blackouts(datetime!(2024-12-25 18:30), &blackout_calendar),
+            None
+        );
+    }
+
+    #[test]
+    fn test_blackout_spanning_daylight_saving_transition() {
+        // Clocks move from EST to EDT on 2024-03-10, the blackout starts at 05:00 and ends at
+        // 04:00 UTC
+        let blackout_calendar = RevenueRecoveryBlackoutCalendar {
+            blackouts: vec![get_blackout(
+                date!(2024 - 03 - 09),
+                date!(2024 - 03 - 10),
+                "America/New_York",
+            )],
+        };
+
+        assert_eq!(
+            get_schedule_time_after_blackouts(datetime!(2024-03-09 04:59), &blackout_calendar),
+            None
+        );
+        assert_eq!(
+            get_schedule_time_after_blackouts(datetime!(2024-03-09 05:00), &blackout_calendar),
+            Some(datetime!(2024-03-11 04:00))
+        );
+    }
+
+    #[test]
+    fn test_retry_moved_past_adjoining_blackouts() {
+        // The second blackout, observed in another timezone, starts before the first one ends
+        let blackout_calendar = RevenueRecoveryBlackoutCalendar {
+            blackouts: vec![
+                get_blackout(date!(2024 - 12 - 31), date!(2024 - 12 - 31), "UTC"),
+                get_blackout(
+                    date!(2025 - 01 - 01),
+                    date!(2025 - 01 - 01),
+                    "Europe/Berlin",
+                ),
+            ],
+        };
+
+        assert_eq!(
+            get_schedule_time_after_blackouts(datetime!(2024-12-31 10:00), &blackout_calendar),
+            Some(datetime!(2025-01-01 23:00))
+        );
+    }
+}
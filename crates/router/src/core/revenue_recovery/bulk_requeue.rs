@@ -0,0 +1,382 @@
+//! Requeueing in bulk of the revenue recovery tasks of a merchant left stuck by an incident.
+//!
+//! While the scheduler is down, the tasks picked up by a scheduler worker can be left locked
+//! without ever being executed, and a bad deployment can fail the executions of the tasks until
+//! they are moved to review. Such tasks are moved back to pending in batches, with a schedule time
+//! spread over a short window so that they are not all picked up at once. The retry count of a
+//! task is never changed, and a task which has been requeued is pending and no longer stuck, so
+//! that the same request can be made again without retrying a payment more than once.
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_enums::ProcessTrackerStatus;
+use common_utils::id_type;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::ApplicationResponse;
+use rand::Rng;
+use time::PrimitiveDateTime;
+
+use super::{
+    dead_letter, CALCULATE_WORKFLOW, EXECUTE_WORKFLOW, NOTIFY_WORKFLOW, RECORD_BACK_WORKFLOW,
+};
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    logger,
+    routes::{metrics, SessionState},
+    types::storage::{self, business_status, revenue_recovery as pcr},
+};
+
+const DEFAULT_BULK_REQUEUE_LIMIT: u16 = 500;
+/// Upper bound on the number of tasks examined by a single request
+const MAX_BULK_REQUEUE_LIMIT: u16 = 1000;
+/// Number of tasks fetched from the database at a time
+const BULK_REQUEUE_BATCH_SIZE: u16 = 100;
+/// A task locked by a scheduler worker more recently than this could still be executing
+const MIN_LOCKED_DURATION_IN_SECS: i64 = 15 * 60;
+/// The requeued tasks are scheduled at random within this window from the time of the request
+const REQUEUE_SPREAD_IN_SECS: u32 = 5 * 60;
+
+/// Statuses of the tasks which can be stuck: locked by a scheduler worker, or moved to review
+const STUCK_TASK_STATUSES: [ProcessTrackerStatus; 3] = [
+    ProcessTrackerStatus::Processing,
+    ProcessTrackerStatus::ProcessStarted,
+    ProcessTrackerStatus::Review,
+];
+
+#[derive(Debug, Default)]
+struct BulkRequeueCounts {
+    examined: u16,
+    updated: u16,
+    skipped: u16,
+}
+
+fn get_bulk_requeue_limit(limit: Option<u16>) -> RouterResult<u16> {
+    let limit = limit.unwrap_or(DEFAULT_BULK_REQUEUE_LIMIT);
+    if limit == 0 || limit > MAX_BULK_REQUEUE_LIMIT {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {MAX_BULK_REQUEUE_LIMIT}"),
+        }
+        .into());
+    }
+    Ok(limit)
+}
+
+fn get_stuck_task_statuses(
+    status: Option<ProcessTrackerStatus>,
+) -> RouterResult<Vec<ProcessTrackerStatus>> {
+    match status {
+        None => Ok(STUCK_TASK_STATUSES.to_vec()),
+        Some(status) if STUCK_TASK_STATUSES.contains(&status) => Ok(vec![status]),
+        Some(status) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "status should be one of processing, process_started or review, found {status}"
+            ),
+        }
+        .into()),
+    }
+}
+
+/// Whether the task is stuck, a task locked by a scheduler worker being taken to be stuck only
+/// once it has been locked for long enough to have been executed
+fn is_task_stuck(process: &storage::ProcessTracker, current_time: PrimitiveDateTime) -> bool {
+    match process.status {
+        ProcessTrackerStatus::Processing | ProcessTrackerStatus::ProcessStarted => {
+            process
+                .updated_at
+                .saturating_add(time::Duration::seconds(MIN_LOCKED_DURATION_IN_SECS))
+                <= current_time
+        }
+        ProcessTrackerStatus::Review => dead_letter::is_task_moved_to_review(process),
+        ProcessTrackerStatus::New
+        | ProcessTrackerStatus::Pending
+        | ProcessTrackerStatus::Finish => false,
+    }
+}
+
+/// Moves the task back to pending without consuming a retry of it. The failed executions of a
+/// task in review are reset, as when the task is requeued from review on its own.
+fn get_requeued_task_update(
+    process: &storage::ProcessTracker,
+    current_time: PrimitiveDateTime,
+    requeue_delay: time::Duration,
+) -> storage::ProcessTrackerUpdate {
+    let tracking_data = dead_letter::is_task_moved_to_review(process)
+        .then(|| dead_letter::set_execution_failures(process.tracking_data.clone(), 0, None));
+
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(current_time.saturating_add(requeue_delay)),
+        tracking_data,
+        business_status: Some(String::from(business_status::PENDING)),
+        status: Some(ProcessTrackerStatus::Pending),
+        updated_at: Some(current_time),
+    }
+}
+
+async fn requeue_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    current_time: PrimitiveDateTime,
+) -> bool {
+    let requeue_delay = time::Duration::seconds(i64::from(
+        rand::thread_rng().gen_range(0..=REQUEUE_SPREAD_IN_SECS),
+    ));
+    let task_update = get_requeued_task_update(&process, current_time, requeue_delay);
+    let task_id = process.id.clone();
+    let previous_status = process.status;
+
+    match db.as_scheduler().update_process(process, task_update).await {
+        Ok(_) => {
+            metrics::REVENUE_RECOVERY_TASK_BULK_REQUEUED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("status", previous_status.to_string())),
+            );
+            true
+        }
+        Err(error) => {
+            logger::error!(%task_id, ?error, "Failed to requeue the stuck revenue recovery task");
+            false
+        }
+    }
+}
+
+/// Requeues the stuck tasks of the merchant matching the filters of the request, examining at most
+/// the limit of the request. The tasks are only counted for a dry run.
+pub async fn bulk_requeue_stuck_tasks(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: revenue_recovery_api::RevenueRecoveryTaskBulkRequeueRequest,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryTaskBulkRequeueResponse> {
+    let db = &*state.store;
+    let limit = get_bulk_requeue_limit(request.limit)?;
+    let statuses = get_stuck_task_statuses(request.status)?;
+
+    // The tasks scheduled in the future have not been picked up yet, and cannot be stuck
+    let current_time = common_utils::date_time::now();
+    let schedule_time_end = request
+        .schedule_time_end
+        .map_or(current_time, |schedule_time_end| {
+            schedule_time_end.min(current_time)
+        });
+    let tags = std::iter::once(pcr::PCR_TASK_TAG.to_string())
+        .chain(
+            request
+                .billing_connector
+                .map(|billing_connector| pcr::get_connector_tag(&billing_connector.to_string())),
+        )
+        .collect::<Vec<_>>();
+
+    let mut counts = BulkRequeueCounts::default();
+    let mut has_more = false;
+    'statuses: for status in statuses {
+        let mut starting_after = None;
+        loop {
+            let batch_size = limit
+                .saturating_sub(counts.examined)
+                .min(BULK_REQUEUE_BATCH_SIZE);
+            if batch_size == 0 {
+                has_more = true;
+                break 'statuses;
+            }
+
+            let processes = db
+                .list_processes_by_merchant_task_constraints(
+                    storage::ProcessTrackerMerchantTaskConstraints {
+                        tags: tags.clone(),
+                        runner: storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+                        names: vec![
+                            String::from(CALCULATE_WORKFLOW),
+                            String::from(EXECUTE_WORKFLOW),
+                            String::from(NOTIFY_WORKFLOW),
+                            String::from(RECORD_BACK_WORKFLOW),
+                        ],
+                        merchant_id: merchant_id.clone(),
+                        profile_id: request.profile_id.clone(),
+                        status: Some(status),
+                        business_status: None,
+                        schedule_time_start: request.schedule_time_start,
+                        schedule_time_end: Some(schedule_time_end),
+                        starting_after: starting_after.take(),
+                        limit: i64::from(batch_size),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the revenue recovery tasks to be requeued")?;
+
+            let is_last_batch = processes.len() < usize::from(batch_size);
+            starting_after = processes.last().and_then(|process| {
+                process
+                    .schedule_time
+                    .map(|schedule_time| (schedule_time, process.id.clone()))
+            });
+
+            for process in processes {
+                counts.examined = counts.examined.saturating_add(1);
+                let is_requeued = is_task_stuck(&process, current_time)
+                    && (request.dry_run || requeue_task(db, process, current_time).await);
+                if is_requeued {
+                    counts.updated = counts.updated.saturating_add(1);
+                } else {
+                    counts.skipped = counts.skipped.saturating_add(1);
+                }
+            }
+
+            if is_last_batch || starting_after.is_none() {
+                break;
+            }
+        }
+    }
+
+    logger::info!(
+        merchant_id = ?merchant_id,
+        dry_run = request.dry_run,
+        examined_count = counts.examined,
+        updated_count = counts.updated,
+        skipped_count = counts.skipped,
+        has_more,
+        "Requeued the stuck revenue recovery tasks of the merchant"
+    );
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RevenueRecoveryTaskBulkRequeueResponse {
+            merchant_id,
+            dry_run: request.dry_run,
+            examined_count: usize::from(counts.examined),
+            updated_count: usize::from(counts.updated),
+            skipped_count: usize::from(counts.skipped),
+            has_more,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_task(
+        status: ProcessTrackerStatus,
+        business_status: &str,
+        updated_at: PrimitiveDateTime,
+    ) -> storage::ProcessTracker {
+        storage::ProcessTracker {
+            id: String::from("PASSIVE_RECOVERY_WORKFLOW_EXECUTE_WORKFLOW_12345"),
+            name: Some(String::from(EXECUTE_WORKFLOW)),
+            tag: vec![],
+            runner: None,
+            retry_count: 2,
+            schedule_time: Some(datetime!(2024-01-15 08:00)),
+            rule: String::new(),
+            tracking_data: serde_json::json!({
+                "execution_failure_count": 3,
+                "last_execution_error": "Received StorageError: DatabaseConnectionError",
+            }),
+            business_status: String::from(business_status),
+            status,
+            event: vec![],
+            created_at: datetime!(2024-01-14 10:00),
+            updated_at,
+            version: common_enums::ApiVersion::V2,
+        }
+    }
+
+    #[test]
+    fn test_only_the_stuck_tasks_are_requeued() {
+        let current_time = datetime!(2024-01-15 10:00);
+
+        assert!(is_task_stuck(
+            &get_task(
+                ProcessTrackerStatus::Processing,
+                business_status::PENDING,
+                datetime!(2024-01-15 08:00)
+            ),
+            current_time
+        ));
+        // The task could still be executing
+        assert!(!is_task_stuck(
+            &get_task(
+                ProcessTrackerStatus::ProcessStarted,
+                business_status::PENDING,
+                datetime!(2024-01-15 09:55)
+            ),
+            current_time
+        ));
+        assert!(is_task_stuck(
+            &get_task(
+                ProcessTrackerStatus::Review,
+                business_status::RECOVERY_WORKFLOW_DEAD_LETTERED,
+                datetime!(2024-01-15 08:00)
+            ),
+            current_time
+        ));
+        // The task was moved to review by the retry flow, rather than after failed executions
+        assert!(!is_task_stuck(
+            &get_task(
+                ProcessTrackerStatus::Review,
+                business_status::EXECUTE_WORKFLOW_COMPLETE_FOR_REVIEW,
+                datetime!(2024-01-15 08:00)
+            ),
+            current_time
+        ));
+        // A requeued task is not requeued again
+        assert!(!is_task_stuck(
+            &get_task(
+                ProcessTrackerStatus::Pending,
+                business_status::PENDING,
+                datetime!(2024-01-15 08:00)
+            ),
+            current_time
+        ));
+    }
+
+    #[test]
+    fn test_requeued_task_keeps_its_retry_count() {
+        let current_time = datetime!(2024-01-15 10:00);
+        let task = get_task(
+            ProcessTrackerStatus::Review,
+            business_status::RECOVERY_WORKFLOW_DEAD_LETTERED,
+            datetime!(2024-01-15 08:00),
+        );
+
+        let task_update = get_requeued_task_update(&task, current_time, time::Duration::minutes(2));
+        assert!(matches!(
+            task_update,
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(ref tracking_data),
+                status: Some(ProcessTrackerStatus::Pending),
+                ..
+            } if schedule_time == datetime!(2024-01-15 10:02)
+                && tracking_data["execution_failure_count"] == 0
+        ));
+
+        // The tracking data of a locked task is left as is
+        let task = get_task(
+            ProcessTrackerStatus::Processing,
+            business_status::PENDING,
+            datetime!(2024-01-15 08:00),
+        );
+        assert!(matches!(
+            get_requeued_task_update(&task, current_time, time::Duration::ZERO),
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: None,
+                tracking_data: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_only_the_stuck_statuses_can_be_requeued() {
+        assert_eq!(
+            get_stuck_task_statuses(None).ok(),
+            Some(STUCK_TASK_STATUSES.to_vec())
+        );
+        assert!(get_stuck_task_statuses(Some(ProcessTrackerStatus::Pending)).is_err());
+        assert!(get_bulk_requeue_limit(Some(MAX_BULK_REQUEUE_LIMIT + 1)).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+//! Cancellation of the retries of an invoice which are already being executed.
+//!
+//! Closing the invoice cancels the pending retry of the payment, but a retry which has been picked
+//! up by the workflow executor is no longer pending. The closing path therefore also raises the
+//! cancellation flag of the payment in redis, which the executor checks right before the retry is
+//! made through the payment connector and again before the outcome of the retry is recorded. An
+//! execution which finds the flag raised is aborted, its task is finished without scheduling any
+//! further retries.
+
+use common_utils::id_type;
+use scheduler::errors::ProcessTrackerError;
+
+use crate::{
+    logger,
+    routes::SessionState,
+    types::storage::{self, business_status, revenue_recovery as pcr},
+};
+
+pub const CANCELLATION_FLAG_PREFIX: &str = "RECOVERY_CANCELLATION";
+
+/// The flag only has to outlive the executions which were in flight when the invoice was closed
+const CANCELLATION_FLAG_TTL_IN_SECS: i64 = 24 * 60 * 60;
+
+/// Event type of the decision recorded when an in-flight retry is aborted
+const EXECUTION_ABORTED_EVENT_TYPE: &str = "execution_aborted";
+
+fn get_cancellation_flag_key(payment_id: &id_type::GlobalPaymentId) -> String {
+    format!(
+        "{CANCELLATION_FLAG_PREFIX}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+/// Raises the cancellation flag of the payment. The pending retry of the payment is cancelled
+/// regardless, so a flag which could not be raised is only logged.
+pub(crate) async fn raise_cancellation_flag(
+    state: &SessionState,
+    payment_id: &id_type::GlobalPaymentId,
+) {
+    let key = get_cancellation_flag_key(payment_id);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(&key.as_str().into(), true, CANCELLATION_FLAG_TTL_IN_SECS)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            ?payment_id,
+            "Failed to raise the cancellation flag of the revenue recovery payment"
+        );
+    }
+}
+
+/// Whether the invoice of the payment has been closed since its retry was picked up. The retry is
+/// not held back if the flag cannot be checked, so that an unavailable redis does not stop the
+/// retries.
+pub(crate) async fn is_recovery_cancelled(
+    state: &SessionState,
+    payment_id: &id_type::GlobalPaymentId,
+) -> bool {
+    let key = get_cancellation_flag_key(payment_id);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn.exists::<()>(&key.as_str().into()).await,
+        Err(error) => Err(error),
+    };
+
+    result
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                ?payment_id,
+                "Failed to check the cancellation flag of the revenue recovery payment"
+            )
+        })
+        .unwrap_or(false)
+}
+
+fn get_execution_aborted_decision(
+    process: &storage::ProcessTracker,
+    pcr_data: &pcr::PcrPaymentData,
+    payment_id: &id_type::GlobalPaymentId,
+    retry_count: u16,
+    current_time: time::PrimitiveDateTime,
+) -> pcr::RevenueRecoveryDecision {
+    pcr::RevenueRecoveryDecision {
+        id: common_utils::generate_time_ordered_id("rrd"),
+        payment_id: payment_id.clone(),
+        merchant_id: pcr_data.merchant_account.get_id().clone(),
+        profile_id: pcr_data.profile.get_id().clone(),
+        event_type: EXECUTION_ABORTED_EVENT_TYPE.to_string(),
+        recovery_action: Some("abort_retry".to_string()),
+        attempt_triggered_by: None,
+        retry_count: i16::try_from(retry_count).ok(),
+        retry_threshold: None,
+        is_billing_connector_payment_sync_called: false,
+        webhook_action: None,
+        task_id: Some(process.id.clone()),
+        error: None,
+        created_at: current_time,
+    }
+}
+
+/// Finishes the task of the aborted retry without scheduling any further retries, and records the
+/// cancellation among the decisions of the payment
+pub(crate) async fn abort_cancelled_execution(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    pcr_data: &pcr::PcrPaymentData,
+    payment_id: &id_type::GlobalPaymentId,
+    retry_count: u16,
+) -> Result<(), ProcessTrackerError> {
+    let decision = get_execution_aborted_decision(
+        &process,
+        pcr_data,
+        payment_id,
+        retry_count,
+        common_utils::date_time::now(),
+    );
+    let task_id = process.id.clone();
+
+    state
+        .store
+        .as_scheduler()
+        .finish_process_with_business_status(
+            process,
+            business_status::EXECUTE_WORKFLOW_ABORTED_FOR_CLOSED_INVOICE,
+        )
+        .await?;
+
+    // The task has already been finished, failure in recording the decision does not fail the
+    // execution
+    if let Err(error) = state.store.insert_revenue_recovery_decision(decision).await {
+        logger::error!(?error, "Failed to record the revenue recovery decision");
+    }
+
+    logger::warn!(
+        %task_id,
+        ?payment_id,
+        "Revenue recovery retry aborted, since the invoice was closed during its execution"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_cancellation_flag_key_of_payment() {
+        let cell_id = id_type::CellId::from_string("defid").unwrap();
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+
+        assert_eq!(
+            get_cancellation_flag_key(&payment_id),
+            format!("RECOVERY_CANCELLATION_{}", payment_id.get_string_repr())
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! Concurrency limits on the revenue recovery retries of a merchant, so that the retries of the
+//! invoices which piled up during an outage do not trip the rate limits of the payment connectors
+//! once they are all due at the same time.
+//!
+//! An executing retry holds a slot in the sorted sets of the merchant (and of the payment
+//! connector of the retry, if limited) in redis. The slots expire after the slot TTL, so that the
+//! slots of the retries which never released them do not hold up the limits forever. A retry for
+//! which a slot is not available is not executed, its task is pushed forward by a randomized delay.
+
+use common_utils::{errors::CustomResult, ext_traits::StringExt, id_type};
+use rand::Rng;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::RecoveryExecutionConcurrencyLimit,
+    core::errors,
+    logger,
+    routes::{metrics, SessionState},
+    types::storage,
+};
+
+pub const EXECUTION_SLOTS_PREFIX: &str = "RECOVERY_EXECUTION_SLOTS";
+
+/// Frees the slots in the sorted sets at `KEYS` which have expired, and takes a slot with the id
+/// `ARGV[3]` in all of them if each of them holds fewer slots than its limit in `ARGV[4..]`.
+/// Returns the (1 based) index of the first key whose limit has been reached, and 0 if the slot
+/// was taken.
+const ACQUIRE_SLOT_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local slot_ttl = tonumber(ARGV[2])
+local slot_id = ARGV[3]
+
+for index, key in ipairs(KEYS) do
+    redis.call('ZREMRANGEBYSCORE', key, '-inf', now - slot_ttl)
+    if redis.call('ZCARD', key) >= tonumber(ARGV[3 + index]) then
+        return index
+    end
+end
+
+for _, key in ipairs(KEYS) do
+    redis.call('ZADD', key, now, slot_id)
+    redis.call('EXPIRE', key, slot_ttl)
+end
+
+return 0
+"#;
+
+/// Frees the slot with the id `ARGV[1]` in the sorted sets at `KEYS`
+const RELEASE_SLOT_SCRIPT: &str = r#"
+for _, key in ipairs(KEYS) do
+    redis.call('ZREM', key, ARGV[1])
+end
+
+return 0
+"#;
+
+/// Limit on the concurrent retries, along with the sorted set holding the slots of the retries
+#[derive(Debug, Clone, PartialEq)]
+struct SlotLimit {
+    scope: &'static str,
+    key: String,
+    max_concurrent_executions: u32,
+}
+
+/// Slot held by an executing retry, which is to be released once the retry is made. A retry which
+/// is not limited, or whose slot could not be taken due to redis being unavailable, holds no slot.
+#[must_use]
+#[derive(Debug)]
+pub(crate) struct ExecutionSlot {
+    slot_id: String,
+    keys: Vec<String>,
+}
+
+impl ExecutionSlot {
+    fn unlimited() -> Self {
+        Self {
+            slot_id: String::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Frees the slot, a slot which could not be freed expires after the slot TTL
+    pub(crate) async fn release(self, state: &SessionState) {
+        if self.keys.is_empty() {
+            return;
+        }
+
+        let result = match state.store.get_redis_conn() {
+            Ok(redis_conn) => release_slot(&redis_conn, self.keys, &self.slot_id).await,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            logger::error!(
+                ?error,
+                slot_id = %self.slot_id,
+                "Failed to release the slot of the revenue recovery retry"
+            );
+        }
+    }
+}
+
+/// Takes a slot for the retry of the task, through the payment connector of the retry. If the
+/// concurrency limits of the merchant have been reached, the task is pushed forward by a randomized
+/// delay and `None` is returned. The retries are not throttled if the slots cannot be checked, so
+/// that an unavailable redis does not stop the retries.
+pub(crate) async fn acquire_execution_slot_or_throttle_task(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+    merchant_id: &id_type::MerchantId,
+    payment_merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> CustomResult<Option<ExecutionSlot>, errors::StorageError> {
+    let concurrency_limit = get_concurrency_limit(state, merchant_id).await;
+    let slot_limits = get_slot_limits(
+        &concurrency_limit,
+        merchant_id,
+        payment_merchant_connector_id,
+    );
+    if slot_limits.is_empty() {
+        return Ok(Some(ExecutionSlot::unlimited()));
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for the revenue recovery concurrency limits"
+            );
+            return Ok(Some(ExecutionSlot::unlimited()));
+        }
+    };
+
+    let slot_id = process.id.clone();
+    let slot_ttl_in_secs = state.conf.revenue_recovery.execution_slot_ttl_in_secs;
+    let throttled_by = match acquire_slot(
+        &redis_conn,
+        &slot_limits,
+        &slot_id,
+        slot_ttl_in_secs,
+        common_utils::date_time::now_unix_timestamp(),
+    )
+    .await
+    {
+        Ok(None) => {
+            return Ok(Some(ExecutionSlot {
+                slot_id,
+                keys: slot_limits
+                    .into_iter()
+                    .map(|slot_limit| slot_limit.key)
+                    .collect(),
+            }))
+        }
+        Ok(Some(slot_limit)) => slot_limit,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to check the revenue recovery concurrency limits"
+            );
+            return Ok(Some(ExecutionSlot::unlimited()));
+        }
+    };
+
+    let throttle_delay = get_throttle_delay(
+        state.conf.revenue_recovery.max_throttle_delay_in_secs,
+        rand::thread_rng().gen_range(0.0..=1.0),
+    );
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process.clone(),
+            get_throttled_task_update(common_utils::date_time::now(), throttle_delay),
+        )
+        .await?;
+
+    metrics::REVENUE_RECOVERY_THROTTLED_TASK_COUNT.add(
+        1,
+        router_env::metric_attributes!(("limit", throttled_by.scope)),
+    );
+    logger::info!(
+        task_id = %process.id,
+        limit = throttled_by.scope,
+        max_concurrent_executions = throttled_by.max_concurrent_executions,
+        ?throttle_delay,
+        "Revenue recovery retry throttled by the concurrency limits of the merchant"
+    );
+
+    Ok(None)
+}
+
+/// Limits on the retries of the merchant, the override of the merchant taking precedence over the
+/// limits configured for the deployment
+async fn get_concurrency_limit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RecoveryExecutionConcurrencyLimit {
+    let default_limit = state.conf.revenue_recovery.execution_concurrency_limit;
+
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_recovery_execution_concurrency_limit_key(),
+            Some("null".to_string()),
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the revenue recovery concurrency limit override"
+            )
+        })
+        .ok()
+        .and_then(|config| {
+            StringExt::<Option<RecoveryExecutionConcurrencyLimit>>::parse_struct(
+                &config.config,
+                "RecoveryExecutionConcurrencyLimit",
+            )
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to parse the revenue recovery concurrency limit override"
+                )
+            })
+            .ok()
+            .flatten()
+        })
+        .unwrap_or(default_limit)
+}
+
+fn get_slot_limits(
+    concurrency_limit: &RecoveryExecutionConcurrencyLimit,
+    merchant_id: &id_type::MerchantId,
+    payment_merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> Vec<SlotLimit> {
+    let merchant_key = format!("{EXECUTION_SLOTS_PREFIX}_{}", merchant_id.get_string_repr());
+
+    [
+        SlotLimit {
+            scope: "merchant",
+            key: merchant_key.clone(),
+            max_concurrent_executions: concurrency_limit.max_concurrent_executions,
+        },
+        SlotLimit {
+            scope: "payment_connector",
+            key: format!(
+                "{merchant_key}_{}",
+                payment_merchant_connector_id.get_string_repr()
+            ),
+            max_concurrent_executions: concurrency_limit
+                .max_concurrent_executions_per_payment_connector,
+        },
+    ]
+    .into_iter()
+    .filter(|slot_limit| slot_limit.max_concurrent_executions > 0)
+    .collect()
+}
+
+/// Delay by which a throttled task is pushed forward, at least a second so that the task is not
+/// picked up again right away
+fn get_throttle_delay(max_throttle_delay_in_secs: u32, spread_factor: f64) -> time::Duration {
+    let max_throttle_delay = f64::from(max_throttle_delay_in_secs.max(1));
+    time::Duration::seconds_f64((max_throttle_delay * spread_factor.clamp(0.0, 1.0)).max(1.0))
+}
+
+/// Keeps the task pending without consuming a retry of it
+fn get_throttled_task_update(
+    current_time: PrimitiveDateTime,
+    throttle_delay: time::Duration,
+) -> storage::ProcessTrackerUpdate {
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(current_time.saturating_add(throttle_delay)),
+        tracking_data: None,
+        business_status: Some(String::from(
+            diesel_models::process_tracker::business_status::EXECUTE_WORKFLOW_THROTTLED,
+        )),
+        status: Some(common_enums::ProcessTrackerStatus::Pending),
+        updated_at: Some(current_time),
+    }
+}
+
+/// Takes the slot in all of the sorted sets of the limits, returning the limit which has been
+/// reached if the slot could not be taken
+async fn acquire_slot<'a>(
+    redis_conn: &RedisConnectionPool,
+    slot_limits: &'a [SlotLimit],
+    slot_id: &str,
+    slot_ttl_in_secs: u32,
+    now: i64,
+) -> CustomResult<Option<&'a SlotLimit>, RedisError> {
+    let throttled_by: u64 = redis_conn
+        .evaluate_redis_script(
+            ACQUIRE_SLOT_SCRIPT,
+            slot_limits
+                .iter()
+                .map(|slot_limit| redis_conn.add_prefix(&slot_limit.key))
+                .collect(),
+            [
+                now.to_string(),
+                slot_ttl_in_secs.to_string(),
+                slot_id.to_string(),
+            ]
+            .into_iter()
+            .chain(
+                slot_limits
+                    .iter()
+                    .map(|slot_limit| slot_limit.max_concurrent_executions.to_string()),
+            )
+            .collect::<Vec<_>>(),
+        )
+        .await?;
+
+    Ok(usize::try_from(throttled_by)
+        .ok()
+        .and_then(|index| index.checked_sub(1))
+        .and_then(|index| slot_limits.get(index)))
+}
+
+async fn release_slot(
+    redis_conn: &RedisConnectionPool,
+    keys: Vec<String>,
+    slot_id: &str,
+) -> CustomResult<(), RedisError> {
+    let _: u64 = redis_conn
+        .evaluate_redis_script(
+            RELEASE_SLOT_SCRIPT,
+            keys.iter().map(|key| redis_conn.add_prefix(key)).collect(),
+            vec![slot_id.to_string()],
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use redis_interface::RedisSettings;
+
+    use super::*;
+
+    fn get_merchant_id() -> id_type::MerchantId {
+        id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap()
+    }
+
+    fn get_merchant_connector_id() -> id_type::MerchantConnectorAccountId {
+        id_type::MerchantConnectorAccountId::wrap("mca_1".to_string()).unwrap()
+    }
+
+    async fn acquire(
+        redis_conn: &RedisConnectionPool,
+        slot_limits: &[SlotLimit],
+        slot_id: &str,
+        now: i64,
+    ) -> Option<&'static str> {
+        acquire_slot(redis_conn, slot_limits, slot_id, 60, now)
+            .await
+            .unwrap()
+            .map(|slot_limit| slot_limit.scope)
+    }
+
+    #[test]
+    fn test_only_the_configured_limits_are_enforced() {
+        let get_scopes = |max_concurrent_executions, max_concurrent_executions_per_connector| {
+            get_slot_limits(
+                &RecoveryExecutionConcurrencyLimit {
+                    max_concurrent_executions,
+                    max_concurrent_executions_per_payment_connector:
+                        max_concurrent_executions_per_connector,
+                },
+                &get_merchant_id(),
+                &get_merchant_connector_id(),
+            )
+            .into_iter()
+            .map(|slot_limit| slot_limit.scope)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(get_scopes(10, 0), vec!["merchant"]);
+        assert_eq!(get_scopes(10, 5), vec!["merchant", "payment_connector"]);
+        assert_eq!(get_scopes(0, 5), vec!["payment_connector"]);
+        assert!(get_scopes(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_throttle_delay_stays_within_bounds() {
+        assert_eq!(get_throttle_delay(60, 0.0), time::Duration::seconds(1));
+        assert_eq!(get_throttle_delay(60, 0.5), time::Duration::seconds(30));
+        assert_eq!(get_throttle_delay(60, 1.0), time::Duration::seconds(60));
+        assert_eq!(get_throttle_delay(0, 1.0), time::Duration::seconds(1));
+    }
+
+    #[tokio::test]
+    async fn test_slots_are_limited_until_released_or_expired() {
+        let redis_conn = RedisConnectionPool::new(&RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        let merchant_id = id_type::MerchantId::try_from(std::borrow::Cow::from(
+            common_utils::generate_id_with_default_len("merchant"),
+        ))
+        .unwrap();
+        let slot_limits = get_slot_limits(
+            &RecoveryExecutionConcurrencyLimit {
+                max_concurrent_executions: 2,
+                max_concurrent_executions_per_payment_connector: 1,
+            },
+            &merchant_id,
+            &get_merchant_connector_id(),
+        );
+        let now = common_utils::date_time::now_unix_timestamp();
+
+        assert_eq!(
+            acquire(&redis_conn, &slot_limits, "task_1", now).await,
+            None
+        );
+        // The payment connector already has a retry executing
+        assert_eq!(
+            acquire(&redis_conn, &slot_limits, "task_2", now).await,
+            Some("payment_connector")
+        );
+        // Retries through other payment connectors are only limited by the merchant limit
+        let merchant_slot_limits = slot_limits.get(..1).unwrap();
+        assert_eq!(
+            acquire(&redis_conn, merchant_slot_limits, "task_3", now).await,
+            None
+        );
+        assert_eq!(
+            acquire(&redis_conn, merchant_slot_limits, "task_4", now).await,
+            Some("merchant")
+        );
+
+        // The released slot is available to the next retry
+        release_slot(
+            &redis_conn,
+            slot_limits
+                .iter()
+                .map(|slot_limit| slot_limit.key.clone())
+                .collect(),
+            "task_1",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            acquire(&redis_conn, &slot_limits, "task_2", now).await,
+            None
+        );
+
+        // The slots which were not released expire after the slot TTL
+        assert_eq!(
+            acquire(&redis_conn, &slot_limits, "task_5", now + 61).await,
+            None
+        );
+    }
+}
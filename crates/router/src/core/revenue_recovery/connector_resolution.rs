@@ -0,0 +1,272 @@
+//! Failed payments whose payment connector cannot be resolved.
+//!
+//! The billing connector refers to the payment connector of a failed attempt by the reference id
+//! of the account at the billing connector, which the billing connector account of the merchant
+//! maps to a payment connector account. An attempt whose reference id is not mapped, on a profile
+//! without a default recovery payment connector, has no payment connector to be retried through.
+//! Such an attempt is not recorded. The payment is held in review by a task carrying the failed
+//! attempt instead, which the merchant finds through the task listing api. Requeueing the task
+//! once the mapping has been added records the attempt and schedules the retry of the payment,
+//! the same way as for any other failed payment.
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_utils::{errors::CustomResult, ext_traits::ValueExt, fp_utils, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::ApplicationResponse;
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        webhooks,
+    },
+    db::StorageInterface,
+    logger,
+    routes::{app::ReqState, metrics, SessionState},
+    types::{
+        domain,
+        storage::{self, business_status, revenue_recovery as pcr},
+        transformers::ForeignFrom,
+    },
+};
+
+pub const UNRESOLVED_CONNECTOR_WORKFLOW: &str = "UNRESOLVED_CONNECTOR_WORKFLOW";
+
+/// Id of the process tracker task which holds the payment in review
+pub(crate) fn get_unresolved_connector_task_id(
+    payment_id: &id_type::GlobalPaymentId,
+    runner: storage::ProcessTrackerRunner,
+) -> String {
+    format!(
+        "{runner}_{UNRESOLVED_CONNECTOR_WORKFLOW}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+pub(super) fn is_unresolved_connector_task(process: &storage::ProcessTracker) -> bool {
+    process.name.as_deref() == Some(UNRESOLVED_CONNECTOR_WORKFLOW)
+}
+
+/// Keeps the task in review, where it is not picked up by the scheduler until it is requeued
+fn get_review_task_update(
+    tracking_data: serde_json::Value,
+    current_time: PrimitiveDateTime,
+) -> storage::ProcessTrackerUpdate {
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: None,
+        tracking_data: Some(tracking_data),
+        business_status: Some(String::from(
+            business_status::UNRESOLVED_CONNECTOR_WORKFLOW_PENDING_MAPPING,
+        )),
+        status: Some(common_enums::ProcessTrackerStatus::Review),
+        updated_at: Some(current_time),
+    }
+}
+
+/// Holds the payment in review until its payment connector is mapped. A payment which is already
+/// held in review keeps its task, with the failed attempt replaced by the latest one. Returns the
+/// id of the task.
+pub(crate) async fn hold_payment_for_unresolved_connector(
+    db: &dyn StorageInterface,
+    tracking_data: pcr::PcrUnresolvedConnectorTrackingData,
+    billing_connector: &str,
+) -> CustomResult<String, errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let task_id = get_unresolved_connector_task_id(&tracking_data.global_payment_id, runner);
+    let merchant_id = tracking_data.merchant_id.clone();
+    let connector_account_reference_id = tracking_data.connector_account_reference_id.clone();
+
+    match db.find_process_by_id(&task_id).await? {
+        Some(process) => {
+            let tracking_data = serde_json::to_value(tracking_data)
+                .change_context(errors::StorageError::SerializationFailed)
+                .attach_printable("Failed to serialize the unresolved connector tracking data")?;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    get_review_task_update(tracking_data, common_utils::date_time::now()),
+                )
+                .await?;
+        }
+        None => {
+            let mut process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id.clone(),
+                UNRESOLVED_CONNECTOR_WORKFLOW,
+                runner,
+                pcr::get_pcr_task_tags(&merchant_id, Some(billing_connector)),
+                tracking_data,
+                None,
+                common_utils::date_time::now(),
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Failed to construct the unresolved connector task")?;
+            process_tracker_entry.status = common_enums::ProcessTrackerStatus::Review;
+            process_tracker_entry.business_status =
+                String::from(business_status::UNRESOLVED_CONNECTOR_WORKFLOW_PENDING_MAPPING);
+            db.insert_process(process_tracker_entry).await?;
+        }
+    }
+
+    metrics::REVENUE_RECOVERY_UNRESOLVED_CONNECTOR_COUNT.add(
+        1,
+        router_env::metric_attributes!(("billing_connector", billing_connector.to_owned())),
+    );
+    logger::warn!(
+        %task_id,
+        %connector_account_reference_id,
+        "Payment connector of the recovery payment not resolved, holding the payment in review"
+    );
+
+    Ok(task_id)
+}
+
+/// Resolves the payment connector of a payment held in review, recording the failed attempt of
+/// the payment and scheduling its retry. The task is finished once the retry has been scheduled,
+/// the task scheduled for the retry being returned in its place.
+pub(super) async fn requeue_unresolved_connector_task(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    process: storage::ProcessTracker,
+) -> RouterResponse<revenue_recovery_api::RecoveryTaskResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+
+    let tracking_data = process
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrUnresolvedConnectorTrackingData>(
+            "PcrUnresolvedConnectorTrackingData",
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the tracking data of the unresolved connector task")?;
+    fp_utils::when(
+        tracking_data.merchant_id != *merchant_account.get_id(),
+        || {
+            Err(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Task `{}` not found for the merchant", process.id),
+            })
+        },
+    )?;
+    fp_utils::when(
+        process.status != common_enums::ProcessTrackerStatus::Review,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment connector of the task has been resolved already".to_string(),
+            })
+        },
+    )?;
+
+    let billing_connector_account = db
+        .find_merchant_connector_account_by_id(
+            key_manager_state,
+            &tracking_data.billing_mca_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: tracking_data.billing_mca_id.get_string_repr().to_string(),
+        })?;
+    let profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let connector_account_reference_id = tracking_data.connector_account_reference_id.clone();
+    let scheduled_task_id = webhooks::record_attempt_of_unresolved_connector_payment(
+        &state,
+        &req_state,
+        &merchant_account,
+        &profile,
+        &key_store,
+        &billing_connector_account,
+        tracking_data,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to schedule the retry of the payment held in review")?
+    .ok_or_else(|| errors::ApiErrorResponse::PreconditionFailed {
+        message: format!(
+            "No payment connector is mapped to the account reference id `{connector_account_reference_id}`"
+        ),
+    })?;
+
+    let process = db
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: common_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(
+                    business_status::UNRESOLVED_CONNECTOR_WORKFLOW_RESOLVED,
+                )),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to finish the unresolved connector task")?;
+
+    metrics::REVENUE_RECOVERY_TASK_REQUEUED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", process.name.clone().unwrap_or_default())),
+    );
+    logger::info!(
+        task_id = %process.id,
+        ?scheduled_task_id,
+        "Payment connector of the recovery payment resolved"
+    );
+
+    // The retry is not scheduled while the retry count of the payment is within the retry
+    // threshold of the billing connector, the finished task is returned then
+    let scheduled_task = match scheduled_task_id {
+        Some(scheduled_task_id) => db
+            .find_process_by_id(&scheduled_task_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the task scheduled for the retry")?,
+        None => None,
+    };
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RecoveryTaskResponse::foreign_from((
+            scheduled_task.unwrap_or(process),
+            common_utils::date_time::now(),
+        )),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_unresolved_connector_task_is_kept_in_review() {
+        let task_update = get_review_task_update(
+            serde_json::json!({ "merchant_id": "merchant_1" }),
+            datetime!(2024-01-15 10:00),
+        );
+
+        assert!(matches!(
+            task_update,
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: None,
+                schedule_time: None,
+                business_status: Some(ref business_status),
+                status: Some(common_enums::ProcessTrackerStatus::Review),
+                ..
+            } if business_status == business_status::UNRESOLVED_CONNECTOR_WORKFLOW_PENDING_MAPPING
+        ));
+    }
+}
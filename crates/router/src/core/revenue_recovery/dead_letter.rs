@@ -0,0 +1,518 @@
+//! Accounting of the executions of the revenue recovery tasks which fail with an error.
+//!
+//! Only the errors returned by the execution of a task are counted, a declined retry of the
+//! payment is handled by the retry flow itself and does not fail the execution. A failed
+//! execution is retried after a backoff without consuming a retry of the payment, until the
+//! executions fail too many times in a row. The task is then moved to review, where it is no
+//! longer picked up by the scheduler until it is requeued through the api. The backoff and the
+//! number of retried executions are the workflow retry limits of the recovery runner, configured in
+//! the scheduler settings.
+
+use api_models::{
+    pagination::LegacyListResponse, process_tracker::revenue_recovery as revenue_recovery_api,
+};
+use common_utils::{ext_traits::ValueExt, fp_utils, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::ApplicationResponse;
+use scheduler::errors::ProcessTrackerError;
+use time::PrimitiveDateTime;
+
+use super::connector_resolution;
+use crate::{
+    core::errors::{self, RouterResponse},
+    events::audit_events::{AuditEvent, AuditEventType},
+    logger,
+    routes::{app::ReqState, metrics, SessionState},
+    types::{
+        domain,
+        storage::{self, business_status, revenue_recovery as pcr},
+        transformers::ForeignFrom,
+    },
+};
+
+/// Records the failed execution of the task, rescheduling the task or moving it to review once
+/// the executions have failed the configured number of times in a row
+pub(crate) async fn handle_execution_failure(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    error: ProcessTrackerError,
+) -> Result<(), ProcessTrackerError> {
+    let db = &*state.store;
+
+    // The execution could have completed the task before failing, in which case there is nothing
+    // left to be retried
+    let Some(process) = db
+        .find_process_by_id(&process.id)
+        .await?
+        .filter(|process| !is_task_completed(process))
+    else {
+        logger::warn!(task_id = %process.id, ?error, "Revenue recovery task failed after completion");
+        return Ok(());
+    };
+
+    let tracking_data = process
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")?;
+
+    let execution_failure_count = tracking_data.execution_failure_count.saturating_add(1);
+    let last_execution_error = get_execution_error_message(&error);
+
+    metrics::REVENUE_RECOVERY_TASK_EXECUTION_FAILURE_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", process.name.clone().unwrap_or_default())),
+    );
+
+    let retry_limits = scheduler::utils::get_workflow_retry_limits(
+        state
+            .conf
+            .scheduler
+            .as_ref()
+            .map(|scheduler_settings| &scheduler_settings.workflow_retries),
+        storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+    );
+    let current_time = common_utils::date_time::now();
+    let Some(requeue_time) = scheduler::utils::get_failed_run_requeue_time(
+        &retry_limits,
+        execution_failure_count,
+        current_time,
+    ) else {
+        logger::error!(
+            task_id = %process.id,
+            execution_failure_count,
+            ?error,
+            "Revenue recovery task failed too many times in a row"
+        );
+        return move_task_to_review(
+            state,
+            process,
+            execution_failure_count,
+            last_execution_error,
+        )
+        .await;
+    };
+
+    let task_update = get_failed_execution_task_update(
+        set_execution_failures(
+            process.tracking_data.clone(),
+            execution_failure_count,
+            Some(last_execution_error),
+        ),
+        current_time,
+        requeue_time,
+    );
+    db.as_scheduler()
+        .update_process(process.clone(), task_update)
+        .await?;
+
+    logger::warn!(
+        task_id = %process.id,
+        execution_failure_count,
+        ?error,
+        %requeue_time,
+        "Revenue recovery task failed, the task is retried after the retry backoff"
+    );
+
+    Ok(())
+}
+
+/// Moves the task to review, where it is no longer picked up until it is requeued once the cause
+/// of the failure has been fixed
+pub(crate) async fn move_task_to_review(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    execution_failure_count: u16,
+    reason: String,
+) -> Result<(), ProcessTrackerError> {
+    let tracking_data = process
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")?;
+
+    let task_update = get_review_task_update(
+        set_execution_failures(
+            process.tracking_data.clone(),
+            execution_failure_count,
+            Some(reason.clone()),
+        ),
+        common_utils::date_time::now(),
+    );
+    state
+        .store
+        .as_scheduler()
+        .update_process(process.clone(), task_update)
+        .await?;
+
+    metrics::REVENUE_RECOVERY_TASK_MOVED_TO_REVIEW_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", process.name.clone().unwrap_or_default())),
+    );
+    logger::error!(task_id = %process.id, reason, "Revenue recovery task moved to review");
+
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::RecoveryTaskMovedToReview {
+            task_id: process.id,
+            task_name: process.name,
+            merchant_id: tracking_data.merchant_id,
+            profile_id: tracking_data.profile_id,
+            payment_id: tracking_data.global_payment_id,
+            execution_failure_count,
+            last_execution_error: Some(reason),
+        }))
+        .emit();
+
+    Ok(())
+}
+
+/// Resets the failed executions of the task once an execution of the task succeeds, since only
+/// the failures in a row are counted
+pub(crate) async fn reset_execution_failures(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) {
+    let has_failed_executions = process
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")
+        .is_ok_and(|tracking_data| tracking_data.execution_failure_count > 0);
+    if !has_failed_executions {
+        return;
+    }
+
+    let db = &*state.store;
+    let result = async {
+        // The execution could have updated the task, the latest task is updated so that its
+        // changes are kept
+        let Some(process) = db.find_process_by_id(&process.id).await? else {
+            return Ok(());
+        };
+        let task_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: None,
+            tracking_data: Some(set_execution_failures(
+                process.tracking_data.clone(),
+                0,
+                None,
+            )),
+            business_status: None,
+            status: None,
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        db.as_scheduler()
+            .update_process(process, task_update)
+            .await
+            .map(|_| ())
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            task_id = %process.id,
+            ?error,
+            "Failed to reset the failed executions of the revenue recovery task"
+        );
+    }
+}
+
+fn is_task_completed(process: &storage::ProcessTracker) -> bool {
+    matches!(
+        process.status,
+        common_enums::ProcessTrackerStatus::Finish | common_enums::ProcessTrackerStatus::Review
+    )
+}
+
+pub(super) fn is_task_moved_to_review(process: &storage::ProcessTracker) -> bool {
+    process.status == common_enums::ProcessTrackerStatus::Review
+        && process.business_status == business_status::RECOVERY_WORKFLOW_DEAD_LETTERED
+}
+
+/// A failed execution is retried without consuming a retry of the payment
+fn get_failed_execution_task_update(
+    tracking_data: serde_json::Value,
+    current_time: PrimitiveDateTime,
+    requeue_time: PrimitiveDateTime,
+) -> storage::ProcessTrackerUpdate {
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(requeue_time),
+        tracking_data: Some(tracking_data),
+        business_status: Some(String::from(
+            business_status::RECOVERY_WORKFLOW_EXECUTION_FAILED,
+        )),
+        status: Some(common_enums::ProcessTrackerStatus::Pending),
+        updated_at: Some(current_time),
+    }
+}
+
+fn get_review_task_update(
+    tracking_data: serde_json::Value,
+    current_time: PrimitiveDateTime,
+) -> storage::ProcessTrackerUpdate {
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: None,
+        tracking_data: Some(tracking_data),
+        business_status: Some(String::from(
+            business_status::RECOVERY_WORKFLOW_DEAD_LETTERED,
+        )),
+        status: Some(common_enums::ProcessTrackerStatus::Review),
+        updated_at: Some(current_time),
+    }
+}
+
+/// Sets the failed executions in the tracking data of the task. The tracking data is updated in
+/// place, so that the fields specific to the calculate task are kept.
+pub(super) fn set_execution_failures(
+    mut tracking_data: serde_json::Value,
+    execution_failure_count: u16,
+    last_execution_error: Option<String>,
+) -> serde_json::Value {
+    if let Some(fields) = tracking_data.as_object_mut() {
+        fields.insert(
+            String::from("execution_failure_count"),
+            execution_failure_count.into(),
+        );
+        fields.insert(
+            String::from("last_execution_error"),
+            last_execution_error.into(),
+        );
+    }
+    tracking_data
+}
+
+/// Description of the error without the attachments of its report, which are logged instead
+fn get_execution_error_message(error: &ProcessTrackerError) -> String {
+    match error {
+        ProcessTrackerError::ERecoveryError(report) => {
+            format!("Received RecoveryError: {}", report.current_context())
+        }
+        ProcessTrackerError::EStorageError(report) => {
+            format!("Received StorageError: {}", report.current_context())
+        }
+        ProcessTrackerError::ERedisError(report) => {
+            format!("Received RedisError: {}", report.current_context())
+        }
+        ProcessTrackerError::EParsingError(report) => {
+            format!("Received ParsingError: {}", report.current_context())
+        }
+        ProcessTrackerError::EValidationError(report) => {
+            format!("Received ValidationError: {}", report.current_context())
+        }
+        error => error.to_string(),
+    }
+}
+
+/// Lists the tasks of the merchant which were moved to review after consecutive execution
+/// failures, in the order of their schedule time
+pub async fn list_review_tasks(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    constraints: revenue_recovery_api::RevenueRecoveryReviewTaskListConstraints,
+) -> RouterResponse<LegacyListResponse<revenue_recovery_api::RecoveryTaskResponse>> {
+    let db = &*state.store;
+
+    let limit = super::get_recovery_list_limit(constraints.limit)?;
+    let starting_after =
+        super::get_task_list_starting_after(db, &merchant_id, constraints.starting_after).await?;
+
+    let processes = db
+        .list_processes_by_merchant_task_constraints(
+            storage::ProcessTrackerMerchantTaskConstraints {
+                tags: vec![pcr::PCR_TASK_TAG.to_string()],
+                runner: storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+                names: vec![
+                    String::from(super::CALCULATE_WORKFLOW),
+                    String::from(super::EXECUTE_WORKFLOW),
+                    String::from(super::RECORD_BACK_WORKFLOW),
+                ],
+                merchant_id,
+                profile_id: constraints.profile_id,
+                status: Some(common_enums::ProcessTrackerStatus::Review),
+                business_status: Some(String::from(
+                    business_status::RECOVERY_WORKFLOW_DEAD_LETTERED,
+                )),
+                schedule_time_start: None,
+                schedule_time_end: None,
+                starting_after,
+                limit: i64::from(limit),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery tasks in review")?;
+
+    let current_time = common_utils::date_time::now();
+    let tasks = processes
+        .into_iter()
+        .map(|process| {
+            revenue_recovery_api::RecoveryTaskResponse::foreign_from((process, current_time))
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(super::get_task_list_response(
+        tasks, limit,
+    )))
+}
+
+/// Requeues a task which was moved to review after consecutive execution failures, once the cause
+/// of the failures has been fixed. The task is picked up right away with its failures reset. A
+/// payment held in review for its unresolved payment connector is retried once the payment
+/// connector is mapped.
+pub async fn requeue_review_task(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    task_id: String,
+) -> RouterResponse<revenue_recovery_api::RecoveryTaskResponse> {
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let merchant_id = merchant_account.get_id().clone();
+    let task_not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Task `{task_id}` not found for the merchant"),
+    };
+
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery task")?
+        .filter(|process| process.runner.as_deref() == Some(runner.to_string().as_str()))
+        .ok_or_else(task_not_found_error)?;
+    if connector_resolution::is_unresolved_connector_task(&process) {
+        return Box::pin(connector_resolution::requeue_unresolved_connector_task(
+            state,
+            req_state,
+            merchant_account,
+            key_store,
+            process,
+        ))
+        .await;
+    }
+
+    let tracking_data = process
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the tracking data of the revenue recovery task")?;
+    fp_utils::when(tracking_data.merchant_id != merchant_id, || {
+        Err(task_not_found_error())
+    })?;
+    fp_utils::when(!is_task_moved_to_review(&process), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only the tasks moved to review after failed executions can be requeued"
+                .to_string(),
+        })
+    })?;
+
+    let current_time = common_utils::date_time::now();
+    let task_update = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(current_time),
+        tracking_data: Some(set_execution_failures(
+            process.tracking_data.clone(),
+            0,
+            None,
+        )),
+        business_status: Some(String::from(business_status::PENDING)),
+        status: Some(common_enums::ProcessTrackerStatus::Pending),
+        updated_at: Some(current_time),
+    };
+    let process = db
+        .as_scheduler()
+        .update_process(process, task_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to requeue the revenue recovery task")?;
+
+    metrics::REVENUE_RECOVERY_TASK_REQUEUED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", process.name.clone().unwrap_or_default())),
+    );
+    logger::info!(
+        task_id = %process.id,
+        last_execution_error = ?tracking_data.last_execution_error,
+        "Revenue recovery task requeued from review"
+    );
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RecoveryTaskResponse::foreign_from((process, current_time)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_failed_execution_is_retried_until_moved_to_review() {
+        let tracking_data = serde_json::json!({ "execution_failure_count": 1 });
+
+        let task_update = get_failed_execution_task_update(
+            tracking_data.clone(),
+            datetime!(2024-01-15 10:00),
+            datetime!(2024-01-15 10:05),
+        );
+        assert!(matches!(
+            task_update,
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                business_status: Some(ref business_status),
+                status: Some(common_enums::ProcessTrackerStatus::Pending),
+                ..
+            } if schedule_time == datetime!(2024-01-15 10:05)
+                && business_status == business_status::RECOVERY_WORKFLOW_EXECUTION_FAILED
+        ));
+
+        let task_update = get_review_task_update(tracking_data, datetime!(2024-01-15 10:00));
+        assert!(matches!(
+            task_update,
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: None,
+                schedule_time: None,
+                business_status: Some(ref business_status),
+                status: Some(common_enums::ProcessTrackerStatus::Review),
+                ..
+            } if business_status == business_status::RECOVERY_WORKFLOW_DEAD_LETTERED
+        ));
+    }
+
+    #[test]
+    fn test_execution_failures_keep_the_calculate_tracking_data() {
+        let tracking_data = serde_json::json!({
+            "merchant_id": "merchant_1",
+            "intent_retry_count": 2,
+            "processor_token_tag": "pm_token_hash_1",
+        });
+
+        let tracking_data = set_execution_failures(
+            tracking_data,
+            2,
+            Some(String::from("Received StorageError: ValueNotFound")),
+        );
+        assert_eq!(tracking_data["intent_retry_count"], 2);
+        assert_eq!(tracking_data["processor_token_tag"], "pm_token_hash_1");
+        assert_eq!(tracking_data["execution_failure_count"], 2);
+        assert_eq!(
+            tracking_data["last_execution_error"],
+            "Received StorageError: ValueNotFound"
+        );
+
+        let tracking_data = set_execution_failures(tracking_data, 0, None);
+        assert_eq!(tracking_data["execution_failure_count"], 0);
+        assert!(tracking_data["last_execution_error"].is_null());
+        assert_eq!(tracking_data["intent_retry_count"], 2);
+    }
+}
@@ -0,0 +1,620 @@
+//! Global kill switch of revenue recovery, for halting the retries of all the merchants during an
+//! incident.
+//!
+//! The switch is read from the database every time it is checked, so that it takes effect on the
+//! tasks which are already picked up by the scheduler. A task halted by the switch is not
+//! consumed, it is rescheduled to be picked up again after the re-check interval.
+//!
+//! Once the switch is turned off, the tasks halted by it would all be due at once. A halted task
+//! picked up after the switch is turned off is instead rescheduled within the resume window, at
+//! the point of the window where its schedule time before the halt falls in the pause, with a
+//! small random delay added. The halted tasks are thereby resumed in the order of their original
+//! schedule times, except for the tasks whose schedule times were within the jitter of each other.
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_utils::{errors::CustomResult, ext_traits::StringExt, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::ApplicationResponse;
+use rand::Rng;
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    db::StorageInterface,
+    logger,
+    routes::{metrics, SessionState},
+    types::storage::{self, business_status, revenue_recovery as pcr},
+    workflows::revenue_recovery as revenue_recovery_flow,
+};
+
+const KILL_SWITCH_CONFIG_KEY: &str = "revenue_recovery_kill_switch";
+
+/// Key in the tracking data of a halted task under which its schedule time before the halt is kept
+const KILL_SWITCH_HALT_KEY: &str = "kill_switch_halt";
+
+/// Number of the latest changes to the switch which are kept in its history
+const MAX_KILL_SWITCH_HISTORY: usize = 100;
+
+/// Holds the task back from being executed if the kill switch is active, rescheduling it to be
+/// picked up again after the re-check interval. A task halted by the switch which is picked up
+/// after the switch is turned off is held back until its time in the resume window. Returns whether
+/// the task was held back.
+pub(crate) async fn hold_task_for_kill_switch(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+    business_status: &'static str,
+) -> CustomResult<bool, errors::StorageError> {
+    let db = &*state.store;
+    let kill_switch = get_kill_switch(db).await?;
+    let current_time = common_utils::date_time::now();
+
+    if !kill_switch.is_active() {
+        return resume_halted_task(state, process, &kill_switch, current_time).await;
+    }
+
+    let recheck_interval = time::Duration::seconds(i64::from(
+        state
+            .conf
+            .revenue_recovery
+            .kill_switch_recheck_interval_in_secs,
+    ));
+    let tracking_data = get_halted_tracking_data(
+        &process.tracking_data,
+        is_halted_by_kill_switch(process),
+        process.schedule_time.unwrap_or(current_time),
+    )
+    .change_context(errors::StorageError::SerializationFailed)
+    .attach_printable("Failed to serialize the tracking data of the halted task")?;
+    let task_update = get_halted_task_update(
+        current_time,
+        recheck_interval,
+        tracking_data,
+        business_status,
+    );
+
+    db.as_scheduler()
+        .update_process(process.clone(), task_update)
+        .await?;
+
+    metrics::REVENUE_RECOVERY_KILL_SWITCH_HALTED_TASK_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", process.name.clone().unwrap_or_default())),
+    );
+    logger::warn!(
+        task_id = %process.id,
+        ?recheck_interval,
+        "Revenue recovery task halted by the global kill switch"
+    );
+
+    Ok(true)
+}
+
+/// Reschedules the task halted by the switch to its time in the resume window, if that time is yet
+/// to come. Returns whether the task was rescheduled.
+async fn resume_halted_task(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+    kill_switch: &pcr::RecoveryKillSwitch,
+    current_time: PrimitiveDateTime,
+) -> CustomResult<bool, errors::StorageError> {
+    let Some((paused_at, lifted_at)) = is_halted_by_kill_switch(process)
+        .then(|| kill_switch.get_latest_pause())
+        .flatten()
+    else {
+        return Ok(false);
+    };
+
+    // The tasks halted before their schedule times were kept have no place in the pause, and are
+    // spread out over the whole window by the jitter alone
+    let halted_schedule_time =
+        get_halted_schedule_time(&process.tracking_data).unwrap_or(paused_at);
+    let settings = &state.conf.revenue_recovery;
+    let resumed_schedule_time = get_resumed_schedule_time(
+        halted_schedule_time,
+        paused_at,
+        lifted_at,
+        settings.kill_switch_resume_window_in_secs,
+        settings.kill_switch_resume_max_jitter_in_secs,
+        rand::thread_rng().gen_range(0.0..=1.0),
+    );
+
+    if resumed_schedule_time <= current_time {
+        return Ok(false);
+    }
+
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process.clone(),
+            get_resumed_task_update(current_time, resumed_schedule_time),
+        )
+        .await?;
+
+    logger::info!(
+        task_id = %process.id,
+        ?halted_schedule_time,
+        ?resumed_schedule_time,
+        "Revenue recovery task halted by the global kill switch rescheduled within the resume window"
+    );
+
+    Ok(true)
+}
+
+/// Finishes the execute task halted by the switch, or resumed after it, if the max recovery window
+/// of the payment has ended in the meantime. The retries of the payment are exhausted without
+/// another attempt being made, so the payment is not handed off for manual collection. Returns
+/// whether the task was finished.
+pub(crate) async fn finish_task_if_recovery_window_expired(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+    merchant_id: &id_type::MerchantId,
+    payment_created_at: PrimitiveDateTime,
+) -> CustomResult<bool, errors::StorageError> {
+    let db = &*state.store;
+
+    let is_held_by_kill_switch = is_halted_by_kill_switch(process)
+        || process.business_status == business_status::RECOVERY_WORKFLOW_RESUMED_AFTER_KILL_SWITCH;
+    if !is_held_by_kill_switch {
+        return Ok(false);
+    }
+
+    let Some(max_recovery_window_in_secs) =
+        revenue_recovery_flow::get_max_recovery_window_in_secs(db, merchant_id).await
+    else {
+        return Ok(false);
+    };
+    let recovery_window_end =
+        payment_created_at.saturating_add(time::Duration::seconds(max_recovery_window_in_secs));
+    if common_utils::date_time::now() < recovery_window_end {
+        return Ok(false);
+    }
+
+    db.as_scheduler()
+        .finish_process_with_business_status(
+            process.clone(),
+            business_status::EXECUTE_WORKFLOW_RETRIES_EXHAUSTED,
+        )
+        .await?;
+
+    logger::warn!(
+        task_id = %process.id,
+        ?recovery_window_end,
+        "Max recovery window of the payment ended while its retry was held by the kill switch"
+    );
+
+    Ok(true)
+}
+
+fn is_halted_by_kill_switch(process: &storage::ProcessTracker) -> bool {
+    [
+        business_status::EXECUTE_WORKFLOW_HALTED_BY_KILL_SWITCH,
+        business_status::CALCULATE_WORKFLOW_HALTED_BY_KILL_SWITCH,
+        business_status::NOTIFY_WORKFLOW_HALTED_BY_KILL_SWITCH,
+    ]
+    .contains(&process.business_status.as_str())
+}
+
+/// Tracking data of the halted task, with the schedule time of the task before the halt. The
+/// schedule time kept by the earlier halts of the task is not replaced by the re-check times.
+fn get_halted_tracking_data(
+    tracking_data: &serde_json::Value,
+    is_halted: bool,
+    schedule_time: PrimitiveDateTime,
+) -> Result<serde_json::Value, serde_json::Error> {
+    let mut tracking_data = tracking_data.clone();
+
+    if let Some(tracking_data) = tracking_data.as_object_mut() {
+        if !is_halted || !tracking_data.contains_key(KILL_SWITCH_HALT_KEY) {
+            tracking_data.insert(
+                KILL_SWITCH_HALT_KEY.to_string(),
+                serde_json::to_value(pcr::RecoveryKillSwitchHalt {
+                    halted_schedule_time: schedule_time,
+                })?,
+            );
+        }
+    }
+
+    Ok(tracking_data)
+}
+
+fn get_halted_schedule_time(tracking_data: &serde_json::Value) -> Option<PrimitiveDateTime> {
+    tracking_data
+        .get(KILL_SWITCH_HALT_KEY)
+        .cloned()
+        .and_then(|halt| serde_json::from_value::<pcr::RecoveryKillSwitchHalt>(halt).ok())
+        .map(|halt| halt.halted_schedule_time)
+}
+
+/// Time in the resume window at which the halted task is resumed. The window starts when the switch
+/// is turned off, and the task is placed in it at the same fraction as its schedule time before the
+/// halt is of the pause.
+fn get_resumed_schedule_time(
+    halted_schedule_time: PrimitiveDateTime,
+    paused_at: PrimitiveDateTime,
+    lifted_at: PrimitiveDateTime,
+    resume_window_in_secs: u32,
+    max_jitter_in_secs: u32,
+    spread_factor: f64,
+) -> PrimitiveDateTime {
+    let pause_in_secs = (lifted_at - paused_at).as_seconds_f64();
+    let position = if pause_in_secs > 0.0 {
+        ((halted_schedule_time - paused_at).as_seconds_f64() / pause_in_secs).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let delay_in_secs = f64::from(resume_window_in_secs) * position
+        + f64::from(max_jitter_in_secs) * spread_factor.clamp(0.0, 1.0);
+
+    lifted_at.saturating_add(time::Duration::seconds_f64(delay_in_secs))
+}
+
+/// Keeps the task pending without consuming a retry of it, so that the halted retry is made once
+/// the switch is turned off
+fn get_halted_task_update(
+    current_time: PrimitiveDateTime,
+    recheck_interval: time::Duration,
+    tracking_data: serde_json::Value,
+    business_status: &'static str,
+) -> storage::ProcessTrackerUpdate {
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(current_time.saturating_add(recheck_interval)),
+        tracking_data: Some(tracking_data),
+        business_status: Some(String::from(business_status)),
+        status: Some(common_enums::ProcessTrackerStatus::Pending),
+        updated_at: Some(current_time),
+    }
+}
+
+fn get_resumed_task_update(
+    current_time: PrimitiveDateTime,
+    resumed_schedule_time: PrimitiveDateTime,
+) -> storage::ProcessTrackerUpdate {
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(resumed_schedule_time),
+        tracking_data: None,
+        business_status: Some(String::from(
+            business_status::RECOVERY_WORKFLOW_RESUMED_AFTER_KILL_SWITCH,
+        )),
+        status: Some(common_enums::ProcessTrackerStatus::Pending),
+        updated_at: Some(current_time),
+    }
+}
+
+/// Reads the switch from the database, bypassing the config cache. The switch is inactive if it
+/// has never been changed.
+async fn get_kill_switch(
+    db: &dyn StorageInterface,
+) -> CustomResult<pcr::RecoveryKillSwitch, errors::StorageError> {
+    Ok(find_kill_switch(db).await?.unwrap_or_default())
+}
+
+async fn find_kill_switch(
+    db: &dyn StorageInterface,
+) -> CustomResult<Option<pcr::RecoveryKillSwitch>, errors::StorageError> {
+    match db.find_config_by_key_from_db(KILL_SWITCH_CONFIG_KEY).await {
+        Ok(config) => config
+            .config
+            .parse_struct::<pcr::RecoveryKillSwitch>("RecoveryKillSwitch")
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to parse the revenue recovery kill switch")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Records the change in the history of the switch, which decides the state of the switch from
+/// then on
+async fn set_kill_switch(
+    db: &dyn StorageInterface,
+    change: pcr::RecoveryKillSwitchChange,
+) -> CustomResult<pcr::RecoveryKillSwitch, errors::StorageError> {
+    let stored_kill_switch = find_kill_switch(db).await?;
+    let is_stored = stored_kill_switch.is_some();
+    let mut kill_switch = stored_kill_switch.unwrap_or_default();
+
+    kill_switch.history.push(change);
+    let excess_changes = kill_switch
+        .history
+        .len()
+        .saturating_sub(MAX_KILL_SWITCH_HISTORY);
+    kill_switch.history.drain(..excess_changes);
+
+    let config = serde_json::to_string(&kill_switch)
+        .change_context(errors::StorageError::SerializationFailed)
+        .attach_printable("Failed to serialize the revenue recovery kill switch")?;
+
+    if is_stored {
+        db.update_config_by_key(
+            KILL_SWITCH_CONFIG_KEY,
+            storage::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await?;
+    } else {
+        db.insert_config(storage::ConfigNew {
+            key: KILL_SWITCH_CONFIG_KEY.to_string(),
+            config,
+        })
+        .await?;
+    }
+
+    Ok(kill_switch)
+}
+
+pub async fn retrieve_kill_switch(
+    state: SessionState,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryKillSwitchResponse> {
+    let kill_switch = get_kill_switch(&*state.store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery kill switch")?;
+
+    Ok(ApplicationResponse::Json(get_kill_switch_response(
+        kill_switch,
+    )))
+}
+
+pub async fn update_kill_switch(
+    state: SessionState,
+    request: revenue_recovery_api::RevenueRecoveryKillSwitchRequest,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryKillSwitchResponse> {
+    let reason = request.reason.trim();
+    let actor = request.actor.trim();
+    if reason.is_empty() || actor.is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "reason and actor must not be empty".to_string(),
+        }
+        .into());
+    }
+
+    let change = pcr::RecoveryKillSwitchChange {
+        is_active: request.is_active,
+        reason: reason.to_string(),
+        actor: actor.to_string(),
+        changed_at: common_utils::date_time::now(),
+    };
+    let kill_switch = set_kill_switch(&*state.store, change)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the revenue recovery kill switch")?;
+
+    metrics::REVENUE_RECOVERY_KILL_SWITCH_TOGGLE_COUNT.add(
+        1,
+        router_env::metric_attributes!(("is_active", request.is_active)),
+    );
+    logger::warn!(
+        is_active = request.is_active,
+        reason,
+        actor,
+        "Revenue recovery kill switch changed"
+    );
+
+    Ok(ApplicationResponse::Json(get_kill_switch_response(
+        kill_switch,
+    )))
+}
+
+fn get_kill_switch_response(
+    kill_switch: pcr::RecoveryKillSwitch,
+) -> revenue_recovery_api::RevenueRecoveryKillSwitchResponse {
+    revenue_recovery_api::RevenueRecoveryKillSwitchResponse {
+        is_active: kill_switch.is_active(),
+        history: kill_switch
+            .history
+            .into_iter()
+            .rev()
+            .map(
+                |change| revenue_recovery_api::RevenueRecoveryKillSwitchChange {
+                    is_active: change.is_active,
+                    reason: change.reason,
+                    actor: change.actor,
+                    changed_at: change.changed_at,
+                },
+            )
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::db::MockDb;
+
+    fn get_change(is_active: bool, changed_at: PrimitiveDateTime) -> pcr::RecoveryKillSwitchChange {
+        pcr::RecoveryKillSwitchChange {
+            is_active,
+            reason: String::from("Incident"),
+            actor: String::from("oncall@example.com"),
+            changed_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_is_read_after_every_change() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let kill_switch = get_kill_switch(&db).await.expect("readable kill switch");
+        assert!(!kill_switch.is_active());
+
+        // Turned on while the tasks are being processed, the next check halts them
+        set_kill_switch(&db, get_change(true, datetime!(2024-01-15 10:00)))
+            .await
+            .expect("kill switch turned on");
+        assert!(get_kill_switch(&db)
+            .await
+            .expect("readable kill switch")
+            .is_active());
+
+        set_kill_switch(&db, get_change(false, datetime!(2024-01-15 11:00)))
+            .await
+            .expect("kill switch turned off");
+        let kill_switch = get_kill_switch(&db).await.expect("readable kill switch");
+        assert!(!kill_switch.is_active());
+
+        let response = get_kill_switch_response(kill_switch);
+        assert_eq!(
+            response
+                .history
+                .iter()
+                .map(|change| (change.is_active, change.changed_at))
+                .collect::<Vec<_>>(),
+            vec![
+                (false, datetime!(2024-01-15 11:00)),
+                (true, datetime!(2024-01-15 10:00)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_history_keeps_latest_changes() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let first_change_time = datetime!(2024-01-15 00:00);
+        for minutes in 0..=i64::try_from(MAX_KILL_SWITCH_HISTORY).expect("valid history size") {
+            set_kill_switch(
+                &db,
+                get_change(
+                    minutes % 2 == 0,
+                    first_change_time + time::Duration::minutes(minutes),
+                ),
+            )
+            .await
+            .expect("kill switch changed");
+        }
+
+        let kill_switch = get_kill_switch(&db).await.expect("readable kill switch");
+        assert_eq!(kill_switch.history.len(), MAX_KILL_SWITCH_HISTORY);
+        assert_eq!(
+            kill_switch.history.first().map(|change| change.changed_at),
+            Some(first_change_time + time::Duration::minutes(1))
+        );
+        assert!(kill_switch.is_active());
+    }
+
+    #[test]
+    fn test_halted_task_is_rescheduled_without_consuming_a_retry() {
+        let task_update = get_halted_task_update(
+            datetime!(2024-01-15 10:00),
+            time::Duration::minutes(5),
+            serde_json::json!({}),
+            storage::business_status::EXECUTE_WORKFLOW_HALTED_BY_KILL_SWITCH,
+        );
+
+        assert!(matches!(
+            task_update,
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                business_status: Some(ref business_status),
+                status: Some(common_enums::ProcessTrackerStatus::Pending),
+                ..
+            } if schedule_time == datetime!(2024-01-15 10:05)
+                && business_status == storage::business_status::EXECUTE_WORKFLOW_HALTED_BY_KILL_SWITCH
+        ));
+    }
+
+    #[test]
+    fn test_halted_schedule_time_is_kept_across_rechecks() {
+        let tracking_data = serde_json::json!({ "merchant_id": "merchant_1" });
+
+        let halted_tracking_data =
+            get_halted_tracking_data(&tracking_data, false, datetime!(2024-01-15 10:00))
+                .expect("serializable tracking data");
+        assert_eq!(
+            halted_tracking_data.get("merchant_id"),
+            tracking_data.get("merchant_id")
+        );
+
+        // The re-check time the task was pushed to is not its schedule time before the halt
+        let rechecked_tracking_data =
+            get_halted_tracking_data(&halted_tracking_data, true, datetime!(2024-01-15 10:05))
+                .expect("serializable tracking data");
+        assert_eq!(
+            get_halted_schedule_time(&rechecked_tracking_data),
+            Some(datetime!(2024-01-15 10:00))
+        );
+
+        // Halted afresh by a later pause, after having been resumed
+        let halted_again_tracking_data =
+            get_halted_tracking_data(&rechecked_tracking_data, false, datetime!(2024-01-20 09:00))
+                .expect("serializable tracking data");
+        assert_eq!(
+            get_halted_schedule_time(&halted_again_tracking_data),
+            Some(datetime!(2024-01-20 09:00))
+        );
+    }
+
+    #[test]
+    fn test_latest_pause_is_of_the_last_flip_of_the_switch() {
+        let kill_switch = pcr::RecoveryKillSwitch {
+            history: vec![
+                get_change(true, datetime!(2024-01-10 10:00)),
+                get_change(false, datetime!(2024-01-10 12:00)),
+                get_change(true, datetime!(2024-01-15 10:00)),
+                get_change(true, datetime!(2024-01-16 10:00)),
+                get_change(false, datetime!(2024-01-18 10:00)),
+                get_change(false, datetime!(2024-01-18 11:00)),
+            ],
+        };
+        assert_eq!(
+            kill_switch.get_latest_pause(),
+            Some((datetime!(2024-01-15 10:00), datetime!(2024-01-18 11:00)))
+        );
+
+        let active_kill_switch = pcr::RecoveryKillSwitch {
+            history: vec![get_change(true, datetime!(2024-01-15 10:00))],
+        };
+        assert_eq!(active_kill_switch.get_latest_pause(), None);
+        assert_eq!(pcr::RecoveryKillSwitch::default().get_latest_pause(), None);
+    }
+
+    #[test]
+    fn test_resumed_tasks_are_spread_over_the_window_in_order() {
+        let paused_at = datetime!(2024-01-15 00:00);
+        let lifted_at = datetime!(2024-01-18 00:00);
+        let resume = |halted_schedule_time, spread_factor| {
+            get_resumed_schedule_time(
+                halted_schedule_time,
+                paused_at,
+                lifted_at,
+                21600,
+                60,
+                spread_factor,
+            )
+        };
+
+        assert_eq!(resume(paused_at, 0.0), lifted_at);
+        assert_eq!(
+            resume(datetime!(2024-01-16 12:00), 0.0),
+            datetime!(2024-01-18 03:00)
+        );
+        assert_eq!(resume(lifted_at, 1.0), datetime!(2024-01-18 06:01));
+
+        // Tasks which were overdue before the pause go first, and the window is not overrun
+        assert_eq!(resume(datetime!(2024-01-14 00:00), 0.0), lifted_at);
+        assert_eq!(
+            resume(datetime!(2024-01-19 00:00), 1.0),
+            datetime!(2024-01-18 06:01)
+        );
+
+        // The jitter does not reorder the tasks further apart than it
+        assert!(
+            resume(datetime!(2024-01-15 01:00), 1.0) < resume(datetime!(2024-01-15 02:00), 0.0)
+        );
+    }
+}
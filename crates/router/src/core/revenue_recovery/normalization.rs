@@ -0,0 +1,402 @@
+use api_models::enums as api_enums;
+use common_enums::AttemptStatus;
+use common_utils::types::{ConnectorTransactionId, MinorUnit};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::revenue_recovery;
+
+use crate::core::errors::{self, CustomResult};
+
+/// Normalizes the invoice and transaction details extracted from the billing connectors, so that
+/// the revenue recovery flow does not depend on every billing connector getting them exactly right.
+pub trait RecoveryDataNormalization: Sync {
+    /// Whether the billing connector reports the amounts in the major unit of the currency.
+    fn is_amount_in_major_unit(&self) -> bool {
+        false
+    }
+
+    /// Explicit mapping of the transaction status reported by the billing connector to the attempt
+    /// status consumed by revenue recovery. Statuses which are not a part of the table are rejected.
+    fn get_attempt_status_mapping(&self) -> &'static [(AttemptStatus, AttemptStatus)];
+
+    /// Normalizes a transaction id sent by the billing connector, for the billing connectors which
+    /// do not send the ids of the same transaction in the same form everywhere.
+    fn normalize_connector_transaction_id(&self, connector_transaction_id: &str) -> String {
+        connector_transaction_id.to_string()
+    }
+
+    /// The hashed transaction ids are left as is, since the id they were hashed from is not known.
+    fn get_normalized_connector_transaction_id(
+        &self,
+        connector_transaction_id: &ConnectorTransactionId,
+    ) -> ConnectorTransactionId {
+        match connector_transaction_id {
+            ConnectorTransactionId::TxnId(id) => {
+                ConnectorTransactionId::from(self.normalize_connector_transaction_id(id))
+            }
+            ConnectorTransactionId::HashedData(_) => connector_transaction_id.clone(),
+        }
+    }
+
+    /// Zero amount invoices are not rejected, since they are skipped by the revenue recovery flow.
+    fn normalize_invoice_data(
+        &self,
+        data: revenue_recovery::RevenueRecoveryInvoiceData,
+    ) -> CustomResult<revenue_recovery::RevenueRecoveryInvoiceData, errors::RevenueRecoveryError>
+    {
+        let amount = self.get_amount_in_minor_unit(data.amount, data.currency)?;
+        Ok(revenue_recovery::RevenueRecoveryInvoiceData { amount, ..data })
+    }
+
+    fn normalize_attempt_data(
+        &self,
+        data: revenue_recovery::RevenueRecoveryAttemptData,
+    ) -> CustomResult<revenue_recovery::RevenueRecoveryAttemptData, errors::RevenueRecoveryError>
+    {
+        let amount = self.get_amount_in_minor_unit(data.amount, data.currency)?;
+        if amount.get_amount_as_i64() <= 0 {
+            return Err(report!(
+                errors::RevenueRecoveryError::InvalidBillingConnectorData
+            ))
+            .attach_printable_lazy(|| {
+                format!("Invalid transaction amount {amount:?} received from the billing connector")
+            });
+        }
+
+        let status = self
+            .get_attempt_status_mapping()
+            .iter()
+            .find_map(|(connector_status, status)| {
+                (*connector_status == data.status).then_some(*status)
+            })
+            .ok_or(report!(
+                errors::RevenueRecoveryError::InvalidBillingConnectorData
+            ))
+            .attach_printable_lazy(|| {
+                format!(
+                    "Unsupported transaction status {:?} received from the billing connector",
+                    data.status
+                )
+            })?;
+        let connector_transaction_id = data
+            .connector_transaction_id
+            .as_ref()
+            .map(|id| self.get_normalized_connector_transaction_id(id));
+        Ok(revenue_recovery::RevenueRecoveryAttemptData {
+            amount,
+            status,
+            connector_transaction_id,
+            ..data
+        })
+    }
+
+    /// Converts the amount to the minor unit of the currency.
+    fn get_amount_in_minor_unit(
+        &self,
+        amount: MinorUnit,
+        currency: common_enums::Currency,
+    ) -> CustomResult<MinorUnit, errors::RevenueRecoveryError> {
+        if self.is_amount_in_major_unit() {
+            10_i64
+                .checked_pow(u32::from(currency.number_of_digits_after_decimal_point()))
+                .and_then(|multiplier| amount.get_amount_as_i64().checked_mul(multiplier))
+                .map(MinorUnit::new)
+                .ok_or(report!(
+                    errors::RevenueRecoveryError::InvalidBillingConnectorData
+                ))
+                .attach_printable_lazy(|| {
+                    format!("Amount {amount:?} overflows the minor unit of {currency}")
+                })
+        } else {
+            Ok(amount)
+        }
+    }
+}
+
+struct DefaultNormalization;
+
+impl RecoveryDataNormalization for DefaultNormalization {
+    fn get_attempt_status_mapping(&self) -> &'static [(AttemptStatus, AttemptStatus)] {
+        &[
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Failure, AttemptStatus::Failure),
+            (AttemptStatus::Pending, AttemptStatus::Pending),
+        ]
+    }
+}
+
+/// Prefix of the transaction ids of Chargebee, which is not a part of the ids in some of its
+/// webhooks
+const CHARGEBEE_TRANSACTION_ID_PREFIX: &str = "txn_";
+
+struct ChargebeeNormalization;
+
+impl RecoveryDataNormalization for ChargebeeNormalization {
+    fn normalize_connector_transaction_id(&self, connector_transaction_id: &str) -> String {
+        connector_transaction_id
+            .strip_prefix(CHARGEBEE_TRANSACTION_ID_PREFIX)
+            .unwrap_or(connector_transaction_id)
+            .to_string()
+    }
+
+    fn get_attempt_status_mapping(&self) -> &'static [(AttemptStatus, AttemptStatus)] {
+        &[
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Failure, AttemptStatus::Failure),
+            // In progress transactions and the ones which need attention of the merchant
+            (AttemptStatus::Pending, AttemptStatus::Pending),
+        ]
+    }
+}
+
+struct RecurlyNormalization;
+
+impl RecoveryDataNormalization for RecurlyNormalization {
+    /// The transaction uuids of Recurly are hexadecimal, which some of its webhooks send in upper
+    /// case
+    fn normalize_connector_transaction_id(&self, connector_transaction_id: &str) -> String {
+        connector_transaction_id.to_ascii_lowercase()
+    }
+
+    fn get_attempt_status_mapping(&self) -> &'static [(AttemptStatus, AttemptStatus)] {
+        &[
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Failure, AttemptStatus::Failure),
+        ]
+    }
+}
+
+struct StripebillingNormalization;
+
+impl RecoveryDataNormalization for StripebillingNormalization {
+    fn get_attempt_status_mapping(&self) -> &'static [(AttemptStatus, AttemptStatus)] {
+        &[
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Failure, AttemptStatus::Failure),
+        ]
+    }
+}
+
+/// Returns the normalization to be applied on the data extracted from the billing connector.
+pub fn get_recovery_data_normalization(
+    connector: api_enums::Connector,
+) -> &'static dyn RecoveryDataNormalization {
+    match connector {
+        api_enums::Connector::Chargebee => &ChargebeeNormalization,
+        api_enums::Connector::Recurly => &RecurlyNormalization,
+        api_enums::Connector::Stripebilling => &StripebillingNormalization,
+        _ => &DefaultNormalization,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn get_invoice_data(amount: i64) -> revenue_recovery::RevenueRecoveryInvoiceData {
+        revenue_recovery::RevenueRecoveryInvoiceData {
+            amount: MinorUnit::new(amount),
+            currency: common_enums::Currency::USD,
+            merchant_reference_id: common_utils::id_type::PaymentReferenceId::from_str("invoice_1")
+                .expect("valid merchant reference id"),
+            customer_details: None,
+            billing_connector_subscription_id: None,
+        }
+    }
+
+    fn get_attempt_data(status: AttemptStatus) -> revenue_recovery::RevenueRecoveryAttemptData {
+        revenue_recovery::RevenueRecoveryAttemptData {
+            amount: MinorUnit::new(1000),
+            currency: common_enums::Currency::USD,
+            merchant_reference_id: common_utils::id_type::PaymentReferenceId::from_str("invoice_1")
+                .expect("valid merchant reference id"),
+            connector_transaction_id: None,
+            error_code: None,
+            error_message: None,
+            processor_payment_method_token: "token_1".to_string(),
+            connector_customer_id: "customer_1".to_string(),
+            connector_account_reference_id: "gateway_1".to_string(),
+            transaction_created_at: None,
+            status,
+            payment_method_type: common_enums::PaymentMethod::Card,
+            payment_method_sub_type: common_enums::PaymentMethodType::Credit,
+            network_advice_code: None,
+            network_decline_code: None,
+            network_error_message: None,
+            processor_response_details: None,
+            card_issuing_country: None,
+            card_details: None,
+        }
+    }
+
+    struct MajorUnitNormalization;
+
+    impl RecoveryDataNormalization for MajorUnitNormalization {
+        fn is_amount_in_major_unit(&self) -> bool {
+            true
+        }
+
+        fn get_attempt_status_mapping(&self) -> &'static [(AttemptStatus, AttemptStatus)] {
+            &[]
+        }
+    }
+
+    #[test]
+    fn test_invoice_with_zero_amount_is_not_rejected() {
+        for connector in [
+            api_enums::Connector::Chargebee,
+            api_enums::Connector::Recurly,
+            api_enums::Connector::Stripebilling,
+        ] {
+            let invoice = get_recovery_data_normalization(connector)
+                .normalize_invoice_data(get_invoice_data(0))
+                .expect("zero amount invoice");
+            assert_eq!(invoice.amount, MinorUnit::new(0));
+        }
+    }
+
+    #[test]
+    fn test_attempt_with_zero_or_negative_amount_is_rejected() {
+        let normalization = get_recovery_data_normalization(api_enums::Connector::Chargebee);
+        for amount in [0, -100] {
+            let result = normalization.normalize_attempt_data(
+                revenue_recovery::RevenueRecoveryAttemptData {
+                    amount: MinorUnit::new(amount),
+                    ..get_attempt_data(AttemptStatus::Charged)
+                },
+            );
+            assert!(result.is_err(), "amount {amount} accepted");
+        }
+    }
+
+    #[test]
+    fn test_major_unit_amount_is_converted_to_minor_unit() {
+        let invoice = MajorUnitNormalization
+            .normalize_invoice_data(get_invoice_data(25))
+            .expect("valid invoice");
+        assert_eq!(invoice.amount, MinorUnit::new(2500));
+
+        let invoice = MajorUnitNormalization
+            .normalize_invoice_data(revenue_recovery::RevenueRecoveryInvoiceData {
+                currency: common_enums::Currency::JPY,
+                ..get_invoice_data(25)
+            })
+            .expect("valid invoice");
+        assert_eq!(invoice.amount, MinorUnit::new(25));
+    }
+
+    fn get_attempt_data_with_transaction_id(
+        connector_transaction_id: &str,
+    ) -> revenue_recovery::RevenueRecoveryAttemptData {
+        revenue_recovery::RevenueRecoveryAttemptData {
+            connector_transaction_id: Some(ConnectorTransactionId::from(
+                connector_transaction_id.to_string(),
+            )),
+            ..get_attempt_data(AttemptStatus::Failure)
+        }
+    }
+
+    #[test]
+    fn test_prefixed_and_unprefixed_transaction_ids_are_normalized_alike() {
+        for (connector, prefixed_id, unprefixed_id) in [
+            (
+                api_enums::Connector::Chargebee,
+                "txn_AzZhUGSPAkLskJQo",
+                "AzZhUGSPAkLskJQo",
+            ),
+            (
+                api_enums::Connector::Recurly,
+                "6A1B2C3D4E5F60718293A4B5C6D7E8F9",
+                "6a1b2c3d4e5f60718293a4b5c6d7e8f9",
+            ),
+        ] {
+            let normalization = get_recovery_data_normalization(connector);
+            let normalize = |connector_transaction_id| {
+                normalization
+                    .normalize_attempt_data(get_attempt_data_with_transaction_id(
+                        connector_transaction_id,
+                    ))
+                    .expect("valid attempt")
+                    .connector_transaction_id
+            };
+            assert_eq!(
+                normalize(prefixed_id),
+                Some(ConnectorTransactionId::from(unprefixed_id.to_string())),
+                "{connector} transaction id {prefixed_id} not normalized"
+            );
+            assert_eq!(normalize(prefixed_id), normalize(unprefixed_id));
+        }
+    }
+
+    #[test]
+    fn test_transaction_ids_of_other_connectors_are_not_normalized() {
+        let normalization = get_recovery_data_normalization(api_enums::Connector::Stripebilling);
+        for connector_transaction_id in ["txn_AzZhUGSPAkLskJQo", "ch_3MmlLrLkdIwHu7ix0snN0B15"] {
+            assert_eq!(
+                normalization.normalize_connector_transaction_id(connector_transaction_id),
+                connector_transaction_id
+            );
+        }
+
+        // a hashed transaction id is matched only as is
+        let hashed_id = ConnectorTransactionId::from(format!("txn_{}", "a".repeat(130)));
+        assert_eq!(
+            get_recovery_data_normalization(api_enums::Connector::Chargebee)
+                .get_normalized_connector_transaction_id(&hashed_id),
+            hashed_id
+        );
+    }
+
+    #[test]
+    fn test_chargebee_status_mapping() {
+        let normalization = get_recovery_data_normalization(api_enums::Connector::Chargebee);
+        for (connector_status, expected_status) in [
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Failure, AttemptStatus::Failure),
+            (AttemptStatus::Pending, AttemptStatus::Pending),
+        ] {
+            let attempt = normalization
+                .normalize_attempt_data(get_attempt_data(connector_status))
+                .expect("supported status");
+            assert_eq!(attempt.status, expected_status);
+        }
+        assert!(normalization
+            .normalize_attempt_data(get_attempt_data(AttemptStatus::Voided))
+            .is_err());
+    }
+
+    #[test]
+    fn test_recurly_status_mapping() {
+        let normalization = get_recovery_data_normalization(api_enums::Connector::Recurly);
+        for (connector_status, expected_status) in [
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Failure, AttemptStatus::Failure),
+        ] {
+            let attempt = normalization
+                .normalize_attempt_data(get_attempt_data(connector_status))
+                .expect("supported status");
+            assert_eq!(attempt.status, expected_status);
+        }
+        assert!(normalization
+            .normalize_attempt_data(get_attempt_data(AttemptStatus::Pending))
+            .is_err());
+    }
+
+    #[test]
+    fn test_stripebilling_status_mapping() {
+        let normalization = get_recovery_data_normalization(api_enums::Connector::Stripebilling);
+        for (connector_status, expected_status) in [
+            (AttemptStatus::Charged, AttemptStatus::Charged),
+            (AttemptStatus::Failure, AttemptStatus::Failure),
+        ] {
+            let attempt = normalization
+                .normalize_attempt_data(get_attempt_data(connector_status))
+                .expect("supported status");
+            assert_eq!(attempt.status, expected_status);
+        }
+        assert!(normalization
+            .normalize_attempt_data(get_attempt_data(AttemptStatus::Authorizing))
+            .is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Snapshot of the recovery of the failed payments of a merchant, for monitoring how many payments
+//! are being recovered and how far along they are.
+//!
+//! The snapshot is counted from the unfinished recovery tasks of the merchant, the decisions taken
+//! on the attempts reported by the billing connector webhooks and the execute tasks finished with
+//! the retries of their payments exhausted, each of which is counted over an index of its own. The
+//! counts are made concurrently and are given up after a fixed time, so that the snapshot of a
+//! merchant with a large number of tasks does not hold up the request.
+
+use std::str::FromStr;
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::api::ApplicationResponse;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+
+const OVERVIEW_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Period up to now over which the recorded attempts are counted
+const ATTEMPTS_PERIOD: time::Duration = time::Duration::hours(24);
+
+/// Period up to now over which the payments with their retries exhausted are counted
+const RETRIES_EXHAUSTED_PERIOD: time::Duration = time::Duration::days(7);
+
+/// Number of the attempts triggered internally and externally, from the number of decisions taken
+/// on the attempts by whom they were triggered
+fn get_attempt_counts(decision_counts: Vec<(Option<String>, i64)>) -> (i64, i64) {
+    decision_counts.into_iter().fold(
+        (0, 0),
+        |(internal_count, external_count), (attempt_triggered_by, count)| match attempt_triggered_by
+            .as_deref()
+            .and_then(|triggered_by| common_enums::TriggeredBy::from_str(triggered_by).ok())
+        {
+            Some(common_enums::TriggeredBy::Internal) => (internal_count + count, external_count),
+            Some(common_enums::TriggeredBy::External) => (internal_count, external_count + count),
+            None => (internal_count, external_count),
+        },
+    )
+}
+
+pub async fn retrieve_recovery_overview(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: revenue_recovery_api::RevenueRecoveryOverviewRequest,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryOverviewResponse> {
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let profile_id = request.profile_id.as_ref();
+    let current_time = common_utils::date_time::now();
+    let overdue_threshold = time::Duration::seconds(i64::from(
+        state
+            .conf
+            .revenue_recovery
+            .scheduler_lag
+            .overdue_threshold_in_secs,
+    ));
+
+    let counts = tokio::time::timeout(OVERVIEW_TIMEOUT, async {
+        tokio::try_join!(
+            db.count_unfinished_processes_by_merchant(
+                runner,
+                &merchant_id,
+                profile_id,
+                current_time.saturating_sub(overdue_threshold),
+            ),
+            db.count_revenue_recovery_decisions_by_attempt_triggered_by(
+                &merchant_id,
+                profile_id,
+                current_time.saturating_sub(ATTEMPTS_PERIOD),
+            ),
+            db.count_finished_processes_by_merchant_business_status(
+                runner,
+                &merchant_id,
+                profile_id,
+                business_status::EXECUTE_WORKFLOW_RETRIES_EXHAUSTED,
+                current_time.saturating_sub(RETRIES_EXHAUSTED_PERIOD),
+            ),
+        )
+    })
+    .await;
+
+    let (task_counts, decision_counts, retries_exhausted_count) = match counts {
+        Ok(counts) => counts
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to count the recovery overview of the merchant")?,
+        Err(_) => {
+            logger::warn!(
+                ?merchant_id,
+                ?profile_id,
+                "Counting the recovery overview of the merchant timed out"
+            );
+            return Err(report!(errors::ApiErrorResponse::ResourceBusy));
+        }
+    };
+    let (internal_attempt_count, external_attempt_count) = get_attempt_counts(decision_counts);
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RevenueRecoveryOverviewResponse {
+            merchant_id,
+            profile_id: request.profile_id,
+            payments_in_recovery_count: task_counts.payments_count,
+            pending_task_count: task_counts.pending_count,
+            overdue_task_count: task_counts.overdue_count,
+            review_task_count: task_counts.review_count,
+            internal_attempt_count,
+            external_attempt_count,
+            retries_exhausted_count,
+            computed_at: current_time,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attempt_counts_by_trigger() {
+        let decision_counts = vec![
+            (Some(String::from("internal")), 12),
+            (Some(String::from("external")), 5),
+            // Decisions taken on the invoice events are not of any attempt
+            (None, 3),
+        ];
+
+        assert_eq!(get_attempt_counts(decision_counts), (12, 5));
+        assert_eq!(get_attempt_counts(Vec::new()), (0, 0));
+    }
+}
@@ -0,0 +1,143 @@
+//! Fallback to the secondary credential of the billing connector account, while the merchant rotates
+//! the credentials of the billing connector.
+//!
+//! The secondary credential is kept alongside the primary one in the connector account details of
+//! the billing connector account, under `secondary_auth`, so that it is encrypted like the primary
+//! credential. ex: `{ "auth_type": "HeaderKey", "api_key": "new_key", "secondary_auth": { "auth_type":
+//! "HeaderKey", "api_key": "old_key" } }`
+//!
+//! A payments sync call which is rejected by the billing connector as unauthorized is made once
+//! more using the secondary credential. The failures other than the authentication failures are
+//! not retried using the secondary credential.
+
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::router_data::{ConnectorAuthType, ErrorResponse};
+
+use crate::{logger, routes::metrics};
+
+const UNAUTHORIZED_STATUS_CODE: u16 = 401;
+const FORBIDDEN_STATUS_CODE: u16 = 403;
+
+/// Credential of the billing connector account using which the payments sync call was made
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum BillingConnectorCredential {
+    Primary,
+    Secondary,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SecondaryConnectorAuth {
+    secondary_auth: Option<ConnectorAuthType>,
+}
+
+/// Credentials of the billing connector account
+#[derive(Debug)]
+pub(crate) struct BillingConnectorAuthTypes {
+    pub(crate) primary: ConnectorAuthType,
+    pub(crate) secondary: Option<ConnectorAuthType>,
+}
+
+impl BillingConnectorAuthTypes {
+    pub(crate) fn from_connector_account_details(
+        connector_account_details: serde_json::Value,
+    ) -> CustomResult<Self, common_utils::errors::ParsingError> {
+        let primary = connector_account_details
+            .clone()
+            .parse_value::<ConnectorAuthType>("ConnectorAuthType")?;
+        let secondary = connector_account_details
+            .parse_value::<SecondaryConnectorAuth>("SecondaryConnectorAuth")
+            .attach_printable("Failed to parse the secondary credential of the account")?
+            .secondary_auth;
+
+        Ok(Self { primary, secondary })
+    }
+}
+
+/// Whether the payments sync was rejected by the billing connector for the credential used
+pub(crate) fn is_auth_failure<T>(response: &Result<T, ErrorResponse>) -> bool {
+    matches!(
+        response,
+        Err(error) if matches!(error.status_code, UNAUTHORIZED_STATUS_CODE | FORBIDDEN_STATUS_CODE)
+    )
+}
+
+/// Records the outcome of the payments sync made using the secondary credential, so that the
+/// merchants can be told to finish the rotation of the credentials
+pub(crate) fn record_secondary_credential_payments_sync<T>(
+    connector_name: &str,
+    response: &Result<T, ErrorResponse>,
+) {
+    let is_successful = !is_auth_failure(response);
+    metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_SECONDARY_CREDENTIAL_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            ("connector", connector_name.to_owned()),
+            (
+                "outcome",
+                if is_successful { "succeeded" } else { "failed" }
+            ),
+        ),
+    );
+    if is_successful {
+        logger::warn!(
+            connector_name,
+            credential = %BillingConnectorCredential::Secondary,
+            "Billing connector payments sync succeeded using the secondary credential, the rotation of the credentials is to be finished"
+        );
+    } else {
+        logger::error!(
+            connector_name,
+            "Billing connector payments sync failed using both the primary and the secondary credentials"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_credentials_are_parsed_with_and_without_secondary_credential() {
+        let auth_types =
+            BillingConnectorAuthTypes::from_connector_account_details(serde_json::json!({
+                "auth_type": "HeaderKey",
+                "api_key": "new_key",
+                "secondary_auth": { "auth_type": "HeaderKey", "api_key": "old_key" }
+            }))
+            .unwrap();
+        assert!(matches!(
+            auth_types.primary,
+            ConnectorAuthType::HeaderKey { .. }
+        ));
+        assert!(matches!(
+            auth_types.secondary,
+            Some(ConnectorAuthType::HeaderKey { .. })
+        ));
+
+        let auth_types = BillingConnectorAuthTypes::from_connector_account_details(
+            serde_json::json!({ "auth_type": "HeaderKey", "api_key": "new_key" }),
+        )
+        .unwrap();
+        assert!(auth_types.secondary.is_none());
+    }
+
+    #[test]
+    fn test_only_unauthorized_responses_are_auth_failures() {
+        let get_response = |status_code| {
+            Err::<(), _>(ErrorResponse {
+                status_code,
+                ..Default::default()
+            })
+        };
+
+        assert!(is_auth_failure(&get_response(401)));
+        assert!(is_auth_failure(&get_response(403)));
+        assert!(!is_auth_failure(&get_response(404)));
+        assert!(!is_auth_failure(&get_response(502)));
+        assert!(!is_auth_failure(&Ok::<(), ErrorResponse>(())));
+    }
+}
@@ -0,0 +1,392 @@
+//! Circuit breaker on the payments sync calls made to a billing connector account, so that the
+//! webhooks received during an outage of the billing connector do not each wait out a call which
+//! is bound to fail.
+//!
+//! The consecutive failures of the calls made through the account are counted in redis, which is
+//! shared by all the pods. Once the failures within the failure window reach the threshold, the
+//! circuit is opened and no call is made through the account for the cool down period. After the
+//! cool down, a single call is let through as a probe, which closes the circuit if it succeeds and
+//! opens it again for another cool down if it fails.
+
+use common_utils::{errors::CustomResult, id_type};
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+
+use crate::{
+    configs::settings::BillingConnectorPaymentsSyncCall,
+    logger,
+    routes::{metrics, SessionState},
+};
+
+pub const PAYMENTS_SYNC_CIRCUIT_PREFIX: &str = "RECOVERY_PAYMENTS_SYNC_CIRCUIT";
+
+/// Number of seconds within which the probe of a half open circuit is expected to complete, after
+/// which another call is let through as the probe
+const PROBE_TTL_IN_SECS: u32 = 60;
+
+/// Reads the circuit in the hash at `KEYS[1]` at the time `ARGV[1]`, with the cool down of
+/// `ARGV[2]` seconds. Returns 0 if the circuit is closed, 1 if it is open, and 2 if the circuit is
+/// half open and the call is to be made as its probe, the probe being held for `ARGV[3]` seconds.
+const CHECK_CIRCUIT_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local opened_at = redis.call('HGET', KEYS[1], 'opened_at')
+if not opened_at then
+    return 0
+end
+if now < tonumber(opened_at) + tonumber(ARGV[2]) then
+    return 1
+end
+local probe_until = redis.call('HGET', KEYS[1], 'probe_until')
+if probe_until and now < tonumber(probe_until) then
+    return 1
+end
+redis.call('HSET', KEYS[1], 'probe_until', now + tonumber(ARGV[3]))
+return 2
+"#;
+
+/// Records the outcome of a call in the hash at `KEYS[1]` at the time `ARGV[1]`, `ARGV[2]` being 1
+/// if the call succeeded. A failure opens the circuit once `ARGV[3]` failures are counted within
+/// `ARGV[4]` seconds, the hash expiring after `ARGV[5]` seconds. Returns 1 if the circuit was
+/// opened, 2 if it was closed, 3 if it was opened again after a failed probe, and 0 otherwise.
+const RECORD_OUTCOME_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local opened_at = redis.call('HGET', KEYS[1], 'opened_at')
+if ARGV[2] == '1' then
+    redis.call('DEL', KEYS[1])
+    if opened_at then
+        return 2
+    end
+    return 0
+end
+if opened_at then
+    if not redis.call('HGET', KEYS[1], 'probe_until') then
+        return 0
+    end
+    redis.call('HSET', KEYS[1], 'opened_at', now)
+    redis.call('HDEL', KEYS[1], 'probe_until')
+    redis.call('EXPIRE', KEYS[1], ARGV[5])
+    return 3
+end
+local window_started_at = tonumber(redis.call('HGET', KEYS[1], 'window_started_at') or now)
+local failures = tonumber(redis.call('HGET', KEYS[1], 'failures') or 0)
+if now - window_started_at > tonumber(ARGV[4]) then
+    window_started_at = now
+    failures = 0
+end
+failures = failures + 1
+redis.call('DEL', KEYS[1])
+if failures >= tonumber(ARGV[3]) then
+    redis.call('HSET', KEYS[1], 'opened_at', now)
+    redis.call('EXPIRE', KEYS[1], ARGV[5])
+    return 1
+end
+redis.call('HSET', KEYS[1], 'failures', failures, 'window_started_at', window_started_at)
+redis.call('EXPIRE', KEYS[1], ARGV[5])
+return 0
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum PaymentsSyncCircuitState {
+    Closed,
+    Open,
+    /// The cool down is over, and the call is to be made as the probe of the circuit
+    HalfOpen,
+}
+
+impl PaymentsSyncCircuitState {
+    fn from_check_result(result: u64) -> Self {
+        match result {
+            1 => Self::Open,
+            2 => Self::HalfOpen,
+            _ => Self::Closed,
+        }
+    }
+
+    /// State the circuit moved to on recording the outcome of a call, if it moved at all
+    fn from_record_result(result: u64) -> Option<Self> {
+        match result {
+            1 | 3 => Some(Self::Open),
+            2 => Some(Self::Closed),
+            _ => None,
+        }
+    }
+}
+
+fn get_circuit_key(billing_connector_account_id: &id_type::MerchantConnectorAccountId) -> String {
+    format!(
+        "{PAYMENTS_SYNC_CIRCUIT_PREFIX}_{}",
+        billing_connector_account_id.get_string_repr()
+    )
+}
+
+/// Seconds after which the circuit of an account which has not been called is forgotten
+fn get_circuit_ttl_in_secs(settings: &BillingConnectorPaymentsSyncCall) -> u32 {
+    settings
+        .circuit_breaker_failure_window_in_secs
+        .max(settings.circuit_breaker_cool_down_in_secs)
+        .saturating_add(PROBE_TTL_IN_SECS)
+}
+
+/// State of the circuit of the billing connector account, before a payments sync call is made
+/// through it. The circuit is taken to be closed if the circuit breaker is disabled or redis is
+/// unavailable, so that the calls are not stopped by the circuit breaker itself.
+pub(crate) async fn check_payments_sync_circuit(
+    state: &SessionState,
+    billing_connector_account_id: &id_type::MerchantConnectorAccountId,
+    connector_name: &str,
+) -> PaymentsSyncCircuitState {
+    let settings = &state.conf.billing_connectors_payment_sync;
+    if settings.circuit_breaker_failure_threshold == 0 {
+        return PaymentsSyncCircuitState::Closed;
+    }
+
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            check_circuit(
+                &redis_conn,
+                &get_circuit_key(billing_connector_account_id),
+                settings.circuit_breaker_cool_down_in_secs,
+                common_utils::date_time::now_unix_timestamp(),
+            )
+            .await
+        }
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(PaymentsSyncCircuitState::Open) => {
+            metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_SHORT_CIRCUITED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("connector", connector_name.to_owned())),
+            );
+            PaymentsSyncCircuitState::Open
+        }
+        Ok(circuit_state) => circuit_state,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to check the circuit of the billing connector payments sync"
+            );
+            PaymentsSyncCircuitState::Closed
+        }
+    }
+}
+
+/// Records the outcome of a payments sync call made through the billing connector account, the
+/// failures being the calls which could not reach the billing connector or found it unavailable
+pub(crate) async fn record_payments_sync_outcome(
+    state: &SessionState,
+    billing_connector_account_id: &id_type::MerchantConnectorAccountId,
+    connector_name: &str,
+    is_success: bool,
+) {
+    let settings = &state.conf.billing_connectors_payment_sync;
+    if settings.circuit_breaker_failure_threshold == 0 {
+        return;
+    }
+
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            record_outcome(
+                &redis_conn,
+                &get_circuit_key(billing_connector_account_id),
+                settings,
+                is_success,
+                common_utils::date_time::now_unix_timestamp(),
+            )
+            .await
+        }
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(Some(circuit_state)) => {
+            metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_CIRCUIT_TRANSITION_COUNT.add(
+                1,
+                router_env::metric_attributes!(
+                    ("connector", connector_name.to_owned()),
+                    ("state", circuit_state.to_string()),
+                ),
+            );
+            logger::warn!(
+                billing_connector_account_id = billing_connector_account_id.get_string_repr(),
+                %circuit_state,
+                "Circuit of the billing connector payments sync changed its state"
+            );
+        }
+        Ok(None) => {}
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to record the outcome of the billing connector payments sync"
+            );
+        }
+    }
+}
+
+async fn check_circuit(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    cool_down_in_secs: u32,
+    now: i64,
+) -> CustomResult<PaymentsSyncCircuitState, RedisError> {
+    let result: u64 = redis_conn
+        .evaluate_redis_script(
+            CHECK_CIRCUIT_SCRIPT,
+            vec![redis_conn.add_prefix(key)],
+            vec![
+                now.to_string(),
+                cool_down_in_secs.to_string(),
+                PROBE_TTL_IN_SECS.to_string(),
+            ],
+        )
+        .await?;
+
+    Ok(PaymentsSyncCircuitState::from_check_result(result))
+}
+
+async fn record_outcome(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    settings: &BillingConnectorPaymentsSyncCall,
+    is_success: bool,
+    now: i64,
+) -> CustomResult<Option<PaymentsSyncCircuitState>, RedisError> {
+    let result: u64 = redis_conn
+        .evaluate_redis_script(
+            RECORD_OUTCOME_SCRIPT,
+            vec![redis_conn.add_prefix(key)],
+            vec![
+                now.to_string(),
+                u8::from(is_success).to_string(),
+                settings.circuit_breaker_failure_threshold.to_string(),
+                settings.circuit_breaker_failure_window_in_secs.to_string(),
+                get_circuit_ttl_in_secs(settings).to_string(),
+            ],
+        )
+        .await?;
+
+    Ok(PaymentsSyncCircuitState::from_record_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use std::collections::HashSet;
+
+    use redis_interface::RedisSettings;
+
+    use super::*;
+
+    fn get_settings() -> BillingConnectorPaymentsSyncCall {
+        BillingConnectorPaymentsSyncCall {
+            billing_connectors_which_require_payment_sync: HashSet::new(),
+            cache_ttl_in_secs: 0,
+            max_retries: 0,
+            retry_base_delay_in_millis: 0,
+            circuit_breaker_failure_threshold: 2,
+            circuit_breaker_failure_window_in_secs: 60,
+            circuit_breaker_cool_down_in_secs: 30,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_circuit_opens_after_consecutive_failures_and_closes_on_probe() {
+        let redis_conn = RedisConnectionPool::new(&RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        let settings = get_settings();
+        let key = get_circuit_key(
+            &id_type::MerchantConnectorAccountId::wrap(common_utils::generate_id_with_default_len(
+                "mca",
+            ))
+            .unwrap(),
+        );
+        let now = common_utils::date_time::now_unix_timestamp();
+        let check = |now| check_circuit(&redis_conn, &key, 30, now);
+
+        assert_eq!(
+            record_outcome(&redis_conn, &key, &settings, false, now)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(check(now).await.unwrap(), PaymentsSyncCircuitState::Closed);
+        assert_eq!(
+            record_outcome(&redis_conn, &key, &settings, false, now + 1)
+                .await
+                .unwrap(),
+            Some(PaymentsSyncCircuitState::Open)
+        );
+        assert_eq!(
+            check(now + 2).await.unwrap(),
+            PaymentsSyncCircuitState::Open
+        );
+
+        // A single call is let through once the cool down is over
+        assert_eq!(
+            check(now + 31).await.unwrap(),
+            PaymentsSyncCircuitState::HalfOpen
+        );
+        assert_eq!(
+            check(now + 32).await.unwrap(),
+            PaymentsSyncCircuitState::Open
+        );
+        assert_eq!(
+            record_outcome(&redis_conn, &key, &settings, false, now + 33)
+                .await
+                .unwrap(),
+            Some(PaymentsSyncCircuitState::Open)
+        );
+        assert_eq!(
+            check(now + 40).await.unwrap(),
+            PaymentsSyncCircuitState::Open
+        );
+
+        assert_eq!(
+            check(now + 64).await.unwrap(),
+            PaymentsSyncCircuitState::HalfOpen
+        );
+        assert_eq!(
+            record_outcome(&redis_conn, &key, &settings, true, now + 65)
+                .await
+                .unwrap(),
+            Some(PaymentsSyncCircuitState::Closed)
+        );
+        assert_eq!(
+            check(now + 66).await.unwrap(),
+            PaymentsSyncCircuitState::Closed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failures_outside_the_window_do_not_open_the_circuit() {
+        let redis_conn = RedisConnectionPool::new(&RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        let settings = get_settings();
+        let key = get_circuit_key(
+            &id_type::MerchantConnectorAccountId::wrap(common_utils::generate_id_with_default_len(
+                "mca",
+            ))
+            .unwrap(),
+        );
+        let now = common_utils::date_time::now_unix_timestamp();
+
+        for outcome_at in [now, now + 61, now + 122] {
+            assert_eq!(
+                record_outcome(&redis_conn, &key, &settings, false, outcome_at)
+                    .await
+                    .unwrap(),
+                None
+            );
+        }
+        assert_eq!(
+            check_circuit(&redis_conn, &key, 30, now + 123)
+                .await
+                .unwrap(),
+            PaymentsSyncCircuitState::Closed
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Override of the billing connector payments sync for the revenue recovery webhooks of a merchant,
+//! so that the calls to the billing connector can be turned off for a merchant while its billing
+//! connector is having issues, without changing the configuration of the deployment.
+//!
+//! The override is kept in the configs of the merchant under the key given by
+//! `get_recovery_payments_sync_override_key`, as `true`, `false` or `null` to remove the override.
+//! It is read through the config cache, which is invalidated when the config is updated.
+
+use common_utils::{ext_traits::StringExt, id_type};
+
+use crate::{db::StorageInterface, logger, routes::metrics};
+
+/// Cached in place of the override of the merchants which have not set one, so that they do not
+/// hit the database for every webhook
+const NO_PAYMENTS_SYNC_OVERRIDE_CONFIG: &str = "null";
+
+/// Source of the decision on whether the payments sync is to be called
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum PaymentsSyncDecisionSource {
+    /// The override set for the merchant
+    MerchantOverride,
+    /// The flag of the billing connector account
+    BillingConnectorAccount,
+    /// The billing connectors configured for the deployment
+    DeploymentConfig,
+}
+
+/// Whether the payments sync is to be called for the webhooks of the merchant, as set by its
+/// override. An override which cannot be read is taken as not set.
+pub(crate) async fn get_payments_sync_override(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> Option<bool> {
+    let key = merchant_id.get_recovery_payments_sync_override_key();
+    db.find_config_by_key_unwrap_or(&key, Some(NO_PAYMENTS_SYNC_OVERRIDE_CONFIG.to_string()))
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the revenue recovery payments sync override `{key}`"
+            )
+        })
+        .ok()
+        .and_then(|config| {
+            StringExt::<Option<bool>>::parse_struct(&config.config, "PaymentsSyncOverride")
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse the revenue recovery payments sync override `{key}`"
+                    )
+                })
+                .ok()
+                .flatten()
+        })
+}
+
+/// Decides whether the payments sync is to be called, the override of the merchant taking
+/// precedence over the flag of the billing connector account. Whether the webhooks of the account
+/// carry all the details of their transactions depends on the integration of the merchant, so the
+/// flag of the account takes precedence over the billing connectors configured for the deployment.
+pub(crate) fn decide_payments_sync(
+    merchant_override: Option<bool>,
+    account_requires_payments_sync: Option<bool>,
+    connector_requires_payments_sync: bool,
+) -> (bool, PaymentsSyncDecisionSource) {
+    match (merchant_override, account_requires_payments_sync) {
+        (Some(is_required), _) => (is_required, PaymentsSyncDecisionSource::MerchantOverride),
+        (None, Some(is_required)) => (
+            is_required,
+            PaymentsSyncDecisionSource::BillingConnectorAccount,
+        ),
+        (None, None) => (
+            connector_requires_payments_sync,
+            PaymentsSyncDecisionSource::DeploymentConfig,
+        ),
+    }
+}
+
+pub(crate) fn record_payments_sync_decision(
+    connector_name: &str,
+    is_required: bool,
+    source: PaymentsSyncDecisionSource,
+) {
+    metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_DECISION_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            ("connector", connector_name.to_owned()),
+            ("source", source.to_string()),
+            ("is_required", is_required),
+        ),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merchant_override_takes_precedence() {
+        assert_eq!(
+            decide_payments_sync(Some(false), Some(true), true),
+            (false, PaymentsSyncDecisionSource::MerchantOverride)
+        );
+        assert_eq!(
+            decide_payments_sync(Some(true), None, false),
+            (true, PaymentsSyncDecisionSource::MerchantOverride)
+        );
+        assert_eq!(
+            decide_payments_sync(None, Some(false), true),
+            (false, PaymentsSyncDecisionSource::BillingConnectorAccount)
+        );
+        assert_eq!(
+            decide_payments_sync(None, Some(true), false),
+            (true, PaymentsSyncDecisionSource::BillingConnectorAccount)
+        );
+        assert_eq!(
+            decide_payments_sync(None, None, true),
+            (true, PaymentsSyncDecisionSource::DeploymentConfig)
+        );
+        assert_eq!(
+            decide_payments_sync(None, None, false),
+            (false, PaymentsSyncDecisionSource::DeploymentConfig)
+        );
+    }
+}
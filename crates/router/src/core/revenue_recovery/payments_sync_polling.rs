@@ -0,0 +1,356 @@
+//! Polling of the billing connector for the transactions of the invoices whose webhooks have gone
+//! quiet.
+//!
+//! Some billing connectors do not deliver their webhooks reliably, and an invoice whose transaction
+//! webhooks are lost stays pending on our side. For the billing connector accounts which opt in, a
+//! process tracker task is kept for each invoice being recovered, and is pushed back on every
+//! webhook of the invoice. Once no webhook of the invoice has arrived for the quiet period of the
+//! account, the task fetches the latest transaction of the invoice through the payments sync of the
+//! billing connector, and processes a new transaction as its webhook would have been. The interval
+//! between two polls doubles after every poll which finds no new transaction, and the invoice is no
+//! longer polled after the allowed number of such polls.
+
+use common_utils::id_type;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::router_request_types::revenue_recovery::BillingConnectorPaymentsSyncId;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        webhooks::{self, BillingConnectorPaymentsSyncResponseData},
+    },
+    logger,
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, business_status, revenue_recovery as pcr},
+    },
+};
+
+const PAYMENTS_SYNC_POLLING_NAME: &str = "RECOVERY_PAYMENTS_SYNC_POLLING";
+const PAYMENTS_SYNC_POLLING_TAG: &str = "RECOVERY_PAYMENTS_SYNC_POLLING";
+const PAYMENTS_SYNC_POLLING_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::RevenueRecoveryPaymentsSyncPollingWorkflow;
+
+/// Upper bound on the interval between two polls of an invoice, however many polls found nothing
+const MAX_POLL_INTERVAL: time::Duration = time::Duration::days(7);
+
+fn generate_task_id_for_payments_sync_polling_workflow(
+    payment_id: &id_type::GlobalPaymentId,
+) -> String {
+    format!(
+        "{PAYMENTS_SYNC_POLLING_RUNNER}_{PAYMENTS_SYNC_POLLING_NAME}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+/// The polling configuration of the billing connector account, if the invoices of the account are
+/// to be polled, which needs the billing connector to support the payments sync
+pub(crate) fn get_payments_sync_polling<'a>(
+    state: &SessionState,
+    billing_connector_account: &'a domain::MerchantConnectorAccount,
+) -> Option<&'a domain::RevenueRecoveryPaymentsSyncPolling> {
+    let is_payments_sync_supported = state
+        .conf
+        .billing_connectors_payment_sync
+        .billing_connectors_which_require_payment_sync
+        .contains(&billing_connector_account.connector_name);
+
+    (billing_connector_account.connector_type == common_enums::ConnectorType::BillingProcessor
+        && !billing_connector_account.is_disabled()
+        && is_payments_sync_supported)
+        .then(|| billing_connector_account.get_recovery_payments_sync_polling())
+        .flatten()
+        .filter(|polling| polling.quiet_period_in_hours > 0)
+}
+
+/// Delay until the next poll of the invoice after the given number of consecutive polls which
+/// found no new transaction, or `None` once no further polls are allowed
+fn get_next_poll_delay(
+    polling: &domain::RevenueRecoveryPaymentsSyncPolling,
+    empty_polls_count: u16,
+) -> Option<time::Duration> {
+    (empty_polls_count < polling.max_empty_polls).then(|| {
+        let multiplier = 2_i32
+            .checked_pow(u32::from(empty_polls_count))
+            .unwrap_or(i32::MAX);
+        time::Duration::hours(i64::from(polling.quiet_period_in_hours))
+            .saturating_mul(multiplier)
+            .min(MAX_POLL_INTERVAL)
+    })
+}
+
+fn get_poll_task_update(
+    tracking_data: &pcr::RecoveryPaymentsSyncPollingTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+    current_time: time::PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTrackerUpdate> {
+    Ok(storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: Some(0),
+        schedule_time: Some(schedule_time),
+        tracking_data: Some(
+            serde_json::to_value(tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to serialize the tracking data of the payments sync polling task",
+                )?,
+        ),
+        business_status: Some(String::from(business_status::PENDING)),
+        status: Some(common_enums::ProcessTrackerStatus::New),
+        updated_at: Some(current_time),
+    })
+}
+
+/// Schedules the polling of the invoice for after the quiet period of the billing connector
+/// account, if the polling has been enabled for the account. The polling task of the invoice, if
+/// any, is pushed back and its count of empty polls is reset.
+#[instrument(skip_all)]
+pub(crate) async fn schedule_payments_sync_polling_task(
+    state: &SessionState,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+    payment_id: &id_type::GlobalPaymentId,
+    merchant_reference_id: &id_type::PaymentReferenceId,
+) -> RouterResult<()> {
+    let Some(polling) = get_payments_sync_polling(state, billing_connector_account) else {
+        return Ok(());
+    };
+
+    let db = state.store.as_ref();
+    let process_tracker_id = generate_task_id_for_payments_sync_polling_workflow(payment_id);
+    let current_time = common_utils::date_time::now();
+    let schedule_time =
+        current_time + time::Duration::hours(i64::from(polling.quiet_period_in_hours));
+    let tracking_data = pcr::RecoveryPaymentsSyncPollingTrackingData {
+        merchant_id: billing_connector_account.merchant_id.clone(),
+        profile_id: billing_connector_account.profile_id.clone(),
+        billing_connector_id: billing_connector_account.get_id(),
+        global_payment_id: payment_id.clone(),
+        merchant_reference_id: merchant_reference_id.clone(),
+        empty_polls_count: 0,
+    };
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments sync polling task")?;
+
+    match existing_process {
+        Some(process) => {
+            db.update_process(
+                process,
+                get_poll_task_update(&tracking_data, schedule_time, current_time)?,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the payments sync polling task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                PAYMENTS_SYNC_POLLING_NAME,
+                PAYMENTS_SYNC_POLLING_RUNNER,
+                [PAYMENTS_SYNC_POLLING_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the payments sync polling task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the payments sync polling task")?;
+        }
+    }
+
+    logger::debug!(
+        ?schedule_time,
+        "Scheduled the recovery payments sync polling task"
+    );
+
+    Ok(())
+}
+
+/// Polls the billing connector for the latest transaction of the invoice, and processes it if it
+/// is new. The polls which fail are counted along with the polls which find nothing new. The task
+/// is rescheduled for the next poll, or completed once the recovery of the invoice is over or no
+/// further polls are allowed.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn perform_payments_sync_poll(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: pcr::RecoveryPaymentsSyncPollingTrackingData,
+    polling: &domain::RevenueRecoveryPaymentsSyncPolling,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile: &domain::Profile,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            &state.into(),
+            &tracking_data.global_payment_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment intent of the polled invoice")?;
+
+    if webhooks::is_recovery_closed(payment_intent.status) {
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                process,
+                business_status::PAYMENTS_SYNC_POLLING_WORKFLOW_COMPLETE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to complete the payments sync polling task")?;
+        return Ok(());
+    }
+
+    // The poll stands in for the webhook of the transaction, and is correlated the same way
+    let recovery_correlation_id = common_utils::generate_time_ordered_id("rrd");
+    logger::info!(%recovery_correlation_id, "Polling the payments sync of the invoice");
+    let poll_result = async {
+        let billing_connector_payment_details =
+            BillingConnectorPaymentsSyncResponseData::handle_billing_connector_payment_sync_call(
+                state,
+                merchant_account,
+                billing_connector_account,
+                &billing_connector_account.connector_name.to_string(),
+                &BillingConnectorPaymentsSyncId::InvoiceId(
+                    tracking_data
+                        .merchant_reference_id
+                        .get_string_repr()
+                        .to_owned(),
+                ),
+                common_utils::date_time::now(),
+                None,
+                webhooks::PaymentsSyncCacheMode::Refresh,
+                Some(&recovery_correlation_id),
+            )
+            .await?
+            .inner();
+
+        webhooks::process_polled_recovery_transaction(
+            state,
+            &state.get_req_state(),
+            merchant_account,
+            profile,
+            key_store,
+            billing_connector_account,
+            payment_intent,
+            &billing_connector_payment_details,
+            recovery_correlation_id,
+        )
+        .await
+    }
+    .await;
+
+    let (empty_polls_count, retry_after) = match poll_result {
+        Ok(Some(response)) => {
+            logger::info!(?response, "Processed the polled transaction of the invoice");
+            (0, None)
+        }
+        Ok(None) => (tracking_data.empty_polls_count.saturating_add(1), None),
+        // A poll rate limited by the billing connector is made again once the rate limit allows,
+        // without being counted as a poll which found nothing
+        Err(error) => match error.current_context() {
+            errors::RevenueRecoveryError::BillingConnectorPaymentsSyncRateLimited {
+                retry_after_in_secs,
+            } => (
+                tracking_data.empty_polls_count,
+                Some(time::Duration::seconds(
+                    i64::try_from(*retry_after_in_secs).unwrap_or(i64::MAX),
+                )),
+            ),
+            _ => {
+                logger::error!(?error, "Failed to poll the transactions of the invoice");
+                (tracking_data.empty_polls_count.saturating_add(1), None)
+            }
+        },
+    };
+
+    let Some(poll_delay) = retry_after.or_else(|| get_next_poll_delay(polling, empty_polls_count))
+    else {
+        logger::info!(
+            empty_polls_count,
+            "No new transaction of the invoice found by the allowed polls, stopping the polling"
+        );
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                process,
+                business_status::PAYMENTS_SYNC_POLLING_WORKFLOW_EMPTY_POLLS_EXHAUSTED,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to complete the payments sync polling task")?;
+        return Ok(());
+    };
+
+    let current_time = common_utils::date_time::now();
+    let updated_tracking_data = pcr::RecoveryPaymentsSyncPollingTrackingData {
+        empty_polls_count,
+        ..tracking_data
+    };
+    db.update_process(
+        process,
+        get_poll_task_update(
+            &updated_tracking_data,
+            current_time + poll_delay,
+            current_time,
+        )?,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to reschedule the payments sync polling task")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_interval_backs_off_until_polls_are_exhausted() {
+        let polling = domain::RevenueRecoveryPaymentsSyncPolling {
+            quiet_period_in_hours: 6,
+            max_empty_polls: 3,
+        };
+
+        assert_eq!(
+            get_next_poll_delay(&polling, 0),
+            Some(time::Duration::hours(6))
+        );
+        assert_eq!(
+            get_next_poll_delay(&polling, 1),
+            Some(time::Duration::hours(12))
+        );
+        assert_eq!(
+            get_next_poll_delay(&polling, 2),
+            Some(time::Duration::hours(24))
+        );
+        assert_eq!(get_next_poll_delay(&polling, 3), None);
+    }
+
+    #[test]
+    fn test_poll_interval_is_capped() {
+        let polling = domain::RevenueRecoveryPaymentsSyncPolling {
+            quiet_period_in_hours: 24,
+            max_empty_polls: u16::MAX,
+        };
+
+        assert_eq!(get_next_poll_delay(&polling, 5), Some(MAX_POLL_INTERVAL));
+        assert_eq!(get_next_poll_delay(&polling, 40), Some(MAX_POLL_INTERVAL));
+    }
+}
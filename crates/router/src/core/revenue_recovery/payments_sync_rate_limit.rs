@@ -0,0 +1,216 @@
+//! Handling of the rate limits of the billing connectors on their payments sync APIs, which are
+//! hit during the large dunning runs of the billing connectors.
+//!
+//! A payments sync call which is rate limited by the billing connector is not retried within the
+//! request. The `Retry-After` of the response is captured, so that the webhook can ask the billing
+//! connector to redeliver it after that time, and the polling of the invoice can be pushed back.
+
+use std::sync::{Arc, Mutex};
+
+use common_utils::{errors::CustomResult, request::Request};
+use hyperswitch_domain_models::{
+    configs::Connectors,
+    router_data::{ErrorResponse, RouterData},
+    router_data_v2::flow_common_types::BillingConnectorPaymentsSyncFlowData,
+    router_flow_types::BillingConnectorPaymentsSync,
+    router_request_types::revenue_recovery::BillingConnectorPaymentsSyncRequest,
+    router_response_types::revenue_recovery::BillingConnectorPaymentsSyncResponse,
+};
+use hyperswitch_interfaces::{
+    api::CaptureSyncMethod, connector_integration_interface::ConnectorIntegrationInterface,
+    errors::ConnectorError, events::connector_api_logs::ConnectorEvent, types::Response,
+};
+
+use crate::{logger, routes::metrics, services};
+
+const TOO_MANY_REQUESTS_STATUS_CODE: u16 = 429;
+
+/// Seconds after which a rate limited payments sync is made again, if the billing connector did
+/// not say when in the `Retry-After` of its response
+const DEFAULT_RETRY_AFTER_IN_SECS: u64 = 60;
+
+type PaymentsSyncIntegration = services::BoxedBillingConnectorPaymentsSyncIntegrationInterface<
+    BillingConnectorPaymentsSync,
+    BillingConnectorPaymentsSyncRequest,
+    BillingConnectorPaymentsSyncResponse,
+>;
+
+/// `Retry-After` of the rate limited response of the billing connector, shared by the integrations
+/// through which the payments sync is made
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CapturedRetryAfter(Arc<Mutex<Option<u64>>>);
+
+impl CapturedRetryAfter {
+    /// Seconds after which the payments sync can be made again
+    pub(crate) fn get_retry_after_in_secs(&self) -> u64 {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|retry_after_in_secs| *retry_after_in_secs)
+            .unwrap_or(DEFAULT_RETRY_AFTER_IN_SECS)
+    }
+
+    fn capture(&self, response: &Response) {
+        if response.status_code != TOO_MANY_REQUESTS_STATUS_CODE {
+            return;
+        }
+
+        let retry_after_in_secs = response
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(reqwest::header::RETRY_AFTER))
+            .and_then(|retry_after| retry_after.to_str().ok())
+            .and_then(|retry_after| {
+                parse_retry_after(retry_after, common_utils::date_time::now_unix_timestamp())
+            });
+        match self.0.lock() {
+            Ok(mut captured) => *captured = retry_after_in_secs,
+            Err(error) => logger::error!(?error, "Failed to capture the Retry-After"),
+        }
+    }
+}
+
+/// Connector integration of the payments sync which captures the `Retry-After` of the rate limited
+/// responses, the headers of the error responses being dropped once the error is built
+pub(crate) struct RetryAfterCapturingIntegration {
+    integration: PaymentsSyncIntegration,
+    captured_retry_after: CapturedRetryAfter,
+}
+
+impl RetryAfterCapturingIntegration {
+    pub(crate) fn new(
+        integration: PaymentsSyncIntegration,
+        captured_retry_after: &CapturedRetryAfter,
+    ) -> Self {
+        Self {
+            integration,
+            captured_retry_after: captured_retry_after.clone(),
+        }
+    }
+}
+
+impl
+    ConnectorIntegrationInterface<
+        BillingConnectorPaymentsSync,
+        BillingConnectorPaymentsSyncFlowData,
+        BillingConnectorPaymentsSyncRequest,
+        BillingConnectorPaymentsSyncResponse,
+    > for RetryAfterCapturingIntegration
+{
+    fn clone_box(&self) -> PaymentsSyncIntegration {
+        Box::new(Self {
+            integration: self.integration.clone_box(),
+            captured_retry_after: self.captured_retry_after.clone(),
+        })
+    }
+
+    fn get_multiple_capture_sync_method(&self) -> CustomResult<CaptureSyncMethod, ConnectorError> {
+        self.integration.get_multiple_capture_sync_method()
+    }
+
+    fn build_request(
+        &self,
+        req: &RouterData<
+            BillingConnectorPaymentsSync,
+            BillingConnectorPaymentsSyncRequest,
+            BillingConnectorPaymentsSyncResponse,
+        >,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, ConnectorError> {
+        self.integration.build_request(req, connectors)
+    }
+
+    fn handle_response(
+        &self,
+        data: &RouterData<
+            BillingConnectorPaymentsSync,
+            BillingConnectorPaymentsSyncRequest,
+            BillingConnectorPaymentsSyncResponse,
+        >,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<
+        RouterData<
+            BillingConnectorPaymentsSync,
+            BillingConnectorPaymentsSyncRequest,
+            BillingConnectorPaymentsSyncResponse,
+        >,
+        ConnectorError,
+    > {
+        self.integration.handle_response(data, event_builder, res)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, ConnectorError> {
+        self.captured_retry_after.capture(&res);
+        self.integration.get_error_response(res, event_builder)
+    }
+
+    fn get_5xx_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, ConnectorError> {
+        self.integration.get_5xx_error_response(res, event_builder)
+    }
+}
+
+/// Seconds to wait as per the `Retry-After`, given either as the seconds to wait or as the time
+/// after which the request can be made
+fn parse_retry_after(retry_after: &str, now: i64) -> Option<u64> {
+    let retry_after = retry_after.trim();
+    if let Ok(retry_after_in_secs) = retry_after.parse::<u64>() {
+        return Some(retry_after_in_secs);
+    }
+
+    // The HTTP dates are always in GMT, which is not accepted as the offset of an RFC 2822 date
+    let retry_at = time::OffsetDateTime::parse(
+        &retry_after.replace(" GMT", " +0000"),
+        &time::format_description::well_known::Rfc2822,
+    )
+    .ok()?;
+    Some(u64::try_from(retry_at.unix_timestamp().saturating_sub(now)).unwrap_or_default())
+}
+
+/// Whether the payments sync was rate limited by the billing connector
+pub(crate) fn is_rate_limited<T>(response: &Result<T, ErrorResponse>) -> bool {
+    matches!(response, Err(error) if error.status_code == TOO_MANY_REQUESTS_STATUS_CODE)
+}
+
+pub(crate) fn record_rate_limited_payments_sync(connector_name: &str, retry_after_in_secs: u64) {
+    metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_RATE_LIMITED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("connector", connector_name.to_owned())),
+    );
+    logger::warn!(
+        connector_name,
+        retry_after_in_secs,
+        "Billing connector payments sync rate limited by the billing connector"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_after_is_parsed_from_seconds_and_dates() {
+        // Wed, 21 Oct 2015 07:27:00 GMT
+        let now = 1_445_412_420;
+
+        assert_eq!(parse_retry_after("120", now), Some(120));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+            Some(60)
+        );
+        // A time which has passed can be retried right away
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:20:00 GMT", now),
+            Some(0)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+}
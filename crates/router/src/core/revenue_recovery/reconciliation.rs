@@ -0,0 +1,892 @@
+//! Nightly reconciliation of the open invoices of a billing connector account against the billing
+//! connector.
+//!
+//! A lost webhook of the billing connector leaves the recovery of an invoice running after the
+//! invoice has been paid or voided at the billing connector. For the billing connector accounts
+//! which opt in, a process tracker task fetches the status of their open invoices every night and
+//! stops the recovery of the invoices found paid or voided, as the lost webhooks would have. The
+//! invoices are checked in batches, one batch per execution of the task, and the report of the run
+//! is stored once all of them have been checked.
+
+use std::collections::HashMap;
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_enums::RecoveryReconciliationDriftKind;
+use common_utils::{errors::CustomResult, ext_traits::StringExt, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    api::ApplicationResponse, payments::PaymentIntent,
+    router_request_types::revenue_recovery::BillingConnectorPaymentsSyncId,
+};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks::{self, BillingConnectorPaymentsSyncResponseData},
+    },
+    db::StorageInterface,
+    logger,
+    routes::{metrics, SessionState},
+    types::{
+        domain,
+        storage::{self, business_status, revenue_recovery as pcr},
+    },
+    utils::timezone,
+};
+
+const RECONCILIATION_NAME: &str = "RECOVERY_RECONCILIATION";
+const RECONCILIATION_TAG: &str = "RECOVERY_RECONCILIATION";
+const RECONCILIATION_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::RevenueRecoveryReconciliationWorkflow;
+
+/// Number of the latest runs whose reports are kept for a billing connector account
+const MAX_RECONCILIATION_REPORTS: usize = 30;
+
+/// Number of drifted invoices listed in the report of a run, the drifts beyond it are counted
+/// but not listed
+const MAX_DRIFTS_PER_REPORT: usize = 100;
+
+/// An open invoice checked by the reconciliation
+#[derive(Debug, Clone)]
+pub(crate) struct ReconciliationCandidate {
+    pub payment_id: id_type::GlobalPaymentId,
+    pub merchant_reference_id: Option<id_type::PaymentReferenceId>,
+    pub status: common_enums::IntentStatus,
+}
+
+impl From<&PaymentIntent> for ReconciliationCandidate {
+    fn from(payment_intent: &PaymentIntent) -> Self {
+        Self {
+            payment_id: payment_intent.id.clone(),
+            merchant_reference_id: payment_intent.merchant_reference_id.clone(),
+            status: payment_intent.status,
+        }
+    }
+}
+
+/// Fetches the status of an invoice at the billing connector
+#[async_trait::async_trait]
+pub(crate) trait InvoiceStatusFetcher: Send + Sync {
+    async fn fetch_invoice_status(
+        &self,
+        merchant_reference_id: &id_type::PaymentReferenceId,
+    ) -> CustomResult<common_enums::AttemptStatus, errors::RevenueRecoveryError>;
+}
+
+/// Fetches the status of the invoice through the payments sync of the billing connector, the
+/// status of the latest transaction of the invoice being the status of the invoice
+struct BillingConnectorInvoiceStatusFetcher<'a> {
+    state: &'a SessionState,
+    merchant_account: &'a domain::MerchantAccount,
+    billing_connector_account: &'a domain::MerchantConnectorAccount,
+}
+
+#[async_trait::async_trait]
+impl InvoiceStatusFetcher for BillingConnectorInvoiceStatusFetcher<'_> {
+    async fn fetch_invoice_status(
+        &self,
+        merchant_reference_id: &id_type::PaymentReferenceId,
+    ) -> CustomResult<common_enums::AttemptStatus, errors::RevenueRecoveryError> {
+        let response =
+            BillingConnectorPaymentsSyncResponseData::handle_billing_connector_payment_sync_call(
+                self.state,
+                self.merchant_account,
+                self.billing_connector_account,
+                &self.billing_connector_account.connector_name.to_string(),
+                &BillingConnectorPaymentsSyncId::InvoiceId(
+                    merchant_reference_id.get_string_repr().to_owned(),
+                ),
+                common_utils::date_time::now(),
+                None,
+                webhooks::PaymentsSyncCacheMode::Refresh,
+                None,
+            )
+            .await?;
+
+        Ok(response.inner().status)
+    }
+}
+
+fn generate_task_id_for_reconciliation_workflow(
+    billing_connector_id: &id_type::MerchantConnectorAccountId,
+) -> String {
+    format!(
+        "{RECONCILIATION_RUNNER}_{RECONCILIATION_NAME}_{}",
+        billing_connector_id.get_string_repr()
+    )
+}
+
+fn get_reconciliation_reports_key(
+    billing_connector_id: &id_type::MerchantConnectorAccountId,
+) -> String {
+    format!(
+        "recovery_reconciliation_reports_{}",
+        billing_connector_id.get_string_repr()
+    )
+}
+
+fn get_next_run_time(
+    state: &SessionState,
+    current_time: time::PrimitiveDateTime,
+) -> RouterResult<time::PrimitiveDateTime> {
+    timezone::get_next_local_hour(
+        current_time,
+        timezone::Tz::UTC,
+        state.conf.revenue_recovery.reconciliation.hour_of_day,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to compute the schedule time of the reconciliation task")
+}
+
+/// Whether the open invoices of the billing connector account are to be reconciled, which needs
+/// the billing connector to support the payments sync
+pub(crate) fn is_reconciliation_enabled(
+    state: &SessionState,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+) -> bool {
+    billing_connector_account.connector_type == common_enums::ConnectorType::BillingProcessor
+        && !billing_connector_account.is_disabled()
+        && billing_connector_account.is_recovery_reconciliation_enabled()
+        && state
+            .conf
+            .billing_connectors_payment_sync
+            .billing_connectors_which_require_payment_sync
+            .contains(&billing_connector_account.connector_name)
+}
+
+/// Schedules the reconciliation task of the billing connector account for the coming night, if
+/// the reconciliation has been enabled for the account. A task which is already scheduled is left
+/// as it is, and a task whose reconciliation was disabled completes on its next run.
+#[instrument(skip_all)]
+pub async fn schedule_reconciliation_task(
+    state: &SessionState,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<()> {
+    if !is_reconciliation_enabled(state, billing_connector_account) {
+        return Ok(());
+    }
+
+    let billing_connector_id = billing_connector_account.get_id();
+    let process_tracker_id = generate_task_id_for_reconciliation_workflow(&billing_connector_id);
+    let db = state.store.as_ref();
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the reconciliation task")?;
+
+    if existing_process
+        .as_ref()
+        .is_some_and(|process| process.status != common_enums::ProcessTrackerStatus::Finish)
+    {
+        return Ok(());
+    }
+
+    let current_time = common_utils::date_time::now();
+    let schedule_time = get_next_run_time(state, current_time)?;
+    let tracking_data = pcr::RecoveryReconciliationTrackingData {
+        merchant_id: billing_connector_account.merchant_id.clone(),
+        profile_id: billing_connector_account.profile_id.clone(),
+        billing_connector_id,
+        current_run: None,
+    };
+
+    match existing_process {
+        Some(process) => {
+            let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(
+                    serde_json::to_value(tracking_data)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable(
+                            "Failed to serialize the tracking data of the reconciliation task",
+                        )?,
+                ),
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(common_enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.update_process(process, process_tracker_update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule the reconciliation task")?;
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                RECONCILIATION_NAME,
+                RECONCILIATION_RUNNER,
+                [RECONCILIATION_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the reconciliation task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the reconciliation task")?;
+        }
+    }
+
+    logger::info!(?schedule_time, "Scheduled the recovery reconciliation task");
+
+    Ok(())
+}
+
+/// The terminal state of the invoice at the billing connector which is not reflected in its
+/// payment, if any. A payment whose retry is being processed is left to the sync of the retry.
+pub(crate) fn get_drift_kind(
+    billing_connector_status: common_enums::AttemptStatus,
+    intent_status: common_enums::IntentStatus,
+) -> Option<RecoveryReconciliationDriftKind> {
+    if webhooks::is_recovery_closed(intent_status)
+        || intent_status == common_enums::IntentStatus::Processing
+    {
+        return None;
+    }
+
+    match billing_connector_status {
+        common_enums::AttemptStatus::Charged => Some(RecoveryReconciliationDriftKind::Paid),
+        common_enums::AttemptStatus::Voided => Some(RecoveryReconciliationDriftKind::Voided),
+        _ => None,
+    }
+}
+
+/// The status to which the payment of a drifted invoice is moved, along with the business status
+/// of its cancelled retry, the same as the webhook of the billing connector would have
+fn get_reconciled_status(
+    drift_kind: RecoveryReconciliationDriftKind,
+) -> (common_enums::IntentStatus, &'static str) {
+    match drift_kind {
+        RecoveryReconciliationDriftKind::Paid => (
+            common_enums::IntentStatus::Succeeded,
+            business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_EXTERNAL_PAYMENT,
+        ),
+        RecoveryReconciliationDriftKind::Voided => (
+            common_enums::IntentStatus::Cancelled,
+            business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_VOIDED_INVOICE,
+        ),
+    }
+}
+
+/// Fetches the status of the invoices from the billing connector one after the other, waiting for
+/// the given interval between two calls so that the rate limits of the billing connector are not
+/// hit. Returns the drifted invoices, and counts the invoices checked in the report of the run.
+pub(crate) async fn detect_drifts(
+    fetcher: &dyn InvoiceStatusFetcher,
+    candidates: &[ReconciliationCandidate],
+    min_interval_between_calls: std::time::Duration,
+    connector: &str,
+    report: &mut pcr::RecoveryReconciliationReport,
+) -> HashMap<id_type::GlobalPaymentId, RecoveryReconciliationDriftKind> {
+    let mut drifts = HashMap::new();
+    let mut is_first_call = true;
+
+    for candidate in candidates {
+        let Some(merchant_reference_id) = candidate.merchant_reference_id.as_ref() else {
+            logger::warn!(
+                payment_id = ?candidate.payment_id,
+                "Recovery payment has no invoice id, skipping its reconciliation"
+            );
+            report.errors_count += 1;
+            continue;
+        };
+
+        if !is_first_call {
+            tokio::time::sleep(min_interval_between_calls).await;
+        }
+        is_first_call = false;
+
+        let billing_connector_status =
+            match fetcher.fetch_invoice_status(merchant_reference_id).await {
+                Ok(billing_connector_status) => billing_connector_status,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        payment_id = ?candidate.payment_id,
+                        "Failed to fetch the status of the invoice from the billing connector"
+                    );
+                    report.errors_count += 1;
+                    continue;
+                }
+            };
+
+        report.checked_count += 1;
+        metrics::REVENUE_RECOVERY_RECONCILIATION_CHECKED_COUNT.add(
+            1,
+            router_env::metric_attributes!(("connector", connector.to_owned())),
+        );
+
+        if let Some(drift_kind) = get_drift_kind(billing_connector_status, candidate.status) {
+            report.drifted_count += 1;
+            metrics::REVENUE_RECOVERY_RECONCILIATION_DRIFT_COUNT.add(
+                1,
+                router_env::metric_attributes!(
+                    ("connector", connector.to_owned()),
+                    ("drift_kind", drift_kind.to_string()),
+                ),
+            );
+            drifts.insert(candidate.payment_id.clone(), drift_kind);
+        }
+    }
+
+    drifts
+}
+
+fn record_drift(
+    report: &mut pcr::RecoveryReconciliationReport,
+    candidate: ReconciliationCandidate,
+    drift_kind: RecoveryReconciliationDriftKind,
+    reconciled: bool,
+) {
+    if reconciled {
+        report.reconciled_count += 1;
+    } else {
+        report.errors_count += 1;
+    }
+
+    if report.drifts.len() < MAX_DRIFTS_PER_REPORT {
+        report.drifts.push(pcr::RecoveryReconciliationDrift {
+            payment_id: candidate.payment_id,
+            merchant_reference_id: candidate.merchant_reference_id,
+            kind: drift_kind,
+            previous_status: candidate.status,
+            reconciled,
+        });
+    }
+}
+
+/// Checks the next batch of the open invoices of the billing connector account and stops the
+/// recovery of the drifted ones. The task is picked up again right away for the next batch, and
+/// is scheduled for the next night once all the open invoices have been checked.
+#[instrument(skip_all)]
+pub(crate) async fn perform_reconciliation_batch(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: pcr::RecoveryReconciliationTrackingData,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let settings = &state.conf.revenue_recovery.reconciliation;
+    let connector = billing_connector_account.connector_name.to_string();
+
+    let mut run =
+        tracking_data
+            .current_run
+            .clone()
+            .unwrap_or_else(|| pcr::RecoveryReconciliationRun {
+                starting_after: None,
+                examined_count: 0,
+                report: pcr::RecoveryReconciliationReport::new(common_utils::date_time::now()),
+            });
+
+    let batch_size = u32::from(settings.batch_size).min(
+        settings
+            .max_invoices_per_run
+            .saturating_sub(run.examined_count),
+    );
+    let payment_intents = if batch_size == 0 {
+        Vec::new()
+    } else {
+        db.find_open_recovery_intents_by_billing_connector_id_profile_id(
+            &state.into(),
+            &tracking_data.billing_connector_id,
+            &tracking_data.profile_id,
+            key_store,
+            &merchant_account.storage_scheme,
+            run.starting_after.as_ref(),
+            i64::from(batch_size),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the open recovery payments of the billing connector")?
+    };
+    let is_run_complete =
+        u32::try_from(payment_intents.len()).is_ok_and(|count| count < batch_size);
+
+    let candidates = payment_intents
+        .iter()
+        .map(ReconciliationCandidate::from)
+        .collect::<Vec<_>>();
+    let fetcher = BillingConnectorInvoiceStatusFetcher {
+        state,
+        merchant_account,
+        billing_connector_account,
+    };
+    let mut drifts = detect_drifts(
+        &fetcher,
+        &candidates,
+        std::time::Duration::from_millis(settings.min_interval_between_calls_in_ms),
+        &connector,
+        &mut run.report,
+    )
+    .await;
+
+    run.examined_count = run
+        .examined_count
+        .saturating_add(u32::try_from(payment_intents.len()).unwrap_or(u32::MAX));
+    if let Some(payment_intent) = payment_intents.last() {
+        run.starting_after = Some(payment_intent.id.clone());
+    }
+
+    for payment_intent in payment_intents {
+        let Some(drift_kind) = drifts.remove(&payment_intent.id) else {
+            continue;
+        };
+
+        let candidate = ReconciliationCandidate::from(&payment_intent);
+        let (status, task_business_status) = get_reconciled_status(drift_kind);
+        let reconciled = match webhooks::stop_invoice_recovery(
+            state,
+            merchant_account,
+            key_store,
+            payment_intent,
+            status,
+            task_business_status,
+        )
+        .await
+        {
+            Ok((cancelled_task_id, _)) => {
+                logger::info!(
+                    payment_id = ?candidate.payment_id,
+                    %drift_kind,
+                    ?cancelled_task_id,
+                    "Stopped the recovery of the drifted invoice"
+                );
+                true
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_id = ?candidate.payment_id,
+                    "Failed to stop the recovery of the drifted invoice"
+                );
+                false
+            }
+        };
+        record_drift(&mut run.report, candidate, drift_kind, reconciled);
+    }
+
+    let is_limit_reached = run.examined_count >= settings.max_invoices_per_run;
+    let current_time = common_utils::date_time::now();
+
+    let (schedule_time, current_run) = if is_run_complete || is_limit_reached {
+        let mut report = run.report;
+        report.completed_at = Some(current_time);
+        report.is_truncated = !is_run_complete;
+
+        logger::info!(
+            checked_count = report.checked_count,
+            drifted_count = report.drifted_count,
+            reconciled_count = report.reconciled_count,
+            errors_count = report.errors_count,
+            "Completed the reconciliation of the open recovery invoices"
+        );
+        store_reconciliation_report(db, &tracking_data.billing_connector_id, report)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store the report of the reconciliation")?;
+
+        (get_next_run_time(state, current_time)?, None)
+    } else {
+        (current_time, Some(run))
+    };
+
+    let updated_tracking_data = pcr::RecoveryReconciliationTrackingData {
+        current_run,
+        ..tracking_data
+    };
+    let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: Some(0),
+        schedule_time: Some(schedule_time),
+        tracking_data: Some(
+            serde_json::to_value(updated_tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to serialize the tracking data of the reconciliation task",
+                )?,
+        ),
+        business_status: Some(String::from(business_status::PENDING)),
+        status: Some(common_enums::ProcessTrackerStatus::New),
+        updated_at: Some(current_time),
+    };
+    db.update_process(process, process_tracker_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule the reconciliation task")?;
+
+    Ok(())
+}
+
+async fn find_reconciliation_reports(
+    db: &dyn StorageInterface,
+    billing_connector_id: &id_type::MerchantConnectorAccountId,
+) -> CustomResult<Option<pcr::RecoveryReconciliationReports>, errors::StorageError> {
+    match db
+        .find_config_by_key_from_db(&get_reconciliation_reports_key(billing_connector_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct::<pcr::RecoveryReconciliationReports>("RecoveryReconciliationReports")
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to parse the reconciliation reports")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Adds the report of a completed run to the reports of the billing connector account, dropping
+/// the oldest reports beyond the reports kept
+async fn store_reconciliation_report(
+    db: &dyn StorageInterface,
+    billing_connector_id: &id_type::MerchantConnectorAccountId,
+    report: pcr::RecoveryReconciliationReport,
+) -> CustomResult<(), errors::StorageError> {
+    let key = get_reconciliation_reports_key(billing_connector_id);
+    let stored_reports = find_reconciliation_reports(db, billing_connector_id).await?;
+    let is_stored = stored_reports.is_some();
+    let mut reports = stored_reports.unwrap_or_default();
+
+    reports.reports.push(report);
+    let excess_reports = reports
+        .reports
+        .len()
+        .saturating_sub(MAX_RECONCILIATION_REPORTS);
+    reports.reports.drain(..excess_reports);
+
+    let config = serde_json::to_string(&reports)
+        .change_context(errors::StorageError::SerializationFailed)
+        .attach_printable("Failed to serialize the reconciliation reports")?;
+
+    if is_stored {
+        db.update_config_by_key(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await?;
+    } else {
+        db.insert_config(storage::ConfigNew { key, config }).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn retrieve_reconciliation_reports(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    billing_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryReconciliationReportsResponse> {
+    let db = state.store.as_ref();
+    let mca_not_found = || errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+        id: billing_connector_id.get_string_repr().to_string(),
+    };
+
+    let billing_connector_account = db
+        .find_merchant_connector_account_by_id(&(&state).into(), &billing_connector_id, &key_store)
+        .await
+        .to_not_found_response(mca_not_found())?;
+    if billing_connector_account.merchant_id != *merchant_account.get_id() {
+        return Err(mca_not_found().into());
+    }
+
+    let reports = find_reconciliation_reports(db, &billing_connector_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the reconciliation reports")?
+        .unwrap_or_default();
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RevenueRecoveryReconciliationReportsResponse {
+            merchant_id: merchant_account.get_id().clone(),
+            billing_connector_id,
+            reconciliation_enabled: billing_connector_account.is_recovery_reconciliation_enabled(),
+            reports: get_reconciliation_reports_response(reports),
+        },
+    ))
+}
+
+/// The completed reports, the latest first
+fn get_reconciliation_reports_response(
+    reports: pcr::RecoveryReconciliationReports,
+) -> Vec<revenue_recovery_api::RevenueRecoveryReconciliationReport> {
+    reports
+        .reports
+        .into_iter()
+        .rev()
+        .filter_map(|report| {
+            let completed_at = report.completed_at?;
+            Some(revenue_recovery_api::RevenueRecoveryReconciliationReport {
+                started_at: report.started_at,
+                completed_at,
+                checked_count: report.checked_count,
+                drifted_count: report.drifted_count,
+                reconciled_count: report.reconciled_count,
+                errors_count: report.errors_count,
+                is_truncated: report.is_truncated,
+                drifts: report
+                    .drifts
+                    .into_iter()
+                    .map(
+                        |drift| revenue_recovery_api::RevenueRecoveryReconciliationDrift {
+                            payment_id: drift.payment_id,
+                            merchant_reference_id: drift.merchant_reference_id,
+                            kind: drift.kind,
+                            previous_status: drift.previous_status,
+                            reconciled: drift.reconciled,
+                        },
+                    )
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::str::FromStr;
+
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::db::MockDb;
+
+    /// Billing connector which returns the configured status of each invoice, and fails for the
+    /// invoices it does not know of
+    struct MockInvoiceStatusFetcher(HashMap<String, common_enums::AttemptStatus>);
+
+    #[async_trait::async_trait]
+    impl InvoiceStatusFetcher for MockInvoiceStatusFetcher {
+        async fn fetch_invoice_status(
+            &self,
+            merchant_reference_id: &id_type::PaymentReferenceId,
+        ) -> CustomResult<common_enums::AttemptStatus, errors::RevenueRecoveryError> {
+            self.0
+                .get(merchant_reference_id.get_string_repr())
+                .copied()
+                .ok_or(errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed.into())
+        }
+    }
+
+    fn get_candidate(
+        invoice_id: Option<&str>,
+        status: common_enums::IntentStatus,
+    ) -> ReconciliationCandidate {
+        let cell_id = id_type::CellId::from_string("defid").expect("valid cell id");
+        ReconciliationCandidate {
+            payment_id: id_type::GlobalPaymentId::generate(&cell_id),
+            merchant_reference_id: invoice_id.map(|invoice_id| {
+                id_type::PaymentReferenceId::from_str(invoice_id)
+                    .expect("valid merchant reference id")
+            }),
+            status,
+        }
+    }
+
+    fn get_completed_report(
+        started_at: time::PrimitiveDateTime,
+    ) -> pcr::RecoveryReconciliationReport {
+        pcr::RecoveryReconciliationReport {
+            completed_at: Some(started_at + time::Duration::minutes(10)),
+            ..pcr::RecoveryReconciliationReport::new(started_at)
+        }
+    }
+
+    #[test]
+    fn test_drift_kind_of_invoice() {
+        assert_eq!(
+            get_drift_kind(
+                common_enums::AttemptStatus::Charged,
+                common_enums::IntentStatus::Failed
+            ),
+            Some(RecoveryReconciliationDriftKind::Paid)
+        );
+        assert_eq!(
+            get_drift_kind(
+                common_enums::AttemptStatus::Voided,
+                common_enums::IntentStatus::RequiresPaymentMethod
+            ),
+            Some(RecoveryReconciliationDriftKind::Voided)
+        );
+
+        // The billing connector is still collecting the invoice
+        assert_eq!(
+            get_drift_kind(
+                common_enums::AttemptStatus::Failure,
+                common_enums::IntentStatus::Failed
+            ),
+            None
+        );
+        // The retry of the payment is being synced, which reconciles the payment
+        assert_eq!(
+            get_drift_kind(
+                common_enums::AttemptStatus::Charged,
+                common_enums::IntentStatus::Processing
+            ),
+            None
+        );
+        assert_eq!(
+            get_drift_kind(
+                common_enums::AttemptStatus::Voided,
+                common_enums::IntentStatus::Succeeded
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_drifted_invoices_are_reconciled_as_the_webhooks_would() {
+        assert_eq!(
+            get_reconciled_status(RecoveryReconciliationDriftKind::Paid),
+            (
+                common_enums::IntentStatus::Succeeded,
+                business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_EXTERNAL_PAYMENT
+            )
+        );
+        assert_eq!(
+            get_reconciled_status(RecoveryReconciliationDriftKind::Voided),
+            (
+                common_enums::IntentStatus::Cancelled,
+                business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_VOIDED_INVOICE
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drifts_are_detected_from_the_billing_connector() {
+        let fetcher = MockInvoiceStatusFetcher(HashMap::from([
+            (
+                String::from("invoice_paid"),
+                common_enums::AttemptStatus::Charged,
+            ),
+            (
+                String::from("invoice_voided"),
+                common_enums::AttemptStatus::Voided,
+            ),
+            (
+                String::from("invoice_failed"),
+                common_enums::AttemptStatus::Failure,
+            ),
+            (
+                String::from("invoice_retrying"),
+                common_enums::AttemptStatus::Charged,
+            ),
+        ]));
+        let paid = get_candidate(Some("invoice_paid"), common_enums::IntentStatus::Failed);
+        let voided = get_candidate(
+            Some("invoice_voided"),
+            common_enums::IntentStatus::RequiresPaymentMethod,
+        );
+        let candidates = vec![
+            paid.clone(),
+            voided.clone(),
+            get_candidate(Some("invoice_failed"), common_enums::IntentStatus::Failed),
+            get_candidate(
+                Some("invoice_retrying"),
+                common_enums::IntentStatus::Processing,
+            ),
+            // Unknown to the billing connector
+            get_candidate(Some("invoice_unknown"), common_enums::IntentStatus::Failed),
+            get_candidate(None, common_enums::IntentStatus::Failed),
+        ];
+        let mut report = pcr::RecoveryReconciliationReport::new(datetime!(2024-01-15 02:00));
+
+        let drifts = detect_drifts(
+            &fetcher,
+            &candidates,
+            std::time::Duration::ZERO,
+            "chargebee",
+            &mut report,
+        )
+        .await;
+
+        assert_eq!(
+            drifts,
+            HashMap::from([
+                (paid.payment_id, RecoveryReconciliationDriftKind::Paid),
+                (voided.payment_id, RecoveryReconciliationDriftKind::Voided),
+            ])
+        );
+        assert_eq!(report.checked_count, 4);
+        assert_eq!(report.drifted_count, 2);
+        assert_eq!(report.errors_count, 2);
+        assert_eq!(report.reconciled_count, 0);
+    }
+
+    #[test]
+    fn test_report_lists_drifts_up_to_the_cap() {
+        let mut report = pcr::RecoveryReconciliationReport::new(datetime!(2024-01-15 02:00));
+        for index in 0..=MAX_DRIFTS_PER_REPORT {
+            let candidate = get_candidate(Some("invoice_paid"), common_enums::IntentStatus::Failed);
+            record_drift(
+                &mut report,
+                candidate,
+                RecoveryReconciliationDriftKind::Paid,
+                index % 2 == 0,
+            );
+        }
+
+        assert_eq!(report.drifts.len(), MAX_DRIFTS_PER_REPORT);
+        assert_eq!(report.reconciled_count, 51);
+        assert_eq!(report.errors_count, 50);
+    }
+
+    #[tokio::test]
+    async fn test_reports_keep_latest_runs() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let billing_connector_id =
+            id_type::MerchantConnectorAccountId::wrap(String::from("mca_chargebee"))
+                .expect("valid connector account id");
+
+        let first_run_time = datetime!(2024-01-01 02:00);
+        for days in 0..=i64::try_from(MAX_RECONCILIATION_REPORTS).expect("valid reports count") {
+            store_reconciliation_report(
+                &db,
+                &billing_connector_id,
+                get_completed_report(first_run_time + time::Duration::days(days)),
+            )
+            .await
+            .expect("report stored");
+        }
+
+        let reports = find_reconciliation_reports(&db, &billing_connector_id)
+            .await
+            .expect("readable reports")
+            .expect("stored reports");
+        assert_eq!(reports.reports.len(), MAX_RECONCILIATION_REPORTS);
+
+        let response = get_reconciliation_reports_response(reports);
+        assert_eq!(
+            response.first().map(|report| report.started_at),
+            Some(
+                first_run_time
+                    + time::Duration::days(
+                        i64::try_from(MAX_RECONCILIATION_REPORTS).expect("valid reports count")
+                    )
+            )
+        );
+        assert_eq!(
+            response.last().map(|report| report.started_at),
+            Some(first_run_time + time::Duration::days(1))
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Record back of the outcome of the revenue recovery retries to the billing connector.
+//!
+//! Once the retries of a payment are over, the billing connector is told of the successful retry,
+//! or of the retries being exhausted, so that it stops dunning the customer for the invoice. The
+//! payment is not undone when the record back fails. The record back alone is retried by a record
+//! back task, which follows the retries of the other recovery tasks on failing, and is moved to
+//! review once it has failed too many times in a row.
+//!
+//! The status of the record back is kept in the revenue recovery metadata of the payment, so that
+//! the recovery status API can tell whether the billing connector has recorded the outcome.
+
+use common_enums::RecoveryRecordBackStatus;
+use common_utils::{errors::CustomResult, ext_traits::ValueExt, id_type};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    merchant_connector_account::MerchantConnectorAccount,
+    payments::{payment_attempt::PaymentAttempt, PaymentIntent},
+};
+use scheduler::errors as sch_errors;
+
+use super::{insert_or_update_pcr_task, types, RECORD_BACK_WORKFLOW};
+use crate::{
+    core::errors,
+    db::StorageInterface,
+    logger,
+    routes::{metrics, SessionState},
+    types::storage::{self, revenue_recovery as pcr},
+};
+
+/// Id of the process tracker task which retries the record back of the payment
+pub(crate) fn get_record_back_pcr_task_id(
+    payment_id: &id_type::GlobalPaymentId,
+    runner: storage::ProcessTrackerRunner,
+) -> String {
+    format!(
+        "{runner}_{RECORD_BACK_WORKFLOW}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+/// Tracking data of the record back task, which records back the attempt with which the retries
+/// of the execute task were over. The failures of the execute task are not carried over.
+fn get_record_back_tracking_data(
+    execute_tracking_data: pcr::PcrWorkflowTrackingData,
+    payment_attempt_id: &id_type::GlobalAttemptId,
+) -> pcr::PcrWorkflowTrackingData {
+    pcr::PcrWorkflowTrackingData {
+        payment_attempt_id: payment_attempt_id.clone(),
+        schedule_adjustments: Vec::new(),
+        decline_class: None,
+        execution_failure_count: 0,
+        last_execution_error: None,
+        schedule_decision: None,
+        ..execute_tracking_data
+    }
+}
+
+/// Records back the outcome of the attempt, scheduling the record back task when the billing
+/// connector could not be updated
+pub(crate) async fn record_back_or_schedule_retry(
+    state: &SessionState,
+    execute_task_process: &storage::ProcessTracker,
+    pcr_data: &pcr::PcrPaymentData,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+    billing_mca: &MerchantConnectorAccount,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let record_back_status = match types::record_back_to_billing_connector(
+        state,
+        payment_attempt,
+        payment_intent,
+        billing_mca,
+    )
+    .await
+    {
+        Ok(()) => RecoveryRecordBackStatus::Completed,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                payment_id = ?payment_intent.id,
+                "Failed to record back to the billing connector, scheduling the record back task"
+            );
+            insert_record_back_task(&*state.store, execute_task_process, payment_attempt).await?;
+            RecoveryRecordBackStatus::Pending
+        }
+    };
+
+    update_record_back_status(state, pcr_data, &payment_intent.id, record_back_status).await?;
+    Ok(())
+}
+
+async fn insert_record_back_task(
+    db: &dyn StorageInterface,
+    execute_task_process: &storage::ProcessTracker,
+    payment_attempt: &PaymentAttempt,
+) -> CustomResult<(), errors::StorageError> {
+    let execute_tracking_data = execute_task_process
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")
+        .change_context(errors::StorageError::DeserializationFailed)?;
+
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let record_back_task_id =
+        get_record_back_pcr_task_id(&execute_tracking_data.global_payment_id, runner);
+    let tag = pcr::get_pcr_task_tags(
+        &execute_tracking_data.merchant_id,
+        pcr::get_billing_connector_from_tags(&execute_task_process.tag),
+    );
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        record_back_task_id.clone(),
+        RECORD_BACK_WORKFLOW,
+        runner,
+        tag,
+        get_record_back_tracking_data(execute_tracking_data, &payment_attempt.id),
+        None,
+        common_utils::date_time::now(),
+        common_enums::ApiVersion::V2,
+    )
+    .change_context(errors::StorageError::SerializationFailed)
+    .attach_printable("Failed to construct process tracker entry")?;
+
+    insert_or_update_pcr_task(db, process_tracker_entry).await?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "RecordBackPCR")));
+
+    logger::info!(%record_back_task_id, "Scheduled the record back task of the payment");
+    Ok(())
+}
+
+/// Updates the status of the record back in the revenue recovery metadata of the payment. The
+/// payment is fetched again, since its status has been updated by the attempt being recorded back.
+async fn update_record_back_status(
+    state: &SessionState,
+    pcr_data: &pcr::PcrPaymentData,
+    payment_id: &id_type::GlobalPaymentId,
+    record_back_status: RecoveryRecordBackStatus,
+) -> CustomResult<(), errors::StorageError> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let storage_scheme = pcr_data.merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_id(
+            key_manager_state,
+            payment_id,
+            &pcr_data.key_store,
+            storage_scheme,
+        )
+        .await?;
+
+    let Some(mut feature_metadata) = payment_intent.feature_metadata.clone() else {
+        return Ok(());
+    };
+    let Some(recovery_metadata) = feature_metadata.payment_revenue_recovery_metadata.as_mut()
+    else {
+        return Ok(());
+    };
+    recovery_metadata.record_back_status = Some(record_back_status);
+
+    let payment_intent_update =
+        hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::RecordUpdate {
+            status: payment_intent.status,
+            feature_metadata: Box::new(Some(feature_metadata)),
+            updated_by: storage_scheme.to_string(),
+        };
+
+    db.update_payment_intent(
+        key_manager_state,
+        payment_intent,
+        payment_intent_update,
+        &pcr_data.key_store,
+        storage_scheme,
+    )
+    .await?;
+    Ok(())
+}
+
+pub(crate) async fn perform_record_back_workflow(
+    state: &SessionState,
+    record_back_task_process: &storage::ProcessTracker,
+    tracking_data: &pcr::PcrWorkflowTrackingData,
+    pcr_data: &pcr::PcrPaymentData,
+    payment_intent: &PaymentIntent,
+    billing_mca: &MerchantConnectorAccount,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_id(
+            &state.into(),
+            &pcr_data.key_store,
+            &tracking_data.payment_attempt_id,
+            pcr_data.merchant_account.storage_scheme,
+        )
+        .await?;
+
+    // The failure of the execution is retried by the dead letter handling of the recovery tasks
+    types::record_back_to_billing_connector(state, &payment_attempt, payment_intent, billing_mca)
+        .await?;
+
+    db.as_scheduler()
+        .finish_process_with_business_status(
+            record_back_task_process.clone(),
+            business_status::RECORD_BACK_WORKFLOW_COMPLETE,
+        )
+        .await?;
+
+    update_record_back_status(
+        state,
+        pcr_data,
+        &payment_intent.id,
+        RecoveryRecordBackStatus::Completed,
+    )
+    .await?;
+
+    logger::info!(
+        task_id = %record_back_task_process.id,
+        "Recorded back the outcome of the revenue recovery retries to the billing connector"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_failures_of_execute_task_are_not_carried_over_to_record_back_task() {
+        let cell_id = id_type::CellId::from_string("defid").unwrap();
+        let payment_attempt_id = id_type::GlobalAttemptId::generate(&cell_id);
+        let execute_tracking_data = pcr::PcrWorkflowTrackingData {
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("profile_1")).unwrap(),
+            global_payment_id: id_type::GlobalPaymentId::generate(&cell_id),
+            payment_attempt_id: id_type::GlobalAttemptId::generate(&cell_id),
+            billing_mca_id: id_type::MerchantConnectorAccountId::wrap(String::from("mca_1"))
+                .unwrap(),
+            schedule_adjustments: vec![common_enums::RecoveryScheduleAdjustment::BlackoutCalendar],
+            recovery_correlation_id: Some(String::from("rrd_1")),
+            decline_class: Some(String::from("insufficient_funds")),
+            execution_failure_count: 2,
+            last_execution_error: Some(String::from("Failed to fetch the payment")),
+            schedule_decision: None,
+        };
+
+        let tracking_data =
+            get_record_back_tracking_data(execute_tracking_data.clone(), &payment_attempt_id);
+
+        assert_eq!(tracking_data.payment_attempt_id, payment_attempt_id);
+        assert_eq!(
+            tracking_data.global_payment_id,
+            execute_tracking_data.global_payment_id
+        );
+        assert_eq!(
+            tracking_data.recovery_correlation_id,
+            execute_tracking_data.recovery_correlation_id
+        );
+        assert_eq!(tracking_data.execution_failure_count, 0);
+        assert!(tracking_data.last_execution_error.is_none());
+        assert!(tracking_data.schedule_adjustments.is_empty());
+    }
+}
@@ -0,0 +1,358 @@
+//! Notification of the merchant ahead of every revenue recovery retry of a payment.
+//!
+//! When the retry notification is enabled for the profile, a notify task is scheduled along with
+//! every retry of the payment, the lead time ahead of the retry. The task sends the
+//! `recovery.retry_upcoming` event to the webhook URL of the profile, so that the merchant can
+//! notify the customer before the payment method is charged again.
+//!
+//! A retry is never made earlier than the lead time after its notification: the retry is
+//! scheduled at least the lead time from the time of scheduling, and is pushed back when the
+//! notification is sent late. The notify task checks the notification config of the profile when
+//! it runs, so that the tasks which are pending finish without sending the notification once the
+//! notifications are disabled.
+
+use common_types::payments::RevenueRecoveryRetryNotification;
+use common_utils::{errors::CustomResult, ext_traits::ValueExt, request::RequestContent};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::PaymentIntent;
+use masking::PeekInterface;
+use scheduler::errors as sch_errors;
+use time::PrimitiveDateTime;
+
+use super::{get_execute_pcr_task_id, insert_or_update_pcr_task, kill_switch, NOTIFY_WORKFLOW};
+use crate::{
+    core::errors,
+    db::StorageInterface,
+    logger,
+    routes::{metrics, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, revenue_recovery as pcr},
+    },
+};
+
+const RETRY_NOTIFICATION_TIMEOUT_SECS: u64 = 5;
+
+/// Id of the process tracker task which notifies the merchant of the upcoming retry of the
+/// payment
+pub(crate) fn get_notify_pcr_task_id(
+    payment_id: &common_utils::id_type::GlobalPaymentId,
+    runner: storage::ProcessTrackerRunner,
+) -> String {
+    format!(
+        "{runner}_{NOTIFY_WORKFLOW}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+pub(crate) fn get_enabled_retry_notification(
+    business_profile: &domain::Profile,
+) -> Option<&RevenueRecoveryRetryNotification> {
+    business_profile
+        .revenue_recovery_retry_notification
+        .as_ref()
+        .filter(|retry_notification| retry_notification.enabled)
+}
+
+fn get_lead_time(retry_notification: &RevenueRecoveryRetryNotification) -> time::Duration {
+    time::Duration::seconds(i64::from(retry_notification.lead_time_in_secs))
+}
+
+/// Moves the retry to at least the lead time from the current time, so that the notification of
+/// the retry can be sent the lead time ahead of it
+pub(crate) fn get_schedule_time_after_lead_time(
+    schedule_time: PrimitiveDateTime,
+    retry_notification: &RevenueRecoveryRetryNotification,
+    current_time: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    schedule_time.max(current_time.saturating_add(get_lead_time(retry_notification)))
+}
+
+/// Schedules the notification of the retry made by the execute task, updating the notify task
+/// of the previous retry of the payment in place
+pub(crate) async fn schedule_retry_notification(
+    db: &dyn StorageInterface,
+    retry_notification: &RevenueRecoveryRetryNotification,
+    execute_task_process: &storage::ProcessTracker,
+) -> CustomResult<(), errors::StorageError> {
+    let Some(retry_time) = execute_task_process.schedule_time else {
+        return Ok(());
+    };
+    let workflow_tracking_data = execute_task_process
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::PcrWorkflowTrackingData>("PcrWorkflowTrackingData")
+        .change_context(errors::StorageError::DeserializationFailed)?;
+
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let notify_task_id = get_notify_pcr_task_id(&workflow_tracking_data.global_payment_id, runner);
+    // The processor token tag of the execute task is not carried over, since the tasks with the
+    // tag are taken to be the retries scheduled for the processor token
+    let tag = pcr::get_pcr_task_tags(
+        &workflow_tracking_data.merchant_id,
+        pcr::get_billing_connector_from_tags(&execute_task_process.tag),
+    );
+    let schedule_time = retry_time
+        .saturating_sub(get_lead_time(retry_notification))
+        .max(common_utils::date_time::now());
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        notify_task_id.clone(),
+        NOTIFY_WORKFLOW,
+        runner,
+        tag,
+        pcr::PcrNotifyWorkflowTrackingData {
+            workflow_tracking_data,
+            execute_task_retry_count: execute_task_process.retry_count,
+        },
+        None,
+        schedule_time,
+        common_enums::ApiVersion::V2,
+    )
+    .change_context(errors::StorageError::SerializationFailed)
+    .attach_printable("Failed to construct process tracker entry")?;
+
+    insert_or_update_pcr_task(db, process_tracker_entry).await?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "NotifyPCR")));
+
+    logger::info!(
+        %notify_task_id,
+        ?schedule_time,
+        ?retry_time,
+        "Scheduled the notification of the revenue recovery retry of the payment"
+    );
+
+    Ok(())
+}
+
+/// Whether the execute task is still waiting to make the retry whose notification was scheduled
+fn is_retry_pending(execute_task_process: &storage::ProcessTracker, retry_count: i32) -> bool {
+    matches!(
+        execute_task_process.status,
+        common_enums::ProcessTrackerStatus::New | common_enums::ProcessTrackerStatus::Pending
+    ) && execute_task_process.retry_count == retry_count
+}
+
+pub(crate) async fn perform_notify_workflow(
+    state: &SessionState,
+    notify_task_process: &storage::ProcessTracker,
+    pcr_data: &pcr::PcrPaymentData,
+    payment_intent: &PaymentIntent,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+
+    if kill_switch::hold_task_for_kill_switch(
+        state,
+        notify_task_process,
+        business_status::NOTIFY_WORKFLOW_HALTED_BY_KILL_SWITCH,
+    )
+    .await?
+    {
+        return Ok(());
+    }
+
+    let tracking_data =
+        notify_task_process
+            .tracking_data
+            .clone()
+            .parse_value::<pcr::PcrNotifyWorkflowTrackingData>("PcrNotifyWorkflowTrackingData")?;
+    let execute_task_id = get_execute_pcr_task_id(
+        &tracking_data.workflow_tracking_data.global_payment_id,
+        storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+    );
+    let execute_task_process = db
+        .find_process_by_id(&execute_task_id)
+        .await?
+        .filter(|process| is_retry_pending(process, tracking_data.execute_task_retry_count));
+
+    let Some((retry_notification, execute_task_process)) =
+        get_enabled_retry_notification(&pcr_data.profile).zip(execute_task_process)
+    else {
+        logger::info!(
+            %execute_task_id,
+            "Revenue recovery retry notification skipped, since the notifications are disabled \
+             or the retry is no longer scheduled"
+        );
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                notify_task_process.clone(),
+                business_status::NOTIFY_WORKFLOW_SKIPPED,
+            )
+            .await?;
+        return Ok(());
+    };
+
+    let current_time = common_utils::date_time::now();
+    let scheduled_retry_time = get_schedule_time_after_lead_time(
+        execute_task_process.schedule_time.unwrap_or(current_time),
+        retry_notification,
+        current_time,
+    );
+    if execute_task_process.schedule_time != Some(scheduled_retry_time) {
+        logger::info!(
+            %execute_task_id,
+            previous_retry_time = ?execute_task_process.schedule_time,
+            ?scheduled_retry_time,
+            "Pushed back the revenue recovery retry, since its notification is sent late"
+        );
+        db.as_scheduler()
+            .update_process(
+                execute_task_process.clone(),
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(scheduled_retry_time),
+                    tracking_data: None,
+                    business_status: None,
+                    status: None,
+                    updated_at: Some(current_time),
+                },
+            )
+            .await?;
+    }
+
+    let notification = api::webhook_events::OutgoingRecoveryRetryUpcoming {
+        merchant_id: pcr_data.merchant_account.get_id().clone(),
+        profile_id: pcr_data.profile.get_id().clone(),
+        event_type: common_enums::EventType::RecoveryRetryUpcoming,
+        payment_id: payment_intent.get_id().clone(),
+        amount: payment_intent.amount_details.calculate_net_amount(),
+        currency: payment_intent.amount_details.currency,
+        retry_count: execute_task_process.retry_count,
+        scheduled_retry_time,
+        timestamp: current_time,
+    };
+    send_retry_notification_to_webhook_url(state, &pcr_data.profile, notification).await;
+
+    db.as_scheduler()
+        .finish_process_with_business_status(
+            notify_task_process.clone(),
+            business_status::NOTIFY_WORKFLOW_COMPLETE,
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// The notification is not retried if it could not be delivered, since the retry is made at its
+/// scheduled time regardless
+async fn send_retry_notification_to_webhook_url(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    notification: api::webhook_events::OutgoingRecoveryRetryUpcoming,
+) {
+    let Some(webhook_url) = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_url.as_ref())
+    else {
+        logger::warn!("Retry notification not sent, since the profile has no webhook URL");
+        return;
+    };
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url.peek())
+        .attach_default_headers()
+        .set_body(RequestContent::Json(Box::new(notification)))
+        .build();
+
+    match state
+        .api_client
+        .send_request(state, request, Some(RETRY_NOTIFICATION_TIMEOUT_SECS), false)
+        .await
+    {
+        Ok(response) if response.status().is_success() => {
+            logger::info!("Revenue recovery retry notification delivered successfully");
+        }
+        Ok(response) => {
+            logger::warn!(
+                status_code = ?response.status(),
+                "Revenue recovery retry notification was not accepted by the webhook URL"
+            );
+        }
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to deliver revenue recovery retry notification"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::core::revenue_recovery::EXECUTE_WORKFLOW;
+
+    fn get_retry_notification(lead_time_in_secs: u32) -> RevenueRecoveryRetryNotification {
+        RevenueRecoveryRetryNotification {
+            enabled: true,
+            lead_time_in_secs,
+        }
+    }
+
+    #[test]
+    fn test_retry_is_scheduled_after_the_lead_time() {
+        let retry_notification = get_retry_notification(24 * 60 * 60);
+        let current_time = datetime!(2024-01-15 10:00);
+
+        // A retry beyond the lead time is left as is
+        assert_eq!(
+            get_schedule_time_after_lead_time(
+                datetime!(2024-01-17 10:00),
+                &retry_notification,
+                current_time
+            ),
+            datetime!(2024-01-17 10:00)
+        );
+        // A retry within the lead time is pushed back to the end of the lead time
+        assert_eq!(
+            get_schedule_time_after_lead_time(
+                datetime!(2024-01-15 13:00),
+                &retry_notification,
+                current_time
+            ),
+            datetime!(2024-01-16 10:00)
+        );
+    }
+
+    #[test]
+    fn test_only_the_pending_retry_of_the_notification_is_notified() {
+        let get_execute_task = |status, retry_count| storage::ProcessTracker {
+            id: String::from("PASSIVE_RECOVERY_WORKFLOW_EXECUTE_WORKFLOW_12345"),
+            name: Some(String::from(EXECUTE_WORKFLOW)),
+            tag: vec![],
+            runner: None,
+            retry_count,
+            schedule_time: Some(datetime!(2024-01-16 10:00)),
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: String::from(business_status::PENDING),
+            status,
+            event: vec![],
+            created_at: datetime!(2024-01-15 10:00),
+            updated_at: datetime!(2024-01-15 10:00),
+            version: common_enums::ApiVersion::V2,
+        };
+
+        assert!(is_retry_pending(
+            &get_execute_task(common_enums::ProcessTrackerStatus::Pending, 2),
+            2
+        ));
+        // The execute task has moved on to the next retry
+        assert!(!is_retry_pending(
+            &get_execute_task(common_enums::ProcessTrackerStatus::Pending, 3),
+            2
+        ));
+        // The retry has been cancelled
+        assert!(!is_retry_pending(
+            &get_execute_task(common_enums::ProcessTrackerStatus::Finish, 2),
+            2
+        ));
+    }
+}
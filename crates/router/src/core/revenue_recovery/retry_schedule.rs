@@ -0,0 +1,368 @@
+//! Retry schedules defined by the merchants for their revenue recovery retries.
+//!
+//! The schedule of a merchant is used in place of the retry configuration of the merchant when the
+//! next retry of a payment is scheduled. The schedule time of a retry is computed when the task of
+//! the retry is scheduled, so a change to the schedule applies to the retries scheduled after it,
+//! the tasks which are already scheduled are not moved.
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_utils::{errors::CustomResult, ext_traits::StringExt, fp_utils, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::ApplicationResponse;
+use scheduler::types::process_data;
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
+    logger,
+    routes::SessionState,
+    types::storage,
+};
+
+/// Shortest delay of a retry of the schedule, 1 hour
+const MIN_RETRY_DELAY_IN_SECS: i32 = 60 * 60;
+
+/// Longest delay of a retry of the schedule, 30 days
+const MAX_RETRY_DELAY_IN_SECS: i32 = 60 * 60 * 24 * 30;
+
+const MAX_RETRY_SCHEDULE_NAME_LENGTH: usize = 64;
+
+/// Cached in place of the schedule of the merchants which have not defined one, so that they do
+/// not hit the database every time a retry is scheduled
+const NO_RETRY_SCHEDULE_CONFIG: &str = "null";
+
+fn get_retry_schedule_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("pcr_retry_schedule_{}", merchant_id.get_string_repr())
+}
+
+/// Retry schedule of the merchant used for scheduling its retries, read through the config cache.
+/// The retry configuration of the merchant is used when the merchant has not defined a schedule,
+/// or the schedule cannot be read.
+pub(crate) async fn get_retry_schedule(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> Option<process_data::RevenueRecoveryRetrySchedule> {
+    let key = get_retry_schedule_key(merchant_id);
+    let result = db
+        .find_config_by_key_unwrap_or(&key, Some(NO_RETRY_SCHEDULE_CONFIG.to_string()))
+        .await
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct::<Option<process_data::RevenueRecoveryRetrySchedule>>(
+                    "RevenueRecoveryRetrySchedule",
+                )
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+
+    match result {
+        Ok(retry_schedule) => retry_schedule,
+        Err(error) if error.current_context().is_db_not_found() => None,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to read the revenue recovery retry schedule `{key}`"
+            );
+            None
+        }
+    }
+}
+
+async fn find_retry_schedule(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<Option<process_data::RevenueRecoveryRetrySchedule>, errors::StorageError> {
+    match db
+        .find_config_by_key_from_db(&get_retry_schedule_key(merchant_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct::<process_data::RevenueRecoveryRetrySchedule>(
+                "RevenueRecoveryRetrySchedule",
+            )
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Failed to parse the revenue recovery retry schedule")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn validate_retry_schedule(
+    request: &revenue_recovery_api::RevenueRecoveryRetryScheduleRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    let invalid_request =
+        |message: String| errors::ApiErrorResponse::InvalidRequestData { message };
+    let name = request.name.trim();
+
+    fp_utils::when(
+        name.is_empty() || name.len() > MAX_RETRY_SCHEDULE_NAME_LENGTH,
+        || {
+            Err(invalid_request(format!(
+                "name must be between 1 and {MAX_RETRY_SCHEDULE_NAME_LENGTH} characters long"
+            )))
+        },
+    )?;
+
+    let delays = request
+        .delays_in_secs
+        .iter()
+        .chain(std::iter::once(&request.default_delay_in_secs));
+    fp_utils::when(
+        delays
+            .clone()
+            .any(|delay| !(MIN_RETRY_DELAY_IN_SECS..=MAX_RETRY_DELAY_IN_SECS).contains(delay)),
+        || {
+            Err(invalid_request(format!(
+                "delays must be between {} and {} seconds",
+                MIN_RETRY_DELAY_IN_SECS, MAX_RETRY_DELAY_IN_SECS
+            )))
+        },
+    )?;
+    fp_utils::when(
+        delays
+            .clone()
+            .zip(delays.skip(1))
+            .any(|(delay, next_delay)| next_delay < delay),
+        || {
+            Err(invalid_request(
+                "delays must not decrease, including the default delay".to_string(),
+            ))
+        },
+    )?;
+
+    fp_utils::when(request.max_retries < 0, || {
+        Err(invalid_request(
+            "max_retries must not be negative".to_string(),
+        ))
+    })
+}
+
+fn get_retry_schedule_from_request(
+    request: revenue_recovery_api::RevenueRecoveryRetryScheduleRequest,
+) -> process_data::RevenueRecoveryRetrySchedule {
+    process_data::RevenueRecoveryRetrySchedule {
+        name: request.name.trim().to_string(),
+        delays_in_secs: request.delays_in_secs,
+        default_delay_in_secs: request.default_delay_in_secs,
+        max_retries: request.max_retries,
+    }
+}
+
+fn get_retry_schedule_response(
+    merchant_id: id_type::MerchantId,
+    retry_schedule: process_data::RevenueRecoveryRetrySchedule,
+) -> revenue_recovery_api::RevenueRecoveryRetryScheduleResponse {
+    revenue_recovery_api::RevenueRecoveryRetryScheduleResponse {
+        merchant_id,
+        name: retry_schedule.name,
+        delays_in_secs: retry_schedule.delays_in_secs,
+        default_delay_in_secs: retry_schedule.default_delay_in_secs,
+        max_retries: retry_schedule.max_retries,
+    }
+}
+
+fn serialize_retry_schedule(
+    retry_schedule: &process_data::RevenueRecoveryRetrySchedule,
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    serde_json::to_string(retry_schedule)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the revenue recovery retry schedule")
+}
+
+pub async fn create_retry_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: revenue_recovery_api::RevenueRecoveryRetryScheduleRequest,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryRetryScheduleResponse> {
+    validate_retry_schedule(&request)?;
+    let db = &*state.store;
+
+    let existing_retry_schedule = find_retry_schedule(db, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery retry schedule")?;
+    fp_utils::when(existing_retry_schedule.is_some(), || {
+        Err(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Retry schedule already exists for the merchant".to_string(),
+        })
+    })?;
+
+    let retry_schedule = get_retry_schedule_from_request(request);
+    db.insert_config(storage::ConfigNew {
+        key: get_retry_schedule_key(&merchant_id),
+        config: serialize_retry_schedule(&retry_schedule)?,
+    })
+    .await
+    .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+        message: "Retry schedule already exists for the merchant".to_string(),
+    })?;
+
+    logger::info!(
+        ?merchant_id,
+        ?retry_schedule,
+        "Revenue recovery retry schedule created"
+    );
+
+    Ok(ApplicationResponse::Json(get_retry_schedule_response(
+        merchant_id,
+        retry_schedule,
+    )))
+}
+
+pub async fn retrieve_retry_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryRetryScheduleResponse> {
+    let retry_schedule = find_retry_schedule(&*state.store, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery retry schedule")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Retry schedule not found for the merchant".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_retry_schedule_response(
+        merchant_id,
+        retry_schedule,
+    )))
+}
+
+pub async fn update_retry_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: revenue_recovery_api::RevenueRecoveryRetryScheduleRequest,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryRetryScheduleResponse> {
+    validate_retry_schedule(&request)?;
+
+    let retry_schedule = get_retry_schedule_from_request(request);
+    state
+        .store
+        .update_config_by_key(
+            &get_retry_schedule_key(&merchant_id),
+            storage::ConfigUpdate::Update {
+                config: Some(serialize_retry_schedule(&retry_schedule)?),
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Retry schedule not found for the merchant".to_string(),
+        })?;
+
+    logger::info!(
+        ?merchant_id,
+        ?retry_schedule,
+        "Revenue recovery retry schedule updated"
+    );
+
+    Ok(ApplicationResponse::Json(get_retry_schedule_response(
+        merchant_id,
+        retry_schedule,
+    )))
+}
+
+pub async fn delete_retry_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryRetryScheduleDeleteResponse> {
+    state
+        .store
+        .delete_config_by_key(&get_retry_schedule_key(&merchant_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Retry schedule not found for the merchant".to_string(),
+        })?;
+
+    logger::info!(?merchant_id, "Revenue recovery retry schedule deleted");
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RevenueRecoveryRetryScheduleDeleteResponse {
+            merchant_id,
+            deleted: true,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::db::MockDb;
+
+    fn get_request(
+        delays_in_secs: Vec<i32>,
+        default_delay_in_secs: i32,
+    ) -> revenue_recovery_api::RevenueRecoveryRetryScheduleRequest {
+        revenue_recovery_api::RevenueRecoveryRetryScheduleRequest {
+            name: String::from("weekly_backoff"),
+            delays_in_secs,
+            default_delay_in_secs,
+            max_retries: 8,
+        }
+    }
+
+    #[test]
+    fn test_validate_retry_schedule() {
+        let hour = 60 * 60;
+        let day = hour * 24;
+
+        assert!(validate_retry_schedule(&get_request(vec![hour, day, 3 * day], 7 * day)).is_ok());
+        assert!(validate_retry_schedule(&get_request(vec![], 30 * day)).is_ok());
+        // Equal delays keep the schedule monotonic
+        assert!(validate_retry_schedule(&get_request(vec![day, day], day)).is_ok());
+
+        let invalid_requests = [
+            get_request(vec![hour - 1, day], 7 * day),
+            get_request(vec![hour, day], 30 * day + 1),
+            get_request(vec![3 * day, day], 7 * day),
+            get_request(vec![hour, 7 * day], 3 * day),
+            revenue_recovery_api::RevenueRecoveryRetryScheduleRequest {
+                max_retries: -1,
+                ..get_request(vec![hour], day)
+            },
+            revenue_recovery_api::RevenueRecoveryRetryScheduleRequest {
+                name: String::from("  "),
+                ..get_request(vec![hour], day)
+            },
+        ];
+        for request in invalid_requests {
+            assert!(
+                validate_retry_schedule(&request).is_err(),
+                "Retry schedule {request:?} is expected to be invalid"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_retry_schedule_falls_back_when_not_defined() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let merchant_id = id_type::MerchantId::default();
+
+        assert!(get_retry_schedule(&db, &merchant_id).await.is_none());
+
+        let retry_schedule = get_retry_schedule_from_request(get_request(vec![3600], 86400));
+        db.insert_config(storage::ConfigNew {
+            key: get_retry_schedule_key(&merchant_id),
+            config: serialize_retry_schedule(&retry_schedule).expect("serializable schedule"),
+        })
+        .await
+        .expect("retry schedule inserted");
+
+        let stored_retry_schedule = get_retry_schedule(&db, &merchant_id)
+            .await
+            .expect("retry schedule of the merchant");
+        assert_eq!(stored_retry_schedule.delays_in_secs, vec![3600]);
+        assert_eq!(stored_retry_schedule.default_delay_in_secs, 86400);
+
+        // A schedule of another merchant is not used
+        assert!(
+            get_retry_schedule(&db, &id_type::MerchantId::get_merchant_id_not_found())
+                .await
+                .is_none()
+        );
+    }
+}
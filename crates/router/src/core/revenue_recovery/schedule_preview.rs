@@ -0,0 +1,177 @@
+//! Preview of the schedule time of the next retry of a payment, for the merchants tuning their
+//! retry configuration.
+//!
+//! The schedule time is computed the same way as by the calculate workflow, from the retry
+//! configuration, the retry schedule and the delay profiles of the decline classes of the merchant,
+//! and is moved into the retry window of the merchant. The adjustments which depend on the other
+//! retries of the payment method and on the profile of the payment, the exclusion window of the
+//! processor token and the lead time of the retry notification, are not applied. Nothing is
+//! scheduled by the preview.
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_utils::{fp_utils, id_type};
+use hyperswitch_domain_models::api::ApplicationResponse;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse},
+        revenue_recovery::types as pcr_types,
+    },
+    routes::SessionState,
+    types::transformers::ForeignFrom,
+    workflows::revenue_recovery::{self as revenue_recovery_flow, RetrySchedule},
+};
+
+/// Schedule time of the retry after the adjustments made to it while it is scheduled, along with
+/// the adjustments made. The payment being previewed, the time of the failure is taken as the
+/// creation time of the payment for the max recovery window of the merchant.
+fn get_adjusted_schedule_time(
+    retry_schedule: &RetrySchedule,
+    failed_at: time::PrimitiveDateTime,
+) -> (
+    time::PrimitiveDateTime,
+    Vec<common_enums::RecoveryScheduleAdjustment>,
+) {
+    let mut schedule_adjustments = Vec::new();
+    if retry_schedule.decline_class.is_some() {
+        schedule_adjustments.push(common_enums::RecoveryScheduleAdjustment::DeclineClassOverride);
+    }
+
+    let schedule_time = match retry_schedule
+        .retry_window
+        .as_ref()
+        .and_then(|retry_window| {
+            pcr_types::get_schedule_time_in_retry_window(
+                retry_schedule.schedule_time,
+                retry_window,
+                None,
+                failed_at,
+            )
+        }) {
+        Some(adjusted_schedule_time) => {
+            schedule_adjustments
+                .push(common_enums::RecoveryScheduleAdjustment::BusinessHoursWindow);
+            adjusted_schedule_time
+        }
+        None => retry_schedule.schedule_time,
+    };
+
+    (schedule_time, schedule_adjustments)
+}
+
+pub async fn preview_retry_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: revenue_recovery_api::RevenueRecoverySchedulePreviewRequest,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoverySchedulePreviewResponse> {
+    fp_utils::when(request.retry_count == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "retry_count must be greater than 0".to_string(),
+        })
+    })?;
+
+    let failed_at = request
+        .failed_at
+        .unwrap_or_else(common_utils::date_time::now);
+    let error_codes = request
+        .decline_code
+        .as_deref()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let retry_schedule = revenue_recovery_flow::get_schedule_time_for_retry_after_failure(
+        state.store.as_ref(),
+        &merchant_id,
+        i32::from(request.retry_count),
+        request.retry_count,
+        None,
+        state.conf.revenue_recovery.network_retry_limit,
+        &error_codes,
+        failed_at,
+    )
+    .await;
+
+    let response = match retry_schedule {
+        Some(retry_schedule) => {
+            let (schedule_time, schedule_adjustments) =
+                get_adjusted_schedule_time(&retry_schedule, failed_at);
+
+            revenue_recovery_api::RevenueRecoverySchedulePreviewResponse {
+                merchant_id,
+                retry_count: request.retry_count,
+                is_retried: true,
+                schedule_time: Some(schedule_time),
+                schedule_decision: Some(
+                    revenue_recovery_api::RecoveryScheduleDecision::foreign_from(
+                        retry_schedule.get_schedule_decision(schedule_time),
+                    ),
+                ),
+                schedule_adjustments,
+            }
+        }
+        None => revenue_recovery_api::RevenueRecoverySchedulePreviewResponse {
+            merchant_id,
+            retry_count: request.retry_count,
+            is_retried: false,
+            schedule_time: None,
+            schedule_decision: None,
+            schedule_adjustments: Vec::new(),
+        },
+    };
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use scheduler::types::process_data;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_retry_schedule(
+        schedule_time: time::PrimitiveDateTime,
+        decline_class: Option<&str>,
+    ) -> RetrySchedule {
+        RetrySchedule {
+            schedule_time,
+            decline_class: decline_class.map(String::from),
+            retry_window: Some(process_data::RevenueRecoveryRetryWindow {
+                start_hour: 9,
+                end_hour: 20,
+                timezone: String::from("UTC"),
+                card_issuing_country_timezones: std::collections::HashMap::new(),
+                max_recovery_window_in_secs: None,
+            }),
+            algorithm: common_enums::RecoveryScheduleAlgorithm::MerchantRetrySchedule,
+            computed_at: datetime!(2024-01-15 10:00),
+        }
+    }
+
+    #[test]
+    fn test_preview_is_moved_into_the_retry_window() {
+        let retry_schedule = get_retry_schedule(datetime!(2024-01-15 22:00), None);
+
+        assert_eq!(
+            get_adjusted_schedule_time(&retry_schedule, datetime!(2024-01-15 10:00)),
+            (
+                datetime!(2024-01-16 09:00),
+                vec![common_enums::RecoveryScheduleAdjustment::BusinessHoursWindow]
+            )
+        );
+    }
+
+    #[test]
+    fn test_preview_of_decline_class_delay_profile() {
+        let retry_schedule =
+            get_retry_schedule(datetime!(2024-01-15 16:00), Some("insufficient_funds"));
+
+        assert_eq!(
+            get_adjusted_schedule_time(&retry_schedule, datetime!(2024-01-15 10:00)),
+            (
+                datetime!(2024-01-15 16:00),
+                vec![common_enums::RecoveryScheduleAdjustment::DeclineClassOverride]
+            )
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Visibility into whether the recovery retries are executed on time. The lag of each execution of
+//! a recovery task behind its schedule time is recorded by the workflow runner, and the producer of
+//! the scheduler periodically counts the tasks which are yet to be picked up long after their
+//! schedule time.
+
+use std::sync::Arc;
+
+use api_models::process_tracker::revenue_recovery as revenue_recovery_api;
+use common_utils::id_type;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::ApplicationResponse;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    logger,
+    routes::{metrics, AppState, SessionState},
+    types::storage,
+};
+
+/// Number of seconds by which the execution of a task started after its schedule time, a task
+/// picked up ahead of its schedule time having no lag
+fn get_schedule_lag_in_secs(
+    schedule_time: Option<time::PrimitiveDateTime>,
+    execution_started_at: time::PrimitiveDateTime,
+) -> Option<f64> {
+    schedule_time.map(|schedule_time| {
+        (execution_started_at - schedule_time)
+            .max(time::Duration::ZERO)
+            .as_seconds_f64()
+    })
+}
+
+/// Records the lag of the execution of the recovery task behind its schedule time
+pub(crate) fn record_schedule_lag(
+    process: &storage::ProcessTracker,
+    execution_started_at: time::PrimitiveDateTime,
+    merchant_id: &id_type::MerchantId,
+    connector: &str,
+) {
+    let Some(lag_in_secs) = get_schedule_lag_in_secs(process.schedule_time, execution_started_at)
+    else {
+        return;
+    };
+
+    metrics::REVENUE_RECOVERY_TASK_SCHEDULE_LAG.record(
+        lag_in_secs,
+        router_env::metric_attributes!(
+            ("merchant_id", merchant_id.get_string_repr().to_owned()),
+            ("connector", connector.to_owned()),
+        ),
+    );
+}
+
+/// Counts the recovery tasks which have not been picked up although their schedule time is more
+/// than the configured threshold ago, of all the merchants or of the given merchant
+async fn count_overdue_tasks(
+    state: &SessionState,
+    merchant_id: Option<&id_type::MerchantId>,
+) -> RouterResult<i64> {
+    let overdue_threshold = time::Duration::seconds(i64::from(
+        state
+            .conf
+            .revenue_recovery
+            .scheduler_lag
+            .overdue_threshold_in_secs,
+    ));
+
+    state
+        .store
+        .count_overdue_processes_by_runner(
+            storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+            common_utils::date_time::now().saturating_sub(overdue_threshold),
+            merchant_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the overdue recovery tasks")
+}
+
+#[instrument(skip_all)]
+async fn record_overdue_tasks(state: &SessionState) {
+    match count_overdue_tasks(state, None).await {
+        Ok(overdue_count) => metrics::REVENUE_RECOVERY_OVERDUE_TASK_COUNT.record(
+            u64::try_from(overdue_count).unwrap_or_default(),
+            router_env::metric_attributes!((
+                "tenant",
+                state.tenant.tenant_id.get_string_repr().to_owned()
+            )),
+        ),
+        Err(error) => logger::error!(?error, "Failed to record the overdue recovery tasks"),
+    }
+}
+
+/// Counts the overdue recovery tasks of each tenant at the configured interval, for as long as the
+/// scheduler runs. Meant to be run by the producer of the scheduler, so that the tasks are counted
+/// once for all the consumers.
+pub async fn start_overdue_tasks_monitor(state: AppState) {
+    let monitor_interval = std::time::Duration::from_secs(u64::from(
+        state
+            .conf
+            .revenue_recovery
+            .scheduler_lag
+            .monitor_interval_in_secs
+            .max(1),
+    ));
+    let mut interval = tokio::time::interval(monitor_interval);
+    let state = Arc::new(state);
+
+    loop {
+        interval.tick().await;
+
+        for tenant in state.conf.multitenancy.get_tenant_ids() {
+            match Arc::clone(&state).get_session_state(&tenant, None, || {
+                errors::ApiErrorResponse::InvalidTenant {
+                    tenant_id: tenant.get_string_repr().to_owned(),
+                }
+            }) {
+                Ok(session_state) => record_overdue_tasks(&session_state).await,
+                Err(error) => logger::error!(
+                    ?error,
+                    "Failed to count the overdue recovery tasks of the tenant"
+                ),
+            }
+        }
+    }
+}
+
+pub async fn retrieve_overdue_tasks(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryOverdueTasksResponse> {
+    let overdue_count = count_overdue_tasks(&state, Some(&merchant_id)).await?;
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RevenueRecoveryOverdueTasksResponse {
+            merchant_id,
+            overdue_count,
+            overdue_threshold_in_secs: state
+                .conf
+                .revenue_recovery
+                .scheduler_lag
+                .overdue_threshold_in_secs,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_schedule_lag_of_task() {
+        let schedule_time = datetime!(2024-01-15 10:00);
+
+        assert_eq!(
+            get_schedule_lag_in_secs(Some(schedule_time), datetime!(2024-01-15 10:02:30)),
+            Some(150.0)
+        );
+        // Picked up ahead of the schedule time
+        assert_eq!(
+            get_schedule_lag_in_secs(Some(schedule_time), datetime!(2024-01-15 09:59:58)),
+            Some(0.0)
+        );
+        assert_eq!(
+            get_schedule_lag_in_secs(None, datetime!(2024-01-15 10:02:30)),
+            None
+        );
+    }
+}
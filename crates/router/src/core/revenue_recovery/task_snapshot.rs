@@ -0,0 +1,258 @@
+//! Snapshot of a recovery task, which gathers the task, the payment it recovers, the attempts of
+//! the payment and the decisions taken on its webhooks into a single document, to be attached to a
+//! ticket when a recovery is stuck.
+//!
+//! The parts of the snapshot other than the task are fetched independently, and a part which
+//! cannot be fetched is listed as missing instead of failing the snapshot. The tokens of the payment
+//! are replaced by their masked representation, the same as in the logs, so that the snapshot can
+//! be shared.
+
+use api_models::{
+    payments as payments_api,
+    process_tracker::{self as process_tracker_api, revenue_recovery as revenue_recovery_api},
+};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    api::ApplicationResponse, payments::PaymentIntent, ApiModelToDieselModelConvertor,
+};
+use masking::Secret;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    logger,
+    routes::SessionState,
+    types::{domain, storage, transformers::ForeignFrom},
+};
+
+/// Number of the latest decisions of the payment included in the snapshot
+const SNAPSHOT_DECISIONS_LIMIT: i64 = 20;
+
+pub async fn retrieve_task_snapshot(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    task_id: String,
+) -> RouterResponse<revenue_recovery_api::RevenueRecoveryTaskSnapshotResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let merchant_id = merchant_account.get_id().clone();
+    let generated_at = common_utils::date_time::now();
+    let task_not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Task `{task_id}` not found for the merchant"),
+    };
+
+    let process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the revenue recovery task")?
+        .filter(|process| process.runner.as_deref() == Some(runner.to_string().as_str()))
+        .ok_or_else(task_not_found_error)?;
+    let task = revenue_recovery_api::RecoveryTaskResponse::foreign_from((process, generated_at));
+    let payment_id = get_task_payment_id(&task.task.tracking_data, &merchant_id)
+        .cloned()
+        .ok_or_else(task_not_found_error)?;
+
+    let mut missing_parts = Vec::new();
+
+    let payment = get_snapshot_part(
+        db.find_payment_intent_by_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await,
+        revenue_recovery_api::RevenueRecoveryTaskSnapshotPart::Payment,
+        &mut missing_parts,
+    )
+    .map(get_payment_snapshot);
+
+    let mut attempts = get_snapshot_part(
+        db.find_payment_attempts_by_payment_intent_id(
+            key_manager_state,
+            &payment_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await,
+        revenue_recovery_api::RevenueRecoveryTaskSnapshotPart::Attempts,
+        &mut missing_parts,
+    )
+    .unwrap_or_default()
+    .iter()
+    .map(payments_api::PaymentAttemptResponse::foreign_from)
+    .map(redact_attempt)
+    .collect::<Vec<_>>();
+    attempts.sort_by_key(|attempt| attempt.created_at);
+
+    let decisions = get_snapshot_part(
+        db.list_revenue_recovery_decisions_by_payment_id(&payment_id, SNAPSHOT_DECISIONS_LIMIT, 0)
+            .await,
+        revenue_recovery_api::RevenueRecoveryTaskSnapshotPart::Decisions,
+        &mut missing_parts,
+    )
+    .unwrap_or_default()
+    .into_iter()
+    .map(ForeignFrom::foreign_from)
+    .collect();
+
+    Ok(ApplicationResponse::Json(
+        revenue_recovery_api::RevenueRecoveryTaskSnapshotResponse {
+            merchant_id,
+            task,
+            payment,
+            attempts,
+            decisions,
+            missing_parts,
+            generated_at,
+        },
+    ))
+}
+
+/// The payment recovered by the task, if the task belongs to the merchant. The tasks whose
+/// tracking data could not be read are not taken to belong to any merchant.
+fn get_task_payment_id<'a>(
+    tracking_data: &'a process_tracker_api::ProcessTaskTrackingData,
+    merchant_id: &id_type::MerchantId,
+) -> Option<&'a id_type::GlobalPaymentId> {
+    match tracking_data {
+        process_tracker_api::ProcessTaskTrackingData::RevenueRecovery(tracking_data) => {
+            (tracking_data.merchant_id == *merchant_id).then_some(&tracking_data.payment_id)
+        }
+        process_tracker_api::ProcessTaskTrackingData::RevenueRecoveryUnresolvedConnector(
+            tracking_data,
+        ) => (tracking_data.merchant_id == *merchant_id).then_some(&tracking_data.payment_id),
+        process_tracker_api::ProcessTaskTrackingData::Redacted => None,
+    }
+}
+
+fn get_snapshot_part<T, E: std::fmt::Debug>(
+    result: Result<T, E>,
+    part: revenue_recovery_api::RevenueRecoveryTaskSnapshotPart,
+    missing_parts: &mut Vec<revenue_recovery_api::RevenueRecoveryTaskSnapshotPart>,
+) -> Option<T> {
+    result
+        .inspect_err(|error| {
+            logger::warn!(?error, ?part, "Failed to fetch a part of the task snapshot");
+            missing_parts.push(part);
+        })
+        .ok()
+}
+
+fn get_payment_snapshot(
+    payment_intent: PaymentIntent,
+) -> revenue_recovery_api::RevenueRecoveryPaymentSnapshot {
+    let recovery_metadata = payment_intent
+        .feature_metadata
+        .and_then(|feature_metadata| feature_metadata.payment_revenue_recovery_metadata)
+        .map(|recovery_metadata| redact_recovery_metadata(recovery_metadata.convert_back()));
+
+    revenue_recovery_api::RevenueRecoveryPaymentSnapshot {
+        payment_id: payment_intent.id,
+        status: payment_intent.status,
+        amount: payment_intent.amount_details.order_amount,
+        currency: payment_intent.amount_details.currency,
+        merchant_reference_id: payment_intent.merchant_reference_id,
+        active_attempt_id: payment_intent.active_attempt_id,
+        recovery_metadata,
+        created_at: payment_intent.created_at,
+        modified_at: payment_intent.modified_at,
+    }
+}
+
+/// Masked representation of a sensitive value, as it appears in the logs
+pub(super) fn mask<T>(value: T) -> String {
+    format!("{:?}", Secret::<T>::new(value))
+}
+
+fn redact_recovery_metadata(
+    recovery_metadata: payments_api::PaymentRevenueRecoveryMetadata,
+) -> payments_api::PaymentRevenueRecoveryMetadata {
+    payments_api::PaymentRevenueRecoveryMetadata {
+        billing_connector_payment_details: payments_api::BillingConnectorPaymentDetails {
+            payment_processor_token: mask(
+                recovery_metadata
+                    .billing_connector_payment_details
+                    .payment_processor_token,
+            ),
+            connector_customer_id: recovery_metadata
+                .billing_connector_payment_details
+                .connector_customer_id,
+            card_details: recovery_metadata
+                .billing_connector_payment_details
+                .card_details,
+        },
+        ..recovery_metadata
+    }
+}
+
+fn redact_attempt(
+    attempt: payments_api::PaymentAttemptResponse,
+) -> payments_api::PaymentAttemptResponse {
+    payments_api::PaymentAttemptResponse {
+        payment_token: attempt.payment_token.map(mask),
+        connector_metadata: attempt
+            .connector_metadata
+            .map(|metadata| Secret::new(serde_json::Value::String(format!("{metadata:?}")))),
+        ..attempt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_task_of_another_merchant_is_not_found() {
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+        let other_merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_2")).unwrap();
+        let cell_id = id_type::CellId::from_string("defid").unwrap();
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let tracking_data =
+            process_tracker_api::ProcessTaskTrackingData::RevenueRecoveryUnresolvedConnector(
+                revenue_recovery_api::RecoveryUnresolvedConnectorTrackingData {
+                    merchant_id: merchant_id.clone(),
+                    profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("profile_1"))
+                        .unwrap(),
+                    payment_id: payment_id.clone(),
+                    billing_connector_id: id_type::MerchantConnectorAccountId::wrap(String::from(
+                        "mca_1",
+                    ))
+                    .unwrap(),
+                    connector_account_reference_id: String::from("acct_1"),
+                    recovery_correlation_id: String::from("rrd_1"),
+                },
+            );
+
+        assert_eq!(
+            get_task_payment_id(&tracking_data, &merchant_id),
+            Some(&payment_id)
+        );
+        assert_eq!(
+            get_task_payment_id(&tracking_data, &other_merchant_id),
+            None
+        );
+        assert_eq!(
+            get_task_payment_id(
+                &process_tracker_api::ProcessTaskTrackingData::Redacted,
+                &merchant_id
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_tokens_are_masked() {
+        let masked_token = mask(String::from("tok_4242424242424242"));
+
+        assert!(!masked_token.contains("4242"));
+        assert!(masked_token.starts_with("***"));
+    }
+}
@@ -1,6 +1,13 @@
+use api_models::process_tracker::{self as process_tracker_api, revenue_recovery};
 use common_enums::AttemptStatus;
+use common_utils::ext_traits::ValueExt;
+use time::PrimitiveDateTime;
 
-use crate::{core::revenue_recovery::types::PcrAttemptStatus, types::transformers::ForeignFrom};
+use crate::{
+    core::revenue_recovery::types::PcrAttemptStatus,
+    logger,
+    types::{storage, transformers::ForeignFrom},
+};
 
 impl ForeignFrom<AttemptStatus> for PcrAttemptStatus {
     fn foreign_from(s: AttemptStatus) -> Self {
@@ -35,3 +42,189 @@ impl ForeignFrom<AttemptStatus> for PcrAttemptStatus {
         }
     }
 }
+
+impl ForeignFrom<storage::revenue_recovery::RevenueRecoveryDecision>
+    for api_models::process_tracker::revenue_recovery::RevenueRecoveryDecisionResponse
+{
+    fn foreign_from(decision: storage::revenue_recovery::RevenueRecoveryDecision) -> Self {
+        Self {
+            id: decision.id,
+            event_type: decision.event_type,
+            recovery_action: decision.recovery_action,
+            attempt_triggered_by: decision.attempt_triggered_by,
+            retry_count: decision
+                .retry_count
+                .and_then(|count| u16::try_from(count).ok()),
+            retry_threshold: decision
+                .retry_threshold
+                .and_then(|threshold| u16::try_from(threshold).ok()),
+            is_billing_connector_payment_sync_called: decision
+                .is_billing_connector_payment_sync_called,
+            webhook_action: decision.webhook_action,
+            task_id: decision.task_id,
+            error: decision.error,
+            created_at: decision.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::revenue_recovery::RevenueRecoveryCollectionItem>
+    for api_models::process_tracker::revenue_recovery::RevenueRecoveryCollectionItemResponse
+{
+    fn foreign_from(item: storage::revenue_recovery::RevenueRecoveryCollectionItem) -> Self {
+        Self {
+            id: item.id,
+            payment_id: item.payment_id,
+            merchant_reference_id: item.merchant_reference_id,
+            amount_outstanding: item.amount_outstanding,
+            currency: item.currency,
+            last_error_code: item.last_error_code,
+            last_failure_reason: item.last_failure_reason,
+            customer_id: item.customer_id,
+            status: item.status,
+            assignee: item.assignee,
+            assigned_by: item.assigned_by,
+            outcome: item.outcome,
+            resolved_by: item.resolved_by,
+            resolved_at: item.resolved_at,
+            created_at: item.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::ProcessTracker> for process_tracker_api::ProcessTaskResponse {
+    fn foreign_from(process: storage::ProcessTracker) -> Self {
+        let tracking_data = get_process_task_tracking_data(&process);
+        Self {
+            id: process.id,
+            name: process.name,
+            tag: process.tag,
+            runner: process.runner,
+            retry_count: process.retry_count,
+            schedule_time: process.schedule_time,
+            status: process.status,
+            business_status: process.business_status,
+            created_at: process.created_at,
+            updated_at: process.updated_at,
+            tracking_data,
+        }
+    }
+}
+
+impl ForeignFrom<(storage::ProcessTracker, PrimitiveDateTime)>
+    for revenue_recovery::RecoveryTaskResponse
+{
+    fn foreign_from((process, current_time): (storage::ProcessTracker, PrimitiveDateTime)) -> Self {
+        // Tasks which are picked up by the producer are no longer waiting in the queue
+        let is_overdue = matches!(
+            process.status,
+            common_enums::ProcessTrackerStatus::New | common_enums::ProcessTrackerStatus::Pending
+        ) && process
+            .schedule_time
+            .is_some_and(|schedule_time| schedule_time < current_time);
+
+        Self {
+            task: process_tracker_api::ProcessTaskResponse::foreign_from(process),
+            is_overdue,
+        }
+    }
+}
+
+impl ForeignFrom<storage::revenue_recovery::PcrWorkflowTrackingData>
+    for revenue_recovery::RecoveryTaskTrackingData
+{
+    fn foreign_from(tracking_data: storage::revenue_recovery::PcrWorkflowTrackingData) -> Self {
+        Self {
+            merchant_id: tracking_data.merchant_id,
+            profile_id: tracking_data.profile_id,
+            payment_id: tracking_data.global_payment_id,
+            payment_attempt_id: tracking_data.payment_attempt_id,
+            billing_connector_id: tracking_data.billing_mca_id,
+            schedule_adjustments: tracking_data.schedule_adjustments,
+            recovery_correlation_id: tracking_data.recovery_correlation_id,
+            decline_class: tracking_data.decline_class,
+            execution_failure_count: tracking_data.execution_failure_count,
+            last_execution_error: tracking_data.last_execution_error,
+            schedule_decision: tracking_data
+                .schedule_decision
+                .map(revenue_recovery::RecoveryScheduleDecision::foreign_from),
+        }
+    }
+}
+
+impl ForeignFrom<storage::revenue_recovery::PcrUnresolvedConnectorTrackingData>
+    for revenue_recovery::RecoveryUnresolvedConnectorTrackingData
+{
+    fn foreign_from(
+        tracking_data: storage::revenue_recovery::PcrUnresolvedConnectorTrackingData,
+    ) -> Self {
+        Self {
+            merchant_id: tracking_data.merchant_id,
+            profile_id: tracking_data.profile_id,
+            payment_id: tracking_data.global_payment_id,
+            billing_connector_id: tracking_data.billing_mca_id,
+            connector_account_reference_id: tracking_data.connector_account_reference_id,
+            recovery_correlation_id: tracking_data.recovery_correlation_id,
+        }
+    }
+}
+
+impl ForeignFrom<storage::revenue_recovery::RecoveryScheduleDecision>
+    for revenue_recovery::RecoveryScheduleDecision
+{
+    fn foreign_from(decision: storage::revenue_recovery::RecoveryScheduleDecision) -> Self {
+        Self {
+            algorithm: decision.algorithm,
+            base_delay_in_secs: decision.base_delay_in_secs,
+            decline_class: decision.decline_class,
+            window_shift_in_secs: decision.window_shift_in_secs,
+            computed_at: decision.computed_at,
+        }
+    }
+}
+
+/// Reads the tracking data of the tasks of revenue recovery, falling back to redacting the
+/// tracking data which cannot be read instead of failing the request
+fn get_process_task_tracking_data(
+    process: &storage::ProcessTracker,
+) -> process_tracker_api::ProcessTaskTrackingData {
+    let recovery_runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow.to_string();
+    if process.runner.as_deref() != Some(recovery_runner.as_str()) {
+        return process_tracker_api::ProcessTaskTrackingData::Redacted;
+    }
+
+    let tracking_data = if process.name.as_deref()
+        == Some(super::connector_resolution::UNRESOLVED_CONNECTOR_WORKFLOW)
+    {
+        process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::revenue_recovery::PcrUnresolvedConnectorTrackingData>(
+                "PcrUnresolvedConnectorTrackingData",
+            )
+            .map(|tracking_data| {
+                process_tracker_api::ProcessTaskTrackingData::RevenueRecoveryUnresolvedConnector(
+                    revenue_recovery::RecoveryUnresolvedConnectorTrackingData::foreign_from(
+                        tracking_data,
+                    ),
+                )
+            })
+    } else {
+        process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::revenue_recovery::PcrWorkflowTrackingData>(
+                "PcrWorkflowTrackingData",
+            )
+            .map(|tracking_data| {
+                process_tracker_api::ProcessTaskTrackingData::RevenueRecovery(
+                    revenue_recovery::RecoveryTaskTrackingData::foreign_from(tracking_data),
+                )
+            })
+    };
+
+    tracking_data.unwrap_or_else(|error| {
+            logger::error!(task_id = %process.id, ?error, "Failed to read the tracking data of the task");
+            process_tracker_api::ProcessTaskTrackingData::Redacted
+        })
+}
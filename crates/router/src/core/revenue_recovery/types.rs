@@ -5,8 +5,9 @@ use api_models::{
     mandates::RecurringDetails,
     payments::{
         AmountDetails, FeatureMetadata, PaymentRevenueRecoveryMetadata,
-        PaymentsUpdateIntentRequest, ProxyPaymentsRequest,
+        PaymentsUpdateIntentRequest, ProxyPaymentsRequest, RevenueRecoveryRetryOverride,
     },
+    process_tracker::revenue_recovery as revenue_recovery_api,
 };
 use common_utils::{
     self,
@@ -27,26 +28,60 @@ use hyperswitch_domain_models::{
     router_response_types::revenue_recovery as revenue_recovery_response,
     ApiModelToDieselModelConvertor,
 };
+use scheduler::types::process_data;
+use strum::IntoEnumIterator;
 use time::PrimitiveDateTime;
 
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult},
         payments::{self, helpers, operations::Operation},
-        revenue_recovery::{self as core_pcr},
+        revenue_recovery::{self as core_pcr, billing_connector_proxy},
     },
     db::StorageInterface,
     logger,
-    routes::SessionState,
+    routes::{metrics, SessionState},
     services::{self, connector_integration_interface::RouterDataConversion},
     types::{
         self, api as api_types, api::payments as payments_types, storage, transformers::ForeignInto,
     },
-    workflows::revenue_recovery::get_schedule_time_to_retry_mit_payments,
+    utils::timezone,
+    workflows::revenue_recovery::get_schedule_time_for_next_retry,
 };
 
 type RecoveryResult<T> = error_stack::Result<T, errors::RecoveryError>;
 
+/// The terminal outcome of an internally triggered recovery retry
+#[derive(Clone, Debug)]
+pub struct RetryOutcome {
+    pub is_successful: bool,
+    pub schedule_adjustments: Vec<enums::RecoveryScheduleAdjustment>,
+    /// Payment connector through which the retry was made
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    pub connector: Option<String>,
+    /// Position of the retry among the internally triggered retries of the payment, starting at 1
+    pub retry_number: u16,
+    /// Decline class of the attempt of the payment preceding the retry
+    pub decline_class: Option<String>,
+    pub card_network: Option<enums::CardNetwork>,
+}
+
+impl RetryOutcome {
+    /// Whether the retry is to be considered for the analytics requested with these filters
+    pub fn matches_filters(
+        &self,
+        decline_class: Option<&str>,
+        card_network: Option<&enums::CardNetwork>,
+    ) -> bool {
+        decline_class.map_or(true, |decline_class| {
+            self.decline_class.as_deref() == Some(decline_class)
+        }) && card_network.map_or(true, |card_network| {
+            self.card_network.as_ref() == Some(card_network)
+        })
+    }
+}
+
 /// The status of Passive Churn Payments
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub enum PcrAttemptStatus {
@@ -94,6 +129,9 @@ impl PcrAttemptStatus {
 pub enum Decision {
     Execute,
     Psync(enums::AttemptStatus, id_type::GlobalAttemptId),
+    /// The previous retry was left pending by an error response of the payment connector, such as
+    /// a timeout, the attempt is synced so that the payment is not charged twice
+    ResolvePendingAttempt(payment_attempt::PaymentAttempt),
     InvalidDecision,
 }
 
@@ -128,6 +166,22 @@ impl Decision {
                     .attach_printable("Error while executing the Psync call")?;
                 Self::Psync(payment_attempt.status, payment_attempt.get_id().clone())
             }
+            (
+                enums::IntentStatus::Processing,
+                enums::PaymentConnectorTransmission::ConnectorCallUnsuccessful,
+                Some(_),
+            ) => {
+                let psync_data = core_pcr::call_psync_api(state, payment_id, pcr_data)
+                    .await
+                    .change_context(errors::RecoveryError::PaymentCallFailed)
+                    .attach_printable("Error while syncing the pending retry attempt")?;
+                let payment_attempt = psync_data
+                    .payment_attempt
+                    .get_required_value("Payment Attempt")
+                    .change_context(errors::RecoveryError::ValueNotFound)
+                    .attach_printable("Error while syncing the pending retry attempt")?;
+                Self::ResolvePendingAttempt(payment_attempt)
+            }
             _ => Self::InvalidDecision,
         })
     }
@@ -136,11 +190,21 @@ impl Decision {
 #[derive(Debug, Clone)]
 pub enum Action {
     SyncPayment(id_type::GlobalAttemptId),
-    RetryPayment(PrimitiveDateTime),
+    RetryPayment(
+        PrimitiveDateTime,
+        storage::revenue_recovery::RecoveryScheduleDecision,
+    ),
     TerminalFailure(payment_attempt::PaymentAttempt),
     SuccessfulPayment(payment_attempt::PaymentAttempt),
-    ReviewPayment,
     ManualReviewAction,
+    /// The retry timed out at the payment connector, the task is picked up again after a short
+    /// delay to sync the attempt before the payment is retried
+    RequeuePayment(PrimitiveDateTime),
+    /// The processor payment method token to be charged is not available, the task is moved to
+    /// review until the token is received
+    MissingPaymentToken,
+    /// The invoice has been closed while the retry was being executed, the retry is aborted
+    CancelledPayment,
 }
 impl Action {
     pub async fn execute_payment(
@@ -148,48 +212,172 @@ impl Action {
         merchant_id: &id_type::MerchantId,
         payment_intent: &PaymentIntent,
         process: &storage::ProcessTracker,
+        tracking_data: &storage::revenue_recovery::PcrWorkflowTrackingData,
         pcr_data: &storage::revenue_recovery::PcrPaymentData,
         revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
     ) -> RecoveryResult<Self> {
         let db = &*state.store;
+
+        if !has_processor_payment_token(revenue_recovery_metadata) {
+            logger::warn!(
+                payment_id = ?payment_intent.id,
+                "Processor payment method token of the recovery payment not found"
+            );
+            return Ok(Self::MissingPaymentToken);
+        }
+
+        if core_pcr::cancellation::is_recovery_cancelled(state, &payment_intent.id).await {
+            return Ok(Self::CancelledPayment);
+        }
+
         let response =
             call_proxy_api(state, payment_intent, pcr_data, revenue_recovery_metadata).await;
         // handle proxy api's response
         match response {
-            Ok(payment_data) => match payment_data.payment_attempt.status.foreign_into() {
-                PcrAttemptStatus::Succeeded => Ok(Self::SuccessfulPayment(
-                    payment_data.payment_attempt.clone(),
-                )),
-                PcrAttemptStatus::Failed => {
-                    Self::decide_retry_failure_action(
-                        db,
-                        merchant_id,
-                        process.clone(),
-                        &payment_data.payment_attempt,
-                    )
-                    .await
-                }
+            Ok(payment_data) => {
+                let pcr_attempt_status: PcrAttemptStatus =
+                    payment_data.payment_attempt.status.foreign_into();
+                record_retry_outcome_metric(
+                    &pcr_attempt_status,
+                    &tracking_data.schedule_adjustments,
+                );
 
-                PcrAttemptStatus::Processing => {
-                    Ok(Self::SyncPayment(payment_data.payment_attempt.id.clone()))
-                }
-                PcrAttemptStatus::InvalidStatus(action) => {
-                    logger::info!(?action, "Invalid Payment Status For PCR Payment");
-                    Ok(Self::ManualReviewAction)
-                }
-            },
-            Err(err) =>
-            // check for an active attempt being constructed or not
-            {
-                logger::error!(execute_payment_res=?err);
-                match payment_intent.active_attempt_id.clone() {
-                    Some(attempt_id) => Ok(Self::SyncPayment(attempt_id)),
-                    None => Ok(Self::ReviewPayment),
+                // persist the schedule trace onto the attempt, so that the effect of the
+                // adjustments can be evaluated later using the attempt metadata, and the attempt
+                // can be traced back to the webhook which scheduled it
+                let payment_attempt = update_schedule_trace_on_attempt(
+                    state,
+                    pcr_data,
+                    payment_data.payment_attempt,
+                    tracking_data,
+                    get_active_connector_switch(revenue_recovery_metadata),
+                )
+                .await?;
+
+                if is_connector_timeout(
+                    payment_attempt.status,
+                    payment_attempt
+                        .error
+                        .as_ref()
+                        .map(|error| error.code.as_str()),
+                ) {
+                    let requeue_delay = time::Duration::seconds(i64::from(
+                        state
+                            .conf
+                            .revenue_recovery
+                            .connector_timeout_requeue_delay_in_secs,
+                    ));
+                    logger::warn!(
+                        payment_id = ?payment_intent.id,
+                        attempt_id = ?payment_attempt.id,
+                        "Recovery retry timed out at the payment connector"
+                    );
+                    return Ok(Self::RequeuePayment(
+                        common_utils::date_time::now().saturating_add(requeue_delay),
+                    ));
                 }
+
+                Self::decide_action_for_proxy_response(
+                    db,
+                    merchant_id,
+                    process,
+                    pcr_attempt_status,
+                    payment_attempt,
+                    revenue_recovery_metadata.retry_override.as_ref(),
+                    state.conf.revenue_recovery.network_retry_limit,
+                )
+                .await
             }
+            // The failures of the retry itself are accounted by the error handler of the
+            // workflow, which moves the task to review if they keep failing
+            Err(err) => Err(err
+                .change_context(errors::RecoveryError::PaymentCallFailed)
+                .attach_printable("Failed to make the recovery retry through the proxy api")),
         }
     }
 
+    /// Decides the action for the attempt of a previous retry which was left pending, once the
+    /// attempt has been synced with the payment connector
+    pub async fn resolve_pending_attempt(
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        process: &storage::ProcessTracker,
+        payment_attempt: payment_attempt::PaymentAttempt,
+        revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
+    ) -> RecoveryResult<Self> {
+        let pcr_attempt_status: PcrAttemptStatus = payment_attempt.status.foreign_into();
+        logger::info!(
+            attempt_id = ?payment_attempt.id,
+            status = ?payment_attempt.status,
+            "Synced the pending recovery retry attempt"
+        );
+
+        Self::decide_action_for_proxy_response(
+            &*state.store,
+            merchant_id,
+            process,
+            pcr_attempt_status,
+            payment_attempt,
+            revenue_recovery_metadata.retry_override.as_ref(),
+            state.conf.revenue_recovery.network_retry_limit,
+        )
+        .await
+    }
+
+    async fn decide_action_for_proxy_response(
+        db: &dyn StorageInterface,
+        merchant_id: &id_type::MerchantId,
+        process: &storage::ProcessTracker,
+        pcr_attempt_status: PcrAttemptStatus,
+        payment_attempt: payment_attempt::PaymentAttempt,
+        retry_override: Option<&RevenueRecoveryRetryOverride>,
+        network_retry_limit: u16,
+    ) -> RecoveryResult<Self> {
+        match pcr_attempt_status {
+            PcrAttemptStatus::Succeeded => Ok(Self::SuccessfulPayment(payment_attempt)),
+            PcrAttemptStatus::Failed => {
+                Self::decide_retry_failure_action(
+                    db,
+                    merchant_id,
+                    process.clone(),
+                    &payment_attempt,
+                    retry_override,
+                    network_retry_limit,
+                )
+                .await
+            }
+
+            PcrAttemptStatus::Processing => Ok(Self::SyncPayment(payment_attempt.id.clone())),
+            PcrAttemptStatus::InvalidStatus(action) => {
+                logger::info!(?action, "Invalid Payment Status For PCR Payment");
+                Ok(Self::ManualReviewAction)
+            }
+        }
+    }
+
+    /// Aborts the retry if the invoice has been closed while the retry was being made, so that its
+    /// outcome is neither recorded nor followed by another retry
+    pub async fn abort_if_recovery_cancelled(
+        self,
+        state: &SessionState,
+        payment_id: &id_type::GlobalPaymentId,
+    ) -> Self {
+        if matches!(self, Self::CancelledPayment)
+            || !core_pcr::cancellation::is_recovery_cancelled(state, payment_id).await
+        {
+            return self;
+        }
+
+        if let Self::SuccessfulPayment(payment_attempt) = &self {
+            logger::error!(
+                ?payment_id,
+                attempt_id = ?payment_attempt.id,
+                "Recovery retry succeeded after the invoice was closed"
+            );
+        }
+        Self::CancelledPayment
+    }
+
     pub async fn execute_payment_task_response_handler(
         &self,
         state: &SessionState,
@@ -203,12 +391,13 @@ impl Action {
         match self {
             Self::SyncPayment(attempt_id) => {
                 core_pcr::insert_psync_pcr_task(
-                    billing_mca.get_id().clone(),
+                    billing_mca,
                     db,
                     pcr_data.merchant_account.get_id().to_owned(),
                     payment_intent.id.clone(),
                     pcr_data.profile.get_id().to_owned(),
                     attempt_id.clone(),
+                    revenue_recovery_metadata.recovery_correlation_id.clone(),
                     storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
                 )
                 .await
@@ -226,10 +415,100 @@ impl Action {
                 Ok(())
             }
 
-            Self::RetryPayment(schedule_time) => {
-                db.as_scheduler()
-                    .retry_process(execute_task_process.clone(), *schedule_time)
+            Self::RetryPayment(schedule_time, schedule_decision) => {
+                let mut tracking_data = execute_task_process
+                    .tracking_data
+                    .clone()
+                    .parse_value::<storage::revenue_recovery::PcrWorkflowTrackingData>(
+                    "PcrWorkflowTrackingData",
+                )?;
+                let mut schedule_decision = schedule_decision.clone();
+
+                let retry_notification =
+                    core_pcr::retry_notification::get_enabled_retry_notification(&pcr_data.profile);
+                let schedule_time = match retry_notification {
+                    Some(retry_notification) => {
+                        let adjusted_schedule_time =
+                            core_pcr::retry_notification::get_schedule_time_after_lead_time(
+                                *schedule_time,
+                                retry_notification,
+                                common_utils::date_time::now(),
+                            );
+                        if adjusted_schedule_time != *schedule_time {
+                            tracking_data
+                                .schedule_adjustments
+                                .push(enums::RecoveryScheduleAdjustment::RetryNotificationLeadTime);
+                            schedule_decision.window_shift_in_secs +=
+                                (adjusted_schedule_time - *schedule_time).whole_seconds();
+                        }
+                        adjusted_schedule_time
+                    }
+                    None => *schedule_time,
+                };
+
+                let schedule_time =
+                    match core_pcr::blackout_calendar::get_blackout_calendar(&pcr_data.profile)
+                        .and_then(|blackout_calendar| {
+                            core_pcr::blackout_calendar::get_schedule_time_after_blackouts(
+                                schedule_time,
+                                blackout_calendar,
+                            )
+                        }) {
+                        Some(adjusted_schedule_time) => {
+                            tracking_data
+                                .schedule_adjustments
+                                .push(enums::RecoveryScheduleAdjustment::BlackoutCalendar);
+                            schedule_decision.window_shift_in_secs +=
+                                (adjusted_schedule_time - schedule_time).whole_seconds();
+                            adjusted_schedule_time
+                        }
+                        None => schedule_time,
+                    };
+                tracking_data.schedule_decision = Some(schedule_decision);
+
+                // The task is retried along with the decision of its next schedule time
+                let updated_execute_task_process = db
+                    .as_scheduler()
+                    .update_process(
+                        execute_task_process.clone(),
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(execute_task_process.retry_count + 1),
+                            schedule_time: Some(schedule_time),
+                            tracking_data: Some(
+                                serde_json::to_value(tracking_data)
+                                    .change_context(errors::RecoveryError::ProcessTrackerFailure)
+                                    .attach_printable(
+                                        "Failed to serialize the execute workflow tracking data",
+                                    )?,
+                            ),
+                            business_status: None,
+                            status: Some(enums::ProcessTrackerStatus::Pending),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+
+                if let Some(retry_notification) = retry_notification {
+                    core_pcr::retry_notification::schedule_retry_notification(
+                        db,
+                        retry_notification,
+                        &updated_execute_task_process,
+                    )
+                    .await?;
+                }
+
+                if let Some(connector_fallback) =
+                    &pcr_data.profile.revenue_recovery_connector_fallback
+                {
+                    switch_connector_on_soft_decline(
+                        state,
+                        pcr_data,
+                        revenue_recovery_metadata,
+                        connector_fallback,
+                    )
                     .await?;
+                }
 
                 // update the connector payment transmission field to Unsuccessful and unset active attempt id
                 revenue_recovery_metadata.set_payment_transmission_field_for_api_request(
@@ -261,23 +540,28 @@ impl Action {
                 db.as_scheduler()
                     .finish_process_with_business_status(
                         execute_task_process.clone(),
-                        business_status::EXECUTE_WORKFLOW_COMPLETE,
+                        business_status::EXECUTE_WORKFLOW_RETRIES_EXHAUSTED,
                     )
                     .await
                     .change_context(errors::RecoveryError::ProcessTrackerFailure)
                     .attach_printable("Failed to update the process tracker")?;
-                // Record back to billing connector for terminal status
-                // TODO: Add support for retrying failed outgoing recordback webhooks
-                self.record_back_to_billing_connector(
+                // The retries are exhausted, the invoice is now to be collected by the merchant
+                core_pcr::hand_off_to_manual_collection(
                     state,
+                    pcr_data,
+                    payment_intent,
                     payment_attempt,
+                )
+                .await?;
+                core_pcr::record_back::record_back_or_schedule_retry(
+                    state,
+                    execute_task_process,
+                    pcr_data,
                     payment_intent,
+                    payment_attempt,
                     billing_mca,
                 )
                 .await
-                .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)
-                .attach_printable("Failed to record back the billing connector")?;
-                Ok(())
             }
             Self::SuccessfulPayment(payment_attempt) => {
                 db.as_scheduler()
@@ -288,21 +572,64 @@ impl Action {
                     .await
                     .change_context(errors::RecoveryError::ProcessTrackerFailure)
                     .attach_printable("Failed to update the process tracker")?;
-                // Record back to billing connector for terminal status
-                // TODO: Add support for retrying failed outgoing recordback webhooks
-                self.record_back_to_billing_connector(
+                core_pcr::record_back::record_back_or_schedule_retry(
                     state,
-                    payment_attempt,
+                    execute_task_process,
+                    pcr_data,
                     payment_intent,
+                    payment_attempt,
                     billing_mca,
                 )
                 .await
-                .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)
-                .attach_printable("Failed to update the process tracker")?;
-                Ok(())
             }
 
-            Self::ReviewPayment => Ok(()),
+            Self::RequeuePayment(schedule_time) => {
+                // The intent is left as is, so that the pending attempt is synced when the task is
+                // picked up again
+                db.as_scheduler()
+                    .update_process(
+                        execute_task_process.clone(),
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: None,
+                            schedule_time: Some(*schedule_time),
+                            tracking_data: None,
+                            business_status: Some(String::from(
+                                business_status::EXECUTE_WORKFLOW_REQUEUED_FOR_CONNECTOR_TIMEOUT,
+                            )),
+                            status: Some(enums::ProcessTrackerStatus::Pending),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+                Ok(())
+            }
+            Self::MissingPaymentToken => {
+                let execution_failure_count = execute_task_process
+                    .tracking_data
+                    .clone()
+                    .parse_value::<storage::revenue_recovery::PcrWorkflowTrackingData>(
+                        "PcrWorkflowTrackingData",
+                    )?
+                    .execution_failure_count;
+                core_pcr::dead_letter::move_task_to_review(
+                    state,
+                    execute_task_process.clone(),
+                    execution_failure_count,
+                    String::from("Processor payment method token not found"),
+                )
+                .await
+            }
+            Self::CancelledPayment => {
+                core_pcr::cancellation::abort_cancelled_execution(
+                    state,
+                    execute_task_process.clone(),
+                    pcr_data,
+                    &payment_intent.id,
+                    revenue_recovery_metadata.total_retry_count,
+                )
+                .await
+            }
             Self::ManualReviewAction => {
                 logger::debug!("Invalid Payment Status For PCR Payment");
                 let pt_update = storage::ProcessTrackerUpdate::StatusUpdate {
@@ -318,120 +645,226 @@ impl Action {
         }
     }
 
-    async fn record_back_to_billing_connector(
-        &self,
-        state: &SessionState,
+    pub(crate) async fn decide_retry_failure_action(
+        db: &dyn StorageInterface,
+        merchant_id: &id_type::MerchantId,
+        pt: storage::ProcessTracker,
         payment_attempt: &payment_attempt::PaymentAttempt,
-        payment_intent: &PaymentIntent,
-        billing_mca: &merchant_connector_account::MerchantConnectorAccount,
-    ) -> RecoveryResult<()> {
-        let connector_name = billing_mca.connector_name.to_string();
-        let connector_data = api_types::ConnectorData::get_connector_by_name(
-            &state.conf.connectors,
-            &connector_name,
-            api_types::GetToken::Connector,
-            Some(billing_mca.get_id()),
+        retry_override: Option<&RevenueRecoveryRetryOverride>,
+        network_retry_limit: u16,
+    ) -> RecoveryResult<Self> {
+        // the attempt which has just failed is a part of the attempts made for the payment
+        let attempt_count = u16::try_from(pt.retry_count + 1).unwrap_or(u16::MAX);
+        let error_codes = get_attempt_error_codes(payment_attempt);
+        let retry_schedule = get_schedule_time_for_next_retry(
+            db,
+            merchant_id,
+            pt.retry_count + 1,
+            attempt_count,
+            retry_override,
+            network_retry_limit,
+            &error_codes,
         )
-        .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)
+        .await;
+        match retry_schedule {
+            Some(retry_schedule) => Ok(Self::RetryPayment(
+                retry_schedule.schedule_time,
+                retry_schedule.get_schedule_decision(retry_schedule.schedule_time),
+            )),
+
+            None => Ok(Self::TerminalFailure(payment_attempt.clone())),
+        }
+    }
+}
+
+/// Records the outcome of the attempt with the billing connector, so that the billing connector
+/// stops dunning the customer for the invoice
+pub(crate) async fn record_back_to_billing_connector(
+    state: &SessionState,
+    payment_attempt: &payment_attempt::PaymentAttempt,
+    payment_intent: &PaymentIntent,
+    billing_mca: &merchant_connector_account::MerchantConnectorAccount,
+) -> RecoveryResult<()> {
+    let connector_name = billing_mca.connector_name.to_string();
+    let connector_data = api_types::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api_types::GetToken::Connector,
+        Some(billing_mca.get_id()),
+    )
+    .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)
+    .attach_printable("invalid connector name received in billing merchant connector account")?;
+
+    let connector_integration: services::BoxedRevenueRecoveryRecordBackInterface<
+        router_flow_types::RecoveryRecordBack,
+        revenue_recovery_request::RevenueRecoveryRecordBackRequest,
+        revenue_recovery_response::RevenueRecoveryRecordBackResponse,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = construct_recovery_record_back_router_data(
+        state,
+        billing_mca,
+        payment_attempt,
+        payment_intent,
+    )?;
+
+    let outbound_proxy = billing_connector_proxy::get_billing_connector_proxy(
+        helpers::MerchantConnectorAccountType::DbVal(Box::new(billing_mca.clone()))
+            .get_connector_account_details(),
+    )
+    .change_context(errors::RecoveryError::BillingConnectorProxyMisconfigured)?;
+
+    let response = services::execute_connector_processing_step_with_timeout(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+        None,
+        outbound_proxy.as_ref(),
+    )
+    .await
+    .map_err(|error| {
+        let error_context = if billing_connector_proxy::is_proxy_misconfigured(&error) {
+            errors::RecoveryError::BillingConnectorProxyMisconfigured
+        } else {
+            errors::RecoveryError::RecordBackToBillingConnectorFailed
+        };
+        error.change_context(error_context)
+    })
+    .attach_printable("Failed while handling response of record back to billing connector")?;
+
+    let record_back_response = match response.response {
+        Ok(response) => Ok(response),
+        error @ Err(_) => {
+            router_env::logger::error!(?error);
+            Err(errors::RecoveryError::RecordBackToBillingConnectorFailed)
+                .attach_printable("Failed while recording back to billing connector")
+        }
+    }?;
+    Ok(())
+}
+
+pub fn construct_recovery_record_back_router_data(
+    state: &SessionState,
+    billing_mca: &merchant_connector_account::MerchantConnectorAccount,
+    payment_attempt: &payment_attempt::PaymentAttempt,
+    payment_intent: &PaymentIntent,
+) -> RecoveryResult<hyperswitch_domain_models::types::RevenueRecoveryRecordBackRouterData> {
+    let auth_type: types::ConnectorAuthType =
+        helpers::MerchantConnectorAccountType::DbVal(Box::new(billing_mca.clone()))
+            .get_connector_account_details()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)?;
+
+    let merchant_reference_id = payment_intent
+        .merchant_reference_id
+        .clone()
+        .ok_or(errors::RecoveryError::RecordBackToBillingConnectorFailed)
         .attach_printable(
-            "invalid connector name received in billing merchant connector account",
+            "Merchant reference id not found while recording back to billing connector",
         )?;
 
-        let connector_integration: services::BoxedRevenueRecoveryRecordBackInterface<
-            router_flow_types::RecoveryRecordBack,
-            revenue_recovery_request::RevenueRecoveryRecordBackRequest,
-            revenue_recovery_response::RevenueRecoveryRecordBackResponse,
-        > = connector_data.connector.get_connector_integration();
+    let router_data = router_data_v2::RouterDataV2 {
+        flow: PhantomData::<router_flow_types::RecoveryRecordBack>,
+        tenant_id: state.tenant.tenant_id.clone(),
+        resource_common_data: flow_common_types::RevenueRecoveryRecordBackData,
+        connector_auth_type: auth_type,
+        request: revenue_recovery_request::RevenueRecoveryRecordBackRequest {
+            merchant_reference_id,
+            amount: payment_attempt.get_total_amount(),
+            currency: payment_intent.amount_details.currency,
+            payment_method_type: Some(payment_attempt.payment_method_subtype),
+            attempt_status: payment_attempt.status,
+            connector_transaction_id: payment_attempt
+                .connector_payment_id
+                .as_ref()
+                .map(|id| common_utils::types::ConnectorTransactionId::TxnId(id.clone())),
+        },
+        response: Err(types::ErrorResponse::default()),
+    };
+    let old_router_data =
+        flow_common_types::RevenueRecoveryRecordBackData::to_old_router_data(router_data)
+            .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)
+            .attach_printable("Cannot construct record back router data")?;
+    Ok(old_router_data)
+}
 
-        let router_data = self.construct_recovery_record_back_router_data(
-            state,
-            billing_mca,
-            payment_attempt,
-            payment_intent,
-        )?;
+fn has_processor_payment_token(revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata) -> bool {
+    !revenue_recovery_metadata
+        .billing_connector_payment_details
+        .payment_processor_token
+        .trim()
+        .is_empty()
+}
 
-        let response = services::execute_connector_processing_step(
-            state,
-            connector_integration,
-            &router_data,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await
-        .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)
-        .attach_printable("Failed while handling response of record back to billing connector")?;
-
-        let record_back_response = match response.response {
-            Ok(response) => Ok(response),
-            error @ Err(_) => {
-                router_env::logger::error!(?error);
-                Err(errors::RecoveryError::RecordBackToBillingConnectorFailed)
-                    .attach_printable("Failed while recording back to billing connector")
-            }
-        }?;
-        Ok(())
+/// Whether the attempt was left pending since the payment connector did not respond in time, in
+/// which case the outcome of the attempt is unknown until it is synced
+fn is_connector_timeout(attempt_status: enums::AttemptStatus, error_code: Option<&str>) -> bool {
+    attempt_status == enums::AttemptStatus::Pending
+        && error_code == Some(consts::REQUEST_TIMEOUT_ERROR_CODE)
+}
+
+fn record_retry_outcome_metric(
+    pcr_attempt_status: &PcrAttemptStatus,
+    schedule_adjustments: &[enums::RecoveryScheduleAdjustment],
+) {
+    let outcome = match pcr_attempt_status {
+        PcrAttemptStatus::Succeeded => "succeeded",
+        PcrAttemptStatus::Failed => "failed",
+        PcrAttemptStatus::Processing => "processing",
+        PcrAttemptStatus::InvalidStatus(_) => "invalid_status",
+    };
+
+    if schedule_adjustments.is_empty() {
+        metrics::REVENUE_RECOVERY_RETRY_OUTCOME_COUNT.add(
+            1,
+            router_env::metric_attributes!(("outcome", outcome), ("adjustment", "none")),
+        );
     }
 
-    pub fn construct_recovery_record_back_router_data(
-        &self,
-        state: &SessionState,
-        billing_mca: &merchant_connector_account::MerchantConnectorAccount,
-        payment_attempt: &payment_attempt::PaymentAttempt,
-        payment_intent: &PaymentIntent,
-    ) -> RecoveryResult<hyperswitch_domain_models::types::RevenueRecoveryRecordBackRouterData> {
-        let auth_type: types::ConnectorAuthType =
-            helpers::MerchantConnectorAccountType::DbVal(Box::new(billing_mca.clone()))
-                .get_connector_account_details()
-                .parse_value("ConnectorAuthType")
-                .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)?;
-
-        let merchant_reference_id = payment_intent
-            .merchant_reference_id
-            .clone()
-            .ok_or(errors::RecoveryError::RecordBackToBillingConnectorFailed)
-            .attach_printable(
-                "Merchant reference id not found while recording back to billing connector",
-            )?;
-
-        let router_data = router_data_v2::RouterDataV2 {
-            flow: PhantomData::<router_flow_types::RecoveryRecordBack>,
-            tenant_id: state.tenant.tenant_id.clone(),
-            resource_common_data: flow_common_types::RevenueRecoveryRecordBackData,
-            connector_auth_type: auth_type,
-            request: revenue_recovery_request::RevenueRecoveryRecordBackRequest {
-                merchant_reference_id,
-                amount: payment_attempt.get_total_amount(),
-                currency: payment_intent.amount_details.currency,
-                payment_method_type: Some(payment_attempt.payment_method_subtype),
-                attempt_status: payment_attempt.status,
-                connector_transaction_id: payment_attempt
-                    .connector_payment_id
-                    .as_ref()
-                    .map(|id| common_utils::types::ConnectorTransactionId::TxnId(id.clone())),
-            },
-            response: Err(types::ErrorResponse::default()),
-        };
-        let old_router_data =
-            flow_common_types::RevenueRecoveryRecordBackData::to_old_router_data(router_data)
-                .change_context(errors::RecoveryError::RecordBackToBillingConnectorFailed)
-                .attach_printable("Cannot construct record back router data")?;
-        Ok(old_router_data)
+    for adjustment in schedule_adjustments {
+        metrics::REVENUE_RECOVERY_RETRY_OUTCOME_COUNT.add(
+            1,
+            router_env::metric_attributes!(
+                ("outcome", outcome),
+                ("adjustment", adjustment.to_string())
+            ),
+        );
     }
+}
 
-    pub(crate) async fn decide_retry_failure_action(
-        db: &dyn StorageInterface,
-        merchant_id: &id_type::MerchantId,
-        pt: storage::ProcessTracker,
-        payment_attempt: &payment_attempt::PaymentAttempt,
-    ) -> RecoveryResult<Self> {
-        let schedule_time =
-            get_schedule_time_to_retry_mit_payments(db, merchant_id, pt.retry_count + 1).await;
-        match schedule_time {
-            Some(schedule_time) => Ok(Self::RetryPayment(schedule_time)),
+async fn update_schedule_trace_on_attempt(
+    state: &SessionState,
+    pcr_data: &storage::revenue_recovery::PcrPaymentData,
+    payment_attempt: payment_attempt::PaymentAttempt,
+    tracking_data: &storage::revenue_recovery::PcrWorkflowTrackingData,
+    connector_switch: Option<common_types::payments::RevenueRecoveryConnectorSwitch>,
+) -> RecoveryResult<payment_attempt::PaymentAttempt> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
 
-            None => Ok(Self::TerminalFailure(payment_attempt.clone())),
-        }
-    }
+    let feature_metadata = get_retry_attempt_feature_metadata(
+        payment_attempt.feature_metadata.clone(),
+        tracking_data,
+        connector_switch,
+    );
+
+    let payment_attempt_update = payment_attempt::PaymentAttemptUpdate::FeatureMetadataUpdate {
+        feature_metadata,
+        updated_by: pcr_data.merchant_account.storage_scheme.to_string(),
+    };
+
+    db.update_payment_attempt(
+        key_manager_state,
+        &pcr_data.key_store,
+        payment_attempt,
+        payment_attempt_update,
+        pcr_data.merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::RecoveryError::PaymentAttemptUpdateFailed)
+    .attach_printable("Failed to persist the schedule trace on the payment attempt")
 }
 
 async fn call_proxy_api(
@@ -493,6 +926,165 @@ async fn call_proxy_api(
     Ok(payment_data)
 }
 
+/// Switch of the payment connector of the retries of the payment, when the retries were switched to
+/// the payment connector through which the next retry is made
+fn get_active_connector_switch(
+    revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
+) -> Option<common_types::payments::RevenueRecoveryConnectorSwitch> {
+    revenue_recovery_metadata
+        .connector_rotation
+        .as_ref()
+        .and_then(|connector_rotation| connector_rotation.last_switch.clone())
+        .filter(|connector_switch| {
+            connector_switch.to_merchant_connector_id
+                == revenue_recovery_metadata.active_attempt_payment_connector_id
+        })
+}
+
+/// Counts the soft decline of the retry on the active payment connector, and moves the next retry
+/// to the next payment connector of the fallback once the soft declines reach the limit
+async fn switch_connector_on_soft_decline(
+    state: &SessionState,
+    pcr_data: &storage::revenue_recovery::PcrPaymentData,
+    revenue_recovery_metadata: &mut PaymentRevenueRecoveryMetadata,
+    connector_fallback: &common_types::payments::RevenueRecoveryConnectorFallback,
+) -> RecoveryResult<()> {
+    let mut connector_rotation = revenue_recovery_metadata
+        .connector_rotation
+        .clone()
+        .unwrap_or_default();
+    let connector_switch = record_soft_decline(
+        &mut connector_rotation,
+        connector_fallback,
+        &revenue_recovery_metadata.active_attempt_payment_connector_id,
+        &revenue_recovery_metadata
+            .billing_connector_payment_details
+            .payment_processor_token,
+    );
+
+    if let Some(connector_switch) = connector_switch {
+        let processor_payment_token = connector_rotation
+            .get_processor_payment_token(&connector_switch.to_merchant_connector_id)
+            .map(str::to_owned)
+            .get_required_value("processor_payment_token")
+            .change_context(errors::RecoveryError::ValueNotFound)
+            .attach_printable("Processor payment token of the connector switched to not found")?;
+        let merchant_connector_account = state
+            .store
+            .find_merchant_connector_account_by_id(
+                &state.into(),
+                &connector_switch.to_merchant_connector_id,
+                &pcr_data.key_store,
+            )
+            .await
+            .change_context(errors::RecoveryError::ValueNotFound)
+            .attach_printable("Failed to fetch the payment connector switched to")?;
+
+        logger::info!(
+            from_merchant_connector_id = ?connector_switch.from_merchant_connector_id,
+            to_merchant_connector_id = ?connector_switch.to_merchant_connector_id,
+            soft_decline_count = connector_switch.soft_decline_count,
+            "Switching the recovery retries to the next payment connector of the fallback"
+        );
+        metrics::REVENUE_RECOVERY_CONNECTOR_SWITCH_COUNT.add(
+            1,
+            router_env::metric_attributes!((
+                "connector",
+                merchant_connector_account.connector_name.to_string()
+            )),
+        );
+
+        revenue_recovery_metadata.active_attempt_payment_connector_id =
+            connector_switch.to_merchant_connector_id;
+        revenue_recovery_metadata.connector = merchant_connector_account.connector_name;
+        revenue_recovery_metadata
+            .billing_connector_payment_details
+            .payment_processor_token = processor_payment_token;
+    }
+
+    revenue_recovery_metadata.connector_rotation = Some(connector_rotation);
+    Ok(())
+}
+
+/// Records the soft decline on the active payment connector in the rotation, returning the switch to
+/// the next payment connector of the fallback once the soft declines reach the configured limit.
+///
+/// The payment connectors are switched to in the order of the fallback, and only the ones for which
+/// a processor payment token of the payment is known are switched to. Network transaction ids are
+/// not used for switching since the proxy payments api takes a processor payment token alone.
+fn record_soft_decline(
+    connector_rotation: &mut common_types::payments::RevenueRecoveryConnectorRotation,
+    connector_fallback: &common_types::payments::RevenueRecoveryConnectorFallback,
+    active_merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    active_processor_payment_token: &str,
+) -> Option<common_types::payments::RevenueRecoveryConnectorSwitch> {
+    connector_rotation.soft_decline_count = connector_rotation.soft_decline_count.saturating_add(1);
+    if !active_processor_payment_token.trim().is_empty() {
+        connector_rotation.set_processor_payment_token(
+            active_merchant_connector_id,
+            active_processor_payment_token.to_string(),
+        );
+    }
+
+    if connector_rotation.soft_decline_count < connector_fallback.soft_declines_before_switch {
+        return None;
+    }
+
+    // a payment connector outside of the fallback is followed by the first one of the fallback
+    let next_position = connector_fallback
+        .payment_connector_ids
+        .iter()
+        .position(|merchant_connector_id| merchant_connector_id == active_merchant_connector_id)
+        .map_or(0, |position| position + 1);
+    let next_merchant_connector_id = connector_fallback
+        .payment_connector_ids
+        .iter()
+        .skip(next_position)
+        .find(|merchant_connector_id| {
+            connector_rotation
+                .get_processor_payment_token(merchant_connector_id)
+                .is_some()
+        })?;
+
+    let connector_switch = common_types::payments::RevenueRecoveryConnectorSwitch {
+        from_merchant_connector_id: active_merchant_connector_id.clone(),
+        to_merchant_connector_id: next_merchant_connector_id.clone(),
+        reason: enums::RecoveryConnectorSwitchReason::SoftDeclineLimitReached,
+        soft_decline_count: connector_rotation.soft_decline_count,
+    };
+    connector_rotation.soft_decline_count = 0;
+    connector_rotation.last_switch = Some(connector_switch.clone());
+
+    Some(connector_switch)
+}
+
+/// Feature metadata of an attempt made by a retry, carrying the schedule trace and the recovery
+/// correlation id of the task which made the retry, along with the switch of the payment connector
+/// under which the retry was made.
+pub(crate) fn get_retry_attempt_feature_metadata(
+    feature_metadata: Option<payment_attempt::PaymentAttemptFeatureMetadata>,
+    tracking_data: &storage::revenue_recovery::PcrWorkflowTrackingData,
+    connector_switch: Option<common_types::payments::RevenueRecoveryConnectorSwitch>,
+) -> payment_attempt::PaymentAttemptFeatureMetadata {
+    let mut feature_metadata =
+        feature_metadata.unwrap_or(payment_attempt::PaymentAttemptFeatureMetadata {
+            revenue_recovery: None,
+        });
+    let revenue_recovery_data = feature_metadata.revenue_recovery.take();
+    feature_metadata.revenue_recovery = Some(payment_attempt::PaymentAttemptRevenueRecoveryData {
+        attempt_triggered_by: enums::TriggeredBy::Internal,
+        processor_response_details: revenue_recovery_data
+            .as_ref()
+            .and_then(|recovery_data| recovery_data.processor_response_details.clone()),
+        schedule_adjustments: Some(tracking_data.schedule_adjustments.clone()),
+        data_provenance: revenue_recovery_data
+            .and_then(|recovery_data| recovery_data.data_provenance),
+        recovery_correlation_id: tracking_data.recovery_correlation_id.clone(),
+        connector_switch,
+    });
+    feature_metadata
+}
+
 pub async fn update_payment_intent_api(
     state: &SessionState,
     global_payment_id: id_type::GlobalPaymentId,
@@ -521,3 +1113,1268 @@ pub async fn update_payment_intent_api(
     .await?;
     Ok(payment_data)
 }
+
+/// Error codes of the failed attempt by which its decline class is looked up, the unified code
+/// taking precedence over the error code sent by the connector
+pub(crate) fn get_attempt_error_codes(
+    payment_attempt: &payment_attempt::PaymentAttempt,
+) -> Vec<&str> {
+    payment_attempt
+        .error
+        .as_ref()
+        .map(|error| {
+            error
+                .unified_code
+                .iter()
+                .chain(std::iter::once(&error.code))
+                .map(String::as_str)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn get_retry_outcome_analytics(
+    retry_outcomes: &[RetryOutcome],
+    group_by: Option<revenue_recovery_api::RevenueRecoveryAnalyticsGroupBy>,
+) -> revenue_recovery_api::RevenueRecoveryAnalyticsResponse {
+    let schedule_adjustments = enums::RecoveryScheduleAdjustment::iter()
+        .map(|adjustment| {
+            let (with_adjustment, without_adjustment): (Vec<_>, Vec<_>) = retry_outcomes
+                .iter()
+                .partition(|outcome| outcome.schedule_adjustments.contains(&adjustment));
+
+            revenue_recovery_api::ScheduleAdjustmentBreakdown {
+                adjustment,
+                with_adjustment: get_retry_outcome_summary(with_adjustment),
+                without_adjustment: get_retry_outcome_summary(without_adjustment),
+            }
+        })
+        .collect();
+
+    let payment_connectors = group_by.map(|group_by| match group_by {
+        revenue_recovery_api::RevenueRecoveryAnalyticsGroupBy::PaymentConnector => {
+            get_payment_connector_breakdown(retry_outcomes)
+        }
+    });
+
+    revenue_recovery_api::RevenueRecoveryAnalyticsResponse {
+        retries: get_retry_outcome_summary(retry_outcomes),
+        schedule_adjustments,
+        payment_connectors,
+    }
+}
+
+/// Outcome of the retries made through each payment connector, in the order in which the payment
+/// connectors first appear in the retries
+fn get_payment_connector_breakdown(
+    retry_outcomes: &[RetryOutcome],
+) -> Vec<revenue_recovery_api::PaymentConnectorBreakdown> {
+    let mut connector_outcomes: Vec<(
+        &Option<id_type::MerchantConnectorAccountId>,
+        Vec<&RetryOutcome>,
+    )> = Vec::new();
+    for outcome in retry_outcomes {
+        match connector_outcomes
+            .iter_mut()
+            .find(|(merchant_connector_id, _)| {
+                **merchant_connector_id == outcome.merchant_connector_id
+            }) {
+            Some((_, outcomes)) => outcomes.push(outcome),
+            None => connector_outcomes.push((&outcome.merchant_connector_id, vec![outcome])),
+        }
+    }
+
+    connector_outcomes
+        .into_iter()
+        .map(|(merchant_connector_id, outcomes)| {
+            let (retries_to_success, recovered_payments) = outcomes
+                .iter()
+                .filter(|outcome| outcome.is_successful)
+                .fold(
+                    (0u32, 0u32),
+                    |(retries_to_success, recovered_payments), outcome| {
+                        (
+                            retries_to_success.saturating_add(u32::from(outcome.retry_number)),
+                            recovered_payments.saturating_add(1),
+                        )
+                    },
+                );
+
+            revenue_recovery_api::PaymentConnectorBreakdown {
+                merchant_connector_id: merchant_connector_id.clone(),
+                connector: outcomes
+                    .iter()
+                    .find_map(|outcome| outcome.connector.clone()),
+                retries: get_retry_outcome_summary(outcomes.iter().copied()),
+                average_retries_to_success: (recovered_payments > 0)
+                    .then(|| f64::from(retries_to_success) / f64::from(recovered_payments)),
+            }
+        })
+        .collect()
+}
+
+fn get_retry_outcome_summary<'a>(
+    retry_outcomes: impl IntoIterator<Item = &'a RetryOutcome>,
+) -> revenue_recovery_api::RetryOutcomeSummary {
+    let (total_attempts, successful_attempts) = retry_outcomes.into_iter().fold(
+        (0u32, 0u32),
+        |(total_attempts, successful_attempts), outcome| {
+            (
+                total_attempts.saturating_add(1),
+                successful_attempts.saturating_add(u32::from(outcome.is_successful)),
+            )
+        },
+    );
+
+    let success_rate = (total_attempts > 0)
+        .then(|| f64::from(successful_attempts) * 100.0 / f64::from(total_attempts));
+
+    revenue_recovery_api::RetryOutcomeSummary {
+        total_attempts,
+        successful_attempts,
+        success_rate,
+    }
+}
+
+/// Number of retries the retry override still grants the payment, capped at the number of retries
+/// allowed by the card networks.
+pub(crate) fn get_remaining_override_retries(
+    retry_override: &RevenueRecoveryRetryOverride,
+    attempt_count: u16,
+    network_retry_limit: u16,
+) -> u16 {
+    let retries_used = attempt_count.saturating_sub(retry_override.retry_count_at_override);
+
+    retry_override
+        .additional_retries
+        .saturating_sub(retries_used)
+        .min(network_retry_limit.saturating_sub(attempt_count))
+}
+
+/// Schedule time of the next retry granted by the retry override. The next future time of the
+/// override schedule is used, falling back to the schedule time configured for the merchant once
+/// the override schedule runs out. `None` is returned when the override grants no more retries.
+pub(crate) fn get_schedule_time_for_retry_override(
+    retry_override: &RevenueRecoveryRetryOverride,
+    attempt_count: u16,
+    network_retry_limit: u16,
+    merchant_schedule_time: Option<PrimitiveDateTime>,
+    current_time: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    if get_remaining_override_retries(retry_override, attempt_count, network_retry_limit) == 0 {
+        return None;
+    }
+
+    retry_override
+        .schedule
+        .iter()
+        .copied()
+        .find(|schedule_time| *schedule_time > current_time)
+        .or(merchant_schedule_time)
+}
+
+/// Moves the schedule time of a pcr task past the exclusion window of the other pcr tasks scheduled
+/// for the same processor token. Returns `None` if the schedule time does not need an adjustment.
+pub(crate) fn get_schedule_time_outside_exclusion_window(
+    schedule_time: PrimitiveDateTime,
+    mut scheduled_times: Vec<PrimitiveDateTime>,
+    exclusion_window: time::Duration,
+) -> Option<PrimitiveDateTime> {
+    scheduled_times.sort();
+    let adjusted_schedule_time =
+        scheduled_times
+            .into_iter()
+            .fold(schedule_time, |schedule_time, scheduled_time| {
+                if (scheduled_time - schedule_time).abs() < exclusion_window {
+                    scheduled_time + exclusion_window
+                } else {
+                    schedule_time
+                }
+            });
+    (adjusted_schedule_time != schedule_time).then_some(adjusted_schedule_time)
+}
+
+/// Moves the schedule time of a pcr task forward to the next start of the retry window, if it
+/// falls outside of the window in the local time of the cardholder. The timezone configured for the
+/// country of the card issuer is used when available, and the timezone of the window otherwise.
+///
+/// Returns `None` if the schedule time does not need an adjustment, or if moving it would take the
+/// retry beyond the max recovery window of the payment, in which case the retry is made at the
+/// schedule time.
+pub(crate) fn get_schedule_time_in_retry_window(
+    schedule_time: PrimitiveDateTime,
+    retry_window: &process_data::RevenueRecoveryRetryWindow,
+    card_issuing_country: Option<enums::CountryAlpha2>,
+    payment_created_at: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let timezone_name = card_issuing_country
+        .and_then(|country| retry_window.card_issuing_country_timezones.get(&country))
+        .unwrap_or(&retry_window.timezone);
+    let Some(timezone) = timezone::parse_timezone(timezone_name) else {
+        logger::warn!(
+            %timezone_name,
+            "Invalid timezone configured for the revenue recovery retry window"
+        );
+        return None;
+    };
+
+    let latest_schedule_time =
+        retry_window
+            .max_recovery_window_in_secs
+            .map(|max_recovery_window| {
+                payment_created_at.saturating_add(time::Duration::seconds(max_recovery_window))
+            });
+
+    get_next_retry_window_start(
+        schedule_time,
+        retry_window.start_hour,
+        retry_window.end_hour,
+        timezone,
+    )
+    .filter(|window_start| {
+        !latest_schedule_time
+            .is_some_and(|latest_schedule_time| *window_start > latest_schedule_time)
+    })
+}
+
+/// Moves the schedule time of a pcr task to the start of the month after the expiry of the card, if
+/// the retry falls in the month in which the card expires. The issuer has likely issued the renewed
+/// card by then, which the payment processor picks up through its card account updater.
+///
+/// Returns `None` if the schedule time does not need an adjustment, if the expiry of the card is not
+/// known, or if moving it would take the retry beyond the max recovery window of the payment.
+pub(crate) fn get_schedule_time_after_card_renewal(
+    schedule_time: PrimitiveDateTime,
+    card_details: &common_types::payments::RevenueRecoveryCardDetails,
+    max_recovery_window_in_secs: Option<i64>,
+    payment_created_at: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let expiry_month = time::Month::try_from(card_details.card_exp_month?).ok()?;
+    // Some billing connectors share the expiry year in two digits
+    let expiry_year = match i32::from(card_details.card_exp_year?) {
+        year if year < 100 => 2000 + year,
+        year => year,
+    };
+
+    if schedule_time.year() != expiry_year || schedule_time.month() != expiry_month {
+        return None;
+    }
+
+    let (renewal_year, renewal_month) = match expiry_month {
+        time::Month::December => (expiry_year + 1, time::Month::January),
+        month => (expiry_year, month.next()),
+    };
+    let renewal_time = time::Date::from_calendar_date(renewal_year, renewal_month, 1)
+        .ok()?
+        .midnight();
+
+    let latest_schedule_time = max_recovery_window_in_secs.map(|max_recovery_window| {
+        payment_created_at.saturating_add(time::Duration::seconds(max_recovery_window))
+    });
+    (!latest_schedule_time.is_some_and(|latest_schedule_time| renewal_time > latest_schedule_time))
+        .then_some(renewal_time)
+}
+
+/// Validates the time to which the merchant reschedules the next retry of a payment, which has to
+/// be in the future and within the max recovery window of the payment
+pub(crate) fn validate_reschedule_time(
+    schedule_time: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
+    payment_created_at: PrimitiveDateTime,
+    max_recovery_window_in_secs: Option<i64>,
+) -> RouterResult<()> {
+    if schedule_time <= current_time {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "schedule_time must be in the future".to_string(),
+        }
+        .into());
+    }
+
+    let latest_schedule_time = max_recovery_window_in_secs.map(|max_recovery_window| {
+        payment_created_at.saturating_add(time::Duration::seconds(max_recovery_window))
+    });
+    match latest_schedule_time {
+        Some(latest_schedule_time) if schedule_time > latest_schedule_time => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "schedule_time must not be later than {latest_schedule_time}, the end of the max recovery window of the payment"
+                ),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Start of the retry window following the schedule time, if the schedule time is outside of the
+/// window in the local time of the timezone
+fn get_next_retry_window_start(
+    schedule_time: PrimitiveDateTime,
+    start_hour: u8,
+    end_hour: u8,
+    timezone: timezone::Tz,
+) -> Option<PrimitiveDateTime> {
+    let local_hour = timezone::to_local_time(schedule_time, timezone).hour();
+    let is_in_window = match start_hour.cmp(&end_hour) {
+        std::cmp::Ordering::Less => (start_hour..end_hour).contains(&local_hour),
+        // The window spans midnight
+        std::cmp::Ordering::Greater => local_hour >= start_hour || local_hour < end_hour,
+        std::cmp::Ordering::Equal => true,
+    };
+
+    if is_in_window {
+        None
+    } else {
+        timezone::get_next_local_hour(schedule_time, timezone, start_hour)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_retry_window(
+        start_hour: u8,
+        end_hour: u8,
+        max_recovery_window_in_secs: Option<i64>,
+    ) -> process_data::RevenueRecoveryRetryWindow {
+        process_data::RevenueRecoveryRetryWindow {
+            start_hour,
+            end_hour,
+            timezone: String::from("America/New_York"),
+            card_issuing_country_timezones: std::collections::HashMap::from([(
+                enums::CountryAlpha2::IN,
+                String::from("Asia/Kolkata"),
+            )]),
+            max_recovery_window_in_secs,
+        }
+    }
+
+    #[test]
+    fn test_schedule_time_in_retry_window_is_not_moved() {
+        let retry_window = get_retry_window(9, 20, None);
+
+        // 09:00 and 19:59 EST
+        for schedule_time in [datetime!(2024-01-15 14:00), datetime!(2024-01-16 00:59)] {
+            assert_eq!(
+                get_schedule_time_in_retry_window(
+                    schedule_time,
+                    &retry_window,
+                    None,
+                    datetime!(2024-01-01 00:00),
+                ),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_schedule_time_outside_retry_window_is_moved_to_next_window_start() {
+        let retry_window = get_retry_window(9, 20, None);
+
+        // 20:00 EST, the window has ended for the day
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-01-16 01:00),
+                &retry_window,
+                None,
+                datetime!(2024-01-01 00:00),
+            ),
+            Some(datetime!(2024-01-16 14:00))
+        );
+        // 08:30 EST, the window is yet to start on the day
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-01-15 13:30),
+                &retry_window,
+                None,
+                datetime!(2024-01-01 00:00),
+            ),
+            Some(datetime!(2024-01-15 14:00))
+        );
+        // 08:30 EST is 19:00 IST, within the window of a card issued in India
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-01-15 13:30),
+                &retry_window,
+                Some(enums::CountryAlpha2::IN),
+                datetime!(2024-01-01 00:00),
+            ),
+            None
+        );
+        // The timezone of the window is used for the countries without a timezone
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-01-15 13:30),
+                &retry_window,
+                Some(enums::CountryAlpha2::US),
+                datetime!(2024-01-01 00:00),
+            ),
+            Some(datetime!(2024-01-15 14:00))
+        );
+    }
+
+    #[test]
+    fn test_retry_window_spanning_midnight() {
+        let retry_window = get_retry_window(22, 6, None);
+
+        // 23:00 and 05:00 EST
+        for schedule_time in [datetime!(2024-01-16 04:00), datetime!(2024-01-16 10:00)] {
+            assert_eq!(
+                get_schedule_time_in_retry_window(
+                    schedule_time,
+                    &retry_window,
+                    None,
+                    datetime!(2024-01-01 00:00),
+                ),
+                None
+            );
+        }
+        // 06:00 EST is moved to 22:00 EST on the same day
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-01-16 11:00),
+                &retry_window,
+                None,
+                datetime!(2024-01-01 00:00),
+            ),
+            Some(datetime!(2024-01-17 03:00))
+        );
+    }
+
+    #[test]
+    fn test_retry_window_across_daylight_saving_transitions() {
+        // 01:30 EST on the day the clocks move forward, the window starts at 09:00 EDT
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-03-10 06:30),
+                &get_retry_window(9, 20, None),
+                None,
+                datetime!(2024-03-01 00:00),
+            ),
+            Some(datetime!(2024-03-10 13:00))
+        );
+        // 02:00 does not exist on the day, the window starts at 03:00 EDT
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-03-10 06:30),
+                &get_retry_window(2, 20, None),
+                None,
+                datetime!(2024-03-01 00:00),
+            ),
+            Some(datetime!(2024-03-10 07:00))
+        );
+        // 00:30 EDT on the day the clocks move back, 01:00 occurs twice and the window starts at
+        // the first of them
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-11-03 04:30),
+                &get_retry_window(1, 20, None),
+                None,
+                datetime!(2024-11-01 00:00),
+            ),
+            Some(datetime!(2024-11-03 05:00))
+        );
+        // 20:30 EDT a day before the clocks move back, the window starts at 09:00 EST
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-11-03 00:30),
+                &get_retry_window(9, 20, None),
+                None,
+                datetime!(2024-11-01 00:00),
+            ),
+            Some(datetime!(2024-11-03 14:00))
+        );
+    }
+
+    #[test]
+    fn test_retry_window_does_not_move_retry_beyond_max_recovery_window() {
+        // 20:00 EST, the next window starts at 14:00 UTC
+        let schedule_time = datetime!(2024-01-16 01:00);
+        let payment_created_at = datetime!(2024-01-01 00:00);
+        let seconds_until_window_start =
+            (datetime!(2024-01-16 14:00) - payment_created_at).whole_seconds();
+
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                schedule_time,
+                &get_retry_window(9, 20, Some(seconds_until_window_start)),
+                None,
+                payment_created_at,
+            ),
+            Some(datetime!(2024-01-16 14:00))
+        );
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                schedule_time,
+                &get_retry_window(9, 20, Some(seconds_until_window_start - 1)),
+                None,
+                payment_created_at,
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_window_with_invalid_timezone_is_ignored() {
+        let retry_window = process_data::RevenueRecoveryRetryWindow {
+            timezone: String::from("Mars/Olympus_Mons"),
+            ..get_retry_window(9, 20, None)
+        };
+
+        assert_eq!(
+            get_schedule_time_in_retry_window(
+                datetime!(2024-01-16 01:00),
+                &retry_window,
+                None,
+                datetime!(2024-01-01 00:00),
+            ),
+            None
+        );
+    }
+
+    fn retry_outcome(
+        is_successful: bool,
+        schedule_adjustments: Vec<enums::RecoveryScheduleAdjustment>,
+    ) -> RetryOutcome {
+        RetryOutcome {
+            is_successful,
+            schedule_adjustments,
+            merchant_connector_id: None,
+            connector: None,
+            retry_number: 1,
+            decline_class: None,
+            card_network: None,
+        }
+    }
+
+    fn connector_retry_outcome(
+        connector: &str,
+        retry_number: u16,
+        is_successful: bool,
+        decline_class: &str,
+        card_network: enums::CardNetwork,
+    ) -> RetryOutcome {
+        RetryOutcome {
+            is_successful,
+            schedule_adjustments: vec![],
+            merchant_connector_id: Some(
+                id_type::MerchantConnectorAccountId::wrap(format!("mca_{connector}")).unwrap(),
+            ),
+            connector: Some(connector.to_string()),
+            retry_number,
+            decline_class: Some(decline_class.to_string()),
+            card_network: Some(card_network),
+        }
+    }
+
+    fn get_breakdown(
+        response: &revenue_recovery_api::RevenueRecoveryAnalyticsResponse,
+        adjustment: enums::RecoveryScheduleAdjustment,
+    ) -> &revenue_recovery_api::ScheduleAdjustmentBreakdown {
+        response
+            .schedule_adjustments
+            .iter()
+            .find(|breakdown| breakdown.adjustment == adjustment)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_retry_in_expiry_month_of_card_is_moved_after_card_renewal() {
+        let get_card_details =
+            |card_exp_month, card_exp_year| common_types::payments::RevenueRecoveryCardDetails {
+                card_exp_month,
+                card_exp_year,
+                ..Default::default()
+            };
+        let payment_created_at = datetime!(2025-01-01 00:00);
+
+        assert_eq!(
+            get_schedule_time_after_card_renewal(
+                datetime!(2025-01-20 10:00),
+                &get_card_details(Some(1), Some(2025)),
+                None,
+                payment_created_at,
+            ),
+            Some(datetime!(2025-02-01 00:00))
+        );
+        // The renewal of a card expiring in December is in January of the next year
+        assert_eq!(
+            get_schedule_time_after_card_renewal(
+                datetime!(2025-12-20 10:00),
+                &get_card_details(Some(12), Some(25)),
+                None,
+                payment_created_at,
+            ),
+            Some(datetime!(2026-01-01 00:00))
+        );
+        // The retries before and after the expiry month are not moved
+        for schedule_time in [datetime!(2024-12-31 23:00), datetime!(2025-02-01 00:00)] {
+            assert_eq!(
+                get_schedule_time_after_card_renewal(
+                    schedule_time,
+                    &get_card_details(Some(1), Some(2025)),
+                    None,
+                    payment_created_at,
+                ),
+                None
+            );
+        }
+        assert_eq!(
+            get_schedule_time_after_card_renewal(
+                datetime!(2025-01-20 10:00),
+                &get_card_details(None, Some(2025)),
+                None,
+                payment_created_at,
+            ),
+            None
+        );
+        // The retry is not moved beyond the max recovery window of the payment
+        assert_eq!(
+            get_schedule_time_after_card_renewal(
+                datetime!(2025-01-20 10:00),
+                &get_card_details(Some(1), Some(2025)),
+                Some(time::Duration::days(30).whole_seconds()),
+                payment_created_at,
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reschedule_time_validation() {
+        let current_time = datetime!(2025-01-10 10:00);
+        let payment_created_at = datetime!(2025-01-01 10:00);
+        let max_recovery_window = Some(time::Duration::days(30).whole_seconds());
+
+        assert!(validate_reschedule_time(
+            datetime!(2025-01-12 10:00),
+            current_time,
+            payment_created_at,
+            max_recovery_window,
+        )
+        .is_ok());
+        // The last moment of the window is still allowed
+        assert!(validate_reschedule_time(
+            datetime!(2025-01-31 10:00),
+            current_time,
+            payment_created_at,
+            max_recovery_window,
+        )
+        .is_ok());
+        assert!(validate_reschedule_time(
+            datetime!(2025-01-31 10:01),
+            current_time,
+            payment_created_at,
+            max_recovery_window,
+        )
+        .is_err());
+        assert!(validate_reschedule_time(
+            current_time,
+            current_time,
+            payment_created_at,
+            max_recovery_window,
+        )
+        .is_err());
+        assert!(validate_reschedule_time(
+            datetime!(2025-01-09 10:00),
+            current_time,
+            payment_created_at,
+            None,
+        )
+        .is_err());
+        // Without a max recovery window, any time in the future is allowed
+        assert!(validate_reschedule_time(
+            datetime!(2026-01-01 10:00),
+            current_time,
+            payment_created_at,
+            None,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_retry_outcome_analytics_with_and_without_adjustments() {
+        let retry_outcomes = vec![
+            retry_outcome(
+                true,
+                vec![
+                    enums::RecoveryScheduleAdjustment::BusinessHoursWindow,
+                    enums::RecoveryScheduleAdjustment::Smoothing,
+                ],
+            ),
+            retry_outcome(
+                false,
+                vec![enums::RecoveryScheduleAdjustment::BusinessHoursWindow],
+            ),
+            retry_outcome(true, vec![enums::RecoveryScheduleAdjustment::Smoothing]),
+            retry_outcome(false, vec![]),
+            retry_outcome(true, vec![]),
+        ];
+
+        let response = get_retry_outcome_analytics(&retry_outcomes, None);
+
+        assert_eq!(
+            response.retries,
+            revenue_recovery_api::RetryOutcomeSummary {
+                total_attempts: 5,
+                successful_attempts: 3,
+                success_rate: Some(60.0),
+            }
+        );
+
+        let business_hours = get_breakdown(
+            &response,
+            enums::RecoveryScheduleAdjustment::BusinessHoursWindow,
+        );
+        assert_eq!(business_hours.with_adjustment.total_attempts, 2);
+        assert_eq!(business_hours.with_adjustment.successful_attempts, 1);
+        assert_eq!(business_hours.with_adjustment.success_rate, Some(50.0));
+        assert_eq!(business_hours.without_adjustment.total_attempts, 3);
+        assert_eq!(business_hours.without_adjustment.successful_attempts, 2);
+
+        let smoothing = get_breakdown(&response, enums::RecoveryScheduleAdjustment::Smoothing);
+        assert_eq!(smoothing.with_adjustment.total_attempts, 2);
+        assert_eq!(smoothing.with_adjustment.success_rate, Some(100.0));
+        assert_eq!(smoothing.without_adjustment.total_attempts, 3);
+        assert_eq!(smoothing.without_adjustment.successful_attempts, 1);
+    }
+
+    #[test]
+    fn test_retry_outcome_analytics_without_attempts_for_adjustment() {
+        let retry_outcomes = vec![retry_outcome(false, vec![]), retry_outcome(true, vec![])];
+
+        let response = get_retry_outcome_analytics(&retry_outcomes, None);
+
+        let decline_class_override = get_breakdown(
+            &response,
+            enums::RecoveryScheduleAdjustment::DeclineClassOverride,
+        );
+        assert_eq!(
+            decline_class_override.with_adjustment,
+            revenue_recovery_api::RetryOutcomeSummary::default()
+        );
+        assert_eq!(decline_class_override.with_adjustment.success_rate, None);
+        assert_eq!(
+            decline_class_override.without_adjustment.success_rate,
+            Some(50.0)
+        );
+    }
+
+    #[test]
+    fn test_retry_outcome_analytics_grouped_by_payment_connector() {
+        let retry_outcomes = vec![
+            // recovered on the third retry, after switching to the second connector
+            connector_retry_outcome("stripe", 1, false, "soft_decline", enums::CardNetwork::Visa),
+            connector_retry_outcome("stripe", 2, false, "soft_decline", enums::CardNetwork::Visa),
+            connector_retry_outcome("adyen", 3, true, "soft_decline", enums::CardNetwork::Visa),
+            // recovered on the first retry
+            connector_retry_outcome(
+                "stripe",
+                1,
+                true,
+                "do_not_honor",
+                enums::CardNetwork::Mastercard,
+            ),
+            // recovered on the second retry
+            connector_retry_outcome("adyen", 1, false, "soft_decline", enums::CardNetwork::Visa),
+            connector_retry_outcome("adyen", 2, true, "soft_decline", enums::CardNetwork::Visa),
+        ];
+        let get_connectors = |decline_class, card_network| {
+            let filtered_outcomes = retry_outcomes
+                .iter()
+                .filter(|outcome| outcome.matches_filters(decline_class, card_network))
+                .cloned()
+                .collect::<Vec<_>>();
+            get_retry_outcome_analytics(
+                &filtered_outcomes,
+                Some(revenue_recovery_api::RevenueRecoveryAnalyticsGroupBy::PaymentConnector),
+            )
+            .payment_connectors
+            .unwrap()
+        };
+
+        let connectors = get_connectors(None, None);
+        assert_eq!(connectors.len(), 2);
+        let (stripe, adyen) = (connectors.first().unwrap(), connectors.get(1).unwrap());
+        assert_eq!(stripe.connector.as_deref(), Some("stripe"));
+        assert_eq!(stripe.retries.total_attempts, 3);
+        assert_eq!(stripe.retries.successful_attempts, 1);
+        assert_eq!(stripe.retries.success_rate, Some(100.0 / 3.0));
+        assert_eq!(stripe.average_retries_to_success, Some(1.0));
+        assert_eq!(adyen.connector.as_deref(), Some("adyen"));
+        assert_eq!(adyen.retries.total_attempts, 3);
+        assert_eq!(adyen.retries.successful_attempts, 2);
+        assert_eq!(adyen.retries.success_rate, Some(200.0 / 3.0));
+        assert_eq!(adyen.average_retries_to_success, Some(2.5));
+
+        let visa_connectors = get_connectors(None, Some(&enums::CardNetwork::Visa));
+        let visa_stripe = visa_connectors.first().unwrap();
+        assert_eq!(visa_stripe.retries.total_attempts, 2);
+        assert_eq!(visa_stripe.retries.success_rate, Some(0.0));
+        assert_eq!(visa_stripe.average_retries_to_success, None);
+        assert_eq!(visa_connectors.get(1).unwrap().retries, adyen.retries);
+
+        let do_not_honor_connectors = get_connectors(Some("do_not_honor"), None);
+        assert_eq!(do_not_honor_connectors.len(), 1);
+        let do_not_honor_stripe = do_not_honor_connectors.first().unwrap();
+        assert_eq!(do_not_honor_stripe.connector.as_deref(), Some("stripe"));
+        assert_eq!(do_not_honor_stripe.retries.success_rate, Some(100.0));
+    }
+
+    #[test]
+    fn test_retry_outcome_analytics_not_grouped() {
+        let retry_outcomes = vec![connector_retry_outcome(
+            "stripe",
+            1,
+            true,
+            "soft_decline",
+            enums::CardNetwork::Visa,
+        )];
+
+        let response = get_retry_outcome_analytics(&retry_outcomes, None);
+
+        assert!(response.payment_connectors.is_none());
+        assert_eq!(response.retries.total_attempts, 1);
+    }
+
+    fn get_retry_override(
+        additional_retries: u16,
+        schedule: Vec<PrimitiveDateTime>,
+    ) -> RevenueRecoveryRetryOverride {
+        RevenueRecoveryRetryOverride {
+            additional_retries,
+            schedule,
+            retry_count_at_override: 5,
+            reason: "High value invoice".to_string(),
+            created_by: "user_1".to_string(),
+            created_at: time::macros::datetime!(2025-01-01 00:00:00),
+        }
+    }
+
+    #[test]
+    fn test_retry_override_follows_the_override_schedule() {
+        let schedule = vec![
+            time::macros::datetime!(2025-01-02 10:00:00),
+            time::macros::datetime!(2025-01-03 10:00:00),
+            time::macros::datetime!(2025-01-04 10:00:00),
+        ];
+        let retry_override = get_retry_override(3, schedule.clone());
+        let merchant_schedule_time = Some(time::macros::datetime!(2025-01-01 12:00:00));
+
+        // one retry of the override is made on each day of the schedule, even though the retries
+        // configured for the merchant are exhausted
+        for (attempt_count, current_time, expected_schedule_time) in [
+            (5, time::macros::datetime!(2025-01-01 01:00:00), schedule[0]),
+            (6, time::macros::datetime!(2025-01-02 10:01:00), schedule[1]),
+            (7, time::macros::datetime!(2025-01-03 10:01:00), schedule[2]),
+        ] {
+            assert_eq!(
+                get_schedule_time_for_retry_override(
+                    &retry_override,
+                    attempt_count,
+                    15,
+                    merchant_schedule_time,
+                    current_time,
+                ),
+                Some(expected_schedule_time)
+            );
+        }
+
+        // all the three additional retries are used up
+        assert_eq!(
+            get_schedule_time_for_retry_override(
+                &retry_override,
+                8,
+                15,
+                merchant_schedule_time,
+                time::macros::datetime!(2025-01-04 10:01:00),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_override_without_schedule_uses_merchant_schedule_time() {
+        let retry_override = get_retry_override(2, vec![]);
+        let merchant_schedule_time = Some(time::macros::datetime!(2025-01-01 12:00:00));
+        let current_time = time::macros::datetime!(2025-01-01 01:00:00);
+
+        assert_eq!(
+            get_schedule_time_for_retry_override(
+                &retry_override,
+                6,
+                15,
+                merchant_schedule_time,
+                current_time,
+            ),
+            merchant_schedule_time
+        );
+        assert_eq!(
+            get_schedule_time_for_retry_override(
+                &retry_override,
+                7,
+                15,
+                merchant_schedule_time,
+                current_time,
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_override_is_capped_by_network_retry_limit() {
+        let retry_override = get_retry_override(10, vec![]);
+
+        assert_eq!(get_remaining_override_retries(&retry_override, 5, 15), 10);
+        assert_eq!(get_remaining_override_retries(&retry_override, 8, 10), 2);
+        assert_eq!(get_remaining_override_retries(&retry_override, 10, 10), 0);
+        assert_eq!(
+            get_schedule_time_for_retry_override(
+                &retry_override,
+                10,
+                10,
+                Some(time::macros::datetime!(2025-01-01 12:00:00)),
+                time::macros::datetime!(2025-01-01 01:00:00),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_schedule_time_for_second_invoice_of_same_processor_token() {
+        let exclusion_window = time::Duration::hours(1);
+        // Retry of the first invoice which uses the processor token
+        let first_invoice_schedule_time = time::macros::datetime!(2025-03-28 10:00:00);
+        let second_invoice_schedule_time =
+            first_invoice_schedule_time + time::Duration::seconds(30);
+
+        let adjusted_schedule_time = get_schedule_time_outside_exclusion_window(
+            second_invoice_schedule_time,
+            vec![first_invoice_schedule_time],
+            exclusion_window,
+        );
+
+        assert_eq!(
+            adjusted_schedule_time,
+            Some(time::macros::datetime!(2025-03-28 11:00:00))
+        );
+    }
+
+    #[test]
+    fn test_schedule_time_outside_exclusion_window_is_not_adjusted() {
+        let exclusion_window = time::Duration::hours(1);
+        let first_invoice_schedule_time = time::macros::datetime!(2025-03-28 10:00:00);
+        let second_invoice_schedule_time = time::macros::datetime!(2025-03-28 12:00:00);
+
+        let adjusted_schedule_time = get_schedule_time_outside_exclusion_window(
+            second_invoice_schedule_time,
+            vec![first_invoice_schedule_time],
+            exclusion_window,
+        );
+
+        assert_eq!(adjusted_schedule_time, None);
+    }
+
+    #[test]
+    fn test_schedule_time_is_moved_past_consecutive_exclusion_windows() {
+        let exclusion_window = time::Duration::hours(1);
+        let schedule_time = time::macros::datetime!(2025-03-28 10:15:00);
+
+        let adjusted_schedule_time = get_schedule_time_outside_exclusion_window(
+            schedule_time,
+            vec![
+                time::macros::datetime!(2025-03-28 11:00:00),
+                time::macros::datetime!(2025-03-28 10:00:00),
+                time::macros::datetime!(2025-03-28 14:00:00),
+            ],
+            exclusion_window,
+        );
+
+        assert_eq!(
+            adjusted_schedule_time,
+            Some(time::macros::datetime!(2025-03-28 12:00:00))
+        );
+    }
+
+    #[cfg(feature = "recovery_fault_injection")]
+    #[test]
+    fn test_failures_injected_in_workflow_fail_the_task() {
+        use crate::utils::fault_injection::{
+            FaultInjectionRegistry, RecoveryFaultPoint, RecoveryFaultTrigger,
+        };
+
+        let registry = FaultInjectionRegistry::default();
+        registry.inject(
+            RecoveryFaultPoint::PaymentsSync,
+            RecoveryFaultTrigger::Probability(1.0),
+        );
+        registry.inject(
+            RecoveryFaultPoint::Redis,
+            RecoveryFaultTrigger::Probability(1.0),
+        );
+
+        let psync_error = registry
+            .check(
+                RecoveryFaultPoint::PaymentsSync,
+                errors::ApiErrorResponse::InternalServerError,
+            )
+            .change_context(errors::RecoveryError::PaymentCallFailed)
+            .unwrap_err();
+        let handoff_error = registry
+            .check(
+                RecoveryFaultPoint::Redis,
+                errors::RecoveryError::ManualCollectionHandoffFailed,
+            )
+            .unwrap_err();
+
+        for (error, expected_error) in [
+            (psync_error, errors::RecoveryError::PaymentCallFailed),
+            (
+                handoff_error,
+                errors::RecoveryError::ManualCollectionHandoffFailed,
+            ),
+        ] {
+            assert!(matches!(
+                scheduler::errors::ProcessTrackerError::from(error),
+                scheduler::errors::ProcessTrackerError::ERecoveryError(error)
+                    if format!("{:?}", error.current_context()) == format!("{expected_error:?}")
+            ));
+        }
+    }
+
+    fn get_execute_task(
+        status: enums::ProcessTrackerStatus,
+        schedule_time: PrimitiveDateTime,
+        tracking_data: serde_json::Value,
+    ) -> storage::ProcessTracker {
+        storage::ProcessTracker {
+            id: String::from("PASSIVE_RECOVERY_WORKFLOW_EXECUTE_WORKFLOW_12345"),
+            name: Some(String::from(core_pcr::EXECUTE_WORKFLOW)),
+            tag: vec![String::from("PCR")],
+            runner: Some(storage::ProcessTrackerRunner::PassiveRecoveryWorkflow.to_string()),
+            retry_count: 2,
+            schedule_time: Some(schedule_time),
+            rule: String::new(),
+            tracking_data,
+            business_status: String::from(business_status::PENDING),
+            status,
+            event: vec![],
+            created_at: datetime!(2024-01-01 00:00),
+            updated_at: datetime!(2024-01-01 00:00),
+            version: common_enums::ApiVersion::V2,
+        }
+    }
+
+    #[test]
+    fn test_connector_timeout_is_told_apart_from_other_pending_attempts() {
+        assert!(is_connector_timeout(
+            enums::AttemptStatus::Pending,
+            Some(consts::REQUEST_TIMEOUT_ERROR_CODE)
+        ));
+        // A server error of the connector leaves the attempt pending as well, it is synced
+        // through the psync task instead
+        assert!(!is_connector_timeout(
+            enums::AttemptStatus::Pending,
+            Some("IR_00")
+        ));
+        assert!(!is_connector_timeout(enums::AttemptStatus::Pending, None));
+        // A declined retry follows the retry schedule of the merchant
+        assert!(!is_connector_timeout(
+            enums::AttemptStatus::Failure,
+            Some(consts::REQUEST_TIMEOUT_ERROR_CODE)
+        ));
+    }
+
+    fn get_merchant_connector_id(id: &str) -> id_type::MerchantConnectorAccountId {
+        id_type::MerchantConnectorAccountId::wrap(id.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_soft_declines_switch_retries_to_next_connector_with_token() {
+        let primary = get_merchant_connector_id("mca_primary");
+        let secondary = get_merchant_connector_id("mca_secondary");
+        let tertiary = get_merchant_connector_id("mca_tertiary");
+        let connector_fallback = common_types::payments::RevenueRecoveryConnectorFallback {
+            payment_connector_ids: vec![primary.clone(), secondary.clone(), tertiary.clone()],
+            soft_declines_before_switch: 2,
+        };
+        let mut connector_rotation =
+            common_types::payments::RevenueRecoveryConnectorRotation::default();
+        // The payment is not known to the secondary connector, so it is skipped
+        connector_rotation.set_processor_payment_token(&tertiary, String::from("tok_tertiary"));
+
+        assert_eq!(
+            record_soft_decline(
+                &mut connector_rotation,
+                &connector_fallback,
+                &primary,
+                "tok_primary"
+            ),
+            None
+        );
+        assert_eq!(connector_rotation.soft_decline_count, 1);
+
+        let connector_switch = record_soft_decline(
+            &mut connector_rotation,
+            &connector_fallback,
+            &primary,
+            "tok_primary",
+        )
+        .unwrap();
+        assert_eq!(connector_switch.from_merchant_connector_id, primary);
+        assert_eq!(connector_switch.to_merchant_connector_id, tertiary);
+        assert_eq!(connector_switch.soft_decline_count, 2);
+        assert_eq!(connector_rotation.soft_decline_count, 0);
+        assert_eq!(connector_rotation.last_switch, Some(connector_switch));
+        assert_eq!(
+            connector_rotation.get_processor_payment_token(&primary),
+            Some("tok_primary")
+        );
+
+        // The last connector of the fallback is not switched away from
+        for _ in 0..3 {
+            assert_eq!(
+                record_soft_decline(
+                    &mut connector_rotation,
+                    &connector_fallback,
+                    &tertiary,
+                    "tok_tertiary"
+                ),
+                None
+            );
+        }
+        assert_eq!(connector_rotation.soft_decline_count, 3);
+    }
+
+    #[test]
+    fn test_recovery_task_response() {
+        use crate::types::transformers::ForeignFrom;
+
+        let cell_id = id_type::CellId::from_string("defid").unwrap();
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let tracking_data =
+            serde_json::to_value(storage::revenue_recovery::PcrWorkflowTrackingData {
+                merchant_id: id_type::MerchantId::default(),
+                profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_recovery"))
+                    .unwrap(),
+                global_payment_id: payment_id.clone(),
+                payment_attempt_id: id_type::GlobalAttemptId::generate(&cell_id),
+                billing_mca_id: id_type::MerchantConnectorAccountId::wrap(
+                    "mca_recovery".to_string(),
+                )
+                .unwrap(),
+                schedule_adjustments: Vec::new(),
+                recovery_correlation_id: None,
+                decline_class: None,
+                execution_failure_count: 0,
+                last_execution_error: None,
+                schedule_decision: Some(storage::revenue_recovery::RecoveryScheduleDecision::new(
+                    enums::RecoveryScheduleAlgorithm::MerchantRetrySchedule,
+                    None,
+                    datetime!(2024-01-15 09:00),
+                    datetime!(2024-01-15 10:00),
+                    datetime!(2024-01-15 11:00),
+                )),
+            })
+            .unwrap();
+        let current_time = datetime!(2024-01-15 12:00);
+        let get_task_response = |status, schedule_time, tracking_data| {
+            revenue_recovery_api::RecoveryTaskResponse::foreign_from((
+                get_execute_task(status, schedule_time, tracking_data),
+                current_time,
+            ))
+        };
+
+        let overdue_task = get_task_response(
+            enums::ProcessTrackerStatus::Pending,
+            datetime!(2024-01-15 11:00),
+            tracking_data.clone(),
+        );
+        assert_eq!(overdue_task.task.retry_count, 2);
+        assert!(overdue_task.is_overdue);
+        assert!(matches!(
+            overdue_task.task.tracking_data,
+            api_models::process_tracker::ProcessTaskTrackingData::RevenueRecovery(
+                ref recovery_tracking_data
+            ) if recovery_tracking_data.payment_id == payment_id
+                && recovery_tracking_data.billing_connector_id.get_string_repr() == "mca_recovery"
+                && recovery_tracking_data.schedule_decision.as_ref().is_some_and(|decision| {
+                    decision.base_delay_in_secs == 3600 && decision.window_shift_in_secs == 3600
+                })
+        ));
+
+        // Neither a task which is scheduled later nor one which is already picked up is overdue
+        for (status, schedule_time) in [
+            (
+                enums::ProcessTrackerStatus::Pending,
+                datetime!(2024-01-15 13:00),
+            ),
+            (
+                enums::ProcessTrackerStatus::ProcessStarted,
+                datetime!(2024-01-15 11:00),
+            ),
+        ] {
+            let task = get_task_response(status, schedule_time, tracking_data.clone());
+            assert!(!task.is_overdue);
+        }
+
+        // Tracking data written before the schedule trace was added to it is still read
+        let mut legacy_tracking_data = tracking_data.clone();
+        if let Some(legacy_tracking_data) = legacy_tracking_data.as_object_mut() {
+            legacy_tracking_data.remove("schedule_adjustments");
+            legacy_tracking_data.remove("recovery_correlation_id");
+            legacy_tracking_data.remove("decline_class");
+            legacy_tracking_data.remove("schedule_decision");
+        }
+        let legacy_task = get_task_response(
+            enums::ProcessTrackerStatus::Pending,
+            datetime!(2024-01-15 13:00),
+            legacy_tracking_data,
+        );
+        assert!(matches!(
+            legacy_task.task.tracking_data,
+            api_models::process_tracker::ProcessTaskTrackingData::RevenueRecovery(_)
+        ));
+
+        // A malformed blob is redacted rather than failing the response
+        let malformed_task = get_task_response(
+            enums::ProcessTrackerStatus::Pending,
+            datetime!(2024-01-15 11:00),
+            serde_json::json!({ "merchant_id": "merchant_1" }),
+        );
+        assert!(malformed_task.is_overdue);
+        assert!(matches!(
+            malformed_task.task.tracking_data,
+            api_models::process_tracker::ProcessTaskTrackingData::Redacted
+        ));
+        let serialized_task = serde_json::to_value(&malformed_task).unwrap();
+        assert_eq!(
+            serialized_task.get("tracking_data"),
+            Some(&serde_json::json!({ "type": "redacted" }))
+        );
+        assert_eq!(
+            serialized_task.get("id"),
+            Some(&serde_json::json!(
+                "PASSIVE_RECOVERY_WORKFLOW_EXECUTE_WORKFLOW_12345"
+            ))
+        );
+
+        // The tracking data of the other runners is never exposed
+        let mut webhook_task = get_execute_task(
+            enums::ProcessTrackerStatus::Pending,
+            datetime!(2024-01-15 11:00),
+            tracking_data,
+        );
+        webhook_task.runner =
+            Some(storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow.to_string());
+        assert!(matches!(
+            api_models::process_tracker::ProcessTaskResponse::foreign_from(webhook_task)
+                .tracking_data,
+            api_models::process_tracker::ProcessTaskTrackingData::Redacted
+        ));
+    }
+}
@@ -1,18 +1,40 @@
 #[cfg(feature = "v1")]
+pub mod bulk_retry;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod delivery_stats;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod event_export;
+#[cfg(feature = "olap")]
+pub mod event_subscription;
+#[cfg(feature = "olap")]
+pub mod event_type_catalog;
+#[cfg(feature = "v1")]
 mod incoming;
 #[cfg(feature = "v2")]
 mod incoming_v2;
+pub mod meta_notifications;
 #[cfg(feature = "v1")]
 mod outgoing;
+pub mod pending_events;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 mod recovery_incoming;
 pub mod types;
 pub mod utils;
+pub mod webhook_digest;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_events_retention;
+pub mod webhook_url_change;
 
 #[cfg(feature = "v2")]
 pub(crate) use self::incoming_v2::incoming_webhooks_wrapper;
+#[cfg(all(feature = "revenue_recovery", feature = "v2"))]
+pub(crate) use self::recovery_incoming::{
+    is_recovery_closed, process_polled_recovery_transaction,
+    record_attempt_of_unresolved_connector_payment, stop_invoice_recovery,
+    BillingConnectorPaymentsSyncResponseData, PaymentsSyncCacheMode,
+};
 #[cfg(feature = "v1")]
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
@@ -0,0 +1,649 @@
+//! Bulk retry of the failed webhook deliveries of a merchant.
+//!
+//! A bulk retry job is a process tracker task which lists the undelivered events of the merchant
+//! matching the constraints of the job, and enqueues a task per event for delivering it again as a
+//! manual retry. The events are delivered by the scheduler rather than by the request creating the
+//! job. The progress of a job is derived from the statuses of the tasks of its events, which are
+//! tagged with the ID of the job.
+
+use common_utils::{ext_traits::ValueExt, fp_utils};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    logger,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage, storage::enums},
+};
+
+pub(crate) const WEBHOOK_BULK_RETRY_JOB_NAME: &str = "WEBHOOK_BULK_RETRY_JOB";
+pub(crate) const WEBHOOK_BULK_RETRY_EVENT_NAME: &str = "WEBHOOK_BULK_RETRY_EVENT";
+const WEBHOOK_BULK_RETRY_JOB_TAG: &str = "WEBHOOK_BULK_RETRY";
+const WEBHOOK_BULK_RETRY_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::WebhookBulkRetryWorkflow;
+/// The maximum number of the events retried by a job, also retried when no count is specified
+const WEBHOOK_BULK_RETRY_MAX_COUNT: u32 = 10_000;
+/// Events are retried only as far back as they can be listed
+const WEBHOOK_BULK_RETRY_MAX_DAYS: i64 = 90;
+const WEBHOOK_BULK_RETRY_PAGE_SIZE: u32 = 100;
+
+/// The number of the events of a job, by the outcome of their redelivery
+#[derive(Debug, Default, PartialEq, Eq)]
+struct WebhookBulkRetryProgress {
+    queued: i64,
+    succeeded: i64,
+    failed: i64,
+    skipped: i64,
+}
+
+fn generate_task_id_for_bulk_retry_job(job_id: &str) -> String {
+    format!("{WEBHOOK_BULK_RETRY_RUNNER}_{WEBHOOK_BULK_RETRY_JOB_NAME}_{job_id}")
+}
+
+fn generate_task_id_for_bulk_retry_event(job_id: &str, initial_attempt_id: &str) -> String {
+    format!(
+        "{WEBHOOK_BULK_RETRY_RUNNER}_{WEBHOOK_BULK_RETRY_EVENT_NAME}_{job_id}_{initial_attempt_id}"
+    )
+}
+
+fn get_bulk_retry_time_range(
+    created_after: Option<time::PrimitiveDateTime>,
+    created_before: Option<time::PrimitiveDateTime>,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<(time::PrimitiveDateTime, time::PrimitiveDateTime)> {
+    let earliest_created_after =
+        (now.date() - time::Duration::days(WEBHOOK_BULK_RETRY_MAX_DAYS)).midnight();
+    let created_after = created_after.unwrap_or(earliest_created_after);
+    let created_before = created_before.unwrap_or(now);
+
+    fp_utils::when(created_after < earliest_created_after, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`created_after` must be a timestamp within the past {WEBHOOK_BULK_RETRY_MAX_DAYS} days"
+            ),
+        }))
+    })?;
+
+    fp_utils::when(created_after > created_before, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+        }))
+    })?;
+
+    Ok((created_after, created_before))
+}
+
+fn get_bulk_retry_max_count(max_count: Option<u32>) -> RouterResult<u32> {
+    match max_count {
+        None => Ok(WEBHOOK_BULK_RETRY_MAX_COUNT),
+        Some(max_count) if (1..=WEBHOOK_BULK_RETRY_MAX_COUNT).contains(&max_count) => Ok(max_count),
+        Some(_) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`max_count` must be a number between 1 and {WEBHOOK_BULK_RETRY_MAX_COUNT}"
+            ),
+        })),
+    }
+}
+
+fn get_bulk_retry_delivery_state(
+    delivery_state: Option<enums::EventDeliveryState>,
+) -> RouterResult<Option<enums::EventDeliveryState>> {
+    match delivery_state {
+        Some(enums::EventDeliveryState::Delivered) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`delivery_state` must be either `failed` or `pending_first_attempt`"
+                    .to_string(),
+            }))
+        }
+        delivery_state => Ok(delivery_state),
+    }
+}
+
+/// Creates a bulk retry job for the undelivered events of the merchant matching the request. The
+/// events are enqueued for redelivery by the job, once it is picked by the scheduler.
+#[instrument(skip(state))]
+pub async fn create_bulk_retry_job(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::WebhookBulkRetryRequest,
+) -> RouterResponse<api::webhook_events::WebhookBulkRetryJobResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if let Some(profile_id) = &request.profile_id {
+        store
+            .find_business_profile_by_merchant_id_profile_id(
+                key_manager_state,
+                &key_store,
+                &merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    let now = common_utils::date_time::now();
+    let (created_after, created_before) =
+        get_bulk_retry_time_range(request.created_after, request.created_before, now)?;
+    let max_count = get_bulk_retry_max_count(request.max_count)?;
+    let delivery_state = get_bulk_retry_delivery_state(request.delivery_state)?;
+
+    let job_id = common_utils::generate_id(consts::ID_LENGTH, "whrj");
+    let tracking_data = storage::WebhookBulkRetryJobTrackingData {
+        job_id: job_id.clone(),
+        merchant_id,
+        profile_id: request.profile_id,
+        created_after,
+        created_before,
+        event_types: request.event_types,
+        delivery_state,
+        max_count,
+        queued_count: None,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_bulk_retry_job(&job_id),
+        WEBHOOK_BULK_RETRY_JOB_NAME,
+        WEBHOOK_BULK_RETRY_RUNNER,
+        [WEBHOOK_BULK_RETRY_JOB_TAG],
+        tracking_data.clone(),
+        None,
+        now,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the webhook bulk retry job task")?;
+
+    let job_process = store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the webhook bulk retry job task")?;
+
+    logger::info!(%job_id, "Created the webhook bulk retry job");
+
+    Ok(ApplicationResponse::Json(get_bulk_retry_job_response(
+        &job_process,
+        tracking_data,
+        WebhookBulkRetryProgress::default(),
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_bulk_retry_job(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    job_id: String,
+) -> RouterResponse<api::webhook_events::WebhookBulkRetryJobResponse> {
+    let store = state.store.as_ref();
+    let job_not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Webhook bulk retry job `{job_id}` does not exist"),
+    };
+
+    let job_process = store
+        .find_process_by_id(&generate_task_id_for_bulk_retry_job(&job_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the webhook bulk retry job task")?
+        .ok_or_else(job_not_found)?;
+    let tracking_data: storage::WebhookBulkRetryJobTrackingData = job_process
+        .tracking_data
+        .clone()
+        .parse_value("WebhookBulkRetryJobTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // The jobs of other merchants are not disclosed
+    fp_utils::when(tracking_data.merchant_id != merchant_id, || {
+        Err(report!(job_not_found()))
+    })?;
+
+    let task_counts = store
+        .count_processes_by_tag_runner_status(&job_id, WEBHOOK_BULK_RETRY_RUNNER)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the tasks of the webhook bulk retry job")?;
+
+    Ok(ApplicationResponse::Json(get_bulk_retry_job_response(
+        &job_process,
+        tracking_data,
+        get_bulk_retry_progress(task_counts),
+    )))
+}
+
+fn get_bulk_retry_progress(
+    task_counts: impl IntoIterator<Item = (enums::ProcessTrackerStatus, String, i64)>,
+) -> WebhookBulkRetryProgress {
+    task_counts.into_iter().fold(
+        WebhookBulkRetryProgress::default(),
+        |mut progress, (status, task_business_status, count)| {
+            let counter = match (status, task_business_status.as_str()) {
+                (
+                    enums::ProcessTrackerStatus::Finish,
+                    business_status::BULK_RETRY_DELIVERY_SUCCESSFUL,
+                ) => &mut progress.succeeded,
+                (
+                    enums::ProcessTrackerStatus::Finish,
+                    business_status::EVENT_ALREADY_DELIVERED
+                    | business_status::EVENT_PAYLOAD_SCRUBBED,
+                ) => &mut progress.skipped,
+                (enums::ProcessTrackerStatus::Finish, _) => &mut progress.failed,
+                _ => &mut progress.queued,
+            };
+            *counter = counter.saturating_add(count);
+            progress
+        },
+    )
+}
+
+fn get_bulk_retry_job_status(
+    job_status: enums::ProcessTrackerStatus,
+    job_business_status: &str,
+    progress: &WebhookBulkRetryProgress,
+) -> enums::WebhookBulkRetryJobStatus {
+    match (job_status, job_business_status) {
+        (enums::ProcessTrackerStatus::Finish, business_status::COMPLETED_BY_PT)
+            if progress.queued > 0 =>
+        {
+            enums::WebhookBulkRetryJobStatus::InProgress
+        }
+        (enums::ProcessTrackerStatus::Finish, business_status::COMPLETED_BY_PT) => {
+            enums::WebhookBulkRetryJobStatus::Completed
+        }
+        (enums::ProcessTrackerStatus::Finish, _) => enums::WebhookBulkRetryJobStatus::Failed,
+        _ => enums::WebhookBulkRetryJobStatus::Pending,
+    }
+}
+
+fn get_bulk_retry_job_response(
+    job_process: &storage::ProcessTracker,
+    tracking_data: storage::WebhookBulkRetryJobTrackingData,
+    progress: WebhookBulkRetryProgress,
+) -> api::webhook_events::WebhookBulkRetryJobResponse {
+    api::webhook_events::WebhookBulkRetryJobResponse {
+        job_id: tracking_data.job_id,
+        merchant_id: tracking_data.merchant_id,
+        status: get_bulk_retry_job_status(
+            job_process.status,
+            &job_process.business_status,
+            &progress,
+        ),
+        total_count: tracking_data.queued_count.map(i64::from),
+        queued_count: progress.queued,
+        succeeded_count: progress.succeeded,
+        failed_count: progress.failed,
+        skipped_count: progress.skipped,
+        created_at: job_process.created_at,
+    }
+}
+
+/// Enqueues a task per undelivered event matching the constraints of the job, the most recent
+/// events first, and completes the job task along with the number of the events enqueued. The
+/// tasks enqueued by an earlier run of the job task are not enqueued again.
+#[instrument(skip_all)]
+pub(crate) async fn enqueue_bulk_retry_events(
+    state: &SessionState,
+    job_process: storage::ProcessTracker,
+    tracking_data: storage::WebhookBulkRetryJobTrackingData,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mut queued_count = 0;
+    let mut starting_after = None;
+    while queued_count < tracking_data.max_count {
+        let limit =
+            WEBHOOK_BULK_RETRY_PAGE_SIZE.min(tracking_data.max_count.saturating_sub(queued_count));
+        let events = match &tracking_data.profile_id {
+            Some(profile_id) => {
+                db.list_initial_events_by_profile_id_constraints(
+                    key_manager_state,
+                    profile_id,
+                    tracking_data.created_after,
+                    tracking_data.created_before,
+                    Some(i64::from(limit)),
+                    None,
+                    Some(false),
+                    tracking_data.delivery_state,
+                    tracking_data.event_types.clone(),
+                    None,
+                    None,
+                    starting_after.take(),
+                    &key_store,
+                )
+                .await
+            }
+            None => {
+                db.list_initial_events_by_merchant_id_constraints(
+                    key_manager_state,
+                    &tracking_data.merchant_id,
+                    tracking_data.created_after,
+                    tracking_data.created_before,
+                    Some(i64::from(limit)),
+                    None,
+                    Some(false),
+                    tracking_data.delivery_state,
+                    tracking_data.event_types.clone(),
+                    None,
+                    None,
+                    starting_after.take(),
+                    &key_store,
+                )
+                .await
+            }
+        }
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the undelivered events of the webhook bulk retry job")?;
+
+        for event in &events {
+            insert_bulk_retry_event_task(state, &tracking_data, &event.event_id).await?;
+        }
+
+        let page_count = u32::try_from(events.len()).unwrap_or(u32::MAX);
+        queued_count = queued_count.saturating_add(page_count);
+        match events.last() {
+            // A page which is not full is the last page of the events
+            Some(last_event) if page_count == limit => {
+                starting_after = Some((last_event.created_at, last_event.event_id.clone()));
+            }
+            _ => break,
+        }
+    }
+
+    let updated_tracking_data = storage::WebhookBulkRetryJobTrackingData {
+        queued_count: Some(queued_count),
+        ..tracking_data
+    };
+    let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: None,
+        tracking_data: Some(
+            serde_json::to_value(updated_tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the webhook bulk retry job tracking data")?,
+        ),
+        business_status: Some(String::from(business_status::COMPLETED_BY_PT)),
+        status: Some(enums::ProcessTrackerStatus::Finish),
+        updated_at: Some(common_utils::date_time::now()),
+    };
+    db.update_process(job_process, process_tracker_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to complete the webhook bulk retry job task")?;
+
+    logger::info!(
+        queued_count,
+        "Enqueued the events of the webhook bulk retry job"
+    );
+
+    Ok(())
+}
+
+async fn insert_bulk_retry_event_task(
+    state: &SessionState,
+    job_tracking_data: &storage::WebhookBulkRetryJobTrackingData,
+    initial_attempt_id: &str,
+) -> RouterResult<()> {
+    let tracking_data = storage::WebhookBulkRetryTrackingData {
+        job_id: job_tracking_data.job_id.clone(),
+        merchant_id: job_tracking_data.merchant_id.clone(),
+        initial_attempt_id: initial_attempt_id.to_owned(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_bulk_retry_event(&job_tracking_data.job_id, initial_attempt_id),
+        WEBHOOK_BULK_RETRY_EVENT_NAME,
+        WEBHOOK_BULK_RETRY_RUNNER,
+        [job_tracking_data.job_id.as_str()],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the webhook bulk retry task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the webhook bulk retry task"),
+    }
+}
+
+/// Delivers the event of the task again, unless it has been delivered since it was enqueued or
+/// its payload has been scrubbed. A successful redelivery marks the event as delivered, so that
+/// the event is not retried by later jobs.
+#[instrument(skip_all)]
+pub(crate) async fn retry_bulk_retry_event(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: storage::WebhookBulkRetryTrackingData,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let initial_event = db
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &tracking_data.initial_attempt_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let task_business_status = if initial_event.payload_scrubbed_at.is_some() {
+        business_status::EVENT_PAYLOAD_SCRUBBED
+    } else if initial_event.is_overall_delivery_successful == Some(true) {
+        business_status::EVENT_ALREADY_DELIVERED
+    } else {
+        let delivery_attempt =
+            super::outgoing::redeliver_event(state.clone(), &key_store, initial_event, None)
+                .await?;
+
+        if delivery_attempt.is_webhook_notified {
+            db.update_event_by_merchant_id_event_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &tracking_data.initial_attempt_id,
+                domain::EventUpdate::OverallDeliveryStatusUpdate {
+                    is_overall_delivery_successful: true,
+                },
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to mark the retried event as delivered")?;
+
+            business_status::BULK_RETRY_DELIVERY_SUCCESSFUL
+        } else {
+            business_status::BULK_RETRY_DELIVERY_FAILED
+        }
+    };
+
+    db.as_scheduler()
+        .finish_process_with_business_status(process, task_business_status)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to complete the webhook bulk retry task")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_bulk_retry_time_range_defaults_and_validation() {
+        let now = datetime!(2025-04-30 10:00);
+
+        assert_eq!(
+            get_bulk_retry_time_range(None, None, now).unwrap(),
+            (datetime!(2025-01-30 00:00), now)
+        );
+        assert_eq!(
+            get_bulk_retry_time_range(
+                Some(datetime!(2025-04-01 00:00)),
+                Some(datetime!(2025-04-02 00:00)),
+                now
+            )
+            .unwrap(),
+            (datetime!(2025-04-01 00:00), datetime!(2025-04-02 00:00))
+        );
+        assert!(get_bulk_retry_time_range(Some(datetime!(2025-01-01 00:00)), None, now).is_err());
+        assert!(get_bulk_retry_time_range(
+            Some(datetime!(2025-04-02 00:00)),
+            Some(datetime!(2025-04-01 00:00)),
+            now
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_bulk_retry_max_count_validation() {
+        assert_eq!(
+            get_bulk_retry_max_count(None).unwrap(),
+            WEBHOOK_BULK_RETRY_MAX_COUNT
+        );
+        assert_eq!(get_bulk_retry_max_count(Some(250)).unwrap(), 250);
+        assert!(get_bulk_retry_max_count(Some(0)).is_err());
+        assert!(get_bulk_retry_max_count(Some(WEBHOOK_BULK_RETRY_MAX_COUNT + 1)).is_err());
+    }
+
+    #[test]
+    fn test_bulk_retry_delivery_state_validation() {
+        assert_eq!(get_bulk_retry_delivery_state(None).unwrap(), None);
+        assert_eq!(
+            get_bulk_retry_delivery_state(Some(enums::EventDeliveryState::PendingFirstAttempt))
+                .unwrap(),
+            Some(enums::EventDeliveryState::PendingFirstAttempt)
+        );
+        assert!(get_bulk_retry_delivery_state(Some(enums::EventDeliveryState::Delivered)).is_err());
+    }
+
+    #[test]
+    fn test_bulk_retry_progress_by_task_status() {
+        let progress = get_bulk_retry_progress([
+            (enums::ProcessTrackerStatus::New, String::from("Pending"), 3),
+            (
+                enums::ProcessTrackerStatus::ProcessStarted,
+                String::from("Pending"),
+                2,
+            ),
+            (
+                enums::ProcessTrackerStatus::Finish,
+                String::from(business_status::BULK_RETRY_DELIVERY_SUCCESSFUL),
+                7,
+            ),
+            (
+                enums::ProcessTrackerStatus::Finish,
+                String::from(business_status::EVENT_ALREADY_DELIVERED),
+                1,
+            ),
+            (
+                enums::ProcessTrackerStatus::Finish,
+                String::from(business_status::EVENT_PAYLOAD_SCRUBBED),
+                1,
+            ),
+            (
+                enums::ProcessTrackerStatus::Finish,
+                String::from(business_status::BULK_RETRY_DELIVERY_FAILED),
+                4,
+            ),
+            (
+                enums::ProcessTrackerStatus::Finish,
+                String::from(business_status::GLOBAL_ERROR),
+                1,
+            ),
+        ]);
+
+        assert_eq!(
+            progress,
+            WebhookBulkRetryProgress {
+                queued: 5,
+                succeeded: 7,
+                failed: 5,
+                skipped: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_bulk_retry_job_status() {
+        let in_progress = WebhookBulkRetryProgress {
+            queued: 1,
+            ..Default::default()
+        };
+        let done = WebhookBulkRetryProgress {
+            succeeded: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_bulk_retry_job_status(enums::ProcessTrackerStatus::New, "Pending", &done),
+            enums::WebhookBulkRetryJobStatus::Pending
+        );
+        assert_eq!(
+            get_bulk_retry_job_status(
+                enums::ProcessTrackerStatus::Finish,
+                business_status::COMPLETED_BY_PT,
+                &in_progress
+            ),
+            enums::WebhookBulkRetryJobStatus::InProgress
+        );
+        assert_eq!(
+            get_bulk_retry_job_status(
+                enums::ProcessTrackerStatus::Finish,
+                business_status::COMPLETED_BY_PT,
+                &done
+            ),
+            enums::WebhookBulkRetryJobStatus::Completed
+        );
+        assert_eq!(
+            get_bulk_retry_job_status(
+                enums::ProcessTrackerStatus::Finish,
+                business_status::GLOBAL_ERROR,
+                &done
+            ),
+            enums::WebhookBulkRetryJobStatus::Failed
+        );
+    }
+}
@@ -0,0 +1,327 @@
+//! Statistics of the webhook deliveries of a merchant or of a profile.
+//!
+//! The statistics are computed from the initial delivery attempts of the events, each event being
+//! counted once by the outcome of its delivery. An event which is not delivered yet is counted as
+//! pending while an automatic retry may still deliver it, that is, while it is within the horizon
+//! of the retry policy of the merchant, and as failed afterwards. The latency is measured for the
+//! initial delivery attempts which received a response from the webhook endpoint.
+
+use common_utils::fp_utils;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use scheduler::utils as scheduler_utils;
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, storage, storage::enums},
+    workflows::outgoing_webhook_retry,
+};
+
+/// The statistics are computed over at most these many days, to keep the aggregation bounded
+const WEBHOOK_DELIVERY_STATS_MAX_DAYS: i64 = 90;
+const WEBHOOK_DELIVERY_STATS_DEFAULT_DAYS: i64 = 30;
+
+fn get_delivery_stats_time_range(
+    created_after: Option<time::PrimitiveDateTime>,
+    created_before: Option<time::PrimitiveDateTime>,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<(time::PrimitiveDateTime, time::PrimitiveDateTime)> {
+    let created_before = created_before.unwrap_or(now);
+    let created_after = created_after
+        .unwrap_or(created_before - time::Duration::days(WEBHOOK_DELIVERY_STATS_DEFAULT_DAYS));
+
+    fp_utils::when(created_after > created_before, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+        }))
+    })?;
+
+    fp_utils::when(
+        created_before - created_after > time::Duration::days(WEBHOOK_DELIVERY_STATS_MAX_DAYS),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The time range must not be longer than {WEBHOOK_DELIVERY_STATS_MAX_DAYS} days"
+                ),
+            }))
+        },
+    )?;
+
+    Ok((created_after, created_before))
+}
+
+/// Computes the delivery statistics of the events of the merchant, or of the profile in the
+/// request, created in the requested time range.
+#[instrument(skip(state))]
+pub async fn get_delivery_stats(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::WebhookDeliveryStatsRequest,
+) -> RouterResponse<api::webhook_events::WebhookDeliveryStatsResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if let Some(profile_id) = &request.profile_id {
+        store
+            .find_business_profile_by_merchant_id_profile_id(
+                key_manager_state,
+                &key_store,
+                &merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    let now = common_utils::date_time::now();
+    let (created_after, created_before) =
+        get_delivery_stats_time_range(request.created_after, request.created_before, now)?;
+
+    // The last automatic retry of an event is made after the sum of the delays of the retries
+    let retry_mapping =
+        outgoing_webhook_retry::get_webhook_delivery_retry_mapping(store, &merchant_id).await;
+    let retry_horizon_in_secs = scheduler_utils::get_retry_mapping_schedule_times(&retry_mapping)
+        .into_iter()
+        .map(i64::from)
+        .sum::<i64>();
+    let pending_after = now - time::Duration::seconds(retry_horizon_in_secs);
+
+    let outcome_counts = store
+        .count_initial_events_by_event_type_date_outcome(
+            &merchant_id,
+            request.profile_id.clone(),
+            created_after,
+            created_before,
+            pending_after,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the events by the outcome of their delivery")?;
+
+    let latency = store
+        .get_initial_events_delivery_latency(
+            &merchant_id,
+            request.profile_id.clone(),
+            created_after,
+            created_before,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the delivery latency of the events")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookDeliveryStatsResponse {
+            merchant_id,
+            profile_id: request.profile_id,
+            created_after,
+            created_before,
+            total: get_total_outcome_counts(&outcome_counts),
+            by_event_type: get_event_type_stats(&outcome_counts),
+            by_day: get_daily_stats(&outcome_counts),
+            initial_attempt_latency: api::webhook_events::WebhookDeliveryLatencyStats {
+                average_ms: latency.average_ms,
+                p95_ms: latency.p95_ms,
+            },
+        },
+    ))
+}
+
+fn add_outcome_counts(
+    total: &mut api::webhook_events::WebhookDeliveryOutcomeCounts,
+    counts: &storage::EventDeliveryOutcomeCounts,
+) {
+    total.delivered_count += counts.delivered_count;
+    total.pending_count += counts.pending_count;
+    total.failed_count += counts.failed_count;
+}
+
+fn get_total_outcome_counts(
+    outcome_counts: &[storage::EventDeliveryOutcomeCounts],
+) -> api::webhook_events::WebhookDeliveryOutcomeCounts {
+    outcome_counts.iter().fold(
+        api::webhook_events::WebhookDeliveryOutcomeCounts::default(),
+        |mut total, counts| {
+            add_outcome_counts(&mut total, counts);
+            total
+        },
+    )
+}
+
+/// Sums the counts of each event type across the days, the event types being listed in the order
+/// of their first appearance.
+fn get_event_type_stats(
+    outcome_counts: &[storage::EventDeliveryOutcomeCounts],
+) -> Vec<api::webhook_events::WebhookDeliveryEventTypeStats> {
+    let mut event_type_stats = Vec::<(
+        enums::EventType,
+        api::webhook_events::WebhookDeliveryOutcomeCounts,
+    )>::new();
+    for counts in outcome_counts {
+        match event_type_stats
+            .iter_mut()
+            .find(|(event_type, _)| *event_type == counts.event_type)
+        {
+            Some((_, total)) => add_outcome_counts(total, counts),
+            None => {
+                let mut total = api::webhook_events::WebhookDeliveryOutcomeCounts::default();
+                add_outcome_counts(&mut total, counts);
+                event_type_stats.push((counts.event_type, total));
+            }
+        }
+    }
+
+    event_type_stats
+        .into_iter()
+        .map(
+            |(event_type, outcome_counts)| api::webhook_events::WebhookDeliveryEventTypeStats {
+                event_type,
+                outcome_counts,
+            },
+        )
+        .collect()
+}
+
+/// Sums the counts of each day across the event types, earliest day first.
+fn get_daily_stats(
+    outcome_counts: &[storage::EventDeliveryOutcomeCounts],
+) -> Vec<api::webhook_events::WebhookDeliveryDailyStats> {
+    let mut daily_stats = std::collections::BTreeMap::<
+        time::Date,
+        api::webhook_events::WebhookDeliveryOutcomeCounts,
+    >::new();
+    for counts in outcome_counts {
+        add_outcome_counts(daily_stats.entry(counts.date).or_default(), counts);
+    }
+
+    daily_stats
+        .into_iter()
+        .map(
+            |(date, outcome_counts)| api::webhook_events::WebhookDeliveryDailyStats {
+                date: date.midnight(),
+                outcome_counts,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::{date, datetime};
+
+    use super::*;
+
+    fn outcome_counts(
+        event_type: enums::EventType,
+        date: time::Date,
+        (delivered_count, pending_count, failed_count): (i64, i64, i64),
+    ) -> storage::EventDeliveryOutcomeCounts {
+        storage::EventDeliveryOutcomeCounts {
+            event_type,
+            date,
+            delivered_count,
+            pending_count,
+            failed_count,
+        }
+    }
+
+    #[test]
+    fn test_delivery_stats_time_range_defaults_and_validation() {
+        let now = datetime!(2025-04-30 10:00);
+
+        assert_eq!(
+            get_delivery_stats_time_range(None, None, now).unwrap(),
+            (datetime!(2025-03-31 10:00), now)
+        );
+        assert_eq!(
+            get_delivery_stats_time_range(None, Some(datetime!(2025-02-01 00:00)), now).unwrap(),
+            (datetime!(2025-01-02 00:00), datetime!(2025-02-01 00:00))
+        );
+        assert!(get_delivery_stats_time_range(
+            Some(datetime!(2024-12-01 00:00)),
+            Some(datetime!(2025-03-02 00:00)),
+            now
+        )
+        .is_err());
+        assert!(get_delivery_stats_time_range(
+            Some(datetime!(2025-04-02 00:00)),
+            Some(datetime!(2025-04-01 00:00)),
+            now
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_delivery_stats_by_event_type_and_by_day() {
+        let outcome_counts = vec![
+            outcome_counts(
+                enums::EventType::PaymentSucceeded,
+                date!(2025 - 04 - 29),
+                (10, 0, 2),
+            ),
+            outcome_counts(
+                enums::EventType::RefundSucceeded,
+                date!(2025 - 04 - 28),
+                (3, 0, 1),
+            ),
+            outcome_counts(
+                enums::EventType::PaymentSucceeded,
+                date!(2025 - 04 - 28),
+                (5, 1, 0),
+            ),
+        ];
+
+        assert_eq!(
+            get_total_outcome_counts(&outcome_counts),
+            api::webhook_events::WebhookDeliveryOutcomeCounts {
+                delivered_count: 18,
+                pending_count: 1,
+                failed_count: 3,
+            }
+        );
+        assert_eq!(
+            get_event_type_stats(&outcome_counts)
+                .into_iter()
+                .map(|stats| (
+                    stats.event_type,
+                    stats.outcome_counts.delivered_count,
+                    stats.outcome_counts.pending_count,
+                    stats.outcome_counts.failed_count
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (enums::EventType::PaymentSucceeded, 15, 1, 2),
+                (enums::EventType::RefundSucceeded, 3, 0, 1),
+            ]
+        );
+        assert_eq!(
+            get_daily_stats(&outcome_counts)
+                .into_iter()
+                .map(|stats| (
+                    stats.date,
+                    stats.outcome_counts.delivered_count,
+                    stats.outcome_counts.pending_count,
+                    stats.outcome_counts.failed_count
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (datetime!(2025-04-28 00:00), 8, 1, 1),
+                (datetime!(2025-04-29 00:00), 10, 0, 2),
+            ]
+        );
+    }
+}
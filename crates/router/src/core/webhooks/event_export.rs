@@ -0,0 +1,445 @@
+//! Export of the webhook events of a merchant, as NDJSON or CSV.
+//!
+//! The export is streamed to the client while the events are read from the database in chunks,
+//! each chunk starting after the last event of the previous chunk, so that the memory used by an
+//! export does not grow with the number of the events exported. Exports including more events
+//! than the configured maximum are rejected before any event is read.
+
+use common_utils::fp_utils;
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::webhook_events::INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::{ApplicationResponse, FileStreamData},
+    types::{api, domain, storage::enums},
+    utils::StringExt,
+};
+
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+fn get_export_time_range(
+    created_after: Option<time::PrimitiveDateTime>,
+    created_before: Option<time::PrimitiveDateTime>,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<(time::PrimitiveDateTime, time::PrimitiveDateTime)> {
+    let export_begin_time =
+        (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
+    let created_after = created_after.unwrap_or(export_begin_time);
+    let created_before = created_before.unwrap_or(now);
+
+    fp_utils::when(created_after > created_before, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+        }))
+    })?;
+
+    fp_utils::when(created_after < export_begin_time, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`created_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."
+            ),
+        }))
+    })?;
+
+    Ok((created_after, created_before))
+}
+
+/// Exports the initial delivery attempts of the events of the merchant, or of the profile in the
+/// constraints, matching the constraints, latest first.
+#[instrument(skip(state))]
+pub async fn export_events(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    constraints: api::webhook_events::EventExportConstraints,
+) -> RouterResponse<()> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if let Some(profile_id) = &constraints.profile_id {
+        store
+            .find_business_profile_by_merchant_id_profile_id(
+                key_manager_state,
+                &key_store,
+                &merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    let (created_after, created_before) = get_export_time_range(
+        constraints.created_after,
+        constraints.created_before,
+        common_utils::date_time::now(),
+    )?;
+
+    let export_settings = &state.conf.webhooks.export;
+    let event_count = store
+        .count_initial_events_by_constraints(
+            &merchant_id,
+            constraints.profile_id.clone(),
+            created_after,
+            created_before,
+            constraints.is_delivered,
+            constraints.delivery_state,
+            constraints.event_types.clone(),
+            constraints.event_class,
+            constraints.delivery_status_code_class,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the events to export")?;
+
+    fp_utils::when(event_count > export_settings.max_row_count, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The export would include {event_count} events, more than the maximum of {} events. \
+                 Narrow down the time range using `created_after` and `created_before`, \
+                 or filter the events further",
+                export_settings.max_row_count
+            ),
+        }))
+    })?;
+
+    let (content_type, file_extension) = match constraints.format {
+        api::webhook_events::EventExportFormat::Ndjson => (
+            NDJSON_CONTENT_TYPE
+                .parse::<mime::Mime>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the content type of the export")?,
+            "ndjson",
+        ),
+        api::webhook_events::EventExportFormat::Csv => (mime::TEXT_CSV, "csv"),
+    };
+    let file_name = format!(
+        "events_{}_{}.{file_extension}",
+        merchant_id.get_string_repr(),
+        created_before.assume_utc().unix_timestamp()
+    );
+
+    let export = EventExport {
+        state: state.clone(),
+        key_store,
+        merchant_id,
+        profile_id: constraints.profile_id,
+        created_after,
+        created_before,
+        is_delivered: constraints.is_delivered,
+        delivery_state: constraints.delivery_state,
+        event_types: constraints.event_types,
+        event_class: constraints.event_class,
+        delivery_status_code_class: constraints.delivery_status_code_class,
+        format: constraints.format,
+        include_payload: constraints.include_payload,
+        chunk_size: export_settings.chunk_size,
+        starting_after: None,
+    };
+
+    // The response status has been sent by the time a chunk fails, so the failure can only be
+    // logged and the response aborted
+    let stream = futures::stream::try_unfold(export, |mut export| async move {
+        Ok(export
+            .next_chunk()
+            .await?
+            .map(|chunk| (actix_web::web::Bytes::from(chunk), export)))
+    })
+    .map(|chunk: RouterResult<_>| {
+        chunk.map_err(|error| {
+            logger::error!(?error, "Failed to export the events");
+            std::io::Error::other("Failed to export the events")
+        })
+    })
+    .boxed();
+
+    Ok(ApplicationResponse::FileStream(Box::new(FileStreamData {
+        content_type,
+        file_name,
+        stream,
+    })))
+}
+
+/// The progress of an export, which reads the next chunk of the events after the last event read
+struct EventExport {
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    created_after: time::PrimitiveDateTime,
+    created_before: time::PrimitiveDateTime,
+    is_delivered: Option<bool>,
+    delivery_state: Option<enums::EventDeliveryState>,
+    event_types: Option<Vec<enums::EventType>>,
+    event_class: Option<enums::EventClass>,
+    delivery_status_code_class: Option<enums::WebhookDeliveryStatusCodeClass>,
+    format: api::webhook_events::EventExportFormat,
+    include_payload: bool,
+    chunk_size: i64,
+    starting_after: Option<(time::PrimitiveDateTime, String)>,
+}
+
+impl EventExport {
+    /// Reads the next chunk of the events and encodes them in the format of the export, returns
+    /// `None` once all the events have been read.
+    async fn next_chunk(&mut self) -> RouterResult<Option<Vec<u8>>> {
+        let store = self.state.store.as_ref();
+        let key_manager_state = &(&self.state).into();
+
+        let events = match &self.profile_id {
+            Some(profile_id) => {
+                store
+                    .list_initial_events_by_profile_id_constraints(
+                        key_manager_state,
+                        profile_id,
+                        self.created_after,
+                        self.created_before,
+                        Some(self.chunk_size),
+                        None,
+                        self.is_delivered,
+                        self.delivery_state,
+                        self.event_types.clone(),
+                        self.event_class,
+                        self.delivery_status_code_class,
+                        self.starting_after.clone(),
+                        &self.key_store,
+                    )
+                    .await
+            }
+            None => {
+                store
+                    .list_initial_events_by_merchant_id_constraints(
+                        key_manager_state,
+                        &self.merchant_id,
+                        self.created_after,
+                        self.created_before,
+                        Some(self.chunk_size),
+                        None,
+                        self.is_delivered,
+                        self.delivery_state,
+                        self.event_types.clone(),
+                        self.event_class,
+                        self.delivery_status_code_class,
+                        self.starting_after.clone(),
+                        &self.key_store,
+                    )
+                    .await
+            }
+        }
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the events to export")?;
+
+        let Some(last_event) = events.last() else {
+            return Ok(None);
+        };
+        // The header of a CSV export is written with the first chunk, an export without events
+        // is empty
+        let is_first_chunk = self.starting_after.is_none();
+        self.starting_after = Some((last_event.created_at, last_event.event_id.clone()));
+
+        let records = events
+            .into_iter()
+            .map(|event| get_export_record(event, self.include_payload))
+            .collect::<RouterResult<Vec<_>>>()?;
+
+        match self.format {
+            api::webhook_events::EventExportFormat::Ndjson => encode_ndjson(&records),
+            api::webhook_events::EventExportFormat::Csv => encode_csv(&records, is_first_chunk),
+        }
+        .map(Some)
+    }
+}
+
+pub(super) fn get_export_record(
+    event: domain::Event,
+    include_payload: bool,
+) -> RouterResult<api::webhook_events::EventExportRecord> {
+    let (request_body, response_body) = if include_payload {
+        get_payload_bodies(&event)?
+    } else {
+        (None, None)
+    };
+    let delivery_status_code_class = event.latest_delivery_status_code_class;
+    let event = api::webhook_events::EventListItemResponse::try_from(event)?;
+
+    Ok(api::webhook_events::EventExportRecord {
+        event_id: event.event_id,
+        merchant_id: event.merchant_id,
+        profile_id: event.profile_id,
+        object_id: event.object_id,
+        event_type: event.event_type,
+        event_class: event.event_class,
+        is_delivery_successful: event.is_delivery_successful,
+        delivery_status_code_class,
+        created: event.created,
+        payload_scrubbed_at: event.payload_scrubbed_at,
+        request_body,
+        response_body,
+    })
+}
+
+/// Reads the bodies of the request sent and of the response received on the delivery attempt.
+fn get_payload_bodies(
+    event: &domain::Event,
+) -> RouterResult<(Option<Secret<String>>, Option<Secret<String>>)> {
+    let request_body = event
+        .request
+        .as_ref()
+        .map(|request| {
+            request
+                .peek()
+                .parse_struct::<api::webhook_events::OutgoingWebhookRequestContent>(
+                    "OutgoingWebhookRequestContent",
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse webhook event request information")
+        })
+        .transpose()?
+        .map(|request| request.body);
+    let response_body = event
+        .response
+        .as_ref()
+        .map(|response| {
+            response
+                .peek()
+                .parse_struct::<api::webhook_events::OutgoingWebhookResponseContent>(
+                    "OutgoingWebhookResponseContent",
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse webhook event response information")
+        })
+        .transpose()?
+        .and_then(|response| response.body);
+
+    Ok((request_body, response_body))
+}
+
+pub(super) fn encode_ndjson<T: serde::Serialize>(records: &[T]) -> RouterResult<Vec<u8>> {
+    let mut chunk = Vec::new();
+    for record in records {
+        serde_json::to_writer(&mut chunk, record)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the event")?;
+        chunk.push(b'\n');
+    }
+    Ok(chunk)
+}
+
+fn encode_csv(
+    records: &[api::webhook_events::EventExportRecord],
+    has_header: bool,
+) -> RouterResult<Vec<u8>> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(has_header)
+        .from_writer(Vec::new());
+    for record in records {
+        writer
+            .serialize(record)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the exported event")?;
+    }
+    writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the exported events")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn export_record(event_id: &str) -> api::webhook_events::EventExportRecord {
+        api::webhook_events::EventExportRecord {
+            event_id: event_id.to_string(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1"))
+                .unwrap(),
+            object_id: "pay_1".to_string(),
+            event_type: enums::EventType::PaymentSucceeded,
+            event_class: enums::EventClass::Payments,
+            is_delivery_successful: Some(false),
+            delivery_status_code_class: Some(enums::WebhookDeliveryStatusCodeClass::ClientError),
+            created: datetime!(2025-04-01 10:00),
+            payload_scrubbed_at: None,
+            request_body: Some(Secret::new(r#"{"type":"payment_succeeded"}"#.to_string())),
+            response_body: None,
+        }
+    }
+
+    #[test]
+    fn test_export_time_range_defaults_and_validation() {
+        let now = datetime!(2025-04-30 10:00);
+
+        assert_eq!(
+            get_export_time_range(None, None, now).unwrap(),
+            (datetime!(2025-01-30 00:00), now)
+        );
+        assert!(get_export_time_range(Some(datetime!(2025-01-29 00:00)), None, now).is_err());
+        assert!(get_export_time_range(
+            Some(datetime!(2025-04-02 00:00)),
+            Some(datetime!(2025-04-01 00:00)),
+            now
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_events_are_encoded_as_ndjson_lines() {
+        let chunk = encode_ndjson(&[export_record("evt_1"), export_record("evt_2")]).unwrap();
+        let lines = String::from_utf8(chunk).unwrap();
+
+        assert_eq!(
+            lines
+                .lines()
+                .map(
+                    |line| serde_json::from_str::<serde_json::Value>(line).unwrap()["event_id"]
+                        .clone()
+                )
+                .collect::<Vec<_>>(),
+            vec!["evt_1", "evt_2"]
+        );
+        assert!(lines.contains(r#""request_body":"{\"type\":\"payment_succeeded\"}""#));
+        assert!(lines.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_csv_header_is_written_with_the_first_chunk_only() {
+        let first_chunk =
+            String::from_utf8(encode_csv(&[export_record("evt_1")], true).unwrap()).unwrap();
+        let next_chunk =
+            String::from_utf8(encode_csv(&[export_record("evt_2")], false).unwrap()).unwrap();
+
+        assert_eq!(
+            first_chunk.lines().next(),
+            Some(
+                "event_id,merchant_id,profile_id,object_id,event_type,event_class,\
+                 is_delivery_successful,delivery_status_code_class,created,payload_scrubbed_at,\
+                 request_body,response_body"
+            )
+        );
+        assert_eq!(first_chunk.lines().count(), 2);
+        assert_eq!(next_chunk.lines().count(), 1);
+        assert!(next_chunk.starts_with("evt_2,merchant_1,pro_1,pay_1,payment_succeeded,"));
+    }
+}
@@ -0,0 +1,130 @@
+//! Subscription of a profile to the event types whose webhooks are sent to it.
+//!
+//! The subscription is consulted only when an event is created, so changing it does not affect
+//! the events created earlier, nor the retries of their delivery.
+
+use common_enums::EventType;
+use diesel_models::business_profile::WebhookDetails;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks::event_type_catalog,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain},
+};
+
+#[instrument(skip(state))]
+pub async fn update_event_subscription(
+    state: SessionState,
+    request: api::webhook_events::WebhookEventSubscriptionUpdateRequestInternal,
+) -> RouterResponse<api::webhook_events::WebhookEventSubscriptionResponse> {
+    let enabled_event_types = get_enabled_event_types(request.enabled_event_types)?;
+
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let business_profile = store
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &request.merchant_id,
+            &request.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: request.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // The subscription can be set up before the webhook URL is configured
+    let webhook_details = WebhookDetails {
+        enabled_event_types: (!enabled_event_types.is_empty()).then_some(enabled_event_types),
+        ..business_profile.webhook_details.clone().unwrap_or_default()
+    };
+
+    let updated_business_profile = store
+        .update_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            business_profile,
+            domain::ProfileUpdate::WebhookDetailsUpdate { webhook_details },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: request.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookEventSubscriptionResponse {
+            merchant_id: updated_business_profile.merchant_id,
+            profile_id: request.profile_id,
+            enabled_event_types: updated_business_profile
+                .webhook_details
+                .and_then(|webhook_details| webhook_details.enabled_event_types)
+                .unwrap_or_default(),
+        },
+    ))
+}
+
+/// Validates the requested event types, and removes the duplicates while retaining their order.
+fn get_enabled_event_types(requested_event_types: Vec<EventType>) -> RouterResult<Vec<EventType>> {
+    let mut enabled_event_types = Vec::with_capacity(requested_event_types.len());
+
+    for event_type in requested_event_types {
+        if !event_type_catalog::is_subscribable_event_type(event_type) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Event type `{event_type}` cannot be subscribed to, only the event types of \
+                     payments, refunds, disputes, mandates and payouts can be"
+                ),
+            }));
+        }
+
+        if !enabled_event_types.contains(&event_type) {
+            enabled_event_types.push(event_type);
+        }
+    }
+
+    Ok(enabled_event_types)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_event_types_are_removed() {
+        let enabled_event_types = get_enabled_event_types(vec![
+            EventType::PaymentFailed,
+            EventType::PaymentSucceeded,
+            EventType::PaymentFailed,
+        ]);
+
+        assert!(matches!(
+            enabled_event_types.as_deref(),
+            Ok([EventType::PaymentFailed, EventType::PaymentSucceeded])
+        ));
+    }
+
+    #[test]
+    fn test_event_types_without_object_cannot_be_subscribed_to() {
+        assert!(
+            get_enabled_event_types(vec![EventType::RefundSucceeded, EventType::DigestDaily])
+                .is_err()
+        );
+        assert!(get_enabled_event_types(vec![]).is_ok_and(|event_types| event_types.is_empty()));
+    }
+}
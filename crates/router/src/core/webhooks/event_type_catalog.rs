@@ -0,0 +1,364 @@
+//! Catalog of the event types sent to the webhook URL of a profile, with the schema and an example
+//! of the payload of each event type.
+//!
+//! The schemas are generated from the API models, so the catalog is built once and shared by all
+//! the requests. Only the enablement of the event types depends on the profile.
+
+use api_models::webhook_events::{
+    EventTypeCatalogItem, EventTypeCatalogRequestInternal, EventTypeCatalogResponse,
+};
+use common_enums::{EventClass, EventType};
+use once_cell::sync::Lazy;
+use strum::IntoEnumIterator;
+use utoipa::{
+    openapi::{schema::Schema, RefOr},
+    ToSchema,
+};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        webhooks::webhook_digest,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain},
+};
+
+/// Version of the payloads of all the event types, to be incremented along with a breaking change
+/// to the payload of an event type
+const EVENT_PAYLOAD_VERSION: u16 = 1;
+
+static EVENT_TYPE_CATALOG: Lazy<Vec<EventTypeCatalogItem>> =
+    Lazy::new(|| EventType::iter().filter_map(get_catalog_item).collect());
+
+/// Builds the catalog, so that the schemas are not generated while serving the first request
+pub fn load_event_type_catalog() {
+    Lazy::force(&EVENT_TYPE_CATALOG);
+}
+
+pub async fn list_event_types(
+    state: SessionState,
+    request: EventTypeCatalogRequestInternal,
+) -> RouterResponse<EventTypeCatalogResponse> {
+    let event_types = match (request.merchant_id, request.profile_id) {
+        (Some(merchant_id), Some(profile_id)) => {
+            let store = state.store.as_ref();
+            let key_manager_state = &(&state).into();
+
+            let key_store = store
+                .get_merchant_key_store_by_merchant_id(
+                    key_manager_state,
+                    &merchant_id,
+                    &store.get_master_key().to_vec().into(),
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+            let business_profile = store
+                .find_business_profile_by_merchant_id_profile_id(
+                    key_manager_state,
+                    &key_store,
+                    &merchant_id,
+                    &profile_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                    id: profile_id.get_string_repr().to_owned(),
+                })?;
+
+            EVENT_TYPE_CATALOG
+                .iter()
+                .cloned()
+                .map(|mut catalog_item| {
+                    catalog_item.is_enabled = Some(is_event_type_enabled_for_profile(
+                        catalog_item.event_type,
+                        &business_profile,
+                    ));
+                    catalog_item
+                })
+                .collect()
+        }
+        _ => EVENT_TYPE_CATALOG.clone(),
+    };
+
+    Ok(ApplicationResponse::Json(EventTypeCatalogResponse {
+        event_types,
+    }))
+}
+
+fn get_catalog_item(event_type: EventType) -> Option<EventTypeCatalogItem> {
+    let (payload_schema_name, payload_schema) = get_payload_schema(event_type)?;
+    let payload_example = match &payload_schema {
+        RefOr::T(schema) => get_schema_example(schema),
+        RefOr::Ref(_) => None,
+    }
+    .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new()));
+
+    Some(EventTypeCatalogItem {
+        event_type,
+        event_class: get_event_class(event_type),
+        description: get_event_type_description(event_type).to_string(),
+        is_enabled: None,
+        payload_version: EVENT_PAYLOAD_VERSION,
+        payload_schema_name: payload_schema_name.to_string(),
+        payload_schema: serde_json::to_value(&payload_schema).unwrap_or_default(),
+        payload_example,
+    })
+}
+
+/// The schema of the object sent in the webhook of the event type. The payout event types are
+/// not sent when payouts are not enabled, so they are left out of the catalog.
+fn get_payload_schema(event_type: EventType) -> Option<(&'static str, RefOr<Schema>)> {
+    match event_type {
+        EventType::PaymentSucceeded
+        | EventType::PaymentFailed
+        | EventType::PaymentProcessing
+        | EventType::PaymentCancelled
+        | EventType::PaymentAuthorized
+        | EventType::PaymentCaptured
+        | EventType::ActionRequired => Some(api_models::payments::PaymentsResponse::schema()),
+        EventType::RefundSucceeded | EventType::RefundFailed => {
+            Some(api_models::refunds::RefundResponse::schema())
+        }
+        EventType::DisputeOpened
+        | EventType::DisputeExpired
+        | EventType::DisputeAccepted
+        | EventType::DisputeCancelled
+        | EventType::DisputeChallenged
+        | EventType::DisputeWon
+        | EventType::DisputeLost => Some(api_models::disputes::DisputeResponse::schema()),
+        EventType::MandateActive | EventType::MandateRevoked => {
+            Some(api_models::mandates::MandateResponse::schema())
+        }
+        EventType::PayoutSuccess
+        | EventType::PayoutFailed
+        | EventType::PayoutInitiated
+        | EventType::PayoutProcessing
+        | EventType::PayoutCancelled
+        | EventType::PayoutExpired
+        | EventType::PayoutReversed => {
+            #[cfg(feature = "payouts")]
+            {
+                Some(api_models::payouts::PayoutCreateResponse::schema())
+            }
+            #[cfg(not(feature = "payouts"))]
+            {
+                None
+            }
+        }
+        EventType::WebhookUrlVerification => {
+            Some(api::webhook_events::OutgoingWebhookUrlVerification::schema())
+        }
+        EventType::DigestDaily => Some(api::webhook_events::OutgoingWebhookDigest::schema()),
+        EventType::RecoveryRetryUpcoming => {
+            Some(api::webhook_events::OutgoingRecoveryRetryUpcoming::schema())
+        }
+    }
+}
+
+/// Whether a profile can subscribe to the event type. Only the events of an object can be
+/// subscribed to, the other events are enabled by their own configuration in the profile.
+pub(super) fn is_subscribable_event_type(event_type: EventType) -> bool {
+    get_event_class(event_type).is_some()
+}
+
+fn get_event_class(event_type: EventType) -> Option<EventClass> {
+    match event_type {
+        EventType::PaymentSucceeded
+        | EventType::PaymentFailed
+        | EventType::PaymentProcessing
+        | EventType::PaymentCancelled
+        | EventType::PaymentAuthorized
+        | EventType::PaymentCaptured
+        | EventType::ActionRequired => Some(EventClass::Payments),
+        EventType::RefundSucceeded | EventType::RefundFailed => Some(EventClass::Refunds),
+        EventType::DisputeOpened
+        | EventType::DisputeExpired
+        | EventType::DisputeAccepted
+        | EventType::DisputeCancelled
+        | EventType::DisputeChallenged
+        | EventType::DisputeWon
+        | EventType::DisputeLost => Some(EventClass::Disputes),
+        EventType::MandateActive | EventType::MandateRevoked => Some(EventClass::Mandates),
+        EventType::PayoutSuccess
+        | EventType::PayoutFailed
+        | EventType::PayoutInitiated
+        | EventType::PayoutProcessing
+        | EventType::PayoutCancelled
+        | EventType::PayoutExpired
+        | EventType::PayoutReversed => {
+            #[cfg(feature = "payouts")]
+            {
+                Some(EventClass::Payouts)
+            }
+            #[cfg(not(feature = "payouts"))]
+            {
+                None
+            }
+        }
+        EventType::WebhookUrlVerification
+        | EventType::DigestDaily
+        | EventType::RecoveryRetryUpcoming => None,
+    }
+}
+
+fn get_event_type_description(event_type: EventType) -> &'static str {
+    match event_type {
+        EventType::PaymentSucceeded => "Sent when a payment is authorized and captured",
+        EventType::PaymentFailed => "Sent when a payment fails",
+        EventType::PaymentProcessing => "Sent when a payment is being processed by the processor",
+        EventType::PaymentCancelled => "Sent when a payment is cancelled",
+        EventType::PaymentAuthorized => "Sent when a payment is authorized, pending capture",
+        EventType::PaymentCaptured => "Sent when a payment is captured, fully or partially",
+        EventType::ActionRequired => "Sent when a payment requires an action from the merchant",
+        EventType::RefundSucceeded => "Sent when a refund succeeds",
+        EventType::RefundFailed => "Sent when a refund fails",
+        EventType::DisputeOpened => "Sent when a dispute is opened against a payment",
+        EventType::DisputeExpired => "Sent when a dispute expires without being challenged",
+        EventType::DisputeAccepted => "Sent when a dispute is accepted",
+        EventType::DisputeCancelled => "Sent when a dispute is cancelled by the customer",
+        EventType::DisputeChallenged => "Sent when a dispute is challenged with evidence",
+        EventType::DisputeWon => "Sent when a dispute is resolved in favour of the merchant",
+        EventType::DisputeLost => "Sent when a dispute is resolved in favour of the customer",
+        EventType::MandateActive => "Sent when a mandate becomes active",
+        EventType::MandateRevoked => "Sent when a mandate is revoked",
+        EventType::PayoutSuccess => "Sent when a payout succeeds",
+        EventType::PayoutFailed => "Sent when a payout fails",
+        EventType::PayoutInitiated => "Sent when a payout is initiated with the processor",
+        EventType::PayoutProcessing => "Sent when a payout is being processed by the processor",
+        EventType::PayoutCancelled => "Sent when a payout is cancelled",
+        EventType::PayoutExpired => "Sent when a payout expires",
+        EventType::PayoutReversed => "Sent when a payout is reversed",
+        EventType::WebhookUrlVerification => {
+            "Sent to a new webhook URL with the token for confirming the change of the URL"
+        }
+        EventType::DigestDaily => {
+            "Sent once a day with the summary of the webhook delivery failures of the profile"
+        }
+        EventType::RecoveryRetryUpcoming => {
+            "Sent ahead of a scheduled revenue recovery retry of a payment"
+        }
+    }
+}
+
+/// Whether the event type is sent to the profile. The events of an object are sent once a webhook
+/// URL is configured and the profile is subscribed to them, the other events are sent only when
+/// they are enabled by the profile.
+fn is_event_type_enabled_for_profile(
+    event_type: EventType,
+    business_profile: &domain::Profile,
+) -> bool {
+    let webhook_details = business_profile.webhook_details.as_ref();
+    match event_type {
+        EventType::WebhookUrlVerification => webhook_details
+            .and_then(|webhook_details| webhook_details.url_change_confirmation_enabled)
+            .unwrap_or(false),
+        EventType::DigestDaily => {
+            webhook_digest::get_enabled_digest_config(business_profile).is_some()
+        }
+        EventType::RecoveryRetryUpcoming => {
+            #[cfg(feature = "v1")]
+            {
+                false
+            }
+            #[cfg(feature = "v2")]
+            {
+                business_profile
+                    .revenue_recovery_retry_notification
+                    .as_ref()
+                    .is_some_and(|retry_notification| retry_notification.enabled)
+                    && webhook_details
+                        .is_some_and(|webhook_details| webhook_details.webhook_url.is_some())
+            }
+        }
+        _ => webhook_details.is_some_and(|webhook_details| {
+            webhook_details.webhook_url.is_some() && webhook_details.is_subscribed_to(event_type)
+        }),
+    }
+}
+
+/// Builds an example of a value of the schema from the examples of the schema and of its fields.
+/// The fields referring to other schemas are left out of the example.
+fn get_schema_example(schema: &Schema) -> Option<serde_json::Value> {
+    match schema {
+        Schema::Object(object) => object.example.clone().or_else(|| {
+            let fields = object
+                .properties
+                .iter()
+                .filter_map(|(name, property)| {
+                    get_example(property).map(|example| (name.clone(), example))
+                })
+                .collect::<serde_json::Map<_, _>>();
+            (!fields.is_empty()).then_some(serde_json::Value::Object(fields))
+        }),
+        Schema::Array(array) => array.example.clone().or_else(|| {
+            get_example(&array.items).map(|example| serde_json::Value::Array(vec![example]))
+        }),
+        Schema::OneOf(one_of) => one_of
+            .example
+            .clone()
+            .or_else(|| one_of.items.iter().find_map(get_example)),
+        Schema::AllOf(all_of) => all_of
+            .example
+            .clone()
+            .or_else(|| all_of.items.iter().find_map(get_example)),
+        _ => None,
+    }
+}
+
+fn get_example(schema: &RefOr<Schema>) -> Option<serde_json::Value> {
+    match schema {
+        RefOr::T(schema) => get_schema_example(schema),
+        RefOr::Ref(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "payouts")]
+    fn test_every_event_type_is_listed_once_with_a_schema() {
+        let listed_event_types = EVENT_TYPE_CATALOG
+            .iter()
+            .map(|catalog_item| catalog_item.event_type)
+            .collect::<Vec<_>>();
+
+        assert_eq!(listed_event_types, EventType::iter().collect::<Vec<_>>());
+        assert_eq!(
+            listed_event_types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<HashSet<_>>()
+                .len(),
+            listed_event_types.len()
+        );
+        assert!(EVENT_TYPE_CATALOG.iter().all(|catalog_item| {
+            catalog_item.payload_schema.is_object()
+                && !catalog_item.payload_schema_name.is_empty()
+                && !catalog_item.description.is_empty()
+        }));
+    }
+
+    #[test]
+    fn test_payload_example_is_built_from_field_examples() {
+        let catalog_item = EVENT_TYPE_CATALOG
+            .iter()
+            .find(|catalog_item| catalog_item.event_type == EventType::DigestDaily);
+
+        assert!(matches!(
+            catalog_item,
+            Some(EventTypeCatalogItem {
+                event_class: None,
+                payload_schema_name,
+                payload_example,
+                ..
+            }) if payload_schema_name == "OutgoingWebhookDigest"
+                && payload_example.get("backlog_size") == Some(&serde_json::json!(3))
+        ));
+    }
+}
@@ -162,6 +162,21 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     )
     .await?;
 
+    utils::validate_incoming_webhook_request(
+        &state,
+        merchant_account.get_id(),
+        &connector_name,
+        merchant_connector_account
+            .as_ref()
+            .and_then(|merchant_connector_account| {
+                merchant_connector_account
+                    .connector_webhook_details
+                    .as_ref()
+            }),
+        req.headers(),
+        &body,
+    )?;
+
     let decoded_body = connector
         .decode_webhook_body(
             &request_details,
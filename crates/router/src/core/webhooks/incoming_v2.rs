@@ -152,6 +152,17 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     let (merchant_connector_account, connector, connector_name) =
         fetch_mca_and_connector(&state, connector_id, &key_store).await?;
 
+    utils::validate_incoming_webhook_request(
+        &state,
+        merchant_account.get_id(),
+        &connector_name,
+        merchant_connector_account
+            .connector_webhook_details
+            .as_ref(),
+        req.headers(),
+        &body,
+    )?;
+
     let decoded_body = connector
         .decode_webhook_body(
             &request_details,
@@ -369,7 +380,12 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                             &object_ref_id,
                         ))
                         .await
-                        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+                        .map_err(|error| {
+                            let api_error = recovery_incoming::get_recovery_webhook_api_error(
+                                error.current_context(),
+                            );
+                            error.change_context(api_error)
+                        })
                         .attach_printable("Failed to process recovery incoming webhook")?
                     }
                 }
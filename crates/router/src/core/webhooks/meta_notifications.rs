@@ -0,0 +1,640 @@
+//! Notifications about the webhook delivery of a profile, such as its webhooks piling up
+//! undelivered or the secrets of its merchant nearing their expiry. These are sent through the
+//! secondary notification channel of the profile, and not as events through the webhook endpoint,
+//! since the endpoint is the one which may not be reachable.
+
+use api_models::webhook_events::{WebhookMetaNotification, WebhookMetaNotificationDetails};
+use common_utils::{fp_utils, request::RequestContent};
+use diesel_models::business_profile::WebhookSecondaryNotificationConfig;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use redis_interface::SetnxReply;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::WebhookMetaNotificationSettings,
+    consts,
+    core::errors::{self, RouterResult},
+    logger,
+    routes::SessionState,
+    services,
+    types::{domain, storage::enums},
+};
+
+const META_NOTIFICATION_KEY_PREFIX: &str = "WEBHOOK_META_NOTIFICATION";
+
+pub fn validate_secondary_notification_config(
+    notification_config: &api_models::admin::WebhookSecondaryNotificationConfig,
+    webhook_url: Option<&masking::Secret<String>>,
+) -> RouterResult<()> {
+    fp_utils::when(
+        notification_config.enabled
+            && notification_config.notification_url.is_none()
+            && notification_config.email_recipient.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "secondary_notification_config must specify a notification_url or an \
+                          email_recipient when the notifications are enabled"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    if let Some(notification_url) = notification_config.notification_url.as_ref() {
+        url::Url::parse(notification_url.peek()).change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "secondary_notification_config.notification_url is not a valid url"
+                    .to_string(),
+            },
+        )?;
+
+        fp_utils::when(
+            webhook_url.is_some_and(|webhook_url| {
+                webhook_url.peek().trim() == notification_url.peek().trim()
+            }),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "secondary_notification_config.notification_url must be different \
+                              from the webhook_url"
+                        .to_string(),
+                }))
+            },
+        )?;
+    }
+
+    fp_utils::when(
+        notification_config
+            .notification_kinds
+            .as_ref()
+            .is_some_and(|notification_kinds| notification_kinds.is_empty()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "secondary_notification_config.notification_kinds must not be empty"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    fp_utils::when(
+        notification_config
+            .backlog_threshold
+            .is_some_and(|backlog_threshold| backlog_threshold < 1),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "secondary_notification_config.backlog_threshold must be greater than 0"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
+/// The secondary notification configuration of the profile, if the profile is subscribed to the
+/// notifications of the kind
+pub(crate) fn get_subscribed_notification_config(
+    business_profile: &domain::Profile,
+    kind: enums::WebhookMetaNotificationKind,
+) -> Option<&WebhookSecondaryNotificationConfig> {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.secondary_notification_config.as_ref())
+        .filter(|notification_config| notification_config.is_subscribed_to(kind))
+}
+
+/// The key marking a notification as sent, so that it is not sent again within the dedup window.
+/// The occurrence distinguishes the notifications of the same kind which are about different
+/// things, such as the reminders of different API keys.
+fn get_dedup_key(
+    profile_id: &common_utils::id_type::ProfileId,
+    kind: enums::WebhookMetaNotificationKind,
+    occurrence: Option<&str>,
+) -> String {
+    let key = format!(
+        "{META_NOTIFICATION_KEY_PREFIX}_{}_{kind}",
+        profile_id.get_string_repr()
+    );
+
+    match occurrence {
+        Some(occurrence) => format!("{key}_{occurrence}"),
+        None => key,
+    }
+}
+
+#[cfg(feature = "v1")]
+fn get_backlog_check_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+    format!(
+        "{META_NOTIFICATION_KEY_PREFIX}_{}_backlog_check",
+        profile_id.get_string_repr()
+    )
+}
+
+pub(crate) fn get_backlog_threshold(
+    notification_config: &WebhookSecondaryNotificationConfig,
+    settings: &WebhookMetaNotificationSettings,
+) -> i64 {
+    notification_config
+        .backlog_threshold
+        .unwrap_or(settings.default_backlog_threshold)
+}
+
+/// Delay before the retry following the failed attempt, doubled for every attempt
+fn get_retry_delay(
+    settings: &WebhookMetaNotificationSettings,
+    failed_attempt: u8,
+) -> std::time::Duration {
+    let multiplier = 1_u64
+        .checked_shl(u32::from(failed_attempt.saturating_sub(1)))
+        .unwrap_or(u64::MAX);
+
+    std::time::Duration::from_millis(settings.retry_delay_in_millis.saturating_mul(multiplier))
+}
+
+fn build_meta_notification(
+    business_profile: &domain::Profile,
+    details: WebhookMetaNotificationDetails,
+) -> WebhookMetaNotification {
+    WebhookMetaNotification {
+        notification_id: common_utils::generate_id(consts::ID_LENGTH, "mn"),
+        merchant_id: business_profile.merchant_id.clone(),
+        profile_id: business_profile.get_id().to_owned(),
+        details,
+        timestamp: common_utils::date_time::now(),
+    }
+}
+
+/// Sets the key if it is not set, returning whether it was set by this call
+async fn claim_key(state: &SessionState, key: &str, expiry_in_secs: u32) -> RouterResult<bool> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &key.into(),
+            common_utils::date_time::now_unix_timestamp(),
+            Some(i64::from(expiry_in_secs)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the webhook meta notification key")?;
+
+    Ok(reply == SetnxReply::KeySet)
+}
+
+async fn release_key(state: &SessionState, key: &str) {
+    match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            if let Err(error) = redis_conn.delete_key(&key.into()).await {
+                logger::warn!(
+                    ?error,
+                    "Failed to release the webhook meta notification key"
+                );
+            }
+        }
+        Err(error) => logger::warn!(?error, "Failed to get redis connection"),
+    }
+}
+
+/// Sends the notification through the secondary notification channel of the profile, unless a
+/// notification of the same kind and occurrence was sent to the profile within the dedup window.
+/// Failures are logged and not returned, so that the flow raising the notification is not
+/// affected by them.
+#[instrument(skip_all, fields(kind))]
+pub(crate) async fn send_meta_notification(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    details: WebhookMetaNotificationDetails,
+    occurrence: Option<&str>,
+) {
+    let kind = details.get_kind();
+    tracing::Span::current().record("kind", kind.to_string());
+
+    let Some(notification_config) = get_subscribed_notification_config(business_profile, kind)
+    else {
+        return;
+    };
+
+    let settings = &state.conf.webhooks.meta_notifications;
+    let dedup_key = get_dedup_key(business_profile.get_id(), kind, occurrence);
+
+    // A notification is preferred over a duplicate, so the notification is sent if redis is not
+    // available
+    match claim_key(state, &dedup_key, settings.dedup_window_in_secs).await {
+        Ok(true) => {}
+        Ok(false) => {
+            logger::debug!("Webhook meta notification already sent within the dedup window");
+            return;
+        }
+        Err(error) => {
+            logger::warn!(?error, "Failed to dedup the webhook meta notification");
+        }
+    }
+
+    let notification = build_meta_notification(business_profile, details);
+    let mut is_delivered = false;
+
+    if let Some(notification_url) = notification_config.notification_url.as_ref() {
+        is_delivered |=
+            send_meta_notification_to_url(state, settings, notification_url, &notification).await;
+    }
+
+    if let Some(email_recipient) = notification_config.email_recipient.clone() {
+        #[cfg(feature = "email")]
+        {
+            is_delivered |=
+                send_meta_notification_email(state, email_recipient, notification).await;
+        }
+        #[cfg(not(feature = "email"))]
+        {
+            let _ = email_recipient;
+            logger::warn!("Webhook meta notification email not sent, since emails are not enabled");
+        }
+    }
+
+    // The notification is sent again on its next occurrence, if it could not be delivered
+    if !is_delivered {
+        release_key(state, &dedup_key).await;
+    }
+}
+
+/// Posts the notification to the notification URL, retrying a bounded number of times
+async fn send_meta_notification_to_url(
+    state: &SessionState,
+    settings: &WebhookMetaNotificationSettings,
+    notification_url: &masking::Secret<String>,
+    notification: &WebhookMetaNotification,
+) -> bool {
+    let max_delivery_attempts = settings.max_delivery_attempts.max(1);
+
+    for attempt in 1..=max_delivery_attempts {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(notification_url.peek())
+            .attach_default_headers()
+            .set_body(RequestContent::Json(Box::new(notification.clone())))
+            .build();
+
+        match state
+            .api_client
+            .send_request(
+                state,
+                request,
+                Some(settings.request_timeout_in_secs),
+                false,
+            )
+            .await
+        {
+            Ok(response) if response.status().is_success() => {
+                logger::info!(attempt, "Webhook meta notification delivered successfully");
+                return true;
+            }
+            Ok(response) => {
+                logger::warn!(
+                    attempt,
+                    status_code = ?response.status(),
+                    "Webhook meta notification was not accepted by the notification URL"
+                );
+            }
+            Err(error) => {
+                logger::warn!(
+                    attempt,
+                    ?error,
+                    "Failed to deliver webhook meta notification"
+                );
+            }
+        }
+
+        if attempt < max_delivery_attempts {
+            tokio::time::sleep(get_retry_delay(settings, attempt)).await;
+        }
+    }
+
+    false
+}
+
+#[cfg(feature = "email")]
+async fn send_meta_notification_email(
+    state: &SessionState,
+    email_recipient: common_utils::pii::Email,
+    notification: WebhookMetaNotification,
+) -> bool {
+    use crate::services::email::types as email_types;
+
+    let recipient_email = match domain::UserEmail::from_pii_email(email_recipient) {
+        Ok(recipient_email) => recipient_email,
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Webhook meta notification email not sent to an invalid email"
+            );
+            return false;
+        }
+    };
+
+    let email_contents = email_types::WebhookMetaNotification {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_WEBHOOK_META_NOTIFICATION,
+        notification,
+    };
+
+    match state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            email_types::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+    {
+        Ok(_) => true,
+        Err(error) => {
+            logger::warn!(?error, "Failed to send webhook meta notification email");
+            false
+        }
+    }
+}
+
+/// Notifies the profile if its backlog of undelivered webhooks has reached its threshold
+pub(crate) async fn notify_delivery_backlog(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    backlog_size: i64,
+    window_start: time::PrimitiveDateTime,
+) {
+    let Some(notification_config) = get_subscribed_notification_config(
+        business_profile,
+        enums::WebhookMetaNotificationKind::DeliveryBacklogThresholdCrossed,
+    ) else {
+        return;
+    };
+
+    let backlog_threshold =
+        get_backlog_threshold(notification_config, &state.conf.webhooks.meta_notifications);
+    if backlog_size < backlog_threshold {
+        return;
+    }
+
+    send_meta_notification(
+        state,
+        business_profile,
+        WebhookMetaNotificationDetails::DeliveryBacklogThresholdCrossed {
+            backlog_size,
+            backlog_threshold,
+            window_start,
+        },
+        None,
+    )
+    .await;
+}
+
+/// Checks the backlog of undelivered webhooks of the profile after a failed delivery. The backlog
+/// is counted at most once within the check interval of a profile, and not at all if redis is not
+/// available, so that an outage of the webhook endpoint does not load the database.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub(crate) async fn check_delivery_backlog(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+) {
+    if get_subscribed_notification_config(
+        business_profile,
+        enums::WebhookMetaNotificationKind::DeliveryBacklogThresholdCrossed,
+    )
+    .is_none()
+    {
+        return;
+    }
+
+    let profile_id = business_profile.get_id();
+    match claim_key(
+        state,
+        &get_backlog_check_key(profile_id),
+        state
+            .conf
+            .webhooks
+            .meta_notifications
+            .backlog_check_interval_in_secs,
+    )
+    .await
+    {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to throttle the webhook delivery backlog check"
+            );
+            return;
+        }
+    }
+
+    let current_time = common_utils::date_time::now();
+    let window_start = current_time.saturating_sub(time::Duration::hours(i64::from(
+        state.conf.webhooks.digest.backlog_window_in_hours,
+    )));
+
+    let backlog_size = match state
+        .store
+        .count_initial_events_by_constraints(
+            &business_profile.merchant_id,
+            Some(profile_id.to_owned()),
+            window_start,
+            current_time,
+            Some(false),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+    {
+        Ok(backlog_size) => backlog_size,
+        Err(error) => {
+            logger::warn!(?error, "Failed to count the undelivered webhooks");
+            return;
+        }
+    };
+
+    notify_delivery_backlog(state, business_profile, backlog_size, window_start).await;
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn notification_config(
+        notification_kinds: Option<Vec<enums::WebhookMetaNotificationKind>>,
+    ) -> WebhookSecondaryNotificationConfig {
+        WebhookSecondaryNotificationConfig {
+            enabled: true,
+            notification_url: Some(masking::Secret::new(
+                "https://merchant.example.com/alerts".to_string(),
+            )),
+            email_recipient: None,
+            notification_kinds,
+            backlog_threshold: None,
+        }
+    }
+
+    fn profile_id() -> common_utils::id_type::ProfileId {
+        common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from("pro_meta"))
+            .expect("valid profile id")
+    }
+
+    #[test]
+    fn test_delivery_backlog_notification() {
+        let details = WebhookMetaNotificationDetails::DeliveryBacklogThresholdCrossed {
+            backlog_size: 1200,
+            backlog_threshold: 1000,
+            window_start: datetime!(2024-06-01 10:00),
+        };
+        let kind = details.get_kind();
+        let mut config = notification_config(Some(vec![kind]));
+        let settings = WebhookMetaNotificationSettings::default();
+
+        assert_eq!(
+            kind,
+            enums::WebhookMetaNotificationKind::DeliveryBacklogThresholdCrossed
+        );
+        assert!(config.is_subscribed_to(kind));
+        assert_eq!(
+            get_backlog_threshold(&config, &settings),
+            settings.default_backlog_threshold
+        );
+        config.backlog_threshold = Some(50);
+        assert_eq!(get_backlog_threshold(&config, &settings), 50);
+
+        // The backlog is deduped per profile, however long the outage lasts
+        assert_eq!(
+            get_dedup_key(&profile_id(), kind, None),
+            "WEBHOOK_META_NOTIFICATION_pro_meta_delivery_backlog_threshold_crossed"
+        );
+
+        config.enabled = false;
+        assert!(!config.is_subscribed_to(kind));
+    }
+
+    #[test]
+    fn test_secret_rotation_reminder_notification() {
+        let details = WebhookMetaNotificationDetails::SecretRotationReminder {
+            key_name: "Sandbox integration key".to_string(),
+            key_prefix: "snd_wBQMLh".to_string(),
+            expires_in_days: 7,
+        };
+        let kind = details.get_kind();
+
+        assert_eq!(
+            kind,
+            enums::WebhookMetaNotificationKind::SecretRotationReminder
+        );
+        assert!(notification_config(None).is_subscribed_to(kind));
+        assert!(!notification_config(Some(vec![
+            enums::WebhookMetaNotificationKind::DeliveryBacklogThresholdCrossed
+        ]))
+        .is_subscribed_to(kind));
+        // Each reminder of each key is sent once
+        assert_eq!(
+            get_dedup_key(&profile_id(), kind, Some("snd_wBQMLh_7")),
+            "WEBHOOK_META_NOTIFICATION_pro_meta_secret_rotation_reminder_snd_wBQMLh_7"
+        );
+        assert_ne!(
+            get_dedup_key(&profile_id(), kind, Some("snd_wBQMLh_7")),
+            get_dedup_key(&profile_id(), kind, Some("snd_wBQMLh_1"))
+        );
+        assert_eq!(
+            serde_json::to_value(&details).expect("serializable details"),
+            serde_json::json!({
+                "kind": "secret_rotation_reminder",
+                "details": {
+                    "key_name": "Sandbox integration key",
+                    "key_prefix": "snd_wBQMLh",
+                    "expires_in_days": 7,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_retry_delay_is_doubled() {
+        let settings = WebhookMetaNotificationSettings {
+            retry_delay_in_millis: 500,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_retry_delay(&settings, 1),
+            std::time::Duration::from_millis(500)
+        );
+        assert_eq!(
+            get_retry_delay(&settings, 3),
+            std::time::Duration::from_millis(2000)
+        );
+        assert_eq!(
+            get_retry_delay(&settings, u8::MAX),
+            std::time::Duration::from_millis(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_secondary_notification_config_validation() {
+        let webhook_url = masking::Secret::new("https://merchant.example.com/webhooks".to_string());
+        let config = |notification_url: Option<&str>, email_recipient: Option<&str>| {
+            api_models::admin::WebhookSecondaryNotificationConfig {
+                enabled: true,
+                notification_url: notification_url
+                    .map(|notification_url| masking::Secret::new(notification_url.to_string())),
+                email_recipient: email_recipient.map(|email_recipient| {
+                    common_utils::pii::Email::try_from(email_recipient.to_string())
+                        .expect("valid email")
+                }),
+                notification_kinds: None,
+                backlog_threshold: None,
+            }
+        };
+
+        assert!(validate_secondary_notification_config(
+            &config(Some("https://merchant.example.com/alerts"), None),
+            Some(&webhook_url)
+        )
+        .is_ok());
+        assert!(validate_secondary_notification_config(
+            &config(None, Some("alerts@merchant.example.com")),
+            Some(&webhook_url)
+        )
+        .is_ok());
+        assert!(validate_secondary_notification_config(&config(None, None), None).is_err());
+        assert!(validate_secondary_notification_config(
+            &config(Some("https://merchant.example.com/webhooks"), None),
+            Some(&webhook_url)
+        )
+        .is_err());
+        assert!(validate_secondary_notification_config(
+            &config(Some("not a url"), None),
+            Some(&webhook_url)
+        )
+        .is_err());
+        assert!(validate_secondary_notification_config(
+            &api_models::admin::WebhookSecondaryNotificationConfig {
+                notification_kinds: Some(vec![]),
+                ..config(Some("https://merchant.example.com/alerts"), None)
+            },
+            Some(&webhook_url)
+        )
+        .is_err());
+        assert!(validate_secondary_notification_config(
+            &api_models::admin::WebhookSecondaryNotificationConfig {
+                backlog_threshold: Some(0),
+                ..config(Some("https://merchant.example.com/alerts"), None)
+            },
+            Some(&webhook_url)
+        )
+        .is_err());
+    }
+}
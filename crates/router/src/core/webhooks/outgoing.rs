@@ -20,12 +20,12 @@ use router_env::{
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_ID};
+use super::{meta_notifications, types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     core::{
-        errors::{self, CustomResult},
+        errors::{self, CustomResult, StorageErrorExt},
         metrics,
     },
     db::StorageInterface,
@@ -46,6 +46,8 @@ use crate::{
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+/// The maximum number of redirects followed when delivering a webhook.
+const OUTGOING_WEBHOOK_MAX_REDIRECTS: usize = 2;
 
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
@@ -82,6 +84,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
+    let entity_version = content.get_entity_version();
 
     let outgoing_webhook = api::OutgoingWebhook {
         merchant_id: merchant_id.clone(),
@@ -89,6 +92,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         event_type,
         content: content.clone(),
         timestamp: now,
+        entity_version,
     };
 
     let request_content =
@@ -96,6 +100,15 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
             .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
             .attach_printable("Failed to construct outgoing webhook request content")?;
 
+    // The events which the profile is not subscribed to are recorded without being sent, so that
+    // they can still be looked up and retried manually
+    let is_subscribed = business_profile
+        .webhook_details
+        .as_ref()
+        .map_or(true, |webhook_details| {
+            webhook_details.is_subscribed_to(event_type)
+        });
+
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
     let new_event = domain::Event {
@@ -133,7 +146,13 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
-        is_overall_delivery_successful: Some(false),
+        // The delivery of the skipped events is neither successful nor failed
+        is_overall_delivery_successful: is_subscribed.then_some(false),
+        entity_version,
+        payload_scrubbed_at: None,
+        delivery_latency_ms: None,
+        latest_delivery_status_code_class: (!is_subscribed)
+            .then_some(enums::WebhookDeliveryStatusCodeClass::SkippedNotSubscribed),
     };
 
     let event_insert_result = state
@@ -156,6 +175,15 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         }
     }?;
 
+    if !is_subscribed {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %idempotent_event_id,
+            "Profile is not subscribed to the event type; skipping outgoing webhook for event"
+        );
+        return Ok(());
+    }
+
     let process_tracker = add_outgoing_webhook_retry_task_to_process_tracker(
         &*state.store,
         &business_profile,
@@ -184,6 +212,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
                 delivery_attempt,
                 Some(content),
                 process_tracker,
+                None,
             ))
             .await;
         }
@@ -204,6 +233,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     content: Option<api::OutgoingWebhookContent>,
     process_tracker: Option<storage::ProcessTracker>,
+    webhook_url_override: Option<url::Url>,
 ) {
     logger::debug!(
         event_id=%event.event_id,
@@ -213,6 +243,12 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     );
 
     let merchant_id = business_profile.merchant_id.clone();
+    let backlog_notification_profile = meta_notifications::get_subscribed_notification_config(
+        &business_profile,
+        enums::WebhookMetaNotificationKind::DeliveryBacklogThresholdCrossed,
+    )
+    .is_some()
+    .then(|| business_profile.clone());
     let trigger_webhook_result = trigger_webhook_to_merchant(
         state.clone(),
         business_profile,
@@ -221,9 +257,22 @@ pub(crate) async fn trigger_webhook_and_raise_event(
         request_content,
         delivery_attempt,
         process_tracker,
+        webhook_url_override,
     )
     .await;
 
+    if let (Err(error), Some(business_profile)) =
+        (&trigger_webhook_result, backlog_notification_profile)
+    {
+        if matches!(
+            error.current_context(),
+            errors::WebhooksFlowError::CallToMerchantFailed
+                | errors::WebhooksFlowError::NotReceivedByMerchant
+        ) {
+            meta_notifications::check_delivery_backlog(&state, &business_profile).await;
+        }
+    }
+
     let _ = raise_webhooks_analytics_event(
         state,
         trigger_webhook_result,
@@ -235,6 +284,111 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     .await;
 }
 
+/// Delivers the event again as a manual retry, recording the delivery as a new attempt of the
+/// event. The delivery is sent to the override URL if one is specified, instead of the webhook URL
+/// configured for the profile. Returns the attempt as it was stored after the delivery.
+#[instrument(skip_all)]
+pub(crate) async fn redeliver_event(
+    state: SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_to_retry: domain::Event,
+    webhook_url_override: Option<url::Url>,
+) -> CustomResult<domain::Event, errors::ApiErrorResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile_id = event_to_retry
+        .business_profile_id
+        .get_required_value("business_profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read business profile ID from event to retry")?;
+    let business_profile = store
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            merchant_key_store,
+            &business_profile_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find business profile")?;
+
+    let delivery_attempt = match webhook_url_override {
+        Some(_) => enums::WebhookDeliveryAttempt::ManualRetryWithOverrideUrl,
+        None => enums::WebhookDeliveryAttempt::ManualRetry,
+    };
+    let new_event_id = utils::generate_event_id();
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &event_to_retry.primary_object_id,
+        event_to_retry.event_type,
+        delivery_attempt,
+    );
+
+    let now = common_utils::date_time::now();
+    let new_event = domain::Event {
+        event_id: new_event_id.clone(),
+        event_type: event_to_retry.event_type,
+        event_class: event_to_retry.event_class,
+        is_webhook_notified: false,
+        primary_object_id: event_to_retry.primary_object_id,
+        primary_object_type: event_to_retry.primary_object_type,
+        created_at: now,
+        merchant_id: Some(business_profile.merchant_id.clone()),
+        business_profile_id: Some(business_profile.get_id().to_owned()),
+        primary_object_created_at: event_to_retry.primary_object_created_at,
+        idempotent_event_id: Some(idempotent_event_id),
+        initial_attempt_id: event_to_retry.initial_attempt_id,
+        request: event_to_retry.request,
+        response: None,
+        delivery_attempt: Some(delivery_attempt),
+        metadata: event_to_retry.metadata,
+        is_overall_delivery_successful: Some(false),
+        entity_version: event_to_retry.entity_version,
+        payload_scrubbed_at: event_to_retry.payload_scrubbed_at,
+        delivery_latency_ms: None,
+        latest_delivery_status_code_class: None,
+    };
+
+    let event = store
+        .insert_event(key_manager_state, new_event, merchant_key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert event")?;
+
+    // We only allow retrying deliveries for events with `request` populated.
+    let request_content = event
+        .request
+        .as_ref()
+        .get_required_value("request")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook event request information")?;
+
+    Box::pin(trigger_webhook_and_raise_event(
+        state.clone(),
+        business_profile,
+        merchant_key_store,
+        event,
+        request_content,
+        delivery_attempt,
+        None,
+        None,
+        webhook_url_override,
+    ))
+    .await;
+
+    store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &merchant_key_store.merchant_id,
+            &new_event_id,
+            merchant_key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
+}
+
 async fn trigger_webhook_to_merchant(
     state: SessionState,
     business_profile: domain::Profile,
@@ -243,13 +397,17 @@ async fn trigger_webhook_to_merchant(
     request_content: OutgoingWebhookRequestContent,
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
+    webhook_url_override: Option<url::Url>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
+        webhook_url_override,
         get_webhook_url_from_business_profile(&business_profile),
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
-        (Err(error), Some(process_tracker)) => {
+        // The profile may not have a webhook URL configured yet when delivering to another URL
+        (Some(webhook_url_override), _, _) => Ok(String::from(webhook_url_override)),
+        (None, Ok(webhook_url), _) => Ok(webhook_url),
+        (None, Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
                 .is_webhook_delivery_retryable_error()
@@ -266,36 +424,69 @@ async fn trigger_webhook_to_merchant(
             }
             Err(error)
         }
-        (Err(error), None) => Err(error),
+        (None, Err(error), None) => Err(error),
     }?;
 
+    let redirect_policy = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.redirect_policy)
+        .unwrap_or_default();
     let event_id = event.event_id;
 
-    let headers = request_content
+    let headers: Vec<_> = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
-    let request = services::RequestBuilder::new()
-        .method(services::Method::Post)
-        .url(&webhook_url)
-        .attach_default_headers()
-        .headers(headers)
-        .set_body(RequestContent::RawBytes(
-            request_content.body.expose().into_bytes(),
-        ))
-        .build();
-
-    let response = state
-        .api_client
-        .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
-        .await;
+    let body = request_content.body.expose().into_bytes();
+
+    let delivery_started_at = std::time::Instant::now();
+    let (response, redirect_outcome) = send_webhook_request_with_redirect_policy(
+        &webhook_url,
+        redirect_policy,
+        is_webhook_redirect_target_host_allowed,
+        |url| {
+            let request = services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&url)
+                .attach_default_headers()
+                .headers(headers.clone())
+                .set_body(RequestContent::RawBytes(body.clone()))
+                .build();
+
+            state.api_client.send_request(
+                &state,
+                request,
+                Some(OUTGOING_WEBHOOK_TIMEOUT_SECS),
+                false,
+            )
+        },
+    )
+    .await;
+    let delivery_latency_ms =
+        i64::try_from(delivery_started_at.elapsed().as_millis()).unwrap_or(i64::MAX);
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         1,
         router_env::metric_attributes!((MERCHANT_ID, business_profile.merchant_id.clone())),
     );
-    logger::debug!(outgoing_webhook_response=?response);
+    logger::debug!(outgoing_webhook_response=?response, ?redirect_outcome);
+
+    if let Some(failure_reason) = redirect_outcome.failure_reason {
+        metrics::WEBHOOK_OUTGOING_REDIRECT_NOT_FOLLOWED_COUNT.add(
+            1,
+            router_env::metric_attributes!(
+                (MERCHANT_ID, business_profile.merchant_id.clone()),
+                ("failure_reason", failure_reason.to_string())
+            ),
+        );
+        logger::warn!(
+            ?failure_reason,
+            redirect_chain = ?redirect_outcome.redirect_chain,
+            "Webhook endpoint responded with a redirect which was not followed"
+        );
+    }
 
     match delivery_attempt {
         enums::WebhookDeliveryAttempt::InitialAttempt => match response {
@@ -319,6 +510,8 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     response,
+                    redirect_outcome,
+                    delivery_latency_ms,
                 )
                 .await?;
 
@@ -377,6 +570,8 @@ async fn trigger_webhook_to_merchant(
                         &business_profile.merchant_id,
                         &event_id,
                         response,
+                        redirect_outcome,
+                        delivery_latency_ms,
                     )
                     .await?;
 
@@ -410,7 +605,8 @@ async fn trigger_webhook_to_merchant(
                 }
             }
         }
-        enums::WebhookDeliveryAttempt::ManualRetry => match response {
+        enums::WebhookDeliveryAttempt::ManualRetry
+        | enums::WebhookDeliveryAttempt::ManualRetryWithOverrideUrl => match response {
             Err(client_error) => {
                 api_client_error_handler(
                     state.clone(),
@@ -431,6 +627,8 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     response,
+                    redirect_outcome,
+                    delivery_latency_ms,
                 )
                 .await?;
 
@@ -605,6 +803,208 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// The redirects encountered when delivering a webhook, along with the reason for not following
+/// the last one, if it was not followed.
+#[derive(Debug, Default)]
+struct WebhookRedirectOutcome {
+    redirect_chain: Vec<api_models::webhook_events::OutgoingWebhookRedirect>,
+    failure_reason: Option<enums::WebhookDeliveryFailureReason>,
+}
+
+/// Sends the webhook to the specified URL, handling redirect responses from the webhook endpoint
+/// according to the redirect policy. The API client does not follow redirects by itself, so the
+/// redirects are followed here, by sending the same request to the redirect target. In case a
+/// redirect is not followed, the redirect response is returned as is.
+async fn send_webhook_request_with_redirect_policy<F, Fut, G, GFut>(
+    webhook_url: &str,
+    redirect_policy: enums::WebhookRedirectPolicy,
+    is_redirect_target_allowed: G,
+    mut send_request: F,
+) -> (
+    CustomResult<reqwest::Response, errors::ApiClientError>,
+    WebhookRedirectOutcome,
+)
+where
+    F: FnMut(String) -> Fut,
+    Fut: std::future::Future<Output = CustomResult<reqwest::Response, errors::ApiClientError>>,
+    G: Fn(url::Url) -> GFut,
+    GFut: std::future::Future<Output = bool>,
+{
+    let mut redirect_outcome = WebhookRedirectOutcome::default();
+    let mut current_url = webhook_url.to_owned();
+
+    loop {
+        let response = match send_request(current_url.clone()).await {
+            Ok(response) if response.status().is_redirection() => response,
+            response => return (response, redirect_outcome),
+        };
+
+        let redirect_target = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| {
+                url::Url::parse(&current_url)
+                    .and_then(|current_url| current_url.join(location))
+                    .ok()
+            });
+        redirect_outcome
+            .redirect_chain
+            .push(api_models::webhook_events::OutgoingWebhookRedirect {
+                status_code: response.status().as_u16(),
+                location_host: redirect_target
+                    .as_ref()
+                    .and_then(|target| target.host_str())
+                    .map(ToOwned::to_owned),
+            });
+
+        let next_url = match (redirect_policy, redirect_target) {
+            (enums::WebhookRedirectPolicy::Refuse, _) => {
+                Err(enums::WebhookDeliveryFailureReason::RedirectRefused)
+            }
+            _ if redirect_outcome.redirect_chain.len() > OUTGOING_WEBHOOK_MAX_REDIRECTS => {
+                Err(enums::WebhookDeliveryFailureReason::RedirectLimitExceeded)
+            }
+            (_, None) => Err(enums::WebhookDeliveryFailureReason::RedirectTargetNotAllowed),
+            (enums::WebhookRedirectPolicy::FollowSameHost, Some(target))
+                if url::Url::parse(webhook_url)
+                    .ok()
+                    .and_then(|webhook_url| webhook_url.host_str().map(ToOwned::to_owned))
+                    .as_deref()
+                    != target.host_str() =>
+            {
+                Err(enums::WebhookDeliveryFailureReason::RedirectRefused)
+            }
+            (_, Some(target)) => Ok(target),
+        };
+        // The redirect target could be pointing to an internal network, validate it just like
+        // the webhook URL configured by the merchant
+        let next_url = match next_url {
+            Ok(target) if !is_redirect_target_allowed(target.clone()).await => {
+                Err(enums::WebhookDeliveryFailureReason::RedirectTargetNotAllowed)
+            }
+            next_url => next_url,
+        };
+
+        match next_url {
+            Ok(target) => current_url = target.into(),
+            Err(failure_reason) => {
+                redirect_outcome.failure_reason = Some(failure_reason);
+                return (Ok(response), redirect_outcome);
+            }
+        }
+    }
+}
+
+/// Checks whether webhooks can be delivered to the redirect target. Only HTTP(S) URLs are allowed,
+/// and hosts which point to the loopback interface or to private, link local or unspecified
+/// addresses are refused.
+fn is_webhook_redirect_target_allowed(target: &url::Url) -> bool {
+    if !matches!(target.scheme(), "http" | "https") {
+        return false;
+    }
+
+    match target.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(url::Host::Ipv4(address)) => {
+            is_webhook_redirect_address_allowed(std::net::IpAddr::V4(address))
+        }
+        Some(url::Host::Ipv6(address)) => {
+            is_webhook_redirect_address_allowed(std::net::IpAddr::V6(address))
+        }
+        None => false,
+    }
+}
+
+/// Checks whether webhooks can be delivered to the redirect target, as for
+/// [`is_webhook_redirect_target_allowed`]. A domain is resolved as well, and is refused if any of
+/// the addresses it resolves to is not allowed, so that a public domain cannot be used to reach
+/// an internal address.
+async fn is_webhook_redirect_target_host_allowed(target: url::Url) -> bool {
+    is_webhook_target_host_allowed_with_resolver(&target, |host, port| async move {
+        tokio::net::lookup_host((host.as_str(), port))
+            .await
+            .map(|addresses| addresses.map(|address| address.ip()).collect())
+    })
+    .await
+}
+
+async fn is_webhook_target_host_allowed_with_resolver<R, RFut>(
+    target: &url::Url,
+    resolve: R,
+) -> bool
+where
+    R: FnOnce(String, u16) -> RFut,
+    RFut: std::future::Future<Output = std::io::Result<Vec<std::net::IpAddr>>>,
+{
+    if !is_webhook_redirect_target_allowed(target) {
+        return false;
+    }
+
+    let (Some(url::Host::Domain(domain)), Some(port)) =
+        (target.host(), target.port_or_known_default())
+    else {
+        // The addresses in the URL have been validated already
+        return true;
+    };
+
+    match resolve(domain.to_owned(), port).await {
+        Ok(addresses) => {
+            !addresses.is_empty()
+                && addresses
+                    .into_iter()
+                    .all(is_webhook_redirect_address_allowed)
+        }
+        Err(error) => {
+            logger::warn!(?error, %domain, "Failed to resolve the webhook target host");
+            false
+        }
+    }
+}
+
+/// Checks whether a manual retry can be delivered to the URL specified to override the configured
+/// webhook URL. Only HTTPS URLs are allowed, and their hosts are validated as for redirect targets.
+fn is_webhook_url_override_allowed(webhook_url_override: &url::Url) -> bool {
+    webhook_url_override.scheme() == "https"
+        && is_webhook_redirect_target_allowed(webhook_url_override)
+}
+
+/// Checks whether a manual retry can be delivered to the override URL, resolving its host as for
+/// redirect targets.
+pub(crate) async fn is_webhook_url_override_host_allowed(webhook_url_override: url::Url) -> bool {
+    is_webhook_url_override_allowed(&webhook_url_override)
+        && is_webhook_redirect_target_host_allowed(webhook_url_override).await
+}
+
+fn is_webhook_redirect_address_allowed(address: std::net::IpAddr) -> bool {
+    match address {
+        std::net::IpAddr::V4(address) => is_webhook_redirect_ipv4_address_allowed(address),
+        std::net::IpAddr::V6(address) => match address.to_ipv4_mapped() {
+            Some(address) => is_webhook_redirect_ipv4_address_allowed(address),
+            None => {
+                let first_segment = address.segments()[0];
+                !(address.is_loopback()
+                    || address.is_unspecified()
+                    // Unique local addresses (fc00::/7)
+                    || (first_segment & 0xfe00) == 0xfc00
+                    // Link local addresses (fe80::/10)
+                    || (first_segment & 0xffc0) == 0xfe80)
+            }
+        },
+    }
+}
+
+fn is_webhook_redirect_ipv4_address_allowed(address: std::net::Ipv4Addr) -> bool {
+    !(address.is_loopback()
+        || address.is_private()
+        || address.is_link_local()
+        || address.is_unspecified()
+        || address.is_broadcast())
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
@@ -686,6 +1086,7 @@ async fn update_event_if_client_error(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     error_message: String,
+    delivery_status_code_class: enums::WebhookDeliveryStatusCodeClass,
 ) -> CustomResult<domain::Event, errors::WebhooksFlowError> {
     let is_webhook_notified = false;
     let key_manager_state = &(&state).into();
@@ -694,6 +1095,8 @@ async fn update_event_if_client_error(
         headers: None,
         status_code: None,
         error_message: Some(error_message),
+        redirect_chain: None,
+        failure_reason: None,
     };
 
     let event_update = domain::EventUpdate::UpdateResponse {
@@ -718,9 +1121,10 @@ async fn update_event_if_client_error(
             .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
+        delivery_latency_ms: None,
     };
 
-    state
+    let updated_event = state
         .store
         .update_event_by_merchant_id_event_id(
             key_manager_state,
@@ -730,7 +1134,18 @@ async fn update_event_if_client_error(
             &merchant_key_store,
         )
         .await
-        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+
+    update_latest_delivery_status_in_storage(
+        state.clone(),
+        merchant_key_store,
+        merchant_id,
+        &updated_event,
+        delivery_status_code_class,
+    )
+    .await?;
+
+    Ok(updated_event)
 }
 
 async fn api_client_error_handler(
@@ -742,6 +1157,12 @@ async fn api_client_error_handler(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     schedule_webhook_retry: ScheduleWebhookRetry,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
+    let delivery_status_code_class = if client_error.current_context().is_upstream_timeout() {
+        enums::WebhookDeliveryStatusCodeClass::Timeout
+    } else {
+        enums::WebhookDeliveryStatusCodeClass::ConnectionError
+    };
+
     // Not including detailed error message in response information since it contains too
     // much of diagnostic information to be exposed to the merchant.
     update_event_if_client_error(
@@ -750,6 +1171,7 @@ async fn api_client_error_handler(
         merchant_id,
         event_id,
         "Unable to send request to merchant server".to_string(),
+        delivery_status_code_class,
     )
     .await?;
 
@@ -780,6 +1202,8 @@ async fn update_event_in_storage(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     response: reqwest::Response,
+    redirect_outcome: WebhookRedirectOutcome,
+    delivery_latency_ms: i64,
 ) -> CustomResult<domain::Event, errors::WebhooksFlowError> {
     let status_code = response.status();
     let is_webhook_notified = status_code.is_success();
@@ -816,6 +1240,9 @@ async fn update_event_in_storage(
         headers: Some(response_headers),
         status_code: Some(status_code.as_u16()),
         error_message: None,
+        redirect_chain: (!redirect_outcome.redirect_chain.is_empty())
+            .then_some(redirect_outcome.redirect_chain),
+        failure_reason: redirect_outcome.failure_reason,
     };
 
     let event_update = domain::EventUpdate::UpdateResponse {
@@ -840,8 +1267,9 @@ async fn update_event_in_storage(
             .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
+        delivery_latency_ms: Some(delivery_latency_ms),
     };
-    state
+    let updated_event = state
         .store
         .update_event_by_merchant_id_event_id(
             key_manager_state,
@@ -851,7 +1279,54 @@ async fn update_event_in_storage(
             &merchant_key_store,
         )
         .await
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+
+    if let Some(delivery_status_code_class) =
+        enums::WebhookDeliveryStatusCodeClass::from_status_code(status_code.as_u16())
+    {
+        update_latest_delivery_status_in_storage(
+            state.clone(),
+            merchant_key_store,
+            merchant_id,
+            &updated_event,
+            delivery_status_code_class,
+        )
+        .await?;
+    }
+
+    Ok(updated_event)
+}
+
+/// Records the outcome of the delivery attempt on the initial delivery attempt of the event, so
+/// that the events can be listed by the outcome of their latest delivery attempt
+async fn update_latest_delivery_status_in_storage(
+    state: SessionState,
+    merchant_key_store: domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    updated_event: &domain::Event,
+    latest_delivery_status_code_class: enums::WebhookDeliveryStatusCodeClass,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let Some(initial_attempt_id) = updated_event.initial_attempt_id.as_ref() else {
+        return Ok(());
+    };
+    let key_manager_state = &(&state).into();
+
+    state
+        .store
+        .update_event_by_merchant_id_event_id(
+            key_manager_state,
+            merchant_id,
+            initial_attempt_id,
+            domain::EventUpdate::LatestDeliveryStatusUpdate {
+                latest_delivery_status_code_class,
+            },
+            &merchant_key_store,
+        )
+        .await
         .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+        .attach_printable("Failed to update the latest delivery status of the initial attempt")?;
+
+    Ok(())
 }
 
 async fn update_overall_delivery_status_in_storage(
@@ -1019,3 +1494,277 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
         },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+
+    async fn allow_all_redirect_targets(_target: url::Url) -> bool {
+        true
+    }
+
+    async fn mount_redirect(server: &MockServer, from: &str, status_code: u16, location: &str) {
+        Mock::given(method("POST"))
+            .and(path(from))
+            .respond_with(ResponseTemplate::new(status_code).insert_header("Location", location))
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_success(server: &MockServer, at: &str) {
+        Mock::given(method("POST"))
+            .and(path(at))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(server)
+            .await;
+    }
+
+    async fn send_webhook<G, GFut>(
+        webhook_url: &str,
+        redirect_policy: enums::WebhookRedirectPolicy,
+        is_redirect_target_allowed: G,
+    ) -> (reqwest::Response, WebhookRedirectOutcome)
+    where
+        G: Fn(url::Url) -> GFut,
+        GFut: std::future::Future<Output = bool>,
+    {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("failed to build client");
+        let (response, redirect_outcome) = send_webhook_request_with_redirect_policy(
+            webhook_url,
+            redirect_policy,
+            is_redirect_target_allowed,
+            |url| {
+                let request = client.post(url).body("{}");
+                async move {
+                    request
+                        .send()
+                        .await
+                        .change_context(errors::ApiClientError::RequestNotSent(
+                            "webhook".to_string(),
+                        ))
+                }
+            },
+        )
+        .await;
+
+        (response.expect("failed to send webhook"), redirect_outcome)
+    }
+
+    #[tokio::test]
+    async fn test_redirect_is_refused_by_default() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/webhook", 301, "/moved").await;
+        mount_success(&server, "/moved").await;
+
+        let (response, redirect_outcome) = send_webhook(
+            &format!("{}/webhook", server.uri()),
+            enums::WebhookRedirectPolicy::default(),
+            allow_all_redirect_targets,
+        )
+        .await;
+
+        assert_eq!(response.status().as_u16(), 301);
+        assert_eq!(
+            redirect_outcome.failure_reason,
+            Some(enums::WebhookDeliveryFailureReason::RedirectRefused)
+        );
+        assert_eq!(
+            redirect_outcome.redirect_chain,
+            vec![api_models::webhook_events::OutgoingWebhookRedirect {
+                status_code: 301,
+                location_host: Some(server.address().ip().to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_same_host_is_followed() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/webhook", 307, "/moved").await;
+        mount_success(&server, "/moved").await;
+
+        let (response, redirect_outcome) = send_webhook(
+            &format!("{}/webhook", server.uri()),
+            enums::WebhookRedirectPolicy::FollowSameHost,
+            allow_all_redirect_targets,
+        )
+        .await;
+
+        assert!(response.status().is_success());
+        assert_eq!(redirect_outcome.failure_reason, None);
+        assert_eq!(redirect_outcome.redirect_chain.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_different_host_is_refused_for_same_host_policy() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/webhook", 308, "https://example.com/webhook").await;
+
+        let (response, redirect_outcome) = send_webhook(
+            &format!("{}/webhook", server.uri()),
+            enums::WebhookRedirectPolicy::FollowSameHost,
+            allow_all_redirect_targets,
+        )
+        .await;
+
+        assert_eq!(response.status().as_u16(), 308);
+        assert_eq!(
+            redirect_outcome.failure_reason,
+            Some(enums::WebhookDeliveryFailureReason::RedirectRefused)
+        );
+        assert_eq!(
+            redirect_outcome.redirect_chain[0].location_host.as_deref(),
+            Some("example.com")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_redirects_are_followed_up_to_the_limit() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/webhook", 302, "/first").await;
+        mount_redirect(&server, "/first", 302, "/second").await;
+        mount_success(&server, "/second").await;
+
+        let (response, redirect_outcome) = send_webhook(
+            &format!("{}/webhook", server.uri()),
+            enums::WebhookRedirectPolicy::Follow,
+            allow_all_redirect_targets,
+        )
+        .await;
+
+        assert!(response.status().is_success());
+        assert_eq!(redirect_outcome.failure_reason, None);
+        assert_eq!(redirect_outcome.redirect_chain.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_redirects_beyond_the_limit_are_not_followed() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/webhook", 302, "/first").await;
+        mount_redirect(&server, "/first", 302, "/second").await;
+        mount_redirect(&server, "/second", 302, "/third").await;
+        mount_success(&server, "/third").await;
+
+        let (response, redirect_outcome) = send_webhook(
+            &format!("{}/webhook", server.uri()),
+            enums::WebhookRedirectPolicy::Follow,
+            allow_all_redirect_targets,
+        )
+        .await;
+
+        assert_eq!(response.status().as_u16(), 302);
+        assert_eq!(
+            redirect_outcome.failure_reason,
+            Some(enums::WebhookDeliveryFailureReason::RedirectLimitExceeded)
+        );
+        assert_eq!(redirect_outcome.redirect_chain.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_internal_network_is_not_followed() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/webhook", 302, "/internal").await;
+        mount_success(&server, "/internal").await;
+
+        let (response, redirect_outcome) = send_webhook(
+            &format!("{}/webhook", server.uri()),
+            enums::WebhookRedirectPolicy::Follow,
+            is_webhook_redirect_target_host_allowed,
+        )
+        .await;
+
+        assert_eq!(response.status().as_u16(), 302);
+        assert_eq!(
+            redirect_outcome.failure_reason,
+            Some(enums::WebhookDeliveryFailureReason::RedirectTargetNotAllowed)
+        );
+    }
+
+    #[test]
+    fn test_webhook_redirect_target_validation() {
+        for (target, is_allowed) in [
+            ("https://example.com/webhook", true),
+            ("http://93.184.216.34/webhook", true),
+            ("ftp://example.com/webhook", false),
+            ("http://localhost:8080/webhook", false),
+            ("http://127.0.0.1/webhook", false),
+            ("http://10.0.0.1/webhook", false),
+            ("http://192.168.1.1/webhook", false),
+            ("http://169.254.169.254/latest/meta-data", false),
+            ("http://[::1]/webhook", false),
+            ("http://[fd00::1]/webhook", false),
+            ("http://[::ffff:127.0.0.1]/webhook", false),
+        ] {
+            let target = url::Url::parse(target).expect("valid URL");
+            assert_eq!(
+                is_webhook_redirect_target_allowed(&target),
+                is_allowed,
+                "unexpected validation result for {target}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_webhook_redirect_target_resolving_to_internal_address_is_refused() {
+        let target = url::Url::parse("https://webhooks.merchant.example/hook").expect("valid URL");
+        let resolve_to = |addresses: &'static [&'static str]| {
+            move |_host: String, _port: u16| async move {
+                Ok(addresses
+                    .iter()
+                    .map(|address| address.parse().expect("valid address"))
+                    .collect())
+            }
+        };
+
+        for (addresses, is_allowed) in [
+            (&["93.184.216.34"][..], true),
+            (&["10.0.0.5"][..], false),
+            (&["169.254.169.254"][..], false),
+            (&["93.184.216.34", "192.168.1.1"][..], false),
+            (&["fd00::1"][..], false),
+            (&[][..], false),
+        ] {
+            assert_eq!(
+                is_webhook_target_host_allowed_with_resolver(&target, resolve_to(addresses)).await,
+                is_allowed,
+                "unexpected validation result for {addresses:?}"
+            );
+        }
+
+        assert!(
+            !is_webhook_target_host_allowed_with_resolver(&target, |_host, _port| async {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "failed to resolve",
+                ))
+            })
+            .await
+        );
+    }
+
+    #[test]
+    fn test_webhook_url_override_validation() {
+        for (webhook_url_override, is_allowed) in [
+            ("https://merchant.example.com/v2/webhooks", true),
+            ("http://merchant.example.com/v2/webhooks", false),
+            ("https://localhost/webhooks", false),
+            ("https://10.0.0.1/webhooks", false),
+            ("https://[::1]/webhooks", false),
+        ] {
+            let webhook_url_override = url::Url::parse(webhook_url_override).expect("valid URL");
+            assert_eq!(
+                is_webhook_url_override_allowed(&webhook_url_override),
+                is_allowed,
+                "unexpected validation result for {webhook_url_override}"
+            );
+        }
+    }
+}
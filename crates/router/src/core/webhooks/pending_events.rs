@@ -0,0 +1,245 @@
+//! Outgoing webhook events raised by a flow, created once the flow ends.
+//!
+//! The events are raised only after the update of the payment or refund which they report has
+//! been committed by the store. A flow run through [`create_events_after_commit`] holds back the
+//! events raised by it until the flow ends, so that an event raised again by a later update of the
+//! flow is created once. The events are created whether the flow succeeds, fails or is cancelled,
+//! since the state they report has already persisted. Events raised outside of such a flow are
+//! created right away.
+
+use std::{
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Instant,
+};
+
+use futures::future::BoxFuture;
+use router_env::{logger, tracing::Instrument};
+
+use crate::core::metrics;
+
+tokio::task_local! {
+    static PENDING_EVENTS: Arc<PendingEvents>;
+}
+
+struct PendingEvent {
+    idempotent_event_id: String,
+    raised_at: Instant,
+    create_event: BoxFuture<'static, ()>,
+}
+
+#[derive(Default)]
+struct PendingEvents {
+    events: Mutex<Vec<PendingEvent>>,
+}
+
+impl PendingEvents {
+    /// An event raised again by the flow replaces the pending one, since an event is created only
+    /// once for its idempotent event id
+    fn push(&self, event: PendingEvent) {
+        let mut events = self.lock();
+        events
+            .retain(|pending_event| pending_event.idempotent_event_id != event.idempotent_event_id);
+        events.push(event);
+    }
+
+    fn take(&self) -> Vec<PendingEvent> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<PendingEvent>> {
+        // The events are only pushed and taken as a whole, so a poisoned lock still holds them
+        // intact
+        self.events.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Creates the events still pending once the flow ends, including when the flow is cancelled by
+/// being dropped before it completes
+struct FlushOnDrop(Arc<PendingEvents>);
+
+impl Drop for FlushOnDrop {
+    fn drop(&mut self) {
+        flush_events(self.0.take());
+    }
+}
+
+/// Runs the flow, creating the outgoing webhook events raised by it once the flow ends
+pub async fn create_events_after_commit<F, T, E>(flow: F) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    // A flow run within another one ends along with the outer flow
+    if PENDING_EVENTS.try_with(|_| ()).is_ok() {
+        return flow.await;
+    }
+
+    let pending_events = FlushOnDrop(Arc::new(PendingEvents::default()));
+    let result = PENDING_EVENTS
+        .scope(Arc::clone(&pending_events.0), flow)
+        .await;
+
+    if result.is_err() {
+        // The failure happened after the updates which raised the events were committed
+        metrics::WEBHOOK_OUTGOING_PENDING_EVENT_OF_FAILED_FLOW_COUNT.add(
+            u64::try_from(pending_events.0.lock().len()).unwrap_or(u64::MAX),
+            &[],
+        );
+    }
+    drop(pending_events);
+
+    result
+}
+
+/// Creates the outgoing webhook event once the flow which raised it ends, or right away if it was
+/// not raised by a flow run through [`create_events_after_commit`]. It must only be called once
+/// the update which the event reports has been committed.
+pub fn create_event_after_commit<F>(idempotent_event_id: String, create_event: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let mut event = Some(PendingEvent {
+        idempotent_event_id,
+        raised_at: Instant::now(),
+        create_event: Box::pin(create_event.in_current_span()),
+    });
+
+    // Not being within a flow is not an error, the event is created right away in that case
+    let _ = PENDING_EVENTS.try_with(|pending_events| {
+        if let Some(event) = event.take() {
+            pending_events.push(event);
+        }
+    });
+
+    if let Some(event) = event {
+        tokio::spawn(event.create_event);
+    }
+}
+
+fn flush_events(events: Vec<PendingEvent>) {
+    if events.is_empty() {
+        return;
+    }
+
+    let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+        logger::error!(
+            pending_events = events.len(),
+            "Outgoing webhook events could not be created since the runtime has shut down"
+        );
+        return;
+    };
+
+    for event in events {
+        metrics::WEBHOOK_OUTGOING_PENDING_EVENT_LAG
+            .record(event.raised_at.elapsed().as_secs_f64(), &[]);
+        metrics::WEBHOOK_OUTGOING_PENDING_EVENT_FLUSHED_COUNT.add(1, &[]);
+        // The lifecycle of the spawned task is not tied to the request, as for the events created
+        // right away
+        runtime.spawn(event.create_event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    fn raise_event(idempotent_event_id: &str, created_events: &mpsc::UnboundedSender<String>) {
+        let created_events = created_events.clone();
+        let event_id = idempotent_event_id.to_string();
+        create_event_after_commit(idempotent_event_id.to_string(), async move {
+            let _ = created_events.send(event_id);
+        });
+    }
+
+    async fn collect_event_ids(
+        mut created_event_ids: mpsc::UnboundedReceiver<String>,
+    ) -> Vec<String> {
+        let mut event_ids = Vec::new();
+        while let Some(event_id) = created_event_ids.recv().await {
+            event_ids.push(event_id);
+        }
+        event_ids.sort();
+        event_ids
+    }
+
+    #[tokio::test]
+    async fn test_events_of_failed_flow_are_created() {
+        let (created_events, created_event_ids) = mpsc::unbounded_channel();
+
+        let result = create_events_after_commit(async {
+            // The payment update which raised the event is committed before the flow fails
+            raise_event("pay_1_payment_succeeded", &created_events);
+            Err::<(), _>("response generation failed")
+        })
+        .await;
+        assert!(result.is_err());
+        drop(created_events);
+
+        assert_eq!(
+            collect_event_ids(created_event_ids).await,
+            vec![String::from("pay_1_payment_succeeded")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_of_cancelled_flow_are_created() {
+        let (created_events, created_event_ids) = mpsc::unbounded_channel();
+        let (event_raised, wait_for_event) = tokio::sync::oneshot::channel();
+
+        let flow = tokio::spawn({
+            let created_events = created_events.clone();
+            create_events_after_commit(async move {
+                raise_event("re_1_refund_succeeded", &created_events);
+                let _ = event_raised.send(());
+                // The request is dropped by the client before the flow completes
+                std::future::pending::<Result<(), &str>>().await
+            })
+        });
+        wait_for_event.await.expect("event raised");
+        flow.abort();
+        assert!(flow.await.is_err());
+        drop(created_events);
+
+        assert_eq!(
+            collect_event_ids(created_event_ids).await,
+            vec![String::from("re_1_refund_succeeded")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_of_successful_flow_are_created_once_after_it_ends() {
+        let (created_events, mut created_event_ids) = mpsc::unbounded_channel();
+
+        let result = create_events_after_commit(async {
+            raise_event("pay_1_payment_processing", &created_events);
+            raise_event("pay_1_payment_succeeded", &created_events);
+            raise_event("pay_1_payment_succeeded", &created_events);
+            // A nested flow ends along with the outer flow
+            create_events_after_commit(async {
+                raise_event("re_1_refund_succeeded", &created_events);
+                Ok::<(), &str>(())
+            })
+            .await
+            .expect("nested flow succeeded");
+            tokio::task::yield_now().await;
+            assert!(created_event_ids.try_recv().is_err());
+            Ok::<(), &str>(())
+        })
+        .await;
+        assert!(result.is_ok());
+        drop(created_events);
+
+        assert_eq!(
+            collect_event_ids(created_event_ids).await,
+            vec![
+                String::from("pay_1_payment_processing"),
+                String::from("pay_1_payment_succeeded"),
+                String::from("re_1_refund_succeeded"),
+            ]
+        );
+    }
+}
@@ -3,7 +3,7 @@ use std::{marker::PhantomData, str::FromStr};
 use api_models::{payments as api_payments, webhooks};
 use common_utils::{
     ext_traits::{AsyncExt, ValueExt},
-    id_type,
+    id_type, pii,
 };
 use diesel_models::{process_tracker as storage, schema::process_tracker::retry_count};
 use error_stack::{report, ResultExt};
@@ -13,13 +13,22 @@ use hyperswitch_domain_models::{
     router_response_types::revenue_recovery as revenue_recovery_response, types as router_types,
 };
 use hyperswitch_interfaces::webhooks as interface_webhooks;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use rand::Rng;
 use router_env::{instrument, tracing};
 use serde_with::rust::unwrap_or_skip;
 
 use crate::{
+    consts,
     core::{
+        customers,
         errors::{self, CustomResult},
         payments::{self, helpers},
+        revenue_recovery::{
+            self as core_revenue_recovery, billing_connector_proxy,
+            normalization as recovery_normalization, payments_sync_auth, payments_sync_override,
+            payments_sync_rate_limit, types as revenue_recovery_types,
+        },
     },
     db::{errors::RevenueRecoveryError, StorageInterface},
     routes::{app::ReqState, metrics, SessionState},
@@ -28,11 +37,12 @@ use crate::{
         connector_integration_interface::{self, RouterDataConversion},
     },
     types::{self, api, domain, storage::revenue_recovery as storage_churn_recovery},
+    utils::fault_injection::inject_recovery_fault,
     workflows::revenue_recovery as revenue_recovery_flow,
 };
 
 #[allow(clippy::too_many_arguments)]
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(recovery_correlation_id))]
 #[cfg(feature = "revenue_recovery")]
 pub async fn recovery_incoming_webhook_flow(
     state: SessionState,
@@ -60,77 +70,410 @@ pub async fn recovery_incoming_webhook_flow(
         .change_context(errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed)
         .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
 
-    let billing_connectors_with_payment_sync_call = &state.conf.billing_connectors_payment_sync;
+    let recovery_correlation_id = get_recovery_correlation_id(request_details.headers);
+    tracing::Span::current().record("recovery_correlation_id", recovery_correlation_id.as_str());
 
-    let should_billing_connector_payment_api_called = billing_connectors_with_payment_sync_call
-        .billing_connectors_which_require_payment_sync
-        .contains(&connector);
+    // Subscription events do not carry any invoice, recovery of all the open invoices of the
+    // subscription is stopped
+    if event_type == webhooks::IncomingWebhookEvent::RecoverySubscriptionCancel {
+        let webhooks::ObjectReferenceId::InvoiceId(
+            webhooks::InvoiceIdType::ConnectorSubscriptionId(subscription_id),
+        ) = object_ref_id
+        else {
+            return Err(report!(
+                errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed
+            ))
+            .attach_printable("Subscription id not found in the subscription cancelled webhook");
+        };
+
+        return handle_subscription_cancellation(
+            &state,
+            &merchant_account,
+            &business_profile,
+            &key_store,
+            subscription_id,
+        )
+        .await;
+    }
+
+    // Dispute events refer to the customer, the retries of all the open invoices of the customer
+    // are paused until the dispute is resolved
+    if matches!(
+        event_type,
+        webhooks::IncomingWebhookEvent::RecoveryDisputeOpened
+            | webhooks::IncomingWebhookEvent::RecoveryDisputeClosed
+    ) {
+        let webhooks::ObjectReferenceId::InvoiceId(webhooks::InvoiceIdType::ConnectorCustomerId(
+            connector_customer_id,
+        )) = object_ref_id
+        else {
+            return Err(report!(
+                errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed
+            ))
+            .attach_printable("Customer id not found in the dispute webhook");
+        };
+
+        let dispute_hold = (event_type == webhooks::IncomingWebhookEvent::RecoveryDisputeOpened)
+            .then(|| {
+                let held_at = common_utils::date_time::now();
+                api_payments::RevenueRecoveryDisputeHold {
+                    held_at,
+                    expires_at: held_at
+                        + time::Duration::seconds(i64::from(
+                            state
+                                .conf
+                                .revenue_recovery
+                                .dispute_hold_max_duration_in_secs,
+                        )),
+                }
+            });
+
+        return handle_customer_dispute(
+            &state,
+            &merchant_account,
+            &business_profile,
+            &key_store,
+            connector_customer_id,
+            dispute_hold,
+        )
+        .await;
+    }
+
+    let mut decision_details = RecoveryDecisionDetails {
+        recovery_correlation_id,
+        ..Default::default()
+    };
+    let response = Box::pin(process_recovery_invoice_webhook(
+        &state,
+        &merchant_account,
+        &business_profile,
+        &key_store,
+        connector_enum,
+        &billing_connector_account,
+        connector_name,
+        connector,
+        request_details,
+        event_type,
+        &req_state,
+        object_ref_id,
+        &mut decision_details,
+    ))
+    .await;
+
+    let data_provenance = state
+        .conf
+        .revenue_recovery
+        .record_data_provenance
+        .then_some((
+            &decision_details.invoice_data_provenance,
+            &decision_details.attempt_data_provenance,
+        ));
+    router_env::logger::info!(recovery_webhook_response = ?response, ?data_provenance);
+
+    // Decisions are recorded for the failed webhooks as well, so that they can be debugged later
+    decision_details
+        .record(
+            &state,
+            &merchant_account,
+            &business_profile,
+            event_type,
+            &response,
+        )
+        .await;
+
+    response
+}
+
+/// Error returned to the billing connector for a failed webhook. The billing connector is asked to
+/// redeliver the webhook whose payments sync was rate limited once the rate limit allows it.
+pub(crate) fn get_recovery_webhook_api_error(
+    error: &errors::RevenueRecoveryError,
+) -> errors::ApiErrorResponse {
+    match error {
+        errors::RevenueRecoveryError::BillingConnectorPaymentsSyncRateLimited {
+            retry_after_in_secs,
+        } => errors::ApiErrorResponse::TooManyRequests {
+            retry_after: *retry_after_in_secs,
+        },
+        errors::RevenueRecoveryError::BillingConnectorAccountMisconfigured { config } => {
+            errors::ApiErrorResponse::InvalidConnectorConfiguration {
+                config: config.clone(),
+            }
+        }
+        errors::RevenueRecoveryError::BillingConnectorProxyMisconfigured => {
+            errors::ApiErrorResponse::InvalidConnectorConfiguration {
+                config: String::from("connector_account_details.outbound_proxy"),
+            }
+        }
+        _ => errors::ApiErrorResponse::WebhookProcessingFailure,
+    }
+}
+
+/// Maximum length of the recovery correlation id taken from the request headers
+const MAX_RECOVERY_CORRELATION_ID_LENGTH: usize = 64;
+
+/// Correlation id which ties the webhook to the sync calls, the attempts, the tasks and the
+/// decision that originate from it. The id set by the caller in the request headers is used so that
+/// the webhook can be traced from its sender, a new id being generated when none is set or the one
+/// set is not a valid id. The id set is to be unique to the delivery of the webhook, as it is the id
+/// of the decision recorded for the webhook.
+pub(crate) fn get_recovery_correlation_id(headers: &actix_web::http::header::HeaderMap) -> String {
+    headers
+        .get(crate::headers::X_RECOVERY_CORRELATION_ID)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|correlation_id| {
+            !correlation_id.is_empty()
+                && correlation_id.len() <= MAX_RECOVERY_CORRELATION_ID_LENGTH
+                && correlation_id
+                    .chars()
+                    .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-')
+        })
+        .map(ToOwned::to_owned)
+        .unwrap_or_else(|| common_utils::generate_time_ordered_id("rrd"))
+}
+
+/// Error of a failed sync call to the billing connector. The billing connector failing to build
+/// the request for the missing or invalid metadata of the account is told apart as a configuration
+/// error, since redelivering the webhook does not fix it.
+fn get_billing_connector_sync_error(
+    error: &errors::ConnectorError,
+    sync_failure: errors::RevenueRecoveryError,
+) -> errors::RevenueRecoveryError {
+    match error {
+        errors::ConnectorError::InvalidConnectorConfig { config } => {
+            errors::RevenueRecoveryError::BillingConnectorAccountMisconfigured {
+                config: config.to_string(),
+            }
+        }
+        errors::ConnectorError::NoConnectorMetaData => {
+            errors::RevenueRecoveryError::BillingConnectorAccountMisconfigured {
+                config: String::from("metadata"),
+            }
+        }
+        _ => sync_failure,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[cfg(feature = "revenue_recovery")]
+async fn process_recovery_invoice_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    connector_enum: &connector_integration_interface::ConnectorEnum,
+    billing_connector_account: &hyperswitch_domain_models::merchant_connector_account::MerchantConnectorAccount,
+    connector_name: &str,
+    connector: api_models::enums::Connector,
+    request_details: &hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails<'_>,
+    event_type: webhooks::IncomingWebhookEvent,
+    req_state: &ReqState,
+    object_ref_id: &webhooks::ObjectReferenceId,
+    decision_details: &mut RecoveryDecisionDetails,
+) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
+    let merchant_override = payments_sync_override::get_payments_sync_override(
+        state.store.as_ref(),
+        merchant_account.get_id(),
+    )
+    .await;
+    let (should_billing_connector_payment_api_called, decision_source) =
+        payments_sync_override::decide_payments_sync(
+            merchant_override,
+            billing_connector_account.get_recovery_payments_sync_required(),
+            state
+                .conf
+                .billing_connectors_payment_sync
+                .billing_connectors_which_require_payment_sync
+                .contains(&connector),
+        );
+    payments_sync_override::record_payments_sync_decision(
+        connector_name,
+        should_billing_connector_payment_api_called,
+        decision_source,
+    );
+    decision_details.is_billing_connector_payment_sync_called =
+        should_billing_connector_payment_api_called;
 
     let billing_connector_payment_details =
         BillingConnectorPaymentsSyncResponseData::get_billing_connector_payment_details(
             should_billing_connector_payment_api_called,
-            &state,
-            &merchant_account,
-            &billing_connector_account,
+            state,
+            merchant_account,
+            billing_connector_account,
             connector_name,
             object_ref_id,
+            connector_enum,
+            request_details,
+            event_type,
+            &decision_details.recovery_correlation_id,
         )
         .await?;
+    // The payments sync is skipped when the billing connector is unavailable and the webhook
+    // carries the details by itself
+    decision_details.is_billing_connector_payment_sync_called =
+        billing_connector_payment_details.is_some();
 
     // Checks whether we have data in recovery_details , If its there then it will use the data and convert it into required from or else fetches from Incoming webhook
 
+    // The invoice can be fetched from the billing connector only for the webhooks of the invoice
+    let invoice_sync_call = match object_ref_id {
+        webhooks::ObjectReferenceId::InvoiceId(webhooks::InvoiceIdType::ConnectorInvoiceId(
+            invoice_id,
+        )) if connector.supports_billing_connector_invoice_sync() => {
+            Some(BillingConnectorInvoiceSyncCall {
+                state,
+                merchant_account,
+                billing_connector_account,
+                connector_name,
+                invoice_id,
+            })
+        }
+        _ => None,
+    };
     let invoice_details = RevenueRecoveryInvoice::get_recovery_invoice_details(
         connector_enum,
+        connector,
         request_details,
         billing_connector_payment_details.as_ref(),
-    )?;
+        invoice_sync_call,
+    )
+    .await?;
+    decision_details.invoice_data_provenance = Some(invoice_details.1.clone());
+
+    // Trial conversions and fully credited invoices have no amount to be recovered
+    if invoice_details.is_zero_amount_invoice() {
+        return invoice_details
+            .handle_zero_amount_invoice(
+                state,
+                req_state,
+                merchant_account,
+                business_profile,
+                key_store,
+                connector,
+            )
+            .await;
+    }
+
+    // Keep the contact details of the customer up to date, so that dunning notifications reach the customer.
+    let customer_id = invoice_details
+        .upsert_customer(state, merchant_account, key_store)
+        .await?;
 
     // Fetch the intent using merchant reference id, if not found create new intent.
     let payment_intent = invoice_details
         .get_payment_intent(
-            &state,
-            &req_state,
-            &merchant_account,
-            &business_profile,
-            &key_store,
+            state,
+            req_state,
+            merchant_account,
+            business_profile,
+            key_store,
         )
         .await
         .transpose()
         .async_unwrap_or_else(|| async {
             invoice_details
                 .create_payment_intent(
-                    &state,
-                    &req_state,
-                    &merchant_account,
-                    &business_profile,
-                    &key_store,
+                    state,
+                    req_state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    customer_id,
                 )
                 .await
         })
         .await?;
+    decision_details.payment_id = Some(payment_intent.payment_id.clone());
+
+    // A webhook of the invoice shows that the webhooks of the invoice are being delivered, the
+    // polling of the invoice is pushed back. Failure in scheduling the polling does not fail the
+    // webhook.
+    if event_type != webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel {
+        if let Err(error) =
+            core_revenue_recovery::payments_sync_polling::schedule_payments_sync_polling_task(
+                state,
+                billing_connector_account,
+                &payment_intent.payment_id,
+                &invoice_details.0.merchant_reference_id,
+            )
+            .await
+        {
+            router_env::logger::error!(?error, "Failed to schedule the payments sync polling");
+        }
+    }
 
     let is_event_recovery_transaction_event = event_type.is_recovery_transaction_event();
     let (recovery_attempt_from_payment_attempt, recovery_intent_from_payment_attempt) =
         RevenueRecoveryAttempt::get_recovery_payment_attempt(
             is_event_recovery_transaction_event,
-            &billing_connector_account,
-            &state,
-            &key_store,
+            billing_connector_account,
+            state,
+            key_store,
             connector_enum,
-            &req_state,
+            connector,
+            req_state,
             billing_connector_payment_details.as_ref(),
             request_details,
-            &merchant_account,
-            &business_profile,
+            merchant_account,
+            business_profile,
             &payment_intent,
+            decision_details,
         )
         .await?;
 
+    handle_recovery_action(
+        state,
+        merchant_account,
+        business_profile,
+        key_store,
+        billing_connector_account,
+        event_type,
+        recovery_attempt_from_payment_attempt,
+        recovery_intent_from_payment_attempt,
+        decision_details,
+    )
+    .await
+}
+
+/// Takes the recovery action of the event on the payment, once the attempt of the event, if any,
+/// has been recorded
+#[allow(clippy::too_many_arguments)]
+async fn handle_recovery_action(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+    event_type: webhooks::IncomingWebhookEvent,
+    recovery_attempt_from_payment_attempt: Option<revenue_recovery::RecoveryPaymentAttempt>,
+    recovery_intent_from_payment_attempt: revenue_recovery::RecoveryPaymentIntent,
+    decision_details: &mut RecoveryDecisionDetails,
+) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
+    // The attempt of a transaction event is only left unrecorded when the payment has been held in
+    // review for its unresolved payment connector
+    if event_type.is_recovery_transaction_event() && recovery_attempt_from_payment_attempt.is_none()
+    {
+        return Ok(webhooks::WebhookResponseTracker::Recovery {
+            payment_id: recovery_intent_from_payment_attempt.payment_id.clone(),
+            status: recovery_intent_from_payment_attempt.status,
+            action: webhooks::RecoveryWebhookAction::PaymentConnectorUnresolved,
+            task_id: Some(
+                core_revenue_recovery::connector_resolution::get_unresolved_connector_task_id(
+                    &recovery_intent_from_payment_attempt.payment_id,
+                    storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+                ),
+            ),
+        });
+    }
+
     let attempt_triggered_by = recovery_attempt_from_payment_attempt
         .as_ref()
         .and_then(|attempt| attempt.get_attempt_triggered_by());
 
     let action = revenue_recovery::RecoveryAction::get_action(event_type, attempt_triggered_by);
+    decision_details.attempt_triggered_by = attempt_triggered_by;
+    decision_details.recovery_action = Some(get_recovery_action_name(&action));
 
     let mca_retry_threshold = billing_connector_account
         .get_retry_threshold()
@@ -146,131 +489,1021 @@ pub async fn recovery_incoming_webhook_flow(
 
     router_env::logger::info!("Intent retry count: {:?}", intent_retry_count);
 
+    decision_details.retry_count = Some(intent_retry_count);
+    decision_details.retry_threshold = Some(mca_retry_threshold);
+
+    let payment_id = recovery_intent_from_payment_attempt.payment_id.clone();
+    let intent_status = recovery_intent_from_payment_attempt.status;
+
     match action {
-        revenue_recovery::RecoveryAction::CancelInvoice => todo!(),
+        revenue_recovery::RecoveryAction::CancelInvoice => {
+            handle_invoice_cancellation(
+                state,
+                merchant_account,
+                key_store,
+                payment_id,
+                intent_status,
+            )
+            .await
+        }
+        revenue_recovery::RecoveryAction::CancelSubscription => Err(report!(
+            errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed
+        ))
+        .attach_printable("Subscription events are not associated with an invoice"),
+        revenue_recovery::RecoveryAction::HoldForDispute
+        | revenue_recovery::RecoveryAction::ReleaseDisputeHold => Err(report!(
+            errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed
+        ))
+        .attach_printable("Dispute events are not associated with an invoice"),
         revenue_recovery::RecoveryAction::ScheduleFailedPayment => {
             handle_schedule_failed_payment(
-                &billing_connector_account,
+                billing_connector_account,
                 intent_retry_count,
                 mca_retry_threshold,
-                &state,
-                &merchant_account,
+                state,
+                merchant_account,
                 &(
                     recovery_attempt_from_payment_attempt,
                     recovery_intent_from_payment_attempt,
                 ),
-                &business_profile,
+                business_profile,
+                &decision_details.recovery_correlation_id,
             )
             .await
         }
         revenue_recovery::RecoveryAction::SuccessPaymentExternal => {
-            // Need to add recovery stop flow for this scenario
-            router_env::logger::info!("Payment has been succeeded via external system");
-            Ok(webhooks::WebhookResponseTracker::NoEffect)
+            // The payment intent has been moved to the status of the recorded attempt already, the
+            // pending retry of the payment is no longer needed
+            let cancelled_task_id = RevenueRecoveryAttempt::cancel_pending_execute_pcr_task(
+                &*state.store,
+                &payment_id,
+                storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+                storage::business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_EXTERNAL_PAYMENT,
+            )
+            .await?;
+            router_env::logger::info!(
+                ?cancelled_task_id,
+                "Payment has been succeeded via external system"
+            );
+            Ok(webhooks::WebhookResponseTracker::Recovery {
+                payment_id,
+                status: intent_status,
+                action: webhooks::RecoveryWebhookAction::SuccessPaymentExternal,
+                task_id: cancelled_task_id,
+            })
         }
         revenue_recovery::RecoveryAction::PendingPayment => {
             router_env::logger::info!(
                 "Pending transactions are not consumed by the revenue recovery webhooks"
             );
-            Ok(webhooks::WebhookResponseTracker::NoEffect)
+            Ok(webhooks::WebhookResponseTracker::Recovery {
+                payment_id,
+                status: intent_status,
+                action: webhooks::RecoveryWebhookAction::PendingPayment,
+                task_id: None,
+            })
         }
         revenue_recovery::RecoveryAction::NoAction => {
             router_env::logger::info!(
                 "No Recovery action is taken place for recovery event : {:?} and attempt triggered_by : {:?} ", event_type.clone(), attempt_triggered_by
             );
-            Ok(webhooks::WebhookResponseTracker::NoEffect)
+            Ok(webhooks::WebhookResponseTracker::Recovery {
+                payment_id,
+                status: intent_status,
+                action: webhooks::RecoveryWebhookAction::NoAction,
+                task_id: None,
+            })
         }
         revenue_recovery::RecoveryAction::InvalidAction => {
             router_env::logger::error!(
                 "Invalid Revenue recovery action state has been received, event : {:?}, triggered_by : {:?}", event_type, attempt_triggered_by
             );
-            Ok(webhooks::WebhookResponseTracker::NoEffect)
+            Ok(webhooks::WebhookResponseTracker::Recovery {
+                payment_id,
+                status: intent_status,
+                action: webhooks::RecoveryWebhookAction::InvalidAction,
+                task_id: None,
+            })
         }
     }
 }
 
-async fn handle_schedule_failed_payment(
-    billing_connector_account: &domain::MerchantConnectorAccount,
-    intent_retry_count: u16,
-    mca_retry_threshold: u16,
+/// Number of payment intents of a subscription fetched at once, while cancelling the subscription
+const SUBSCRIPTION_INVOICES_PAGE_SIZE: i64 = 100;
+
+/// Upper bound on the number of pages looked up for a single subscription
+const SUBSCRIPTION_INVOICES_MAX_PAGES: i64 = 10;
+
+/// Stops the recovery of all the open invoices of a cancelled subscription. The pending retries
+/// of the invoices are cancelled and the payment intents are marked as cancelled.
+async fn handle_subscription_cancellation(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
-    payment_attempt_with_recovery_intent: &(
-        Option<revenue_recovery::RecoveryPaymentAttempt>,
-        revenue_recovery::RecoveryPaymentIntent,
-    ),
     business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    subscription_id: &str,
 ) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
-    let (recovery_attempt_from_payment_attempt, recovery_intent_from_payment_attempt) =
-        payment_attempt_with_recovery_intent;
-    (intent_retry_count <= mca_retry_threshold)
-        .then(|| {
-            router_env::logger::error!(
-                "Payment retry count {} is less than threshold {}",
-                intent_retry_count,
-                mca_retry_threshold
-            );
-            Ok(webhooks::WebhookResponseTracker::NoEffect)
-        })
-        .async_unwrap_or_else(|| async {
-            RevenueRecoveryAttempt::insert_execute_pcr_task(
-                &billing_connector_account.get_id(),
-                &*state.store,
-                merchant_account.get_id().to_owned(),
-                recovery_intent_from_payment_attempt.clone(),
-                business_profile.get_id().to_owned(),
-                intent_retry_count,
-                recovery_attempt_from_payment_attempt
-                    .as_ref()
-                    .map(|attempt| attempt.attempt_id.clone()),
-                storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+    let db = &*state.store;
+    let key_manager_state = &(state).into();
+    let mut cancelled_payment_ids = Vec::new();
+
+    for page in 0..SUBSCRIPTION_INVOICES_MAX_PAGES {
+        let payment_intents = db
+            .find_payment_intents_by_billing_connector_subscription_id_profile_id(
+                key_manager_state,
+                subscription_id,
+                business_profile.get_id(),
+                key_store,
+                &merchant_account.storage_scheme,
+                SUBSCRIPTION_INVOICES_PAGE_SIZE,
+                page * SUBSCRIPTION_INVOICES_PAGE_SIZE,
             )
             .await
-        })
+            .change_context(errors::RevenueRecoveryError::PaymentIntentFetchFailed)
+            .attach_printable("Failed to fetch the payment intents of the subscription")?;
+
+        let is_last_page = i64::try_from(payment_intents.len())
+            .map_or(true, |count| count < SUBSCRIPTION_INVOICES_PAGE_SIZE);
+
+        for payment_intent in payment_intents {
+            if is_recovery_closed(payment_intent.status) {
+                continue;
+            }
+
+            let (_, payment_intent) = stop_invoice_recovery(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent,
+                common_enums::IntentStatus::Cancelled,
+                storage::business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_SUBSCRIPTION_CANCELLATION,
+            )
+            .await?;
+
+            cancelled_payment_ids.push(payment_intent.id);
+        }
+
+        if is_last_page {
+            router_env::logger::info!(
+                subscription_id,
+                ?cancelled_payment_ids,
+                "Stopped revenue recovery for the cancelled subscription"
+            );
+            return Ok(webhooks::WebhookResponseTracker::NoEffect);
+        }
+    }
+
+    router_env::logger::warn!(
+        subscription_id,
+        ?cancelled_payment_ids,
+        "Reached the limit of payment intents looked up for the cancelled subscription"
+    );
+
+    Ok(webhooks::WebhookResponseTracker::NoEffect)
+}
+
+/// Stops the recovery of the invoice voided at the billing connector
+async fn handle_invoice_cancellation(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: id_type::GlobalPaymentId,
+    intent_status: common_enums::IntentStatus,
+) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
+    if is_recovery_closed(intent_status) {
+        router_env::logger::info!(
+            ?payment_id,
+            ?intent_status,
+            "Invoice has been voided after the recovery of the invoice was closed"
+        );
+        return Ok(webhooks::WebhookResponseTracker::Recovery {
+            payment_id,
+            status: intent_status,
+            action: webhooks::RecoveryWebhookAction::NoAction,
+            task_id: None,
+        });
+    }
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_id(
+            &state.into(),
+            &payment_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
         .await
+        .change_context(errors::RevenueRecoveryError::PaymentIntentFetchFailed)
+        .attach_printable("Failed to fetch the payment intent of the voided invoice")?;
+
+    let (cancelled_task_id, payment_intent) = stop_invoice_recovery(
+        state,
+        merchant_account,
+        key_store,
+        payment_intent,
+        common_enums::IntentStatus::Cancelled,
+        storage::business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_VOIDED_INVOICE,
+    )
+    .await?;
+
+    router_env::logger::info!(
+        ?payment_id,
+        ?cancelled_task_id,
+        "Invoice has been voided, stopping revenue recovery"
+    );
+
+    Ok(webhooks::WebhookResponseTracker::Recovery {
+        payment_id,
+        status: payment_intent.status,
+        action: if cancelled_task_id.is_some() {
+            webhooks::RecoveryWebhookAction::RetryCancelled
+        } else {
+            webhooks::RecoveryWebhookAction::NoAction
+        },
+        task_id: cancelled_task_id,
+    })
 }
 
-#[derive(Debug)]
-pub struct RevenueRecoveryInvoice(revenue_recovery::RevenueRecoveryInvoiceData);
-#[derive(Debug)]
-pub struct RevenueRecoveryAttempt(revenue_recovery::RevenueRecoveryAttemptData);
+/// Records the failed attempt of a payment held in review for its unresolved payment connector,
+/// and schedules the retry of the payment as for a failed payment. Returns `None` if the payment
+/// connector of the attempt still cannot be resolved, and the id of the task scheduled for the
+/// retry otherwise, if any.
+pub(crate) async fn record_attempt_of_unresolved_connector_payment(
+    state: &SessionState,
+    req_state: &ReqState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+    tracking_data: storage_churn_recovery::PcrUnresolvedConnectorTrackingData,
+) -> CustomResult<Option<Option<String>>, errors::RevenueRecoveryError> {
+    // The sources of the fields of the attempt are not kept along with the attempt
+    let invoice_transaction_details = RevenueRecoveryAttempt(
+        tracking_data.attempt_data,
+        revenue_recovery::RecoveryDataProvenance::default(),
+    );
+    let Some(payment_merchant_connector_account) = invoice_transaction_details
+        .find_payment_merchant_connector_account(
+            state,
+            key_store,
+            billing_connector_account,
+            business_profile,
+        )
+        .await?
+    else {
+        return Ok(None);
+    };
 
-impl RevenueRecoveryInvoice {
-    fn get_recovery_invoice_details(
-        connector_enum: &connector_integration_interface::ConnectorEnum,
-        request_details: &hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails<'_>,
-        billing_connector_payment_details: Option<
-            &revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
-        >,
-    ) -> CustomResult<Self, errors::RevenueRecoveryError> {
-        billing_connector_payment_details.map_or_else(
-            || {
-                interface_webhooks::IncomingWebhook::get_revenue_recovery_invoice_details(
-                    connector_enum,
-                    request_details,
-                )
-                .change_context(errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed)
-                .attach_printable("Failed while getting revenue recovery invoice details")
-                .map(RevenueRecoveryInvoice)
-            },
-            |data| {
-                Ok(Self(revenue_recovery::RevenueRecoveryInvoiceData::from(
-                    data,
-                )))
-            },
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_id(
+            &state.into(),
+            &tracking_data.global_payment_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::RevenueRecoveryError::PaymentIntentFetchFailed)
+        .attach_printable("Failed to fetch the payment intent of the payment held in review")?;
+    let payment_intent = revenue_recovery::RecoveryPaymentIntent {
+        payment_id: payment_intent.id,
+        status: payment_intent.status,
+        feature_metadata: payment_intent
+            .feature_metadata
+            .map(hyperswitch_domain_models::ApiModelToDieselModelConvertor::convert_back),
+    };
+
+    let (recovery_attempt, recovery_intent) = invoice_transaction_details
+        .record_payment_attempt(
+            state,
+            req_state,
+            merchant_account,
+            business_profile,
+            key_store,
+            &payment_intent,
+            billing_connector_account,
+            Some(payment_merchant_connector_account),
+            &tracking_data.recovery_correlation_id,
         )
+        .await?;
+
+    let mca_retry_threshold = billing_connector_account
+        .get_retry_threshold()
+        .ok_or(report!(
+            errors::RevenueRecoveryError::BillingThresholdRetryCountFetchFailed
+        ))?;
+    let intent_retry_count = recovery_intent
+        .feature_metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get_retry_count())
+        .ok_or(report!(errors::RevenueRecoveryError::RetryCountFetchFailed))?;
+
+    let response = handle_schedule_failed_payment(
+        billing_connector_account,
+        intent_retry_count,
+        mca_retry_threshold,
+        state,
+        merchant_account,
+        &(Some(recovery_attempt), recovery_intent),
+        business_profile,
+        &tracking_data.recovery_correlation_id,
+    )
+    .await?;
+
+    Ok(Some(match response {
+        webhooks::WebhookResponseTracker::Recovery { task_id, .. } => task_id,
+        _ => None,
+    }))
+}
+
+/// Event of the billing connector which would have been delivered for a transaction of the given
+/// status, the transactions in any other status are not consumed by revenue recovery
+fn get_event_type_of_polled_transaction(
+    status: common_enums::AttemptStatus,
+) -> Option<webhooks::IncomingWebhookEvent> {
+    match status {
+        common_enums::AttemptStatus::Charged => {
+            Some(webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess)
+        }
+        common_enums::AttemptStatus::Failure => {
+            Some(webhooks::IncomingWebhookEvent::RecoveryPaymentFailure)
+        }
+        common_enums::AttemptStatus::Pending => {
+            Some(webhooks::IncomingWebhookEvent::RecoveryPaymentPending)
+        }
+        _ => None,
     }
+}
 
-    async fn get_payment_intent(
-        &self,
-        state: &SessionState,
-        req_state: &ReqState,
-        merchant_account: &domain::MerchantAccount,
-        profile: &domain::Profile,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<Option<revenue_recovery::RecoveryPaymentIntent>, errors::RevenueRecoveryError>
+/// Status of the transaction of which the billing connector delivers the given event
+fn get_transaction_status_of_event(
+    event_type: webhooks::IncomingWebhookEvent,
+) -> Option<common_enums::AttemptStatus> {
+    match event_type {
+        webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess => {
+            Some(common_enums::AttemptStatus::Charged)
+        }
+        webhooks::IncomingWebhookEvent::RecoveryPaymentFailure => {
+            Some(common_enums::AttemptStatus::Failure)
+        }
+        webhooks::IncomingWebhookEvent::RecoveryPaymentPending => {
+            Some(common_enums::AttemptStatus::Pending)
+        }
+        _ => None,
+    }
+}
+
+/// Processes the latest transaction of an invoice found by polling the payments sync of the
+/// billing connector, the same way as the webhook of the transaction would have been. Returns
+/// `None` if the transaction has been recorded already, or cannot be told apart from the recorded
+/// ones, the poll having found nothing new.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn process_polled_recovery_transaction(
+    state: &SessionState,
+    req_state: &ReqState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    billing_connector_account: &domain::MerchantConnectorAccount,
+    payment_intent: hyperswitch_domain_models::payments::PaymentIntent,
+    billing_connector_payment_details: &revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
+    recovery_correlation_id: String,
+) -> CustomResult<Option<webhooks::WebhookResponseTracker>, errors::RevenueRecoveryError> {
+    let connector = billing_connector_account.connector_name;
+    let Some(event_type) =
+        get_event_type_of_polled_transaction(billing_connector_payment_details.status)
+    else {
+        router_env::logger::info!(
+            status = ?billing_connector_payment_details.status,
+            "Polled transaction of the invoice is not consumed by revenue recovery"
+        );
+        return Ok(None);
+    };
+
+    let (attempt_details, provenance) = revenue_recovery::RevenueRecoveryAttemptData::merge(
+        Some(revenue_recovery::RevenueRecoveryAttemptData::from(
+            billing_connector_payment_details,
+        )),
+        None,
+    )
+    .ok_or(errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed)
+    .attach_printable("Attempt details not found in the payments sync")?;
+    let invoice_transaction_details =
+        recovery_normalization::get_recovery_data_normalization(connector)
+            .normalize_attempt_data(attempt_details)
+            .map(|attempt_details| RevenueRecoveryAttempt(attempt_details, provenance))?;
+
+    // A transaction without an id would be recorded again on every poll
+    if invoice_transaction_details
+        .0
+        .connector_transaction_id
+        .is_none()
     {
-        let payment_response = Box::pin(payments::payments_get_intent_using_merchant_reference(
+        router_env::logger::warn!(
+            "Polled transaction of the invoice has no transaction id, skipping its processing"
+        );
+        return Ok(None);
+    }
+
+    let payment_intent = revenue_recovery::RecoveryPaymentIntent {
+        payment_id: payment_intent.id,
+        status: payment_intent.status,
+        feature_metadata: payment_intent
+            .feature_metadata
+            .map(hyperswitch_domain_models::ApiModelToDieselModelConvertor::convert_back),
+    };
+    if invoice_transaction_details
+        .get_payment_attempt(
+            state,
+            req_state,
+            merchant_account,
+            business_profile,
+            key_store,
+            &payment_intent,
+            connector,
+        )
+        .await?
+        .is_some()
+    {
+        return Ok(None);
+    }
+
+    let mut decision_details = RecoveryDecisionDetails {
+        recovery_correlation_id,
+        payment_id: Some(payment_intent.payment_id.clone()),
+        is_billing_connector_payment_sync_called: true,
+        attempt_data_provenance: Some(invoice_transaction_details.1.clone()),
+        ..Default::default()
+    };
+    let recorded_transaction = invoice_transaction_details
+        .record_recovery_transaction(
+            state,
+            req_state,
+            merchant_account,
+            business_profile,
+            key_store,
+            billing_connector_account,
+            connector,
+            &payment_intent,
+            &decision_details.recovery_correlation_id,
+        )
+        .await;
+    let response = match recorded_transaction {
+        Ok((recovery_attempt, recovery_intent)) => {
+            handle_recovery_action(
+                state,
+                merchant_account,
+                business_profile,
+                key_store,
+                billing_connector_account,
+                event_type,
+                recovery_attempt,
+                recovery_intent,
+                &mut decision_details,
+            )
+            .await
+        }
+        Err(error) => Err(error),
+    };
+    router_env::logger::info!(polled_transaction_response = ?response);
+
+    decision_details
+        .record(
+            state,
+            merchant_account,
+            business_profile,
+            event_type,
+            &response,
+        )
+        .await;
+
+    response.map(Some)
+}
+
+/// Whether the recovery of the payment is over, the payment having succeeded or been cancelled
+pub(crate) fn is_recovery_closed(intent_status: common_enums::IntentStatus) -> bool {
+    matches!(
+        intent_status,
+        common_enums::IntentStatus::Succeeded
+            | common_enums::IntentStatus::Cancelled
+            | common_enums::IntentStatus::PartiallyCaptured
+    )
+}
+
+/// Stops the recovery of an open invoice which has been closed at the billing connector. The
+/// pending retry of the payment is cancelled and the payment intent is moved to the given status.
+/// Returns the id of the cancelled task along with the updated payment intent.
+pub(crate) async fn stop_invoice_recovery(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: hyperswitch_domain_models::payments::PaymentIntent,
+    status: common_enums::IntentStatus,
+    business_status: &'static str,
+) -> CustomResult<
+    (
+        Option<String>,
+        hyperswitch_domain_models::payments::PaymentIntent,
+    ),
+    errors::RevenueRecoveryError,
+> {
+    let db = &*state.store;
+
+    // The flag is raised ahead of cancelling the pending retry, so that a retry which has already
+    // been picked up is aborted as well
+    core_revenue_recovery::cancellation::raise_cancellation_flag(state, &payment_intent.id).await;
+
+    let cancelled_task_id = RevenueRecoveryAttempt::cancel_pending_execute_pcr_task(
+        db,
+        &payment_intent.id,
+        storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+        business_status,
+    )
+    .await?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            &state.into(),
+            payment_intent,
+            hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::SyncUpdate {
+                status,
+                amount_captured: None,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::RevenueRecoveryError::PaymentIntentUpdateFailed)
+        .attach_printable("Failed to update the status of the payment intent of the invoice")?;
+
+    Ok((cancelled_task_id, payment_intent))
+}
+
+/// Number of payment intents of a customer fetched at once, while handling a dispute of the customer
+const CUSTOMER_INVOICES_PAGE_SIZE: i64 = 100;
+
+/// Upper bound on the number of pages looked up for a single customer
+const CUSTOMER_INVOICES_MAX_PAGES: i64 = 10;
+
+/// Places the dispute hold on all the open invoices of the customer and parks their pending
+/// retries, or releases the hold and reschedules the retries when `dispute_hold` is `None`.
+async fn handle_customer_dispute(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    connector_customer_id: &str,
+    dispute_hold: Option<api_payments::RevenueRecoveryDisputeHold>,
+) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
+    let db = &*state.store;
+    let key_manager_state = &(state).into();
+    let mut updated_payment_ids = Vec::new();
+
+    for page in 0..CUSTOMER_INVOICES_MAX_PAGES {
+        let payment_intents = db
+            .find_payment_intents_by_billing_connector_customer_id_profile_id(
+                key_manager_state,
+                connector_customer_id,
+                business_profile.get_id(),
+                key_store,
+                &merchant_account.storage_scheme,
+                CUSTOMER_INVOICES_PAGE_SIZE,
+                page * CUSTOMER_INVOICES_PAGE_SIZE,
+            )
+            .await
+            .change_context(errors::RevenueRecoveryError::PaymentIntentFetchFailed)
+            .attach_printable("Failed to fetch the payment intents of the customer")?;
+
+        let is_last_page = i64::try_from(payment_intents.len())
+            .map_or(true, |count| count < CUSTOMER_INVOICES_PAGE_SIZE);
+
+        for payment_intent in payment_intents {
+            if matches!(
+                payment_intent.status,
+                common_enums::IntentStatus::Succeeded
+                    | common_enums::IntentStatus::Cancelled
+                    | common_enums::IntentStatus::PartiallyCaptured
+            ) {
+                continue;
+            }
+
+            let Some(mut feature_metadata) = payment_intent.feature_metadata.clone() else {
+                continue;
+            };
+            let Some(mut recovery_metadata) = feature_metadata.payment_revenue_recovery_metadata
+            else {
+                continue;
+            };
+
+            // Releasing the hold has no effect on the invoices which were not held
+            if dispute_hold.is_none() && recovery_metadata.dispute_hold.is_none() {
+                continue;
+            }
+
+            let payment_id = payment_intent.id.clone();
+            let retry_override = recovery_metadata
+                .retry_override
+                .clone()
+                .map(hyperswitch_domain_models::ApiModelToDieselModelConvertor::convert_back);
+            recovery_metadata.dispute_hold = dispute_hold
+                .clone()
+                .map(hyperswitch_domain_models::ApiModelToDieselModelConvertor::convert_from);
+            feature_metadata.payment_revenue_recovery_metadata = Some(recovery_metadata);
+
+            db.update_payment_intent(
+                key_manager_state,
+                payment_intent.clone(),
+                hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::RecordUpdate {
+                    status: payment_intent.status,
+                    feature_metadata: Box::new(Some(feature_metadata)),
+                    updated_by: merchant_account.storage_scheme.to_string(),
+                },
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::RevenueRecoveryError::PaymentIntentUpdateFailed)
+            .attach_printable("Failed to update the dispute hold of the payment intent")?;
+
+            match dispute_hold.as_ref() {
+                Some(dispute_hold) => {
+                    RevenueRecoveryAttempt::park_pending_execute_pcr_task(
+                        db,
+                        &payment_id,
+                        storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+                        dispute_hold,
+                    )
+                    .await?;
+                }
+                None => {
+                    RevenueRecoveryAttempt::resume_parked_execute_pcr_task(
+                        db,
+                        merchant_account.get_id(),
+                        &payment_id,
+                        storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+                        retry_override.as_ref(),
+                        state.conf.revenue_recovery.network_retry_limit,
+                    )
+                    .await?;
+                }
+            }
+
+            updated_payment_ids.push(payment_id);
+        }
+
+        if is_last_page {
+            router_env::logger::info!(
+                connector_customer_id,
+                is_held = dispute_hold.is_some(),
+                ?updated_payment_ids,
+                "Updated the dispute hold of the revenue recovery invoices of the customer"
+            );
+            return Ok(webhooks::WebhookResponseTracker::NoEffect);
+        }
+    }
+
+    router_env::logger::warn!(
+        connector_customer_id,
+        ?updated_payment_ids,
+        "Reached the limit of payment intents looked up for the disputed customer"
+    );
+
+    Ok(webhooks::WebhookResponseTracker::NoEffect)
+}
+
+async fn handle_schedule_failed_payment(
+    billing_connector_account: &domain::MerchantConnectorAccount,
+    intent_retry_count: u16,
+    mca_retry_threshold: u16,
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_attempt_with_recovery_intent: &(
+        Option<revenue_recovery::RecoveryPaymentAttempt>,
+        revenue_recovery::RecoveryPaymentIntent,
+    ),
+    business_profile: &domain::Profile,
+    recovery_correlation_id: &str,
+) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
+    let (recovery_attempt_from_payment_attempt, recovery_intent_from_payment_attempt) =
+        payment_attempt_with_recovery_intent;
+    let network_retry_limit = state.conf.revenue_recovery.network_retry_limit;
+
+    // The retry override of the payment takes precedence over the retry threshold of the billing connector
+    let is_retry_overridden = recovery_intent_from_payment_attempt
+        .get_retry_override()
+        .is_some_and(|retry_override| {
+            revenue_recovery_types::get_remaining_override_retries(
+                retry_override,
+                intent_retry_count,
+                network_retry_limit,
+            ) > 0
+        });
+
+    (intent_retry_count <= mca_retry_threshold && !is_retry_overridden)
+        .then(|| {
+            router_env::logger::error!(
+                "Payment retry count {} is less than threshold {}",
+                intent_retry_count,
+                mca_retry_threshold
+            );
+            Ok(webhooks::WebhookResponseTracker::Recovery {
+                payment_id: recovery_intent_from_payment_attempt.payment_id.clone(),
+                status: recovery_intent_from_payment_attempt.status,
+                action: webhooks::RecoveryWebhookAction::RetryThresholdNotExceeded,
+                task_id: None,
+            })
+        })
+        .async_unwrap_or_else(|| async {
+            inject_recovery_fault!(
+                state,
+                ProcessTrackerInsert,
+                errors::RevenueRecoveryError::ProcessTrackerCreationError
+            );
+
+            RevenueRecoveryAttempt::insert_calculate_pcr_task(
+                billing_connector_account,
+                &*state.store,
+                merchant_account.get_id().to_owned(),
+                recovery_intent_from_payment_attempt.clone(),
+                business_profile.get_id().to_owned(),
+                intent_retry_count,
+                recovery_attempt_from_payment_attempt
+                    .as_ref()
+                    .map(|attempt| attempt.attempt_id.clone()),
+                recovery_attempt_from_payment_attempt
+                    .as_ref()
+                    .map(|attempt| attempt.processor_payment_method_token.as_str()),
+                recovery_attempt_from_payment_attempt
+                    .as_ref()
+                    .and_then(|attempt| attempt.error_code.clone()),
+                recovery_attempt_from_payment_attempt
+                    .as_ref()
+                    .and_then(|attempt| attempt.card_issuing_country),
+                recovery_correlation_id,
+                storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+            )
+            .await
+        })
+        .await
+}
+
+/// Details of the decision taken by revenue recovery, collected while a webhook is processed so
+/// that they are available even when the processing fails midway.
+#[derive(Debug, Default)]
+struct RecoveryDecisionDetails {
+    /// Identifier of the decision, which is also propagated to the attempts and tasks created for
+    /// the webhook so that a recovery can be traced end to end
+    recovery_correlation_id: String,
+    payment_id: Option<id_type::GlobalPaymentId>,
+    recovery_action: Option<&'static str>,
+    attempt_triggered_by: Option<common_enums::TriggeredBy>,
+    retry_count: Option<u16>,
+    retry_threshold: Option<u16>,
+    is_billing_connector_payment_sync_called: bool,
+    invoice_data_provenance: Option<revenue_recovery::RecoveryDataProvenance>,
+    attempt_data_provenance: Option<revenue_recovery::RecoveryDataProvenance>,
+}
+
+impl RecoveryDecisionDetails {
+    /// Decisions can only be recorded once the payment of the invoice is known
+    fn to_revenue_recovery_decision(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        event_type: webhooks::IncomingWebhookEvent,
+        response: &CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError>,
+    ) -> Option<storage_churn_recovery::RevenueRecoveryDecision> {
+        let (payment_id, webhook_action, task_id, error) = match response {
+            Ok(webhooks::WebhookResponseTracker::Recovery {
+                payment_id,
+                action,
+                task_id,
+                ..
+            }) => (
+                Some(payment_id.clone()),
+                Some(action.to_string()),
+                task_id.clone(),
+                None,
+            ),
+            Ok(_) => (self.payment_id.clone(), None, None, None),
+            Err(error) => (
+                self.payment_id.clone(),
+                None,
+                None,
+                Some(format!("{:?}", error.current_context())),
+            ),
+        };
+
+        payment_id.map(
+            |payment_id| storage_churn_recovery::RevenueRecoveryDecision {
+                id: self.recovery_correlation_id.clone(),
+                payment_id,
+                merchant_id: merchant_id.to_owned(),
+                profile_id: profile_id.to_owned(),
+                event_type: event_type.to_string(),
+                recovery_action: self.recovery_action.map(ToString::to_string),
+                attempt_triggered_by: self
+                    .attempt_triggered_by
+                    .map(|triggered_by| triggered_by.to_string()),
+                retry_count: self.retry_count.and_then(|count| i16::try_from(count).ok()),
+                retry_threshold: self
+                    .retry_threshold
+                    .and_then(|threshold| i16::try_from(threshold).ok()),
+                is_billing_connector_payment_sync_called: self
+                    .is_billing_connector_payment_sync_called,
+                webhook_action,
+                task_id,
+                error,
+                created_at: common_utils::date_time::now(),
+            },
+        )
+    }
+
+    /// Failure in recording the decision does not fail the webhook
+    async fn record(
+        self,
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+        business_profile: &domain::Profile,
+        event_type: webhooks::IncomingWebhookEvent,
+        response: &CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError>,
+    ) {
+        let Some(decision) = self.to_revenue_recovery_decision(
+            merchant_account.get_id(),
+            business_profile.get_id(),
+            event_type,
+            response,
+        ) else {
+            router_env::logger::info!(
+                ?event_type,
+                "Payment of the recovery webhook could not be resolved, skipping recording the decision"
+            );
+            return;
+        };
+
+        if let Err(error) = state.store.insert_revenue_recovery_decision(decision).await {
+            router_env::logger::error!(?error, "Failed to record the revenue recovery decision");
+        }
+    }
+}
+
+fn get_recovery_action_name(action: &revenue_recovery::RecoveryAction) -> &'static str {
+    match action {
+        revenue_recovery::RecoveryAction::CancelInvoice => "cancel_invoice",
+        revenue_recovery::RecoveryAction::CancelSubscription => "cancel_subscription",
+        revenue_recovery::RecoveryAction::HoldForDispute => "hold_for_dispute",
+        revenue_recovery::RecoveryAction::ReleaseDisputeHold => "release_dispute_hold",
+        revenue_recovery::RecoveryAction::ScheduleFailedPayment => "schedule_failed_payment",
+        revenue_recovery::RecoveryAction::SuccessPaymentExternal => "success_payment_external",
+        revenue_recovery::RecoveryAction::PendingPayment => "pending_payment",
+        revenue_recovery::RecoveryAction::NoAction => "no_action",
+        revenue_recovery::RecoveryAction::InvalidAction => "invalid_action",
+    }
+}
+
+#[derive(Debug)]
+pub struct RevenueRecoveryInvoice(
+    revenue_recovery::RevenueRecoveryInvoiceData,
+    revenue_recovery::RecoveryDataProvenance,
+);
+#[derive(Debug)]
+pub struct RevenueRecoveryAttempt(
+    revenue_recovery::RevenueRecoveryAttemptData,
+    revenue_recovery::RecoveryDataProvenance,
+);
+
+impl RevenueRecoveryInvoice {
+    async fn get_recovery_invoice_details(
+        connector_enum: &connector_integration_interface::ConnectorEnum,
+        connector: api_models::enums::Connector,
+        request_details: &hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails<'_>,
+        billing_connector_payment_details: Option<
+            &revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
+        >,
+        invoice_sync_call: Option<BillingConnectorInvoiceSyncCall<'_>>,
+    ) -> CustomResult<Self, errors::RevenueRecoveryError> {
+        let webhook_invoice_details =
+            interface_webhooks::IncomingWebhook::get_revenue_recovery_invoice_details(
+                connector_enum,
+                request_details,
+            )
+            .change_context(errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed)
+            .attach_printable("Failed while getting revenue recovery invoice details");
+
+        // The webhook is only used to fill the fields missing in the synced data, when the
+        // payment details are fetched from the billing connector
+        let (sync_invoice_details, webhook_invoice_details) =
+            match billing_connector_payment_details {
+                Some(data) => (
+                    Some(revenue_recovery::RevenueRecoveryInvoiceData::from(data)),
+                    webhook_invoice_details
+                        .map_err(|error| {
+                            router_env::logger::info!(
+                                ?error,
+                                "Invoice details could not be parsed from the webhook, using the synced details"
+                            )
+                        })
+                        .ok(),
+                ),
+                None => match (webhook_invoice_details, invoice_sync_call) {
+                    (Ok(webhook_invoice_details), _) => (None, Some(webhook_invoice_details)),
+                    // The invoice is fetched from the billing connector when the webhook does not
+                    // carry the details of the invoice
+                    (Err(error), Some(invoice_sync_call)) => {
+                        router_env::logger::info!(
+                            ?error,
+                            "Invoice details could not be parsed from the webhook, syncing the invoice"
+                        );
+                        let invoice = invoice_sync_call.call().await?;
+                        (Some(invoice.into()), None)
+                    }
+                    (Err(error), None) => return Err(error),
+                },
+            };
+
+        let (invoice_details, provenance) = revenue_recovery::RevenueRecoveryInvoiceData::merge(
+            sync_invoice_details,
+            webhook_invoice_details,
+        )
+        .ok_or(errors::RevenueRecoveryError::InvoiceWebhookProcessingFailed)
+        .attach_printable("Invoice details not found in the webhook or the payments sync")?;
+        router_env::logger::debug!(invoice_data_provenance = ?provenance);
+
+        recovery_normalization::get_recovery_data_normalization(connector)
+            .normalize_invoice_data(invoice_details)
+            .map(|invoice_details| Self(invoice_details, provenance))
+    }
+
+    fn is_zero_amount_invoice(&self) -> bool {
+        self.0.amount.get_amount_as_i64() <= 0
+    }
+
+    /// Skips the recovery of zero amount invoices. In case the recovery of the invoice has already
+    /// been started, i.e. the invoice has been credited down to zero, the pending retry is cancelled.
+    async fn handle_zero_amount_invoice(
+        &self,
+        state: &SessionState,
+        req_state: &ReqState,
+        merchant_account: &domain::MerchantAccount,
+        profile: &domain::Profile,
+        key_store: &domain::MerchantKeyStore,
+        connector: api_models::enums::Connector,
+    ) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
+        metrics::REVENUE_RECOVERY_ZERO_AMOUNT_INVOICE_COUNT.add(
+            1,
+            router_env::metric_attributes!(("connector", connector.to_string())),
+        );
+
+        let payment_intent = self
+            .get_payment_intent(state, req_state, merchant_account, profile, key_store)
+            .await?;
+
+        let Some(payment_intent) = payment_intent else {
+            router_env::logger::info!(
+                merchant_reference_id = ?self.0.merchant_reference_id,
+                amount = ?self.0.amount,
+                "Skipping revenue recovery for the zero amount invoice"
+            );
+            return Ok(webhooks::WebhookResponseTracker::NoEffect);
+        };
+
+        let cancelled_task_id = RevenueRecoveryAttempt::cancel_pending_execute_pcr_task(
+            &*state.store,
+            &payment_intent.payment_id,
+            storage::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+            storage::business_status::EXECUTE_WORKFLOW_CANCELLED_FOR_ZERO_AMOUNT_INVOICE,
+        )
+        .await?;
+
+        router_env::logger::info!(
+            payment_id = ?payment_intent.payment_id,
+            ?cancelled_task_id,
+            "Invoice has been credited down to zero amount, stopping revenue recovery"
+        );
+
+        Ok(webhooks::WebhookResponseTracker::Recovery {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            action: if cancelled_task_id.is_some() {
+                webhooks::RecoveryWebhookAction::RetryCancelled
+            } else {
+                webhooks::RecoveryWebhookAction::NoAction
+            },
+            task_id: cancelled_task_id,
+        })
+    }
+
+    async fn get_payment_intent(
+        &self,
+        state: &SessionState,
+        req_state: &ReqState,
+        merchant_account: &domain::MerchantAccount,
+        profile: &domain::Profile,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Option<revenue_recovery::RecoveryPaymentIntent>, errors::RevenueRecoveryError>
+    {
+        let payment_response = Box::pin(payments::payments_get_intent_using_merchant_reference(
             state.clone(),
             merchant_account.clone(),
             profile.clone(),
@@ -317,8 +1550,18 @@ impl RevenueRecoveryInvoice {
         merchant_account: &domain::MerchantAccount,
         profile: &domain::Profile,
         key_store: &domain::MerchantKeyStore,
+        customer_id: Option<id_type::GlobalCustomerId>,
     ) -> CustomResult<revenue_recovery::RecoveryPaymentIntent, errors::RevenueRecoveryError> {
-        let payload = api_payments::PaymentsCreateIntentRequest::from(&self.0);
+        inject_recovery_fault!(
+            state,
+            PaymentIntentCreate,
+            errors::RevenueRecoveryError::PaymentIntentCreateFailed
+        );
+
+        let payload = api_payments::PaymentsCreateIntentRequest {
+            customer_id,
+            ..api_payments::PaymentsCreateIntentRequest::from(&self.0)
+        };
         let global_payment_id = id_type::GlobalPaymentId::generate(&state.conf.cell_information.id);
 
         let create_intent_response = Box::pin(payments::payments_intent_core::<
@@ -355,36 +1598,260 @@ impl RevenueRecoveryInvoice {
             feature_metadata: response.feature_metadata,
         })
     }
+
+    /// Creates or updates the customer linked to the invoice with the contact details shared by
+    /// the billing connector. The customer is created only when the email of the customer is known.
+    async fn upsert_customer(
+        &self,
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Option<id_type::GlobalCustomerId>, errors::RevenueRecoveryError> {
+        let customer_details = match self.0.customer_details.as_ref() {
+            Some(customer_details) => customer_details,
+            None => return Ok(None),
+        };
+
+        let merchant_reference_id = id_type::CustomerId::try_from(std::borrow::Cow::from(
+            customer_details.billing_connector_customer_id.clone(),
+        ))
+        .change_context(errors::RevenueRecoveryError::CustomerUpdateFailed)
+        .attach_printable("Invalid customer id received from the billing connector")?;
+
+        let existing_customer = state
+            .store
+            .find_optional_by_merchant_id_merchant_reference_id(
+                &state.into(),
+                &merchant_reference_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::RevenueRecoveryError::CustomerUpdateFailed)
+            .attach_printable("Failed to fetch the customer linked to the invoice")?;
+
+        let customer_response = match (existing_customer, customer_details.email.clone()) {
+            (Some(customer), email) => {
+                if email.is_none() && customer_details.locale.is_none() {
+                    return Ok(Some(customer.id));
+                }
+
+                let metadata = customer_details.locale.as_ref().map(|locale| {
+                    get_customer_metadata_with_locale(customer.metadata.as_ref(), locale)
+                });
+                let request = api_models::customers::CustomerUpdateRequestInternal {
+                    id: customer.id,
+                    request: api_models::customers::CustomerUpdateRequest {
+                        email,
+                        metadata,
+                        ..Default::default()
+                    },
+                };
+
+                Box::pin(customers::update_customer(
+                    state.clone(),
+                    merchant_account.clone(),
+                    request,
+                    key_store.clone(),
+                ))
+                .await
+            }
+            (None, Some(email)) => {
+                let request = api_models::customers::CustomerRequest {
+                    merchant_reference_id: Some(merchant_reference_id),
+                    // Billing connectors do not always share the name of the customer, the customer
+                    // id at billing connector is used instead
+                    name: Secret::new(customer_details.billing_connector_customer_id.clone()),
+                    email,
+                    metadata: customer_details
+                        .locale
+                        .as_ref()
+                        .map(|locale| get_customer_metadata_with_locale(None, locale)),
+                    ..Default::default()
+                };
+
+                Box::pin(customers::create_customer(
+                    state.clone(),
+                    merchant_account.clone(),
+                    key_store.clone(),
+                    request,
+                ))
+                .await
+            }
+            (None, None) => {
+                router_env::logger::info!(
+                    "Skipping customer creation as the billing connector did not share the customer email"
+                );
+                return Ok(None);
+            }
+        }
+        .change_context(errors::RevenueRecoveryError::CustomerUpdateFailed)?;
+
+        let customer = customer_response
+            .get_json_body()
+            .change_context(errors::RevenueRecoveryError::CustomerUpdateFailed)
+            .attach_printable("expected json response")?;
+
+        Ok(Some(customer.id))
+    }
+}
+
+/// Converts the transaction time sent by the billing connector to UTC, if the billing connector
+/// sends local timestamps, and clamps it to the current time if it is further in the future than
+/// the allowed skew.
+fn get_normalized_transaction_created_at(
+    transaction_created_at: Option<time::PrimitiveDateTime>,
+    utc_offset: Option<time::UtcOffset>,
+    max_future_skew: time::Duration,
+    now: time::PrimitiveDateTime,
+) -> Option<time::PrimitiveDateTime> {
+    transaction_created_at.map(|created_at| {
+        let created_at_in_utc = utc_offset.map_or(created_at, |offset| {
+            let converted_created_at = created_at
+                .assume_offset(offset)
+                .to_offset(time::UtcOffset::UTC);
+            let converted_created_at =
+                time::PrimitiveDateTime::new(converted_created_at.date(), converted_created_at.time());
+            router_env::logger::info!(
+                ?created_at,
+                ?converted_created_at,
+                "Converted the transaction time sent by billing connector to UTC"
+            );
+            converted_created_at
+        });
+
+        if created_at_in_utc > now + max_future_skew {
+            router_env::logger::warn!(
+                transaction_created_at = ?created_at_in_utc,
+                ?now,
+                "Transaction time sent by billing connector is in the future, clamping it to the current time"
+            );
+            now
+        } else {
+            created_at_in_utc
+        }
+    })
+}
+
+/// Adds the locale of the customer to the customer metadata, retaining the other keys of the metadata.
+fn get_customer_metadata_with_locale(
+    metadata: Option<&pii::SecretSerdeValue>,
+    locale: &Secret<String>,
+) -> pii::SecretSerdeValue {
+    let mut metadata = match metadata.map(|metadata| metadata.clone().expose()) {
+        Some(serde_json::Value::Object(metadata)) => metadata,
+        _ => serde_json::Map::new(),
+    };
+    metadata.insert(
+        core_revenue_recovery::CUSTOMER_LOCALE_METADATA_KEY.to_string(),
+        serde_json::Value::String(locale.peek().to_owned()),
+    );
+    pii::SecretSerdeValue::new(serde_json::Value::Object(metadata))
+}
+
+/// Picks the latest transaction of the invoice that was created before the event occurred. Falls
+/// back to the latest transaction of the invoice if none of them were created before the event.
+fn get_invoice_transaction_for_event(
+    mut latest_transaction: revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
+    event_timestamp: time::PrimitiveDateTime,
+) -> revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+    let invoice_transactions = std::mem::take(&mut latest_transaction.invoice_transactions);
+    std::iter::once(latest_transaction.clone())
+        .chain(invoice_transactions)
+        .filter(|transaction| {
+            transaction
+                .transaction_created_at
+                .is_some_and(|created_at| created_at <= event_timestamp)
+        })
+        .max_by_key(|transaction| transaction.transaction_created_at)
+        .unwrap_or(latest_transaction)
+}
+
+/// Picks the transaction of the webhook when the billing connector returned the other transactions
+/// of the invoice along with it. The transaction with the id of the webhook is picked, falling back
+/// to the latest transaction in the status of the event, and then to the transaction returned by the
+/// billing connector, when none of the transactions have the id.
+fn get_transaction_for_event(
+    mut transaction: revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
+    transaction_id: &str,
+    event_status: Option<common_enums::AttemptStatus>,
+) -> revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+    let invoice_transactions = std::mem::take(&mut transaction.invoice_transactions);
+    if invoice_transactions.is_empty() {
+        return transaction;
+    }
+    let transactions = std::iter::once(&transaction).chain(invoice_transactions.iter());
+
+    if let Some(matching_transaction) = transactions.clone().find(|returned_transaction| {
+        returned_transaction
+            .connector_transaction_id
+            .as_ref()
+            .is_some_and(|id| id.get_id() == transaction_id)
+    }) {
+        return matching_transaction.clone();
+    }
+
+    let selected_transaction = transactions
+        .filter(|returned_transaction| Some(returned_transaction.status) == event_status)
+        .max_by_key(|returned_transaction| returned_transaction.transaction_created_at)
+        .unwrap_or(&transaction);
+    router_env::logger::warn!(
+        webhook_transaction_id = transaction_id,
+        selected_transaction_id = ?selected_transaction.connector_transaction_id,
+        ?event_status,
+        "Billing connector payments sync returned no transaction with the id of the webhook"
+    );
+    selected_transaction.clone()
 }
 
 impl RevenueRecoveryAttempt {
     fn get_recovery_invoice_transaction_details(
         connector_enum: &connector_integration_interface::ConnectorEnum,
+        connector: api_models::enums::Connector,
         request_details: &hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails<'_>,
         billing_connector_payment_details: Option<
             &revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
         >,
     ) -> CustomResult<Self, errors::RevenueRecoveryError> {
-        billing_connector_payment_details.map_or_else(
-            || {
-                interface_webhooks::IncomingWebhook::get_revenue_recovery_attempt_details(
-                    connector_enum,
-                    request_details,
-                )
-                .change_context(errors::RevenueRecoveryError::TransactionWebhookProcessingFailed)
-                .attach_printable(
-                    "Failed to get recovery attempt details from the billing connector",
-                )
-                .map(RevenueRecoveryAttempt)
-            },
-            |data| {
-                Ok(Self(revenue_recovery::RevenueRecoveryAttemptData::from(
-                    data,
-                )))
-            },
+        let webhook_attempt_details =
+            interface_webhooks::IncomingWebhook::get_revenue_recovery_attempt_details(
+                connector_enum,
+                request_details,
+            )
+            .change_context(errors::RevenueRecoveryError::TransactionWebhookProcessingFailed)
+            .attach_printable("Failed to get recovery attempt details from the billing connector");
+
+        let (sync_attempt_details, webhook_attempt_details) =
+            match billing_connector_payment_details {
+                Some(data) => (
+                    Some(revenue_recovery::RevenueRecoveryAttemptData::from(data)),
+                    webhook_attempt_details
+                        .map_err(|error| {
+                            router_env::logger::info!(
+                                ?error,
+                                "Attempt details could not be parsed from the webhook, using the synced details"
+                            )
+                        })
+                        .ok(),
+                ),
+                None => (None, Some(webhook_attempt_details?)),
+            };
+
+        let (attempt_details, provenance) = revenue_recovery::RevenueRecoveryAttemptData::merge(
+            sync_attempt_details,
+            webhook_attempt_details,
         )
+        .ok_or(errors::RevenueRecoveryError::TransactionWebhookProcessingFailed)
+        .attach_printable("Attempt details not found in the webhook or the payments sync")?;
+        router_env::logger::debug!(attempt_data_provenance = ?provenance);
+
+        recovery_normalization::get_recovery_data_normalization(connector)
+            .normalize_attempt_data(attempt_details)
+            .map(|attempt_details| Self(attempt_details, provenance))
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn get_payment_attempt(
         &self,
         state: &SessionState,
@@ -393,6 +1860,7 @@ impl RevenueRecoveryAttempt {
         profile: &domain::Profile,
         key_store: &domain::MerchantKeyStore,
         payment_intent: &revenue_recovery::RecoveryPaymentIntent,
+        connector: api_models::enums::Connector,
     ) -> CustomResult<
         Option<(
             revenue_recovery::RecoveryPaymentAttempt,
@@ -428,6 +1896,10 @@ impl RevenueRecoveryAttempt {
         .await;
         let response = match attempt_response {
             Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => {
+                // the transaction ids of the attempts recorded before the normalization of the
+                // transaction ids was introduced are normalized while matching them
+                let normalization =
+                    recovery_normalization::get_recovery_data_normalization(connector);
                 let final_attempt =
                     self.0
                         .connector_transaction_id
@@ -436,6 +1908,11 @@ impl RevenueRecoveryAttempt {
                             payments_response
                                 .find_attempt_in_attempts_list_using_connector_transaction_id(
                                     transaction_id,
+                                    |attempt_transaction_id| {
+                                        normalization.get_normalized_connector_transaction_id(
+                                            attempt_transaction_id,
+                                        )
+                                    },
                                 )
                         });
                 let payment_attempt =
@@ -443,6 +1920,16 @@ impl RevenueRecoveryAttempt {
                         attempt_id: attempt_res.id.to_owned(),
                         attempt_status: attempt_res.status.to_owned(),
                         feature_metadata: attempt_res.feature_metadata.to_owned(),
+                        processor_payment_method_token: self
+                            .0
+                            .processor_payment_method_token
+                            .clone(),
+                        error_code: attempt_res
+                            .error
+                            .as_ref()
+                            .and_then(|error| error.unified_code.clone())
+                            .or_else(|| self.0.error_code.clone()),
+                        card_issuing_country: self.0.card_issuing_country,
                     });
                 // If we have an attempt, combine it with payment_intent in a tuple.
                 let res_with_payment_intent_and_attempt =
@@ -469,8 +1956,9 @@ impl RevenueRecoveryAttempt {
         profile: &domain::Profile,
         key_store: &domain::MerchantKeyStore,
         payment_intent: &revenue_recovery::RecoveryPaymentIntent,
-        billing_connector_account_id: &id_type::MerchantConnectorAccountId,
+        billing_connector_account: &domain::MerchantConnectorAccount,
         payment_connector_account: Option<domain::MerchantConnectorAccount>,
+        recovery_correlation_id: &str,
     ) -> CustomResult<
         (
             revenue_recovery::RecoveryPaymentAttempt,
@@ -478,8 +1966,28 @@ impl RevenueRecoveryAttempt {
         ),
         errors::RevenueRecoveryError,
     > {
-        let request_payload = self
-            .create_payment_record_request(billing_connector_account_id, payment_connector_account);
+        let transaction_time_config = &state.conf.billing_connectors_transaction_time;
+        let transaction_created_at = get_normalized_transaction_created_at(
+            self.0.transaction_created_at,
+            transaction_time_config
+                .get_utc_offset(&billing_connector_account.connector_name.to_string()),
+            time::Duration::seconds(i64::from(transaction_time_config.max_future_skew_in_secs)),
+            common_utils::date_time::now(),
+        );
+        let request_payload = self.create_payment_record_request(
+            &billing_connector_account.get_id(),
+            payment_connector_account,
+            transaction_created_at,
+            state.conf.revenue_recovery.record_data_provenance,
+            recovery_correlation_id,
+        );
+
+        inject_recovery_fault!(
+            state,
+            RecordAttempt,
+            errors::RevenueRecoveryError::PaymentAttemptFetchFailed
+        );
+
         let attempt_response = Box::pin(payments::record_attempt_core(
             state.clone(),
             req_state.clone(),
@@ -500,6 +2008,12 @@ impl RevenueRecoveryAttempt {
                         attempt_id: attempt_response.id.clone(),
                         attempt_status: attempt_response.status,
                         feature_metadata: attempt_response.payment_attempt_feature_metadata,
+                        processor_payment_method_token: self
+                            .0
+                            .processor_payment_method_token
+                            .clone(),
+                        error_code: self.0.error_code.clone(),
+                        card_issuing_country: self.0.card_issuing_country,
                     },
                     revenue_recovery::RecoveryPaymentIntent {
                         payment_id: payment_intent.payment_id.clone(),
@@ -526,15 +2040,25 @@ impl RevenueRecoveryAttempt {
         &self,
         billing_merchant_connector_account_id: &id_type::MerchantConnectorAccountId,
         payment_merchant_connector_account: Option<domain::MerchantConnectorAccount>,
+        transaction_created_at: Option<time::PrimitiveDateTime>,
+        should_record_data_provenance: bool,
+        recovery_correlation_id: &str,
     ) -> api_payments::PaymentsAttemptRecordRequest {
         let amount_details = api_payments::PaymentAttemptAmountDetails::from(&self.0);
         let feature_metadata = api_payments::PaymentAttemptFeatureMetadata {
             revenue_recovery: Some(api_payments::PaymentAttemptRevenueRecoveryData {
                 // Since we are recording the external paymenmt attempt, this is hardcoded to External
                 attempt_triggered_by: common_enums::TriggeredBy::External,
+                processor_response_details: self.0.processor_response_details.clone(),
+                schedule_adjustments: None,
+                data_provenance: should_record_data_provenance.then(|| self.1.get_field_sources()),
+                recovery_correlation_id: Some(recovery_correlation_id.to_string()),
+                connector_switch: None,
             }),
         };
         let error = Option::<api_payments::RecordAttemptErrorDetails>::from(&self.0);
+        let (payment_method_type, payment_method_subtype) =
+            self.0.get_payment_method_and_sub_type();
         api_payments::PaymentsAttemptRecordRequest {
             amount_details,
             status: self.0.status,
@@ -545,15 +2069,16 @@ impl RevenueRecoveryAttempt {
             error,
             description: None,
             connector_transaction_id: self.0.connector_transaction_id.clone(),
-            payment_method_type: self.0.payment_method_type,
+            payment_method_type,
             billing_connector_id: billing_merchant_connector_account_id.clone(),
-            payment_method_subtype: self.0.payment_method_sub_type,
+            payment_method_subtype,
             payment_method_data: None,
             metadata: None,
             feature_metadata: Some(feature_metadata),
-            transaction_created_at: self.0.transaction_created_at,
+            transaction_created_at,
             processor_payment_method_token: self.0.processor_payment_method_token.clone(),
             connector_customer_id: self.0.connector_customer_id.clone(),
+            card_details: self.0.card_details.clone(),
         }
     }
 
@@ -562,11 +2087,32 @@ impl RevenueRecoveryAttempt {
         state: &SessionState,
         key_store: &domain::MerchantKeyStore,
         billing_connector_account: &domain::MerchantConnectorAccount,
+        business_profile: &domain::Profile,
     ) -> CustomResult<Option<domain::MerchantConnectorAccount>, errors::RevenueRecoveryError> {
         let payment_merchant_connector_account_id = billing_connector_account
             .get_payment_merchant_connector_account_id_using_account_reference_id(
                 self.0.connector_account_reference_id.clone(),
-            );
+            )
+            .or_else(|| {
+                // Fall back to the default recovery payment connector of the profile when the
+                // billing connector has no mapping for the account reference id
+                let default_recovery_payment_connector_id = business_profile
+                    .default_recovery_payment_connector_id
+                    .clone()?;
+                router_env::logger::warn!(
+                    connector_account_reference_id = %self.0.connector_account_reference_id,
+                    default_recovery_payment_connector_id = ?default_recovery_payment_connector_id,
+                    "Payment merchant connector account mapping not found, falling back to the default recovery payment connector"
+                );
+                metrics::REVENUE_RECOVERY_CONNECTOR_FALLBACK_COUNT.add(
+                    1,
+                    router_env::metric_attributes!((
+                        "billing_connector",
+                        billing_connector_account.connector_name.to_string()
+                    )),
+                );
+                Some(default_recovery_payment_connector_id)
+            });
         let db = &*state.store;
         let key_manager_state = &(state).into();
         let payment_merchant_connector_account = payment_merchant_connector_account_id
@@ -584,6 +2130,37 @@ impl RevenueRecoveryAttempt {
         Ok(payment_merchant_connector_account)
     }
 
+    /// Holds the payment in review along with the failed attempt, which is recorded once the
+    /// payment connector of the attempt is mapped
+    async fn hold_for_unresolved_connector(
+        self,
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+        business_profile: &domain::Profile,
+        billing_connector_account: &domain::MerchantConnectorAccount,
+        payment_intent: &revenue_recovery::RecoveryPaymentIntent,
+        recovery_correlation_id: &str,
+    ) -> CustomResult<String, errors::RevenueRecoveryError> {
+        let tracking_data = storage_churn_recovery::PcrUnresolvedConnectorTrackingData {
+            merchant_id: merchant_account.get_id().clone(),
+            profile_id: business_profile.get_id().clone(),
+            global_payment_id: payment_intent.payment_id.clone(),
+            billing_mca_id: billing_connector_account.get_id(),
+            connector_account_reference_id: self.0.connector_account_reference_id.clone(),
+            recovery_correlation_id: recovery_correlation_id.to_string(),
+            attempt_data: self.0,
+        };
+
+        core_revenue_recovery::connector_resolution::hold_payment_for_unresolved_connector(
+            &*state.store,
+            tracking_data,
+            &billing_connector_account.connector_name.to_string(),
+        )
+        .await
+        .change_context(errors::RevenueRecoveryError::ProcessTrackerCreationError)
+        .attach_printable("Failed to hold the payment with the unresolved payment connector")
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn get_recovery_payment_attempt(
         is_recovery_transaction_event: bool,
@@ -591,6 +2168,7 @@ impl RevenueRecoveryAttempt {
         state: &SessionState,
         key_store: &domain::MerchantKeyStore,
         connector_enum: &connector_integration_interface::ConnectorEnum,
+        connector: api_models::enums::Connector,
         req_state: &ReqState,
         billing_connector_payment_details: Option<
             &revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
@@ -599,6 +2177,7 @@ impl RevenueRecoveryAttempt {
         merchant_account: &domain::MerchantAccount,
         business_profile: &domain::Profile,
         payment_intent: &revenue_recovery::RecoveryPaymentIntent,
+        decision_details: &mut RecoveryDecisionDetails,
     ) -> CustomResult<
         (
             Option<revenue_recovery::RecoveryPaymentAttempt>,
@@ -610,46 +2189,26 @@ impl RevenueRecoveryAttempt {
             true => {
                 let invoice_transaction_details = Self::get_recovery_invoice_transaction_details(
                     connector_enum,
+                    connector,
                     request_details,
                     billing_connector_payment_details,
                 )?;
+                decision_details.attempt_data_provenance =
+                    Some(invoice_transaction_details.1.clone());
 
-                // Find the payment merchant connector ID at the top level to avoid multiple DB calls.
-                let payment_merchant_connector_account = invoice_transaction_details
-                    .find_payment_merchant_connector_account(
-                        state,
-                        key_store,
-                        billing_connector_account,
-                    )
-                    .await?;
-
-                let (payment_attempt, updated_payment_intent) = invoice_transaction_details
-                    .get_payment_attempt(
+                invoice_transaction_details
+                    .record_recovery_transaction(
                         state,
                         req_state,
                         merchant_account,
                         business_profile,
                         key_store,
+                        billing_connector_account,
+                        connector,
                         payment_intent,
+                        &decision_details.recovery_correlation_id,
                     )
-                    .await
-                    .transpose()
-                    .async_unwrap_or_else(|| async {
-                        invoice_transaction_details
-                            .record_payment_attempt(
-                                state,
-                                req_state,
-                                merchant_account,
-                                business_profile,
-                                key_store,
-                                payment_intent,
-                                &billing_connector_account.get_id(),
-                                payment_merchant_connector_account,
-                            )
-                            .await
-                    })
-                    .await?;
-                (Some(payment_attempt), updated_payment_intent)
+                    .await?
             }
 
             false => (None, payment_intent.clone()),
@@ -658,36 +2217,114 @@ impl RevenueRecoveryAttempt {
         Ok(payment_attempt_with_recovery_intent)
     }
 
+    /// Records the attempt of the transaction of the invoice, unless it has been recorded already.
+    /// The attempt is left unrecorded when the payment has been held in review for its unresolved
+    /// payment connector.
     #[allow(clippy::too_many_arguments)]
-    async fn insert_execute_pcr_task(
-        billing_mca_id: &id_type::MerchantConnectorAccountId,
+    async fn record_recovery_transaction(
+        self,
+        state: &SessionState,
+        req_state: &ReqState,
+        merchant_account: &domain::MerchantAccount,
+        business_profile: &domain::Profile,
+        key_store: &domain::MerchantKeyStore,
+        billing_connector_account: &domain::MerchantConnectorAccount,
+        connector: api_models::enums::Connector,
+        payment_intent: &revenue_recovery::RecoveryPaymentIntent,
+        recovery_correlation_id: &str,
+    ) -> CustomResult<
+        (
+            Option<revenue_recovery::RecoveryPaymentAttempt>,
+            revenue_recovery::RecoveryPaymentIntent,
+        ),
+        errors::RevenueRecoveryError,
+    > {
+        // Find the payment merchant connector ID at the top level to avoid multiple DB calls.
+        let payment_merchant_connector_account = self
+            .find_payment_merchant_connector_account(
+                state,
+                key_store,
+                billing_connector_account,
+                business_profile,
+            )
+            .await?;
+
+        let existing_payment_attempt = self
+            .get_payment_attempt(
+                state,
+                req_state,
+                merchant_account,
+                business_profile,
+                key_store,
+                payment_intent,
+                connector,
+            )
+            .await?;
+
+        let payment_attempt_with_recovery_intent =
+            match (existing_payment_attempt, payment_merchant_connector_account) {
+                (Some((payment_attempt, updated_payment_intent)), _) => {
+                    (Some(payment_attempt), updated_payment_intent)
+                }
+                // A failed attempt without a payment connector has nothing to be retried through,
+                // the payment is held in review until the payment connector is mapped
+                (None, None) if self.0.status == common_enums::AttemptStatus::Failure => {
+                    self.hold_for_unresolved_connector(
+                        state,
+                        merchant_account,
+                        business_profile,
+                        billing_connector_account,
+                        payment_intent,
+                        recovery_correlation_id,
+                    )
+                    .await?;
+                    (None, payment_intent.clone())
+                }
+                (None, payment_merchant_connector_account) => {
+                    let (payment_attempt, updated_payment_intent) = self
+                        .record_payment_attempt(
+                            state,
+                            req_state,
+                            merchant_account,
+                            business_profile,
+                            key_store,
+                            payment_intent,
+                            billing_connector_account,
+                            payment_merchant_connector_account,
+                            recovery_correlation_id,
+                        )
+                        .await?;
+                    (Some(payment_attempt), updated_payment_intent)
+                }
+            };
+
+        Ok(payment_attempt_with_recovery_intent)
+    }
+
+    /// Inserts the calculate task of the payment, which computes the schedule time of the next
+    /// retry and creates the execute task. The calculate task of a previous failed payment of the
+    /// invoice is updated instead.
+    #[allow(clippy::too_many_arguments)]
+    async fn insert_calculate_pcr_task(
+        billing_connector_account: &domain::MerchantConnectorAccount,
         db: &dyn StorageInterface,
         merchant_id: id_type::MerchantId,
         payment_intent: revenue_recovery::RecoveryPaymentIntent,
         profile_id: id_type::ProfileId,
         intent_retry_count: u16,
         payment_attempt_id: Option<id_type::GlobalAttemptId>,
+        processor_payment_method_token: Option<&str>,
+        last_attempt_error_code: Option<String>,
+        card_issuing_country: Option<common_enums::CountryAlpha2>,
+        recovery_correlation_id: &str,
         runner: storage::ProcessTrackerRunner,
     ) -> CustomResult<webhooks::WebhookResponseTracker, errors::RevenueRecoveryError> {
-        let task = "EXECUTE_WORKFLOW";
+        let task = core_revenue_recovery::CALCULATE_WORKFLOW;
 
         let payment_id = payment_intent.payment_id.clone();
 
-        let process_tracker_id = format!("{runner}_{task}_{}", payment_id.get_string_repr());
-
-        let schedule_time = revenue_recovery_flow::get_schedule_time_to_retry_mit_payments(
-            db,
-            &merchant_id,
-            (intent_retry_count + 1).into(),
-        )
-        .await
-        .map_or_else(
-            || {
-                Err(errors::RevenueRecoveryError::ScheduleTimeFetchFailed)
-                    .attach_printable("Failed to get schedule time for pcr workflow")
-            },
-            Ok, // Simply returns `time` wrapped in `Ok`
-        )?;
+        let process_tracker_id =
+            core_revenue_recovery::get_calculate_pcr_task_id(&payment_id, runner);
 
         let payment_attempt_id = payment_attempt_id
             .ok_or(report!(
@@ -695,22 +2332,47 @@ impl RevenueRecoveryAttempt {
             ))
             .attach_printable("payment attempt id is required for pcr workflow tracking")?;
 
-        let execute_workflow_tracking_data = storage_churn_recovery::PcrWorkflowTrackingData {
-            billing_mca_id: billing_mca_id.clone(),
-            global_payment_id: payment_id.clone(),
-            merchant_id,
-            profile_id,
-            payment_attempt_id,
-        };
+        let processor_token_tag = processor_payment_method_token
+            .map(|token| storage_churn_recovery::get_processor_token_tag(&merchant_id, token))
+            .transpose()
+            .change_context(errors::RevenueRecoveryError::ProcessTrackerCreationError)
+            .attach_printable("Failed to generate the processor token tag for pcr workflow")?;
 
-        let tag = ["PCR"];
+        let calculate_workflow_tracking_data =
+            storage_churn_recovery::PcrCalculateWorkflowTrackingData {
+                workflow_tracking_data: storage_churn_recovery::PcrWorkflowTrackingData {
+                    billing_mca_id: billing_connector_account.get_id(),
+                    global_payment_id: payment_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_id,
+                    payment_attempt_id,
+                    schedule_adjustments: Vec::new(),
+                    recovery_correlation_id: Some(recovery_correlation_id.to_string()),
+                    decline_class: None,
+                    execution_failure_count: 0,
+                    last_execution_error: None,
+                    schedule_decision: None,
+                },
+                intent_retry_count,
+                processor_token_tag,
+                last_attempt_error_code,
+                card_issuing_country,
+            };
+
+        let schedule_time = common_utils::date_time::now();
 
+        // The processor token tag is only added to the execute task, so that the calculate task is
+        // not considered while spacing out the retries of the processor token
+        let tag = storage_churn_recovery::get_pcr_task_tags(
+            &merchant_id,
+            Some(&billing_connector_account.connector_name.to_string()),
+        );
         let process_tracker_entry = storage::ProcessTrackerNew::new(
-            process_tracker_id,
+            process_tracker_id.clone(),
             task,
             runner,
             tag,
-            execute_workflow_tracking_data,
+            calculate_workflow_tracking_data,
             Some(intent_retry_count.into()),
             schedule_time,
             common_enums::ApiVersion::V2,
@@ -718,34 +2380,385 @@ impl RevenueRecoveryAttempt {
         .change_context(errors::RevenueRecoveryError::ProcessTrackerCreationError)
         .attach_printable("Failed to construct process tracker entry")?;
 
-        db.insert_process(process_tracker_entry)
+        core_revenue_recovery::insert_or_update_pcr_task(db, process_tracker_entry)
             .await
             .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
-            .attach_printable("Failed to enter process_tracker_entry in DB")?;
-        metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "ExecutePCR")));
+            .attach_printable("Failed to insert or update the calculate task of the payment")?;
+        metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "CalculatePCR")));
 
-        Ok(webhooks::WebhookResponseTracker::Payment {
+        Ok(webhooks::WebhookResponseTracker::Recovery {
             payment_id,
             status: payment_intent.status,
+            action: webhooks::RecoveryWebhookAction::RetryScheduled,
+            task_id: Some(process_tracker_id),
         })
     }
+
+    /// Cancels the execute task of the payment, in case it has not been picked up yet. Returns the
+    /// id of the cancelled task.
+    async fn cancel_pending_execute_pcr_task(
+        db: &dyn StorageInterface,
+        payment_id: &id_type::GlobalPaymentId,
+        runner: storage::ProcessTrackerRunner,
+        business_status: &'static str,
+    ) -> CustomResult<Option<String>, errors::RevenueRecoveryError> {
+        let process_tracker_id = core_revenue_recovery::get_execute_pcr_task_id(payment_id, runner);
+
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+            .attach_printable("Failed to fetch the pcr task of the payment")?;
+
+        match process {
+            Some(process)
+                if matches!(
+                    process.status,
+                    common_enums::ProcessTrackerStatus::New
+                        | common_enums::ProcessTrackerStatus::Pending
+                ) =>
+            {
+                db.finish_process_with_business_status(process, business_status)
+                    .await
+                    .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+                    .attach_printable("Failed to cancel the pcr task of the payment")?;
+
+                Ok(Some(process_tracker_id))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Parks the execute task of the payment until the dispute hold expires, in case it has not
+    /// been picked up yet. The task is resumed earlier if the hold is released.
+    async fn park_pending_execute_pcr_task(
+        db: &dyn StorageInterface,
+        payment_id: &id_type::GlobalPaymentId,
+        runner: storage::ProcessTrackerRunner,
+        dispute_hold: &api_payments::RevenueRecoveryDisputeHold,
+    ) -> CustomResult<Option<String>, errors::RevenueRecoveryError> {
+        let process_tracker_id = core_revenue_recovery::get_execute_pcr_task_id(payment_id, runner);
+
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+            .attach_printable("Failed to fetch the pcr task of the payment")?;
+
+        match process {
+            Some(process)
+                if matches!(
+                    process.status,
+                    common_enums::ProcessTrackerStatus::New
+                        | common_enums::ProcessTrackerStatus::Pending
+                ) =>
+            {
+                core_revenue_recovery::park_execute_task_for_dispute_hold(
+                    db,
+                    process,
+                    dispute_hold,
+                )
+                .await
+                .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+                .attach_printable("Failed to park the pcr task of the payment")?;
+
+                Ok(Some(process_tracker_id))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Reschedules the execute task of the payment parked for a dispute, using the retry schedule
+    /// of the payment. Returns the id of the rescheduled task.
+    async fn resume_parked_execute_pcr_task(
+        db: &dyn StorageInterface,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::GlobalPaymentId,
+        runner: storage::ProcessTrackerRunner,
+        retry_override: Option<&api_payments::RevenueRecoveryRetryOverride>,
+        network_retry_limit: u16,
+    ) -> CustomResult<Option<String>, errors::RevenueRecoveryError> {
+        let process_tracker_id = core_revenue_recovery::get_execute_pcr_task_id(payment_id, runner);
+
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+            .attach_printable("Failed to fetch the pcr task of the payment")?;
+
+        let Some(process) = process.filter(|process| {
+            process.status == common_enums::ProcessTrackerStatus::Pending
+                && process.business_status
+                    == storage::business_status::EXECUTE_WORKFLOW_PAUSED_FOR_DISPUTE
+        }) else {
+            return Ok(None);
+        };
+
+        // The retry which was due when the task was parked is yet to be made. The decline of the
+        // last attempt is not considered, since the retry is delayed by the dispute already.
+        let retry_schedule = revenue_recovery_flow::get_schedule_time_for_next_retry(
+            db,
+            merchant_id,
+            process.retry_count + 1,
+            u16::try_from(process.retry_count).unwrap_or(u16::MAX),
+            retry_override,
+            network_retry_limit,
+            &[],
+        )
+        .await;
+        let schedule_time = retry_schedule
+            .as_ref()
+            .map_or_else(common_utils::date_time::now, |retry_schedule| {
+                retry_schedule.schedule_time
+            });
+
+        let mut tracking_data = process
+            .tracking_data
+            .clone()
+            .parse_value::<storage_churn_recovery::PcrWorkflowTrackingData>(
+                "PcrWorkflowTrackingData",
+            )
+            .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+            .attach_printable("Failed to parse the tracking data of the pcr task")?;
+        tracking_data.schedule_decision = retry_schedule
+            .map(|retry_schedule| retry_schedule.get_schedule_decision(schedule_time));
+        let tracking_data = serde_json::to_value(tracking_data)
+            .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+            .attach_printable("Failed to serialize the tracking data of the pcr task")?;
+
+        db.update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: Some(String::from(storage::business_status::PENDING)),
+                status: None,
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await
+        .change_context(errors::RevenueRecoveryError::ProcessTrackerResponseError)
+        .attach_printable("Failed to resume the pcr task of the payment")?;
+
+        Ok(Some(process_tracker_id))
+    }
 }
 
 pub struct BillingConnectorPaymentsSyncResponseData(
     revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
 );
+
+const PAYMENTS_SYNC_CACHE_PREFIX: &str = "RECOVERY_PAYMENTS_SYNC";
+
+/// Use of the cached payments sync responses of the transactions. The transactions are cached by
+/// their billing connector account and transaction id, the lookups by the invoice id are never
+/// cached since the transaction they refer to depends on the time of the event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PaymentsSyncCacheMode {
+    /// The cached response of the transaction is used if there is one, the billing connector is
+    /// called and its response cached otherwise
+    ReadThrough,
+    /// The billing connector is always called, and its response replaces the cached one
+    Refresh,
+    /// The billing connector is always called, and the cached response is left as is
+    Bypass,
+}
+
+/// Failures of the payments sync which are worth retrying right away, the timeouts being reported
+/// as gateway timeouts
+fn is_transient_payments_sync_failure<T>(
+    response: &Result<T, hyperswitch_domain_models::router_data::ErrorResponse>,
+) -> bool {
+    matches!(response, Err(error) if matches!(error.status_code, 502..=504))
+}
+
+/// Outcome of a call made to the billing connector for the payments sync
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum PaymentsSyncCallOutcome {
+    Success,
+    AuthFailure,
+    Timeout,
+    RateLimited,
+    OtherError,
+}
+
+impl PaymentsSyncCallOutcome {
+    /// The response is not present when the call could not be made, or its response not handled
+    fn from_response<T>(
+        response: Option<&Result<T, hyperswitch_domain_models::router_data::ErrorResponse>>,
+    ) -> Self {
+        match response {
+            Some(Ok(_)) => Self::Success,
+            Some(response) if payments_sync_auth::is_auth_failure(response) => Self::AuthFailure,
+            Some(response) if payments_sync_rate_limit::is_rate_limited(response) => {
+                Self::RateLimited
+            }
+            Some(Err(error)) if error.code == consts::REQUEST_TIMEOUT_ERROR_CODE => Self::Timeout,
+            Some(Err(_)) | None => Self::OtherError,
+        }
+    }
+}
+
+fn record_payments_sync_call(
+    connector_name: &str,
+    merchant_id: &id_type::MerchantId,
+    call_time: time::Duration,
+    outcome: PaymentsSyncCallOutcome,
+) {
+    let merchant_id = merchant_id.get_string_repr().to_owned();
+    metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_CALL_TIME.record(
+        call_time.as_seconds_f64(),
+        router_env::metric_attributes!(
+            ("connector", connector_name.to_owned()),
+            ("merchant_id", merchant_id.clone()),
+        ),
+    );
+    metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_CALL_OUTCOME_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            ("connector", connector_name.to_owned()),
+            ("merchant_id", merchant_id),
+            ("outcome", outcome.to_string()),
+        ),
+    );
+}
+
+/// Delay before retrying the payments sync after the given number of attempts, doubling after every
+/// attempt. Half of the delay is randomized, so that the retries of the concurrent webhooks of the
+/// billing connector are spread out.
+fn get_payments_sync_retry_delay(
+    base_delay_in_millis: u32,
+    attempts_made: u8,
+    spread_factor: f64,
+) -> std::time::Duration {
+    let delay_in_millis =
+        f64::from(base_delay_in_millis) * 2_f64.powi(i32::from(attempts_made.saturating_sub(1)));
+    std::time::Duration::from_secs_f64(
+        delay_in_millis * (0.5 + 0.5 * spread_factor.clamp(0.0, 1.0)) / 1000.0,
+    )
+}
+
+/// Timeout of the payments sync calls made through the billing connector account, the timeout of
+/// the account taking precedence over the one configured for the deployment. The default request
+/// timeout is used when neither is configured.
+fn get_payments_sync_timeout_in_secs(
+    account_timeout_in_secs: Option<u16>,
+    default_timeout_in_secs: Option<u64>,
+) -> Option<u64> {
+    account_timeout_in_secs
+        .map(u64::from)
+        .or(default_timeout_in_secs)
+}
+
+/// Whether the details needed to process the webhook can be parsed from the webhook itself, without
+/// the payments sync of the billing connector
+fn is_webhook_self_sufficient(
+    connector_enum: &connector_integration_interface::ConnectorEnum,
+    request_details: &hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails<'_>,
+    is_recovery_transaction_event: bool,
+) -> bool {
+    interface_webhooks::IncomingWebhook::get_revenue_recovery_invoice_details(
+        connector_enum,
+        request_details,
+    )
+    .is_ok()
+        && (!is_recovery_transaction_event
+            || interface_webhooks::IncomingWebhook::get_revenue_recovery_attempt_details(
+                connector_enum,
+                request_details,
+            )
+            .is_ok())
+}
+
+fn get_payments_sync_cache_key(
+    billing_connector_account_id: &id_type::MerchantConnectorAccountId,
+    transaction_id: &str,
+) -> String {
+    format!(
+        "{PAYMENTS_SYNC_CACHE_PREFIX}_{}_{transaction_id}",
+        billing_connector_account_id.get_string_repr()
+    )
+}
+
+/// Router data of the billing connector payments sync, along with the secondary credential of the
+/// billing connector account to retry the call with on an authentication failure
 pub struct BillingConnectorPaymentsSyncFlowRouterData(
     router_types::BillingConnectorPaymentsSyncRouterData,
+    Option<types::ConnectorAuthType>,
 );
 
 impl BillingConnectorPaymentsSyncResponseData {
-    async fn handle_billing_connector_payment_sync_call(
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(skip_all, fields(connector = connector_name, payments_sync_time_in_millis))]
+    pub(crate) async fn handle_billing_connector_payment_sync_call(
         state: &SessionState,
         merchant_account: &domain::MerchantAccount,
         merchant_connector_account: &hyperswitch_domain_models::merchant_connector_account::MerchantConnectorAccount,
         connector_name: &str,
-        id: &str,
+        id: &revenue_recovery_request::BillingConnectorPaymentsSyncId,
+        event_timestamp: time::PrimitiveDateTime,
+        event_status: Option<common_enums::AttemptStatus>,
+        cache_mode: PaymentsSyncCacheMode,
+        recovery_correlation_id: Option<&str>,
     ) -> CustomResult<Self, errors::RevenueRecoveryError> {
+        let cache_ttl_in_secs = state.conf.billing_connectors_payment_sync.cache_ttl_in_secs;
+        let cache_key = match id {
+            revenue_recovery_request::BillingConnectorPaymentsSyncId::TransactionId(
+                transaction_id,
+            ) if cache_ttl_in_secs > 0 => Some(get_payments_sync_cache_key(
+                &merchant_connector_account.get_id(),
+                transaction_id,
+            )),
+            _ => None,
+        };
+
+        if let Some(cache_key) = cache_key
+            .as_deref()
+            .filter(|_| cache_mode == PaymentsSyncCacheMode::ReadThrough)
+        {
+            let metric_attributes =
+                router_env::metric_attributes!(("connector", connector_name.to_owned()));
+            match Self::get_cached_response(state, cache_key).await {
+                Some(response) => {
+                    metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_CACHE_HIT_COUNT
+                        .add(1, metric_attributes);
+                    return Ok(Self(response));
+                }
+                None => {
+                    metrics::REVENUE_RECOVERY_PAYMENTS_SYNC_CACHE_MISS_COUNT
+                        .add(1, metric_attributes);
+                }
+            }
+        }
+
+        let billing_connector_account_id = merchant_connector_account.get_id();
+        let circuit_state =
+            core_revenue_recovery::payments_sync_circuit::check_payments_sync_circuit(
+                state,
+                &billing_connector_account_id,
+                connector_name,
+            )
+            .await;
+        if circuit_state
+            == core_revenue_recovery::payments_sync_circuit::PaymentsSyncCircuitState::Open
+        {
+            return Err(report!(
+                errors::RevenueRecoveryError::BillingConnectorPaymentsSyncCircuitOpen
+            ))
+            .attach_printable_lazy(|| {
+                format!("Payments sync through {connector_name} is paused after repeated failures")
+            });
+        }
+
+        inject_recovery_fault!(
+            state,
+            BillingConnectorPaymentsSync,
+            errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed
+        );
+
         let connector_data = api::ConnectorData::get_connector_by_name(
             &state.conf.connectors,
             connector_name,
@@ -755,19 +2768,22 @@ impl BillingConnectorPaymentsSyncResponseData {
         .change_context(errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed)
         .attach_printable("invalid connector name received in payment attempt")?;
 
-        let connector_integration: services::BoxedBillingConnectorPaymentsSyncIntegrationInterface<
-            router_flow_types::BillingConnectorPaymentsSync,
-            revenue_recovery_request::BillingConnectorPaymentsSyncRequest,
-            revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
-        > = connector_data.connector.get_connector_integration();
+        let outbound_proxy = billing_connector_proxy::get_billing_connector_proxy(
+            helpers::MerchantConnectorAccountType::DbVal(Box::new(
+                merchant_connector_account.clone(),
+            ))
+            .get_connector_account_details(),
+        )
+        .change_context(errors::RevenueRecoveryError::BillingConnectorProxyMisconfigured)?;
 
-        let router_data =
+        let (mut router_data, secondary_auth_type) =
             BillingConnectorPaymentsSyncFlowRouterData::construct_router_data_for_billing_connector_payment_sync_call(
                 state,
                 connector_name,
                 merchant_connector_account,
                 merchant_account,
                 id,
+                recovery_correlation_id,
             )
             .await
             .change_context(errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed)
@@ -776,28 +2792,251 @@ impl BillingConnectorPaymentsSyncResponseData {
             )?
             .inner();
 
-        let response = services::execute_connector_processing_step(
-            state,
-            connector_integration,
-            &router_data,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await
-        .change_context(errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed)
-        .attach_printable("Failed while fetching billing connector payment details")?;
+        // The transient failures of the billing connector are retried within the request, so that
+        // the billing connector does not redeliver the webhook only for the same call to be made
+        let retry_settings = &state.conf.billing_connectors_payment_sync;
+        let timeout_in_secs = get_payments_sync_timeout_in_secs(
+            merchant_connector_account.get_recovery_payments_sync_timeout_in_secs(),
+            retry_settings.timeout_in_secs,
+        );
+        let captured_retry_after = payments_sync_rate_limit::CapturedRetryAfter::default();
+        let mut attempts_made: u8 = 0;
+        let mut credential = payments_sync_auth::BillingConnectorCredential::Primary;
+        let mut payments_sync_time = time::Duration::ZERO;
+        let response = loop {
+            attempts_made = attempts_made.saturating_add(1);
+            let connector_integration: services::BoxedBillingConnectorPaymentsSyncIntegrationInterface<
+                router_flow_types::BillingConnectorPaymentsSync,
+                revenue_recovery_request::BillingConnectorPaymentsSyncRequest,
+                revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
+            > = connector_data.connector.get_connector_integration();
+
+            let (response, call_time) = common_utils::metrics::utils::time_future(
+                services::execute_connector_processing_step_with_timeout(
+                    state,
+                    Box::new(
+                        payments_sync_rate_limit::RetryAfterCapturingIntegration::new(
+                            connector_integration,
+                            &captured_retry_after,
+                        ),
+                    ),
+                    &router_data,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    timeout_in_secs,
+                    outbound_proxy.as_ref(),
+                ),
+            )
+            .await;
+            payments_sync_time += call_time;
+            record_payments_sync_call(
+                connector_name,
+                merchant_account.get_id(),
+                call_time,
+                PaymentsSyncCallOutcome::from_response(
+                    response.as_ref().ok().map(|response| &response.response),
+                ),
+            );
+
+            let response = response
+                .map_err(|error| {
+                    let error_context = if billing_connector_proxy::is_proxy_misconfigured(&error) {
+                        errors::RevenueRecoveryError::BillingConnectorProxyMisconfigured
+                    } else {
+                        get_billing_connector_sync_error(
+                            error.current_context(),
+                            errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed,
+                        )
+                    };
+                    error.change_context(error_context)
+                })
+                .attach_printable_lazy(|| {
+                    format!("Failed to sync the payment from {connector_name} using {id:?}")
+                })
+                .attach_printable_lazy(|| format!("Attempts made: {attempts_made}"));
+
+            match (response, secondary_auth_type.as_ref()) {
+                // An unauthorized call is made once more using the secondary credential, as the
+                // merchant could be rotating the credentials of the billing connector
+                (Ok(response), Some(secondary_auth_type))
+                    if credential == payments_sync_auth::BillingConnectorCredential::Primary
+                        && payments_sync_auth::is_auth_failure(&response.response) =>
+                {
+                    router_env::logger::warn!(
+                        error = ?response.response,
+                        "Billing connector payments sync was unauthorized, retrying using the secondary credential"
+                    );
+                    router_data.connector_auth_type = secondary_auth_type.clone();
+                    credential = payments_sync_auth::BillingConnectorCredential::Secondary;
+                }
+                (Ok(response), _)
+                    if is_transient_payments_sync_failure(&response.response)
+                        && attempts_made <= retry_settings.max_retries =>
+                {
+                    let retry_delay = get_payments_sync_retry_delay(
+                        retry_settings.retry_base_delay_in_millis,
+                        attempts_made,
+                        rand::thread_rng().gen_range(0.0..=1.0),
+                    );
+                    router_env::logger::warn!(
+                        attempts_made,
+                        ?retry_delay,
+                        error = ?response.response,
+                        "Billing connector payments sync failed transiently, retrying"
+                    );
+                    tokio::time::sleep(retry_delay).await;
+                }
+                (response, _) => break response,
+            }
+        };
+
+        if credential == payments_sync_auth::BillingConnectorCredential::Secondary {
+            if let Ok(response) = response.as_ref() {
+                payments_sync_auth::record_secondary_credential_payments_sync(
+                    connector_name,
+                    &response.response,
+                );
+            }
+        }
+        tracing::Span::current().record(
+            "payments_sync_time_in_millis",
+            u64::try_from(payments_sync_time.whole_milliseconds()).unwrap_or(u64::MAX),
+        );
+        router_env::logger::info!(
+            %credential,
+            attempts_made,
+            "Billing connector payments sync made"
+        );
+
+        // The billing connector is taken to be unavailable only if it could not be reached or
+        // failed transiently, its 4xx responses show that it is up. A misconfigured account does
+        // not tell either way, as the billing connector is not called.
+        let is_account_misconfigured = response.as_ref().is_err_and(|error| {
+            matches!(
+                error.current_context(),
+                errors::RevenueRecoveryError::BillingConnectorAccountMisconfigured { .. }
+                    | errors::RevenueRecoveryError::BillingConnectorProxyMisconfigured
+            )
+        });
+        if !is_account_misconfigured {
+            core_revenue_recovery::payments_sync_circuit::record_payments_sync_outcome(
+                state,
+                &billing_connector_account_id,
+                connector_name,
+                response
+                    .as_ref()
+                    .is_ok_and(|response| !is_transient_payments_sync_failure(&response.response)),
+            )
+            .await;
+        }
+
+        let response = response?;
+        // A rate limited payments sync is not retried before the time asked by the billing connector
+        if payments_sync_rate_limit::is_rate_limited(&response.response) {
+            let retry_after_in_secs = captured_retry_after.get_retry_after_in_secs();
+            payments_sync_rate_limit::record_rate_limited_payments_sync(
+                connector_name,
+                retry_after_in_secs,
+            );
+            return Err(report!(
+                errors::RevenueRecoveryError::BillingConnectorPaymentsSyncRateLimited {
+                    retry_after_in_secs
+                }
+            ))
+            .attach_printable(format!("Attempts made: {attempts_made}"));
+        }
 
         let additional_recovery_details = match response.response {
             Ok(response) => Ok(response),
-            error @ Err(_) => {
-                router_env::logger::error!(?error);
-                Err(errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed)
+            Err(error) => {
+                router_env::logger::error!(?error, attempts_made);
+                // The timeouts are told apart from the failures, to tell a slow billing
+                // connector from a failing one
+                let error_context = if error.code == consts::REQUEST_TIMEOUT_ERROR_CODE {
+                    errors::RevenueRecoveryError::BillingConnectorPaymentsSyncTimedOut
+                } else {
+                    errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed
+                };
+                Err(report!(error_context))
                     .attach_printable("Failed while fetching billing connector payment details")
+                    .attach_printable(format!("Attempts made: {attempts_made}"))
             }
         }?;
+
+        // The billing connector can return all the transactions of the invoice, of which the one
+        // with the id of the webhook, or the one made before the event, is the one it refers to
+        let additional_recovery_details = match id {
+            revenue_recovery_request::BillingConnectorPaymentsSyncId::TransactionId(
+                transaction_id,
+            ) => {
+                get_transaction_for_event(additional_recovery_details, transaction_id, event_status)
+            }
+            revenue_recovery_request::BillingConnectorPaymentsSyncId::InvoiceId(_) => {
+                get_invoice_transaction_for_event(additional_recovery_details, event_timestamp)
+            }
+        };
+
+        if let Some(cache_key) = cache_key.filter(|_| cache_mode != PaymentsSyncCacheMode::Bypass) {
+            Self::cache_response(
+                state,
+                &cache_key,
+                &additional_recovery_details,
+                cache_ttl_in_secs,
+            )
+            .await;
+        }
+
         Ok(Self(additional_recovery_details))
     }
 
+    /// Reads the cached response of the transaction. The errors of redis are logged and taken as a
+    /// miss, as the response can always be fetched from the billing connector.
+    async fn get_cached_response(
+        state: &SessionState,
+        cache_key: &str,
+    ) -> Option<revenue_recovery_response::BillingConnectorPaymentsSyncResponse> {
+        crate::db::get_and_deserialize_key(
+            state.store.as_ref(),
+            cache_key,
+            "BillingConnectorPaymentsSyncResponse",
+        )
+        .await
+        .map_err(|error| {
+            router_env::logger::debug!(
+                ?error,
+                "Payments sync response of the transaction is not cached"
+            )
+        })
+        .ok()
+    }
+
+    async fn cache_response(
+        state: &SessionState,
+        cache_key: &str,
+        response: &revenue_recovery_response::BillingConnectorPaymentsSyncResponse,
+        cache_ttl_in_secs: u32,
+    ) {
+        let result = match state.store.get_redis_conn() {
+            Ok(redis_conn) => {
+                redis_conn
+                    .serialize_and_set_key_with_expiry(
+                        &cache_key.into(),
+                        response,
+                        i64::from(cache_ttl_in_secs),
+                    )
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            router_env::logger::error!(
+                ?error,
+                "Failed to cache the payments sync response of the transaction"
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn get_billing_connector_payment_details(
         should_billing_connector_payment_api_called: bool,
         state: &SessionState,
@@ -805,29 +3044,77 @@ impl BillingConnectorPaymentsSyncResponseData {
         billing_connector_account: &hyperswitch_domain_models::merchant_connector_account::MerchantConnectorAccount,
         connector_name: &str,
         object_ref_id: &webhooks::ObjectReferenceId,
+        connector_enum: &connector_integration_interface::ConnectorEnum,
+        request_details: &hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails<'_>,
+        event_type: webhooks::IncomingWebhookEvent,
+        recovery_correlation_id: &str,
     ) -> CustomResult<
         Option<revenue_recovery_response::BillingConnectorPaymentsSyncResponse>,
         errors::RevenueRecoveryError,
     > {
         let response_data = match should_billing_connector_payment_api_called {
             true => {
-                let billing_connector_transaction_id = object_ref_id
-                    .clone()
-                    .get_connector_transaction_id_as_string()
+                let billing_connector_psync_id = match object_ref_id {
+                    webhooks::ObjectReferenceId::InvoiceId(
+                        webhooks::InvoiceIdType::ConnectorInvoiceId(invoice_id),
+                    ) => revenue_recovery_request::BillingConnectorPaymentsSyncId::InvoiceId(
+                        invoice_id.clone(),
+                    ),
+                    _ => revenue_recovery_request::BillingConnectorPaymentsSyncId::TransactionId(
+                        object_ref_id
+                            .clone()
+                            .get_connector_transaction_id_as_string()
+                            .change_context(
+                                errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed,
+                            )
+                            .attach_printable("Billing connector Payments api call failed")?,
+                    ),
+                };
+                let event_timestamp =
+                    interface_webhooks::IncomingWebhook::get_revenue_recovery_event_timestamp(
+                        connector_enum,
+                        request_details,
+                    )
                     .change_context(
                         errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed,
                     )
-                    .attach_printable("Billing connector Payments api call failed")?;
+                    .attach_printable("Failed to get the event timestamp from the webhook")?
+                    .unwrap_or_else(common_utils::date_time::now);
                 let billing_connector_payment_details =
                     Self::handle_billing_connector_payment_sync_call(
                         state,
                         merchant_account,
                         billing_connector_account,
                         connector_name,
-                        &billing_connector_transaction_id,
+                        &billing_connector_psync_id,
+                        event_timestamp,
+                        get_transaction_status_of_event(event_type),
+                        PaymentsSyncCacheMode::ReadThrough,
+                        Some(recovery_correlation_id),
                     )
-                    .await?;
-                Some(billing_connector_payment_details.inner())
+                    .await;
+                match billing_connector_payment_details {
+                    Ok(billing_connector_payment_details) => {
+                        Some(billing_connector_payment_details.inner())
+                    }
+                    Err(error)
+                        if matches!(
+                            error.current_context(),
+                            errors::RevenueRecoveryError::BillingConnectorPaymentsSyncCircuitOpen
+                        ) && is_webhook_self_sufficient(
+                            connector_enum,
+                            request_details,
+                            event_type.is_recovery_transaction_event(),
+                        ) =>
+                    {
+                        router_env::logger::warn!(
+                            ?error,
+                            "Billing connector is unavailable, processing the webhook by its payload"
+                        );
+                        None
+                    }
+                    Err(error) => return Err(error),
+                }
             }
             false => None,
         };
@@ -835,7 +3122,7 @@ impl BillingConnectorPaymentsSyncResponseData {
         Ok(response_data)
     }
 
-    fn inner(self) -> revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+    pub(crate) fn inner(self) -> revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
         self.0
     }
 }
@@ -846,22 +3133,30 @@ impl BillingConnectorPaymentsSyncFlowRouterData {
         connector_name: &str,
         merchant_connector_account: &hyperswitch_domain_models::merchant_connector_account::MerchantConnectorAccount,
         merchant_account: &domain::MerchantAccount,
-        billing_connector_psync_id: &str,
+        billing_connector_psync_id: &revenue_recovery_request::BillingConnectorPaymentsSyncId,
+        recovery_correlation_id: Option<&str>,
     ) -> CustomResult<Self, errors::RevenueRecoveryError> {
-        let auth_type: types::ConnectorAuthType = helpers::MerchantConnectorAccountType::DbVal(
-            Box::new(merchant_connector_account.clone()),
+        let payments_sync_auth::BillingConnectorAuthTypes {
+            primary: auth_type,
+            secondary: secondary_auth_type,
+        } = payments_sync_auth::BillingConnectorAuthTypes::from_connector_account_details(
+            helpers::MerchantConnectorAccountType::DbVal(Box::new(
+                merchant_connector_account.clone(),
+            ))
+            .get_connector_account_details(),
         )
-        .get_connector_account_details()
-        .parse_value("ConnectorAuthType")
         .change_context(errors::RevenueRecoveryError::BillingConnectorPaymentsSyncFailed)?;
 
         let router_data = types::RouterDataV2 {
             flow: PhantomData::<router_flow_types::BillingConnectorPaymentsSync>,
             tenant_id: state.tenant.tenant_id.clone(),
-            resource_common_data: flow_common_types::BillingConnectorPaymentsSyncFlowData,
+            resource_common_data: flow_common_types::BillingConnectorPaymentsSyncFlowData {
+                connector_meta_data: merchant_connector_account.metadata.clone(),
+            },
             connector_auth_type: auth_type,
             request: revenue_recovery_request::BillingConnectorPaymentsSyncRequest {
-                billing_connector_psync_id: billing_connector_psync_id.to_string(),
+                billing_connector_psync_id: billing_connector_psync_id.clone(),
+                recovery_correlation_id: recovery_correlation_id.map(ToOwned::to_owned),
             },
             response: Err(types::ErrorResponse::default()),
         };
@@ -875,10 +3170,889 @@ impl BillingConnectorPaymentsSyncFlowRouterData {
                 "Cannot construct router data for making the billing connector payments api call",
             )?;
 
+        Ok(Self(old_router_data, secondary_auth_type))
+    }
+
+    fn inner(
+        self,
+    ) -> (
+        router_types::BillingConnectorPaymentsSyncRouterData,
+        Option<types::ConnectorAuthType>,
+    ) {
+        (self.0, self.1)
+    }
+}
+
+/// Invoice sync of a webhook of the invoice, made when the webhook does not carry the details of
+/// the invoice
+pub struct BillingConnectorInvoiceSyncCall<'a> {
+    state: &'a SessionState,
+    merchant_account: &'a domain::MerchantAccount,
+    billing_connector_account:
+        &'a hyperswitch_domain_models::merchant_connector_account::MerchantConnectorAccount,
+    connector_name: &'a str,
+    invoice_id: &'a str,
+}
+
+pub struct BillingConnectorInvoiceSyncFlowRouterData(
+    router_types::BillingConnectorInvoiceSyncRouterData,
+);
+
+impl BillingConnectorInvoiceSyncCall<'_> {
+    async fn call(
+        self,
+    ) -> CustomResult<
+        revenue_recovery_response::BillingConnectorInvoiceSyncResponse,
+        errors::RevenueRecoveryError,
+    > {
+        let Self {
+            state,
+            merchant_account,
+            billing_connector_account,
+            connector_name,
+            invoice_id,
+        } = self;
+
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            connector_name,
+            api::GetToken::Connector,
+            None,
+        )
+        .change_context(errors::RevenueRecoveryError::BillingConnectorInvoiceSyncFailed)
+        .attach_printable("invalid connector name received in the billing connector account")?;
+
+        let connector_integration: services::BoxedBillingConnectorInvoiceSyncIntegrationInterface<
+            router_flow_types::BillingConnectorInvoiceSync,
+            revenue_recovery_request::BillingConnectorInvoiceSyncRequest,
+            revenue_recovery_response::BillingConnectorInvoiceSyncResponse,
+        > = connector_data.connector.get_connector_integration();
+
+        let router_data =
+            BillingConnectorInvoiceSyncFlowRouterData::construct_router_data_for_billing_connector_invoice_sync_call(
+                state,
+                billing_connector_account,
+                merchant_account,
+                invoice_id,
+            )
+            .await?
+            .inner();
+
+        let outbound_proxy = billing_connector_proxy::get_billing_connector_proxy(
+            helpers::MerchantConnectorAccountType::DbVal(Box::new(
+                billing_connector_account.clone(),
+            ))
+            .get_connector_account_details(),
+        )
+        .change_context(errors::RevenueRecoveryError::BillingConnectorProxyMisconfigured)?;
+
+        let timeout_in_secs = get_payments_sync_timeout_in_secs(
+            billing_connector_account.get_recovery_payments_sync_timeout_in_secs(),
+            state.conf.billing_connectors_payment_sync.timeout_in_secs,
+        );
+        let response = services::execute_connector_processing_step_with_timeout(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+            None,
+            timeout_in_secs,
+            outbound_proxy.as_ref(),
+        )
+        .await
+        .map_err(|error| {
+            let error_context = if billing_connector_proxy::is_proxy_misconfigured(&error) {
+                errors::RevenueRecoveryError::BillingConnectorProxyMisconfigured
+            } else {
+                get_billing_connector_sync_error(
+                    error.current_context(),
+                    errors::RevenueRecoveryError::BillingConnectorInvoiceSyncFailed,
+                )
+            };
+            error.change_context(error_context)
+        })
+        .attach_printable_lazy(|| {
+            format!("Failed to sync the invoice {invoice_id} from {connector_name}")
+        })?;
+
+        response.response.map_err(|error| {
+            router_env::logger::error!(?error);
+            report!(errors::RevenueRecoveryError::BillingConnectorInvoiceSyncFailed)
+                .attach_printable("Failed while fetching the invoice from the billing connector")
+        })
+    }
+}
+
+impl BillingConnectorInvoiceSyncFlowRouterData {
+    async fn construct_router_data_for_billing_connector_invoice_sync_call(
+        state: &SessionState,
+        merchant_connector_account: &hyperswitch_domain_models::merchant_connector_account::MerchantConnectorAccount,
+        merchant_account: &domain::MerchantAccount,
+        invoice_id: &str,
+    ) -> CustomResult<Self, errors::RevenueRecoveryError> {
+        let auth_type: types::ConnectorAuthType = helpers::MerchantConnectorAccountType::DbVal(
+            Box::new(merchant_connector_account.clone()),
+        )
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::RevenueRecoveryError::BillingConnectorInvoiceSyncFailed)?;
+
+        let router_data = types::RouterDataV2 {
+            flow: PhantomData::<router_flow_types::BillingConnectorInvoiceSync>,
+            tenant_id: state.tenant.tenant_id.clone(),
+            resource_common_data: flow_common_types::BillingConnectorInvoiceSyncFlowData {
+                connector_meta_data: merchant_connector_account.metadata.clone(),
+            },
+            connector_auth_type: auth_type,
+            request: revenue_recovery_request::BillingConnectorInvoiceSyncRequest {
+                billing_connector_invoice_id: invoice_id.to_string(),
+            },
+            response: Err(types::ErrorResponse::default()),
+        };
+
+        let old_router_data =
+            flow_common_types::BillingConnectorInvoiceSyncFlowData::to_old_router_data(
+                router_data,
+            )
+            .change_context(errors::RevenueRecoveryError::BillingConnectorInvoiceSyncFailed)
+            .attach_printable(
+                "Cannot construct router data for making the billing connector invoice api call",
+            )?;
+
         Ok(Self(old_router_data))
     }
 
-    fn inner(self) -> router_types::BillingConnectorPaymentsSyncRouterData {
+    fn inner(self) -> router_types::BillingConnectorInvoiceSyncRouterData {
         self.0
     }
 }
+
+#[cfg(test)]
+mod replay;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_customer_metadata_with_locale_retains_existing_keys() {
+        let metadata = pii::SecretSerdeValue::new(serde_json::json!({ "plan": "premium" }));
+        let locale = Secret::new("de-DE".to_string());
+
+        let updated_metadata = get_customer_metadata_with_locale(Some(&metadata), &locale);
+
+        assert_eq!(
+            updated_metadata.expose(),
+            serde_json::json!({ "plan": "premium", "locale": "de-DE" })
+        );
+    }
+
+    #[test]
+    fn test_customer_metadata_with_locale_overrides_previous_locale() {
+        let metadata = pii::SecretSerdeValue::new(serde_json::json!({ "locale": "en-US" }));
+        let locale = Secret::new("fr-FR".to_string());
+
+        let updated_metadata = get_customer_metadata_with_locale(Some(&metadata), &locale);
+
+        assert_eq!(
+            updated_metadata.expose(),
+            serde_json::json!({ "locale": "fr-FR" })
+        );
+    }
+
+    #[test]
+    fn test_customer_metadata_with_locale_is_masked_in_logs() {
+        let locale = Secret::new("fr-FR".to_string());
+
+        let updated_metadata = get_customer_metadata_with_locale(None, &locale);
+
+        assert!(!format!("{updated_metadata:?}").contains("fr-FR"));
+    }
+
+    #[test]
+    fn test_normalized_transaction_created_at_for_utc_timestamp() {
+        let now = time::macros::datetime!(2025-03-27 10:00:00);
+        let created_at = time::macros::datetime!(2025-03-27 09:30:00);
+
+        let normalized_created_at = get_normalized_transaction_created_at(
+            Some(created_at),
+            None,
+            time::Duration::minutes(5),
+            now,
+        );
+
+        assert_eq!(normalized_created_at, Some(created_at));
+    }
+
+    #[test]
+    fn test_normalized_transaction_created_at_for_offset_timestamp() {
+        let now = time::macros::datetime!(2025-03-27 10:00:00);
+        // Local time of a billing connector in UTC+05:30
+        let created_at = time::macros::datetime!(2025-03-27 15:00:00);
+
+        let normalized_created_at = get_normalized_transaction_created_at(
+            Some(created_at),
+            Some(time::macros::offset!(+5:30)),
+            time::Duration::minutes(5),
+            now,
+        );
+
+        assert_eq!(
+            normalized_created_at,
+            Some(time::macros::datetime!(2025-03-27 09:30:00))
+        );
+    }
+
+    #[test]
+    fn test_normalized_transaction_created_at_for_future_timestamp() {
+        let now = time::macros::datetime!(2025-03-27 10:00:00);
+        let created_at = now + time::Duration::hours(3);
+
+        let normalized_created_at = get_normalized_transaction_created_at(
+            Some(created_at),
+            None,
+            time::Duration::minutes(5),
+            now,
+        );
+
+        assert_eq!(normalized_created_at, Some(now));
+    }
+
+    fn get_sync_response_for_transaction(
+        transaction_id: &str,
+        transaction_created_at: time::PrimitiveDateTime,
+    ) -> revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+        revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+            amount: common_utils::types::MinorUnit::new(1000),
+            currency: common_enums::Currency::USD,
+            merchant_reference_id: id_type::PaymentReferenceId::from_str("invoice_1")
+                .expect("valid merchant reference id"),
+            connector_transaction_id: Some(common_utils::types::ConnectorTransactionId::from(
+                transaction_id.to_string(),
+            )),
+            error_code: None,
+            error_message: None,
+            processor_payment_method_token: "token_1".to_string(),
+            connector_customer_id: "customer_1".to_string(),
+            connector_account_reference_id: "gateway_1".to_string(),
+            transaction_created_at: Some(transaction_created_at),
+            status: common_enums::AttemptStatus::Failure,
+            payment_method_type: common_enums::PaymentMethod::Card,
+            payment_method_sub_type: common_enums::PaymentMethodType::Credit,
+            network_advice_code: None,
+            network_decline_code: None,
+            network_error_message: None,
+            processor_response_details: None,
+            card_issuing_country: None,
+            card_details: None,
+            billing_connector_subscription_id: None,
+            invoice_transactions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_invoice_transaction_for_event_picks_transaction_made_before_event() {
+        let latest_transaction = revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+            invoice_transactions: vec![
+                get_sync_response_for_transaction(
+                    "txn_1",
+                    time::macros::datetime!(2025-03-26 10:00:00),
+                ),
+                get_sync_response_for_transaction(
+                    "txn_2",
+                    time::macros::datetime!(2025-03-27 10:00:00),
+                ),
+            ],
+            ..get_sync_response_for_transaction(
+                "txn_3",
+                time::macros::datetime!(2025-03-28 10:00:00),
+            )
+        };
+
+        let transaction = get_invoice_transaction_for_event(
+            latest_transaction,
+            time::macros::datetime!(2025-03-27 10:05:00),
+        );
+
+        assert_eq!(
+            transaction
+                .connector_transaction_id
+                .map(|id| id.get_id().to_owned()),
+            Some("txn_2".to_string())
+        );
+        assert!(transaction.invoice_transactions.is_empty());
+    }
+
+    #[test]
+    fn test_invoice_transaction_for_event_falls_back_to_latest_transaction() {
+        let latest_transaction = revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+            invoice_transactions: vec![get_sync_response_for_transaction(
+                "txn_1",
+                time::macros::datetime!(2025-03-26 10:00:00),
+            )],
+            ..get_sync_response_for_transaction(
+                "txn_2",
+                time::macros::datetime!(2025-03-28 10:00:00),
+            )
+        };
+
+        let transaction = get_invoice_transaction_for_event(
+            latest_transaction,
+            time::macros::datetime!(2025-03-25 10:00:00),
+        );
+
+        assert_eq!(
+            transaction
+                .connector_transaction_id
+                .map(|id| id.get_id().to_owned()),
+            Some("txn_2".to_string())
+        );
+    }
+
+    fn get_transactions_of_invoice(
+    ) -> revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+        revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+            invoice_transactions: vec![
+                revenue_recovery_response::BillingConnectorPaymentsSyncResponse {
+                    status: common_enums::AttemptStatus::Charged,
+                    ..get_sync_response_for_transaction(
+                        "txn_1",
+                        time::macros::datetime!(2025-03-26 10:00:00),
+                    )
+                },
+                get_sync_response_for_transaction(
+                    "txn_2",
+                    time::macros::datetime!(2025-03-27 10:00:00),
+                ),
+            ],
+            ..get_sync_response_for_transaction(
+                "txn_3",
+                time::macros::datetime!(2025-03-25 10:00:00),
+            )
+        }
+    }
+
+    fn get_selected_transaction_id(
+        transaction_id: &str,
+        event_status: Option<common_enums::AttemptStatus>,
+    ) -> Option<String> {
+        get_transaction_for_event(get_transactions_of_invoice(), transaction_id, event_status)
+            .connector_transaction_id
+            .map(|id| id.get_id().to_owned())
+    }
+
+    #[test]
+    fn test_transaction_for_event_picks_transaction_with_webhook_id() {
+        let transaction = get_transaction_for_event(
+            get_transactions_of_invoice(),
+            "txn_1",
+            Some(common_enums::AttemptStatus::Failure),
+        );
+
+        assert_eq!(
+            transaction
+                .connector_transaction_id
+                .map(|id| id.get_id().to_owned()),
+            Some("txn_1".to_string())
+        );
+        assert!(transaction.invoice_transactions.is_empty());
+        assert_eq!(
+            get_selected_transaction_id("txn_3", None),
+            Some("txn_3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_transaction_for_event_falls_back_to_latest_transaction_in_event_status() {
+        // The latest failed transaction is picked over the transaction returned first
+        assert_eq!(
+            get_selected_transaction_id("txn_4", Some(common_enums::AttemptStatus::Failure)),
+            Some("txn_2".to_string())
+        );
+        assert_eq!(
+            get_selected_transaction_id("txn_4", Some(common_enums::AttemptStatus::Charged)),
+            Some("txn_1".to_string())
+        );
+        // The transaction returned by the billing connector is used when no transaction is in the
+        // status of the event
+        assert_eq!(
+            get_selected_transaction_id("txn_4", Some(common_enums::AttemptStatus::Pending)),
+            Some("txn_3".to_string())
+        );
+        assert_eq!(
+            get_selected_transaction_id("txn_4", None),
+            Some("txn_3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_transaction_status_of_event() {
+        assert_eq!(
+            get_transaction_status_of_event(webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess),
+            Some(common_enums::AttemptStatus::Charged)
+        );
+        assert_eq!(
+            get_transaction_status_of_event(webhooks::IncomingWebhookEvent::RecoveryInvoiceCancel),
+            None
+        );
+    }
+
+    fn get_decision_details(
+        payment_id: Option<id_type::GlobalPaymentId>,
+    ) -> RecoveryDecisionDetails {
+        RecoveryDecisionDetails {
+            recovery_correlation_id: "rrd_recovery".to_string(),
+            payment_id,
+            recovery_action: Some(get_recovery_action_name(
+                &revenue_recovery::RecoveryAction::ScheduleFailedPayment,
+            )),
+            attempt_triggered_by: Some(common_enums::TriggeredBy::External),
+            retry_count: Some(2),
+            retry_threshold: Some(4),
+            is_billing_connector_payment_sync_called: true,
+            invoice_data_provenance: None,
+            attempt_data_provenance: None,
+        }
+    }
+
+    #[test]
+    fn test_recovery_decision_records_error_of_failed_flow() {
+        let cell_id = id_type::CellId::from_string("defid").expect("valid cell id");
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let profile_id = id_type::ProfileId::try_from(std::borrow::Cow::from("pro_recovery"))
+            .expect("valid profile id");
+        let response = Err(report!(RevenueRecoveryError::PaymentIntentFetchFailed));
+
+        let decision = get_decision_details(Some(payment_id.clone()))
+            .to_revenue_recovery_decision(
+                &id_type::MerchantId::default(),
+                &profile_id,
+                webhooks::IncomingWebhookEvent::RecoveryPaymentFailure,
+                &response,
+            )
+            .expect("decision for resolved payment");
+
+        assert_eq!(decision.payment_id, payment_id);
+        assert_eq!(decision.event_type, "recovery_payment_failure");
+        assert_eq!(
+            decision.recovery_action.as_deref(),
+            Some("schedule_failed_payment")
+        );
+        assert_eq!(decision.retry_count, Some(2));
+        assert_eq!(decision.retry_threshold, Some(4));
+        assert_eq!(decision.error.as_deref(), Some("PaymentIntentFetchFailed"));
+        assert_eq!(decision.task_id, None);
+    }
+
+    #[test]
+    fn test_recovery_decision_is_not_recorded_without_payment() {
+        let profile_id = id_type::ProfileId::try_from(std::borrow::Cow::from("pro_recovery"))
+            .expect("valid profile id");
+        let response = Err(report!(RevenueRecoveryError::PaymentIntentFetchFailed));
+
+        let decision = get_decision_details(None).to_revenue_recovery_decision(
+            &id_type::MerchantId::default(),
+            &profile_id,
+            webhooks::IncomingWebhookEvent::RecoveryPaymentFailure,
+            &response,
+        );
+
+        assert!(decision.is_none());
+    }
+
+    #[test]
+    fn test_calculate_task_tracking_data_is_read_as_execute_task_tracking_data() {
+        let cell_id = id_type::CellId::from_string("defid").expect("valid cell id");
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let calculate_workflow_tracking_data =
+            storage_churn_recovery::PcrCalculateWorkflowTrackingData {
+                workflow_tracking_data: storage_churn_recovery::PcrWorkflowTrackingData {
+                    merchant_id: id_type::MerchantId::default(),
+                    profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from(
+                        "pro_recovery",
+                    ))
+                    .expect("valid profile id"),
+                    global_payment_id: payment_id.clone(),
+                    payment_attempt_id: id_type::GlobalAttemptId::generate(&cell_id),
+                    billing_mca_id: id_type::MerchantConnectorAccountId::wrap(
+                        "mca_recovery".to_string(),
+                    )
+                    .expect("valid merchant connector account id"),
+                    schedule_adjustments: Vec::new(),
+                    recovery_correlation_id: None,
+                    decline_class: None,
+                    execution_failure_count: 0,
+                    last_execution_error: None,
+                    schedule_decision: None,
+                },
+                intent_retry_count: 3,
+                processor_token_tag: Some("pm_token_hash_1".to_string()),
+                last_attempt_error_code: Some("insufficient_funds".to_string()),
+                card_issuing_country: None,
+            };
+        let tracking_data = serde_json::to_value(&calculate_workflow_tracking_data)
+            .expect("serializable tracking data");
+
+        let workflow_tracking_data = tracking_data
+            .clone()
+            .parse_value::<storage_churn_recovery::PcrWorkflowTrackingData>(
+                "PcrWorkflowTrackingData",
+            )
+            .expect("calculate tracking data readable as workflow tracking data");
+        let parsed_calculate_workflow_tracking_data = tracking_data
+            .parse_value::<storage_churn_recovery::PcrCalculateWorkflowTrackingData>(
+                "PcrCalculateWorkflowTrackingData",
+            )
+            .expect("valid calculate tracking data");
+
+        assert_eq!(workflow_tracking_data.global_payment_id, payment_id);
+        assert_eq!(
+            parsed_calculate_workflow_tracking_data.intent_retry_count,
+            3
+        );
+        assert_eq!(
+            parsed_calculate_workflow_tracking_data
+                .processor_token_tag
+                .as_deref(),
+            Some("pm_token_hash_1")
+        );
+        assert_eq!(
+            parsed_calculate_workflow_tracking_data
+                .last_attempt_error_code
+                .as_deref(),
+            Some("insufficient_funds")
+        );
+    }
+
+    #[test]
+    fn test_calculate_and_execute_tasks_of_payment_have_distinct_ids() {
+        let cell_id = id_type::CellId::from_string("defid").expect("valid cell id");
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+
+        let calculate_task_id =
+            core_revenue_recovery::get_calculate_pcr_task_id(&payment_id, runner);
+        let execute_task_id = core_revenue_recovery::get_execute_pcr_task_id(&payment_id, runner);
+
+        assert_ne!(calculate_task_id, execute_task_id);
+        assert!(calculate_task_id.contains(core_revenue_recovery::CALCULATE_WORKFLOW));
+        assert!(calculate_task_id.ends_with(payment_id.get_string_repr()));
+    }
+
+    #[test]
+    fn test_recovery_correlation_id_is_propagated_to_retry_attempt() {
+        let cell_id = id_type::CellId::from_string("defid").expect("valid cell id");
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let profile_id = id_type::ProfileId::try_from(std::borrow::Cow::from("pro_recovery"))
+            .expect("valid profile id");
+        let decision_details = get_decision_details(Some(payment_id.clone()));
+        let response = Ok(webhooks::WebhookResponseTracker::NoEffect);
+
+        let decision = decision_details
+            .to_revenue_recovery_decision(
+                &id_type::MerchantId::default(),
+                &profile_id,
+                webhooks::IncomingWebhookEvent::RecoveryPaymentFailure,
+                &response,
+            )
+            .expect("decision for resolved payment");
+
+        let calculate_workflow_tracking_data =
+            storage_churn_recovery::PcrCalculateWorkflowTrackingData {
+                workflow_tracking_data: storage_churn_recovery::PcrWorkflowTrackingData {
+                    merchant_id: id_type::MerchantId::default(),
+                    profile_id,
+                    global_payment_id: payment_id,
+                    payment_attempt_id: id_type::GlobalAttemptId::generate(&cell_id),
+                    billing_mca_id: id_type::MerchantConnectorAccountId::wrap(
+                        "mca_recovery".to_string(),
+                    )
+                    .expect("valid merchant connector account id"),
+                    schedule_adjustments: Vec::new(),
+                    recovery_correlation_id: Some(decision_details.recovery_correlation_id.clone()),
+                    decline_class: None,
+                    execution_failure_count: 0,
+                    last_execution_error: None,
+                    schedule_decision: None,
+                },
+                intent_retry_count: 3,
+                processor_token_tag: None,
+                last_attempt_error_code: None,
+                card_issuing_country: None,
+            };
+        let workflow_tracking_data = serde_json::to_value(&calculate_workflow_tracking_data)
+            .expect("serializable tracking data")
+            .parse_value::<storage_churn_recovery::PcrWorkflowTrackingData>(
+                "PcrWorkflowTrackingData",
+            )
+            .expect("calculate tracking data readable as workflow tracking data");
+
+        let retry_attempt_feature_metadata =
+            revenue_recovery_types::get_retry_attempt_feature_metadata(
+                None,
+                &workflow_tracking_data,
+                None,
+            );
+
+        assert_eq!(
+            retry_attempt_feature_metadata
+                .revenue_recovery
+                .and_then(|recovery_data| recovery_data.recovery_correlation_id),
+            Some(decision.id)
+        );
+    }
+
+    #[cfg(feature = "recovery_fault_injection")]
+    #[test]
+    fn test_failures_injected_in_webhook_flow_are_recorded_in_decision() {
+        use crate::utils::fault_injection::{
+            FaultInjectionRegistry, RecoveryFaultPoint, RecoveryFaultTrigger,
+        };
+
+        let cell_id = id_type::CellId::from_string("defid").expect("valid cell id");
+        let payment_id = id_type::GlobalPaymentId::generate(&cell_id);
+        let profile_id = id_type::ProfileId::try_from(std::borrow::Cow::from("pro_recovery"))
+            .expect("valid profile id");
+        let registry = FaultInjectionRegistry::default();
+
+        for (point, error, expected_error) in [
+            (
+                RecoveryFaultPoint::BillingConnectorPaymentsSync,
+                RevenueRecoveryError::BillingConnectorPaymentsSyncFailed,
+                "BillingConnectorPaymentsSyncFailed",
+            ),
+            (
+                RecoveryFaultPoint::PaymentIntentCreate,
+                RevenueRecoveryError::PaymentIntentCreateFailed,
+                "PaymentIntentCreateFailed",
+            ),
+            (
+                RecoveryFaultPoint::RecordAttempt,
+                RevenueRecoveryError::PaymentAttemptFetchFailed,
+                "PaymentAttemptFetchFailed",
+            ),
+            (
+                RecoveryFaultPoint::ProcessTrackerInsert,
+                RevenueRecoveryError::ProcessTrackerCreationError,
+                "ProcessTrackerCreationError",
+            ),
+        ] {
+            registry.inject(point, RecoveryFaultTrigger::Countdown(0));
+            let response = registry
+                .check(point, error)
+                .map(|()| webhooks::WebhookResponseTracker::NoEffect);
+
+            let decision = get_decision_details(Some(payment_id.clone()))
+                .to_revenue_recovery_decision(
+                    &id_type::MerchantId::default(),
+                    &profile_id,
+                    webhooks::IncomingWebhookEvent::RecoveryPaymentFailure,
+                    &response,
+                )
+                .expect("decision for resolved payment");
+
+            assert_eq!(decision.error.as_deref(), Some(expected_error));
+            // The countdown is disarmed once the failure has been injected
+            assert!(registry.check(point, error).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_polled_transactions_are_processed_as_their_webhooks() {
+        assert_eq!(
+            get_event_type_of_polled_transaction(common_enums::AttemptStatus::Failure),
+            Some(webhooks::IncomingWebhookEvent::RecoveryPaymentFailure)
+        );
+        assert_eq!(
+            get_event_type_of_polled_transaction(common_enums::AttemptStatus::Charged),
+            Some(webhooks::IncomingWebhookEvent::RecoveryPaymentSuccess)
+        );
+        assert_eq!(
+            get_event_type_of_polled_transaction(common_enums::AttemptStatus::Pending),
+            Some(webhooks::IncomingWebhookEvent::RecoveryPaymentPending)
+        );
+        assert_eq!(
+            get_event_type_of_polled_transaction(common_enums::AttemptStatus::Voided),
+            None
+        );
+    }
+
+    #[test]
+    fn test_recovery_correlation_id_is_taken_from_the_request_headers() {
+        let get_correlation_id = |correlation_id: &'static str| {
+            let mut headers = actix_web::http::header::HeaderMap::new();
+            headers.insert(
+                actix_web::http::header::HeaderName::from_static("x-recovery-correlation-id"),
+                actix_web::http::header::HeaderValue::from_static(correlation_id),
+            );
+            get_recovery_correlation_id(&headers)
+        };
+
+        assert_eq!(get_correlation_id("req_7f3a-91"), "req_7f3a-91");
+        assert!(get_correlation_id("").starts_with("rrd_"));
+        assert!(get_correlation_id("id with spaces").starts_with("rrd_"));
+        assert!(get_correlation_id(
+            "a_correlation_id_which_is_longer_than_the_sixty_four_characters_allowed"
+        )
+        .starts_with("rrd_"));
+        assert!(
+            get_recovery_correlation_id(&actix_web::http::header::HeaderMap::new())
+                .starts_with("rrd_")
+        );
+    }
+
+    #[test]
+    fn test_payments_sync_cache_key_is_scoped_to_the_billing_connector_account() {
+        let billing_connector_account_id =
+            id_type::MerchantConnectorAccountId::wrap(String::from("mca_chargebee"))
+                .expect("valid merchant connector account id");
+
+        assert_eq!(
+            get_payments_sync_cache_key(&billing_connector_account_id, "txn_123"),
+            "RECOVERY_PAYMENTS_SYNC_mca_chargebee_txn_123"
+        );
+    }
+
+    #[test]
+    fn test_only_gateway_failures_of_the_payments_sync_are_retried() {
+        let get_error_response = |status_code| {
+            Err::<(), _>(hyperswitch_domain_models::router_data::ErrorResponse {
+                status_code,
+                ..Default::default()
+            })
+        };
+
+        assert!(is_transient_payments_sync_failure(&get_error_response(503)));
+        // Timeouts are reported as gateway timeouts
+        assert!(is_transient_payments_sync_failure(&get_error_response(504)));
+        assert!(!is_transient_payments_sync_failure(&get_error_response(
+            500
+        )));
+        assert!(!is_transient_payments_sync_failure(&get_error_response(
+            404
+        )));
+        assert!(!is_transient_payments_sync_failure(&Ok::<(), _>(())));
+    }
+
+    #[test]
+    fn test_payments_sync_call_outcome() {
+        let get_error_response = |status_code, code: &str| {
+            Err::<(), _>(hyperswitch_domain_models::router_data::ErrorResponse {
+                status_code,
+                code: code.to_string(),
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(
+            PaymentsSyncCallOutcome::from_response(Some(&Ok::<(), _>(()))),
+            PaymentsSyncCallOutcome::Success
+        );
+        assert_eq!(
+            PaymentsSyncCallOutcome::from_response(Some(&get_error_response(401, "unauthorized"))),
+            PaymentsSyncCallOutcome::AuthFailure
+        );
+        assert_eq!(
+            PaymentsSyncCallOutcome::from_response(Some(&get_error_response(429, "rate_limited"))),
+            PaymentsSyncCallOutcome::RateLimited
+        );
+        assert_eq!(
+            PaymentsSyncCallOutcome::from_response(Some(&get_error_response(
+                504,
+                consts::REQUEST_TIMEOUT_ERROR_CODE
+            ))),
+            PaymentsSyncCallOutcome::Timeout
+        );
+        assert_eq!(
+            PaymentsSyncCallOutcome::from_response(Some(&get_error_response(500, "server_error"))),
+            PaymentsSyncCallOutcome::OtherError
+        );
+        assert_eq!(
+            PaymentsSyncCallOutcome::from_response(None::<&Result<(), _>>),
+            PaymentsSyncCallOutcome::OtherError
+        );
+        assert_eq!(
+            PaymentsSyncCallOutcome::AuthFailure.to_string(),
+            "auth_failure"
+        );
+    }
+
+    #[test]
+    fn test_payments_sync_retry_delay_backs_off_with_jitter() {
+        assert_eq!(
+            get_payments_sync_retry_delay(200, 1, 1.0),
+            std::time::Duration::from_millis(200)
+        );
+        assert_eq!(
+            get_payments_sync_retry_delay(200, 2, 1.0),
+            std::time::Duration::from_millis(400)
+        );
+        assert_eq!(
+            get_payments_sync_retry_delay(200, 2, 0.0),
+            std::time::Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn test_payments_sync_timeout_of_the_account_overrides_the_default() {
+        assert_eq!(
+            get_payments_sync_timeout_in_secs(Some(5), Some(10)),
+            Some(5)
+        );
+        assert_eq!(get_payments_sync_timeout_in_secs(None, Some(10)), Some(10));
+        assert_eq!(get_payments_sync_timeout_in_secs(None, None), None);
+    }
+
+    #[test]
+    fn test_rate_limited_webhook_is_asked_to_be_redelivered_later() {
+        assert!(matches!(
+            get_recovery_webhook_api_error(
+                &RevenueRecoveryError::BillingConnectorPaymentsSyncRateLimited {
+                    retry_after_in_secs: 30
+                }
+            ),
+            errors::ApiErrorResponse::TooManyRequests { retry_after: 30 }
+        ));
+        assert!(matches!(
+            get_recovery_webhook_api_error(
+                &RevenueRecoveryError::BillingConnectorPaymentsSyncFailed
+            ),
+            errors::ApiErrorResponse::WebhookProcessingFailure
+        ));
+    }
+
+    #[test]
+    fn test_missing_metadata_of_account_is_surfaced_as_configuration_error() {
+        let sync_error = get_billing_connector_sync_error(
+            &errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.site_id",
+            },
+            RevenueRecoveryError::BillingConnectorPaymentsSyncFailed,
+        );
+        assert!(matches!(
+            &sync_error,
+            RevenueRecoveryError::BillingConnectorAccountMisconfigured { config }
+                if config == "metadata.site_id"
+        ));
+        assert!(matches!(
+            get_recovery_webhook_api_error(&sync_error),
+            errors::ApiErrorResponse::InvalidConnectorConfiguration { config }
+                if config == "metadata.site_id"
+        ));
+        assert!(matches!(
+            get_billing_connector_sync_error(
+                &errors::ConnectorError::NoConnectorMetaData,
+                RevenueRecoveryError::BillingConnectorInvoiceSyncFailed,
+            ),
+            RevenueRecoveryError::BillingConnectorAccountMisconfigured { config }
+                if config == "metadata"
+        ));
+        assert!(matches!(
+            get_billing_connector_sync_error(
+                &errors::ConnectorError::ResponseDeserializationFailed,
+                RevenueRecoveryError::BillingConnectorInvoiceSyncFailed,
+            ),
+            RevenueRecoveryError::BillingConnectorInvoiceSyncFailed
+        ));
+    }
+}
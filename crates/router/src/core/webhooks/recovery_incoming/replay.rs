@@ -0,0 +1,408 @@
+//! Replays the recorded webhooks of the billing connectors through the parsing, the classification
+//! and the decision of the revenue recovery webhook flow, and compares the outcome of every
+//! fixture with the outcome recorded in it.
+//!
+//! The fixtures are kept in `tests/fixtures/revenue_recovery`, along with the description of their
+//! format. The outcome of a fixture which does not match is printed in the same format, so that
+//! an intended change in the outcome can be reviewed and recorded in the fixture.
+
+#![allow(clippy::expect_used)]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use api_models::{enums as api_enums, webhooks};
+use hyperswitch_domain_models::revenue_recovery;
+use hyperswitch_interfaces::webhooks::{IncomingWebhook, IncomingWebhookRequestDetails};
+use masking::PeekInterface;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{get_recovery_action_name, RevenueRecoveryAttempt, RevenueRecoveryInvoice};
+use crate::{services::connector_integration_interface::ConnectorEnum, types::api};
+
+/// Billing connectors which are replayed, each of them is required to have its fixtures. A billing
+/// connector is to be added here along with the fixtures of its webhooks.
+const REPLAYED_BILLING_CONNECTORS: [api_enums::Connector; 4] = [
+    api_enums::Connector::Chargebee,
+    api_enums::Connector::DummyBillingConnector,
+    api_enums::Connector::Recurly,
+    api_enums::Connector::Stripebilling,
+];
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReplayFixture {
+    description: String,
+    /// Whether the attempt referred to by the webhook was triggered by us, as found in the
+    /// payment attempts of the invoice while processing the webhook
+    #[serde(default)]
+    attempt_triggered_by: Option<common_enums::TriggeredBy>,
+    /// The payload of the webhook, a string payload is replayed as is
+    webhook: serde_json::Value,
+    expected: ReplayOutcome,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReplayOutcome {
+    event_type: Replayed<webhooks::IncomingWebhookEvent>,
+    object_reference_id: Option<Replayed<ReplayedReference>>,
+    invoice: Option<Replayed<ReplayedInvoice>>,
+    attempt: Option<Replayed<ReplayedAttempt>>,
+    action: Option<String>,
+}
+
+/// Result of a stage of the replay, the error is recorded by the name of the error
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Replayed<T> {
+    Ok(T),
+    Err(String),
+}
+
+impl<T, C: error_stack::Context> From<Result<T, error_stack::Report<C>>> for Replayed<T> {
+    fn from(result: Result<T, error_stack::Report<C>>) -> Self {
+        match result {
+            Ok(value) => Self::Ok(value),
+            Err(report) => Self::Err(format!("{:?}", report.current_context())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ReplayedReference {
+    ConnectorInvoiceId(String),
+    ConnectorSubscriptionId(String),
+    ConnectorCustomerId(String),
+    ConnectorTransactionId(String),
+}
+
+impl ReplayedReference {
+    fn replay(object_reference_id: webhooks::ObjectReferenceId) -> Replayed<Self> {
+        match object_reference_id {
+            webhooks::ObjectReferenceId::InvoiceId(
+                webhooks::InvoiceIdType::ConnectorInvoiceId(id),
+            ) => Replayed::Ok(Self::ConnectorInvoiceId(id)),
+            webhooks::ObjectReferenceId::InvoiceId(
+                webhooks::InvoiceIdType::ConnectorSubscriptionId(id),
+            ) => Replayed::Ok(Self::ConnectorSubscriptionId(id)),
+            webhooks::ObjectReferenceId::InvoiceId(
+                webhooks::InvoiceIdType::ConnectorCustomerId(id),
+            ) => Replayed::Ok(Self::ConnectorCustomerId(id)),
+            webhooks::ObjectReferenceId::PaymentId(
+                api_models::payments::PaymentIdType::ConnectorTransactionId(id),
+            ) => Replayed::Ok(Self::ConnectorTransactionId(id)),
+            _ => Replayed::Err(String::from("UnsupportedObjectReferenceId")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReplayedInvoice {
+    amount: i64,
+    currency: common_enums::Currency,
+    merchant_reference_id: String,
+    billing_connector_customer_id: Option<String>,
+    customer_email: Option<String>,
+    customer_locale: Option<String>,
+    billing_connector_subscription_id: Option<String>,
+}
+
+impl From<revenue_recovery::RevenueRecoveryInvoiceData> for ReplayedInvoice {
+    fn from(invoice: revenue_recovery::RevenueRecoveryInvoiceData) -> Self {
+        let customer_details = invoice.customer_details.as_ref();
+        Self {
+            amount: invoice.amount.get_amount_as_i64(),
+            currency: invoice.currency,
+            merchant_reference_id: invoice.merchant_reference_id.get_string_repr().to_string(),
+            billing_connector_customer_id: customer_details
+                .map(|customer| customer.billing_connector_customer_id.clone()),
+            customer_email: customer_details
+                .and_then(|customer| customer.email.as_ref())
+                .map(|email| email.peek().to_string()),
+            customer_locale: customer_details
+                .and_then(|customer| customer.locale.as_ref())
+                .map(|locale| locale.peek().to_string()),
+            billing_connector_subscription_id: invoice.billing_connector_subscription_id,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReplayedAttempt {
+    amount: i64,
+    currency: common_enums::Currency,
+    merchant_reference_id: String,
+    connector_transaction_id: Option<String>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    processor_payment_method_token: String,
+    connector_customer_id: String,
+    connector_account_reference_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    transaction_created_at: Option<time::PrimitiveDateTime>,
+    status: common_enums::AttemptStatus,
+    payment_method_type: common_enums::PaymentMethod,
+    payment_method_sub_type: common_enums::PaymentMethodType,
+    network_decline_code: Option<String>,
+    card_issuing_country: Option<common_enums::CountryAlpha2>,
+}
+
+impl From<revenue_recovery::RevenueRecoveryAttemptData> for ReplayedAttempt {
+    fn from(attempt: revenue_recovery::RevenueRecoveryAttemptData) -> Self {
+        Self {
+            amount: attempt.amount.get_amount_as_i64(),
+            currency: attempt.currency,
+            merchant_reference_id: attempt.merchant_reference_id.get_string_repr().to_string(),
+            connector_transaction_id: attempt
+                .connector_transaction_id
+                .as_ref()
+                .map(|id| id.get_id().clone()),
+            error_code: attempt.error_code,
+            error_message: attempt.error_message,
+            processor_payment_method_token: attempt.processor_payment_method_token,
+            connector_customer_id: attempt.connector_customer_id,
+            connector_account_reference_id: attempt.connector_account_reference_id,
+            transaction_created_at: attempt.transaction_created_at,
+            status: attempt.status,
+            payment_method_type: attempt.payment_method_type,
+            payment_method_sub_type: attempt.payment_method_sub_type,
+            network_decline_code: attempt.network_decline_code,
+            card_issuing_country: attempt.card_issuing_country,
+        }
+    }
+}
+
+fn get_fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/revenue_recovery")
+}
+
+fn get_connector_enum(connector: api_enums::Connector) -> Option<ConnectorEnum> {
+    api::ConnectorData::convert_connector(&connector.to_string()).ok()
+}
+
+fn get_request_details<'a>(
+    headers: &'a actix_web::http::header::HeaderMap,
+    body: &'a [u8],
+) -> IncomingWebhookRequestDetails<'a> {
+    IncomingWebhookRequestDetails {
+        method: actix_web::http::Method::POST,
+        uri: actix_web::http::Uri::from_static("/v2/webhooks/recovery"),
+        headers,
+        body,
+        query_params: String::new(),
+    }
+}
+
+/// Replays the webhook in the same order as the revenue recovery webhook flow. The payments sync
+/// of the billing connectors is not replayed, only the data carried by the webhooks is.
+async fn replay_webhook(
+    connector: api_enums::Connector,
+    body: &[u8],
+    attempt_triggered_by: Option<common_enums::TriggeredBy>,
+) -> ReplayOutcome {
+    let connector_enum = get_connector_enum(connector).expect("replayed connector not found");
+    let headers = actix_web::http::header::HeaderMap::new();
+    let request_details = get_request_details(&headers, body);
+
+    let event_type = match connector_enum.get_webhook_event_type(&request_details) {
+        Ok(event_type) => event_type,
+        // The webhook is rejected before it reaches the revenue recovery flow
+        Err(report) => {
+            return ReplayOutcome {
+                event_type: Replayed::from(Err::<webhooks::IncomingWebhookEvent, _>(report)),
+                object_reference_id: None,
+                invoice: None,
+                attempt: None,
+                action: None,
+            }
+        }
+    };
+
+    let object_reference_id = match connector_enum.get_webhook_object_reference_id(&request_details)
+    {
+        Ok(object_reference_id) => ReplayedReference::replay(object_reference_id),
+        Err(report) => Replayed::from(Err::<ReplayedReference, _>(report)),
+    };
+
+    // Subscription and dispute events do not carry an invoice
+    let invoice = if matches!(
+        event_type,
+        webhooks::IncomingWebhookEvent::RecoverySubscriptionCancel
+            | webhooks::IncomingWebhookEvent::RecoveryDisputeOpened
+            | webhooks::IncomingWebhookEvent::RecoveryDisputeClosed
+    ) {
+        None
+    } else {
+        Some(Replayed::from(
+            RevenueRecoveryInvoice::get_recovery_invoice_details(
+                &connector_enum,
+                connector,
+                &request_details,
+                None,
+                None,
+            )
+            .await
+            .map(|invoice| ReplayedInvoice::from(invoice.0)),
+        ))
+    };
+
+    let attempt = event_type.is_recovery_transaction_event().then(|| {
+        Replayed::from(
+            RevenueRecoveryAttempt::get_recovery_invoice_transaction_details(
+                &connector_enum,
+                connector,
+                &request_details,
+                None,
+            )
+            .map(|attempt| ReplayedAttempt::from(attempt.0)),
+        )
+    });
+
+    let action = revenue_recovery::RecoveryAction::get_action(event_type, attempt_triggered_by);
+
+    ReplayOutcome {
+        event_type: Replayed::Ok(event_type),
+        object_reference_id: Some(object_reference_id),
+        invoice,
+        attempt,
+        action: Some(get_recovery_action_name(&action).to_string()),
+    }
+}
+
+fn get_sorted_dir_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut paths = fs::read_dir(dir)
+        .expect("failed to read the fixtures directory")
+        .map(|entry| entry.expect("failed to read the fixtures directory").path())
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+fn get_connector_fixtures() -> Vec<(api_enums::Connector, Vec<PathBuf>)> {
+    get_sorted_dir_entries(&get_fixtures_dir())
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .map(|dir| {
+            let connector_name = dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .expect("invalid fixtures directory name");
+            let connector = api_enums::Connector::from_str(connector_name)
+                .expect("fixtures directory is not named after a connector");
+            let fixtures = get_sorted_dir_entries(&dir)
+                .into_iter()
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|extension| extension == "json")
+                })
+                .collect();
+            (connector, fixtures)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_replay_recorded_webhooks() {
+    let mut replayed_fixtures = 0;
+    let mut mismatches = Vec::new();
+
+    for (connector, fixtures) in get_connector_fixtures() {
+        for path in fixtures {
+            let fixture = match serde_json::from_slice::<ReplayFixture>(
+                &fs::read(&path).expect("failed to read the fixture"),
+            ) {
+                Ok(fixture) => fixture,
+                Err(error) => {
+                    mismatches.push(format!("{}, invalid fixture: {error}", path.display()));
+                    continue;
+                }
+            };
+            let body = match &fixture.webhook {
+                serde_json::Value::String(body) => body.clone().into_bytes(),
+                webhook => serde_json::to_vec(webhook).expect("failed to serialize the webhook"),
+            };
+
+            let outcome = replay_webhook(connector, &body, fixture.attempt_triggered_by).await;
+            replayed_fixtures += 1;
+            if outcome != fixture.expected {
+                mismatches.push(format!(
+                    "{} ({}), replayed outcome:\n{}",
+                    path.display(),
+                    fixture.description,
+                    serde_json::to_string_pretty(&outcome)
+                        .expect("failed to serialize the outcome")
+                ));
+            }
+        }
+    }
+
+    assert!(replayed_fixtures > 0, "no fixtures found to be replayed");
+    assert!(
+        mismatches.is_empty(),
+        "the outcome of the fixtures changed, review the outcomes and update the fixtures if the \
+         change is intended:\n\n{}",
+        mismatches.join("\n\n")
+    );
+}
+
+#[test]
+fn test_fixtures_cover_the_billing_connectors() {
+    let connector_fixtures = get_connector_fixtures();
+
+    for connector in REPLAYED_BILLING_CONNECTORS {
+        assert!(
+            connector_fixtures
+                .iter()
+                .any(
+                    |(fixtures_connector, fixtures)| *fixtures_connector == connector
+                        && !fixtures.is_empty()
+                ),
+            "no fixtures found for the billing connector {connector}"
+        );
+    }
+    for (connector, _) in &connector_fixtures {
+        assert!(
+            REPLAYED_BILLING_CONNECTORS.contains(connector),
+            "fixtures found for {connector}, which is not a replayed billing connector"
+        );
+    }
+
+    // The connectors which parse the recovery data from their webhooks are billing connectors,
+    // whether they were added to the replayed billing connectors or not
+    let body: &[u8] = b"{}";
+    let headers = actix_web::http::header::HeaderMap::new();
+    let request_details = get_request_details(&headers, body);
+    for connector in api_enums::Connector::iter() {
+        let Some(connector_enum) = get_connector_enum(connector) else {
+            continue;
+        };
+        let is_not_implemented = |error: &hyperswitch_interfaces::errors::ConnectorError| {
+            matches!(
+                error,
+                hyperswitch_interfaces::errors::ConnectorError::NotImplemented(_)
+                    | hyperswitch_interfaces::errors::ConnectorError::WebhooksNotImplemented
+            )
+        };
+        let parses_recovery_data = connector_enum
+            .get_revenue_recovery_invoice_details(&request_details)
+            .err()
+            .map_or(true, |report| !is_not_implemented(report.current_context()))
+            || connector_enum
+                .get_revenue_recovery_attempt_details(&request_details)
+                .err()
+                .map_or(true, |report| !is_not_implemented(report.current_context()));
+
+        assert!(
+            !parses_recovery_data || REPLAYED_BILLING_CONNECTORS.contains(&connector),
+            "{connector} parses revenue recovery webhooks, but has no fixtures to be replayed"
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use std::marker::PhantomData;
 
-use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use common_utils::{errors::CustomResult, ext_traits::ValueExt, pii};
 use error_stack::ResultExt;
 
 use crate::{
+    configs::settings,
     core::{
         errors::{self},
+        metrics,
         payments::helpers,
     },
     db::{get_and_deserialize_key, StorageInterface},
@@ -144,7 +146,9 @@ pub(crate) fn get_idempotent_event_id(
     let common_prefix = format!("{primary_object_id}_{event_type}");
     match delivery_attempt {
         WebhookDeliveryAttempt::InitialAttempt => common_prefix,
-        WebhookDeliveryAttempt::AutomaticRetry | WebhookDeliveryAttempt::ManualRetry => {
+        WebhookDeliveryAttempt::AutomaticRetry
+        | WebhookDeliveryAttempt::ManualRetry
+        | WebhookDeliveryAttempt::ManualRetryWithOverrideUrl => {
             common_utils::generate_id(EVENT_ID_SUFFIX_LENGTH, &common_prefix)
         }
     }
@@ -154,3 +158,209 @@ pub(crate) fn get_idempotent_event_id(
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+/// Rejects an incoming webhook whose body is larger than the limit, or whose content type is not
+/// accepted, before any work is done on the body. The limits configured in the webhook details of
+/// the connector account take precedence over the ones configured for all the connectors.
+pub(crate) fn validate_incoming_webhook_request(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    connector_webhook_details: Option<&pii::SecretSerdeValue>,
+    headers: &actix_web::http::header::HeaderMap,
+    body: &[u8],
+) -> errors::RouterResult<()> {
+    let webhook_details = connector_webhook_details.and_then(|webhook_details| {
+        webhook_details
+            .clone()
+            .parse_value::<api_models::admin::MerchantConnectorWebhookDetails>(
+                "MerchantConnectorWebhookDetails",
+            )
+            .map_err(|error| {
+                logger::warn!(
+                    ?error,
+                    "Failed to parse the webhook details of the connector account, using the default webhook limits"
+                )
+            })
+            .ok()
+    });
+    let content_type = headers
+        .get(actix_web::http::header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok());
+
+    check_incoming_webhook_request(
+        &state.conf.webhooks.incoming,
+        webhook_details.as_ref(),
+        content_type,
+        body.len(),
+    )
+    .map_err(|error| {
+        let reason = match error {
+            errors::ApiErrorResponse::WebhookPayloadTooLarge { .. } => "body_too_large",
+            _ => "unsupported_content_type",
+        };
+        metrics::WEBHOOK_INCOMING_REJECTED_COUNT.add(
+            1,
+            router_env::metric_attributes!(
+                (super::MERCHANT_ID, merchant_id.clone()),
+                ("connector", connector_name.to_owned()),
+                ("reason", reason)
+            ),
+        );
+        logger::warn!(
+            reason,
+            body_size = body.len(),
+            ?content_type,
+            "Rejected the incoming webhook before parsing"
+        );
+        error_stack::report!(error)
+    })
+}
+
+fn check_incoming_webhook_request(
+    settings: &settings::IncomingWebhookSettings,
+    webhook_details: Option<&api_models::admin::MerchantConnectorWebhookDetails>,
+    content_type: Option<&str>,
+    body_size: usize,
+) -> Result<(), errors::ApiErrorResponse> {
+    let max_body_size_in_bytes = webhook_details
+        .and_then(|webhook_details| webhook_details.max_body_size_in_bytes)
+        .unwrap_or(settings.max_body_size_in_bytes);
+    if u32::try_from(body_size).map_or(true, |body_size| body_size > max_body_size_in_bytes) {
+        return Err(errors::ApiErrorResponse::WebhookPayloadTooLarge {
+            max_size_in_bytes: max_body_size_in_bytes,
+        });
+    }
+
+    let accepted_content_types = webhook_details
+        .and_then(|webhook_details| webhook_details.accepted_content_types.as_ref())
+        .unwrap_or(&settings.accepted_content_types);
+    if accepted_content_types.is_empty() {
+        return Ok(());
+    }
+
+    // Parameters such as the charset are not considered, `application/json; charset=utf-8` is
+    // accepted when `application/json` is
+    let media_type = content_type
+        .and_then(|content_type| content_type.split(';').next())
+        .map(str::trim)
+        .unwrap_or_default();
+    let is_accepted = accepted_content_types.iter().any(|accepted_content_type| {
+        accepted_content_type
+            .trim()
+            .eq_ignore_ascii_case(media_type)
+    });
+
+    if is_accepted {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::WebhookUnsupportedContentType)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_settings() -> settings::IncomingWebhookSettings {
+        settings::IncomingWebhookSettings {
+            max_body_size_in_bytes: 1024,
+            accepted_content_types: vec!["application/json".to_string()],
+        }
+    }
+
+    fn get_webhook_details(
+        max_body_size_in_bytes: Option<u32>,
+        accepted_content_types: Option<Vec<String>>,
+    ) -> api_models::admin::MerchantConnectorWebhookDetails {
+        api_models::admin::MerchantConnectorWebhookDetails {
+            merchant_secret: masking::Secret::new("secret".to_string()),
+            additional_secret: None,
+            max_body_size_in_bytes,
+            accepted_content_types,
+        }
+    }
+
+    #[test]
+    fn test_oversized_webhook_is_rejected() {
+        let settings = get_settings();
+
+        assert!(
+            check_incoming_webhook_request(&settings, None, Some("application/json"), 1024).is_ok()
+        );
+        assert!(matches!(
+            check_incoming_webhook_request(&settings, None, Some("application/json"), 1025),
+            Err(errors::ApiErrorResponse::WebhookPayloadTooLarge {
+                max_size_in_bytes: 1024
+            })
+        ));
+
+        // The limit of the connector account overrides the default limit, in either direction
+        let webhook_details = get_webhook_details(Some(4096), None);
+        assert!(check_incoming_webhook_request(
+            &settings,
+            Some(&webhook_details),
+            Some("application/json"),
+            2048
+        )
+        .is_ok());
+        let webhook_details = get_webhook_details(Some(512), None);
+        assert!(matches!(
+            check_incoming_webhook_request(
+                &settings,
+                Some(&webhook_details),
+                Some("application/json"),
+                513
+            ),
+            Err(errors::ApiErrorResponse::WebhookPayloadTooLarge {
+                max_size_in_bytes: 512
+            })
+        ));
+    }
+
+    #[test]
+    fn test_webhook_with_wrong_content_type_is_rejected() {
+        let settings = get_settings();
+
+        assert!(check_incoming_webhook_request(
+            &settings,
+            None,
+            Some("Application/JSON; charset=utf-8"),
+            10
+        )
+        .is_ok());
+        assert!(matches!(
+            check_incoming_webhook_request(&settings, None, Some("text/xml"), 10),
+            Err(errors::ApiErrorResponse::WebhookUnsupportedContentType)
+        ));
+        assert!(matches!(
+            check_incoming_webhook_request(&settings, None, None, 10),
+            Err(errors::ApiErrorResponse::WebhookUnsupportedContentType)
+        ));
+
+        let webhook_details = get_webhook_details(None, Some(vec!["text/xml".to_string()]));
+        assert!(check_incoming_webhook_request(
+            &settings,
+            Some(&webhook_details),
+            Some("text/xml"),
+            10
+        )
+        .is_ok());
+        assert!(matches!(
+            check_incoming_webhook_request(
+                &settings,
+                Some(&webhook_details),
+                Some("application/json"),
+                10
+            ),
+            Err(errors::ApiErrorResponse::WebhookUnsupportedContentType)
+        ));
+
+        // Any content type is accepted when none are configured
+        let settings = settings::IncomingWebhookSettings {
+            accepted_content_types: Vec::new(),
+            ..get_settings()
+        };
+        assert!(check_incoming_webhook_request(&settings, None, None, 10).is_ok());
+    }
+}
@@ -0,0 +1,665 @@
+//! Daily digest of the webhook delivery failures of a profile.
+//!
+//! A process tracker task is scheduled per profile for the configured hour of the day in the
+//! configured timezone. Every run summarizes the failed delivery attempts of the preceding day,
+//! emits the summary as a `digest.daily` audit event and sends it through the configured channels.
+
+use std::collections::HashMap;
+
+use common_utils::{ext_traits::StringExt, fp_utils, request::RequestContent};
+use diesel_models::{business_profile::WebhookDigestConfig, process_tracker::business_status};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    events::audit_events::{AuditEvent, AuditEventType},
+    logger,
+    routes::SessionState,
+    services,
+    types::{api, domain, storage, storage::enums},
+    utils::timezone,
+};
+
+const WEBHOOK_DIGEST_NAME: &str = "WEBHOOK_DIGEST";
+const WEBHOOK_DIGEST_TAG: &str = "WEBHOOK_DIGEST";
+const WEBHOOK_DIGEST_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::WebhookDigestWorkflow;
+const WEBHOOK_DIGEST_TIMEOUT_SECS: u64 = 5;
+
+/// The day summarized by a digest, as a range of UTC times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct WebhookDigestWindow {
+    /// The date, in the timezone of the digest, on which the digest is sent
+    pub digest_date: time::Date,
+    pub start: time::PrimitiveDateTime,
+    pub end: time::PrimitiveDateTime,
+}
+
+pub fn validate_digest_config(
+    digest_config: &api_models::admin::WebhookDigestConfig,
+) -> RouterResult<()> {
+    fp_utils::when(digest_config.hour_of_day > 23, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "digest_config.hour_of_day must be between 0 and 23".to_string(),
+        }))
+    })?;
+
+    fp_utils::when(
+        timezone::parse_timezone(&digest_config.timezone).is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "digest_config.timezone {} is not a valid IANA timezone",
+                    digest_config.timezone
+                ),
+            }))
+        },
+    )?;
+
+    fp_utils::when(
+        digest_config.enabled && digest_config.channels.is_empty(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "digest_config.channels must not be empty when the digest is enabled"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
+pub(crate) fn get_enabled_digest_config(
+    business_profile: &domain::Profile,
+) -> Option<&WebhookDigestConfig> {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.digest_config.as_ref())
+        .filter(|digest_config| digest_config.enabled)
+}
+
+fn generate_task_id_for_webhook_digest_workflow(
+    profile_id: &common_utils::id_type::ProfileId,
+) -> String {
+    format!(
+        "{WEBHOOK_DIGEST_RUNNER}_{WEBHOOK_DIGEST_NAME}_{}",
+        profile_id.get_string_repr()
+    )
+}
+
+fn get_next_schedule_time(
+    digest_config: &WebhookDigestConfig,
+    current_time: time::PrimitiveDateTime,
+) -> Option<time::PrimitiveDateTime> {
+    let digest_timezone = timezone::parse_timezone(&digest_config.timezone)?;
+    timezone::get_next_local_hour(current_time, digest_timezone, digest_config.hour_of_day)
+}
+
+/// Schedules the digest task of the profile for the next occurrence of the configured hour, if the
+/// digest was enabled or reconfigured by the profile update. A task whose digest was disabled
+/// completes on its next run.
+#[instrument(skip_all)]
+pub(crate) async fn schedule_webhook_digest_task(
+    state: &SessionState,
+    previous_digest_config: Option<&WebhookDigestConfig>,
+    business_profile: &domain::Profile,
+) -> RouterResult<()> {
+    let Some(digest_config) = get_enabled_digest_config(business_profile) else {
+        return Ok(());
+    };
+
+    if previous_digest_config == Some(digest_config) {
+        return Ok(());
+    }
+
+    let current_time = common_utils::date_time::now();
+    let schedule_time = get_next_schedule_time(digest_config, current_time)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the schedule time of the webhook digest task")?;
+
+    let profile_id = business_profile.get_id();
+    let process_tracker_id = generate_task_id_for_webhook_digest_workflow(profile_id);
+    let db = state.store.as_ref();
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the webhook digest task")?;
+
+    match existing_process {
+        Some(process) => {
+            // The tracking data is retained, so that a digest already sent for the day is not
+            // sent again
+            let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                tracking_data: None,
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.update_process(process, process_tracker_update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule the webhook digest task")?;
+        }
+        None => {
+            let tracking_data = storage::WebhookDigestTrackingData {
+                merchant_id: business_profile.merchant_id.clone(),
+                profile_id: profile_id.to_owned(),
+                last_digest_date: None,
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                WEBHOOK_DIGEST_NAME,
+                WEBHOOK_DIGEST_RUNNER,
+                [WEBHOOK_DIGEST_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the webhook digest task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the webhook digest task")?;
+        }
+    }
+
+    logger::info!(?schedule_time, "Scheduled the webhook digest task");
+
+    Ok(())
+}
+
+/// The day ending at the configured hour of the local date of the schedule time
+pub(crate) fn get_digest_window(
+    digest_config: &WebhookDigestConfig,
+    schedule_time: time::PrimitiveDateTime,
+) -> Option<WebhookDigestWindow> {
+    let digest_timezone = timezone::parse_timezone(&digest_config.timezone)?;
+    let hour = time::Time::from_hms(digest_config.hour_of_day, 0, 0).ok()?;
+    let digest_date = timezone::to_local_time(schedule_time, digest_timezone).date();
+
+    Some(WebhookDigestWindow {
+        digest_date,
+        start: timezone::to_utc_time(
+            time::PrimitiveDateTime::new(digest_date.previous_day()?, hour),
+            digest_timezone,
+        ),
+        end: timezone::to_utc_time(
+            time::PrimitiveDateTime::new(digest_date, hour),
+            digest_timezone,
+        ),
+    })
+}
+
+/// Whether the digest of the date is yet to be sent
+pub(crate) fn should_send_digest(
+    last_digest_date: Option<time::Date>,
+    digest_date: time::Date,
+) -> bool {
+    last_digest_date.map_or(true, |last_digest_date| last_digest_date < digest_date)
+}
+
+/// Claims the digest of the window for the task before it is sent, by moving the task to its next
+/// schedule time along with the date of the digest. A task which fails after the claim does not
+/// send the digest of the date again, so that a digest is sent at most once.
+#[instrument(skip_all)]
+pub(crate) async fn claim_digest_and_reschedule_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: &storage::WebhookDigestTrackingData,
+    digest_config: &WebhookDigestConfig,
+    window: &WebhookDigestWindow,
+) -> RouterResult<()> {
+    let current_time = common_utils::date_time::now();
+    let schedule_time = get_next_schedule_time(digest_config, window.end.max(current_time))
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the schedule time of the webhook digest task")?;
+
+    let updated_tracking_data = storage::WebhookDigestTrackingData {
+        last_digest_date: Some(window.digest_date),
+        ..tracking_data.clone()
+    };
+
+    let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: Some(0),
+        schedule_time: Some(schedule_time),
+        tracking_data: Some(
+            serde_json::to_value(updated_tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the webhook digest tracking data")?,
+        ),
+        business_status: Some(String::from(business_status::PENDING)),
+        status: Some(enums::ProcessTrackerStatus::New),
+        updated_at: Some(current_time),
+    };
+
+    state
+        .store
+        .update_process(process, process_tracker_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule the webhook digest task")?;
+
+    Ok(())
+}
+
+/// Counts the status codes of the failed attempts, and returns the most frequent ones
+pub(crate) fn get_top_failure_status_codes(
+    status_codes: impl IntoIterator<Item = Option<u16>>,
+    count: usize,
+) -> Vec<api::webhook_events::WebhookDigestStatusCodeCount> {
+    let mut status_code_counts = HashMap::<Option<u16>, i64>::new();
+    for status_code in status_codes {
+        *status_code_counts.entry(status_code).or_default() += 1;
+    }
+
+    let mut top_status_codes = status_code_counts
+        .into_iter()
+        .map(
+            |(status_code, count)| api::webhook_events::WebhookDigestStatusCodeCount {
+                status_code,
+                count,
+            },
+        )
+        .collect::<Vec<_>>();
+    top_status_codes.sort_by(|first, second| {
+        second
+            .count
+            .cmp(&first.count)
+            .then(first.status_code.cmp(&second.status_code))
+    });
+    top_status_codes.truncate(count);
+
+    top_status_codes
+}
+
+fn get_response_status_code(event: &domain::Event) -> Option<u16> {
+    let response: api::webhook_events::OutgoingWebhookResponseContent = event
+        .response
+        .as_ref()?
+        .peek()
+        .parse_struct("OutgoingWebhookResponseContent")
+        .map_err(|error| logger::warn!(?error, "Failed to parse webhook event response"))
+        .ok()?;
+
+    response.status_code
+}
+
+#[instrument(skip_all)]
+pub(crate) async fn build_webhook_digest(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    digest_config: &WebhookDigestConfig,
+    window: &WebhookDigestWindow,
+) -> RouterResult<api::webhook_events::OutgoingWebhookDigest> {
+    let db = state.store.as_ref();
+    let digest_settings = &state.conf.webhooks.digest;
+    let profile_id = business_profile.get_id();
+
+    let mut failed_deliveries_by_event_type = db
+        .count_failed_delivery_attempts_by_profile_id_event_type(
+            profile_id,
+            window.start,
+            window.end,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the failed webhook delivery attempts")?
+        .into_iter()
+        .map(
+            |(event_type, count)| api::webhook_events::WebhookDigestEventTypeCount {
+                event_type,
+                count,
+            },
+        )
+        .collect::<Vec<_>>();
+    failed_deliveries_by_event_type.sort_by(|first, second| second.count.cmp(&first.count));
+
+    let total_failed_deliveries = failed_deliveries_by_event_type
+        .iter()
+        .map(|event_type_count| event_type_count.count)
+        .sum();
+
+    let failed_attempts = db
+        .list_failed_delivery_attempts_by_profile_id(
+            &state.into(),
+            profile_id,
+            window.start,
+            window.end,
+            digest_settings.failed_attempts_sample_limit,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the failed webhook delivery attempts")?;
+
+    let top_failure_status_codes = get_top_failure_status_codes(
+        failed_attempts.iter().map(get_response_status_code),
+        digest_settings.top_status_codes_count,
+    );
+
+    let backlog_size = db
+        .count_initial_events_by_constraints(
+            &business_profile.merchant_id,
+            Some(profile_id.to_owned()),
+            window.end.saturating_sub(time::Duration::hours(i64::from(
+                digest_settings.backlog_window_in_hours,
+            ))),
+            window.end,
+            Some(false),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the undelivered webhooks")?;
+
+    #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
+    let recovery_exhaustions = Some(
+        db.count_revenue_recovery_collection_items_by_profile_id_created_at(
+            profile_id,
+            window.start,
+            window.end,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the payments handed off to manual collection")?,
+    );
+    #[cfg(not(all(feature = "v2", feature = "revenue_recovery")))]
+    let recovery_exhaustions = None;
+
+    Ok(api::webhook_events::OutgoingWebhookDigest {
+        merchant_id: business_profile.merchant_id.clone(),
+        profile_id: profile_id.to_owned(),
+        event_type: enums::EventType::DigestDaily,
+        timezone: digest_config.timezone.clone(),
+        window_start: window.start,
+        window_end: window.end,
+        total_failed_deliveries,
+        failed_deliveries_by_event_type,
+        top_failure_status_codes,
+        backlog_size,
+        recovery_exhaustions,
+        timestamp: common_utils::date_time::now(),
+    })
+}
+
+/// Emits the digest as an audit event and sends it through the configured channels. A channel
+/// which fails is not retried, since the digest of the day has been claimed by the task.
+#[instrument(skip_all)]
+pub(crate) async fn send_webhook_digest(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    digest_config: &WebhookDigestConfig,
+    digest: api::webhook_events::OutgoingWebhookDigest,
+) {
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::DigestDaily {
+            digest: Box::new(digest.clone()),
+        }))
+        .emit();
+
+    if digest_config.is_channel_enabled(enums::WebhookDigestChannel::Webhook) {
+        send_webhook_digest_to_webhook_url(state, business_profile, digest.clone()).await;
+    }
+
+    if digest_config.is_channel_enabled(enums::WebhookDigestChannel::Email) {
+        #[cfg(feature = "email")]
+        send_webhook_digest_email(state, merchant_account, digest_config, digest).await;
+        #[cfg(not(feature = "email"))]
+        {
+            let _ = merchant_account;
+            logger::warn!("Webhook digest email not sent, since emails are not enabled");
+        }
+    }
+}
+
+async fn send_webhook_digest_to_webhook_url(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    digest: api::webhook_events::OutgoingWebhookDigest,
+) {
+    let Some(webhook_url) = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_url.as_ref())
+    else {
+        logger::warn!("Webhook digest not sent, since the profile has no webhook URL");
+        return;
+    };
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url.peek())
+        .attach_default_headers()
+        .set_body(RequestContent::Json(Box::new(digest)))
+        .build();
+
+    match state
+        .api_client
+        .send_request(state, request, Some(WEBHOOK_DIGEST_TIMEOUT_SECS), false)
+        .await
+    {
+        Ok(response) if response.status().is_success() => {
+            logger::info!("Webhook digest delivered successfully");
+        }
+        Ok(response) => {
+            logger::warn!(
+                status_code = ?response.status(),
+                "Webhook digest was not accepted by the webhook URL"
+            );
+        }
+        Err(error) => {
+            logger::warn!(?error, "Failed to deliver webhook digest");
+        }
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_webhook_digest_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    digest_config: &WebhookDigestConfig,
+    digest: api::webhook_events::OutgoingWebhookDigest,
+) {
+    use common_utils::ext_traits::ValueExt;
+
+    use crate::{consts, services::email::types as email_types};
+
+    let recipient_email = digest_config.email_recipient.clone().or_else(|| {
+        merchant_account
+            .merchant_details
+            .clone()
+            .and_then(|merchant_details| {
+                merchant_details
+                    .parse_value::<api::MerchantDetails>("MerchantDetails")
+                    .map_err(|error| logger::warn!(?error, "Failed to parse merchant details"))
+                    .ok()
+            })
+            .and_then(|merchant_details| merchant_details.primary_email)
+    });
+
+    let Some(recipient_email) = recipient_email else {
+        logger::warn!("Webhook digest email not sent, since no recipient email is configured");
+        return;
+    };
+
+    let recipient_email = match domain::UserEmail::from_pii_email(recipient_email) {
+        Ok(recipient_email) => recipient_email,
+        Err(error) => {
+            logger::warn!(?error, "Webhook digest email not sent to an invalid email");
+            return;
+        }
+    };
+
+    let email_contents = email_types::WebhookFailureDigest {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_WEBHOOK_FAILURE_DIGEST,
+        digest,
+    };
+
+    if let Err(error) = state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            email_types::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+    {
+        logger::warn!(?error, "Failed to send webhook digest email");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use time::macros::{date, datetime};
+
+    use super::*;
+
+    fn digest_config(timezone: &str, hour_of_day: u8) -> WebhookDigestConfig {
+        WebhookDigestConfig {
+            enabled: true,
+            hour_of_day,
+            timezone: timezone.to_string(),
+            channels: vec![enums::WebhookDigestChannel::Webhook],
+            email_recipient: None,
+        }
+    }
+
+    #[test]
+    fn test_digest_window_spans_the_preceding_local_day() {
+        let window = get_digest_window(
+            &digest_config("Asia/Kolkata", 9),
+            datetime!(2024-06-02 03:30),
+        )
+        .expect("valid digest window");
+
+        assert_eq!(
+            window,
+            WebhookDigestWindow {
+                digest_date: date!(2024 - 06 - 02),
+                start: datetime!(2024-06-01 03:30),
+                end: datetime!(2024-06-02 03:30),
+            }
+        );
+    }
+
+    #[test]
+    fn test_digest_window_across_daylight_saving_transition() {
+        // The day on which clocks move forward is 23 hours long
+        let window = get_digest_window(
+            &digest_config("America/New_York", 9),
+            datetime!(2024-03-10 13:00),
+        )
+        .expect("valid digest window");
+
+        assert_eq!(window.start, datetime!(2024-03-09 14:00));
+        assert_eq!(window.end, datetime!(2024-03-10 13:00));
+    }
+
+    #[test]
+    fn test_digest_is_sent_once_per_date() {
+        let digest_date = date!(2024 - 06 - 02);
+
+        assert!(should_send_digest(None, digest_date));
+        assert!(should_send_digest(Some(date!(2024 - 06 - 01)), digest_date));
+        assert!(!should_send_digest(Some(digest_date), digest_date));
+        assert!(!should_send_digest(
+            Some(date!(2024 - 06 - 03)),
+            digest_date
+        ));
+    }
+
+    #[test]
+    fn test_next_schedule_time_after_digest_is_on_next_day() {
+        let config = digest_config("Asia/Kolkata", 9);
+        let window =
+            get_digest_window(&config, datetime!(2024-06-02 03:30)).expect("valid digest window");
+
+        assert_eq!(
+            get_next_schedule_time(&config, window.end),
+            Some(datetime!(2024-06-03 03:30))
+        );
+    }
+
+    #[test]
+    fn test_top_failure_status_codes() {
+        let top_status_codes = get_top_failure_status_codes(
+            [
+                Some(500),
+                Some(404),
+                None,
+                Some(500),
+                Some(503),
+                None,
+                Some(500),
+            ],
+            2,
+        );
+
+        assert_eq!(
+            top_status_codes,
+            vec![
+                api::webhook_events::WebhookDigestStatusCodeCount {
+                    status_code: Some(500),
+                    count: 3,
+                },
+                api::webhook_events::WebhookDigestStatusCodeCount {
+                    status_code: None,
+                    count: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_digest_config_validation() {
+        let digest_config =
+            |hour_of_day, timezone: &str, channels| api_models::admin::WebhookDigestConfig {
+                enabled: true,
+                hour_of_day,
+                timezone: timezone.to_string(),
+                channels,
+                email_recipient: None,
+            };
+
+        assert!(validate_digest_config(&digest_config(
+            23,
+            "Europe/Berlin",
+            vec![enums::WebhookDigestChannel::Email]
+        ))
+        .is_ok());
+        assert!(validate_digest_config(&digest_config(
+            24,
+            "Europe/Berlin",
+            vec![enums::WebhookDigestChannel::Email]
+        ))
+        .is_err());
+        assert!(validate_digest_config(&digest_config(
+            9,
+            "Europe/Berlln",
+            vec![enums::WebhookDigestChannel::Webhook]
+        ))
+        .is_err());
+        assert!(validate_digest_config(&digest_config(9, "Europe/Berlin", vec![])).is_err());
+    }
+}
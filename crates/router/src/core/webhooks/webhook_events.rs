@@ -1,18 +1,33 @@
-use common_utils::{self, fp_utils};
+use api_models::pagination::{LegacyArrayListResponse, LegacyListResponse, PaginatedListResponse};
+use common_utils::{
+    self,
+    crypto::OptionalEncryptableSecretString,
+    ext_traits::Encode,
+    fp_utils, type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
 use error_stack::ResultExt;
-use masking::PeekInterface;
-use router_env::{instrument, tracing};
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+#[cfg(feature = "v1")]
+use scheduler::{types::process_data, utils as scheduler_utils};
 
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
-    routes::SessionState,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
     services::ApplicationResponse,
     types::{api, domain, storage, transformers::ForeignTryFrom},
-    utils::{OptionExt, StringExt},
+    utils::StringExt,
 };
+#[cfg(feature = "v1")]
+use crate::{types::transformers::ForeignFrom, workflows::outgoing_webhook_retry};
 
-const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
-const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: u16 = 100;
+pub(crate) const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+#[cfg(feature = "v1")]
+const WEBHOOK_DELIVERY_RETRY_POLICY_MAX_RETRIES: i64 = 100;
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -25,7 +40,7 @@ pub async fn list_initial_delivery_attempts(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
     api_constraints: api::webhook_events::EventListConstraints,
-) -> RouterResponse<api::webhook_events::TotalEventsResponse> {
+) -> RouterResponse<LegacyListResponse<api::webhook_events::EventListItemResponse>> {
     let profile_id = api_constraints.profile_id.clone();
     let constraints = api::webhook_events::EventListConstraintsInternal::foreign_try_from(
         api_constraints.clone(),
@@ -41,12 +56,24 @@ pub async fn list_initial_delivery_attempts(
         (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
 
     let events = match constraints {
-        api_models::webhook_events::EventListConstraintsInternal::ObjectIdFilter { object_id } => {
+        api_models::webhook_events::EventListConstraintsInternal::ObjectIdFilter {
+            object_id,
+            is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+        } => {
             match account {
                 MerchantAccountOrProfile::MerchantAccount(merchant_account) => store
                 .list_initial_events_by_merchant_id_primary_object_id(key_manager_state,
                    merchant_account.get_id(),
                     &object_id,
+                    is_delivered,
+                    delivery_state,
+                    event_types,
+                    event_class,
+                    delivery_status_code_class,
                     &key_store,
                 )
                 .await,
@@ -54,6 +81,11 @@ pub async fn list_initial_delivery_attempts(
                 .list_initial_events_by_profile_id_primary_object_id(key_manager_state,
                     business_profile.get_id(),
                     &object_id,
+                    is_delivered,
+                    delivery_state,
+                    event_types,
+                    event_class,
+                    delivery_status_code_class,
                     &key_store,
                 )
                 .await,
@@ -64,16 +96,22 @@ pub async fn list_initial_delivery_attempts(
             created_before,
             limit,
             offset,
-            is_delivered
+            is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+            cursor,
         } => {
+            let starting_after = cursor.as_deref().map(decode_event_list_cursor).transpose()?;
             let limit = match limit {
-                Some(limit) if  limit <= INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Ok(Some(limit)),
-                Some(limit) if limit > INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Err(
+                Some(limit) if  limit <= i64::from(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT) => Ok(Some(limit)),
+                Some(limit) if limit > i64::from(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT) => Err(
                     errors::ApiErrorResponse::InvalidRequestData{
                         message: format!("`limit` must be a number less than {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT}")
                     }
                 ),
-                _  => Ok(Some(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT)),
+                _  => Ok(Some(i64::from(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT))),
             }?;
             let offset = match offset {
                 Some(offset) if offset > 0 => Some(offset),
@@ -116,6 +154,11 @@ pub async fn list_initial_delivery_attempts(
                     limit,
                     offset,
                     is_delivered,
+                    delivery_state,
+                    event_types,
+                    event_class,
+                    delivery_status_code_class,
+                    starting_after,
                     &key_store,
                 )
                 .await,
@@ -127,6 +170,11 @@ pub async fn list_initial_delivery_attempts(
                     limit,
                     offset,
                     is_delivered,
+                    delivery_state,
+                    event_types,
+                    event_class,
+                    delivery_status_code_class,
+                    starting_after,
                     &key_store,
                 )
                 .await,
@@ -136,11 +184,24 @@ pub async fn list_initial_delivery_attempts(
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("Failed to list events with specified constraints")?;
 
-    let events = events
+    let mut events = events
         .into_iter()
         .map(api::webhook_events::EventListItemResponse::try_from)
         .collect::<Result<Vec<_>, _>>()?;
 
+    if !events.is_empty() {
+        let initial_attempt_ids = events
+            .iter()
+            .map(|event| event.initial_attempt_id.clone())
+            .collect();
+        let attempts_summaries = store
+            .summarize_events_by_merchant_id_initial_attempt_ids(&merchant_id, initial_attempt_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to summarize the delivery attempts of the events")?;
+        set_attempts_summaries(&mut events, attempts_summaries);
+    }
+
     let created_after = api_constraints
         .created_after
         .unwrap_or(events_list_begin_time);
@@ -155,22 +216,162 @@ pub async fn list_initial_delivery_attempts(
             created_after,
             created_before,
             is_delivered,
+            api_constraints.delivery_state,
+            api_constraints.event_types,
+            api_constraints.event_class,
+            api_constraints.delivery_status_code_class,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to get total events count")?;
 
+    // The events of an object are listed in full, so they are not paged by a cursor
+    let page = match api_constraints.object_id {
+        Some(_) => {
+            let limit = u16::try_from(events.len()).unwrap_or(u16::MAX);
+            PaginatedListResponse::new(events, limit)
+        }
+        None => PaginatedListResponse::new(
+            events,
+            api_constraints
+                .limit
+                .unwrap_or(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT),
+        )
+        .with_next_cursor(|event| encode_event_list_cursor(event.created, &event.event_id)),
+    };
+
     Ok(ApplicationResponse::Json(
-        api::webhook_events::TotalEventsResponse::new(total_count, events),
+        page.with_total_count(total_count)
+            .with_legacy_fields(api::webhook_events::EVENT_LIST_LEGACY_FIELDS),
     ))
 }
 
+/// Sets the number of the delivery attempts and the time of the latest attempt of each event. An
+/// event without a summary has had only its initial attempt.
+fn set_attempts_summaries(
+    events: &mut [api::webhook_events::EventListItemResponse],
+    attempts_summaries: Vec<storage::EventAttemptsSummary>,
+) {
+    let attempts_summaries = attempts_summaries
+        .into_iter()
+        .map(|summary| (summary.initial_attempt_id.clone(), summary))
+        .collect::<std::collections::HashMap<_, _>>();
+
+    for event in events {
+        let (attempt_count, last_attempted_at) = attempts_summaries
+            .get(&event.initial_attempt_id)
+            .map(|summary| {
+                (
+                    u32::try_from(summary.attempt_count).unwrap_or(u32::MAX),
+                    summary.last_attempted_at,
+                )
+            })
+            .unwrap_or((1, event.created));
+        event.attempt_count = Some(attempt_count);
+        event.last_attempted_at = Some(last_attempted_at);
+    }
+}
+
+/// Opaque cursor of the events list, made of the creation time and the id of the last event of
+/// the page
+fn encode_event_list_cursor(created_at: time::PrimitiveDateTime, event_id: &str) -> String {
+    use base64::Engine;
+
+    let created_at = created_at.assume_utc().unix_timestamp_nanos();
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(format!("{created_at}:{event_id}"))
+}
+
+/// Creation time and id of the event after which the events of the page are listed
+fn decode_event_list_cursor(
+    cursor: &str,
+) -> errors::RouterResult<(time::PrimitiveDateTime, String)> {
+    use base64::Engine;
+
+    let invalid_cursor_error = || errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("`cursor` `{cursor}` is not a valid cursor of the events list"),
+    };
+    let cursor = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|cursor| String::from_utf8(cursor).ok())
+        .ok_or_else(invalid_cursor_error)?;
+    let (created_at, event_id) = cursor
+        .split_once(':')
+        .filter(|(_, event_id)| !event_id.is_empty())
+        .ok_or_else(invalid_cursor_error)?;
+    let created_at = created_at
+        .parse::<i128>()
+        .ok()
+        .and_then(|created_at| time::OffsetDateTime::from_unix_timestamp_nanos(created_at).ok())
+        .ok_or_else(invalid_cursor_error)?;
+
+    Ok((
+        time::PrimitiveDateTime::new(created_at.date(), created_at.time()),
+        event_id.to_string(),
+    ))
+}
+
+/// Lists the delivery attempts of an event, with previews of their request and response bodies.
+/// An event of another profile, when the attempts are listed on behalf of a profile, is reported
+/// as not found. The attempts are listed as a bare array unless the envelope is requested.
 #[instrument(skip(state))]
 pub async fn list_delivery_attempts(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    initial_attempt_id: String,
+    is_envelope_requested: bool,
+) -> RouterResponse<LegacyArrayListResponse<api::webhook_events::EventDeliveryAttemptResponse>> {
+    let delivery_attempts =
+        get_delivery_attempts(state, merchant_id, profile_id, initial_attempt_id)
+            .await?
+            .into_iter()
+            .map(|attempt| {
+                api::webhook_events::EventRetrieveResponse::try_from(attempt)
+                    .map(get_delivery_attempt_preview)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+    // All the delivery attempts of the event are listed
+    let limit = u16::try_from(delivery_attempts.len()).unwrap_or(u16::MAX);
+
+    Ok(ApplicationResponse::Json(
+        PaginatedListResponse::new(delivery_attempts, limit)
+            .with_legacy_array(is_envelope_requested),
+    ))
+}
+
+/// Lists the delivery attempts of an event with their complete requests and responses, as the
+/// delivery attempts were listed before their payloads were previewed. To be removed once the
+/// consumers of the list have moved to retrieving the payloads of the attempts separately.
+#[instrument(skip(state))]
+pub async fn list_delivery_attempts_with_payloads(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    initial_attempt_id: String,
+    is_envelope_requested: bool,
+) -> RouterResponse<LegacyArrayListResponse<api::webhook_events::EventRetrieveResponse>> {
+    let delivery_attempts =
+        get_delivery_attempts(state, merchant_id, profile_id, initial_attempt_id)
+            .await?
+            .into_iter()
+            .map(api::webhook_events::EventRetrieveResponse::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+    // All the delivery attempts of the event are listed
+    let limit = u16::try_from(delivery_attempts.len()).unwrap_or(u16::MAX);
+
+    Ok(ApplicationResponse::Json(
+        PaginatedListResponse::new(delivery_attempts, limit)
+            .with_legacy_array(is_envelope_requested),
+    ))
+}
+
+async fn get_delivery_attempts(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
     initial_attempt_id: String,
-) -> RouterResponse<Vec<api::webhook_events::EventRetrieveResponse>> {
+) -> errors::RouterResult<Vec<domain::Event>> {
     let store = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
@@ -194,27 +395,69 @@ pub async fn list_delivery_attempts(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to list delivery attempts for initial event")?;
 
-    if events.is_empty() {
+    // The attempts of the events of other profiles are not disclosed, not even their existence
+    let is_of_other_profile = profile_id.as_ref().is_some_and(|profile_id| {
+        events
+            .iter()
+            .any(|event| event.business_profile_id.as_ref() != Some(profile_id))
+    });
+
+    if events.is_empty() || is_of_other_profile {
         Err(error_stack::report!(
             errors::ApiErrorResponse::EventNotFound
         ))
         .attach_printable("No delivery attempts found with the specified `initial_attempt_id`")
     } else {
-        Ok(ApplicationResponse::Json(
-            events
-                .into_iter()
-                .map(api::webhook_events::EventRetrieveResponse::try_from)
-                .collect::<Result<Vec<_>, _>>()?,
-        ))
+        Ok(events)
+    }
+}
+
+/// Number of the characters of a request or response body included in its preview
+const DELIVERY_ATTEMPT_BODY_PREVIEW_LENGTH: usize = 1024;
+
+/// Returns the beginning of the body, and whether the body is longer than it.
+fn get_body_preview(body: &str) -> (String, bool) {
+    let mut characters = body.chars();
+    let preview = characters
+        .by_ref()
+        .take(DELIVERY_ATTEMPT_BODY_PREVIEW_LENGTH)
+        .collect::<String>();
+    (preview, characters.next().is_some())
+}
+
+fn get_delivery_attempt_preview(
+    attempt: api::webhook_events::EventRetrieveResponse,
+) -> api::webhook_events::EventDeliveryAttemptResponse {
+    let (request_body_preview, is_request_body_truncated) =
+        get_body_preview(attempt.request.body.peek());
+    let (response_body_preview, is_response_body_truncated) = attempt
+        .response
+        .body
+        .as_ref()
+        .map(|body| get_body_preview(body.peek()))
+        .map_or((None, false), |(preview, is_truncated)| {
+            (Some(Secret::new(preview)), is_truncated)
+        });
+
+    api::webhook_events::EventDeliveryAttemptResponse {
+        event_information: attempt.event_information,
+        delivery_attempt: attempt.delivery_attempt,
+        status_code: attempt.response.status_code,
+        error_message: attempt.response.error_message,
+        request_body_preview: Secret::new(request_body_preview),
+        response_body_preview,
+        is_truncated: is_request_body_truncated || is_response_body_truncated,
     }
 }
 
+/// Retrieves the complete request and response of a delivery attempt of an event. An attempt
+/// which is not of the event is reported as not found.
 #[instrument(skip(state))]
-#[cfg(feature = "v1")]
-pub async fn retry_delivery_attempt(
+pub async fn retrieve_delivery_attempt_payload(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
-    event_id: String,
+    initial_attempt_id: String,
+    attempt_id: String,
 ) -> RouterResponse<api::webhook_events::EventRetrieveResponse> {
     let store = state.store.as_ref();
     let key_manager_state = &(&state).into();
@@ -228,100 +471,596 @@ pub async fn retry_delivery_attempt(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let event_to_retry = store
+    let attempt = store
         .find_event_by_merchant_id_event_id(
             key_manager_state,
-            &key_store.merchant_id,
-            &event_id,
+            &merchant_id,
+            &attempt_id,
             &key_store,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
 
-    let business_profile_id = event_to_retry
-        .business_profile_id
-        .get_required_value("business_profile_id")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to read business profile ID from event to retry")?;
-    let business_profile = store
-        .find_business_profile_by_profile_id(key_manager_state, &key_store, &business_profile_id)
+    fp_utils::when(
+        attempt.initial_attempt_id.as_deref() != Some(initial_attempt_id.as_str()),
+        || {
+            Err(error_stack::report!(
+                errors::ApiErrorResponse::EventNotFound
+            ))
+            .attach_printable("The delivery attempt is not an attempt of the specified event")
+        },
+    )?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(attempt)?,
+    ))
+}
+
+/// Retrieves an event, identified by its initial delivery attempt, along with its latest delivery
+/// attempt. An event which is not of the merchant, or not of the profile when it is retrieved on
+/// behalf of a profile, is reported as not found, without revealing that it exists.
+#[instrument(skip(state))]
+pub async fn retrieve_event(
+    state: SessionState,
+    request: api::webhook_events::EventRetrieveRequestInternal,
+) -> RouterResponse<api::webhook_events::EventDetailsResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let event = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &request.merchant_id,
+            &request.event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    // The retries of an event are retrieved as its delivery attempts, not as events
+    let is_retry = event
+        .initial_attempt_id
+        .as_ref()
+        .is_some_and(|initial_attempt_id| *initial_attempt_id != event.event_id);
+    let is_of_other_profile = request
+        .profile_id
+        .as_ref()
+        .is_some_and(|profile_id| event.business_profile_id.as_ref() != Some(profile_id));
+    fp_utils::when(is_retry || is_of_other_profile, || {
+        Err(errors::ApiErrorResponse::EventNotFound)
+    })?;
+
+    let attempts = store
+        .list_events_by_merchant_id_initial_attempt_id(
+            key_manager_state,
+            &request.merchant_id,
+            &event.event_id,
+            &key_store,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to find business profile")?;
+        .attach_printable("Failed to list delivery attempts of the event")?;
 
-    let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
-    let new_event_id = super::utils::generate_event_id();
-    let idempotent_event_id = super::utils::get_idempotent_event_id(
-        &event_to_retry.primary_object_id,
-        event_to_retry.event_type,
-        delivery_attempt,
-    );
+    Ok(ApplicationResponse::Json(get_event_details_response(
+        event,
+        &attempts,
+        request.constraints.expand,
+    )?))
+}
 
-    let now = common_utils::date_time::now();
-    let new_event = domain::Event {
-        event_id: new_event_id.clone(),
-        event_type: event_to_retry.event_type,
-        event_class: event_to_retry.event_class,
-        is_webhook_notified: false,
-        primary_object_id: event_to_retry.primary_object_id,
-        primary_object_type: event_to_retry.primary_object_type,
-        created_at: now,
-        merchant_id: Some(business_profile.merchant_id.clone()),
-        business_profile_id: Some(business_profile.get_id().to_owned()),
-        primary_object_created_at: event_to_retry.primary_object_created_at,
-        idempotent_event_id: Some(idempotent_event_id),
-        initial_attempt_id: event_to_retry.initial_attempt_id,
-        request: event_to_retry.request,
-        response: None,
-        delivery_attempt: Some(delivery_attempt),
-        metadata: event_to_retry.metadata,
-        is_overall_delivery_successful: Some(false),
+/// Builds the details of the event from its initial delivery attempt and all of its delivery
+/// attempts, latest first.
+fn get_event_details_response(
+    event: domain::Event,
+    attempts: &[domain::Event],
+    expand: Option<api::webhook_events::EventRetrieveExpand>,
+) -> errors::RouterResult<api::webhook_events::EventDetailsResponse> {
+    // Events inserted by old versions of the application are not linked to their attempts
+    let latest_attempt = attempts.first().unwrap_or(&event);
+    let latest_delivery_attempt = api::webhook_events::EventLatestDeliveryAttempt {
+        attempt_id: latest_attempt.event_id.clone(),
+        delivery_attempt: latest_attempt.delivery_attempt,
+        is_delivery_successful: latest_attempt.is_webhook_notified,
+        status_code: latest_attempt
+            .response
+            .as_ref()
+            .and_then(|response| {
+                response
+                    .peek()
+                    .parse_struct::<api::webhook_events::OutgoingWebhookResponseContent>(
+                        "OutgoingWebhookResponseContent",
+                    )
+                    .ok()
+            })
+            .and_then(|response| response.status_code),
+        created: latest_attempt.created_at,
     };
+    let delivery_attempts_count = u32::try_from(attempts.len()).unwrap_or(u32::MAX).max(1);
 
-    let event = store
-        .insert_event(key_manager_state, new_event, &key_store)
+    let (request, response) = match expand {
+        Some(api::webhook_events::EventRetrieveExpand::Payload) => {
+            let request: Option<api::webhook_events::OutgoingWebhookRequestContent> = event
+                .request
+                .as_ref()
+                .map(|request| {
+                    request
+                        .peek()
+                        .parse_struct("OutgoingWebhookRequestContent")
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to parse webhook event request information")
+                })
+                .transpose()?;
+            let response: Option<api::webhook_events::OutgoingWebhookResponseContent> = event
+                .response
+                .as_ref()
+                .map(|response| {
+                    response
+                        .peek()
+                        .parse_struct("OutgoingWebhookResponseContent")
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to parse webhook event response information")
+                })
+                .transpose()?;
+            (request, response)
+        }
+        None => (None, None),
+    };
+
+    Ok(api::webhook_events::EventDetailsResponse {
+        event_information: api::webhook_events::EventListItemResponse::try_from(event)?,
+        delivery_attempts_count,
+        latest_delivery_attempt,
+        request,
+        response,
+    })
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn retry_delivery_attempt(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    event_id: String,
+    override_url: Option<Secret<String>>,
+) -> RouterResponse<api::webhook_events::EventRetrieveResponse> {
+    let webhook_url_override = match override_url.as_ref() {
+        Some(override_url) => Some(get_webhook_url_override(override_url).await?),
+        None => None,
+    };
+
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
         .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to insert event")?;
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    // We only allow retrying deliveries for events with `request` populated.
-    let request_content = event
-        .request
+    let event_to_retry = store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            &event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    // An event of another profile is retried as if it did not exist
+    let is_of_other_profile = profile_id
         .as_ref()
-        .get_required_value("request")
-        .change_context(errors::ApiErrorResponse::InternalServerError)?
-        .peek()
-        .parse_struct("OutgoingWebhookRequestContent")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to parse webhook event request information")?;
+        .is_some_and(|profile_id| event_to_retry.business_profile_id.as_ref() != Some(profile_id));
+    fp_utils::when(is_of_other_profile, || {
+        Err(errors::ApiErrorResponse::EventNotFound)
+    })?;
 
-    Box::pin(super::outgoing::trigger_webhook_and_raise_event(
+    // The content of a scrubbed event must not be sent again, not even as a placeholder
+    fp_utils::when(event_to_retry.payload_scrubbed_at.is_some(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payload of the event has been scrubbed, it cannot be delivered again"
+                .to_string(),
+        })
+    })?;
+
+    let updated_event = super::outgoing::redeliver_event(
         state.clone(),
-        business_profile,
         &key_store,
-        event,
-        request_content,
-        delivery_attempt,
-        None,
-        None,
+        event_to_retry,
+        webhook_url_override,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
     ))
-    .await;
+}
+
+#[cfg(feature = "v1")]
+async fn get_webhook_url_override(override_url: &Secret<String>) -> errors::RouterResult<url::Url> {
+    let invalid_override_url = || {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message:
+                "`override_url` must be an HTTPS URL which does not point to an internal address"
+                    .to_string(),
+        })
+    };
+
+    let webhook_url_override =
+        url::Url::parse(override_url.peek()).map_err(|_| invalid_override_url())?;
+    // The host of the URL is resolved, the URL is refused if it resolves to an internal address
+    if super::outgoing::is_webhook_url_override_host_allowed(webhook_url_override.clone()).await {
+        Ok(webhook_url_override)
+    } else {
+        Err(invalid_override_url())
+    }
+}
+
+/// Replaces the request and response bodies of all the delivery attempts of an event with a
+/// placeholder recording when and by whom they were scrubbed. The rest of the delivery history of
+/// the event is kept as is. Scrubbing an event again scrubs only the attempts made since, with the
+/// placeholder of the first scrub.
+#[instrument(skip(state, req_state))]
+pub async fn scrub_event_payload(
+    state: SessionState,
+    req_state: ReqState,
+    request: api::webhook_events::EventPayloadScrubRequestInternal,
+    scrubbed_by: String,
+) -> RouterResponse<api::webhook_events::EventPayloadScrubResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
 
-    let updated_event = store
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let event = store
         .find_event_by_merchant_id_event_id(
             key_manager_state,
-            &key_store.merchant_id,
-            &new_event_id,
+            &request.merchant_id,
+            &request.event_id,
             &key_store,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
 
+    // The events of the other profiles of the merchant are not visible to the user
+    fp_utils::when(
+        event.business_profile_id.as_ref() != Some(&request.profile_id),
+        || Err(errors::ApiErrorResponse::EventNotFound),
+    )?;
+
+    let initial_attempt_id = event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| event.event_id.clone());
+    let attempts = store
+        .list_events_by_merchant_id_initial_attempt_id(
+            key_manager_state,
+            &request.merchant_id,
+            &initial_attempt_id,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list delivery attempts of the event to scrub")?;
+    // Events inserted by old versions of the application are not linked to their attempts
+    let attempts = if attempts.is_empty() {
+        vec![event]
+    } else {
+        attempts
+    };
+
+    let placeholder = attempts
+        .iter()
+        .find_map(get_scrubbed_event_payload)
+        .unwrap_or_else(|| api::webhook_events::ScrubbedEventPayload {
+            scrubbed_at: common_utils::date_time::now(),
+            scrubbed_by,
+        });
+    let placeholder_body = placeholder
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the scrubbed event payload")?;
+
+    let mut newly_scrubbed_attempt_ids = Vec::new();
+    for attempt in attempts
+        .iter()
+        .filter(|attempt| attempt.payload_scrubbed_at.is_none())
+    {
+        let event_update = get_payload_scrub_update(
+            key_manager_state,
+            &key_store,
+            attempt,
+            &placeholder_body,
+            placeholder.scrubbed_at,
+        )
+        .await?;
+
+        store
+            .update_event_by_merchant_id_event_id(
+                key_manager_state,
+                &request.merchant_id,
+                &attempt.event_id,
+                event_update,
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to scrub the payload of the delivery attempt")?;
+        newly_scrubbed_attempt_ids.push(attempt.event_id.clone());
+    }
+
+    if !newly_scrubbed_attempt_ids.is_empty() {
+        logger::info!(
+            event_id = %request.event_id,
+            %initial_attempt_id,
+            ?newly_scrubbed_attempt_ids,
+            "Scrubbed the payload of the event"
+        );
+        req_state
+            .event_context
+            .event(AuditEvent::new(AuditEventType::EventPayloadScrubbed {
+                merchant_id: request.merchant_id.clone(),
+                profile_id: request.profile_id.clone(),
+                initial_attempt_id: initial_attempt_id.clone(),
+                scrubbed_attempt_ids: newly_scrubbed_attempt_ids,
+                scrubbed_by: placeholder.scrubbed_by.clone(),
+            }))
+            .emit();
+    }
+
     Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+        api::webhook_events::EventPayloadScrubResponse {
+            event_id: request.event_id,
+            initial_attempt_id,
+            scrubbed_attempt_ids: attempts
+                .into_iter()
+                .map(|attempt| attempt.event_id)
+                .collect(),
+            placeholder,
+        },
     ))
 }
 
+/// The placeholder stored in place of the payload of the attempt, if the attempt was scrubbed
+fn get_scrubbed_event_payload(
+    attempt: &domain::Event,
+) -> Option<api::webhook_events::ScrubbedEventPayload> {
+    let request = attempt.payload_scrubbed_at.and(attempt.request.as_ref())?;
+    let request_content: api::webhook_events::OutgoingWebhookRequestContent = request
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .ok()?;
+    request_content
+        .body
+        .peek()
+        .parse_struct("ScrubbedEventPayload")
+        .ok()
+}
+
+/// Replaces the request and response bodies of the attempt with the placeholder, keeping the
+/// headers, status code and error details of the delivery. A payload which is absent is left
+/// absent.
+async fn get_payload_scrub_update(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    attempt: &domain::Event,
+    placeholder_body: &str,
+    scrubbed_at: time::PrimitiveDateTime,
+) -> errors::RouterResult<domain::EventUpdate> {
+    let (request, response) = get_scrubbed_payloads(attempt, placeholder_body)?;
+
+    Ok(domain::EventUpdate::PayloadScrubUpdate {
+        request: encrypt_event_payload(key_manager_state, key_store, request).await?,
+        response: encrypt_event_payload(key_manager_state, key_store, response).await?,
+        payload_scrubbed_at: scrubbed_at,
+    })
+}
+
+fn get_scrubbed_payloads(
+    attempt: &domain::Event,
+    placeholder_body: &str,
+) -> errors::RouterResult<(Option<String>, Option<String>)> {
+    let request = attempt
+        .request
+        .as_ref()
+        .map(|request| {
+            let request_content: Result<api::webhook_events::OutgoingWebhookRequestContent, _> =
+                request.peek().parse_struct("OutgoingWebhookRequestContent");
+            api::webhook_events::OutgoingWebhookRequestContent {
+                body: Secret::new(placeholder_body.to_string()),
+                headers: request_content
+                    .map(|request_content| request_content.headers)
+                    .unwrap_or_default(),
+            }
+            .encode_to_string_of_json()
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the scrubbed webhook request content")?;
+
+    let response = attempt
+        .response
+        .as_ref()
+        .map(|response| {
+            let response_content: Result<api::webhook_events::OutgoingWebhookResponseContent, _> =
+                response
+                    .peek()
+                    .parse_struct("OutgoingWebhookResponseContent");
+            let response_content =
+                response_content.unwrap_or(api::webhook_events::OutgoingWebhookResponseContent {
+                    body: None,
+                    headers: None,
+                    status_code: None,
+                    error_message: None,
+                    redirect_chain: None,
+                    failure_reason: None,
+                });
+            api::webhook_events::OutgoingWebhookResponseContent {
+                body: Some(Secret::new(placeholder_body.to_string())),
+                ..response_content
+            }
+            .encode_to_string_of_json()
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the scrubbed webhook response content")?;
+
+    Ok((request, response))
+}
+
+async fn encrypt_event_payload(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    payload: Option<String>,
+) -> errors::RouterResult<OptionalEncryptableSecretString> {
+    let Some(payload) = payload else {
+        return Ok(None);
+    };
+
+    crypto_operation(
+        key_manager_state,
+        type_name!(domain::Event),
+        CryptoOperation::Encrypt(Secret::new(payload)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Some)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the scrubbed webhook event payload")
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn preview_retry_policy(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::WebhookRetryPolicyPreviewRequest,
+) -> RouterResponse<api::webhook_events::WebhookRetryPolicyPreviewResponse> {
+    let retry_mapping = match request.policy {
+        Some(policy) => {
+            validate_retry_policy(&policy)?;
+            process_data::RetryMapping::foreign_from(policy)
+        }
+        None => {
+            outgoing_webhook_retry::get_webhook_delivery_retry_mapping(
+                state.store.as_ref(),
+                &merchant_id,
+            )
+            .await
+        }
+    };
+
+    Ok(ApplicationResponse::Json(get_retry_policy_preview(
+        &retry_mapping,
+    )))
+}
+
+#[cfg(feature = "v1")]
+fn validate_retry_policy(
+    policy: &api::webhook_events::WebhookDeliveryRetryPolicy,
+) -> errors::RouterResult<()> {
+    fp_utils::when(policy.start_after_in_secs <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`start_after_in_secs` must be a positive number".to_string(),
+        })
+    })?;
+
+    fp_utils::when(
+        policy
+            .intervals
+            .iter()
+            .any(|interval| interval.interval_in_secs <= 0 || interval.count <= 0),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`interval_in_secs` and `count` of the intervals must be positive numbers"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let retries_count = policy
+        .intervals
+        .iter()
+        .fold(1_i64, |count, interval| count + i64::from(interval.count));
+    fp_utils::when(
+        retries_count > WEBHOOK_DELIVERY_RETRY_POLICY_MAX_RETRIES,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The retry policy must not have more than {WEBHOOK_DELIVERY_RETRY_POLICY_MAX_RETRIES} retries"
+            ),
+        })
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Computes the delivery attempts produced by the retry mapping, using the same function the
+/// scheduler uses for scheduling the retries.
+#[cfg(feature = "v1")]
+fn get_retry_policy_preview(
+    retry_mapping: &process_data::RetryMapping,
+) -> api::webhook_events::WebhookRetryPolicyPreviewResponse {
+    let initial_attempt = api::webhook_events::WebhookDeliveryAttemptPreview {
+        attempt_number: 1,
+        scheduled_after_in_secs: 0,
+        earliest_after_in_secs: 0,
+        latest_after_in_secs: 0,
+    };
+
+    let attempts = std::iter::once(initial_attempt)
+        .chain(
+            scheduler_utils::get_retry_mapping_schedule_times(retry_mapping)
+                .into_iter()
+                .scan(0_i64, |scheduled_after, delay| {
+                    *scheduled_after += i64::from(delay);
+                    Some(*scheduled_after)
+                })
+                .zip(2..)
+                .map(|(scheduled_after_in_secs, attempt_number)| {
+                    // The scheduler does not apply any jitter to the schedule time of the retries
+                    api::webhook_events::WebhookDeliveryAttemptPreview {
+                        attempt_number,
+                        scheduled_after_in_secs,
+                        earliest_after_in_secs: scheduled_after_in_secs,
+                        latest_after_in_secs: scheduled_after_in_secs,
+                    }
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    api::webhook_events::WebhookRetryPolicyPreviewResponse {
+        max_attempts: attempts.last().map_or(1, |attempt| attempt.attempt_number),
+        total_horizon_in_secs: attempts
+            .last()
+            .map_or(0, |attempt| attempt.latest_after_in_secs),
+        attempts,
+    }
+}
+
 async fn get_account_and_key_store(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
@@ -384,3 +1123,748 @@ async fn get_account_and_key_store(
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::sync::Arc;
+
+    use common_utils::{crypto::Encryptable, pii::EncryptionStrategy};
+    use diesel_models::enums as storage_enums;
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+        },
+        services,
+    };
+
+    fn encryptable(data: String) -> Encryptable<Secret<String>> {
+        Encryptable::new(
+            Secret::new(data),
+            Secret::<Vec<u8>, EncryptionStrategy>::new(Vec::new()),
+        )
+    }
+
+    fn get_delivered_attempt(event_id: &str) -> domain::Event {
+        let request = api::webhook_events::OutgoingWebhookRequestContent {
+            body: Secret::new(r#"{"payment_id":"pay_123","amount":6540}"#.to_string()),
+            headers: vec![(
+                "content-type".to_string(),
+                Secret::new("application/json".to_string()),
+            )],
+        };
+        let response = api::webhook_events::OutgoingWebhookResponseContent {
+            body: Some(Secret::new(r#"{"received":"pay_123"}"#.to_string())),
+            headers: Some(vec![(
+                "x-request-id".to_string(),
+                Secret::new("req_123".to_string()),
+            )]),
+            status_code: Some(500),
+            error_message: Some("Internal Server Error".to_string()),
+            redirect_chain: None,
+            failure_reason: None,
+        };
+
+        domain::Event {
+            event_id: event_id.to_string(),
+            event_type: storage_enums::EventType::PaymentSucceeded,
+            event_class: storage_enums::EventClass::Payments,
+            is_webhook_notified: false,
+            primary_object_id: "pay_123".to_string(),
+            primary_object_type: storage_enums::EventObjectType::PaymentDetails,
+            created_at: datetime!(2025-04-01 10:00),
+            merchant_id: Some(common_utils::id_type::MerchantId::default()),
+            business_profile_id: Some(
+                common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                    "pro_scrub_test",
+                ))
+                .expect("valid profile id"),
+            ),
+            primary_object_created_at: None,
+            idempotent_event_id: Some("pay_123_succeeded".to_string()),
+            initial_attempt_id: Some("evt_initial".to_string()),
+            request: Some(encryptable(
+                request
+                    .encode_to_string_of_json()
+                    .expect("serializable request"),
+            )),
+            response: Some(encryptable(
+                response
+                    .encode_to_string_of_json()
+                    .expect("serializable response"),
+            )),
+            delivery_attempt: Some(storage_enums::WebhookDeliveryAttempt::AutomaticRetry),
+            metadata: None,
+            is_overall_delivery_successful: Some(false),
+            entity_version: None,
+            payload_scrubbed_at: None,
+            delivery_latency_ms: None,
+            latest_delivery_status_code_class: None,
+        }
+    }
+
+    fn scrub(
+        attempt: domain::Event,
+        placeholder: &api::webhook_events::ScrubbedEventPayload,
+    ) -> domain::Event {
+        let placeholder_body = placeholder
+            .encode_to_string_of_json()
+            .expect("serializable placeholder");
+        let (request, response) =
+            get_scrubbed_payloads(&attempt, &placeholder_body).expect("scrubbed payloads");
+
+        domain::Event {
+            request: request.map(encryptable),
+            response: response.map(encryptable),
+            payload_scrubbed_at: Some(placeholder.scrubbed_at),
+            ..attempt
+        }
+    }
+
+    #[test]
+    fn test_scrubbed_attempt_is_retrieved_with_placeholder_and_delivery_history() {
+        let placeholder = api::webhook_events::ScrubbedEventPayload {
+            scrubbed_at: datetime!(2025-04-11 10:15:12),
+            scrubbed_by: "usr_123".to_string(),
+        };
+        let scrubbed_attempt = scrub(get_delivered_attempt("evt_retry"), &placeholder);
+
+        let retrieved = api::webhook_events::EventRetrieveResponse::try_from(scrubbed_attempt)
+            .expect("retrievable event");
+        let request_body: api::webhook_events::ScrubbedEventPayload = retrieved
+            .request
+            .body
+            .peek()
+            .parse_struct("ScrubbedEventPayload")
+            .expect("placeholder in request body");
+        let response_body: api::webhook_events::ScrubbedEventPayload = retrieved
+            .response
+            .body
+            .as_ref()
+            .expect("response body")
+            .peek()
+            .parse_struct("ScrubbedEventPayload")
+            .expect("placeholder in response body");
+
+        assert_eq!(request_body, placeholder);
+        assert_eq!(response_body, placeholder);
+        assert_eq!(
+            retrieved
+                .request
+                .headers
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["content-type"]
+        );
+        assert_eq!(retrieved.response.status_code, Some(500));
+        assert_eq!(
+            retrieved.response.error_message.as_deref(),
+            Some("Internal Server Error")
+        );
+        assert_eq!(
+            retrieved.delivery_attempt,
+            Some(storage_enums::WebhookDeliveryAttempt::AutomaticRetry)
+        );
+        assert_eq!(
+            retrieved.event_information.initial_attempt_id,
+            "evt_initial"
+        );
+        assert_eq!(
+            retrieved.event_information.is_delivery_successful,
+            Some(false)
+        );
+        assert_eq!(
+            retrieved.event_information.payload_scrubbed_at,
+            Some(placeholder.scrubbed_at)
+        );
+    }
+
+    #[test]
+    fn test_placeholder_of_earlier_scrub_is_reused() {
+        let placeholder = api::webhook_events::ScrubbedEventPayload {
+            scrubbed_at: datetime!(2025-04-11 10:15:12),
+            scrubbed_by: "usr_123".to_string(),
+        };
+        let attempts = [
+            scrub(get_delivered_attempt("evt_initial"), &placeholder),
+            get_delivered_attempt("evt_manual_retry"),
+        ];
+
+        assert_eq!(get_scrubbed_event_payload(&attempts[1]), None);
+        assert_eq!(
+            attempts.iter().find_map(get_scrubbed_event_payload),
+            Some(placeholder)
+        );
+    }
+
+    #[test]
+    fn test_absent_payload_is_left_absent() {
+        let attempt = domain::Event {
+            response: None,
+            ..get_delivered_attempt("evt_initial")
+        };
+
+        let (request, response) = get_scrubbed_payloads(&attempt, "{}").expect("scrubbed payloads");
+
+        assert!(request.is_some());
+        assert!(response.is_none());
+    }
+
+    #[test]
+    fn test_event_details_with_latest_attempt_and_expanded_payload() {
+        let initial_attempt = domain::Event {
+            delivery_attempt: Some(storage_enums::WebhookDeliveryAttempt::InitialAttempt),
+            ..get_delivered_attempt("evt_initial")
+        };
+        let latest_attempt = domain::Event {
+            is_webhook_notified: true,
+            created_at: datetime!(2025-04-01 10:05),
+            response: None,
+            ..get_delivered_attempt("evt_retry")
+        };
+        let attempts = [latest_attempt, initial_attempt.clone()];
+
+        let details = get_event_details_response(initial_attempt.clone(), &attempts, None)
+            .expect("event details");
+
+        assert_eq!(details.event_information.event_id, "evt_initial");
+        assert_eq!(details.delivery_attempts_count, 2);
+        assert_eq!(details.latest_delivery_attempt.attempt_id, "evt_retry");
+        assert!(details.latest_delivery_attempt.is_delivery_successful);
+        assert_eq!(details.latest_delivery_attempt.status_code, None);
+        assert_eq!(
+            details.latest_delivery_attempt.created,
+            datetime!(2025-04-01 10:05)
+        );
+        assert!(details.request.is_none());
+        assert!(details.response.is_none());
+
+        let details = get_event_details_response(
+            initial_attempt.clone(),
+            &[initial_attempt],
+            Some(api::webhook_events::EventRetrieveExpand::Payload),
+        )
+        .expect("event details");
+
+        assert_eq!(details.delivery_attempts_count, 1);
+        assert_eq!(details.latest_delivery_attempt.attempt_id, "evt_initial");
+        assert_eq!(details.latest_delivery_attempt.status_code, Some(500));
+        assert_eq!(
+            details
+                .request
+                .expect("expanded request")
+                .body
+                .peek()
+                .as_str(),
+            r#"{"payment_id":"pay_123","amount":6540}"#
+        );
+        assert_eq!(
+            details.response.expect("expanded response").status_code,
+            Some(500)
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_preview_matches_scheduled_retries() {
+        let policy = api::webhook_events::WebhookDeliveryRetryPolicy {
+            start_after_in_secs: 60,
+            intervals: vec![
+                api::webhook_events::WebhookDeliveryRetryInterval {
+                    interval_in_secs: 300,
+                    count: 2,
+                },
+                api::webhook_events::WebhookDeliveryRetryInterval {
+                    interval_in_secs: 3600,
+                    count: 1,
+                },
+            ],
+        };
+        validate_retry_policy(&policy).expect("valid retry policy");
+        let retry_mapping = process_data::RetryMapping::foreign_from(policy);
+
+        let preview = get_retry_policy_preview(&retry_mapping);
+
+        // Replays the scheduling of the retries, as done by the outgoing webhook retry workflow
+        let mut scheduled_after_in_secs = 0;
+        let mut scheduled_attempts = vec![0];
+        let mut retry_count = 0;
+        while let Some(delay) =
+            scheduler_utils::get_retry_mapping_schedule_time(&retry_mapping, retry_count)
+        {
+            scheduled_after_in_secs += i64::from(delay);
+            scheduled_attempts.push(scheduled_after_in_secs);
+            retry_count += 1;
+        }
+
+        assert_eq!(
+            preview
+                .attempts
+                .iter()
+                .map(|attempt| attempt.scheduled_after_in_secs)
+                .collect::<Vec<_>>(),
+            scheduled_attempts
+        );
+        assert_eq!(scheduled_attempts, vec![0, 60, 360, 660, 4260]);
+        assert_eq!(preview.max_attempts, 5);
+        assert_eq!(preview.total_horizon_in_secs, 4260);
+    }
+
+    #[test]
+    fn test_retry_policy_with_non_positive_interval_is_rejected() {
+        let policy = api::webhook_events::WebhookDeliveryRetryPolicy {
+            start_after_in_secs: 60,
+            intervals: vec![api::webhook_events::WebhookDeliveryRetryInterval {
+                interval_in_secs: 0,
+                count: 2,
+            }],
+        };
+
+        assert!(validate_retry_policy(&policy).is_err());
+    }
+
+    #[test]
+    fn test_retry_policy_with_too_many_retries_is_rejected() {
+        let policy = api::webhook_events::WebhookDeliveryRetryPolicy {
+            start_after_in_secs: 60,
+            intervals: vec![api::webhook_events::WebhookDeliveryRetryInterval {
+                interval_in_secs: 60,
+                count: 500,
+            }],
+        };
+
+        assert!(validate_retry_policy(&policy).is_err());
+    }
+
+    #[test]
+    fn test_event_list_cursor_round_trips() {
+        let created_at = datetime!(2025-04-01 10:00:00.123456);
+        let cursor = encode_event_list_cursor(created_at, "evt_018e31720d1b7a2b");
+
+        let (decoded_created_at, decoded_event_id) =
+            decode_event_list_cursor(&cursor).expect("valid cursor");
+        assert_eq!(decoded_created_at, created_at);
+        assert_eq!(decoded_event_id, "evt_018e31720d1b7a2b");
+
+        assert!(decode_event_list_cursor("not a cursor").is_err());
+        assert!(decode_event_list_cursor(&encode_event_list_cursor(created_at, "")).is_err());
+    }
+
+    #[test]
+    fn test_attempts_summaries_are_set_on_listed_events() {
+        let mut events = ["evt_retried", "evt_initial_only"]
+            .into_iter()
+            .map(|event_id| {
+                api::webhook_events::EventListItemResponse::try_from(domain::Event {
+                    initial_attempt_id: Some(event_id.to_string()),
+                    ..get_delivered_attempt(event_id)
+                })
+                .expect("event with all required fields")
+            })
+            .collect::<Vec<_>>();
+        let last_attempted_at = datetime!(2025-04-01 11:00);
+
+        set_attempts_summaries(
+            &mut events,
+            vec![storage::EventAttemptsSummary {
+                initial_attempt_id: "evt_retried".to_string(),
+                attempt_count: 3,
+                last_attempted_at,
+            }],
+        );
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| (event.attempt_count, event.last_attempted_at))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some(3), Some(last_attempted_at)),
+                (Some(1), Some(datetime!(2025-04-01 10:00))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_delivery_attempt_bodies_are_previewed() {
+        let get_attempt = || {
+            api::webhook_events::EventRetrieveResponse::try_from(get_delivered_attempt(
+                "evt_initial",
+            ))
+            .expect("delivery attempt with all required fields")
+        };
+
+        let preview = get_delivery_attempt_preview(get_attempt());
+
+        assert_eq!(preview.event_information.event_id, "evt_initial");
+        assert_eq!(preview.status_code, Some(500));
+        assert_eq!(
+            preview.request_body_preview.peek().as_str(),
+            r#"{"payment_id":"pay_123","amount":6540}"#
+        );
+        assert_eq!(
+            preview
+                .response_body_preview
+                .as_ref()
+                .map(|body| body.peek().as_str()),
+            Some(r#"{"received":"pay_123"}"#)
+        );
+        assert!(!preview.is_truncated);
+
+        let mut attempt = get_attempt();
+        attempt.response.body = Some(Secret::new(
+            "é".repeat(DELIVERY_ATTEMPT_BODY_PREVIEW_LENGTH + 1),
+        ));
+        let preview = get_delivery_attempt_preview(attempt);
+
+        assert_eq!(
+            preview
+                .response_body_preview
+                .map(|body| body.peek().chars().count()),
+            Some(DELIVERY_ATTEMPT_BODY_PREVIEW_LENGTH)
+        );
+        assert!(preview.is_truncated);
+    }
+
+    async fn get_mock_session_state() -> SessionState {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+
+        Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string())
+                    .expect("valid tenant id"),
+                None,
+                || {},
+            )
+            .expect("session state of the public tenant")
+    }
+
+    async fn insert_key_store(
+        state: &SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> domain::MerchantKeyStore {
+        let store = state.store.as_ref();
+        let key_manager_state = &state.into();
+        let master_key = store.get_master_key();
+        let key = crypto_operation(
+            key_manager_state,
+            type_name!(domain::MerchantKeyStore),
+            CryptoOperation::Encrypt(
+                services::generate_aes256_key()
+                    .expect("generated key")
+                    .to_vec()
+                    .into(),
+            ),
+            Identifier::Merchant(merchant_id.to_owned()),
+            master_key,
+        )
+        .await
+        .and_then(|key| key.try_into_operation())
+        .expect("encrypted key");
+
+        store
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.to_owned(),
+                    key,
+                    created_at: common_utils::date_time::now(),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .expect("inserted key store")
+    }
+
+    async fn insert_initial_attempt(
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+        event: domain::Event,
+    ) -> domain::Event {
+        state
+            .store
+            .insert_event(&state.into(), event, key_store)
+            .await
+            .expect("inserted event")
+    }
+
+    fn get_initial_attempt(
+        event_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> domain::Event {
+        domain::Event {
+            event_id: event_id.to_string(),
+            merchant_id: Some(merchant_id.to_owned()),
+            business_profile_id: Some(profile_id.to_owned()),
+            created_at: common_utils::date_time::now(),
+            initial_attempt_id: Some(event_id.to_string()),
+            idempotent_event_id: Some(format!("{event_id}_idempotent")),
+            delivery_attempt: Some(storage_enums::WebhookDeliveryAttempt::InitialAttempt),
+            request: None,
+            response: None,
+            ..get_delivered_attempt(event_id)
+        }
+    }
+
+    fn get_profile_id(profile_id: &'static str) -> common_utils::id_type::ProfileId {
+        common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(profile_id))
+            .expect("valid profile id")
+    }
+
+    fn get_merchant_id(merchant_id: &'static str) -> common_utils::id_type::MerchantId {
+        common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(merchant_id))
+            .expect("valid merchant id")
+    }
+
+    #[tokio::test]
+    async fn test_events_of_other_profiles_and_merchants_are_not_found() {
+        let state = get_mock_session_state().await;
+        let merchant_id = get_merchant_id("merchant_events_owner");
+        let other_merchant_id = get_merchant_id("merchant_events_other");
+        let profile_id = get_profile_id("pro_events_owner");
+        let other_profile_id = get_profile_id("pro_events_other");
+
+        let key_store = insert_key_store(&state, &merchant_id).await;
+        insert_key_store(&state, &other_merchant_id).await;
+        insert_initial_attempt(
+            &state,
+            &key_store,
+            get_initial_attempt("evt_profile_owner", &merchant_id, &profile_id),
+        )
+        .await;
+
+        let is_event_not_found = |error: &error_stack::Report<errors::ApiErrorResponse>| {
+            matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::EventNotFound
+            )
+        };
+
+        // Another profile of the same merchant
+        let error = list_delivery_attempts(
+            state.clone(),
+            merchant_id.clone(),
+            Some(other_profile_id.clone()),
+            "evt_profile_owner".to_string(),
+            false,
+        )
+        .await
+        .expect_err("attempts of an event of another profile");
+        assert!(is_event_not_found(&error));
+
+        let error = retry_delivery_attempt(
+            state.clone(),
+            merchant_id.clone(),
+            Some(other_profile_id.clone()),
+            "evt_profile_owner".to_string(),
+            None,
+        )
+        .await
+        .expect_err("retry of an event of another profile");
+        assert!(is_event_not_found(&error));
+
+        // Another merchant, with or without a profile
+        for profile_id in [None, Some(profile_id.clone())] {
+            let error = list_delivery_attempts(
+                state.clone(),
+                other_merchant_id.clone(),
+                profile_id.clone(),
+                "evt_profile_owner".to_string(),
+                false,
+            )
+            .await
+            .expect_err("attempts of an event of another merchant");
+            assert!(is_event_not_found(&error));
+
+            let error = retry_delivery_attempt(
+                state.clone(),
+                other_merchant_id.clone(),
+                profile_id,
+                "evt_profile_owner".to_string(),
+                None,
+            )
+            .await
+            .expect_err("retry of an event of another merchant");
+            assert!(is_event_not_found(&error));
+        }
+
+        // The event exists, and was not retried by any of the refused requests
+        let attempts = state
+            .store
+            .list_events_by_merchant_id_initial_attempt_id(
+                &(&state).into(),
+                &merchant_id,
+                "evt_profile_owner",
+                &key_store,
+            )
+            .await
+            .expect("attempts of the event");
+        assert_eq!(attempts.len(), 1);
+    }
+
+    async fn insert_merchant_account(
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+    ) -> domain::MerchantAccount {
+        let now = common_utils::date_time::now();
+        let merchant_account = domain::MerchantAccount::from(domain::MerchantAccountSetter {
+            merchant_id: key_store.merchant_id.clone(),
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: "pk_events_test".to_string(),
+            storage_scheme: storage_enums::MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            primary_business_details: serde_json::json!([]),
+            frm_routing_algorithm: None,
+            created_at: now,
+            modified_at: now,
+            intent_fulfillment_time: None,
+            payout_routing_algorithm: None,
+            organization_id: common_utils::id_type::OrganizationId::try_from_string(
+                "org_events_test".to_string(),
+            )
+            .expect("valid organization id"),
+            is_recon_enabled: false,
+            default_profile: None,
+            recon_status: storage_enums::ReconStatus::NotRequested,
+            payment_link_config: None,
+            pm_collect_link_config: None,
+            version: common_enums::ApiVersion::V1,
+            is_platform_account: false,
+            product_type: None,
+        });
+
+        state
+            .store
+            .insert_merchant(&state.into(), merchant_account, key_store)
+            .await
+            .expect("inserted merchant account")
+    }
+
+    #[tokio::test]
+    async fn test_initial_attempts_are_listed_by_status_code_class_event_type_and_time_range() {
+        let state = get_mock_session_state().await;
+        let merchant_id = get_merchant_id("merchant_events_filters");
+        let profile_id = get_profile_id("pro_events_filters");
+        let key_store = insert_key_store(&state, &merchant_id).await;
+        insert_merchant_account(&state, &key_store).await;
+
+        let now = common_utils::date_time::now();
+        let events = [
+            (
+                "evt_succeeded_4xx",
+                storage_enums::EventType::PaymentSucceeded,
+                Some(storage_enums::WebhookDeliveryStatusCodeClass::ClientError),
+                now - time::Duration::hours(1),
+            ),
+            (
+                "evt_failed_4xx",
+                storage_enums::EventType::PaymentFailed,
+                Some(storage_enums::WebhookDeliveryStatusCodeClass::ClientError),
+                now - time::Duration::minutes(90),
+            ),
+            // Of another status code class
+            (
+                "evt_succeeded_5xx",
+                storage_enums::EventType::PaymentSucceeded,
+                Some(storage_enums::WebhookDeliveryStatusCodeClass::ServerError),
+                now - time::Duration::hours(1),
+            ),
+            // Never answered by the endpoint
+            (
+                "evt_succeeded_unanswered",
+                storage_enums::EventType::PaymentSucceeded,
+                None,
+                now - time::Duration::hours(1),
+            ),
+            // Of another event type
+            (
+                "evt_cancelled_4xx",
+                storage_enums::EventType::PaymentCancelled,
+                Some(storage_enums::WebhookDeliveryStatusCodeClass::ClientError),
+                now - time::Duration::hours(1),
+            ),
+            // Created before the time range
+            (
+                "evt_succeeded_4xx_earlier",
+                storage_enums::EventType::PaymentSucceeded,
+                Some(storage_enums::WebhookDeliveryStatusCodeClass::ClientError),
+                now - time::Duration::hours(3),
+            ),
+        ];
+        for (event_id, event_type, delivery_status_code_class, created_at) in events {
+            insert_initial_attempt(
+                &state,
+                &key_store,
+                domain::Event {
+                    event_type,
+                    latest_delivery_status_code_class: delivery_status_code_class,
+                    created_at,
+                    ..get_initial_attempt(event_id, &merchant_id, &profile_id)
+                },
+            )
+            .await;
+        }
+
+        let response = list_initial_delivery_attempts(
+            state,
+            merchant_id,
+            api::webhook_events::EventListConstraints {
+                created_after: Some(now - time::Duration::hours(2)),
+                created_before: Some(now),
+                limit: None,
+                offset: None,
+                cursor: None,
+                object_id: None,
+                profile_id: None,
+                is_delivered: Some(false),
+                delivery_state: None,
+                event_types: Some(vec![
+                    storage_enums::EventType::PaymentSucceeded,
+                    storage_enums::EventType::PaymentFailed,
+                ]),
+                event_class: None,
+                delivery_status_code_class: Some(
+                    storage_enums::WebhookDeliveryStatusCodeClass::ClientError,
+                ),
+            },
+        )
+        .await
+        .expect("listed initial attempts");
+
+        let page = match response {
+            ApplicationResponse::Json(response) => Some(response.page),
+            _ => None,
+        }
+        .expect("initial attempts listed as json");
+        assert_eq!(
+            page.items
+                .iter()
+                .map(|event| event.event_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["evt_succeeded_4xx", "evt_failed_4xx"]
+        );
+    }
+}
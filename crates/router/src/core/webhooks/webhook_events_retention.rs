@@ -0,0 +1,895 @@
+//! Retention of the webhook events of a merchant.
+//!
+//! A process tracker task is scheduled per merchant, which deletes the initial events created
+//! before the retention period along with their retry attempts, a batch at a time, and moves
+//! itself to the next day. When the events archive is enabled, the undelivered events of a batch
+//! are first written to the archive storage as an NDJSON object, each line an event along with the
+//! summary of its delivery attempts. The object is read back and its checksum verified, and a
+//! reference to it is recorded in the archive ledger before the batch is deleted. A batch which
+//! could not be archived is not deleted, and is archived again on the next run.
+
+use std::collections::HashMap;
+
+use api_models::pagination::PaginatedListResponse;
+use common_utils::crypto::{self, GenerateDigest};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use external_services::file_storage::FileStorageInterface;
+use router_env::{instrument, tracing};
+
+use super::{
+    event_export::{encode_ndjson, get_export_record},
+    webhook_events::INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS,
+};
+use crate::{
+    configs::settings::WebhookEventRetentionSettings,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    logger,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage, storage::enums},
+};
+
+const WEBHOOK_EVENTS_RETENTION_NAME: &str = "WEBHOOK_EVENTS_RETENTION";
+const WEBHOOK_EVENTS_RETENTION_TAG: &str = "WEBHOOK_EVENTS_RETENTION";
+const WEBHOOK_EVENTS_RETENTION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::WebhookEventsRetentionWorkflow;
+/// Events are retained for as long as they can be listed
+const WEBHOOK_EVENTS_RETENTION_DAYS: i64 = INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS;
+const EVENTS_ARCHIVE_LIST_DEFAULT_LIMIT: u16 = 20;
+const EVENTS_ARCHIVE_LIST_MAX_LIMIT: u16 = 100;
+
+/// Storage to which the undelivered events are archived before they are deleted
+pub(crate) struct EventsArchiveSink<'a> {
+    /// Prefix of the keys of the archive objects
+    pub prefix: &'a str,
+    pub client: &'a dyn FileStorageInterface,
+}
+
+impl<'a> EventsArchiveSink<'a> {
+    /// The configured events archive, if archiving is enabled
+    pub(crate) fn from_state(state: &'a SessionState) -> Option<Self> {
+        let events_archive = state.conf.events_archive.get_inner();
+
+        events_archive.enabled.then(|| Self {
+            prefix: &events_archive.prefix,
+            client: state.events_archive_client.as_ref(),
+        })
+    }
+}
+
+/// A line of an archive object, an undelivered event along with the summary of its delivery
+/// attempts
+#[derive(Debug, serde::Serialize)]
+struct EventArchiveRecord {
+    #[serde(flatten)]
+    event: api::webhook_events::EventExportRecord,
+    /// Number of the delivery attempts of the event, including the initial attempt
+    attempt_count: i64,
+    /// Time of the latest delivery attempt of the event
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    last_attempted_at: Option<time::PrimitiveDateTime>,
+}
+
+fn generate_task_id_for_events_retention(
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    format!(
+        "{WEBHOOK_EVENTS_RETENTION_RUNNER}_{WEBHOOK_EVENTS_RETENTION_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_events_archive_list_limit(limit: Option<u16>) -> RouterResult<u16> {
+    match limit {
+        None => Ok(EVENTS_ARCHIVE_LIST_DEFAULT_LIMIT),
+        Some(limit) if (1..=EVENTS_ARCHIVE_LIST_MAX_LIMIT).contains(&limit) => Ok(limit),
+        Some(_) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`limit` must be a number between 1 and {EVENTS_ARCHIVE_LIST_MAX_LIMIT}"
+            ),
+        })),
+    }
+}
+
+/// Events created before the returned time are past the retention period
+fn get_retention_cutoff(now: time::PrimitiveDateTime) -> time::PrimitiveDateTime {
+    (now.date() - time::Duration::days(WEBHOOK_EVENTS_RETENTION_DAYS)).midnight()
+}
+
+fn get_archive_object_key(
+    prefix: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    archive_id: &str,
+) -> String {
+    format!(
+        "{prefix}/{}/{archive_id}.ndjson",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_archive_checksum(archive: &[u8]) -> RouterResult<String> {
+    crypto::Sha256
+        .generate_digest(archive)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the checksum of the events archive")
+}
+
+/// Schedules the retention task of the merchant to run now. A task which is already scheduled is
+/// left as is, so that scheduling the task again does not run it twice.
+#[instrument(skip(state))]
+pub async fn schedule_events_retention(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+) -> RouterResponse<api::webhook_events::EventsRetentionScheduleResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let current_time = common_utils::date_time::now();
+    let process_tracker_id = generate_task_id_for_events_retention(&merchant_id);
+
+    let existing_process = store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the webhook events retention task")?;
+
+    let scheduled_at = match existing_process {
+        Some(process) if process.status != enums::ProcessTrackerStatus::Finish => {
+            process.schedule_time
+        }
+        Some(process) => {
+            let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(current_time),
+                tracking_data: None,
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            store
+                .update_process(process, process_tracker_update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule the webhook events retention task")?
+                .schedule_time
+        }
+        None => {
+            let tracking_data = storage::WebhookEventsRetentionTrackingData {
+                merchant_id: merchant_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                WEBHOOK_EVENTS_RETENTION_NAME,
+                WEBHOOK_EVENTS_RETENTION_RUNNER,
+                [WEBHOOK_EVENTS_RETENTION_TAG],
+                tracking_data,
+                None,
+                current_time,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the webhook events retention task")?;
+
+            store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the webhook events retention task")?
+                .schedule_time
+        }
+    };
+
+    logger::info!(?scheduled_at, "Scheduled the webhook events retention task");
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventsRetentionScheduleResponse {
+            merchant_id,
+            retention_days: WEBHOOK_EVENTS_RETENTION_DAYS,
+            is_archive_enabled: state.conf.events_archive.get_inner().enabled,
+            scheduled_at,
+        },
+    ))
+}
+
+/// Lists the archives of the events of the merchant recorded in the ledger, latest first.
+#[instrument(skip(state))]
+pub async fn list_events_archives(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    constraints: api::webhook_events::EventsArchiveListConstraints,
+) -> RouterResponse<PaginatedListResponse<api::webhook_events::EventsArchiveResponse>> {
+    let store = state.store.as_ref();
+
+    let limit = get_events_archive_list_limit(constraints.limit)?;
+    let offset = constraints.offset.unwrap_or(0);
+
+    let (events_archives, total_count) = futures::try_join!(
+        store.list_events_archives_by_merchant_id(
+            &merchant_id,
+            i64::from(limit),
+            i64::from(offset)
+        ),
+        store.count_events_archives_by_merchant_id(&merchant_id),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the events archives of the merchant")?;
+
+    let events_archives = events_archives
+        .into_iter()
+        .map(|archive| api::webhook_events::EventsArchiveResponse {
+            archive_id: archive.id,
+            merchant_id: archive.merchant_id,
+            object_key: archive.object_key,
+            checksum: archive.checksum,
+            events_count: archive.events_count,
+            oldest_event_created_at: archive.oldest_event_created_at,
+            newest_event_created_at: archive.newest_event_created_at,
+            created_at: archive.created_at,
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        PaginatedListResponse::new(events_archives, limit).with_total_count(total_count),
+    ))
+}
+
+/// Moves the retention task to the same time on the next day before the run, so that the task
+/// keeps running daily even if a run fails.
+#[instrument(skip_all)]
+pub(crate) async fn reschedule_events_retention_task(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+) -> RouterResult<()> {
+    let current_time = common_utils::date_time::now();
+    let schedule_time = process
+        .schedule_time
+        .unwrap_or(current_time)
+        .max(current_time)
+        .saturating_add(time::Duration::days(1));
+
+    let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: Some(0),
+        schedule_time: Some(schedule_time),
+        tracking_data: None,
+        business_status: Some(String::from(business_status::PENDING)),
+        status: Some(enums::ProcessTrackerStatus::New),
+        updated_at: Some(current_time),
+    };
+
+    state
+        .store
+        .update_process(process, process_tracker_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule the webhook events retention task")?;
+
+    Ok(())
+}
+
+/// Deletes the initial events of the merchant created before the retention period along with
+/// their retry attempts, the latest first, at most the configured number of batches. The
+/// undelivered events of a batch are archived to the sink, if any, before the batch is deleted.
+/// Returns the number of the initial events deleted.
+#[instrument(skip_all)]
+pub(crate) async fn delete_expired_events(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    archive_sink: Option<&EventsArchiveSink<'_>>,
+    retention_settings: &WebhookEventRetentionSettings,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<usize> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let created_after = time::PrimitiveDateTime::new(
+        time::OffsetDateTime::UNIX_EPOCH.date(),
+        time::Time::MIDNIGHT,
+    );
+    let created_before = get_retention_cutoff(now);
+    let mut deleted_count = 0;
+
+    for _ in 0..retention_settings.max_batches_per_run {
+        let events = store
+            .list_initial_events_by_merchant_id_constraints(
+                key_manager_state,
+                merchant_id,
+                created_after,
+                created_before,
+                Some(retention_settings.batch_size),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the expired events of the merchant")?;
+
+        if events.is_empty() {
+            break;
+        }
+
+        let batch_size = events.len();
+        let initial_attempt_ids = events
+            .iter()
+            .map(|event| event.event_id.clone())
+            .collect::<Vec<_>>();
+        let undelivered_events = events
+            .into_iter()
+            .filter(|event| event.is_overall_delivery_successful != Some(true))
+            .collect::<Vec<_>>();
+
+        if let Some(archive_sink) = archive_sink.filter(|_| !undelivered_events.is_empty()) {
+            // The batch is not deleted unless its undelivered events are archived
+            archive_events(state, archive_sink, merchant_id, undelivered_events, now).await?;
+        }
+
+        store
+            .delete_events_by_merchant_id_initial_attempt_ids(merchant_id, initial_attempt_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the expired events of the merchant")?;
+
+        deleted_count += batch_size;
+
+        if i64::try_from(batch_size).unwrap_or(i64::MAX) < retention_settings.batch_size {
+            break;
+        }
+    }
+
+    logger::info!(deleted_count, "Deleted the expired webhook events");
+
+    Ok(deleted_count)
+}
+
+/// Writes the events to the archive storage as an NDJSON object, verifies the checksum of the
+/// object read back from the storage and records the archive in the ledger.
+#[instrument(skip_all)]
+async fn archive_events(
+    state: &SessionState,
+    archive_sink: &EventsArchiveSink<'_>,
+    merchant_id: &common_utils::id_type::MerchantId,
+    events: Vec<domain::Event>,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<storage::EventsArchive> {
+    let store = state.store.as_ref();
+
+    let (Some(oldest_event_created_at), Some(newest_event_created_at)) = (
+        events.iter().map(|event| event.created_at).min(),
+        events.iter().map(|event| event.created_at).max(),
+    ) else {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Cannot archive an empty batch of events");
+    };
+    let events_count = i32::try_from(events.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Too many events in the batch to be archived")?;
+
+    let attempts_summaries = store
+        .summarize_events_by_merchant_id_initial_attempt_ids(
+            merchant_id,
+            events.iter().map(|event| event.event_id.clone()).collect(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to summarize the delivery attempts of the events to archive")?
+        .into_iter()
+        .map(|summary| (summary.initial_attempt_id.clone(), summary))
+        .collect::<HashMap<_, _>>();
+
+    let records = events
+        .into_iter()
+        .map(|event| {
+            let attempts_summary = attempts_summaries.get(&event.event_id);
+            Ok(EventArchiveRecord {
+                attempt_count: attempts_summary.map_or(1, |summary| summary.attempt_count),
+                last_attempted_at: attempts_summary.map(|summary| summary.last_attempted_at),
+                event: get_export_record(event, true)?,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+    let archive = encode_ndjson(&records)?;
+    let checksum = get_archive_checksum(&archive)?;
+
+    let archive_id = common_utils::generate_id(consts::ID_LENGTH, "evar");
+    let object_key = get_archive_object_key(archive_sink.prefix, merchant_id, &archive_id);
+
+    archive_sink
+        .client
+        .upload_file(&object_key, archive)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the events archive")?;
+
+    let written_archive = archive_sink
+        .client
+        .retrieve_file(&object_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read back the events archive")?;
+    let written_checksum = get_archive_checksum(&written_archive)?;
+
+    if written_checksum != checksum {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+            format!(
+                "Checksum of the events archive `{object_key}` read back does not match the checksum of the archive written"
+            ),
+        );
+    }
+
+    let events_archive = store
+        .insert_events_archive(storage::EventsArchive {
+            id: archive_id,
+            merchant_id: merchant_id.to_owned(),
+            object_key,
+            checksum,
+            events_count,
+            oldest_event_created_at,
+            newest_event_created_at,
+            created_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the events archive in the ledger")?;
+
+    logger::info!(
+        archive_id = %events_archive.id,
+        events_count,
+        "Archived the undelivered webhook events"
+    );
+
+    Ok(events_archive)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::sync::Arc;
+
+    use common_utils::{errors::CustomResult, type_name, types::keymanager::Identifier};
+    use diesel_models::enums as storage_enums;
+    use external_services::file_storage::{FileStorageConfig, FileStorageError};
+    use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+        },
+        services,
+    };
+
+    const RETENTION_SETTINGS: WebhookEventRetentionSettings = WebhookEventRetentionSettings {
+        batch_size: 2,
+        max_batches_per_run: 10,
+    };
+
+    /// Archive storage to which no archive can be written
+    #[derive(Clone)]
+    struct FailingStorage;
+
+    #[async_trait::async_trait]
+    impl FileStorageInterface for FailingStorage {
+        async fn upload_file(
+            &self,
+            _file_key: &str,
+            _file: Vec<u8>,
+        ) -> CustomResult<(), FileStorageError> {
+            Err(report!(FileStorageError::UploadFailed))
+        }
+
+        async fn delete_file(&self, _file_key: &str) -> CustomResult<(), FileStorageError> {
+            Ok(())
+        }
+
+        async fn retrieve_file(&self, _file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+            Err(report!(FileStorageError::RetrieveFailed))
+        }
+    }
+
+    /// Archive storage which reads back an object other than the one written
+    #[derive(Clone)]
+    struct CorruptingStorage;
+
+    #[async_trait::async_trait]
+    impl FileStorageInterface for CorruptingStorage {
+        async fn upload_file(
+            &self,
+            _file_key: &str,
+            _file: Vec<u8>,
+        ) -> CustomResult<(), FileStorageError> {
+            Ok(())
+        }
+
+        async fn delete_file(&self, _file_key: &str) -> CustomResult<(), FileStorageError> {
+            Ok(())
+        }
+
+        async fn retrieve_file(&self, _file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+            Ok(b"{}\n".to_vec())
+        }
+    }
+
+    async fn get_mock_session_state() -> SessionState {
+        let (tx, _) = tokio::sync::oneshot::channel();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            Settings::default(),
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+
+        Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string())
+                    .expect("valid tenant id"),
+                None,
+                || {},
+            )
+            .expect("session state of the public tenant")
+    }
+
+    async fn insert_key_store(
+        state: &SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> domain::MerchantKeyStore {
+        let store = state.store.as_ref();
+        let key_manager_state = &state.into();
+        let master_key = store.get_master_key();
+        let key = crypto_operation(
+            key_manager_state,
+            type_name!(domain::MerchantKeyStore),
+            CryptoOperation::Encrypt(
+                services::generate_aes256_key()
+                    .expect("generated key")
+                    .to_vec()
+                    .into(),
+            ),
+            Identifier::Merchant(merchant_id.to_owned()),
+            master_key,
+        )
+        .await
+        .and_then(|key| key.try_into_operation())
+        .expect("encrypted key");
+
+        store
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.to_owned(),
+                    key,
+                    created_at: common_utils::date_time::now(),
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .expect("inserted key store")
+    }
+
+    fn get_event(
+        event_id: &str,
+        initial_attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_at: time::PrimitiveDateTime,
+        is_delivered: bool,
+    ) -> domain::Event {
+        domain::Event {
+            event_id: event_id.to_string(),
+            event_type: storage_enums::EventType::PaymentSucceeded,
+            event_class: storage_enums::EventClass::Payments,
+            is_webhook_notified: is_delivered,
+            primary_object_id: "pay_123".to_string(),
+            primary_object_type: storage_enums::EventObjectType::PaymentDetails,
+            created_at,
+            merchant_id: Some(merchant_id.to_owned()),
+            business_profile_id: Some(
+                common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                    "pro_retention_test",
+                ))
+                .expect("valid profile id"),
+            ),
+            primary_object_created_at: None,
+            idempotent_event_id: Some(format!("{event_id}_idempotent")),
+            initial_attempt_id: Some(initial_attempt_id.to_string()),
+            request: None,
+            response: None,
+            delivery_attempt: Some(if event_id == initial_attempt_id {
+                storage_enums::WebhookDeliveryAttempt::InitialAttempt
+            } else {
+                storage_enums::WebhookDeliveryAttempt::AutomaticRetry
+            }),
+            metadata: None,
+            is_overall_delivery_successful: Some(is_delivered),
+            entity_version: None,
+            payload_scrubbed_at: None,
+            delivery_latency_ms: None,
+            latest_delivery_status_code_class: None,
+        }
+    }
+
+    /// Inserts the events of the merchant, three of which are past the retention period. Of
+    /// those, `evt_undelivered_retried` has a retry attempt and `evt_delivered` was delivered.
+    async fn insert_events(
+        state: &SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        now: time::PrimitiveDateTime,
+    ) -> domain::MerchantKeyStore {
+        let key_store = insert_key_store(state, merchant_id).await;
+        let days_ago = |days| now - time::Duration::days(days);
+
+        for event in [
+            get_event("evt_recent", "evt_recent", merchant_id, days_ago(10), false),
+            get_event(
+                "evt_undelivered",
+                "evt_undelivered",
+                merchant_id,
+                days_ago(100),
+                false,
+            ),
+            get_event(
+                "evt_delivered",
+                "evt_delivered",
+                merchant_id,
+                days_ago(101),
+                true,
+            ),
+            get_event(
+                "evt_undelivered_retried",
+                "evt_undelivered_retried",
+                merchant_id,
+                days_ago(102),
+                false,
+            ),
+            get_event(
+                "evt_undelivered_retry",
+                "evt_undelivered_retried",
+                merchant_id,
+                days_ago(102) + time::Duration::hours(1),
+                false,
+            ),
+        ] {
+            state
+                .store
+                .insert_event(&state.into(), event, &key_store)
+                .await
+                .expect("inserted event");
+        }
+
+        key_store
+    }
+
+    async fn get_remaining_event_ids(
+        state: &SessionState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
+    ) -> Vec<&'static str> {
+        let mut remaining_event_ids = Vec::new();
+        for event_id in [
+            "evt_recent",
+            "evt_undelivered",
+            "evt_delivered",
+            "evt_undelivered_retried",
+            "evt_undelivered_retry",
+        ] {
+            if state
+                .store
+                .find_event_by_merchant_id_event_id(&state.into(), merchant_id, event_id, key_store)
+                .await
+                .is_ok()
+            {
+                remaining_event_ids.push(event_id);
+            }
+        }
+        remaining_event_ids
+    }
+
+    #[tokio::test]
+    async fn test_events_are_not_deleted_when_the_archive_cannot_be_written() {
+        let state = get_mock_session_state().await;
+        let merchant_id = common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+            "merchant_retention_upload_failure",
+        ))
+        .expect("valid merchant id");
+        let now = common_utils::date_time::now();
+        let key_store = insert_events(&state, &merchant_id, now).await;
+
+        for client in [
+            Arc::new(FailingStorage) as Arc<dyn FileStorageInterface>,
+            Arc::new(CorruptingStorage),
+        ] {
+            let archive_sink = EventsArchiveSink {
+                prefix: "events_archive",
+                client: client.as_ref(),
+            };
+
+            delete_expired_events(
+                &state,
+                &merchant_id,
+                Some(&archive_sink),
+                &RETENTION_SETTINGS,
+                now,
+            )
+            .await
+            .expect_err("expired events are not deleted without the archive");
+
+            assert_eq!(
+                get_remaining_event_ids(&state, &merchant_id, &key_store).await,
+                vec![
+                    "evt_recent",
+                    "evt_undelivered",
+                    "evt_delivered",
+                    "evt_undelivered_retried",
+                    "evt_undelivered_retry",
+                ]
+            );
+            assert_eq!(
+                state
+                    .store
+                    .count_events_archives_by_merchant_id(&merchant_id)
+                    .await
+                    .expect("events archives count"),
+                0
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_undelivered_events_are_archived_before_they_are_deleted() {
+        let state = get_mock_session_state().await;
+        let merchant_id = common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+            "merchant_retention_archived",
+        ))
+        .expect("valid merchant id");
+        let now = common_utils::date_time::now();
+        let key_store = insert_events(&state, &merchant_id, now).await;
+        let client = FileStorageConfig::FileSystem
+            .get_file_storage_client()
+            .await;
+        let archive_sink = EventsArchiveSink {
+            prefix: "events_archive_test",
+            client: client.as_ref(),
+        };
+
+        let deleted_count = delete_expired_events(
+            &state,
+            &merchant_id,
+            Some(&archive_sink),
+            &RETENTION_SETTINGS,
+            now,
+        )
+        .await
+        .expect("expired events deleted");
+
+        assert_eq!(deleted_count, 3);
+        assert_eq!(
+            get_remaining_event_ids(&state, &merchant_id, &key_store).await,
+            vec!["evt_recent"]
+        );
+
+        // A batch of two events, of which one is undelivered, and a batch of one event
+        let events_archives = state
+            .store
+            .list_events_archives_by_merchant_id(&merchant_id, 10, 0)
+            .await
+            .expect("events archives");
+        assert_eq!(events_archives.len(), 2);
+
+        let mut archived_events = Vec::new();
+        for events_archive in events_archives {
+            let archive = client
+                .retrieve_file(&events_archive.object_key)
+                .await
+                .expect("written archive");
+            client
+                .delete_file(&events_archive.object_key)
+                .await
+                .expect("deleted archive");
+
+            assert!(events_archive.object_key.starts_with(&format!(
+                "events_archive_test/{}/",
+                merchant_id.get_string_repr()
+            )));
+            assert_eq!(
+                get_archive_checksum(&archive).expect("checksum"),
+                events_archive.checksum
+            );
+            assert_eq!(events_archive.events_count, 1);
+            assert_eq!(
+                events_archive.oldest_event_created_at,
+                events_archive.newest_event_created_at
+            );
+
+            for line in archive
+                .split(|byte| *byte == b'\n')
+                .filter(|line| !line.is_empty())
+            {
+                let record: serde_json::Value =
+                    serde_json::from_slice(line).expect("archived event");
+                archived_events.push((
+                    record["event_id"].as_str().map(ToOwned::to_owned),
+                    record["attempt_count"].as_i64(),
+                ));
+            }
+        }
+        archived_events.sort();
+
+        assert_eq!(
+            archived_events,
+            vec![
+                (Some("evt_undelivered".to_string()), Some(1)),
+                (Some("evt_undelivered_retried".to_string()), Some(2)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_events_are_deleted_without_the_archive() {
+        let state = get_mock_session_state().await;
+        let merchant_id = common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+            "merchant_retention_unarchived",
+        ))
+        .expect("valid merchant id");
+        let now = common_utils::date_time::now();
+        let key_store = insert_events(&state, &merchant_id, now).await;
+
+        let deleted_count =
+            delete_expired_events(&state, &merchant_id, None, &RETENTION_SETTINGS, now)
+                .await
+                .expect("expired events deleted");
+
+        assert_eq!(deleted_count, 3);
+        assert_eq!(
+            get_remaining_event_ids(&state, &merchant_id, &key_store).await,
+            vec!["evt_recent"]
+        );
+        assert_eq!(
+            state
+                .store
+                .count_events_archives_by_merchant_id(&merchant_id)
+                .await
+                .expect("events archives count"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_events_archive_list_limit() {
+        assert_eq!(
+            get_events_archive_list_limit(None).expect("default limit"),
+            EVENTS_ARCHIVE_LIST_DEFAULT_LIMIT
+        );
+        assert_eq!(get_events_archive_list_limit(Some(50)).expect("limit"), 50);
+        assert!(get_events_archive_list_limit(Some(0)).is_err());
+        assert!(get_events_archive_list_limit(Some(EVENTS_ARCHIVE_LIST_MAX_LIMIT + 1)).is_err());
+    }
+}
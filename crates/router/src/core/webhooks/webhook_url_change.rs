@@ -0,0 +1,458 @@
+use common_utils::{crypto, fp_utils, request::RequestContent};
+use diesel_models::business_profile::{PendingWebhookUrlChange, WebhookDetails};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    logger,
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{api, domain, storage::enums},
+    utils::OptionExt,
+};
+
+/// Time within which a webhook URL change must be confirmed, after which it expires
+const WEBHOOK_URL_CHANGE_EXPIRY_SECS: i64 = 24 * 60 * 60;
+const WEBHOOK_URL_VERIFICATION_TOKEN_LENGTH: usize = 32;
+const WEBHOOK_URL_VERIFICATION_TIMEOUT_SECS: u64 = 5;
+
+/// Constructs the webhook details to be stored on updating the profile.
+///
+/// If URL change confirmation is enabled and the webhook URL is being changed, the existing URL
+/// is retained and the new URL is stored as a pending change until it is confirmed. The event
+/// subscription of the profile is retained as well, since it is not part of the profile update.
+pub(crate) fn get_webhook_details_for_update(
+    current_webhook_details: Option<&WebhookDetails>,
+    requested_webhook_details: Option<WebhookDetails>,
+    now: time::PrimitiveDateTime,
+) -> Option<WebhookDetails> {
+    let mut webhook_details = requested_webhook_details?;
+    let Some(current_webhook_details) = current_webhook_details else {
+        return Some(webhook_details);
+    };
+
+    webhook_details
+        .enabled_event_types
+        .clone_from(&current_webhook_details.enabled_event_types);
+
+    let is_url_change_confirmation_enabled = webhook_details
+        .url_change_confirmation_enabled
+        .or(current_webhook_details.url_change_confirmation_enabled)
+        .unwrap_or(false);
+
+    webhook_details.pending_url_change = match (
+        current_webhook_details.webhook_url.as_ref(),
+        webhook_details.webhook_url.clone(),
+    ) {
+        (Some(current_url), Some(requested_url)) if current_url.peek() == requested_url.peek() => {
+            current_webhook_details.pending_url_change.clone()
+        }
+        (Some(current_url), Some(requested_url)) if is_url_change_confirmation_enabled => {
+            // Webhooks continue to be delivered to the existing URL until the change is confirmed
+            webhook_details.webhook_url = Some(current_url.clone());
+            Some(PendingWebhookUrlChange {
+                webhook_url: requested_url,
+                verification_token: Secret::new(
+                    crypto::generate_cryptographically_secure_random_string(
+                        WEBHOOK_URL_VERIFICATION_TOKEN_LENGTH,
+                    ),
+                ),
+                status: enums::WebhookUrlChangeStatus::Pending,
+                requested_at: now,
+                expires_at: now
+                    .saturating_add(time::Duration::seconds(WEBHOOK_URL_CHANGE_EXPIRY_SECS)),
+            })
+        }
+        _ => None,
+    };
+
+    Some(webhook_details)
+}
+
+/// Sends the verification event to the new webhook URL, if the profile update started a new
+/// webhook URL change.
+#[instrument(skip_all)]
+pub(crate) async fn trigger_webhook_url_verification(
+    state: &SessionState,
+    previous_webhook_details: Option<&WebhookDetails>,
+    business_profile: &domain::Profile,
+) {
+    let Some(pending_url_change) = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.pending_url_change.as_ref())
+        .filter(|pending_url_change| {
+            pending_url_change.status == enums::WebhookUrlChangeStatus::Pending
+        })
+    else {
+        return;
+    };
+
+    let is_new_url_change = previous_webhook_details
+        .and_then(|webhook_details| webhook_details.pending_url_change.as_ref())
+        .map_or(true, |previous_url_change| {
+            previous_url_change.verification_token.peek()
+                != pending_url_change.verification_token.peek()
+        });
+
+    if !is_new_url_change {
+        return;
+    }
+
+    let verification = api::webhook_events::OutgoingWebhookUrlVerification {
+        merchant_id: business_profile.merchant_id.clone(),
+        profile_id: business_profile.get_id().to_owned(),
+        event_type: enums::EventType::WebhookUrlVerification,
+        verification_token: pending_url_change.verification_token.clone(),
+        expires_at: pending_url_change.expires_at,
+        timestamp: common_utils::date_time::now(),
+    };
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(pending_url_change.webhook_url.peek())
+        .attach_default_headers()
+        .set_body(RequestContent::Json(Box::new(verification)))
+        .build();
+
+    // The verification event is not retried, updating the webhook URL again sends a new event
+    match state
+        .api_client
+        .send_request(
+            state,
+            request,
+            Some(WEBHOOK_URL_VERIFICATION_TIMEOUT_SECS),
+            false,
+        )
+        .await
+    {
+        Ok(response) if response.status().is_success() => {
+            logger::info!("Webhook URL verification event delivered successfully");
+        }
+        Ok(response) => {
+            logger::warn!(
+                status_code = ?response.status(),
+                "Webhook URL verification event was not accepted by the new webhook URL"
+            );
+        }
+        Err(error) => {
+            logger::warn!(?error, "Failed to deliver webhook URL verification event");
+        }
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn confirm_webhook_url_change(
+    state: SessionState,
+    request: api::webhook_events::WebhookUrlChangeConfirmRequestInternal,
+) -> RouterResponse<api::webhook_events::WebhookUrlChangeConfirmResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let business_profile = store
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &request.merchant_id,
+            &request.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: request.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let mut webhook_details = business_profile.webhook_details.clone().ok_or(report!(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No pending webhook URL change found for the profile".to_string(),
+        }
+    ))?;
+
+    let status = apply_url_change_confirmation(
+        &mut webhook_details,
+        request.verification_token.as_ref(),
+        request.force_confirm,
+        common_utils::date_time::now(),
+    )?;
+
+    let updated_business_profile = store
+        .update_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            business_profile,
+            domain::ProfileUpdate::WebhookDetailsUpdate { webhook_details },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: request.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    fp_utils::when(status == enums::WebhookUrlChangeStatus::Expired, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The webhook URL change has expired, update the webhook URL again to \
+                      receive a new verification token"
+                .to_string(),
+        }))
+    })?;
+
+    let webhook_url = updated_business_profile
+        .webhook_details
+        .and_then(|webhook_details| webhook_details.webhook_url)
+        .get_required_value("webhook_url")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Webhook URL not found after confirming the webhook URL change")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookUrlChangeConfirmResponse {
+            merchant_id: updated_business_profile.merchant_id,
+            profile_id: request.profile_id,
+            webhook_url,
+            status,
+        },
+    ))
+}
+
+/// Moves the pending webhook URL change to its next state.
+///
+/// A pending change is confirmed when the verification token matches, and marked as expired if
+/// it was not confirmed before its expiry. A force confirmation skips the token verification and
+/// confirms the change even if it has expired.
+fn apply_url_change_confirmation(
+    webhook_details: &mut WebhookDetails,
+    verification_token: Option<&Secret<String>>,
+    force_confirm: bool,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<enums::WebhookUrlChangeStatus> {
+    let pending_url_change = webhook_details.pending_url_change.as_mut().ok_or(report!(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No pending webhook URL change found for the profile".to_string(),
+        }
+    ))?;
+
+    match pending_url_change.get_status(now) {
+        enums::WebhookUrlChangeStatus::Confirmed => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The webhook URL change has already been confirmed".to_string(),
+            }))
+        }
+        enums::WebhookUrlChangeStatus::Expired if !force_confirm => {
+            pending_url_change.status = enums::WebhookUrlChangeStatus::Expired;
+            Ok(enums::WebhookUrlChangeStatus::Expired)
+        }
+        enums::WebhookUrlChangeStatus::Pending | enums::WebhookUrlChangeStatus::Expired => {
+            let is_token_valid = verification_token.is_some_and(|verification_token| {
+                verification_token.peek() == pending_url_change.verification_token.peek()
+            });
+
+            fp_utils::when(!force_confirm && !is_token_valid, || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Invalid verification token".to_string(),
+                }))
+            })?;
+
+            pending_url_change.status = enums::WebhookUrlChangeStatus::Confirmed;
+            webhook_details.webhook_url = Some(pending_url_change.webhook_url.clone());
+            Ok(enums::WebhookUrlChangeStatus::Confirmed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const CURRENT_URL: &str = "https://merchant.example.com/webhooks";
+    const NEW_URL: &str = "https://merchant.example.com/v2/webhooks";
+
+    fn webhook_details(
+        webhook_url: &str,
+        url_change_confirmation_enabled: Option<bool>,
+    ) -> WebhookDetails {
+        WebhookDetails {
+            webhook_version: None,
+            webhook_username: None,
+            webhook_password: None,
+            webhook_url: Some(Secret::new(webhook_url.to_string())),
+            payment_created_enabled: None,
+            payment_succeeded_enabled: None,
+            payment_failed_enabled: None,
+            url_change_confirmation_enabled,
+            pending_url_change: None,
+            redirect_policy: None,
+            digest_config: None,
+            secondary_notification_config: None,
+            enabled_event_types: None,
+        }
+    }
+
+    fn webhook_details_with_pending_change(now: time::PrimitiveDateTime) -> WebhookDetails {
+        get_webhook_details_for_update(
+            Some(&webhook_details(CURRENT_URL, Some(true))),
+            Some(webhook_details(NEW_URL, None)),
+            now,
+        )
+        .unwrap()
+    }
+
+    fn get_verification_token(webhook_details: &WebhookDetails) -> Secret<String> {
+        webhook_details
+            .pending_url_change
+            .as_ref()
+            .unwrap()
+            .verification_token
+            .clone()
+    }
+
+    #[test]
+    fn test_url_change_without_confirmation_takes_effect_immediately() {
+        let updated_webhook_details = get_webhook_details_for_update(
+            Some(&webhook_details(CURRENT_URL, None)),
+            Some(webhook_details(NEW_URL, None)),
+            common_utils::date_time::now(),
+        )
+        .unwrap();
+
+        assert_eq!(updated_webhook_details.webhook_url.unwrap().peek(), NEW_URL);
+        assert!(updated_webhook_details.pending_url_change.is_none());
+    }
+
+    #[test]
+    fn test_url_change_with_confirmation_retains_current_url() {
+        let now = common_utils::date_time::now();
+        let updated_webhook_details = webhook_details_with_pending_change(now);
+        let pending_url_change = updated_webhook_details.pending_url_change.unwrap();
+
+        assert_eq!(
+            updated_webhook_details.webhook_url.unwrap().peek(),
+            CURRENT_URL
+        );
+        assert_eq!(pending_url_change.webhook_url.peek(), NEW_URL);
+        assert_eq!(
+            pending_url_change.get_status(now),
+            enums::WebhookUrlChangeStatus::Pending
+        );
+    }
+
+    #[test]
+    fn test_event_subscription_is_retained_on_update() {
+        let current_webhook_details = WebhookDetails {
+            enabled_event_types: Some(vec![enums::EventType::PaymentSucceeded]),
+            ..webhook_details(CURRENT_URL, None)
+        };
+
+        let updated_webhook_details = get_webhook_details_for_update(
+            Some(&current_webhook_details),
+            Some(webhook_details(NEW_URL, None)),
+            common_utils::date_time::now(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            updated_webhook_details.enabled_event_types,
+            Some(vec![enums::EventType::PaymentSucceeded])
+        );
+        assert!(updated_webhook_details.is_subscribed_to(enums::EventType::PaymentSucceeded));
+        assert!(!updated_webhook_details.is_subscribed_to(enums::EventType::RefundSucceeded));
+    }
+
+    #[test]
+    fn test_confirm_url_change_with_valid_token() {
+        let now = common_utils::date_time::now();
+        let mut webhook_details = webhook_details_with_pending_change(now);
+        let verification_token = get_verification_token(&webhook_details);
+
+        let status = apply_url_change_confirmation(
+            &mut webhook_details,
+            Some(&verification_token),
+            false,
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(status, enums::WebhookUrlChangeStatus::Confirmed);
+        assert_eq!(webhook_details.webhook_url.unwrap().peek(), NEW_URL);
+    }
+
+    #[test]
+    fn test_confirm_url_change_with_invalid_token() {
+        let now = common_utils::date_time::now();
+        let mut webhook_details = webhook_details_with_pending_change(now);
+
+        let result = apply_url_change_confirmation(
+            &mut webhook_details,
+            Some(&Secret::new("invalid_token".to_string())),
+            false,
+            now,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(webhook_details.webhook_url.unwrap().peek(), CURRENT_URL);
+    }
+
+    #[test]
+    fn test_confirm_url_change_after_expiry() {
+        let now = common_utils::date_time::now();
+        let mut webhook_details = webhook_details_with_pending_change(now);
+        let verification_token = get_verification_token(&webhook_details);
+        let after_expiry =
+            now.saturating_add(time::Duration::seconds(WEBHOOK_URL_CHANGE_EXPIRY_SECS + 1));
+
+        let status = apply_url_change_confirmation(
+            &mut webhook_details,
+            Some(&verification_token),
+            false,
+            after_expiry,
+        )
+        .unwrap();
+
+        assert_eq!(status, enums::WebhookUrlChangeStatus::Expired);
+        assert_eq!(webhook_details.webhook_url.unwrap().peek(), CURRENT_URL);
+        assert_eq!(
+            webhook_details.pending_url_change.unwrap().status,
+            enums::WebhookUrlChangeStatus::Expired
+        );
+    }
+
+    #[test]
+    fn test_force_confirm_url_change_after_expiry() {
+        let now = common_utils::date_time::now();
+        let mut webhook_details = webhook_details_with_pending_change(now);
+        let after_expiry =
+            now.saturating_add(time::Duration::seconds(WEBHOOK_URL_CHANGE_EXPIRY_SECS + 1));
+
+        let status =
+            apply_url_change_confirmation(&mut webhook_details, None, true, after_expiry).unwrap();
+
+        assert_eq!(status, enums::WebhookUrlChangeStatus::Confirmed);
+        assert_eq!(webhook_details.webhook_url.unwrap().peek(), NEW_URL);
+    }
+
+    #[test]
+    fn test_confirm_url_change_twice() {
+        let now = common_utils::date_time::now();
+        let mut webhook_details = webhook_details_with_pending_change(now);
+        let verification_token = get_verification_token(&webhook_details);
+
+        apply_url_change_confirmation(&mut webhook_details, Some(&verification_token), false, now)
+            .unwrap();
+        let result = apply_url_change_confirmation(
+            &mut webhook_details,
+            Some(&verification_token),
+            false,
+            now,
+        );
+
+        assert!(result.is_err());
+    }
+}
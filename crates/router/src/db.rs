@@ -16,6 +16,7 @@ pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod ephemeral_key;
 pub mod events;
+pub mod events_archive;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
@@ -32,6 +33,8 @@ pub mod payment_link;
 pub mod payment_method_session;
 pub mod refund;
 pub mod relay;
+pub mod revenue_recovery_collection_item;
+pub mod revenue_recovery_decision;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
@@ -102,6 +105,7 @@ pub trait StorageInterface:
     + ephemeral_key::EphemeralKeyInterface
     + ephemeral_key::ClientSecretInterface
     + events::EventInterface
+    + events_archive::EventsArchiveInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
@@ -137,6 +141,8 @@ pub trait StorageInterface:
     + authentication::AuthenticationInterface
     + generic_link::GenericLinkInterface
     + relay::RelayInterface
+    + revenue_recovery_collection_item::RevenueRecoveryCollectionItemInterface
+    + revenue_recovery_decision::RevenueRecoveryDecisionInterface
     + user::theme::ThemeInterface
     + payment_method_session::PaymentMethodsSessionInterface
     + 'static
@@ -200,6 +200,7 @@ impl DisputeInterface for MockDb {
             dispute_amount: dispute.dispute_amount,
             organization_id: dispute.organization_id,
             dispute_currency: dispute.dispute_currency,
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -392,6 +393,7 @@ impl DisputeInterface for MockDb {
                 dispute_to_update.dispute_stage = dispute_stage;
                 dispute_to_update.dispute_status = dispute_status;
                 dispute_to_update.connector_status = connector_status;
+                dispute_to_update.entity_version += 1;
             }
             storage::DisputeUpdate::StatusUpdate {
                 dispute_status,
@@ -401,6 +403,7 @@ impl DisputeInterface for MockDb {
                     dispute_to_update.connector_status = status;
                 }
                 dispute_to_update.dispute_status = dispute_status;
+                dispute_to_update.entity_version += 1;
             }
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
@@ -924,6 +927,69 @@ mod tests {
                 assert_ne!(created_dispute.modified_at, updated_dispute.modified_at);
                 assert_eq!(created_dispute.connector, updated_dispute.connector);
                 assert_eq!(created_dispute.evidence, updated_dispute.evidence);
+                assert_eq!(
+                    created_dispute.entity_version + 1,
+                    updated_dispute.entity_version
+                );
+            }
+
+            #[tokio::test]
+            async fn test_concurrent_status_updates_have_distinct_entity_versions() {
+                let merchant_id =
+                    common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+
+                let payment_id =
+                    common_utils::id_type::PaymentId::try_from(Cow::Borrowed("payment_1")).unwrap();
+
+                let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+                    .await
+                    .expect("Failed to create Mock store");
+
+                let created_dispute = mockdb
+                    .insert_dispute(create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }))
+                    .await
+                    .unwrap();
+
+                assert_eq!(
+                    created_dispute.entity_version,
+                    common_utils::consts::INITIAL_ENTITY_VERSION
+                );
+
+                let (first_update, second_update) = tokio::join!(
+                    mockdb.update_dispute(
+                        created_dispute.clone(),
+                        DisputeUpdate::StatusUpdate {
+                            dispute_status: DisputeStatus::DisputeChallenged,
+                            connector_status: None,
+                        },
+                    ),
+                    mockdb.update_dispute(
+                        created_dispute.clone(),
+                        DisputeUpdate::StatusUpdate {
+                            dispute_status: DisputeStatus::DisputeWon,
+                            connector_status: None,
+                        },
+                    )
+                );
+
+                let mut versions = [
+                    first_update.unwrap().entity_version,
+                    second_update.unwrap().entity_version,
+                ];
+                versions.sort_unstable();
+                assert_eq!(versions, [2, 3]);
+
+                let found_dispute = mockdb
+                    .find_dispute_by_merchant_id_dispute_id(&merchant_id, "dispute_1")
+                    .await
+                    .unwrap();
+                assert_eq!(found_dispute.entity_version, 3);
             }
 
             #[tokio::test]
@@ -1002,6 +1068,10 @@ mod tests {
                 assert_ne!(created_dispute.modified_at, updated_dispute.modified_at);
                 assert_eq!(created_dispute.connector, updated_dispute.connector);
                 assert_ne!(created_dispute.evidence, updated_dispute.evidence);
+                assert_eq!(
+                    created_dispute.entity_version,
+                    updated_dispute.entity_version
+                );
             }
         }
     }
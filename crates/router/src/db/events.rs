@@ -36,11 +36,17 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Event, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn list_initial_events_by_merchant_id_primary_object_id(
         &self,
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -54,6 +60,11 @@ where
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -65,11 +76,17 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn list_initial_events_by_profile_id_primary_object_id(
         &self,
         state: &KeyManagerState,
         profile_id: &common_utils::id_type::ProfileId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -83,6 +100,11 @@ where
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -95,6 +117,7 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Event, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn count_initial_events_by_constraints(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -102,7 +125,59 @@ where
         created_after: time::PrimitiveDateTime,
         created_before: time::PrimitiveDateTime,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
     ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn list_failed_delivery_attempts_by_profile_id(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    async fn count_failed_delivery_attempts_by_profile_id_event_type(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(storage::enums::EventType, i64)>, errors::StorageError>;
+
+    async fn count_initial_events_by_event_type_date_outcome(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        pending_after: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::EventDeliveryOutcomeCounts>, errors::StorageError>;
+
+    async fn get_initial_events_delivery_latency(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryLatency, errors::StorageError>;
+
+    async fn summarize_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::EventAttemptsSummary>, errors::StorageError>;
+
+    /// Deletes the given initial delivery attempts of the merchant along with all of their retry
+    /// attempts
+    async fn delete_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<bool, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -158,6 +233,11 @@ impl EventInterface for Store {
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -165,6 +245,11 @@ impl EventInterface for Store {
             &conn,
             merchant_id,
             primary_object_id,
+            is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -197,6 +282,11 @@ impl EventInterface for Store {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -208,6 +298,11 @@ impl EventInterface for Store {
             limit,
             offset,
             is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+            starting_after,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -271,6 +366,11 @@ impl EventInterface for Store {
         state: &KeyManagerState,
         profile_id: &common_utils::id_type::ProfileId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -278,6 +378,11 @@ impl EventInterface for Store {
             &conn,
             profile_id,
             primary_object_id,
+            is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -310,6 +415,11 @@ impl EventInterface for Store {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -321,6 +431,11 @@ impl EventInterface for Store {
             limit,
             offset,
             is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+            starting_after,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
@@ -374,6 +489,10 @@ impl EventInterface for Store {
         created_after: time::PrimitiveDateTime,
         created_before: time::PrimitiveDateTime,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
     ) -> CustomResult<i64, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
         storage::Event::count_initial_attempts_by_constraints(
@@ -383,12 +502,174 @@ impl EventInterface for Store {
             created_after,
             created_before,
             is_delivered,
+            delivery_state,
+            event_types,
+            event_class,
+            delivery_status_code_class,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_failed_delivery_attempts_by_profile_id(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_failed_delivery_attempts_by_profile_id(
+            &conn,
+            profile_id,
+            created_after,
+            created_before,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn count_failed_delivery_attempts_by_profile_id_event_type(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(storage::enums::EventType, i64)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::count_failed_delivery_attempts_by_profile_id_event_type(
+            &conn,
+            profile_id,
+            created_after,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_initial_events_by_event_type_date_outcome(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        pending_after: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::EventDeliveryOutcomeCounts>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::count_initial_attempts_by_event_type_date_outcome(
+            &conn,
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+            pending_after,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_initial_events_delivery_latency(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryLatency, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::get_initial_attempts_delivery_latency(
+            &conn,
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn summarize_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::EventAttemptsSummary>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::summarize_attempts_by_merchant_id_initial_attempt_ids(
+            &conn,
+            merchant_id,
+            initial_attempt_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Event::delete_by_merchant_id_initial_attempt_ids(
+            &conn,
+            merchant_id,
+            initial_attempt_ids,
         )
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 }
 
+/// Whether the event is in the delivery state, considering an event which has neither a response
+/// nor the outcome of a retry recorded as not sent yet
+fn is_of_delivery_state(
+    event: &storage::Event,
+    delivery_state: Option<storage::enums::EventDeliveryState>,
+) -> bool {
+    let is_sent = event.response.is_some() || event.latest_delivery_status_code_class.is_some();
+    match delivery_state {
+        Some(storage::enums::EventDeliveryState::Delivered) => {
+            event.is_overall_delivery_successful == Some(true)
+        }
+        Some(storage::enums::EventDeliveryState::Failed) => {
+            event.is_overall_delivery_successful == Some(false) && is_sent
+        }
+        Some(storage::enums::EventDeliveryState::PendingFirstAttempt) => {
+            event.is_overall_delivery_successful == Some(false) && !is_sent
+        }
+        None => true,
+    }
+}
+
+/// Whether the event was not sent, since the profile is not subscribed to its event type
+fn is_skipped_not_subscribed(event: &storage::Event) -> bool {
+    event.latest_delivery_status_code_class
+        == Some(storage::enums::WebhookDeliveryStatusCodeClass::SkippedNotSubscribed)
+}
+
 #[async_trait::async_trait]
 impl EventInterface for MockDb {
     async fn insert_event(
@@ -454,6 +735,11 @@ impl EventInterface for MockDb {
         state: &KeyManagerState,
         merchant_id: &common_utils::id_type::MerchantId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -463,6 +749,17 @@ impl EventInterface for MockDb {
                 event.merchant_id == Some(merchant_id.to_owned())
                     && event.initial_attempt_id.as_ref() == Some(&event.event_id)
                     && event.primary_object_id == primary_object_id
+                    && is_delivered.map_or(true, |is_delivered| {
+                        event.is_overall_delivery_successful == Some(is_delivered)
+                    })
+                    && event_types
+                        .as_ref()
+                        .map_or(true, |event_types| event_types.contains(&event.event_type))
+                    && event_class.map_or(true, |event_class| event.event_class == event_class)
+                    && delivery_status_code_class.map_or(true, |delivery_status_code_class| {
+                        event.latest_delivery_status_code_class == Some(delivery_status_code_class)
+                    })
+                    && is_of_delivery_state(event, delivery_state)
             })
             .cloned()
             .collect::<Vec<_>>();
@@ -493,6 +790,11 @@ impl EventInterface for MockDb {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -501,7 +803,22 @@ impl EventInterface for MockDb {
                 && event.initial_attempt_id.as_ref() == Some(&event.event_id)
                 && (event.created_at >= created_after)
                 && (event.created_at <= created_before)
-                && (event.is_overall_delivery_successful == is_delivered);
+                && is_delivered.map_or(true, |is_delivered| {
+                    event.is_overall_delivery_successful == Some(is_delivered)
+                })
+                && event_types
+                    .as_ref()
+                    .map_or(true, |event_types| event_types.contains(&event.event_type))
+                && event_class.map_or(true, |event_class| event.event_class == event_class)
+                && delivery_status_code_class.map_or(true, |delivery_status_code_class| {
+                    event.latest_delivery_status_code_class == Some(delivery_status_code_class)
+                })
+                && is_of_delivery_state(event, delivery_state)
+                && starting_after
+                    .as_ref()
+                    .map_or(true, |(created_at, event_id)| {
+                        (&event.created_at, &event.event_id) < (created_at, event_id)
+                    });
 
             check
         });
@@ -528,7 +845,12 @@ impl EventInterface for MockDb {
             usize::MAX
         };
 
-        let events = events_iter
+        let mut events = events_iter.collect::<Vec<_>>();
+        events.sort_by(|event1, event2| {
+            (&event2.created_at, &event2.event_id).cmp(&(&event1.created_at, &event1.event_id))
+        });
+        let events = events
+            .into_iter()
             .skip(offset)
             .take(limit)
             .cloned()
@@ -588,6 +910,11 @@ impl EventInterface for MockDb {
         state: &KeyManagerState,
         profile_id: &common_utils::id_type::ProfileId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -597,6 +924,17 @@ impl EventInterface for MockDb {
                 event.business_profile_id == Some(profile_id.to_owned())
                     && event.initial_attempt_id.as_ref() == Some(&event.event_id)
                     && event.primary_object_id == primary_object_id
+                    && is_delivered.map_or(true, |is_delivered| {
+                        event.is_overall_delivery_successful == Some(is_delivered)
+                    })
+                    && event_types
+                        .as_ref()
+                        .map_or(true, |event_types| event_types.contains(&event.event_type))
+                    && event_class.map_or(true, |event_class| event.event_class == event_class)
+                    && delivery_status_code_class.map_or(true, |delivery_status_code_class| {
+                        event.latest_delivery_status_code_class == Some(delivery_status_code_class)
+                    })
+                    && is_of_delivery_state(event, delivery_state)
             })
             .cloned()
             .collect::<Vec<_>>();
@@ -627,6 +965,11 @@ impl EventInterface for MockDb {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -635,7 +978,22 @@ impl EventInterface for MockDb {
                 && event.initial_attempt_id.as_ref() == Some(&event.event_id)
                 && (event.created_at >= created_after)
                 && (event.created_at <= created_before)
-                && (event.is_overall_delivery_successful == is_delivered);
+                && is_delivered.map_or(true, |is_delivered| {
+                    event.is_overall_delivery_successful == Some(is_delivered)
+                })
+                && event_types
+                    .as_ref()
+                    .map_or(true, |event_types| event_types.contains(&event.event_type))
+                && event_class.map_or(true, |event_class| event.event_class == event_class)
+                && delivery_status_code_class.map_or(true, |delivery_status_code_class| {
+                    event.latest_delivery_status_code_class == Some(delivery_status_code_class)
+                })
+                && is_of_delivery_state(event, delivery_state)
+                && starting_after
+                    .as_ref()
+                    .map_or(true, |(created_at, event_id)| {
+                        (&event.created_at, &event.event_id) < (created_at, event_id)
+                    });
 
             check
         });
@@ -662,7 +1020,12 @@ impl EventInterface for MockDb {
             usize::MAX
         };
 
-        let events = events_iter
+        let mut events = events_iter.collect::<Vec<_>>();
+        events.sort_by(|event1, event2| {
+            (&event2.created_at, &event2.event_id).cmp(&(&event1.created_at, &event1.event_id))
+        });
+        let events = events
+            .into_iter()
             .skip(offset)
             .take(limit)
             .cloned()
@@ -704,9 +1067,11 @@ impl EventInterface for MockDb {
             domain::EventUpdate::UpdateResponse {
                 is_webhook_notified,
                 response,
+                delivery_latency_ms,
             } => {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
+                event_to_update.delivery_latency_ms = delivery_latency_ms;
             }
             domain::EventUpdate::OverallDeliveryStatusUpdate {
                 is_overall_delivery_successful,
@@ -714,6 +1079,24 @@ impl EventInterface for MockDb {
                 event_to_update.is_overall_delivery_successful =
                     Some(is_overall_delivery_successful)
             }
+            domain::EventUpdate::LatestDeliveryStatusUpdate {
+                latest_delivery_status_code_class,
+            } => {
+                event_to_update.latest_delivery_status_code_class =
+                    Some(latest_delivery_status_code_class)
+            }
+            domain::EventUpdate::PayloadScrubUpdate {
+                request,
+                response,
+                payload_scrubbed_at,
+            } => {
+                // The payloads which are absent are left as they are, as in the database
+                event_to_update.request =
+                    request.map(Into::into).or(event_to_update.request.take());
+                event_to_update.response =
+                    response.map(Into::into).or(event_to_update.response.take());
+                event_to_update.payload_scrubbed_at = Some(payload_scrubbed_at);
+            }
         }
 
         event_to_update
@@ -734,6 +1117,10 @@ impl EventInterface for MockDb {
         created_after: time::PrimitiveDateTime,
         created_before: time::PrimitiveDateTime,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
     ) -> CustomResult<i64, errors::StorageError> {
         let locked_events = self.events.lock().await;
 
@@ -743,7 +1130,17 @@ impl EventInterface for MockDb {
                 && (event.business_profile_id == profile_id)
                 && (event.created_at >= created_after)
                 && (event.created_at <= created_before)
-                && (event.is_overall_delivery_successful == is_delivered);
+                && is_delivered.map_or(true, |is_delivered| {
+                    event.is_overall_delivery_successful == Some(is_delivered)
+                })
+                && event_types
+                    .as_ref()
+                    .map_or(true, |event_types| event_types.contains(&event.event_type))
+                && event_class.map_or(true, |event_class| event.event_class == event_class)
+                && delivery_status_code_class.map_or(true, |delivery_status_code_class| {
+                    event.latest_delivery_status_code_class == Some(delivery_status_code_class)
+                })
+                && is_of_delivery_state(event, delivery_state);
 
             check
         });
@@ -754,6 +1151,234 @@ impl EventInterface for MockDb {
             .change_context(errors::StorageError::MockDbError)
             .attach_printable("Failed to convert usize to i64")
     }
+
+    async fn list_failed_delivery_attempts_by_profile_id(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let mut events = self
+            .events
+            .lock()
+            .await
+            .iter()
+            .filter(|event| {
+                event.business_profile_id.as_ref() == Some(profile_id)
+                    && !event.is_webhook_notified
+                    && !is_skipped_not_subscribed(event)
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|event1, event2| event2.created_at.cmp(&event1.created_at));
+
+        let mut domain_events = Vec::with_capacity(events.len());
+
+        for event in events
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+        {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
+    async fn count_failed_delivery_attempts_by_profile_id_event_type(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(storage::enums::EventType, i64)>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let mut counts = Vec::<(storage::enums::EventType, i64)>::new();
+        for event in locked_events.iter().filter(|event| {
+            event.business_profile_id.as_ref() == Some(profile_id)
+                && !event.is_webhook_notified
+                && !is_skipped_not_subscribed(event)
+                && (event.created_at >= created_after)
+                && (event.created_at <= created_before)
+        }) {
+            match counts
+                .iter_mut()
+                .find(|(event_type, _)| *event_type == event.event_type)
+            {
+                Some((_, count)) => *count += 1,
+                None => counts.push((event.event_type, 1)),
+            }
+        }
+
+        Ok(counts)
+    }
+
+    async fn count_initial_events_by_event_type_date_outcome(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        pending_after: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::EventDeliveryOutcomeCounts>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let mut counts = Vec::<storage::EventDeliveryOutcomeCounts>::new();
+        for event in locked_events.iter().filter(|event| {
+            event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                && (event.merchant_id == Some(merchant_id.to_owned()))
+                && profile_id.as_ref().map_or(true, |profile_id| {
+                    event.business_profile_id.as_ref() == Some(profile_id)
+                })
+                && !is_skipped_not_subscribed(event)
+                && (event.created_at >= created_after)
+                && (event.created_at <= created_before)
+        }) {
+            let date = event.created_at.date();
+            let is_of_group = |counts: &storage::EventDeliveryOutcomeCounts| {
+                counts.event_type == event.event_type && counts.date == date
+            };
+            if !counts.iter().any(is_of_group) {
+                counts.push(storage::EventDeliveryOutcomeCounts {
+                    event_type: event.event_type,
+                    date,
+                    delivered_count: 0,
+                    pending_count: 0,
+                    failed_count: 0,
+                });
+            }
+            let outcome_counts = counts
+                .iter_mut()
+                .find(|counts| is_of_group(counts))
+                .ok_or(errors::StorageError::MockDbError)?;
+
+            if event.is_overall_delivery_successful == Some(true) {
+                outcome_counts.delivered_count += 1;
+            } else if event.created_at > pending_after {
+                outcome_counts.pending_count += 1;
+            } else {
+                outcome_counts.failed_count += 1;
+            }
+        }
+
+        Ok(counts)
+    }
+
+    async fn get_initial_events_delivery_latency(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryLatency, errors::StorageError> {
+        let mut latencies = self
+            .events
+            .lock()
+            .await
+            .iter()
+            .filter(|event| {
+                event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && (event.merchant_id == Some(merchant_id.to_owned()))
+                    && profile_id.as_ref().map_or(true, |profile_id| {
+                        event.business_profile_id.as_ref() == Some(profile_id)
+                    })
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+            })
+            .filter_map(|event| event.delivery_latency_ms)
+            .collect::<Vec<_>>();
+        latencies.sort_unstable();
+
+        // Same as `PERCENTILE_CONT`, interpolating linearly between the two closest latencies
+        #[allow(clippy::as_conversions)]
+        let p95_ms = latencies.len().checked_sub(1).map(|last_index| {
+            let position = 0.95 * last_index as f64;
+            let lower = latencies
+                .get(position.floor() as usize)
+                .copied()
+                .unwrap_or_default();
+            let upper = latencies
+                .get(position.ceil() as usize)
+                .copied()
+                .unwrap_or_default();
+            lower as f64 + (upper - lower) as f64 * position.fract()
+        });
+        #[allow(clippy::as_conversions)]
+        let average_ms = (!latencies.is_empty())
+            .then(|| latencies.iter().sum::<i64>() as f64 / latencies.len() as f64);
+
+        Ok(storage::EventDeliveryLatency { average_ms, p95_ms })
+    }
+
+    async fn summarize_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::EventAttemptsSummary>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let summaries = initial_attempt_ids
+            .into_iter()
+            .filter_map(|initial_attempt_id| {
+                let attempts = locked_events
+                    .iter()
+                    .filter(|event| {
+                        event.merchant_id == Some(merchant_id.to_owned())
+                            && event.initial_attempt_id.as_ref() == Some(&initial_attempt_id)
+                    })
+                    .collect::<Vec<_>>();
+                let last_attempted_at = attempts.iter().map(|event| event.created_at).max()?;
+
+                Some(storage::EventAttemptsSummary {
+                    initial_attempt_id,
+                    attempt_count: i64::try_from(attempts.len()).unwrap_or(i64::MAX),
+                    last_attempted_at,
+                })
+            })
+            .collect();
+
+        Ok(summaries)
+    }
+
+    async fn delete_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut locked_events = self.events.lock().await;
+        let events_count = locked_events.len();
+
+        locked_events.retain(|event| {
+            event.merchant_id.as_ref() != Some(merchant_id)
+                || !event
+                    .initial_attempt_id
+                    .as_ref()
+                    .is_some_and(|initial_attempt_id| {
+                        initial_attempt_ids.contains(initial_attempt_id)
+                    })
+        });
+
+        if locked_events.len() == events_count {
+            return Err(errors::StorageError::ValueNotFound(format!(
+                "No events found for merchant_id = {merchant_id:?} and the initial attempt IDs"
+            ))
+            .into());
+        }
+
+        Ok(true)
+    }
 }
 
 #[cfg(test)]
@@ -866,6 +1491,10 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    entity_version: None,
+                    payload_scrubbed_at: None,
+                    delivery_latency_ms: None,
+                    latest_delivery_status_code_class: None,
                 },
                 &merchant_key_store,
             )
@@ -882,6 +1511,7 @@ mod tests {
                 domain::EventUpdate::UpdateResponse {
                     is_webhook_notified: true,
                     response: None,
+                    delivery_latency_ms: None,
                 },
                 &merchant_key_store,
             )
@@ -891,5 +1521,197 @@ mod tests {
         assert!(updated_event.is_webhook_notified);
         assert_eq!(updated_event.primary_object_id, payment_id);
         assert_eq!(updated_event.event_id, event_id);
+
+        let list_events = |is_delivered, event_types, event_class| {
+            mockdb.list_initial_events_by_merchant_id_primary_object_id(
+                key_manager_state,
+                &merchant_id,
+                payment_id,
+                is_delivered,
+                None,
+                event_types,
+                event_class,
+                None,
+                &merchant_key_store,
+            )
+        };
+        let events = list_events(
+            Some(false),
+            Some(vec![
+                enums::EventType::PaymentSucceeded,
+                enums::EventType::PaymentFailed,
+            ]),
+            Some(enums::EventClass::Payments),
+        )
+        .await
+        .unwrap();
+        assert_eq!(events.len(), 1);
+
+        let events = list_events(None, Some(vec![enums::EventType::RefundSucceeded]), None)
+            .await
+            .unwrap();
+        assert!(events.is_empty());
+
+        let events = list_events(
+            Some(true),
+            Some(vec![enums::EventType::PaymentSucceeded]),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(events.is_empty());
+
+        let new_event = |event_id: &str, event_type, created_at| domain::Event {
+            event_id: event_id.into(),
+            event_type,
+            event_class: enums::EventClass::Payments,
+            is_webhook_notified: false,
+            primary_object_id: event_id.into(),
+            primary_object_type: enums::EventObjectType::PaymentDetails,
+            created_at,
+            merchant_id: Some(merchant_id.to_owned()),
+            business_profile_id: Some(business_profile_id.to_owned()),
+            primary_object_created_at: None,
+            idempotent_event_id: Some(event_id.into()),
+            initial_attempt_id: Some(event_id.into()),
+            request: None,
+            response: None,
+            delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+            metadata: None,
+            is_overall_delivery_successful: Some(false),
+            entity_version: None,
+            payload_scrubbed_at: None,
+            delivery_latency_ms: None,
+            latest_delivery_status_code_class: None,
+        };
+        for (event_id, event_type, created_at, delivery_status_code_class) in [
+            (
+                "evt_payment_4xx",
+                enums::EventType::PaymentSucceeded,
+                datetime!(2025-04-10 0:00),
+                enums::WebhookDeliveryStatusCodeClass::ClientError,
+            ),
+            (
+                "evt_payment_5xx",
+                enums::EventType::PaymentSucceeded,
+                datetime!(2025-04-11 0:00),
+                enums::WebhookDeliveryStatusCodeClass::ServerError,
+            ),
+            (
+                "evt_refund_4xx",
+                enums::EventType::RefundSucceeded,
+                datetime!(2025-04-12 0:00),
+                enums::WebhookDeliveryStatusCodeClass::ClientError,
+            ),
+            (
+                "evt_payment_4xx_earlier",
+                enums::EventType::PaymentSucceeded,
+                datetime!(2025-03-01 0:00),
+                enums::WebhookDeliveryStatusCodeClass::ClientError,
+            ),
+        ] {
+            mockdb
+                .insert_event(
+                    key_manager_state,
+                    new_event(event_id, event_type, created_at),
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+            mockdb
+                .update_event_by_merchant_id_event_id(
+                    key_manager_state,
+                    &merchant_id,
+                    event_id,
+                    domain::EventUpdate::LatestDeliveryStatusUpdate {
+                        latest_delivery_status_code_class: delivery_status_code_class,
+                    },
+                    &merchant_key_store,
+                )
+                .await
+                .unwrap();
+        }
+
+        let events = mockdb
+            .list_initial_events_by_merchant_id_constraints(
+                key_manager_state,
+                &merchant_id,
+                datetime!(2025-04-01 0:00),
+                datetime!(2025-04-30 0:00),
+                None,
+                None,
+                Some(false),
+                None,
+                Some(vec![enums::EventType::PaymentSucceeded]),
+                None,
+                Some(enums::WebhookDeliveryStatusCodeClass::ClientError),
+                None,
+                &merchant_key_store,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.event_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["evt_payment_4xx"]
+        );
+
+        let count = mockdb
+            .count_initial_events_by_constraints(
+                &merchant_id,
+                Some(business_profile_id.to_owned()),
+                datetime!(2025-04-01 0:00),
+                datetime!(2025-04-30 0:00),
+                Some(false),
+                None,
+                None,
+                None,
+                Some(enums::WebhookDeliveryStatusCodeClass::ClientError),
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+
+        // The webhook of the event was never sent
+        mockdb
+            .insert_event(
+                key_manager_state,
+                new_event(
+                    "evt_payment_unsent",
+                    enums::EventType::PaymentSucceeded,
+                    datetime!(2025-04-13 0:00),
+                ),
+                &merchant_key_store,
+            )
+            .await
+            .unwrap();
+
+        let count_by_delivery_state = |delivery_state| {
+            mockdb.count_initial_events_by_constraints(
+                &merchant_id,
+                Some(business_profile_id.to_owned()),
+                datetime!(2025-04-01 0:00),
+                datetime!(2025-04-30 0:00),
+                Some(false),
+                Some(delivery_state),
+                None,
+                None,
+                None,
+            )
+        };
+        assert_eq!(
+            count_by_delivery_state(enums::EventDeliveryState::PendingFirstAttempt)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            count_by_delivery_state(enums::EventDeliveryState::Failed)
+                .await
+                .unwrap(),
+            3
+        );
     }
 }
@@ -0,0 +1,165 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait EventsArchiveInterface {
+    async fn insert_events_archive(
+        &self,
+        events_archive: storage::EventsArchive,
+    ) -> CustomResult<storage::EventsArchive, errors::StorageError>;
+
+    async fn list_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::EventsArchive>, errors::StorageError>;
+
+    async fn count_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<i64, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EventsArchiveInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_events_archive(
+        &self,
+        events_archive: storage::EventsArchive,
+    ) -> CustomResult<storage::EventsArchive, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        events_archive
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::EventsArchive>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EventsArchive::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EventsArchive::count_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl EventsArchiveInterface for MockDb {
+    async fn insert_events_archive(
+        &self,
+        events_archive: storage::EventsArchive,
+    ) -> CustomResult<storage::EventsArchive, errors::StorageError> {
+        let mut locked_events_archives = self.events_archives.lock().await;
+
+        if locked_events_archives
+            .iter()
+            .any(|archive| archive.id == events_archive.id)
+        {
+            return Err(errors::StorageError::DuplicateValue {
+                entity: "events_archive",
+                key: Some(events_archive.id),
+            }
+            .into());
+        }
+
+        locked_events_archives.push(events_archive.clone());
+
+        Ok(events_archive)
+    }
+
+    async fn list_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::EventsArchive>, errors::StorageError> {
+        let locked_events_archives = self.events_archives.lock().await;
+
+        let mut events_archives = locked_events_archives
+            .iter()
+            .filter(|archive| archive.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        events_archives.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(events_archives
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn count_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let locked_events_archives = self.events_archives.lock().await;
+        let count = locked_events_archives
+            .iter()
+            .filter(|archive| archive.merchant_id == *merchant_id)
+            .count();
+
+        Ok(i64::try_from(count).unwrap_or(i64::MAX))
+    }
+}
+
+#[async_trait::async_trait]
+impl EventsArchiveInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_events_archive(
+        &self,
+        events_archive: storage::EventsArchive,
+    ) -> CustomResult<storage::EventsArchive, errors::StorageError> {
+        self.diesel_store
+            .insert_events_archive(events_archive)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::EventsArchive>, errors::StorageError> {
+        self.diesel_store
+            .list_events_archives_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn count_events_archives_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_events_archives_by_merchant_id(merchant_id)
+            .await
+    }
+}
@@ -752,6 +752,11 @@ impl EventInterface for KafkaStore {
         state: &KeyManagerState,
         merchant_id: &id_type::MerchantId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -759,6 +764,11 @@ impl EventInterface for KafkaStore {
                 state,
                 merchant_id,
                 primary_object_id,
+                is_delivered,
+                delivery_state,
+                event_types,
+                event_class,
+                delivery_status_code_class,
                 merchant_key_store,
             )
             .await
@@ -773,6 +783,11 @@ impl EventInterface for KafkaStore {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -784,6 +799,11 @@ impl EventInterface for KafkaStore {
                 limit,
                 offset,
                 is_delivered,
+                delivery_state,
+                event_types,
+                event_class,
+                delivery_status_code_class,
+                starting_after,
                 merchant_key_store,
             )
             .await
@@ -811,6 +831,11 @@ impl EventInterface for KafkaStore {
         state: &KeyManagerState,
         profile_id: &id_type::ProfileId,
         primary_object_id: &str,
+        is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -818,6 +843,11 @@ impl EventInterface for KafkaStore {
                 state,
                 profile_id,
                 primary_object_id,
+                is_delivered,
+                delivery_state,
+                event_types,
+                event_class,
+                delivery_status_code_class,
                 merchant_key_store,
             )
             .await
@@ -832,6 +862,11 @@ impl EventInterface for KafkaStore {
         limit: Option<i64>,
         offset: Option<i64>,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
+        starting_after: Option<(PrimitiveDateTime, String)>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -843,6 +878,11 @@ impl EventInterface for KafkaStore {
                 limit,
                 offset,
                 is_delivered,
+                delivery_state,
+                event_types,
+                event_class,
+                delivery_status_code_class,
+                starting_after,
                 merchant_key_store,
             )
             .await
@@ -874,6 +914,10 @@ impl EventInterface for KafkaStore {
         created_after: PrimitiveDateTime,
         created_before: PrimitiveDateTime,
         is_delivered: Option<bool>,
+        delivery_state: Option<storage::enums::EventDeliveryState>,
+        event_types: Option<Vec<storage::enums::EventType>>,
+        event_class: Option<storage::enums::EventClass>,
+        delivery_status_code_class: Option<storage::enums::WebhookDeliveryStatusCodeClass>,
     ) -> CustomResult<i64, errors::StorageError> {
         self.diesel_store
             .count_initial_events_by_constraints(
@@ -882,9 +926,105 @@ impl EventInterface for KafkaStore {
                 created_after,
                 created_before,
                 is_delivered,
+                delivery_state,
+                event_types,
+                event_class,
+                delivery_status_code_class,
+            )
+            .await
+    }
+
+    async fn list_failed_delivery_attempts_by_profile_id(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &id_type::ProfileId,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_failed_delivery_attempts_by_profile_id(
+                state,
+                profile_id,
+                created_after,
+                created_before,
+                limit,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn count_failed_delivery_attempts_by_profile_id_event_type(
+        &self,
+        profile_id: &id_type::ProfileId,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+    ) -> CustomResult<Vec<(storage::enums::EventType, i64)>, errors::StorageError> {
+        self.diesel_store
+            .count_failed_delivery_attempts_by_profile_id_event_type(
+                profile_id,
+                created_after,
+                created_before,
+            )
+            .await
+    }
+
+    async fn count_initial_events_by_event_type_date_outcome(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<id_type::ProfileId>,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        pending_after: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::EventDeliveryOutcomeCounts>, errors::StorageError> {
+        self.diesel_store
+            .count_initial_events_by_event_type_date_outcome(
+                merchant_id,
+                profile_id,
+                created_after,
+                created_before,
+                pending_after,
             )
             .await
     }
+
+    async fn get_initial_events_delivery_latency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<id_type::ProfileId>,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryLatency, errors::StorageError> {
+        self.diesel_store
+            .get_initial_events_delivery_latency(
+                merchant_id,
+                profile_id,
+                created_after,
+                created_before,
+            )
+            .await
+    }
+
+    async fn summarize_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::EventAttemptsSummary>, errors::StorageError> {
+        self.diesel_store
+            .summarize_events_by_merchant_id_initial_attempt_ids(merchant_id, initial_attempt_ids)
+            .await
+    }
+
+    async fn delete_events_by_merchant_id_initial_attempt_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        initial_attempt_ids: Vec<String>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_events_by_merchant_id_initial_attempt_ids(merchant_id, initial_attempt_ids)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1682,6 +1822,26 @@ impl PaymentAttemptInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v2")]
+    async fn find_payment_attempts_by_profile_id_time_range(
+        &self,
+        key_manager_state: &KeyManagerState,
+        profile_id: &id_type::ProfileId,
+        time_range: &common_utils::types::TimeRange,
+        merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<storage::PaymentAttempt>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_attempts_by_profile_id_time_range(
+                key_manager_state,
+                profile_id,
+                time_range,
+                merchant_key_store,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_last_successful_attempt_by_payment_id_merchant_id(
         &self,
@@ -2045,6 +2205,87 @@ impl PaymentIntentInterface for KafkaStore {
             )
             .await
     }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_intents_by_billing_connector_subscription_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_subscription_id: &str,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: &MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<
+        Vec<hyperswitch_domain_models::payments::PaymentIntent>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .find_payment_intents_by_billing_connector_subscription_id_profile_id(
+                state,
+                billing_connector_subscription_id,
+                profile_id,
+                merchant_key_store,
+                storage_scheme,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_open_recovery_intents_by_billing_connector_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_id: &id_type::MerchantConnectorAccountId,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: &MerchantStorageScheme,
+        starting_after: Option<&id_type::GlobalPaymentId>,
+        limit: i64,
+    ) -> error_stack::Result<
+        Vec<hyperswitch_domain_models::payments::PaymentIntent>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .find_open_recovery_intents_by_billing_connector_id_profile_id(
+                state,
+                billing_connector_id,
+                profile_id,
+                merchant_key_store,
+                storage_scheme,
+                starting_after,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_intents_by_billing_connector_customer_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_customer_id: &str,
+        profile_id: &id_type::ProfileId,
+        merchant_key_store: &domain::MerchantKeyStore,
+        storage_scheme: &MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<
+        Vec<hyperswitch_domain_models::payments::PaymentIntent>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .find_payment_intents_by_billing_connector_customer_id_profile_id(
+                state,
+                billing_connector_customer_id,
+                profile_id,
+                merchant_key_store,
+                storage_scheme,
+                limit,
+                offset,
+            )
+            .await
+    }
     #[cfg(all(feature = "olap", feature = "v2"))]
     async fn get_filtered_active_attempt_ids_for_total_count(
         &self,
@@ -2628,6 +2869,83 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_pending_processes_by_tag_runner(
+        &self,
+        tags: Vec<String>,
+        runner: storage::ProcessTrackerRunner,
+        time_lower_limit: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_pending_processes_by_tag_runner(tags, runner, time_lower_limit)
+            .await
+    }
+
+    async fn list_processes_by_merchant_task_constraints(
+        &self,
+        constraints: storage::ProcessTrackerMerchantTaskConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .list_processes_by_merchant_task_constraints(constraints)
+            .await
+    }
+
+    async fn count_overdue_processes_by_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        scheduled_before: PrimitiveDateTime,
+        merchant_id: Option<&id_type::MerchantId>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_overdue_processes_by_runner(runner, scheduled_before, merchant_id)
+            .await
+    }
+
+    async fn count_unfinished_processes_by_merchant(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<&id_type::ProfileId>,
+        scheduled_before: PrimitiveDateTime,
+    ) -> CustomResult<storage::ProcessTrackerMerchantTaskCounts, errors::StorageError> {
+        self.diesel_store
+            .count_unfinished_processes_by_merchant(
+                runner,
+                merchant_id,
+                profile_id,
+                scheduled_before,
+            )
+            .await
+    }
+
+    async fn count_finished_processes_by_merchant_business_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<&id_type::ProfileId>,
+        business_status: &'static str,
+        finished_after: PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_finished_processes_by_merchant_business_status(
+                runner,
+                merchant_id,
+                profile_id,
+                business_status,
+                finished_after,
+            )
+            .await
+    }
+
+    async fn count_processes_by_tag_runner_status(
+        &self,
+        tag: &str,
+        runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<Vec<(ProcessTrackerStatus, String, i64)>, errors::StorageError> {
+        self.diesel_store
+            .count_processes_by_tag_runner_status(tag, runner)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -334,7 +334,8 @@ mod storage {
     use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
     use storage_impl::redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+        decide_storage_scheme, increment_entity_version, kv_wrapper, KvOperation, Op, PartitionKey,
+        RedisConnInterface,
     };
 
     use super::RefundInterface;
@@ -471,6 +472,7 @@ mod storage {
                         processor_transaction_data: new.processor_transaction_data.clone(),
                         issuer_error_code: None,
                         issuer_error_message: None,
+                        entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
                         // Below fields are deprecated. Please add any new fields above this line.
                         connector_refund_data: None,
                         connector_transaction_data: None,
@@ -662,7 +664,23 @@ mod storage {
                 }
                 enums::MerchantStorageScheme::RedisKv => {
                     let key_str = key.to_string();
-                    let updated_refund = refund.clone().apply_changeset(this.clone());
+                    let mut updated_refund = refund.clone().apply_changeset(this.clone());
+                    if storage_types::RefundUpdateInternal::from(refund.clone()).is_status_update()
+                    {
+                        // Concurrent updates read the same version, so the next version is handed
+                        // out by redis rather than derived from the refund that was read
+                        updated_refund.entity_version = increment_entity_version(
+                            &self
+                                .get_redis_conn()
+                                .map_err(|err| err.to_redis_failed_response(&key_str))?,
+                            key.clone(),
+                            &field,
+                            this.entity_version,
+                            self.ttl_for_kv,
+                        )
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&key_str))?;
+                    }
 
                     let redis_value = updated_refund
                         .encode_to_string_of_json()
@@ -1036,6 +1054,7 @@ impl RefundInterface for MockDb {
             processor_transaction_data: new.processor_transaction_data.clone(),
             issuer_error_code: None,
             issuer_error_message: None,
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_refund_data: None,
             connector_transaction_data: None,
@@ -1087,6 +1106,7 @@ impl RefundInterface for MockDb {
             unified_message: None,
             processor_refund_data: new.processor_refund_data.clone(),
             processor_transaction_data: new.processor_transaction_data.clone(),
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -0,0 +1,508 @@
+#[cfg(feature = "v2")]
+use diesel_models::revenue_recovery_collection_item::RevenueRecoveryCollectionItemUpdateInternal;
+#[cfg(feature = "v2")]
+use error_stack::{report, ResultExt};
+#[cfg(feature = "v2")]
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::db::kafka_store::KafkaStore;
+#[cfg(feature = "v2")]
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{enums, revenue_recovery as storage},
+};
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+pub trait RevenueRecoveryCollectionItemInterface {
+    async fn insert_revenue_recovery_collection_item(
+        &self,
+        item: storage::RevenueRecoveryCollectionItemNew,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError>;
+
+    async fn find_revenue_recovery_collection_item_by_profile_id_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError>;
+
+    async fn list_revenue_recovery_collection_items_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: Option<enums::RecoveryCollectionStatus>,
+        assignee: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryCollectionItem>, errors::StorageError>;
+
+    /// Number of the items of the profile created in the given time range, i.e. the payments which
+    /// were handed off to manual collection after their retries were exhausted
+    async fn count_revenue_recovery_collection_items_by_profile_id_created_at(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn update_open_revenue_recovery_collection_item(
+        &self,
+        id: &str,
+        update: storage::RevenueRecoveryCollectionItemUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError>;
+}
+
+#[cfg(feature = "v1")]
+pub trait RevenueRecoveryCollectionItemInterface {}
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+impl RevenueRecoveryCollectionItemInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_revenue_recovery_collection_item(
+        &self,
+        item: storage::RevenueRecoveryCollectionItemNew,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        item.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_revenue_recovery_collection_item_by_profile_id_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryCollectionItem::find_by_profile_id_id(&conn, profile_id, id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_revenue_recovery_collection_items_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: Option<enums::RecoveryCollectionStatus>,
+        assignee: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryCollectionItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryCollectionItem::list_by_profile_id(
+            &conn, profile_id, status, assignee, limit, offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_revenue_recovery_collection_items_by_profile_id_created_at(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryCollectionItem::count_by_profile_id_created_at(
+            &conn,
+            profile_id,
+            created_after,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_open_revenue_recovery_collection_item(
+        &self,
+        id: &str,
+        update: storage::RevenueRecoveryCollectionItemUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RevenueRecoveryCollectionItem::update_open_item_by_id(&conn, id, update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[cfg(feature = "v1")]
+impl RevenueRecoveryCollectionItemInterface for Store {}
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+impl RevenueRecoveryCollectionItemInterface for MockDb {
+    async fn insert_revenue_recovery_collection_item(
+        &self,
+        item: storage::RevenueRecoveryCollectionItemNew,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        let mut items = self.revenue_recovery_collection_items.lock().await;
+        if items.iter().any(|existing_item| {
+            existing_item.id == item.id
+                || (existing_item.payment_id == item.payment_id
+                    && existing_item.status == enums::RecoveryCollectionStatus::Open)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "revenue_recovery_collection_item",
+                key: Some(item.payment_id.get_string_repr().to_owned()),
+            })?
+        }
+
+        let item = storage::RevenueRecoveryCollectionItem {
+            id: item.id,
+            payment_id: item.payment_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            merchant_reference_id: item.merchant_reference_id,
+            amount_outstanding: item.amount_outstanding,
+            currency: item.currency,
+            last_error_code: item.last_error_code,
+            last_failure_reason: item.last_failure_reason,
+            customer_id: item.customer_id,
+            status: item.status,
+            assignee: None,
+            assigned_by: None,
+            outcome: None,
+            resolved_by: None,
+            resolved_at: None,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+        };
+        items.push(item.clone());
+        Ok(item)
+    }
+
+    async fn find_revenue_recovery_collection_item_by_profile_id_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        self.revenue_recovery_collection_items
+            .lock()
+            .await
+            .iter()
+            .find(|item| item.profile_id == *profile_id && item.id == id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find revenue recovery collection item for id = {id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_revenue_recovery_collection_items_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: Option<enums::RecoveryCollectionStatus>,
+        assignee: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryCollectionItem>, errors::StorageError> {
+        let mut items = self
+            .revenue_recovery_collection_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| {
+                item.profile_id == *profile_id
+                    && status.map_or(true, |status| item.status == status)
+                    && assignee
+                        .as_ref()
+                        .map_or(true, |assignee| item.assignee.as_ref() == Some(assignee))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        items.sort_by(|item1, item2| item2.created_at.cmp(&item1.created_at));
+
+        Ok(items
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn count_revenue_recovery_collection_items_by_profile_id_created_at(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let items = self.revenue_recovery_collection_items.lock().await;
+        let count = items
+            .iter()
+            .filter(|item| {
+                item.profile_id == *profile_id
+                    && item.created_at >= created_after
+                    && item.created_at <= created_before
+            })
+            .count();
+        i64::try_from(count)
+            .change_context(errors::StorageError::MockDbError)
+            .attach_printable("Failed to convert usize to i64")
+    }
+
+    async fn update_open_revenue_recovery_collection_item(
+        &self,
+        id: &str,
+        update: storage::RevenueRecoveryCollectionItemUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        self.revenue_recovery_collection_items
+            .lock()
+            .await
+            .iter_mut()
+            .find(|item| item.id == id && item.status == enums::RecoveryCollectionStatus::Open)
+            .map(|item| {
+                let updated_item = RevenueRecoveryCollectionItemUpdateInternal::from(update)
+                    .apply_changeset(item.clone());
+                *item = updated_item.clone();
+                updated_item
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find open revenue recovery collection item for id = {id}"
+                ))
+                .into(),
+            )
+    }
+}
+
+#[cfg(feature = "v1")]
+impl RevenueRecoveryCollectionItemInterface for MockDb {}
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+impl RevenueRecoveryCollectionItemInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_revenue_recovery_collection_item(
+        &self,
+        item: storage::RevenueRecoveryCollectionItemNew,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        self.diesel_store
+            .insert_revenue_recovery_collection_item(item)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_revenue_recovery_collection_item_by_profile_id_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        id: &str,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        self.diesel_store
+            .find_revenue_recovery_collection_item_by_profile_id_id(profile_id, id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_revenue_recovery_collection_items_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: Option<enums::RecoveryCollectionStatus>,
+        assignee: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryCollectionItem>, errors::StorageError> {
+        self.diesel_store
+            .list_revenue_recovery_collection_items_by_profile_id(
+                profile_id, status, assignee, limit, offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn count_revenue_recovery_collection_items_by_profile_id_created_at(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_revenue_recovery_collection_items_by_profile_id_created_at(
+                profile_id,
+                created_after,
+                created_before,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_open_revenue_recovery_collection_item(
+        &self,
+        id: &str,
+        update: storage::RevenueRecoveryCollectionItemUpdate,
+    ) -> CustomResult<storage::RevenueRecoveryCollectionItem, errors::StorageError> {
+        self.diesel_store
+            .update_open_revenue_recovery_collection_item(id, update)
+            .await
+    }
+}
+
+#[cfg(feature = "v1")]
+impl RevenueRecoveryCollectionItemInterface for KafkaStore {}
+
+#[cfg(all(test, feature = "v2"))]
+mod tests {
+    use std::borrow::Cow;
+
+    use common_utils::types::MinorUnit;
+
+    use crate::{
+        db::{revenue_recovery_collection_item::RevenueRecoveryCollectionItemInterface, MockDb},
+        types::storage::{enums, revenue_recovery as storage},
+    };
+
+    #[allow(clippy::unwrap_used)]
+    fn collection_item_new(
+        id: &str,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> storage::RevenueRecoveryCollectionItemNew {
+        let now = common_utils::date_time::now();
+        storage::RevenueRecoveryCollectionItemNew {
+            id: id.to_owned(),
+            payment_id: payment_id.clone(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant1"))
+                .unwrap(),
+            profile_id: profile_id.clone(),
+            merchant_reference_id: None,
+            amount_outstanding: MinorUnit::new(1000),
+            currency: enums::Currency::USD,
+            last_error_code: Some("insufficient_funds".to_owned()),
+            last_failure_reason: Some("Insufficient funds".to_owned()),
+            customer_id: None,
+            status: enums::RecoveryCollectionStatus::Open,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_revenue_recovery_collection_item_lifecycle() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let cell_id = common_utils::id_type::CellId::from_string("12345").unwrap();
+        let payment_id = common_utils::id_type::GlobalPaymentId::generate(&cell_id);
+        let profile_id = common_utils::id_type::ProfileId::try_from(Cow::from("profile1")).unwrap();
+
+        mockdb
+            .insert_revenue_recovery_collection_item(collection_item_new(
+                "rci_1",
+                &payment_id,
+                &profile_id,
+            ))
+            .await
+            .unwrap();
+
+        // The payment is already in the queue, it should not be handed off again
+        assert!(mockdb
+            .insert_revenue_recovery_collection_item(collection_item_new(
+                "rci_2",
+                &payment_id,
+                &profile_id,
+            ))
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_unique_violation());
+
+        let open_items = mockdb
+            .list_revenue_recovery_collection_items_by_profile_id(
+                &profile_id,
+                Some(enums::RecoveryCollectionStatus::Open),
+                None,
+                10,
+                0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(open_items.len(), 1);
+
+        let assigned_item = mockdb
+            .update_open_revenue_recovery_collection_item(
+                "rci_1",
+                storage::RevenueRecoveryCollectionItemUpdate::Assign {
+                    assignee: "collector@example.com".to_owned(),
+                    assigned_by: "user1".to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            assigned_item.assignee.as_deref(),
+            Some("collector@example.com")
+        );
+
+        let assigned_items = mockdb
+            .list_revenue_recovery_collection_items_by_profile_id(
+                &profile_id,
+                None,
+                Some("collector@example.com".to_owned()),
+                10,
+                0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(assigned_items.len(), 1);
+
+        let resolved_item = mockdb
+            .update_open_revenue_recovery_collection_item(
+                "rci_1",
+                storage::RevenueRecoveryCollectionItemUpdate::Resolve {
+                    outcome: enums::RecoveryCollectionOutcome::Uncollectible,
+                    resolved_by: "user1".to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            resolved_item.status,
+            enums::RecoveryCollectionStatus::Resolved
+        );
+        assert!(resolved_item.resolved_at.is_some());
+
+        // A resolved item cannot be resolved again
+        assert!(mockdb
+            .update_open_revenue_recovery_collection_item(
+                "rci_1",
+                storage::RevenueRecoveryCollectionItemUpdate::Resolve {
+                    outcome: enums::RecoveryCollectionOutcome::PaidExternally,
+                    resolved_by: "user1".to_owned(),
+                },
+            )
+            .await
+            .is_err());
+
+        // Once resolved, the payment can be handed off again
+        mockdb
+            .insert_revenue_recovery_collection_item(collection_item_new(
+                "rci_2",
+                &payment_id,
+                &profile_id,
+            ))
+            .await
+            .unwrap();
+
+        let open_items = mockdb
+            .list_revenue_recovery_collection_items_by_profile_id(
+                &profile_id,
+                Some(enums::RecoveryCollectionStatus::Open),
+                None,
+                10,
+                0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(open_items.len(), 1);
+        assert_eq!(open_items[0].id, "rci_2");
+    }
+}
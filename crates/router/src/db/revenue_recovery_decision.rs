@@ -0,0 +1,200 @@
+#[cfg(feature = "v2")]
+use error_stack::report;
+#[cfg(feature = "v2")]
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::db::kafka_store::KafkaStore;
+#[cfg(feature = "v2")]
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::revenue_recovery as storage,
+};
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+pub trait RevenueRecoveryDecisionInterface {
+    async fn insert_revenue_recovery_decision(
+        &self,
+        decision: storage::RevenueRecoveryDecision,
+    ) -> CustomResult<storage::RevenueRecoveryDecision, errors::StorageError>;
+
+    async fn list_revenue_recovery_decisions_by_payment_id(
+        &self,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryDecision>, errors::StorageError>;
+
+    async fn count_revenue_recovery_decisions_by_payment_id(
+        &self,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn count_revenue_recovery_decisions_by_attempt_triggered_by(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(Option<String>, i64)>, errors::StorageError>;
+}
+
+#[cfg(feature = "v1")]
+pub trait RevenueRecoveryDecisionInterface {}
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+impl RevenueRecoveryDecisionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_revenue_recovery_decision(
+        &self,
+        decision: storage::RevenueRecoveryDecision,
+    ) -> CustomResult<storage::RevenueRecoveryDecision, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        decision
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_revenue_recovery_decisions_by_payment_id(
+        &self,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryDecision>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryDecision::list_by_payment_id(&conn, payment_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_revenue_recovery_decisions_by_payment_id(
+        &self,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryDecision::count_by_payment_id(&conn, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_revenue_recovery_decisions_by_attempt_triggered_by(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(Option<String>, i64)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RevenueRecoveryDecision::count_by_merchant_id_attempt_triggered_by(
+            &conn,
+            merchant_id,
+            profile_id,
+            created_after,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[cfg(feature = "v1")]
+impl RevenueRecoveryDecisionInterface for Store {}
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+impl RevenueRecoveryDecisionInterface for MockDb {
+    async fn insert_revenue_recovery_decision(
+        &self,
+        _decision: storage::RevenueRecoveryDecision,
+    ) -> CustomResult<storage::RevenueRecoveryDecision, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_revenue_recovery_decisions_by_payment_id(
+        &self,
+        _payment_id: &common_utils::id_type::GlobalPaymentId,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryDecision>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_revenue_recovery_decisions_by_payment_id(
+        &self,
+        _payment_id: &common_utils::id_type::GlobalPaymentId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_revenue_recovery_decisions_by_attempt_triggered_by(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: Option<&common_utils::id_type::ProfileId>,
+        _created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(Option<String>, i64)>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[cfg(feature = "v1")]
+impl RevenueRecoveryDecisionInterface for MockDb {}
+
+#[cfg(feature = "v2")]
+#[async_trait::async_trait]
+impl RevenueRecoveryDecisionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_revenue_recovery_decision(
+        &self,
+        decision: storage::RevenueRecoveryDecision,
+    ) -> CustomResult<storage::RevenueRecoveryDecision, errors::StorageError> {
+        self.diesel_store
+            .insert_revenue_recovery_decision(decision)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_revenue_recovery_decisions_by_payment_id(
+        &self,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RevenueRecoveryDecision>, errors::StorageError> {
+        self.diesel_store
+            .list_revenue_recovery_decisions_by_payment_id(payment_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn count_revenue_recovery_decisions_by_payment_id(
+        &self,
+        payment_id: &common_utils::id_type::GlobalPaymentId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_revenue_recovery_decisions_by_payment_id(payment_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn count_revenue_recovery_decisions_by_attempt_triggered_by(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<(Option<String>, i64)>, errors::StorageError> {
+        self.diesel_store
+            .count_revenue_recovery_decisions_by_attempt_triggered_by(
+                merchant_id,
+                profile_id,
+                created_after,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "v1")]
+impl RevenueRecoveryDecisionInterface for KafkaStore {}
@@ -8,10 +8,12 @@ use time::OffsetDateTime;
 use super::EventType;
 #[cfg(feature = "dummy_connector")]
 use crate::routes::dummy_connector::types::{
-    DummyConnectorPaymentCompleteRequest, DummyConnectorPaymentConfirmRequest,
-    DummyConnectorPaymentRequest, DummyConnectorPaymentResponse,
-    DummyConnectorPaymentRetrieveRequest, DummyConnectorRefundRequest,
-    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyBillingInvoiceTransactionsRequest, DummyBillingInvoiceTransactionsResponse,
+    DummyBillingRecordBackRequest, DummyBillingRecordBackResponse, DummyBillingTransaction,
+    DummyBillingTransactionRetrieveRequest, DummyConnectorPaymentCompleteRequest,
+    DummyConnectorPaymentConfirmRequest, DummyConnectorPaymentRequest,
+    DummyConnectorPaymentResponse, DummyConnectorPaymentRetrieveRequest,
+    DummyConnectorRefundRequest, DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
 };
 use crate::{
     core::payments::PaymentsRedirectResponseData,
@@ -123,7 +125,13 @@ impl_api_event_type!(
         DummyConnectorPaymentConfirmRequest,
         DummyConnectorRefundRetrieveRequest,
         DummyConnectorRefundResponse,
-        DummyConnectorRefundRequest
+        DummyConnectorRefundRequest,
+        DummyBillingTransactionRetrieveRequest,
+        DummyBillingTransaction,
+        DummyBillingInvoiceTransactionsRequest,
+        DummyBillingInvoiceTransactionsResponse,
+        DummyBillingRecordBackRequest,
+        DummyBillingRecordBackResponse
     )
 );
 
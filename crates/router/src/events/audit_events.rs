@@ -1,5 +1,5 @@
 use api_models::payments::Amount;
-use common_utils::types::MinorUnit;
+use common_utils::{id_type, types::MinorUnit};
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
@@ -39,6 +39,43 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    /// The invoice is handed off to the manual collection queue once revenue recovery is exhausted
+    #[serde(rename = "recovery.handed_off")]
+    RecoveryHandedOff {
+        payment_id: id_type::GlobalPaymentId,
+        collection_item_id: String,
+        amount_outstanding: MinorUnit,
+        currency: common_enums::Currency,
+        /// Identifier of the billing connector webhook from which the last retry originated
+        recovery_correlation_id: Option<String>,
+    },
+    /// The executions of a revenue recovery task failed with an error too many times in a row, and
+    /// the task is moved to review until it is requeued
+    #[serde(rename = "recovery.task_moved_to_review")]
+    RecoveryTaskMovedToReview {
+        task_id: String,
+        task_name: Option<String>,
+        merchant_id: id_type::MerchantId,
+        profile_id: id_type::ProfileId,
+        payment_id: id_type::GlobalPaymentId,
+        execution_failure_count: u16,
+        last_execution_error: Option<String>,
+    },
+    /// The daily summary of the webhook delivery failures of a profile
+    #[serde(rename = "digest.daily")]
+    DigestDaily {
+        digest: Box<api_models::webhook_events::OutgoingWebhookDigest>,
+    },
+    /// The stored payloads of the delivery attempts of a webhook event were replaced by a
+    /// placeholder on the request of the merchant
+    #[serde(rename = "event.payload_scrubbed")]
+    EventPayloadScrubbed {
+        merchant_id: id_type::MerchantId,
+        profile_id: id_type::ProfileId,
+        initial_attempt_id: String,
+        scrubbed_attempt_ids: Vec<String>,
+        scrubbed_by: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +120,10 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus { .. } => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::RecoveryHandedOff { .. } => "recovery.handed_off",
+            AuditEventType::RecoveryTaskMovedToReview { .. } => "recovery.task_moved_to_review",
+            AuditEventType::DigestDaily { .. } => "digest.daily",
+            AuditEventType::EventPayloadScrubbed { .. } => "event.payload_scrubbed",
         };
         format!(
             "{event_type}-{}",
@@ -77,6 +77,7 @@ pub mod headers {
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
+    pub const X_RECOVERY_CORRELATION_ID: &str = "X-Recovery-Correlation-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
     pub const X_CLIENT_VERSION: &str = "X-Client-Version";
@@ -122,6 +123,12 @@ pub fn mk_app(
         server_app = server_app.service(DummyConnector::server(state.clone()));
     }
 
+    #[cfg(all(feature = "dummy_connector", feature = "v2"))]
+    {
+        use routes::DummyBillingConnector;
+        server_app = server_app.service(DummyBillingConnector::server(state.clone()));
+    }
+
     #[cfg(any(feature = "olap", feature = "oltp"))]
     {
         #[cfg(feature = "olap")]
@@ -205,7 +212,16 @@ pub fn mk_app(
 
         #[cfg(feature = "v2")]
         {
-            server_app = server_app.service(routes::ProcessTracker::server(state.clone()));
+            server_app = server_app
+                .service(routes::ProcessTracker::server(state.clone()))
+                .service(routes::RecoveryCollections::server(state.clone()))
+                .service(routes::RecoveryTasks::server(state.clone()))
+                .service(routes::RecoveryKillSwitch::server(state.clone()))
+                .service(routes::RecoveryRetrySchedules::server(state.clone()))
+                .service(routes::RecoveryReconciliation::server(state.clone()))
+                .service(routes::RecoveryOverview::server(state.clone()))
+                .service(routes::RecoverySchedule::server(state.clone()))
+                .service(routes::RecoveryDebug::server(state.clone()));
         }
     }
 
@@ -252,6 +268,8 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
         errors::ApplicationError::ApiClientError(error.current_context().clone())
     })?);
     let state = Box::pin(AppState::new(conf, tx, api_client)).await;
+    #[cfg(feature = "olap")]
+    core::webhooks::event_type_catalog::load_event_type_catalog();
     let request_body_limit = server.request_body_limit;
 
     let server_builder =
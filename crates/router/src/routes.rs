@@ -68,6 +68,8 @@ pub mod relay;
 #[cfg(feature = "olap")]
 pub mod process_tracker;
 
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+pub use self::app::DummyBillingConnector;
 #[cfg(feature = "dummy_connector")]
 pub use self::app::DummyConnector;
 #[cfg(feature = "v2")]
@@ -82,7 +84,11 @@ pub use self::app::{
     Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, Organization, RecoveryCollections, RecoveryDebug, RecoveryKillSwitch,
+    RecoveryOverview, RecoveryReconciliation, RecoveryRetrySchedules, RecoverySchedule,
+    RecoveryTasks, Routing, Verify, WebhookEvents,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
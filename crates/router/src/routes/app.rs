@@ -80,6 +80,8 @@ use crate::routes::feature_matrix;
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "recon", feature = "olap"))]
 use crate::routes::recon as recon_routes;
+#[cfg(feature = "recovery_fault_injection")]
+use crate::utils::fault_injection::FaultInjectionRegistry;
 pub use crate::{
     configs::settings,
     db::{
@@ -121,7 +123,10 @@ pub struct SessionState {
     pub opensearch_client: Arc<OpenSearchClient>,
     pub grpc_client: Arc<GrpcClients>,
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
+    pub events_archive_client: Arc<dyn FileStorageInterface>,
     pub locale: String,
+    #[cfg(feature = "recovery_fault_injection")]
+    pub fault_injection: Arc<FaultInjectionRegistry>,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -229,6 +234,9 @@ pub struct AppState {
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
     pub grpc_client: Arc<GrpcClients>,
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
+    pub events_archive_client: Arc<dyn FileStorageInterface>,
+    #[cfg(feature = "recovery_fault_injection")]
+    pub fault_injection: Arc<FaultInjectionRegistry>,
 }
 impl scheduler::SchedulerAppState for AppState {
     fn get_tenants(&self) -> Vec<id_type::TenantId> {
@@ -390,9 +398,19 @@ impl AppState {
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
+            let events_archive_client = conf
+                .events_archive
+                .get_inner()
+                .storage
+                .get_file_storage_client()
+                .await;
 
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
 
+            #[cfg(feature = "recovery_fault_injection")]
+            let fault_injection =
+                Arc::new(FaultInjectionRegistry::new(&conf.recovery_fault_injection));
+
             Self {
                 flow_name: String::from("default"),
                 stores,
@@ -412,6 +430,9 @@ impl AppState {
                 encryption_client,
                 grpc_client,
                 theme_storage_client,
+                events_archive_client,
+                #[cfg(feature = "recovery_fault_injection")]
+                fault_injection,
             }
         })
         .await
@@ -504,7 +525,10 @@ impl AppState {
             opensearch_client: Arc::clone(&self.opensearch_client),
             grpc_client: Arc::clone(&self.grpc_client),
             theme_storage_client: self.theme_storage_client.clone(),
+            events_archive_client: self.events_archive_client.clone(),
             locale: locale.unwrap_or(common_utils::consts::DEFAULT_LOCALE.to_string()),
+            #[cfg(feature = "recovery_fault_injection")]
+            fault_injection: Arc::clone(&self.fault_injection),
         })
     }
 }
@@ -559,6 +583,34 @@ impl DummyConnector {
     }
 }
 
+/// Mock routes of the dummy billing connector, serving its payments sync and record back calls
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+pub struct DummyBillingConnector;
+
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+impl DummyBillingConnector {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/dummy-billing-connector").app_data(web::Data::new(state));
+        #[cfg(not(feature = "external_access_dc"))]
+        {
+            route = route.guard(actix_web::guard::Host("localhost"));
+        }
+        route
+            .service(
+                web::resource("/transactions/{transaction_id}")
+                    .route(web::get().to(dummy_billing_connector_transaction)),
+            )
+            .service(
+                web::resource("/invoices/{invoice_id}/transactions")
+                    .route(web::get().to(dummy_billing_connector_invoice_transactions)),
+            )
+            .service(
+                web::resource("/invoices/{invoice_id}/record_back")
+                    .route(web::post().to(dummy_billing_connector_record_back)),
+            )
+    }
+}
+
 pub struct Payments;
 
 #[cfg(all(
@@ -635,6 +687,26 @@ impl Payments {
                 )
                 .service(
                     web::resource("/capture").route(web::post().to(payments::payments_capture)),
+                )
+                .service(
+                    web::resource("/recovery")
+                        .route(web::get().to(payments::payments_recovery_status)),
+                )
+                .service(
+                    web::resource("/recovery/overrides")
+                        .route(web::post().to(payments::payments_recovery_override)),
+                )
+                .service(
+                    web::resource("/recovery/cancel")
+                        .route(web::post().to(payments::payments_recovery_cancel)),
+                )
+                .service(
+                    web::resource("/recovery/reschedule")
+                        .route(web::post().to(payments::payments_recovery_reschedule)),
+                )
+                .service(
+                    web::resource("/recovery/decisions")
+                        .route(web::get().to(payments::payments_recovery_decisions)),
                 ),
         );
 
@@ -2464,19 +2536,102 @@ impl WebhookEvents {
     pub fn server(config: AppState) -> Scope {
         web::scope("/events")
             .app_data(web::Data::new(config))
+            .service(web::resource("/types").route(web::get().to(webhook_events::list_event_types)))
+            .service(
+                web::resource("/profile/types")
+                    .route(web::get().to(webhook_events::list_event_types_with_jwtauth)),
+            )
             .service(web::scope("/profile/list").service(web::resource("").route(
                 web::get().to(webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth),
             )))
+            .service(
+                web::resource("/profile/webhook_url/confirm")
+                    .route(web::post().to(webhook_events::confirm_webhook_url_change_with_jwtauth)),
+            )
+            .service(
+                web::resource("/profile/subscription")
+                    .route(web::put().to(webhook_events::update_event_subscription_with_jwtauth)),
+            )
+            .service(
+                web::resource("/profile/retry_policy/preview")
+                    .route(web::post().to(webhook_events::preview_retry_policy_with_jwtauth)),
+            )
+            .service(
+                web::resource("/profile/stats")
+                    .route(web::get().to(webhook_events::get_webhook_delivery_stats_with_jwtauth)),
+            )
+            .service(
+                web::resource("/profile/{event_id}/scrub")
+                    .route(web::post().to(webhook_events::scrub_event_payload_with_jwtauth)),
+            )
+            .service(
+                web::resource("/profile/{event_id}/attempts").route(
+                    web::get().to(webhook_events::list_webhook_delivery_attempts_with_jwtauth),
+                ),
+            )
+            .service(
+                web::resource("/profile/{event_id}/retry").route(
+                    web::post().to(webhook_events::retry_webhook_delivery_attempt_with_jwtauth),
+                ),
+            )
+            .service(
+                web::resource("/profile/{event_id}")
+                    .route(web::get().to(webhook_events::retrieve_webhook_event_with_jwtauth)),
+            )
             .service(
                 web::scope("/{merchant_id}")
                     .service(web::resource("").route(
                         web::get().to(webhook_events::list_initial_webhook_delivery_attempts),
                     ))
+                    .service(
+                        web::resource("/profile/{profile_id}/webhook_url/force_confirm").route(
+                            web::post().to(webhook_events::force_confirm_webhook_url_change),
+                        ),
+                    )
+                    .service(
+                        web::resource("/profile/{profile_id}/subscription")
+                            .route(web::put().to(webhook_events::update_event_subscription)),
+                    )
+                    .service(
+                        web::resource("/retry")
+                            .route(web::post().to(webhook_events::bulk_retry_webhook_deliveries)),
+                    )
+                    .service(
+                        web::resource("/retry_jobs/{job_id}")
+                            .route(web::get().to(webhook_events::retrieve_webhook_bulk_retry_job)),
+                    )
+                    .service(
+                        web::resource("/stats")
+                            .route(web::get().to(webhook_events::get_webhook_delivery_stats)),
+                    )
+                    .service(
+                        web::resource("/export")
+                            .route(web::get().to(webhook_events::export_webhook_events)),
+                    )
+                    .service(
+                        web::resource("/retention")
+                            .route(web::post().to(webhook_events::schedule_events_retention)),
+                    )
+                    .service(
+                        web::resource("/archives")
+                            .route(web::get().to(webhook_events::list_events_archives)),
+                    )
                     .service(
                         web::scope("/{event_id}")
+                            .service(
+                                web::resource("")
+                                    .route(web::get().to(webhook_events::retrieve_webhook_event)),
+                            )
                             .service(web::resource("attempts").route(
                                 web::get().to(webhook_events::list_webhook_delivery_attempts),
                             ))
+                            .service(
+                                web::resource("attempts/{attempt_id}/payload").route(
+                                    web::get().to(
+                                        webhook_events::retrieve_webhook_delivery_attempt_payload,
+                                    ),
+                                ),
+                            )
                             .service(web::resource("retry").route(
                                 web::post().to(webhook_events::retry_webhook_delivery_attempt),
                             )),
@@ -2506,9 +2661,194 @@ impl ProcessTracker {
         use super::process_tracker::revenue_recovery;
         web::scope("/v2/process_tracker/revenue_recovery_workflow")
             .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("/analytics")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_analytics_api)),
+            )
             .service(
                 web::resource("/{revenue_recovery_id}")
                     .route(web::get().to(revenue_recovery::revenue_recovery_pt_retrieve_api)),
             )
     }
 }
+
+#[cfg(feature = "olap")]
+pub struct RecoveryCollections;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoveryCollections {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/collections")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_collections_list_api)),
+            )
+            .service(
+                web::resource("/{id}/assign").route(
+                    web::post().to(revenue_recovery::revenue_recovery_collection_assign_api),
+                ),
+            )
+            .service(
+                web::resource("/{id}/resolve").route(
+                    web::post().to(revenue_recovery::revenue_recovery_collection_resolve_api),
+                ),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct RecoveryTasks;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoveryTasks {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/tasks")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("/{merchant_id}")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_tasks_list_api)),
+            )
+            .service(
+                web::resource("/{merchant_id}/review")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_review_tasks_list_api)),
+            )
+            .service(
+                web::resource("/{merchant_id}/requeue").route(
+                    web::post().to(revenue_recovery::revenue_recovery_tasks_bulk_requeue_api),
+                ),
+            )
+            .service(
+                web::resource("/{merchant_id}/overdue")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_overdue_tasks_api)),
+            )
+            .service(
+                web::resource("/{merchant_id}/{task_id}/requeue").route(
+                    web::post().to(revenue_recovery::revenue_recovery_review_task_requeue_api),
+                ),
+            )
+            .service(
+                web::resource("/{merchant_id}/{task_id}/snapshot")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_task_snapshot_api)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct RecoveryKillSwitch;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoveryKillSwitch {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/kill_switch")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("")
+                    .route(
+                        web::get().to(revenue_recovery::revenue_recovery_kill_switch_retrieve_api),
+                    )
+                    .route(
+                        web::post().to(revenue_recovery::revenue_recovery_kill_switch_update_api),
+                    ),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct RecoveryReconciliation;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoveryReconciliation {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/reconciliation")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("/{merchant_id}/{merchant_connector_account_id}").route(
+                    web::get().to(revenue_recovery::revenue_recovery_reconciliation_reports_api),
+                ),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct RecoveryOverview;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoveryOverview {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/overview")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("/{merchant_id}")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_overview_api)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct RecoveryDebug;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoveryDebug {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/debug")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("/{merchant_id}/billing-sync").route(
+                    web::post().to(revenue_recovery::revenue_recovery_billing_sync_debug_api),
+                ),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct RecoverySchedule;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoverySchedule {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/schedule")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("/preview")
+                    .route(web::post().to(revenue_recovery::revenue_recovery_schedule_preview_api)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct RecoveryRetrySchedules;
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+impl RecoveryRetrySchedules {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::revenue_recovery;
+        web::scope("/v2/recovery/retry_schedules")
+            .app_data(web::Data::new(state.clone()))
+            .service(
+                web::resource("/{merchant_id}")
+                    .route(
+                        web::post()
+                            .to(revenue_recovery::revenue_recovery_retry_schedule_create_api),
+                    )
+                    .route(
+                        web::get()
+                            .to(revenue_recovery::revenue_recovery_retry_schedule_retrieve_api),
+                    )
+                    .route(
+                        web::put().to(revenue_recovery::revenue_recovery_retry_schedule_update_api),
+                    )
+                    .route(
+                        web::delete()
+                            .to(revenue_recovery::revenue_recovery_retry_schedule_delete_api),
+                    ),
+            )
+    }
+}
@@ -148,3 +148,70 @@ pub async fn dummy_connector_refund_data(
     )
     .await
 }
+
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyBillingTransactionRetrieve))]
+pub async fn dummy_billing_connector_transaction(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyBillingTransactionRetrieve;
+    let transaction_id = path.into_inner();
+    let payload = types::DummyBillingTransactionRetrieveRequest { transaction_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::billing_transaction(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyBillingInvoiceTransactionsRetrieve))]
+pub async fn dummy_billing_connector_invoice_transactions(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyBillingInvoiceTransactionsRetrieve;
+    let invoice_id = path.into_inner();
+    let payload = types::DummyBillingInvoiceTransactionsRequest { invoice_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::billing_invoice_transactions(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyBillingRecordBack))]
+pub async fn dummy_billing_connector_record_back(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyBillingRecordBackRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyBillingRecordBack;
+    let mut payload = json_payload.into_inner();
+    payload.invoice_id = Some(path.into_inner());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::billing_record_back(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -227,3 +227,50 @@ pub async fn refund_data(
         .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+pub async fn billing_transaction(
+    _state: SessionState,
+    req: types::DummyBillingTransactionRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyBillingTransaction> {
+    let transaction = utils::get_billing_transactions()
+        .into_iter()
+        .find(|transaction| transaction.id == req.transaction_id)
+        .ok_or(errors::DummyConnectorErrors::TransactionNotFound)?;
+    Ok(api::ApplicationResponse::Json(transaction))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+pub async fn billing_invoice_transactions(
+    _state: SessionState,
+    req: types::DummyBillingInvoiceTransactionsRequest,
+) -> types::DummyConnectorResponse<types::DummyBillingInvoiceTransactionsResponse> {
+    let transactions = utils::get_billing_transactions()
+        .into_iter()
+        .filter(|transaction| transaction.invoice_id == req.invoice_id)
+        .collect::<Vec<_>>();
+    if transactions.is_empty() {
+        return Err(errors::DummyConnectorErrors::InvoiceNotFound.into());
+    }
+    Ok(api::ApplicationResponse::Json(
+        types::DummyBillingInvoiceTransactionsResponse { transactions },
+    ))
+}
+
+/// The outcome recorded back to the dummy billing connector is accepted for any invoice, since the
+/// invoices of the webhooks are not a part of the fixtures
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+pub async fn billing_record_back(
+    _state: SessionState,
+    req: types::DummyBillingRecordBackRequest,
+) -> types::DummyConnectorResponse<types::DummyBillingRecordBackResponse> {
+    let invoice_id = req
+        .invoice_id
+        .get_required_value("invoice_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "invoice_id",
+        })?;
+    Ok(api::ApplicationResponse::Json(
+        types::DummyBillingRecordBackResponse { invoice_id },
+    ))
+}
@@ -37,6 +37,12 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_09", message = "Transaction does not exist in our records")]
+    TransactionNotFound,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_10", message = "Invoice does not exist in our records")]
+    InvoiceNotFound,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +89,12 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::TransactionNotFound => {
+                AER::NotFound(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::InvoiceNotFound => {
+                AER::NotFound(ApiError::new("DC", 10, self.error_message(), None))
+            }
         }
     }
 }
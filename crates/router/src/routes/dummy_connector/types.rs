@@ -18,6 +18,9 @@ pub enum Flow {
     DummyPaymentComplete,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyBillingTransactionRetrieve,
+    DummyBillingInvoiceTransactionsRetrieve,
+    DummyBillingRecordBack,
 }
 
 impl FlowMetric for Flow {}
@@ -372,6 +375,78 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+/// Outcome of a transaction of the dummy billing connector
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyBillingTransactionOutcome {
+    Succeeded,
+    Failed,
+    Pending,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyBillingCardFunding {
+    Credit,
+    Debit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyBillingCard {
+    pub network: Option<api_models::enums::CardNetwork>,
+    pub last4: Option<String>,
+    pub exp_month: Option<u8>,
+    pub exp_year: Option<u16>,
+    pub funding: DummyBillingCardFunding,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyBillingTransaction {
+    pub id: String,
+    pub invoice_id: String,
+    pub outcome: DummyBillingTransactionOutcome,
+    pub decline_code: Option<String>,
+    pub decline_message: Option<String>,
+    pub amount: i64,
+    pub currency: Currency,
+    pub gateway_account_id: String,
+    pub gateway_transaction_id: Option<String>,
+    pub gateway_customer_id: String,
+    pub payment_method_token: String,
+    pub card: DummyBillingCard,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyBillingTransactionRetrieveRequest {
+    pub transaction_id: String,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyBillingInvoiceTransactionsRequest {
+    pub invoice_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyBillingInvoiceTransactionsResponse {
+    pub transactions: Vec<DummyBillingTransaction>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyBillingRecordBackRequest {
+    pub outcome: DummyBillingTransactionOutcome,
+    pub amount: i64,
+    pub currency: Currency,
+    pub gateway_transaction_id: Option<String>,
+    pub invoice_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyBillingRecordBackResponse {
+    pub invoice_id: String,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
@@ -350,3 +350,81 @@ impl types::DummyConnectorPaymentData {
             .build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
     }
 }
+
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+fn build_billing_transaction(
+    id: &str,
+    invoice_id: &str,
+    outcome: types::DummyBillingTransactionOutcome,
+    decline: Option<(&str, &str)>,
+    created_at: time::PrimitiveDateTime,
+) -> types::DummyBillingTransaction {
+    types::DummyBillingTransaction {
+        id: id.to_string(),
+        invoice_id: invoice_id.to_string(),
+        outcome,
+        decline_code: decline.map(|(code, _)| code.to_string()),
+        decline_message: decline.map(|(_, message)| message.to_string()),
+        amount: 1000,
+        currency: api_models::enums::Currency::USD,
+        gateway_account_id: "dummy_gateway_account".to_string(),
+        gateway_transaction_id: Some(format!("pi_{id}")),
+        gateway_customer_id: "dummy_gateway_customer".to_string(),
+        payment_method_token: "dummy_payment_method_token".to_string(),
+        card: types::DummyBillingCard {
+            network: Some(api_models::enums::CardNetwork::Visa),
+            last4: Some("4242".to_string()),
+            exp_month: Some(12),
+            exp_year: Some(2030),
+            funding: types::DummyBillingCardFunding::Credit,
+        },
+        created_at,
+    }
+}
+
+/// Fixture transactions of the dummy billing connector. The invoices are named after the outcome
+/// of their latest transaction, the transactions of an invoice are listed from the oldest.
+#[cfg(all(feature = "dummy_connector", feature = "v2"))]
+pub fn get_billing_transactions() -> Vec<types::DummyBillingTransaction> {
+    use types::DummyBillingTransactionOutcome as Outcome;
+
+    let now = common_utils::date_time::now();
+    let minutes_ago = |minutes| now - time::Duration::minutes(minutes);
+    vec![
+        build_billing_transaction(
+            "dummy_txn_insufficient_funds",
+            "dummy_inv_failed",
+            Outcome::Failed,
+            Some(("insufficient_funds", "Your card has insufficient funds.")),
+            minutes_ago(50),
+        ),
+        build_billing_transaction(
+            "dummy_txn_do_not_honor",
+            "dummy_inv_failed",
+            Outcome::Failed,
+            Some(("do_not_honor", "Your card was declined.")),
+            minutes_ago(40),
+        ),
+        build_billing_transaction(
+            "dummy_txn_expired_card",
+            "dummy_inv_succeeded",
+            Outcome::Failed,
+            Some(("expired_card", "Your card has expired.")),
+            minutes_ago(30),
+        ),
+        build_billing_transaction(
+            "dummy_txn_succeeded",
+            "dummy_inv_succeeded",
+            Outcome::Succeeded,
+            None,
+            minutes_ago(20),
+        ),
+        build_billing_transaction(
+            "dummy_txn_pending",
+            "dummy_inv_pending",
+            Outcome::Pending,
+            None,
+            minutes_ago(10),
+        ),
+    ]
+}
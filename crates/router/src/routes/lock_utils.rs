@@ -154,7 +154,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsCreateAndConfirmIntent
             | Flow::PaymentStartRedirection
             | Flow::ProxyConfirmIntent
-            | Flow::PaymentsRetrieveUsingMerchantReferenceId => Self::Payments,
+            | Flow::PaymentsRetrieveUsingMerchantReferenceId
+            | Flow::RevenueRecoveryRetryOverride
+            | Flow::RevenueRecoveryCancel
+            | Flow::RevenueRecoveryReschedule
+            | Flow::RevenueRecoveryStatus
+            | Flow::RevenueRecoveryDecisionList => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -181,8 +186,22 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::IncomingRelayWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
+            | Flow::WebhookEventRetrieve
             | Flow::WebhookEventDeliveryAttemptList
+            | Flow::WebhookEventDeliveryAttemptPayloadRetrieve
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventBulkRetry
+            | Flow::WebhookEventBulkRetryJobRetrieve
+            | Flow::WebhookDeliveryStats
+            | Flow::WebhookUrlChangeConfirm
+            | Flow::WebhookUrlChangeForceConfirm
+            | Flow::WebhookEventSubscriptionUpdate
+            | Flow::WebhookRetryPolicyPreview
+            | Flow::WebhookEventPayloadScrub
+            | Flow::WebhookEventExport
+            | Flow::WebhookEventTypeList
+            | Flow::WebhookEventsRetentionSchedule
+            | Flow::WebhookEventsArchiveList
             | Flow::RecoveryIncomingWebhookReceive => Self::Webhooks,
 
             Flow::ApiKeyCreate
@@ -335,7 +354,27 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodSessionDeleteSavedPaymentMethod
             | Flow::PaymentMethodSessionUpdate => Self::PaymentMethodSession,
 
-            Flow::RevenueRecoveryRetrieve => Self::ProcessTracker,
+            Flow::RevenueRecoveryRetrieve
+            | Flow::RevenueRecoveryAnalytics
+            | Flow::RevenueRecoveryCollectionList
+            | Flow::RevenueRecoveryCollectionAssign
+            | Flow::RevenueRecoveryCollectionResolve
+            | Flow::RevenueRecoveryTaskList
+            | Flow::RevenueRecoveryReviewTaskList
+            | Flow::RevenueRecoveryReviewTaskRequeue
+            | Flow::RevenueRecoveryTaskBulkRequeue
+            | Flow::RevenueRecoveryKillSwitchRetrieve
+            | Flow::RevenueRecoveryKillSwitchUpdate
+            | Flow::RevenueRecoveryRetryScheduleCreate
+            | Flow::RevenueRecoveryRetryScheduleRetrieve
+            | Flow::RevenueRecoveryRetryScheduleUpdate
+            | Flow::RevenueRecoveryRetryScheduleDelete
+            | Flow::RevenueRecoveryReconciliationReportsRetrieve
+            | Flow::RevenueRecoveryOverdueTasksRetrieve
+            | Flow::RevenueRecoverySchedulePreview
+            | Flow::RevenueRecoveryOverviewRetrieve
+            | Flow::RevenueRecoveryTaskSnapshotRetrieve
+            | Flow::RevenueRecoveryBillingSyncDebug => Self::ProcessTracker,
         }
     }
 }
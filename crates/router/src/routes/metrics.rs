@@ -2,7 +2,7 @@ pub mod bg_metrics_collector;
 pub mod request;
 pub mod utils;
 
-use router_env::{counter_metric, global_meter, histogram_metric_f64};
+use router_env::{counter_metric, gauge_metric, global_meter, histogram_metric_f64};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
@@ -14,6 +14,7 @@ counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 counter_metric!(REQUEST_STATUS, GLOBAL_METER);
 histogram_metric_f64!(REQUEST_TIME, GLOBAL_METER);
 histogram_metric_f64!(EXTERNAL_REQUEST_TIME, GLOBAL_METER);
+counter_metric!(API_RATE_LIMITED, GLOBAL_METER); // No. of requests throttled by the API rate limits
 
 // Operation Level Metrics
 counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
@@ -117,6 +118,51 @@ counter_metric!(AUTO_PAYOUT_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_PAYOUT_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYOUT_COUNT, GLOBAL_METER);
 
+// Metrics for Revenue Recovery
+counter_metric!(REVENUE_RECOVERY_RETRY_OUTCOME_COUNT, GLOBAL_METER); // Outcome of internally triggered recovery retries
+counter_metric!(REVENUE_RECOVERY_ZERO_AMOUNT_INVOICE_COUNT, GLOBAL_METER); // Revenue recovery webhooks skipped for zero amount invoices
+counter_metric!(REVENUE_RECOVERY_CONNECTOR_FALLBACK_COUNT, GLOBAL_METER); // Recovery attempts recorded against the default payment connector of the profile
+counter_metric!(REVENUE_RECOVERY_KILL_SWITCH_TOGGLE_COUNT, GLOBAL_METER); // Changes made to the global kill switch of revenue recovery
+counter_metric!(REVENUE_RECOVERY_KILL_SWITCH_HALTED_TASK_COUNT, GLOBAL_METER); // Recovery tasks halted and rescheduled by the global kill switch
+counter_metric!(REVENUE_RECOVERY_TASK_EXECUTION_FAILURE_COUNT, GLOBAL_METER); // Executions of recovery tasks which failed with an error
+counter_metric!(REVENUE_RECOVERY_TASK_MOVED_TO_REVIEW_COUNT, GLOBAL_METER); // Recovery tasks moved to review after consecutive execution failures
+counter_metric!(REVENUE_RECOVERY_TASK_REQUEUED_COUNT, GLOBAL_METER); // Recovery tasks requeued from review
+counter_metric!(REVENUE_RECOVERY_TASK_BULK_REQUEUED_COUNT, GLOBAL_METER); // Stuck recovery tasks requeued in bulk
+counter_metric!(REVENUE_RECOVERY_CONNECTOR_SWITCH_COUNT, GLOBAL_METER); // Recovery retries switched to the next payment connector of the fallback
+counter_metric!(REVENUE_RECOVERY_THROTTLED_TASK_COUNT, GLOBAL_METER); // Recovery retries throttled and rescheduled by the concurrency limits
+counter_metric!(REVENUE_RECOVERY_RECONCILIATION_CHECKED_COUNT, GLOBAL_METER); // Open recovery invoices checked against the billing connector by the nightly reconciliation
+counter_metric!(REVENUE_RECOVERY_RECONCILIATION_DRIFT_COUNT, GLOBAL_METER); // Checked invoices found paid or voided at the billing connector, the drift rate being this over the checked count
+histogram_metric_f64!(REVENUE_RECOVERY_TASK_SCHEDULE_LAG, GLOBAL_METER); // Seconds between the schedule time of a recovery task and the start of its execution
+gauge_metric!(REVENUE_RECOVERY_OVERDUE_TASK_COUNT, GLOBAL_METER); // Recovery tasks not picked up by the scheduler long after their schedule time
+counter_metric!(REVENUE_RECOVERY_UNRESOLVED_CONNECTOR_COUNT, GLOBAL_METER); // Failed recovery payments held in review since their payment connector could not be resolved
+counter_metric!(REVENUE_RECOVERY_PAYMENTS_SYNC_CACHE_HIT_COUNT, GLOBAL_METER); // Billing connector payments sync calls served from the cached response of the transaction
+counter_metric!(
+    REVENUE_RECOVERY_PAYMENTS_SYNC_CACHE_MISS_COUNT,
+    GLOBAL_METER
+); // Billing connector payments sync calls which found no cached response of the transaction
+counter_metric!(
+    REVENUE_RECOVERY_PAYMENTS_SYNC_CIRCUIT_TRANSITION_COUNT,
+    GLOBAL_METER
+); // Circuits of the billing connector payments sync which were opened or closed
+counter_metric!(
+    REVENUE_RECOVERY_PAYMENTS_SYNC_SHORT_CIRCUITED_COUNT,
+    GLOBAL_METER
+); // Billing connector payments sync calls skipped as the circuit of the billing connector was open
+counter_metric!(
+    REVENUE_RECOVERY_PAYMENTS_SYNC_RATE_LIMITED_COUNT,
+    GLOBAL_METER
+); // Billing connector payments sync calls rate limited by the billing connector
+counter_metric!(
+    REVENUE_RECOVERY_PAYMENTS_SYNC_SECONDARY_CREDENTIAL_COUNT,
+    GLOBAL_METER
+); // Billing connector payments sync calls made using the secondary credential of the account
+histogram_metric_f64!(REVENUE_RECOVERY_PAYMENTS_SYNC_CALL_TIME, GLOBAL_METER); // Seconds taken by every call made to the billing connector for the payments sync, retries included
+counter_metric!(
+    REVENUE_RECOVERY_PAYMENTS_SYNC_CALL_OUTCOME_COUNT,
+    GLOBAL_METER
+); // Outcome of every call made to the billing connector for the payments sync, retries included
+counter_metric!(REVENUE_RECOVERY_PAYMENTS_SYNC_DECISION_COUNT, GLOBAL_METER); // Decisions on calling the billing connector payments sync for the recovery webhooks, by the source which made them
+
 // Scheduler / Process Tracker related metrics
 counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process tracker
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
@@ -2844,6 +2844,289 @@ pub async fn payments_capture(
     .await
 }
 
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::RevenueRecoveryStatus, payment_id))]
+pub async fn payments_recovery_status(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::GlobalPaymentId>,
+) -> impl Responder {
+    let flow = Flow::RevenueRecoveryStatus;
+
+    let global_payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", global_payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        global_payment_id,
+        |state, auth: auth::AuthenticationData, global_payment_id, _| {
+            crate::core::revenue_recovery::retrieve_revenue_recovery_status(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile,
+                global_payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuth {
+                permission: Permission::ProfileRevenueRecoveryRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::RevenueRecoveryDecisionList, payment_id))]
+pub async fn payments_recovery_decisions(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::GlobalPaymentId>,
+    query_params: web::Query<
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryDecisionListConstraints,
+    >,
+) -> impl Responder {
+    let flow = Flow::RevenueRecoveryDecisionList;
+
+    let global_payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", global_payment_id.get_string_repr());
+
+    let payload = internal_payload_types::PaymentsGenericRequestWithResourceId {
+        global_payment_id,
+        payload: query_params.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            crate::core::revenue_recovery::list_revenue_recovery_decisions(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile,
+                req.global_payment_id,
+                req.payload,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuth {
+                permission: Permission::ProfileRevenueRecoveryRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::RevenueRecoveryRetryOverride, payment_id))]
+pub async fn payments_recovery_override(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryOverrideRequest,
+    >,
+    path: web::Path<common_utils::id_type::GlobalPaymentId>,
+) -> impl Responder {
+    let flow = Flow::RevenueRecoveryRetryOverride;
+
+    let global_payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", global_payment_id.get_string_repr());
+
+    let internal_payload = internal_payload_types::PaymentsGenericRequestWithResourceId {
+        global_payment_id,
+        payload: payload.into_inner(),
+    };
+
+    let locking_action = internal_payload.get_locking_input(flow.clone());
+
+    // Overrides created from the dashboard are attributed to the user, the ones created using the
+    // admin api key are attributed to the admin api
+    if auth::is_jwt_auth(req.headers()) {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            internal_payload,
+            |state, user_from_token: auth::UserFromToken, req, _| {
+                crate::core::revenue_recovery::create_revenue_recovery_retry_override_for_user(
+                    state,
+                    user_from_token,
+                    req.global_payment_id,
+                    req.payload,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileRevenueRecoveryWrite,
+            },
+            locking_action,
+        ))
+        .await
+    } else {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            internal_payload,
+            |state, auth: auth::AuthenticationData, req, _| {
+                crate::core::revenue_recovery::create_revenue_recovery_retry_override(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    auth.profile,
+                    req.global_payment_id,
+                    "admin_api".to_string(),
+                    req.payload,
+                )
+            },
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            locking_action,
+        ))
+        .await
+    }
+}
+
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::RevenueRecoveryReschedule, payment_id))]
+pub async fn payments_recovery_reschedule(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRescheduleRequest,
+    >,
+    path: web::Path<common_utils::id_type::GlobalPaymentId>,
+) -> impl Responder {
+    let flow = Flow::RevenueRecoveryReschedule;
+
+    let global_payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", global_payment_id.get_string_repr());
+
+    let internal_payload = internal_payload_types::PaymentsGenericRequestWithResourceId {
+        global_payment_id,
+        payload: payload.into_inner(),
+    };
+
+    let locking_action = internal_payload.get_locking_input(flow.clone());
+
+    if auth::is_jwt_auth(req.headers()) {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            internal_payload,
+            |state, user_from_token: auth::UserFromToken, req, _| {
+                crate::core::revenue_recovery::reschedule_revenue_recovery_task_for_user(
+                    state,
+                    user_from_token,
+                    req.global_payment_id,
+                    req.payload,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileRevenueRecoveryWrite,
+            },
+            locking_action,
+        ))
+        .await
+    } else {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            internal_payload,
+            |state, auth: auth::AuthenticationData, req, _| {
+                crate::core::revenue_recovery::reschedule_revenue_recovery_task(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    auth.profile,
+                    req.global_payment_id,
+                    "admin_api".to_string(),
+                    req.payload,
+                )
+            },
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            locking_action,
+        ))
+        .await
+    }
+}
+
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::RevenueRecoveryCancel, payment_id))]
+pub async fn payments_recovery_cancel(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::GlobalPaymentId>,
+) -> impl Responder {
+    let flow = Flow::RevenueRecoveryCancel;
+
+    let global_payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", global_payment_id.get_string_repr());
+
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: global_payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    // Cancellations made from the dashboard are attributed to the user, the ones made using the
+    // admin api key are attributed to the admin api
+    if auth::is_jwt_auth(req.headers()) {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            global_payment_id,
+            |state, user_from_token: auth::UserFromToken, global_payment_id, _| {
+                crate::core::revenue_recovery::cancel_revenue_recovery_task_for_user(
+                    state,
+                    user_from_token,
+                    global_payment_id,
+                )
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileRevenueRecoveryWrite,
+            },
+            locking_action,
+        ))
+        .await
+    } else {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            global_payment_id,
+            |state, auth: auth::AuthenticationData, global_payment_id, _| {
+                crate::core::revenue_recovery::cancel_revenue_recovery_task(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    auth.profile,
+                    global_payment_id,
+                    "admin_api".to_string(),
+                )
+            },
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            locking_action,
+        ))
+        .await
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsList))]
 pub async fn list_payment_methods(
@@ -24,9 +24,10 @@ pub async fn revenue_recovery_pt_retrieve_api(
         state,
         &req,
         payload,
-        |state, _: (), id, _| {
+        |state, user_from_token: auth::UserFromToken, id, _| {
             revenue_recovery::retrieve_revenue_recovery_process_tracker(
                 state,
+                user_from_token,
                 id.revenue_recovery_id,
             )
         },
@@ -37,3 +38,530 @@ pub async fn revenue_recovery_pt_retrieve_api(
     ))
     .await
 }
+
+pub async fn revenue_recovery_analytics_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<revenue_recovery_api::RevenueRecoveryAnalyticsRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryAnalytics;
+    let payload = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            revenue_recovery::retrieve_revenue_recovery_analytics(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile,
+                req,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileRevenueRecoveryRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_collections_list_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<revenue_recovery_api::RevenueRecoveryCollectionListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryCollectionList;
+    let payload = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, user_from_token: auth::UserFromToken, constraints, _| {
+            revenue_recovery::list_revenue_recovery_collection_items(
+                state,
+                user_from_token,
+                constraints,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileRevenueRecoveryRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_collection_assign_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoveryCollectionAssignRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryCollectionAssign;
+    let mut payload = json_payload.into_inner();
+    payload.collection_item_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, user_from_token: auth::UserFromToken, req, _| {
+            revenue_recovery::assign_revenue_recovery_collection_item(state, user_from_token, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileRevenueRecoveryWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_collection_resolve_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoveryCollectionResolveRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryCollectionResolve;
+    let mut payload = json_payload.into_inner();
+    payload.collection_item_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, user_from_token: auth::UserFromToken, req, _| {
+            revenue_recovery::resolve_revenue_recovery_collection_item(state, user_from_token, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileRevenueRecoveryWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_tasks_list_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<revenue_recovery_api::RevenueRecoveryTaskListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryTaskList;
+    let merchant_id = path.into_inner();
+    let payload = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationDataWithoutProfile, constraints, _| {
+            revenue_recovery::list_revenue_recovery_tasks(
+                state,
+                auth.merchant_account.get_id().clone(),
+                constraints,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_review_tasks_list_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<revenue_recovery_api::RevenueRecoveryReviewTaskListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryReviewTaskList;
+    let merchant_id = path.into_inner();
+    let payload = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationDataWithoutProfile, constraints, _| {
+            revenue_recovery::dead_letter::list_review_tasks(
+                state,
+                auth.merchant_account.get_id().clone(),
+                constraints,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_review_task_requeue_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryReviewTaskRequeue;
+    let (merchant_id, task_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        task_id,
+        |state, auth: auth::AuthenticationDataWithoutProfile, task_id, req_state| {
+            revenue_recovery::dead_letter::requeue_review_task(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                task_id,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_task_snapshot_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryTaskSnapshotRetrieve;
+    let (merchant_id, task_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        task_id,
+        |state, auth: auth::AuthenticationDataWithoutProfile, task_id, _| {
+            revenue_recovery::task_snapshot::retrieve_task_snapshot(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                task_id,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_billing_sync_debug_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoveryBillingSyncRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryBillingSyncDebug;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationDataWithoutProfile, request, _| {
+            revenue_recovery::billing_sync_debug::sync_billing_connector_payment(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                request,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_tasks_bulk_requeue_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoveryTaskBulkRequeueRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryTaskBulkRequeue;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationDataWithoutProfile, request, _| {
+            revenue_recovery::bulk_requeue::bulk_requeue_stuck_tasks(
+                state,
+                auth.merchant_account.get_id().clone(),
+                request,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_kill_switch_retrieve_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryKillSwitchRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| revenue_recovery::kill_switch::retrieve_kill_switch(state),
+        &auth::V2AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_kill_switch_update_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoveryKillSwitchRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryKillSwitchUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| revenue_recovery::kill_switch::update_kill_switch(state, request),
+        &auth::V2AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_retry_schedule_create_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoveryRetryScheduleRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryRetryScheduleCreate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationDataWithoutProfile, request, _| {
+            revenue_recovery::retry_schedule::create_retry_schedule(
+                state,
+                auth.merchant_account.get_id().clone(),
+                request,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_retry_schedule_retrieve_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryRetryScheduleRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationDataWithoutProfile, _, _| {
+            revenue_recovery::retry_schedule::retrieve_retry_schedule(
+                state,
+                auth.merchant_account.get_id().clone(),
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_retry_schedule_update_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoveryRetryScheduleRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryRetryScheduleUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationDataWithoutProfile, request, _| {
+            revenue_recovery::retry_schedule::update_retry_schedule(
+                state,
+                auth.merchant_account.get_id().clone(),
+                request,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_retry_schedule_delete_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryRetryScheduleDelete;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationDataWithoutProfile, _, _| {
+            revenue_recovery::retry_schedule::delete_retry_schedule(
+                state,
+                auth.merchant_account.get_id().clone(),
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_reconciliation_reports_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryReconciliationReportsRetrieve;
+    let (merchant_id, billing_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        billing_connector_id,
+        |state, auth: auth::AuthenticationDataWithoutProfile, billing_connector_id, _| {
+            revenue_recovery::reconciliation::retrieve_reconciliation_reports(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                billing_connector_id,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_overdue_tasks_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryOverdueTasksRetrieve;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationDataWithoutProfile, _, _| {
+            revenue_recovery::scheduler_lag::retrieve_overdue_tasks(
+                state,
+                auth.merchant_account.get_id().clone(),
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_schedule_preview_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<revenue_recovery_api::RevenueRecoverySchedulePreviewRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoverySchedulePreview;
+    let payload = json_payload.into_inner();
+    let merchant_id = payload.merchant_id.clone();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationDataWithoutProfile, payload, _| {
+            revenue_recovery::schedule_preview::preview_retry_schedule(
+                state,
+                auth.merchant_account.get_id().clone(),
+                payload,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revenue_recovery_overview_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<revenue_recovery_api::RevenueRecoveryOverviewRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevenueRecoveryOverviewRetrieve;
+    let merchant_id = path.into_inner();
+    let payload = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationDataWithoutProfile, request, _| {
+            revenue_recovery::overview::retrieve_recovery_overview(
+                state,
+                auth.merchant_account.get_id().clone(),
+                request,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
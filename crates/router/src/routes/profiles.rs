@@ -107,15 +107,17 @@ pub async fn profile_retrieve(
     .await
 }
 
-#[cfg(feature = "v2")]
+#[cfg(all(feature = "olap", feature = "v2"))]
 #[instrument(skip_all, fields(flow = ?Flow::ProfileRetrieve))]
 pub async fn profile_retrieve(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::ProfileId>,
+    query: web::Query<api_models::admin::ProfileRetrieveQuery>,
 ) -> HttpResponse {
     let flow = Flow::ProfileRetrieve;
     let profile_id = path.into_inner();
+    let include_capabilities = query.into_inner().include_capabilities.unwrap_or(false);
 
     Box::pin(api::server_wrap(
         flow,
@@ -123,7 +125,7 @@ pub async fn profile_retrieve(
         &req,
         profile_id,
         |state, auth::AuthenticationDataWithoutProfile { key_store, .. }, profile_id, _| {
-            retrieve_profile(state, profile_id, key_store)
+            retrieve_profile(state, profile_id, key_store, include_capabilities)
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
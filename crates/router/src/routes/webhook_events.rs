@@ -1,8 +1,14 @@
-use actix_web::{web, HttpRequest, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use error_stack::ResultExt;
 use router_env::{instrument, tracing, Flow};
 
+#[cfg(feature = "v1")]
+use crate::core::webhooks::{bulk_retry, delivery_stats, event_export, webhook_events_retention};
 use crate::{
-    core::{api_locking, webhooks::webhook_events},
+    core::{
+        api_locking, errors,
+        webhooks::{event_subscription, event_type_catalog, webhook_events, webhook_url_change},
+    },
     routes::AppState,
     services::{
         api,
@@ -10,8 +16,18 @@ use crate::{
         authorization::permissions::Permission,
     },
     types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        EventDeliveryAttemptListConstraints, EventExportRequestInternal, EventListConstraints,
+        EventListRequestInternal, EventPayloadScrubRequestInternal, EventRetrieveConstraints,
+        EventRetrieveRequestInternal, EventTypeCatalogRequestInternal,
+        EventsArchiveListConstraints, EventsArchiveListRequestInternal,
+        EventsRetentionScheduleRequestInternal, WebhookBulkRetryJobRetrieveRequestInternal,
+        WebhookBulkRetryRequest, WebhookBulkRetryRequestInternal,
+        WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryAttemptPayloadRequestInternal,
+        WebhookDeliveryRetryRequest, WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryStatsRequest, WebhookDeliveryStatsRequestInternal,
+        WebhookEventSubscriptionUpdateRequest, WebhookEventSubscriptionUpdateRequestInternal,
+        WebhookRetryPolicyPreviewRequest, WebhookUrlChangeConfirmRequest,
+        WebhookUrlChangeConfirmRequestInternal,
     },
 };
 
@@ -20,11 +36,13 @@ pub async fn list_initial_webhook_delivery_attempts(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::MerchantId>,
-    query: web::Query<EventListConstraints>,
-) -> impl Responder {
+) -> HttpResponse {
     let flow = Flow::WebhookEventInitialDeliveryAttemptList;
     let merchant_id = path.into_inner();
-    let constraints = query.into_inner();
+    let constraints = match get_event_list_constraints(req.query_string()) {
+        Ok(constraints) => constraints,
+        Err(error) => return api::log_and_return_error_response(error),
+    };
 
     let request_internal = EventListRequestInternal {
         merchant_id: merchant_id.clone(),
@@ -60,10 +78,12 @@ pub async fn list_initial_webhook_delivery_attempts(
 pub async fn list_initial_webhook_delivery_attempts_with_jwtauth(
     state: web::Data<AppState>,
     req: HttpRequest,
-    query: web::Query<EventListConstraints>,
-) -> impl Responder {
+) -> HttpResponse {
     let flow = Flow::WebhookEventInitialDeliveryAttemptList;
-    let constraints = query.into_inner();
+    let constraints = match get_event_list_constraints(req.query_string()) {
+        Ok(constraints) => constraints,
+        Err(error) => return api::log_and_return_error_response(error),
+    };
 
     let request_internal = EventListRequestInternal {
         merchant_id: common_utils::id_type::MerchantId::default(),
@@ -96,18 +116,371 @@ pub async fn list_initial_webhook_delivery_attempts_with_jwtauth(
     .await
 }
 
+/// Constraints of the events list in the query string. The `event_types` parameter can be repeated,
+/// its values are taken together as a comma separated list.
+fn get_event_list_constraints(query_string: &str) -> errors::RouterResult<EventListConstraints> {
+    parse_event_constraints(query_string, "listing the events")
+}
+
+fn parse_event_constraints<T: serde::de::DeserializeOwned>(
+    query_string: &str,
+    operation: &str,
+) -> errors::RouterResult<T> {
+    let (event_types, other_params): (Vec<_>, Vec<_>) =
+        url::form_urlencoded::parse(query_string.as_bytes())
+            .partition(|(key, _)| key == "event_types");
+
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.extend_pairs(other_params);
+    if !event_types.is_empty() {
+        let event_types = event_types
+            .into_iter()
+            .map(|(_, event_type)| event_type)
+            .collect::<Vec<_>>()
+            .join(",");
+        query.append_pair("event_types", &event_types);
+    }
+
+    serde_urlencoded::from_str(&query.finish()).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid query parameters for {operation}"),
+        },
+    )
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventExport))]
+#[cfg(feature = "v1")]
+pub async fn export_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEventExport;
+    let merchant_id = path.into_inner();
+    // The event types of the export can be repeated in the query string, as for the events list
+    let constraints = match parse_event_constraints(req.query_string(), "exporting the events") {
+        Ok(constraints) => constraints,
+        Err(error) => return api::log_and_return_error_response(error),
+    };
+
+    let request_internal = EventExportRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            event_export::export_events(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsRetentionSchedule))]
+#[cfg(feature = "v1")]
+pub async fn schedule_events_retention(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventsRetentionSchedule;
+    let merchant_id = path.into_inner();
+
+    let request_internal = EventsRetentionScheduleRequestInternal {
+        merchant_id: merchant_id.clone(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events_retention::schedule_events_retention(state, request_internal.merchant_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsArchiveList))]
+#[cfg(feature = "v1")]
+pub async fn list_events_archives(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<EventsArchiveListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventsArchiveList;
+    let merchant_id = path.into_inner();
+
+    let request_internal = EventsArchiveListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events_retention::list_events_archives(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetrieve))]
+pub async fn retrieve_webhook_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    query: web::Query<EventRetrieveConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventRetrieve;
+    let (merchant_id, event_id) = path.into_inner();
+
+    let request_internal = EventRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+        profile_id: None,
+        event_id,
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| webhook_events::retrieve_event(state, request_internal),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetrieve))]
+pub async fn retrieve_webhook_event_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<EventRetrieveConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventRetrieve;
+
+    let request_internal = EventRetrieveRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        profile_id: None,
+        event_id: path.into_inner(),
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth: UserFromToken, mut request_internal, _| {
+            request_internal.merchant_id = auth.merchant_id;
+            request_internal.profile_id = Some(auth.profile_id);
+
+            webhook_events::retrieve_event(state, request_internal)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryAttemptList))]
 pub async fn list_webhook_delivery_attempts(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<(common_utils::id_type::MerchantId, String)>,
-) -> impl Responder {
+    query: web::Query<EventDeliveryAttemptListConstraints>,
+) -> HttpResponse {
     let flow = Flow::WebhookEventDeliveryAttemptList;
     let (merchant_id, initial_attempt_id) = path.into_inner();
 
     let request_internal = WebhookDeliveryAttemptListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        profile_id: None,
+        initial_attempt_id,
+        constraints: query.into_inner(),
+    };
+    let jwt_auth = auth::JWTAuthMerchantFromRoute {
+        merchant_id,
+        required_permission: Permission::MerchantWebhookEventRead,
+    };
+    let auth_type = auth::auth_type(&auth::AdminApiAuth, &jwt_auth, req.headers());
+
+    // The complete payloads of the attempts are listed for the consumers yet to move to retrieving
+    // the payloads of the attempts separately
+    if request_internal.constraints.include_payloads {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            request_internal,
+            |state, _, request_internal, _| {
+                webhook_events::list_delivery_attempts_with_payloads(
+                    state,
+                    request_internal.merchant_id,
+                    request_internal.profile_id,
+                    request_internal.initial_attempt_id,
+                    request_internal.constraints.paginated,
+                )
+            },
+            auth_type,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    } else {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            request_internal,
+            |state, _, request_internal, _| {
+                webhook_events::list_delivery_attempts(
+                    state,
+                    request_internal.merchant_id,
+                    request_internal.profile_id,
+                    request_internal.initial_attempt_id,
+                    request_internal.constraints.paginated,
+                )
+            },
+            auth_type,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryAttemptList))]
+pub async fn list_webhook_delivery_attempts_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<EventDeliveryAttemptListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEventDeliveryAttemptList;
+
+    let request_internal = WebhookDeliveryAttemptListRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        profile_id: None,
+        initial_attempt_id: path.into_inner(),
+        constraints: query.into_inner(),
+    };
+
+    let jwt_auth = auth::JWTAuth {
+        permission: Permission::ProfileWebhookEventRead,
+    };
+
+    // The complete payloads of the attempts are listed for the consumers yet to move to retrieving
+    // the payloads of the attempts separately
+    if request_internal.constraints.include_payloads {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            request_internal,
+            |state, auth: UserFromToken, request_internal, _| {
+                webhook_events::list_delivery_attempts_with_payloads(
+                    state,
+                    auth.merchant_id,
+                    Some(auth.profile_id),
+                    request_internal.initial_attempt_id,
+                    request_internal.constraints.paginated,
+                )
+            },
+            &jwt_auth,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    } else {
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            request_internal,
+            |state, auth: UserFromToken, request_internal, _| {
+                webhook_events::list_delivery_attempts(
+                    state,
+                    auth.merchant_id,
+                    Some(auth.profile_id),
+                    request_internal.initial_attempt_id,
+                    request_internal.constraints.paginated,
+                )
+            },
+            &jwt_auth,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryAttemptPayloadRetrieve))]
+pub async fn retrieve_webhook_delivery_attempt_payload(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryAttemptPayloadRetrieve;
+    let (merchant_id, initial_attempt_id, attempt_id) = path.into_inner();
+
+    let request_internal = WebhookDeliveryAttemptPayloadRequestInternal {
         merchant_id: merchant_id.clone(),
         initial_attempt_id,
+        attempt_id,
     };
 
     Box::pin(api::server_wrap(
@@ -116,10 +489,11 @@ pub async fn list_webhook_delivery_attempts(
         &req,
         request_internal,
         |state, _, request_internal, _| {
-            webhook_events::list_delivery_attempts(
+            webhook_events::retrieve_delivery_attempt_payload(
                 state,
                 request_internal.merchant_id,
                 request_internal.initial_attempt_id,
+                request_internal.attempt_id,
             )
         },
         auth::auth_type(
@@ -141,12 +515,14 @@ pub async fn retry_webhook_delivery_attempt(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    body: web::Bytes,
 ) -> impl Responder {
     let flow = Flow::WebhookEventDeliveryRetry;
     let (merchant_id, event_id) = path.into_inner();
 
     let request_internal = WebhookDeliveryRetryRequestInternal {
         merchant_id: merchant_id.clone(),
+        profile_id: None,
         event_id,
     };
 
@@ -156,10 +532,113 @@ pub async fn retry_webhook_delivery_attempt(
         &req,
         request_internal,
         |state, _, request_internal, _| {
-            webhook_events::retry_delivery_attempt(
+            // The body is parsed only once the request is authenticated
+            let retry_request = get_delivery_retry_request(&body);
+            async move {
+                webhook_events::retry_delivery_attempt(
+                    state,
+                    request_internal.merchant_id,
+                    request_internal.profile_id,
+                    request_internal.event_id,
+                    retry_request?.override_url,
+                )
+                .await
+            }
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryRetry))]
+#[cfg(feature = "v1")]
+pub async fn retry_webhook_delivery_attempt_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Bytes,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryRetry;
+
+    let request_internal = WebhookDeliveryRetryRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        profile_id: None,
+        event_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth: UserFromToken, request_internal, _| {
+            // The body is parsed only once the request is authenticated
+            let retry_request = get_delivery_retry_request(&body);
+            async move {
+                webhook_events::retry_delivery_attempt(
+                    state,
+                    auth.merchant_id,
+                    Some(auth.profile_id),
+                    request_internal.event_id,
+                    retry_request?.override_url,
+                )
+                .await
+            }
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// The request body is optional, a retry without a body is delivered to the configured webhook URL
+#[cfg(feature = "v1")]
+fn get_delivery_retry_request(body: &[u8]) -> errors::RouterResult<WebhookDeliveryRetryRequest> {
+    if body.is_empty() {
+        return Ok(WebhookDeliveryRetryRequest::default());
+    }
+
+    serde_json::from_slice(body).change_context(errors::ApiErrorResponse::InvalidRequestData {
+        message: "Invalid request body for retrying the delivery of the event".to_string(),
+    })
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventBulkRetry))]
+#[cfg(feature = "v1")]
+pub async fn bulk_retry_webhook_deliveries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<WebhookBulkRetryRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventBulkRetry;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookBulkRetryRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            bulk_retry::create_bulk_retry_job(
                 state,
                 request_internal.merchant_id,
-                request_internal.event_id,
+                request_internal.request,
             )
         },
         auth::auth_type(
@@ -174,3 +653,393 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventBulkRetryJobRetrieve))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_webhook_bulk_retry_job(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventBulkRetryJobRetrieve;
+    let (merchant_id, job_id) = path.into_inner();
+
+    let request_internal = WebhookBulkRetryJobRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+        job_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            bulk_retry::retrieve_bulk_retry_job(
+                state,
+                request_internal.merchant_id,
+                request_internal.job_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookDeliveryStats))]
+#[cfg(feature = "v1")]
+pub async fn get_webhook_delivery_stats(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<WebhookDeliveryStatsRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookDeliveryStats;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookDeliveryStatsRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            delivery_stats::get_delivery_stats(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookDeliveryStats))]
+#[cfg(feature = "v1")]
+pub async fn get_webhook_delivery_stats_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<WebhookDeliveryStatsRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookDeliveryStats;
+
+    let request_internal = WebhookDeliveryStatsRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        request: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth: UserFromToken, mut request_internal, _| {
+            request_internal.merchant_id = auth.merchant_id;
+            request_internal.request.profile_id = Some(auth.profile_id);
+
+            delivery_stats::get_delivery_stats(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookRetryPolicyPreview))]
+#[cfg(feature = "v1")]
+pub async fn preview_retry_policy_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<WebhookRetryPolicyPreviewRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookRetryPolicyPreview;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: UserFromToken, payload, _| {
+            webhook_events::preview_retry_policy(state, auth.merchant_id, payload)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventPayloadScrub))]
+pub async fn scrub_event_payload_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventPayloadScrub;
+    let event_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        event_id,
+        |state, auth: UserFromToken, event_id, req_state| {
+            let request_internal = EventPayloadScrubRequestInternal {
+                merchant_id: auth.merchant_id,
+                profile_id: auth.profile_id,
+                event_id,
+            };
+
+            webhook_events::scrub_event_payload(state, req_state, request_internal, auth.user_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookUrlChangeConfirm))]
+pub async fn confirm_webhook_url_change_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<WebhookUrlChangeConfirmRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookUrlChangeConfirm;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: UserFromToken, payload, _| {
+            let request_internal = WebhookUrlChangeConfirmRequestInternal {
+                merchant_id: auth.merchant_id,
+                profile_id: auth.profile_id,
+                verification_token: Some(payload.verification_token),
+                force_confirm: false,
+            };
+
+            webhook_url_change::confirm_webhook_url_change(state, request_internal)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookUrlChangeForceConfirm))]
+pub async fn force_confirm_webhook_url_change(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookUrlChangeForceConfirm;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    let request_internal = WebhookUrlChangeConfirmRequestInternal {
+        merchant_id,
+        profile_id,
+        verification_token: None,
+        force_confirm: true,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_url_change::confirm_webhook_url_change(state, request_internal)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventSubscriptionUpdate))]
+pub async fn update_event_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<WebhookEventSubscriptionUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventSubscriptionUpdate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    let request_internal = WebhookEventSubscriptionUpdateRequestInternal {
+        merchant_id,
+        profile_id,
+        enabled_event_types: json_payload.into_inner().enabled_event_types,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            event_subscription::update_event_subscription(state, request_internal)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventSubscriptionUpdate))]
+pub async fn update_event_subscription_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<WebhookEventSubscriptionUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventSubscriptionUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: UserFromToken, payload, _| {
+            let request_internal = WebhookEventSubscriptionUpdateRequestInternal {
+                merchant_id: auth.merchant_id,
+                profile_id: auth.profile_id,
+                enabled_event_types: payload.enabled_event_types,
+            };
+
+            event_subscription::update_event_subscription(state, request_internal)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventTypeList))]
+pub async fn list_event_types(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::WebhookEventTypeList;
+
+    let request_internal = EventTypeCatalogRequestInternal {
+        merchant_id: None,
+        profile_id: None,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, (), request_internal, _| {
+            event_type_catalog::list_event_types(state, request_internal)
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventTypeList))]
+pub async fn list_event_types_with_jwtauth(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::WebhookEventTypeList;
+
+    let request_internal = EventTypeCatalogRequestInternal {
+        merchant_id: None,
+        profile_id: None,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth: UserFromToken, mut request_internal, _| {
+            request_internal.merchant_id = Some(auth.merchant_id);
+            request_internal.profile_id = Some(auth.profile_id);
+
+            event_type_catalog::list_event_types(state, request_internal)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_enums::{EventClass, EventType};
+
+    use super::*;
+
+    #[test]
+    fn test_event_types_are_taken_from_repeated_and_comma_separated_params() {
+        let constraints = get_event_list_constraints(
+            "object_id=pay_1&event_types=payment_succeeded&is_delivered=false\
+             &event_types=payment_failed,refund_succeeded&event_class=payments",
+        )
+        .unwrap();
+        assert_eq!(
+            constraints.event_types,
+            Some(vec![
+                EventType::PaymentSucceeded,
+                EventType::PaymentFailed,
+                EventType::RefundSucceeded,
+            ])
+        );
+        assert_eq!(constraints.event_class, Some(EventClass::Payments));
+        assert_eq!(constraints.object_id.as_deref(), Some("pay_1"));
+        assert_eq!(constraints.is_delivered, Some(false));
+
+        assert!(get_event_list_constraints("")
+            .unwrap()
+            .event_types
+            .is_none());
+        assert!(get_event_list_constraints("event_types=payment_done").is_err());
+    }
+}
@@ -14,7 +14,7 @@ use std::{
 use actix_http::header::HeaderMap;
 use actix_web::{
     body,
-    http::header::{HeaderName, HeaderValue},
+    http::header::{ContentDisposition, HeaderName, HeaderValue},
     web, FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
 };
 pub use client::{ApiClient, MockApiClient, ProxyClient};
@@ -29,9 +29,9 @@ use error_stack::{report, Report, ResultExt};
 use hyperswitch_domain_models::router_data_v2::flow_common_types as common_types;
 pub use hyperswitch_domain_models::{
     api::{
-        ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
-        GenericLinks, PaymentLinkAction, PaymentLinkFormData, PaymentLinkStatusData,
-        RedirectionFormData,
+        ApplicationResponse, FileStreamData, GenericExpiredLinkData, GenericLinkFormData,
+        GenericLinkStatusData, GenericLinks, PaymentLinkAction, PaymentLinkFormData,
+        PaymentLinkStatusData, RedirectionFormData,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -61,7 +61,7 @@ use crate::{
     configs::Settings,
     consts,
     core::{
-        api_locking,
+        api_locking, api_rate_limit,
         errors::{self, CustomResult},
         payments,
     },
@@ -118,10 +118,17 @@ pub type BoxedBillingConnectorPaymentsSyncIntegrationInterface<T, Req, Res> =
         Res,
     >;
 
+pub type BoxedBillingConnectorInvoiceSyncIntegrationInterface<T, Req, Res> =
+    BoxedConnectorIntegrationInterface<
+        T,
+        common_types::BillingConnectorInvoiceSyncFlowData,
+        Req,
+        Res,
+    >;
+
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
 /// In other cases, It will be created if required, even if it is not passed
-#[instrument(skip_all, fields(connector_name, payment_method))]
 pub async fn execute_connector_processing_step<
     'b,
     'a,
@@ -139,6 +146,41 @@ pub async fn execute_connector_processing_step<
 where
     T: Clone + Debug + 'static,
     // BoxedConnectorIntegration<T, Req, Resp>: 'b,
+{
+    execute_connector_processing_step_with_timeout(
+        state,
+        connector_integration,
+        req,
+        call_connector_action,
+        connector_request,
+        None,
+        None,
+    )
+    .await
+}
+
+/// Same as [`execute_connector_processing_step`], with the call to the connector timed out after
+/// `option_timeout_secs` instead of the default request timeout, and sent through the
+/// `outbound_proxy` of the merchant instead of the proxy of the deployment
+#[instrument(skip_all, fields(connector_name, payment_method))]
+pub async fn execute_connector_processing_step_with_timeout<
+    'b,
+    'a,
+    T,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+>(
+    state: &'b SessionState,
+    connector_integration: BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &'b types::RouterData<T, Req, Resp>,
+    call_connector_action: payments::CallConnectorAction,
+    connector_request: Option<Request>,
+    option_timeout_secs: Option<u64>,
+    outbound_proxy: Option<&client::OutboundProxy>,
+) -> CustomResult<types::RouterData<T, Req, Resp>, errors::ConnectorError>
+where
+    T: Clone + Debug + 'static,
 {
     // If needed add an error stack as follows
     // connector_integration.build_request(req).attach_printable("Failed to build request");
@@ -233,9 +275,14 @@ where
                     let request_url = request.url.clone();
                     let request_method = request.method;
                     let current_time = Instant::now();
-                    let response =
-                        call_connector_api(state, request, "execute_connector_processing_step")
-                            .await;
+                    let response = call_connector_api_with_timeout(
+                        state,
+                        request,
+                        "execute_connector_processing_step",
+                        option_timeout_secs,
+                        outbound_proxy,
+                    )
+                    .await;
                     let external_latency = current_time.elapsed().as_millis();
                     logger::info!(raw_connector_request=?masked_request_body);
                     let status_code = response
@@ -393,14 +440,40 @@ pub async fn call_connector_api(
     state: &SessionState,
     request: Request,
     flow_name: &str,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    call_connector_api_with_timeout(state, request, flow_name, None, None).await
+}
+
+#[instrument(skip_all)]
+pub async fn call_connector_api_with_timeout(
+    state: &SessionState,
+    request: Request,
+    flow_name: &str,
+    option_timeout_secs: Option<u64>,
+    outbound_proxy: Option<&client::OutboundProxy>,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let current_time = Instant::now();
     let headers = request.headers.clone();
     let url = request.url.clone();
-    let response = state
-        .api_client
-        .send_request(state, request, None, true)
-        .await;
+    let response = match outbound_proxy {
+        // The api client is set up with the proxy of the deployment, so the request is sent using
+        // a client set up with the proxy of the merchant instead
+        Some(outbound_proxy) => {
+            send_request_with_outbound_proxy(
+                state,
+                request,
+                option_timeout_secs,
+                Some(outbound_proxy),
+            )
+            .await
+        }
+        None => {
+            state
+                .api_client
+                .send_request(state, request, option_timeout_secs, true)
+                .await
+        }
+    };
 
     match response.as_ref() {
         Ok(resp) => {
@@ -429,17 +502,35 @@ pub async fn send_request(
     state: &SessionState,
     request: Request,
     option_timeout_secs: Option<u64>,
+) -> CustomResult<reqwest::Response, errors::ApiClientError> {
+    send_request_with_outbound_proxy(state, request, option_timeout_secs, None).await
+}
+
+#[instrument(skip_all)]
+pub async fn send_request_with_outbound_proxy(
+    state: &SessionState,
+    request: Request,
+    option_timeout_secs: Option<u64>,
+    outbound_proxy: Option<&client::OutboundProxy>,
 ) -> CustomResult<reqwest::Response, errors::ApiClientError> {
     logger::info!(method=?request.method, headers=?request.headers, payload=?request.body, ?request);
 
     let url =
         url::Url::parse(&request.url).change_context(errors::ApiClientError::UrlParsingFailed)?;
 
-    let client = client::create_client(
-        &state.conf.proxy,
-        request.certificate,
-        request.certificate_key,
-    )?;
+    let client = match outbound_proxy {
+        Some(outbound_proxy) => client::create_client_with_outbound_proxy(
+            &state.conf.proxy,
+            outbound_proxy,
+            request.certificate,
+            request.certificate_key,
+        ),
+        None => client::create_client(
+            &state.conf.proxy,
+            request.certificate,
+            request.certificate_key,
+        ),
+    }?;
 
     let headers = request.headers.construct_header_map()?;
     let metrics_tag = router_env::metric_attributes!((
@@ -795,20 +886,29 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
-    let output = {
-        lock_action
-            .clone()
-            .perform_locking_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
+    let rate_limit_result =
+        api_rate_limit::check_rate_limit(&session_state, flow.get_rate_limited_api(), &auth_type)
             .await
             .switch();
-        lock_action
-            .free_lock_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        res
+
+    let output = match rate_limit_result {
+        Ok(()) => {
+            lock_action
+                .clone()
+                .perform_locking_action(&session_state, merchant_id.to_owned())
+                .await
+                .switch()?;
+            let res = func(session_state.clone(), auth_out, payload, request_state)
+                .await
+                .switch();
+            lock_action
+                .free_lock_action(&session_state, merchant_id.to_owned())
+                .await
+                .switch()?;
+            res
+        }
+        // Throttled requests are recorded as API events, unlike the authentication failures
+        Err(error) => Err(error),
     };
     let request_duration = Instant::now()
         .saturating_duration_since(start_instant)
@@ -967,6 +1067,7 @@ where
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
+        Ok(ApplicationResponse::FileStream(file_stream)) => http_response_file_stream(*file_stream),
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -1191,6 +1292,13 @@ pub fn http_response_file_data<T: body::MessageBody + 'static>(
     HttpResponse::Ok().content_type(content_type).body(res)
 }
 
+pub fn http_response_file_stream(file_stream: FileStreamData) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(file_stream.content_type)
+        .insert_header(ContentDisposition::attachment(file_stream.file_name))
+        .streaming(file_stream.stream)
+}
+
 pub fn http_response_html_data<T: body::MessageBody + 'static>(
     res: T,
     optional_headers: Option<HashSet<(&'static str, String)>>,
@@ -98,6 +98,65 @@ pub fn create_client(
     }
 }
 
+/// Outbound proxy of a merchant, used for its connector calls in place of the proxy configured for
+/// the deployment
+#[derive(Clone, Debug)]
+pub struct OutboundProxy {
+    pub url: String,
+    pub username: Option<masking::Secret<String>>,
+    pub password: Option<masking::Secret<String>>,
+    /// Base64 encoded PEM bundle of the certificate authorities trusted by the proxy
+    pub ca_certificate: Option<masking::Secret<String>>,
+}
+
+/// Creates a client which sends all the traffic through the outbound proxy of the merchant. Any
+/// failure to construct the client is an invalid proxy configuration, as the proxy of the merchant
+/// is the only input which is not validated by the deployment.
+pub fn create_client_with_outbound_proxy(
+    proxy_config: &Proxy,
+    outbound_proxy: &OutboundProxy,
+    client_certificate: Option<masking::Secret<String>>,
+    client_certificate_key: Option<masking::Secret<String>>,
+) -> CustomResult<reqwest::Client, ApiClientError> {
+    let mut proxy = reqwest::Proxy::all(&outbound_proxy.url)
+        .change_context(ApiClientError::InvalidProxyConfiguration)
+        .attach_printable("Outbound proxy configuration error")?;
+    if let (Some(username), Some(password)) = (&outbound_proxy.username, &outbound_proxy.password) {
+        proxy = proxy.basic_auth(username.peek(), password.peek());
+    }
+
+    let mut client_builder = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .pool_idle_timeout(Duration::from_secs(
+            proxy_config
+                .idle_pool_connection_timeout
+                .unwrap_or_default(),
+        ))
+        .proxy(proxy);
+
+    if let Some(ca_certificate) = outbound_proxy.ca_certificate.clone() {
+        client_builder = create_certificate(ca_certificate)
+            .change_context(ApiClientError::InvalidProxyConfiguration)
+            .attach_printable("Invalid certificate authorities of the outbound proxy")?
+            .into_iter()
+            .fold(client_builder, |client_builder, certificate| {
+                client_builder.add_root_certificate(certificate)
+            })
+            .use_rustls_tls();
+    }
+
+    if let (Some(certificate), Some(certificate_key)) = (client_certificate, client_certificate_key)
+    {
+        let identity = create_identity_from_certificate_and_key(certificate, certificate_key)?;
+        client_builder = client_builder.identity(identity).use_rustls_tls();
+    }
+
+    client_builder
+        .build()
+        .change_context(ApiClientError::InvalidProxyConfiguration)
+        .attach_printable("Failed to construct client with the outbound proxy")
+}
+
 pub fn create_identity_from_certificate_and_key(
     encoded_certificate: masking::Secret<String>,
     encoded_certificate_key: masking::Secret<String>,
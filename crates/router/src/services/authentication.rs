@@ -191,6 +191,36 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    /// Identifier of the credential used to authenticate the request, against which the API rate
+    /// limits are tracked
+    pub fn get_rate_limit_key(&self) -> Option<String> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(format!("api_key_{}", key_id.get_string_repr())),
+            Self::AdminApiKey | Self::AdminApiAuthWithMerchantId { .. } => {
+                Some("admin_api_key".to_string())
+            }
+            Self::OrganizationJwt { user_id, .. }
+            | Self::MerchantJwt {
+                user_id: Some(user_id),
+                ..
+            }
+            | Self::MerchantJwtWithProfileId { user_id, .. }
+            | Self::UserJwt { user_id }
+            | Self::SinglePurposeJwt { user_id, .. }
+            | Self::SinglePurposeOrLoginJwt { user_id, .. } => Some(format!("user_{user_id}")),
+            Self::MerchantJwt {
+                merchant_id,
+                user_id: None,
+            }
+            | Self::MerchantId { merchant_id }
+            | Self::PublishableKey { merchant_id }
+            | Self::WebhookAuth { merchant_id } => {
+                Some(format!("merchant_{}", merchant_id.get_string_repr()))
+            }
+            Self::NoAuth => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, serde::Deserialize, strum::Display)]
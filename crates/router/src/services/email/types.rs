@@ -80,6 +80,20 @@ pub enum EmailBody {
         prefix: String,
     },
     WelcomeToCommunity,
+    WebhookFailureDigest {
+        profile_id: String,
+        timezone: String,
+        window_start: String,
+        window_end: String,
+        total_failed_deliveries: i64,
+        backlog_size: i64,
+        failed_deliveries_by_event_type: String,
+        top_failure_status_codes: String,
+    },
+    WebhookMetaNotification {
+        profile_id: String,
+        message: String,
+    },
 }
 
 pub mod html {
@@ -239,6 +253,34 @@ Email         : {user_email}
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
+            EmailBody::WebhookFailureDigest {
+                profile_id,
+                timezone,
+                window_start,
+                window_end,
+                total_failed_deliveries,
+                backlog_size,
+                failed_deliveries_by_event_type,
+                top_failure_status_codes,
+            } => format!(
+                include_str!("assets/webhook_failure_digest.html"),
+                profile_id = profile_id,
+                timezone = timezone,
+                window_start = window_start,
+                window_end = window_end,
+                total_failed_deliveries = total_failed_deliveries,
+                backlog_size = backlog_size,
+                failed_deliveries_by_event_type = failed_deliveries_by_event_type,
+                top_failure_status_codes = top_failure_status_codes,
+            ),
+            EmailBody::WebhookMetaNotification {
+                profile_id,
+                message,
+            } => format!(
+                include_str!("assets/webhook_meta_notification.html"),
+                profile_id = profile_id,
+                message = message,
+            ),
         }
     }
 }
@@ -681,3 +723,113 @@ impl EmailData for WelcomeToCommunity {
         })
     }
 }
+
+pub struct WebhookFailureDigest {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub digest: api_models::webhook_events::OutgoingWebhookDigest,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookFailureDigest {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let failed_deliveries_by_event_type = self
+            .digest
+            .failed_deliveries_by_event_type
+            .iter()
+            .map(|event_type_count| {
+                format!(
+                    "<li>{}: {}</li>",
+                    event_type_count.event_type, event_type_count.count
+                )
+            })
+            .collect::<String>();
+
+        let top_failure_status_codes = self
+            .digest
+            .top_failure_status_codes
+            .iter()
+            .map(|status_code_count| {
+                let status_code = status_code_count
+                    .status_code
+                    .map_or("No response".to_string(), |status_code| {
+                        status_code.to_string()
+                    });
+                format!("<li>{status_code}: {}</li>", status_code_count.count)
+            })
+            .collect::<String>();
+
+        let date_time_format =
+            time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]");
+        let window_start = self
+            .digest
+            .window_start
+            .format(&date_time_format)
+            .change_context(EmailError::ContentBuildFailure)?;
+        let window_end = self
+            .digest
+            .window_end
+            .format(&date_time_format)
+            .change_context(EmailError::ContentBuildFailure)?;
+
+        let body = html::get_html_body(EmailBody::WebhookFailureDigest {
+            profile_id: self.digest.profile_id.get_string_repr().to_owned(),
+            timezone: self.digest.timezone.clone(),
+            window_start,
+            window_end,
+            total_failed_deliveries: self.digest.total_failed_deliveries,
+            backlog_size: self.digest.backlog_size,
+            failed_deliveries_by_event_type,
+            top_failure_status_codes,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
+
+pub struct WebhookMetaNotification {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub notification: api_models::webhook_events::WebhookMetaNotification,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookMetaNotification {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        use api_models::webhook_events::WebhookMetaNotificationDetails;
+
+        let message = match &self.notification.details {
+            WebhookMetaNotificationDetails::DeliveryBacklogThresholdCrossed {
+                backlog_size,
+                backlog_threshold,
+                ..
+            } => format!(
+                "{backlog_size} webhooks are pending delivery to your webhook endpoint, above \
+                 the threshold of {backlog_threshold}"
+            ),
+            WebhookMetaNotificationDetails::SecretRotationReminder {
+                key_name,
+                key_prefix,
+                expires_in_days,
+            } => format!(
+                "Your API key {key_name} ({key_prefix}) expires in {expires_in_days} days, \
+                 please rotate it"
+            ),
+        };
+
+        let body = html::get_html_body(EmailBody::WebhookMetaNotification {
+            profile_id: self.notification.profile_id.get_string_repr().to_owned(),
+            message,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
@@ -57,7 +57,10 @@ pub use hyperswitch_domain_models::{
         WebhookSourceVerifyData,
     },
     router_request_types::{
-        revenue_recovery::{BillingConnectorPaymentsSyncRequest, RevenueRecoveryRecordBackRequest},
+        revenue_recovery::{
+            BillingConnectorInvoiceSyncRequest, BillingConnectorPaymentsSyncRequest,
+            RevenueRecoveryRecordBackRequest,
+        },
         unified_authentication_service::{
             UasAuthenticationRequestData, UasAuthenticationResponseData,
             UasConfirmationRequestData, UasPostAuthenticationRequestData,
@@ -78,7 +81,8 @@ pub use hyperswitch_domain_models::{
     },
     router_response_types::{
         revenue_recovery::{
-            BillingConnectorPaymentsSyncResponse, RevenueRecoveryRecordBackResponse,
+            BillingConnectorInvoiceSyncResponse, BillingConnectorPaymentsSyncResponse,
+            RevenueRecoveryRecordBackResponse,
         },
         AcceptDisputeResponse, CaptureSyncResponse, DefendDisputeResponse, MandateReference,
         MandateRevokeResponseData, PaymentsResponseData, PreprocessingResponseId,
@@ -1066,6 +1070,19 @@ impl ForeignFrom<&domain::MerchantConnectorAccountFeatureMetadata>
                         .mca_reference
                         .recovery_to_billing
                         .clone(),
+                    reconciliation_enabled: revenue_recovery_metadata.reconciliation_enabled,
+                    payments_sync_polling: revenue_recovery_metadata
+                        .payments_sync_polling
+                        .as_ref()
+                        .map(
+                            |polling| api_models::admin::RevenueRecoveryPaymentsSyncPolling {
+                                quiet_period_in_hours: polling.quiet_period_in_hours,
+                                max_empty_polls: polling.max_empty_polls,
+                            },
+                        ),
+                    payments_sync_timeout_in_secs: revenue_recovery_metadata
+                        .payments_sync_timeout_in_secs,
+                    payments_sync_required: revenue_recovery_metadata.payments_sync_required,
                 },
             );
         Self { revenue_recovery }
@@ -1092,6 +1109,17 @@ impl ForeignTryFrom<&api_models::admin::MerchantConnectorAccountFeatureMetadata>
                     billing_connector_retry_threshold: revenue_recovery_metadata
                         .billing_connector_retry_threshold,
                     mca_reference,
+                    reconciliation_enabled: revenue_recovery_metadata.reconciliation_enabled,
+                    payments_sync_polling: revenue_recovery_metadata
+                        .payments_sync_polling
+                        .as_ref()
+                        .map(|polling| domain::RevenueRecoveryPaymentsSyncPolling {
+                            quiet_period_in_hours: polling.quiet_period_in_hours,
+                            max_empty_polls: polling.max_empty_polls,
+                        }),
+                    payments_sync_timeout_in_secs: revenue_recovery_metadata
+                        .payments_sync_timeout_in_secs,
+                    payments_sync_required: revenue_recovery_metadata.payments_sync_required,
                 })
             })
             .transpose()?;
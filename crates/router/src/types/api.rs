@@ -57,7 +57,8 @@ pub use hyperswitch_interfaces::{
         },
         fraud_check::FraudCheck,
         revenue_recovery::{
-            BillingConnectorPaymentsSyncIntegration, RevenueRecovery, RevenueRecoveryRecordBack,
+            BillingConnectorInvoiceSyncIntegration, BillingConnectorPaymentsSyncIntegration,
+            RevenueRecovery, RevenueRecoveryRecordBack,
         },
         revenue_recovery_v2::RevenueRecoveryV2,
         BoxedConnector, Connector, ConnectorAccessToken, ConnectorAccessTokenV2, ConnectorCommon,
@@ -390,6 +391,10 @@ impl ConnectorData {
                 enums::Connector::DummyConnector7 => Ok(ConnectorEnum::Old(Box::new(
                     &connector::DummyConnector::<7>,
                 ))),
+                #[cfg(feature = "dummy_connector")]
+                enums::Connector::DummyBillingConnector => Ok(ConnectorEnum::Old(Box::new(
+                    connector::DummyBillingConnector::new(),
+                ))),
                 enums::Connector::Ebanx => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Ebanx::new())))
                 }
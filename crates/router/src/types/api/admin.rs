@@ -270,6 +270,11 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             is_clear_pan_retries_enabled: item.is_clear_pan_retries_enabled,
             is_debit_routing_enabled: Some(item.is_debit_routing_enabled),
             merchant_business_country: item.merchant_business_country,
+            default_recovery_payment_connector_id: item.default_recovery_payment_connector_id,
+            revenue_recovery_connector_fallback: item.revenue_recovery_connector_fallback,
+            revenue_recovery_retry_notification: item.revenue_recovery_retry_notification,
+            revenue_recovery_blackout_calendar: item.revenue_recovery_blackout_calendar,
+            capabilities: None,
         })
     }
 }
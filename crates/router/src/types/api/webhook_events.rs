@@ -1,6 +1,25 @@
 pub use api_models::webhook_events::{
-    EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
-    OutgoingWebhookResponseContent, TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    EventDeliveryAttemptListConstraints, EventDeliveryAttemptResponse, EventDetailsResponse,
+    EventExportConstraints, EventExportFormat, EventExportRecord, EventExportRequestInternal,
+    EventLatestDeliveryAttempt, EventListConstraints, EventListConstraintsInternal,
+    EventListItemResponse, EventListRequestInternal, EventPayloadScrubRequestInternal,
+    EventPayloadScrubResponse, EventRetrieveConstraints, EventRetrieveExpand,
+    EventRetrieveRequestInternal, EventRetrieveResponse, EventTypeCatalogItem,
+    EventTypeCatalogRequestInternal, EventTypeCatalogResponse, EventsArchiveListConstraints,
+    EventsArchiveListRequestInternal, EventsArchiveResponse,
+    EventsRetentionScheduleRequestInternal, EventsRetentionScheduleResponse,
+    OutgoingRecoveryRetryUpcoming, OutgoingWebhookDigest, OutgoingWebhookRequestContent,
+    OutgoingWebhookResponseContent, OutgoingWebhookUrlVerification, ScrubbedEventPayload,
+    WebhookBulkRetryJobResponse, WebhookBulkRetryJobRetrieveRequestInternal,
+    WebhookBulkRetryRequest, WebhookBulkRetryRequestInternal,
+    WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryAttemptPayloadRequestInternal,
+    WebhookDeliveryAttemptPreview, WebhookDeliveryDailyStats, WebhookDeliveryEventTypeStats,
+    WebhookDeliveryLatencyStats, WebhookDeliveryOutcomeCounts, WebhookDeliveryRetryInterval,
+    WebhookDeliveryRetryPolicy, WebhookDeliveryRetryRequest, WebhookDeliveryRetryRequestInternal,
+    WebhookDeliveryStatsRequest, WebhookDeliveryStatsRequestInternal, WebhookDeliveryStatsResponse,
+    WebhookDigestEventTypeCount, WebhookDigestStatusCodeCount, WebhookEventSubscriptionResponse,
+    WebhookEventSubscriptionUpdateRequest, WebhookEventSubscriptionUpdateRequestInternal,
+    WebhookRetryPolicyPreviewRequest, WebhookRetryPolicyPreviewResponse,
+    WebhookUrlChangeConfirmRequest, WebhookUrlChangeConfirmRequestInternal,
+    WebhookUrlChangeConfirmResponse, EVENT_LIST_LEGACY_FIELDS,
 };
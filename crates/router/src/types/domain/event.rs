@@ -5,7 +5,10 @@ use common_utils::{
     types::keymanager::{KeyManagerState, ToEncryptable},
 };
 use diesel_models::{
-    enums::{EventClass, EventObjectType, EventType, WebhookDeliveryAttempt},
+    enums::{
+        EventClass, EventObjectType, EventType, WebhookDeliveryAttempt,
+        WebhookDeliveryStatusCodeClass,
+    },
     events::{EventMetadata, EventUpdateInternal},
 };
 use error_stack::ResultExt;
@@ -71,6 +74,20 @@ pub struct Event {
 
     /// Indicates whether the event was ultimately delivered.
     pub is_overall_delivery_successful: Option<bool>,
+
+    /// Version of the primary object at the time the event was created.
+    pub entity_version: Option<i64>,
+
+    /// The timestamp when the request and response bodies of the event were replaced by a
+    /// placeholder.
+    pub payload_scrubbed_at: Option<time::PrimitiveDateTime>,
+
+    /// The time in milliseconds taken by the webhook endpoint to respond to the delivery attempt.
+    pub delivery_latency_ms: Option<i64>,
+
+    /// The class of the status code received by the latest delivery attempt of the event, or the
+    /// kind of error it failed with. Populated on the initial delivery attempt only.
+    pub latest_delivery_status_code_class: Option<WebhookDeliveryStatusCodeClass>,
 }
 
 #[derive(Debug)]
@@ -78,10 +95,19 @@ pub enum EventUpdate {
     UpdateResponse {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
+        delivery_latency_ms: Option<i64>,
     },
     OverallDeliveryStatusUpdate {
         is_overall_delivery_successful: bool,
     },
+    LatestDeliveryStatusUpdate {
+        latest_delivery_status_code_class: WebhookDeliveryStatusCodeClass,
+    },
+    PayloadScrubUpdate {
+        request: OptionalEncryptableSecretString,
+        response: OptionalEncryptableSecretString,
+        payload_scrubbed_at: time::PrimitiveDateTime,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -90,17 +116,50 @@ impl From<EventUpdate> for EventUpdateInternal {
             EventUpdate::UpdateResponse {
                 is_webhook_notified,
                 response,
+                delivery_latency_ms,
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
+                request: None,
                 response: response.map(Into::into),
                 is_overall_delivery_successful: None,
+                payload_scrubbed_at: None,
+                delivery_latency_ms,
+                latest_delivery_status_code_class: None,
             },
             EventUpdate::OverallDeliveryStatusUpdate {
                 is_overall_delivery_successful,
             } => Self {
                 is_webhook_notified: None,
+                request: None,
                 response: None,
                 is_overall_delivery_successful: Some(is_overall_delivery_successful),
+                payload_scrubbed_at: None,
+                delivery_latency_ms: None,
+                latest_delivery_status_code_class: None,
+            },
+            EventUpdate::LatestDeliveryStatusUpdate {
+                latest_delivery_status_code_class,
+            } => Self {
+                is_webhook_notified: None,
+                request: None,
+                response: None,
+                is_overall_delivery_successful: None,
+                payload_scrubbed_at: None,
+                delivery_latency_ms: None,
+                latest_delivery_status_code_class: Some(latest_delivery_status_code_class),
+            },
+            EventUpdate::PayloadScrubUpdate {
+                request,
+                response,
+                payload_scrubbed_at,
+            } => Self {
+                is_webhook_notified: None,
+                request: request.map(Into::into),
+                response: response.map(Into::into),
+                is_overall_delivery_successful: None,
+                payload_scrubbed_at: Some(payload_scrubbed_at),
+                delivery_latency_ms: None,
+                latest_delivery_status_code_class: None,
             },
         }
     }
@@ -130,6 +189,10 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            entity_version: self.entity_version,
+            payload_scrubbed_at: self.payload_scrubbed_at,
+            delivery_latency_ms: self.delivery_latency_ms,
+            latest_delivery_status_code_class: self.latest_delivery_status_code_class,
         })
     }
 
@@ -180,6 +243,10 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
             is_overall_delivery_successful: item.is_overall_delivery_successful,
+            entity_version: item.entity_version,
+            payload_scrubbed_at: item.payload_scrubbed_at,
+            delivery_latency_ms: item.delivery_latency_ms,
+            latest_delivery_status_code_class: item.latest_delivery_status_code_class,
         })
     }
 
@@ -202,6 +269,10 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            entity_version: self.entity_version,
+            payload_scrubbed_at: self.payload_scrubbed_at,
+            delivery_latency_ms: self.delivery_latency_ms,
+            latest_delivery_status_code_class: self.latest_delivery_status_code_class,
         })
     }
 }
@@ -45,7 +45,8 @@ pub mod user_authentication_method;
 pub mod user_role;
 
 pub use diesel_models::{
-    process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
+    process_tracker::business_status, ProcessTracker, ProcessTrackerMerchantTaskConstraints,
+    ProcessTrackerMerchantTaskCounts, ProcessTrackerNew, ProcessTrackerRunner,
     ProcessTrackerUpdate,
 };
 #[cfg(feature = "v1")]
@@ -1 +1,8 @@
-pub use diesel_models::events::{Event, EventMetadata, EventNew};
+pub use diesel_models::{
+    events::{
+        Event, EventAttemptsSummary, EventDeliveryLatency, EventDeliveryOutcomeCounts,
+        EventMetadata, EventNew, WebhookBulkRetryJobTrackingData, WebhookBulkRetryTrackingData,
+        WebhookDigestTrackingData, WebhookEventsRetentionTrackingData,
+    },
+    events_archive::EventsArchive,
+};
@@ -1,14 +1,275 @@
 use std::fmt::Debug;
 
-use common_utils::id_type;
-use hyperswitch_domain_models::{business_profile, merchant_account, merchant_key_store};
-#[derive(serde::Serialize, serde::Deserialize, Debug)]
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    errors::{CryptoError, CustomResult},
+    id_type,
+};
+pub use diesel_models::{
+    revenue_recovery_collection_item::{
+        RevenueRecoveryCollectionItem, RevenueRecoveryCollectionItemNew,
+        RevenueRecoveryCollectionItemUpdate,
+    },
+    revenue_recovery_decision::RevenueRecoveryDecision,
+};
+use hyperswitch_domain_models::{
+    business_profile, merchant_account, merchant_key_store, revenue_recovery,
+};
+
+const PROCESSOR_TOKEN_TAG_PREFIX: &str = "pm_token_hash";
+/// Tag of all the pcr tasks, the only tag of the tasks created before the tasks were tagged with
+/// their merchant and billing connector
+pub const PCR_TASK_TAG: &str = "PCR";
+const MERCHANT_TAG_PREFIX: &str = "merchant:";
+const CONNECTOR_TAG_PREFIX: &str = "connector:";
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct PcrWorkflowTrackingData {
     pub merchant_id: id_type::MerchantId,
     pub profile_id: id_type::ProfileId,
     pub global_payment_id: id_type::GlobalPaymentId,
     pub payment_attempt_id: id_type::GlobalAttemptId,
     pub billing_mca_id: id_type::MerchantConnectorAccountId,
+    /// Adjustments applied while computing the schedule time of this task
+    #[serde(default)]
+    pub schedule_adjustments: Vec<common_enums::RecoveryScheduleAdjustment>,
+    /// Identifier of the billing connector webhook which scheduled this task
+    #[serde(default)]
+    pub recovery_correlation_id: Option<String>,
+    /// Decline class of the failed attempt, whose delay profile decided the schedule time of this
+    /// task
+    #[serde(default)]
+    pub decline_class: Option<String>,
+    /// Number of consecutive executions of this task which failed with an error, the declines of
+    /// the payment not being counted
+    #[serde(default)]
+    pub execution_failure_count: u16,
+    /// Error with which the latest failed execution of this task failed
+    #[serde(default)]
+    pub last_execution_error: Option<String>,
+    /// Inputs of the decision of the schedule time of this task, not present for the tasks
+    /// scheduled before the decisions were recorded
+    #[serde(default)]
+    pub schedule_decision: Option<RecoveryScheduleDecision>,
+}
+
+/// Inputs of the decision of the schedule time of a pcr task, recorded so that the time at which
+/// a retry is made can be explained later
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct RecoveryScheduleDecision {
+    pub algorithm: common_enums::RecoveryScheduleAlgorithm,
+    /// Delay of the base schedule time decided by the algorithm, from the time of the decision
+    pub base_delay_in_secs: i64,
+    /// Decline class of the failed attempt, when the delay profile of the class was used
+    pub decline_class: Option<String>,
+    /// Shift of the base schedule time applied by the retry window, the exclusion window of the
+    /// processor token and the lead time of the retry notification
+    pub window_shift_in_secs: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub computed_at: time::PrimitiveDateTime,
+}
+
+impl RecoveryScheduleDecision {
+    pub fn new(
+        algorithm: common_enums::RecoveryScheduleAlgorithm,
+        decline_class: Option<String>,
+        computed_at: time::PrimitiveDateTime,
+        base_schedule_time: time::PrimitiveDateTime,
+        schedule_time: time::PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            algorithm,
+            base_delay_in_secs: (base_schedule_time - computed_at).whole_seconds(),
+            decline_class,
+            window_shift_in_secs: (schedule_time - base_schedule_time).whole_seconds(),
+            computed_at,
+        }
+    }
+}
+
+/// Tracking data of the task which computes the schedule time of the next retry and creates the
+/// execute task of the payment
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct PcrCalculateWorkflowTrackingData {
+    #[serde(flatten)]
+    pub workflow_tracking_data: PcrWorkflowTrackingData,
+    /// Retry count of the payment intent when the failed payment was received
+    pub intent_retry_count: u16,
+    /// Tag of the processor payment method token of the failed payment attempt
+    pub processor_token_tag: Option<String>,
+    /// Error code of the failed payment attempt, the unified code when it is available
+    #[serde(default)]
+    pub last_attempt_error_code: Option<String>,
+    /// Country of the issuer of the card of the failed payment attempt, whose timezone is used for
+    /// the retry window
+    #[serde(default)]
+    pub card_issuing_country: Option<common_enums::CountryAlpha2>,
+}
+
+/// Tracking data of the task which notifies the merchant of the upcoming retry of the payment
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct PcrNotifyWorkflowTrackingData {
+    #[serde(flatten)]
+    pub workflow_tracking_data: PcrWorkflowTrackingData,
+    /// Retry count of the execute task when the retry was scheduled, the notification is not sent
+    /// if the execute task has moved on to another retry since
+    pub execute_task_retry_count: i32,
+}
+
+/// Tracking data of the task held in review for a failed payment whose payment connector could not
+/// be resolved from the account reference id shared by the billing connector. The failed attempt
+/// is kept so that it can be recorded once the payment connector is mapped.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct PcrUnresolvedConnectorTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub global_payment_id: id_type::GlobalPaymentId,
+    pub billing_mca_id: id_type::MerchantConnectorAccountId,
+    /// Reference of the account of the payment connector at the billing connector, for which no
+    /// payment connector was found
+    pub connector_account_reference_id: String,
+    /// Identifier of the billing connector webhook which moved the payment to review
+    pub recovery_correlation_id: String,
+    pub attempt_data: revenue_recovery::RevenueRecoveryAttemptData,
+}
+
+/// Global kill switch of revenue recovery, stored in the configs table along with the changes
+/// made to it. The latest change decides whether the switch is active.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct RecoveryKillSwitch {
+    /// Changes made to the switch, the oldest first
+    pub history: Vec<RecoveryKillSwitchChange>,
+}
+
+impl RecoveryKillSwitch {
+    pub fn is_active(&self) -> bool {
+        self.history.last().is_some_and(|change| change.is_active)
+    }
+
+    /// Times at which the switch was last turned on and turned off, if it has been turned off
+    /// since. Repeated changes which did not flip the switch are not counted.
+    pub fn get_latest_pause(&self) -> Option<(time::PrimitiveDateTime, time::PrimitiveDateTime)> {
+        let lifted_at = self
+            .history
+            .last()
+            .filter(|change| !change.is_active)?
+            .changed_at;
+        let paused_at = self
+            .history
+            .iter()
+            .rev()
+            .skip_while(|change| !change.is_active)
+            .take_while(|change| change.is_active)
+            .last()?
+            .changed_at;
+
+        Some((paused_at, lifted_at))
+    }
+}
+
+/// Schedule time of a task before it was first halted by the kill switch. It is kept in the
+/// tracking data of the halted task, alongside the tracking data of its workflow, since the schedule
+/// time of the task is moved forward every time the task is halted.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct RecoveryKillSwitchHalt {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub halted_schedule_time: time::PrimitiveDateTime,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct RecoveryKillSwitchChange {
+    pub is_active: bool,
+    pub reason: String,
+    pub actor: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub changed_at: time::PrimitiveDateTime,
+}
+
+/// Tracking data of the task which reconciles the open invoices of a billing connector account
+/// every night
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct RecoveryReconciliationTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub billing_connector_id: id_type::MerchantConnectorAccountId,
+    /// The run whose invoices are being checked in batches, if any
+    #[serde(default)]
+    pub current_run: Option<RecoveryReconciliationRun>,
+}
+
+/// Tracking data of the task which polls the billing connector for the transactions of an invoice
+/// whose webhooks have gone quiet
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct RecoveryPaymentsSyncPollingTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub billing_connector_id: id_type::MerchantConnectorAccountId,
+    pub global_payment_id: id_type::GlobalPaymentId,
+    /// Id of the invoice at the billing connector, by which the transactions are synced
+    pub merchant_reference_id: id_type::PaymentReferenceId,
+    /// Number of consecutive polls which found no new transaction of the invoice
+    #[serde(default)]
+    pub empty_polls_count: u16,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct RecoveryReconciliationRun {
+    /// Id of the last payment checked by the run, the next batch starts after it
+    pub starting_after: Option<id_type::GlobalPaymentId>,
+    /// Number of open invoices looked up by the run so far, checked or not
+    pub examined_count: u32,
+    /// Report of the invoices checked by the run so far
+    pub report: RecoveryReconciliationReport,
+}
+
+/// Reports of the latest reconciliation runs of a billing connector account, stored in the
+/// configs table
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct RecoveryReconciliationReports {
+    /// Reports of the completed runs, the oldest first
+    pub reports: Vec<RecoveryReconciliationReport>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct RecoveryReconciliationReport {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+    pub checked_count: u32,
+    pub drifted_count: u32,
+    pub reconciled_count: u32,
+    pub errors_count: u32,
+    /// Whether the run stopped at the limit on the invoices checked in a run
+    #[serde(default)]
+    pub is_truncated: bool,
+    /// The drifted invoices, capped so that the report stays small
+    #[serde(default)]
+    pub drifts: Vec<RecoveryReconciliationDrift>,
+}
+
+impl RecoveryReconciliationReport {
+    pub fn new(started_at: time::PrimitiveDateTime) -> Self {
+        Self {
+            started_at,
+            completed_at: None,
+            checked_count: 0,
+            drifted_count: 0,
+            reconciled_count: 0,
+            errors_count: 0,
+            is_truncated: false,
+            drifts: Vec::new(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct RecoveryReconciliationDrift {
+    pub payment_id: id_type::GlobalPaymentId,
+    pub merchant_reference_id: Option<id_type::PaymentReferenceId>,
+    pub kind: common_enums::RecoveryReconciliationDriftKind,
+    pub previous_status: common_enums::IntentStatus,
+    pub reconciled: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -17,3 +278,107 @@ pub struct PcrPaymentData {
     pub profile: business_profile::Profile,
     pub key_store: merchant_key_store::MerchantKeyStore,
 }
+
+/// Tag used for finding the pcr tasks scheduled for a processor payment method token. The token
+/// is hashed along with the merchant id so that it is not stored as is in the process tracker.
+pub fn get_processor_token_tag(
+    merchant_id: &id_type::MerchantId,
+    processor_payment_method_token: &str,
+) -> CustomResult<String, CryptoError> {
+    let digest = crypto::Sha256.generate_digest(
+        format!(
+            "{}:{processor_payment_method_token}",
+            merchant_id.get_string_repr()
+        )
+        .as_bytes(),
+    )?;
+    Ok(format!(
+        "{PROCESSOR_TOKEN_TAG_PREFIX}_{}",
+        hex::encode(digest)
+    ))
+}
+
+/// Tags of a pcr task, which let the tasks of a merchant and a billing connector be looked up
+/// without reading the tracking data of every task
+pub fn get_pcr_task_tags(
+    merchant_id: &id_type::MerchantId,
+    billing_connector: Option<&str>,
+) -> Vec<String> {
+    [
+        Some(PCR_TASK_TAG.to_string()),
+        Some(get_merchant_tag(merchant_id)),
+        billing_connector.map(get_connector_tag),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+pub fn get_merchant_tag(merchant_id: &id_type::MerchantId) -> String {
+    format!("{MERCHANT_TAG_PREFIX}{}", merchant_id.get_string_repr())
+}
+
+pub fn get_connector_tag(billing_connector: &str) -> String {
+    format!("{CONNECTOR_TAG_PREFIX}{billing_connector}")
+}
+
+/// Billing connector of the task as per its tags, not present for the tasks created before the
+/// tasks were tagged with their billing connector
+pub fn get_billing_connector_from_tags(tags: &[String]) -> Option<&str> {
+    tags.iter()
+        .find_map(|tag| tag.strip_prefix(CONNECTOR_TAG_PREFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_pcr_task_tags_carry_the_merchant_and_billing_connector() {
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+
+        let tags = get_pcr_task_tags(&merchant_id, Some("chargebee"));
+        assert_eq!(
+            tags,
+            vec![
+                String::from("PCR"),
+                String::from("merchant:merchant_1"),
+                String::from("connector:chargebee"),
+            ]
+        );
+        assert_eq!(get_billing_connector_from_tags(&tags), Some("chargebee"));
+
+        // The tasks created before the tasks were tagged with their billing connector
+        let legacy_tags = vec![String::from("PCR"), String::from("pm_token_hash_1234")];
+        assert_eq!(get_billing_connector_from_tags(&legacy_tags), None);
+        assert_eq!(
+            get_pcr_task_tags(&merchant_id, None),
+            vec![String::from("PCR"), String::from("merchant:merchant_1")]
+        );
+    }
+
+    #[test]
+    fn test_schedule_decision_separates_the_base_delay_from_the_window_shift() {
+        let computed_at = time::macros::datetime!(2024-01-15 10:00);
+        let decision = RecoveryScheduleDecision::new(
+            common_enums::RecoveryScheduleAlgorithm::DeclineClassDelayProfile,
+            Some(String::from("insufficient_funds")),
+            computed_at,
+            time::macros::datetime!(2024-01-16 04:00),
+            time::macros::datetime!(2024-01-16 09:30),
+        );
+
+        assert_eq!(decision.base_delay_in_secs, 18 * 60 * 60);
+        assert_eq!(decision.window_shift_in_secs, 5 * 60 * 60 + 30 * 60);
+        assert_eq!(
+            serde_json::from_value::<RecoveryScheduleDecision>(
+                serde_json::to_value(&decision).unwrap()
+            )
+            .unwrap(),
+            decision
+        );
+    }
+}
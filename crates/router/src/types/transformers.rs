@@ -349,6 +349,12 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::DummyConnector6 => Self::DummyConnector6,
             #[cfg(feature = "dummy_connector")]
             api_enums::Connector::DummyConnector7 => Self::DummyConnector7,
+            #[cfg(feature = "dummy_connector")]
+            api_enums::Connector::DummyBillingConnector => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "dummybillingconnector is not a routable connector".to_string(),
+                })?
+            }
             api_enums::Connector::Threedsecureio => {
                 Err(common_utils::errors::ValidationError::InvalidValue {
                     message: "threedsecureio is not a routable connector".to_string(),
@@ -958,6 +964,7 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            entity_version: Some(dispute.entity_version),
         }
     }
 }
@@ -1921,24 +1928,43 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
             && (item.created_after.is_some()
                 || item.created_before.is_some()
                 || item.limit.is_some()
-                || item.offset.is_some())
+                || item.offset.is_some()
+                || item.cursor.is_some())
         {
             return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                 message:
                     "Either only `object_id` must be specified, or one or more of \
-                          `created_after`, `created_before`, `limit` and `offset` must be specified"
+                          `created_after`, `created_before`, `limit`, `offset` and `cursor` must be specified"
                         .to_string()
             }));
         }
 
+        if item.offset.is_some() && item.cursor.is_some() {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only one of `offset` and `cursor` must be specified".to_string()
+            }));
+        }
+
         match item.object_id {
-            Some(object_id) => Ok(Self::ObjectIdFilter { object_id }),
+            Some(object_id) => Ok(Self::ObjectIdFilter {
+                object_id,
+                is_delivered: item.is_delivered,
+                delivery_state: item.delivery_state,
+                event_types: item.event_types,
+                event_class: item.event_class,
+                delivery_status_code_class: item.delivery_status_code_class,
+            }),
             None => Ok(Self::GenericFilter {
                 created_after: item.created_after,
                 created_before: item.created_before,
                 limit: item.limit.map(i64::from),
                 offset: item.offset.map(i64::from),
                 is_delivered: item.is_delivered,
+                delivery_state: item.delivery_state,
+                event_types: item.event_types,
+                event_class: item.event_class,
+                delivery_status_code_class: item.delivery_status_code_class,
+                cursor: item.cursor,
             }),
         }
     }
@@ -1977,6 +2003,10 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             is_delivery_successful: item.is_overall_delivery_successful,
             initial_attempt_id,
             created: item.created_at,
+            entity_version: item.entity_version,
+            payload_scrubbed_at: item.payload_scrubbed_at,
+            attempt_count: None,
+            last_attempted_at: None,
         })
     }
 }
@@ -2106,6 +2136,16 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            url_change_confirmation_enabled: item.url_change_confirmation_enabled,
+            // Pending changes are only created while updating the profile
+            pending_url_change: None,
+            redirect_policy: item.redirect_policy,
+            digest_config: item.digest_config.map(ForeignFrom::foreign_from),
+            secondary_notification_config: item
+                .secondary_notification_config
+                .map(ForeignFrom::foreign_from),
+            // The subscription is only changed by the event subscription update
+            enabled_event_types: None,
         }
     }
 }
@@ -2122,6 +2162,85 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            url_change_confirmation_enabled: item.url_change_confirmation_enabled,
+            pending_url_change: item.pending_url_change.map(ForeignFrom::foreign_from),
+            redirect_policy: item.redirect_policy,
+            digest_config: item.digest_config.map(ForeignFrom::foreign_from),
+            secondary_notification_config: item
+                .secondary_notification_config
+                .map(ForeignFrom::foreign_from),
+            enabled_event_types: item.enabled_event_types,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::WebhookDigestConfig>
+    for diesel_models::business_profile::WebhookDigestConfig
+{
+    fn foreign_from(item: api_models::admin::WebhookDigestConfig) -> Self {
+        Self {
+            enabled: item.enabled,
+            hour_of_day: item.hour_of_day,
+            timezone: item.timezone,
+            channels: item.channels,
+            email_recipient: item.email_recipient,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::WebhookDigestConfig>
+    for api_models::admin::WebhookDigestConfig
+{
+    fn foreign_from(item: diesel_models::business_profile::WebhookDigestConfig) -> Self {
+        Self {
+            enabled: item.enabled,
+            hour_of_day: item.hour_of_day,
+            timezone: item.timezone,
+            channels: item.channels,
+            email_recipient: item.email_recipient,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::WebhookSecondaryNotificationConfig>
+    for diesel_models::business_profile::WebhookSecondaryNotificationConfig
+{
+    fn foreign_from(item: api_models::admin::WebhookSecondaryNotificationConfig) -> Self {
+        Self {
+            enabled: item.enabled,
+            notification_url: item.notification_url,
+            email_recipient: item.email_recipient,
+            notification_kinds: item.notification_kinds,
+            backlog_threshold: item.backlog_threshold,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::WebhookSecondaryNotificationConfig>
+    for api_models::admin::WebhookSecondaryNotificationConfig
+{
+    fn foreign_from(
+        item: diesel_models::business_profile::WebhookSecondaryNotificationConfig,
+    ) -> Self {
+        Self {
+            enabled: item.enabled,
+            notification_url: item.notification_url,
+            email_recipient: item.email_recipient,
+            notification_kinds: item.notification_kinds,
+            backlog_threshold: item.backlog_threshold,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::PendingWebhookUrlChange>
+    for api_models::admin::PendingWebhookUrlChange
+{
+    fn foreign_from(item: diesel_models::business_profile::PendingWebhookUrlChange) -> Self {
+        Self {
+            status: item.get_status(common_utils::date_time::now()),
+            webhook_url: item.webhook_url,
+            requested_at: item.requested_at,
+            expires_at: item.expires_at,
         }
     }
 }
@@ -2340,3 +2459,18 @@ impl ForeignFrom<card_info_types::CardInfoUpdateRequest> for storage::CardInfo {
         }
     }
 }
+
+impl ForeignFrom<api_models::webhook_events::WebhookDeliveryRetryPolicy>
+    for scheduler::types::process_data::RetryMapping
+{
+    fn foreign_from(item: api_models::webhook_events::WebhookDeliveryRetryPolicy) -> Self {
+        Self {
+            start_after: item.start_after_in_secs,
+            frequencies: item
+                .intervals
+                .into_iter()
+                .map(|interval| (interval.interval_in_secs, interval.count))
+                .collect(),
+        }
+    }
+}
@@ -3,8 +3,10 @@ pub mod connector_onboarding;
 pub mod currency;
 pub mod db_utils;
 pub mod ext_traits;
+pub mod fault_injection;
 #[cfg(feature = "kv_store")]
 pub mod storage_partitioning;
+pub mod timezone;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -40,7 +42,6 @@ use masking::{ExposeInterface, SwitchStrategy};
 use nanoid::nanoid;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use tracing_futures::Instrument;
 use uuid::Uuid;
 
 pub use self::ext_traits::{OptionExt, ValidateCall};
@@ -1218,10 +1219,17 @@ where
             // So when server shutdown won't wait for this thread's completion.
 
             if let Some(event_type) = event_type {
-                tokio::spawn(
+                // The payment update reported by the event is committed, the event is created
+                // once the flow ends
+                webhooks_core::pending_events::create_event_after_commit(
+                    webhooks_core::utils::get_idempotent_event_id(
+                        payment_id.get_string_repr(),
+                        event_type,
+                        enums::WebhookDeliveryAttempt::InitialAttempt,
+                    ),
                     async move {
                         let primary_object_created_at = payments_response_json.created;
-                        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                        let _ = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
                             cloned_state,
                             merchant_account,
                             business_profile,
@@ -1235,9 +1243,8 @@ where
                             )),
                             primary_object_created_at,
                         ))
-                        .await
-                    }
-                    .in_current_span(),
+                        .await;
+                    },
                 );
             } else {
                 logger::warn!(
@@ -1293,9 +1300,16 @@ pub async fn trigger_refund_outgoing_webhook(
         let cloned_merchant_account = merchant_account.clone();
         let primary_object_created_at = refund_response.created_at;
         if let Some(outgoing_event_type) = event_type {
-            tokio::spawn(
+            // The refund update reported by the event is committed, the event is created once the
+            // flow ends
+            webhooks_core::pending_events::create_event_after_commit(
+                webhooks_core::utils::get_idempotent_event_id(
+                    &refund_id,
+                    outgoing_event_type,
+                    enums::WebhookDeliveryAttempt::InitialAttempt,
+                ),
                 async move {
-                    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                    let _ = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
                         cloned_state,
                         cloned_merchant_account,
                         business_profile,
@@ -1307,9 +1321,8 @@ pub async fn trigger_refund_outgoing_webhook(
                         webhooks::OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
                         primary_object_created_at,
                     ))
-                    .await
-                }
-                .in_current_span(),
+                    .await;
+                },
             );
         } else {
             logger::warn!("Outgoing webhook not sent because of missing event type status mapping");
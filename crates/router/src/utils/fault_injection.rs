@@ -0,0 +1,204 @@
+//! Failure injection at the integration points of the revenue recovery flow, for reproducing
+//! partial failures of the flow in tests and staging environments.
+//!
+//! The points are armed through the `recovery_fault_injection` config or at runtime through the
+//! registry on the session state. Without the `recovery_fault_injection` feature, the checks made
+//! by [`inject_recovery_fault`] are compiled away.
+
+#[cfg(feature = "recovery_fault_injection")]
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+#[cfg(feature = "recovery_fault_injection")]
+use common_utils::errors::CustomResult;
+#[cfg(feature = "recovery_fault_injection")]
+use error_stack::report;
+#[cfg(feature = "recovery_fault_injection")]
+use rand::Rng;
+#[cfg(feature = "recovery_fault_injection")]
+use router_env::logger;
+
+#[cfg(feature = "recovery_fault_injection")]
+use crate::configs::settings::RecoveryFaultInjection;
+
+/// Integration points of the revenue recovery flow at which a failure can be injected
+#[cfg(feature = "recovery_fault_injection")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecoveryFaultPoint {
+    /// Payments sync call made to the billing connector while processing a recovery webhook
+    BillingConnectorPaymentsSync,
+    /// Creation of the payment intent of an invoice received for the first time
+    PaymentIntentCreate,
+    /// Recording of a payment attempt made outside of the recovery flow
+    RecordAttempt,
+    /// Insertion of the process tracker task which schedules the retry of a failed payment
+    ProcessTrackerInsert,
+    /// Reads of the merchant configs of the recovery flow, which are served from redis
+    Redis,
+    /// Payments sync call made by the execute task after a retry
+    PaymentsSync,
+}
+
+/// Condition under which a failure is injected at an armed point
+#[cfg(feature = "recovery_fault_injection")]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryFaultTrigger {
+    /// Fails with the given probability, between 0 and 1, every time the point is reached
+    Probability(f64),
+    /// Passes the given number of times the point is reached, and fails once after that
+    Countdown(u32),
+}
+
+/// Points of the recovery flow which are currently armed, shared by all the session states of the
+/// application so that a countdown spans requests and process tracker tasks alike
+#[cfg(feature = "recovery_fault_injection")]
+#[derive(Debug, Default)]
+pub struct FaultInjectionRegistry {
+    faults: Mutex<HashMap<RecoveryFaultPoint, RecoveryFaultTrigger>>,
+}
+
+#[cfg(feature = "recovery_fault_injection")]
+impl FaultInjectionRegistry {
+    pub fn new(config: &RecoveryFaultInjection) -> Self {
+        Self {
+            faults: Mutex::new(config.faults.clone()),
+        }
+    }
+
+    /// Arms the point, replacing the trigger it was armed with earlier
+    pub fn inject(&self, point: RecoveryFaultPoint, trigger: RecoveryFaultTrigger) {
+        self.get_faults().insert(point, trigger);
+    }
+
+    pub fn clear(&self, point: RecoveryFaultPoint) {
+        self.get_faults().remove(&point);
+    }
+
+    /// Whether a failure is to be injected this time the point is reached. A countdown is
+    /// disarmed once it has failed.
+    pub fn should_fail(&self, point: RecoveryFaultPoint) -> bool {
+        let mut faults = self.get_faults();
+        match faults.get(&point).copied() {
+            None => false,
+            Some(RecoveryFaultTrigger::Probability(probability)) => {
+                rand::thread_rng().gen_bool(probability.clamp(0.0, 1.0))
+            }
+            Some(RecoveryFaultTrigger::Countdown(0)) => {
+                faults.remove(&point);
+                true
+            }
+            Some(RecoveryFaultTrigger::Countdown(remaining)) => {
+                faults.insert(
+                    point,
+                    RecoveryFaultTrigger::Countdown(remaining.saturating_sub(1)),
+                );
+                false
+            }
+        }
+    }
+
+    /// Fails with `error` if a failure is to be injected at the point
+    pub fn check<E>(&self, point: RecoveryFaultPoint, error: E) -> CustomResult<(), E>
+    where
+        E: error_stack::Context,
+    {
+        common_utils::fp_utils::when(self.should_fail(point), || {
+            logger::warn!(%point, "Injecting a failure in the revenue recovery flow");
+            Err(report!(error).attach_printable(format!("Failure injected at {point}")))
+        })
+    }
+
+    fn get_faults(&self) -> MutexGuard<'_, HashMap<RecoveryFaultPoint, RecoveryFaultTrigger>> {
+        // The map is left consistent by every holder of the lock, even one which panicked
+        self.faults.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Returns `$error` from the enclosing function if a failure is injected at the
+/// [`RecoveryFaultPoint`] `$point` through the registry of the session state `$state`.
+#[cfg(feature = "recovery_fault_injection")]
+macro_rules! inject_recovery_fault {
+    ($state:expr, $point:ident, $error:expr) => {
+        $state.fault_injection.check(
+            $crate::utils::fault_injection::RecoveryFaultPoint::$point,
+            $error,
+        )?
+    };
+}
+
+#[cfg(not(feature = "recovery_fault_injection"))]
+macro_rules! inject_recovery_fault {
+    ($state:expr, $point:ident, $error:expr) => {};
+}
+
+pub(crate) use inject_recovery_fault;
+
+#[cfg(all(test, feature = "recovery_fault_injection"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_countdown_fails_once_after_passing_the_given_number_of_times() {
+        let registry = FaultInjectionRegistry::default();
+        registry.inject(
+            RecoveryFaultPoint::RecordAttempt,
+            RecoveryFaultTrigger::Countdown(2),
+        );
+
+        let outcomes = (0..4)
+            .map(|_| registry.should_fail(RecoveryFaultPoint::RecordAttempt))
+            .collect::<Vec<_>>();
+
+        assert_eq!(outcomes, vec![false, false, true, false]);
+        assert!(!registry.should_fail(RecoveryFaultPoint::PaymentsSync));
+    }
+
+    #[test]
+    fn test_probability_trigger_bounds() {
+        let registry = FaultInjectionRegistry::default();
+        registry.inject(
+            RecoveryFaultPoint::Redis,
+            RecoveryFaultTrigger::Probability(1.0),
+        );
+        registry.inject(
+            RecoveryFaultPoint::PaymentsSync,
+            RecoveryFaultTrigger::Probability(0.0),
+        );
+
+        assert!((0..10).all(|_| registry.should_fail(RecoveryFaultPoint::Redis)));
+        assert!((0..10).all(|_| !registry.should_fail(RecoveryFaultPoint::PaymentsSync)));
+
+        registry.clear(RecoveryFaultPoint::Redis);
+        assert!(!registry.should_fail(RecoveryFaultPoint::Redis));
+    }
+
+    #[test]
+    fn test_faults_are_parsed_from_config() {
+        let config = config::Config::builder()
+            .add_source(config::File::from_str(
+                r#"
+                [faults]
+                billing_connector_payments_sync = { probability = 1.0 }
+                process_tracker_insert = { countdown = 1 }
+                "#,
+                config::FileFormat::Toml,
+            ))
+            .build()
+            .expect("valid config")
+            .try_deserialize::<RecoveryFaultInjection>()
+            .expect("valid recovery fault injection config");
+        let registry = FaultInjectionRegistry::new(&config);
+
+        assert!(registry.should_fail(RecoveryFaultPoint::BillingConnectorPaymentsSync));
+        assert!(!registry.should_fail(RecoveryFaultPoint::ProcessTrackerInsert));
+        assert!(registry.should_fail(RecoveryFaultPoint::ProcessTrackerInsert));
+        assert!(!registry.should_fail(RecoveryFaultPoint::PaymentIntentCreate));
+    }
+}
@@ -0,0 +1,172 @@
+//! Conversions between UTC and the local time of an IANA timezone, for scheduling tasks at a given
+//! local time of the day.
+//!
+//! A local time which does not exist, because it falls in the gap of a daylight saving transition,
+//! is resolved using the offset in effect before the transition, which moves it forward by the
+//! length of the gap. A local time which occurs twice is resolved to its earlier occurrence.
+
+use chrono::{Offset, TimeZone};
+pub use chrono_tz::Tz;
+use time::PrimitiveDateTime;
+
+/// Parses an IANA timezone name such as `Asia/Kolkata` or `America/New_York`
+pub fn parse_timezone(timezone: &str) -> Option<Tz> {
+    timezone.parse::<Tz>().ok()
+}
+
+/// Converts the UTC time to the local time of the timezone
+pub fn to_local_time(utc_time: PrimitiveDateTime, timezone: Tz) -> PrimitiveDateTime {
+    let offset = to_chrono_naive_date_time(utc_time)
+        .map(|naive_utc_time| {
+            timezone
+                .offset_from_utc_datetime(&naive_utc_time)
+                .fix()
+                .local_minus_utc()
+        })
+        .unwrap_or_default();
+
+    utc_time.saturating_add(time::Duration::seconds(i64::from(offset)))
+}
+
+/// Converts the local time of the timezone to UTC
+pub fn to_utc_time(local_time: PrimitiveDateTime, timezone: Tz) -> PrimitiveDateTime {
+    let offset = to_chrono_naive_date_time(local_time)
+        .map(
+            |naive_local_time| match timezone.offset_from_local_datetime(&naive_local_time) {
+                chrono::LocalResult::Single(offset) => offset.fix().local_minus_utc(),
+                chrono::LocalResult::Ambiguous(earliest, _) => earliest.fix().local_minus_utc(),
+                chrono::LocalResult::None => {
+                    // Transitions are months apart, the offset a day earlier is the one in effect
+                    // before the transition
+                    let before_transition = naive_local_time
+                        .checked_sub_signed(chrono::Duration::days(1))
+                        .unwrap_or(naive_local_time);
+                    timezone
+                        .offset_from_utc_datetime(&before_transition)
+                        .fix()
+                        .local_minus_utc()
+                }
+            },
+        )
+        .unwrap_or_default();
+
+    local_time.saturating_sub(time::Duration::seconds(i64::from(offset)))
+}
+
+/// Returns the UTC time of the next occurrence of the hour of the day in the timezone, strictly
+/// after the given UTC time
+pub fn get_next_local_hour(
+    utc_time: PrimitiveDateTime,
+    timezone: Tz,
+    hour_of_day: u8,
+) -> Option<PrimitiveDateTime> {
+    let hour = time::Time::from_hms(hour_of_day, 0, 0).ok()?;
+    let local_date = to_local_time(utc_time, timezone).date();
+
+    let next_time = to_utc_time(PrimitiveDateTime::new(local_date, hour), timezone);
+    if next_time > utc_time {
+        return Some(next_time);
+    }
+
+    let next_local_date = local_date.next_day()?;
+    Some(to_utc_time(
+        PrimitiveDateTime::new(next_local_date, hour),
+        timezone,
+    ))
+}
+
+fn to_chrono_naive_date_time(date_time: PrimitiveDateTime) -> Option<chrono::NaiveDateTime> {
+    chrono::DateTime::from_timestamp(
+        date_time.assume_utc().unix_timestamp(),
+        date_time.nanosecond(),
+    )
+    .map(|date_time| date_time.naive_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn new_york() -> Tz {
+        parse_timezone("America/New_York").expect("valid timezone")
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert!(parse_timezone("Asia/Kolkata").is_some());
+        assert!(parse_timezone("UTC").is_some());
+        assert!(parse_timezone("Mars/Olympus_Mons").is_none());
+        assert!(parse_timezone("+05:30").is_none());
+    }
+
+    #[test]
+    fn test_conversions_with_fixed_offset() {
+        let kolkata = parse_timezone("Asia/Kolkata").expect("valid timezone");
+
+        assert_eq!(
+            to_local_time(datetime!(2024-06-01 20:00), kolkata),
+            datetime!(2024-06-02 01:30)
+        );
+        assert_eq!(
+            to_utc_time(datetime!(2024-06-02 01:30), kolkata),
+            datetime!(2024-06-01 20:00)
+        );
+    }
+
+    #[test]
+    fn test_nonexistent_local_time_moves_forward() {
+        // Clocks move from 02:00 EST to 03:00 EDT, 02:30 does not exist on the day
+        assert_eq!(
+            to_utc_time(datetime!(2024-03-10 02:00), new_york()),
+            datetime!(2024-03-10 07:00)
+        );
+        assert_eq!(
+            to_local_time(datetime!(2024-03-10 07:00), new_york()),
+            datetime!(2024-03-10 03:00)
+        );
+        assert_eq!(
+            to_utc_time(datetime!(2024-03-10 03:00), new_york()),
+            datetime!(2024-03-10 07:00)
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_local_time_resolves_to_earliest() {
+        // Clocks move from 02:00 EDT back to 01:00 EST, 01:00 occurs twice on the day
+        assert_eq!(
+            to_utc_time(datetime!(2024-11-03 01:00), new_york()),
+            datetime!(2024-11-03 05:00)
+        );
+        assert_eq!(
+            to_local_time(datetime!(2024-11-03 06:00), new_york()),
+            datetime!(2024-11-03 01:00)
+        );
+    }
+
+    #[test]
+    fn test_get_next_local_hour() {
+        // 08:00 EST, the hour has not passed yet on the day
+        assert_eq!(
+            get_next_local_hour(datetime!(2024-01-15 13:00), new_york(), 9),
+            Some(datetime!(2024-01-15 14:00))
+        );
+        // Exactly at the hour, the next occurrence is on the next day
+        assert_eq!(
+            get_next_local_hour(datetime!(2024-01-15 14:00), new_york(), 9),
+            Some(datetime!(2024-01-16 14:00))
+        );
+        // The offset changes between the two days
+        assert_eq!(
+            get_next_local_hour(datetime!(2024-03-09 14:30), new_york(), 9),
+            Some(datetime!(2024-03-10 13:00))
+        );
+        assert_eq!(
+            get_next_local_hour(datetime!(2024-01-15 13:00), new_york(), 24),
+            None
+        );
+    }
+}
@@ -279,6 +279,7 @@ pub async fn generate_sample_data(
             platform_merchant_id: None,
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
+            entity_version: common_utils::consts::INITIAL_ENTITY_VERSION,
         };
         let (connector_transaction_id, processor_transaction_data) =
             ConnectorTransactionId::form_id_and_data(attempt_id.clone());
@@ -10,4 +10,14 @@ pub mod refund_router;
 
 pub mod tokenized_data;
 
+pub mod webhook_bulk_retry;
+
+pub mod webhook_digest;
+
+pub mod webhook_events_retention;
+
 pub mod revenue_recovery;
+
+pub mod revenue_recovery_reconciliation;
+
+pub mod revenue_recovery_payments_sync_polling;
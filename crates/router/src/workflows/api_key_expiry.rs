@@ -1,3 +1,4 @@
+use api_models::webhook_events::WebhookMetaNotificationDetails;
 use common_utils::{errors::ValidationError, ext_traits::ValueExt, types::theme::ThemeLineage};
 use diesel_models::{
     enums as storage_enums, process_tracker::business_status, ApiKeyExpiryTrackingData,
@@ -6,7 +7,9 @@ use router_env::logger;
 use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerSessionState};
 
 use crate::{
-    consts, errors,
+    consts,
+    core::webhooks::meta_notifications,
+    errors,
     logger::error,
     routes::{metrics, SessionState},
     services::email::types::{self as email_types, ApiKeyExpiryReminder},
@@ -117,6 +120,24 @@ impl ProcessTrackerWorkflow<SessionState> for ApiKeyExpiryWorkflow {
             .await
             .map_err(errors::ProcessTrackerError::EEmailError)?;
 
+        let business_profiles = db
+            .list_profile_by_merchant_id(key_manager_satte, &key_store, &tracking_data.merchant_id)
+            .await?;
+        let occurrence = format!("{}_{expires_in}", tracking_data.prefix);
+        for business_profile in business_profiles {
+            meta_notifications::send_meta_notification(
+                state,
+                &business_profile,
+                WebhookMetaNotificationDetails::SecretRotationReminder {
+                    key_name: tracking_data.api_key_name.clone(),
+                    key_prefix: tracking_data.prefix.clone(),
+                    expires_in_days: *expires_in,
+                },
+                Some(&occurrence),
+            )
+            .await;
+        }
+
         // If all the mails have been sent, then retry_count would be equal to length of the expiry_reminder_days vector
         if retry_count
             == i32::try_from(tracking_data.expiry_reminder_days.len() - 1)
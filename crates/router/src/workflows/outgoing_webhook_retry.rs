@@ -100,6 +100,22 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        // The content of a scrubbed event must not be sent again, neither as stored nor regenerated
+        // from the current state of the resource
+        if initial_event.payload_scrubbed_at.is_some() {
+            logger::info!(
+                initial_attempt_id = %initial_event.event_id,
+                "The payload of the event has been scrubbed, finishing task"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::EVENT_PAYLOAD_SCRUBBED,
+                )
+                .await?;
+            return Ok(());
+        }
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -119,6 +135,10 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            entity_version: initial_event.entity_version,
+            payload_scrubbed_at: initial_event.payload_scrubbed_at,
+            delivery_latency_ms: None,
+            latest_delivery_status_code_class: None,
         };
 
         let event = db
@@ -144,6 +164,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                     delivery_attempt,
                     None,
                     Some(process),
+                    None,
                 ))
                 .await;
             }
@@ -178,6 +199,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             event_type,
                             content: content.clone(),
                             timestamp: event.created_at,
+                            entity_version: content.get_entity_version(),
                         };
 
                         let request_content = webhooks_core::get_outgoing_webhook_request(
@@ -202,6 +224,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             delivery_attempt,
                             Some(content),
                             Some(process),
+                            None,
                         ))
                         .await;
                     }
@@ -283,6 +306,20 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<time::PrimitiveDateTime> {
+    let retry_mapping = get_webhook_delivery_retry_mapping(db, merchant_id).await;
+
+    let time_delta = scheduler_utils::get_retry_mapping_schedule_time(&retry_mapping, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+/// Get the retry mapping used for scheduling the webhook delivery retries of the merchant.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub(crate) async fn get_webhook_delivery_retry_mapping(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> process_data::RetryMapping {
     let key = "pt_mapping_outgoing_webhooks";
 
     let result = db
@@ -312,13 +349,7 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
         },
     );
 
-    let time_delta = scheduler_utils::get_outgoing_webhook_retry_schedule_time(
-        mapping,
-        merchant_id,
-        retry_count,
-    );
-
-    scheduler_utils::get_time_from_delta(time_delta)
+    scheduler_utils::get_outgoing_webhook_retry_mapping(mapping, merchant_id)
 }
 
 /// Schedule the webhook delivery task for retry
@@ -427,7 +458,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
-                | ApplicationResponse::FileData(_) => {
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::FileStream(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
@@ -484,7 +516,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -516,7 +549,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -1,7 +1,8 @@
 #[cfg(feature = "v2")]
-use api_models::payments::PaymentsGetIntentRequest;
+use api_models::payments::{PaymentsGetIntentRequest, RevenueRecoveryRetryOverride};
 #[cfg(feature = "v2")]
 use common_utils::{
+    errors::CustomResult,
     ext_traits::{StringExt, ValueExt},
     id_type,
 };
@@ -49,12 +50,20 @@ impl ProcessTrackerWorkflow<SessionState> for ExecutePcrWorkflow {
         state: &'a SessionState,
         process: storage::ProcessTracker,
     ) -> Result<(), errors::ProcessTrackerError> {
+        let execution_started_at = common_utils::date_time::now();
         let tracking_data = process
             .tracking_data
             .clone()
             .parse_value::<pcr_storage_types::PcrWorkflowTrackingData>(
             "PCRWorkflowTrackingData",
         )?;
+        // The correlation id ties the execution to the webhook which scheduled the task
+        logger::info!(
+            process_id = %process.id,
+            task = ?process.name,
+            recovery_correlation_id = ?tracking_data.recovery_correlation_id,
+            "Executing the revenue recovery task"
+        );
         let request = PaymentsGetIntentRequest {
             id: tracking_data.global_payment_id.clone(),
         };
@@ -96,7 +105,23 @@ impl ProcessTrackerWorkflow<SessionState> for ExecutePcrWorkflow {
             )
             .await?;
 
+        pcr::scheduler_lag::record_schedule_lag(
+            &process,
+            execution_started_at,
+            &tracking_data.merchant_id,
+            &billing_mca.connector_name.to_string(),
+        );
+
         match process.name.as_deref() {
+            Some("CALCULATE_WORKFLOW") => {
+                pcr::perform_calculate_workflow(
+                    state,
+                    &process,
+                    &payment_data.payment_intent,
+                    &pcr_data.profile,
+                )
+                .await
+            }
             Some("EXECUTE_WORKFLOW") => {
                 Box::pin(pcr::perform_execute_payment(
                     state,
@@ -109,12 +134,53 @@ impl ProcessTrackerWorkflow<SessionState> for ExecutePcrWorkflow {
                 ))
                 .await
             }
+            Some("NOTIFY_WORKFLOW") => {
+                pcr::retry_notification::perform_notify_workflow(
+                    state,
+                    &process,
+                    &pcr_data,
+                    &payment_data.payment_intent,
+                )
+                .await
+            }
+            Some("RECORD_BACK_WORKFLOW") => {
+                pcr::record_back::perform_record_back_workflow(
+                    state,
+                    &process,
+                    &tracking_data,
+                    &pcr_data,
+                    &payment_data.payment_intent,
+                    &billing_mca,
+                )
+                .await
+            }
             Some("PSYNC_WORKFLOW") => todo!(),
 
             Some("REVIEW_WORKFLOW") => todo!(),
             _ => Err(errors::ProcessTrackerError::JobNotFound),
         }
     }
+
+    #[cfg(feature = "v2")]
+    async fn success_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) {
+        pcr::dead_letter::reset_execution_failures(state, &process).await
+    }
+
+    /// The declines of the payment are handled by the retry flow without failing the execution,
+    /// the errors reaching here are the failures of the execution itself
+    #[cfg(feature = "v2")]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        Ok(pcr::dead_letter::handle_execution_failure(state, process, error).await?)
+    }
 }
 #[cfg(feature = "v2")]
 pub(crate) async fn extract_data_and_perform_action(
@@ -156,12 +222,204 @@ pub(crate) async fn extract_data_and_perform_action(
     Ok(pcr_payment_data)
 }
 
+/// Schedule time of the next retry of a payment
+#[cfg(feature = "v2")]
+#[derive(Debug)]
+pub(crate) struct RetrySchedule {
+    pub schedule_time: time::PrimitiveDateTime,
+    /// Decline class of the failed attempt, whose delay profile was used for the schedule time
+    pub decline_class: Option<String>,
+    /// Retry window of the merchant into which the schedule time is to be moved. Retries
+    /// scheduled by a retry override are made at the time asked for, without a window.
+    pub retry_window: Option<process_data::RevenueRecoveryRetryWindow>,
+    pub algorithm: common_enums::RecoveryScheduleAlgorithm,
+    /// Time at which the schedule time was computed
+    pub computed_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v2")]
+impl RetrySchedule {
+    /// The decision of the retry, given the schedule time of the retry after its adjustments
+    pub fn get_schedule_decision(
+        &self,
+        schedule_time: time::PrimitiveDateTime,
+    ) -> pcr_storage_types::RecoveryScheduleDecision {
+        pcr_storage_types::RecoveryScheduleDecision::new(
+            self.algorithm,
+            self.decline_class.clone(),
+            self.computed_at,
+            self.schedule_time,
+            schedule_time,
+        )
+    }
+}
+
+/// Schedule time of the next retry of a payment by the retry schedule of the merchant, without a
+/// retry override or the delay profile of a decline class. The retry algorithm and the retry
+/// schedule defined by the merchant are applied as for [`get_schedule_time_for_next_retry`].
 #[cfg(feature = "v2")]
-pub(crate) async fn get_schedule_time_to_retry_mit_payments(
+pub async fn get_schedule_time_to_retry_mit_payments(
     db: &dyn StorageInterface,
     merchant_id: &id_type::MerchantId,
     retry_count: i32,
 ) -> Option<time::PrimitiveDateTime> {
+    // The attempt count and the network retry limit only apply to a retry override
+    get_schedule_time_for_next_retry(db, merchant_id, retry_count, 0, None, 0, &[])
+        .await
+        .map(|retry_schedule| retry_schedule.schedule_time)
+}
+
+/// Schedule time of the next retry of the payment. The retry override of the payment, if any,
+/// takes precedence over the retry schedule configured for the merchant. Otherwise the delay
+/// profile of the decline class of the `error_codes` of the failed attempt, if any, decides when
+/// the retry is made. The retry schedule defined by the merchant, if any, is used in place of the
+/// retry configuration of the merchant.
+#[cfg(feature = "v2")]
+pub(crate) async fn get_schedule_time_for_next_retry(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    retry_count: i32,
+    attempt_count: u16,
+    retry_override: Option<&RevenueRecoveryRetryOverride>,
+    network_retry_limit: u16,
+    error_codes: &[&str],
+) -> Option<RetrySchedule> {
+    get_schedule_time_for_retry_after_failure(
+        db,
+        merchant_id,
+        retry_count,
+        attempt_count,
+        retry_override,
+        network_retry_limit,
+        error_codes,
+        common_utils::date_time::now(),
+    )
+    .await
+}
+
+/// Schedule time of the next retry of the payment, as computed at the given time at which the
+/// attempt of the payment failed. See [`get_schedule_time_for_next_retry`].
+#[cfg(feature = "v2")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn get_schedule_time_for_retry_after_failure(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    retry_count: i32,
+    attempt_count: u16,
+    retry_override: Option<&RevenueRecoveryRetryOverride>,
+    network_retry_limit: u16,
+    error_codes: &[&str],
+    current_time: time::PrimitiveDateTime,
+) -> Option<RetrySchedule> {
+    let mapping = get_pcr_payments_retry_mapping(db).await;
+    let custom_retry_schedule = pcr::retry_schedule::get_retry_schedule(db, merchant_id).await;
+    let get_time_from_delta = |delta: Option<i32>| {
+        delta.map(|delta| current_time.saturating_add(time::Duration::seconds(delta.into())))
+    };
+
+    if let Some(retry_override) = retry_override {
+        // the retries granted by the override continue beyond the retries configured for the merchant
+        let merchant_schedule_time = get_time_from_delta(match &custom_retry_schedule {
+            Some(custom_retry_schedule) => Some(
+                scheduler_utils::get_pcr_payments_extended_custom_retry_schedule_time(
+                    custom_retry_schedule,
+                    retry_count,
+                ),
+            ),
+            None => scheduler_utils::get_pcr_payments_extended_retry_schedule_time(
+                mapping.clone(),
+                merchant_id,
+                retry_count,
+            ),
+        });
+
+        let schedule_time = types::get_schedule_time_for_retry_override(
+            retry_override,
+            attempt_count,
+            network_retry_limit,
+            merchant_schedule_time,
+            current_time,
+        );
+
+        if let Some(schedule_time) = schedule_time {
+            logger::info!(
+                ?schedule_time,
+                attempt_count,
+                "Using the retry override to schedule the next retry"
+            );
+            return Some(RetrySchedule {
+                schedule_time,
+                decline_class: None,
+                retry_window: None,
+                algorithm: common_enums::RecoveryScheduleAlgorithm::RetryOverride,
+                computed_at: current_time,
+            });
+        }
+    }
+
+    let decline_class_schedule_time =
+        scheduler_utils::get_pcr_decline_class_schedule_time(&mapping, error_codes, current_time)
+            .map(|(decline_class, schedule_time)| (decline_class.to_string(), schedule_time));
+    let retry_window =
+        scheduler_utils::get_pcr_payments_retry_window(&mapping, merchant_id).cloned();
+
+    // The delay profile of the decline class only decides when the retry is made, whether the
+    // payment is retried at all is still decided by the retry schedule of the merchant
+    let schedule_time = get_time_from_delta(match &custom_retry_schedule {
+        Some(custom_retry_schedule) => {
+            scheduler_utils::get_pcr_payments_custom_retry_schedule_time(
+                custom_retry_schedule,
+                retry_count,
+            )
+        }
+        None => {
+            scheduler_utils::get_pcr_payments_retry_schedule_time(mapping, merchant_id, retry_count)
+        }
+    })?;
+
+    match decline_class_schedule_time {
+        Some((decline_class, decline_class_schedule_time)) => {
+            logger::info!(
+                %decline_class,
+                ?error_codes,
+                ?schedule_time,
+                ?decline_class_schedule_time,
+                "Using the delay profile of the decline class to schedule the next retry"
+            );
+            Some(RetrySchedule {
+                schedule_time: decline_class_schedule_time,
+                decline_class: Some(decline_class),
+                retry_window,
+                algorithm: common_enums::RecoveryScheduleAlgorithm::DeclineClassDelayProfile,
+                computed_at: current_time,
+            })
+        }
+        None => Some(RetrySchedule {
+            schedule_time,
+            decline_class: None,
+            retry_window,
+            algorithm: common_enums::RecoveryScheduleAlgorithm::MerchantRetrySchedule,
+            computed_at: current_time,
+        }),
+    }
+}
+
+/// Max recovery window of the payments of the merchant in seconds, counted from the creation of
+/// the payment. The payments are retried indefinitely when it is not configured.
+#[cfg(feature = "v2")]
+pub(crate) async fn get_max_recovery_window_in_secs(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> Option<i64> {
+    let mapping = get_pcr_payments_retry_mapping(db).await;
+    scheduler_utils::get_pcr_payments_retry_window(&mapping, merchant_id)
+        .and_then(|retry_window| retry_window.max_recovery_window_in_secs)
+}
+
+#[cfg(feature = "v2")]
+pub(crate) async fn get_pcr_payments_retry_mapping(
+    db: &dyn StorageInterface,
+) -> process_data::RevenueRecoveryPaymentProcessTrackerMapping {
     let key = "pt_mapping_pcr_retries";
     let result = db
         .find_config_by_key(key)
@@ -173,7 +431,7 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
                 .change_context(StorageError::DeserializationFailed)
         });
 
-    let mapping = result.map_or_else(
+    result.map_or_else(
         |error| {
             if error.current_context().is_db_not_found() {
                 logger::debug!("Revenue Recovery retry config `{key}` not found, ignoring");
@@ -189,10 +447,5 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
             logger::debug!(?mapping, "Using custom pcr payments retry config");
             mapping
         },
-    );
-
-    let time_delta =
-        scheduler_utils::get_pcr_payments_retry_schedule_time(mapping, merchant_id, retry_count);
-
-    scheduler_utils::get_time_from_delta(time_delta)
+    )
 }
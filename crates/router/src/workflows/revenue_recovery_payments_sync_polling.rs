@@ -0,0 +1,113 @@
+#[cfg(feature = "v2")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v2")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v2")]
+use router_env::logger;
+use scheduler::workflows::ProcessTrackerWorkflow;
+#[cfg(feature = "v2")]
+use scheduler::SchedulerSessionState;
+
+#[cfg(feature = "v2")]
+use crate::{
+    core::revenue_recovery::payments_sync_polling,
+    types::storage::revenue_recovery::RecoveryPaymentsSyncPollingTrackingData,
+};
+use crate::{errors, logger::error, routes::SessionState, types::storage};
+
+pub struct RevenueRecoveryPaymentsSyncPollingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RevenueRecoveryPaymentsSyncPollingWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(())
+    }
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: RecoveryPaymentsSyncPollingTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RecoveryPaymentsSyncPollingTrackingData")?;
+        let key_manager_state = &state.into();
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let billing_connector_account = db
+            .find_merchant_connector_account_by_id(
+                key_manager_state,
+                &tracking_data.billing_connector_id,
+                &key_store,
+            )
+            .await?;
+
+        let Some(polling) =
+            payments_sync_polling::get_payments_sync_polling(state, &billing_connector_account)
+        else {
+            logger::info!(
+                "Payments sync polling is disabled for the billing connector, completing the task"
+            );
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        };
+
+        payments_sync_polling::perform_payments_sync_poll(
+            state,
+            process,
+            tracking_data,
+            polling,
+            &merchant_account,
+            &key_store,
+            &profile,
+            &billing_connector_account,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
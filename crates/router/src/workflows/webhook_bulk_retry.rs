@@ -0,0 +1,60 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use router_env::tracing::{self, instrument};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+#[cfg(feature = "v1")]
+use crate::core::webhooks::bulk_retry;
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct WebhookBulkRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookBulkRetryWorkflow {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        match process.name.as_deref() {
+            Some(bulk_retry::WEBHOOK_BULK_RETRY_JOB_NAME) => {
+                let tracking_data: storage::WebhookBulkRetryJobTrackingData = process
+                    .tracking_data
+                    .clone()
+                    .parse_value("WebhookBulkRetryJobTrackingData")?;
+                bulk_retry::enqueue_bulk_retry_events(state, process, tracking_data).await?;
+            }
+            Some(bulk_retry::WEBHOOK_BULK_RETRY_EVENT_NAME) => {
+                let tracking_data: storage::WebhookBulkRetryTrackingData = process
+                    .tracking_data
+                    .clone()
+                    .parse_value("WebhookBulkRetryTrackingData")?;
+                bulk_retry::retry_bulk_retry_event(state, process, tracking_data).await?;
+            }
+            _ => Err(errors::ProcessTrackerError::UnexpectedFlow)?,
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)
+    }
+
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,130 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerSessionState};
+
+use crate::{
+    core::webhooks::{meta_notifications, webhook_digest},
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, WebhookDigestTrackingData},
+};
+
+pub struct WebhookDigestWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookDigestWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: WebhookDigestTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookDigestTrackingData")?;
+        let key_manager_state = &state.into();
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let Some(digest_config) = webhook_digest::get_enabled_digest_config(&business_profile)
+        else {
+            logger::info!("Webhook digest is disabled for the profile, completing the task");
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        };
+
+        let window = webhook_digest::get_digest_window(
+            digest_config,
+            process
+                .schedule_time
+                .unwrap_or_else(common_utils::date_time::now),
+        )
+        .ok_or(errors::ProcessTrackerError::EApiErrorResponse)?;
+
+        let should_send_digest =
+            webhook_digest::should_send_digest(tracking_data.last_digest_date, window.digest_date);
+
+        webhook_digest::claim_digest_and_reschedule_task(
+            state,
+            process,
+            &tracking_data,
+            digest_config,
+            &window,
+        )
+        .await?;
+
+        if !should_send_digest {
+            logger::info!(digest_date = %window.digest_date, "Webhook digest already sent for the date");
+            return Ok(());
+        }
+
+        let digest = webhook_digest::build_webhook_digest(
+            state,
+            &key_store,
+            &business_profile,
+            digest_config,
+            &window,
+        )
+        .await?;
+
+        meta_notifications::notify_delivery_backlog(
+            state,
+            &business_profile,
+            digest.backlog_size,
+            window.end.saturating_sub(time::Duration::hours(i64::from(
+                state.conf.webhooks.digest.backlog_window_in_hours,
+            ))),
+        )
+        .await;
+
+        webhook_digest::send_webhook_digest(
+            state,
+            &merchant_account,
+            &business_profile,
+            digest_config,
+            digest,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+use common_utils::ext_traits::ValueExt;
+use router_env::tracing::{self, instrument};
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::webhooks::webhook_events_retention;
+use crate::{errors, logger::error, routes::SessionState, types::storage};
+
+pub struct WebhookEventsRetentionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookEventsRetentionWorkflow {
+    #[cfg(all(feature = "olap", feature = "v1"))]
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::WebhookEventsRetentionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookEventsRetentionTrackingData")?;
+
+        // The task is moved to the next day before the run, a failed run is retried then
+        webhook_events_retention::reschedule_events_retention_task(state, process).await?;
+
+        webhook_events_retention::delete_expired_events(
+            state,
+            &tracking_data.merchant_id,
+            webhook_events_retention::EventsArchiveSink::from_state(state).as_ref(),
+            &state.conf.webhooks.retention,
+            common_utils::date_time::now(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[cfg(not(all(feature = "olap", feature = "v1")))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)
+    }
+
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, ?error, "Failed while executing workflow");
+        Ok(())
+    }
+}
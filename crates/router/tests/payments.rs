@@ -458,6 +458,7 @@ async fn payments_create_core() {
         force_3ds_challenge_trigger: None,
         issuer_error_code: None,
         issuer_error_message: None,
+        entity_version: None,
     };
     let expected_response =
         services::ApplicationResponse::JsonWithHeaders((expected_response, vec![]));
@@ -729,6 +730,7 @@ async fn payments_create_core_adyen_no_redirect() {
             force_3ds_challenge_trigger: None,
             issuer_error_code: None,
             issuer_error_message: None,
+            entity_version: None,
         },
         vec![],
     ));
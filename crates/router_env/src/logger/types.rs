@@ -515,10 +515,38 @@ pub enum Flow {
     DeleteTheme,
     /// List initial webhook delivery attempts
     WebhookEventInitialDeliveryAttemptList,
+    /// Retrieve a webhook event along with its latest delivery attempt
+    WebhookEventRetrieve,
     /// List delivery attempts for a webhook event
     WebhookEventDeliveryAttemptList,
+    /// Retrieve the complete request and response of a delivery attempt of a webhook event
+    WebhookEventDeliveryAttemptPayloadRetrieve,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Retry the failed deliveries of the webhook events of a merchant in bulk
+    WebhookEventBulkRetry,
+    /// Retrieve the progress of a bulk retry job of webhook deliveries
+    WebhookEventBulkRetryJobRetrieve,
+    /// Retrieve the webhook delivery statistics of a merchant or a profile
+    WebhookDeliveryStats,
+    /// Confirm a pending change of the outgoing webhook URL
+    WebhookUrlChangeConfirm,
+    /// Force confirm a pending change of the outgoing webhook URL
+    WebhookUrlChangeForceConfirm,
+    /// Preview the delivery attempts produced by a webhook delivery retry policy
+    WebhookRetryPolicyPreview,
+    /// Scrub the stored payloads of the delivery attempts of a webhook event
+    WebhookEventPayloadScrub,
+    /// Export the webhook events of a merchant as a file
+    WebhookEventExport,
+    /// List the event types sent to the webhook URL, with the schemas of their payloads
+    WebhookEventTypeList,
+    /// Update the event types whose webhooks are sent to a profile
+    WebhookEventSubscriptionUpdate,
+    /// Schedule the retention job of the webhook events of a merchant
+    WebhookEventsRetentionSchedule,
+    /// List the archives of the webhook events of a merchant
+    WebhookEventsArchiveList,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -580,11 +608,98 @@ pub enum Flow {
     TotalPaymentMethodCount,
     /// Process Tracker Revenue Recovery Workflow Retrieve
     RevenueRecoveryRetrieve,
+    /// Process Tracker Revenue Recovery Workflow Analytics
+    RevenueRecoveryAnalytics,
+    /// Revenue Recovery retry override flow
+    RevenueRecoveryRetryOverride,
+    /// Revenue Recovery cancel scheduled retry flow
+    RevenueRecoveryCancel,
+    /// Revenue Recovery reschedule retry flow
+    RevenueRecoveryReschedule,
+    /// Revenue Recovery status of a payment flow
+    RevenueRecoveryStatus,
+    /// Revenue Recovery decisions of a payment list flow
+    RevenueRecoveryDecisionList,
+    /// Revenue Recovery manual collection items list flow
+    RevenueRecoveryCollectionList,
+    /// Revenue Recovery manual collection item assign flow
+    RevenueRecoveryCollectionAssign,
+    /// Revenue Recovery manual collection item resolve flow
+    RevenueRecoveryCollectionResolve,
+    /// Revenue Recovery tasks of a merchant list flow
+    RevenueRecoveryTaskList,
+    /// Revenue Recovery tasks of a merchant in review list flow
+    RevenueRecoveryReviewTaskList,
+    /// Revenue Recovery task in review requeue flow
+    RevenueRecoveryReviewTaskRequeue,
+    /// Revenue Recovery stuck tasks of a merchant bulk requeue flow
+    RevenueRecoveryTaskBulkRequeue,
+    /// Revenue Recovery global kill switch retrieve flow
+    RevenueRecoveryKillSwitchRetrieve,
+    /// Revenue Recovery global kill switch update flow
+    RevenueRecoveryKillSwitchUpdate,
+    /// Revenue Recovery retry schedule of a merchant create flow
+    RevenueRecoveryRetryScheduleCreate,
+    /// Revenue Recovery retry schedule of a merchant retrieve flow
+    RevenueRecoveryRetryScheduleRetrieve,
+    /// Revenue Recovery retry schedule of a merchant update flow
+    RevenueRecoveryRetryScheduleUpdate,
+    /// Revenue Recovery retry schedule of a merchant delete flow
+    RevenueRecoveryRetryScheduleDelete,
+    /// Revenue Recovery reconciliation reports of a billing connector retrieve flow
+    RevenueRecoveryReconciliationReportsRetrieve,
+    /// Revenue Recovery overdue tasks of a merchant retrieve flow
+    RevenueRecoveryOverdueTasksRetrieve,
+    /// Revenue Recovery schedule time of the next retry preview flow
+    RevenueRecoverySchedulePreview,
+    /// Revenue Recovery overview of a merchant retrieve flow
+    RevenueRecoveryOverviewRetrieve,
+    /// Revenue Recovery snapshot of a task retrieve flow
+    RevenueRecoveryTaskSnapshotRetrieve,
+    /// Revenue Recovery payments sync with the billing connector made for debugging flow
+    RevenueRecoveryBillingSyncDebug,
+}
+
+/// Heavy read APIs which are rate limited per credential, each group having its own limits
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RateLimitedApi {
+    /// Listing and retrieval of the outgoing webhook events, along with their delivery attempts
+    EventsList,
+    /// Generation of the reports exported by the analytics service, and exports of the outgoing
+    /// webhook events
+    Export,
+    /// Search over the payment attempts, refunds and disputes
+    Search,
+    /// Metrics and filters served by the analytics service, and the statistics of the outgoing
+    /// webhook deliveries
+    Analytics,
+    /// Calls made to the billing connectors for debugging the revenue recovery
+    RecoveryDebug,
 }
 
 /// Trait for providing generic behaviour to flow metric
-pub trait FlowMetric: ToString + std::fmt::Debug + Clone {}
-impl FlowMetric for Flow {}
+pub trait FlowMetric: ToString + std::fmt::Debug + Clone {
+    /// Group of rate limited APIs to which the flow belongs, if it is rate limited
+    fn get_rate_limited_api(&self) -> Option<RateLimitedApi> {
+        None
+    }
+}
+
+impl FlowMetric for Flow {
+    fn get_rate_limited_api(&self) -> Option<RateLimitedApi> {
+        match self {
+            Self::WebhookEventInitialDeliveryAttemptList
+            | Self::WebhookEventDeliveryAttemptList
+            | Self::WebhookEventRetrieve => Some(RateLimitedApi::EventsList),
+            Self::WebhookEventExport => Some(RateLimitedApi::Export),
+            Self::WebhookDeliveryStats => Some(RateLimitedApi::Analytics),
+            Self::RevenueRecoveryBillingSyncDebug => Some(RateLimitedApi::RecoveryDebug),
+            _ => None,
+        }
+    }
+}
 
 /// Category of log event.
 #[derive(Debug)]
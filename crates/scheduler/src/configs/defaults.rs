@@ -7,6 +7,7 @@ impl Default for super::settings::SchedulerSettings {
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
+            workflow_retries: super::settings::WorkflowRetrySettings::default(),
         }
     }
 }
@@ -41,3 +42,34 @@ impl Default for super::settings::Server {
         }
     }
 }
+
+impl Default for super::settings::WorkflowRetryLimits {
+    fn default() -> Self {
+        Self {
+            max_execution_retries: 2,
+            // 1 minute
+            retry_backoff_in_secs: 60,
+            // 15 minutes
+            max_retry_backoff_in_secs: 900,
+        }
+    }
+}
+
+impl super::settings::WorkflowRetryLimits {
+    /// Defaults of the runners whose runs are retried differently from the rest, used unless the
+    /// runner has its own limits configured
+    pub fn get_runner_default(runner: diesel_models::enums::ProcessTrackerRunner) -> Option<Self> {
+        match runner {
+            // The recovery executor talks to the billing and payment connectors, whose transient
+            // failures are tolerated for longer before the task is moved to review
+            diesel_models::enums::ProcessTrackerRunner::PassiveRecoveryWorkflow => Some(Self {
+                max_execution_retries: 5,
+                // 5 minutes
+                retry_backoff_in_secs: 300,
+                // 1 hour
+                max_retry_backoff_in_secs: 3600,
+            }),
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+
+use diesel_models::enums::ProcessTrackerRunner;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
@@ -10,6 +13,7 @@ pub struct SchedulerSettings {
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
+    pub workflow_retries: WorkflowRetrySettings,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -37,3 +41,50 @@ pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
 }
+
+/// Limits on the requeues of the runs of a workflow which fail with an error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct WorkflowRetryLimits {
+    /// Number of times a task whose runs keep failing in a row is requeued, before it is no longer
+    /// picked up
+    pub max_execution_retries: u16,
+    /// Number of seconds after which a failed run is requeued, doubled with every run failing in a
+    /// row
+    pub retry_backoff_in_secs: u32,
+    /// Upper limit of the number of seconds after which a failed run is requeued
+    pub max_retry_backoff_in_secs: u32,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct WorkflowRetrySettings {
+    /// Limits of the runners which have neither their own limits configured nor their own
+    /// defaults
+    pub default: WorkflowRetryLimits,
+    /// Limits configured for each runner, keyed by the name of the runner. The name is matched
+    /// regardless of its case, since the keys of the configuration overridden through the
+    /// environment variables are lowercased.
+    #[serde(deserialize_with = "deserialize_runner_retry_limits")]
+    pub runners: HashMap<ProcessTrackerRunner, WorkflowRetryLimits>,
+}
+
+fn deserialize_runner_retry_limits<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<ProcessTrackerRunner, WorkflowRetryLimits>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    HashMap::<String, WorkflowRetryLimits>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(runner, retry_limits)| {
+            runner
+                .to_uppercase()
+                .parse::<ProcessTrackerRunner>()
+                .map(|runner| (runner, retry_limits))
+                .map_err(|_| {
+                    serde::de::Error::custom(format!("unknown process tracker runner `{runner}`"))
+                })
+        })
+        .collect()
+}
@@ -21,6 +21,8 @@ impl super::settings::SchedulerSettings {
 
         self.server.validate()?;
 
+        self.workflow_retries.validate()?;
+
         Ok(())
     }
 }
@@ -44,3 +46,34 @@ impl super::settings::Server {
         })
     }
 }
+
+impl super::settings::WorkflowRetrySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.default.validate("default")?;
+
+        self.runners
+            .iter()
+            .try_for_each(|(runner, retry_limits)| retry_limits.validate(&runner.to_string()))
+    }
+}
+
+impl super::settings::WorkflowRetryLimits {
+    pub fn validate(&self, runner: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.retry_backoff_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "workflow retry backoff of `{runner}` must be greater than 0"
+            )))
+        })?;
+
+        when(
+            self.max_retry_backoff_in_secs < self.retry_backoff_in_secs,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "max workflow retry backoff of `{runner}` must not be less than its retry backoff"
+            )))
+            },
+        )
+    }
+}
@@ -30,7 +30,19 @@ use crate::{
 
 // Valid consumer business statuses
 pub fn valid_business_statuses() -> Vec<&'static str> {
-    vec![storage::business_status::PENDING]
+    vec![
+        storage::business_status::PENDING,
+        // Revenue recovery tasks which were pushed back, and are to be picked up again at their
+        // schedule time
+        storage::business_status::EXECUTE_WORKFLOW_PAUSED_FOR_DISPUTE,
+        storage::business_status::EXECUTE_WORKFLOW_HALTED_BY_KILL_SWITCH,
+        storage::business_status::CALCULATE_WORKFLOW_HALTED_BY_KILL_SWITCH,
+        storage::business_status::NOTIFY_WORKFLOW_HALTED_BY_KILL_SWITCH,
+        storage::business_status::RECOVERY_WORKFLOW_RESUMED_AFTER_KILL_SWITCH,
+        storage::business_status::EXECUTE_WORKFLOW_THROTTLED,
+        storage::business_status::EXECUTE_WORKFLOW_REQUEUED_FOR_CONNECTOR_TIMEOUT,
+        storage::business_status::RECOVERY_WORKFLOW_EXECUTION_FAILED,
+    ]
 }
 
 #[instrument(skip_all)]
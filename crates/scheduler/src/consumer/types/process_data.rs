@@ -92,6 +92,129 @@ pub struct RevenueRecoveryPaymentProcessTrackerMapping {
 
     /// Merchant-specific retry configuration.
     pub custom_merchant_mapping: HashMap<common_utils::id_type::MerchantId, RetryMapping>,
+
+    /// Default (fallback) retry algorithm used when no merchant-specific retry algorithm exists.
+    #[serde(default)]
+    pub default_retry_algorithm: RevenueRecoveryRetryAlgorithm,
+
+    /// Merchant-specific retry algorithm.
+    #[serde(default)]
+    pub custom_merchant_retry_algorithm:
+        HashMap<common_utils::id_type::MerchantId, RevenueRecoveryRetryAlgorithm>,
+
+    /// Decline classes of the error codes of the failed attempts, keyed by the error code. The
+    /// unified error code of an attempt is used when available, and the error code sent by the
+    /// connector otherwise.
+    #[serde(default)]
+    pub decline_classes: HashMap<String, String>,
+
+    /// Delay profiles used for scheduling the retry after a decline, keyed by the decline class.
+    /// The retries after the declines of the other classes are scheduled using the retry
+    /// algorithm.
+    #[serde(default)]
+    pub decline_class_delay_profiles: HashMap<String, RevenueRecoveryDelayProfile>,
+
+    /// Default (fallback) retry window used when no merchant-specific retry window exists. The
+    /// retries are not moved into a window when neither is configured.
+    #[serde(default)]
+    pub default_retry_window: Option<RevenueRecoveryRetryWindow>,
+
+    /// Merchant-specific retry window.
+    #[serde(default)]
+    pub custom_merchant_retry_window:
+        HashMap<common_utils::id_type::MerchantId, RevenueRecoveryRetryWindow>,
+}
+
+/// Hours of the day, in the local time of the cardholder, in which the revenue recovery retries
+/// are made. A retry scheduled outside of the window is moved forward to the next start of the
+/// window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevenueRecoveryRetryWindow {
+    /// Hour of the day (0-23) at which the window starts.
+    pub start_hour: u8,
+
+    /// Hour of the day (0-23) at which the window ends, exclusive. A window which ends before it
+    /// starts spans midnight, e.g. 22 to 6, and one which ends when it starts spans the whole day.
+    pub end_hour: u8,
+
+    /// IANA timezone of the window, e.g. `America/New_York`, used when the timezone of the
+    /// country of the card issuer is not configured.
+    pub timezone: String,
+
+    /// IANA timezones keyed by the country of the card issuer.
+    #[serde(default)]
+    pub card_issuing_country_timezones: HashMap<enums::CountryAlpha2, String>,
+
+    /// Time since the creation of the payment after which it is no longer recovered, in seconds.
+    /// A retry is not moved into the window if that would take it beyond this time.
+    pub max_recovery_window_in_secs: Option<i64>,
+}
+
+/// Delay after which a declined payment is retried, used in place of the delay computed by the
+/// retry algorithm.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RevenueRecoveryDelayProfile {
+    /// The payment is retried after a delay picked randomly between the bounds, e.g. 3 to 5 days
+    /// for the do not honor declines.
+    Delay {
+        min_delay_in_secs: i32,
+        max_delay_in_secs: i32,
+    },
+
+    /// The payment is retried on the next of the days of the month at the hour (UTC), e.g. around
+    /// the paydays for the insufficient funds declines. The days beyond the end of a month fall on
+    /// the last day of the month.
+    DaysOfMonth { days: Vec<u8>, hour: u8 },
+}
+
+/// Algorithm used for computing the delays of the revenue recovery retries.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RevenueRecoveryRetryAlgorithm {
+    /// The delays are taken from the retry mapping.
+    #[default]
+    StaticMapping,
+
+    /// The delays grow exponentially with the retry count and are randomly spread out, so that the
+    /// invoices which failed in the same billing run are not retried at the same time.
+    ExponentialBackoff(ExponentialBackoffRetryConfig),
+}
+
+/// Configuration of the exponential backoff retry algorithm.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExponentialBackoffRetryConfig {
+    /// Delay of the first attempt, in seconds.
+    pub base_delay_in_secs: i32,
+
+    /// Factor by which the delay grows with every retry.
+    pub multiplier: f64,
+
+    /// Upper limit of the delay before the jitter is applied, in seconds.
+    pub max_delay_in_secs: i32,
+
+    /// Percentage of the delay by which the delay is randomly moved earlier or later.
+    pub jitter_percentage: u8,
+
+    /// Number of retries after which the payment is no longer retried.
+    pub max_retries: i32,
+}
+
+/// Retry schedule defined by a merchant for its revenue recovery retries, used in place of the
+/// retry mapping and the retry algorithm of the merchant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevenueRecoveryRetrySchedule {
+    /// Name of the schedule given by the merchant.
+    pub name: String,
+
+    /// Delays of the retries, in seconds, indexed by the retry count.
+    pub delays_in_secs: Vec<i32>,
+
+    /// Delay of the retries beyond the delays of the schedule, in seconds.
+    pub default_delay_in_secs: i32,
+
+    /// Number of retries after which the payment is no longer retried.
+    pub max_retries: i32,
 }
 
 impl Default for RevenueRecoveryPaymentProcessTrackerMapping {
@@ -115,6 +238,12 @@ impl Default for RevenueRecoveryPaymentProcessTrackerMapping {
                 ],
             },
             custom_merchant_mapping: HashMap::new(),
+            default_retry_algorithm: RevenueRecoveryRetryAlgorithm::default(),
+            custom_merchant_retry_algorithm: HashMap::new(),
+            decline_classes: HashMap::new(),
+            decline_class_delay_profiles: HashMap::new(),
+            default_retry_window: None,
+            custom_merchant_retry_window: HashMap::new(),
         }
     }
 }
@@ -62,6 +62,51 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_pending_processes_by_tag_runner(
+        &self,
+        tags: Vec<String>,
+        runner: storage_enums::ProcessTrackerRunner,
+        time_lower_limit: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn list_processes_by_merchant_task_constraints(
+        &self,
+        constraints: storage::process_tracker::ProcessTrackerMerchantTaskConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn count_overdue_processes_by_runner(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        scheduled_before: PrimitiveDateTime,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn count_unfinished_processes_by_merchant(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        scheduled_before: PrimitiveDateTime,
+    ) -> CustomResult<
+        storage::process_tracker::ProcessTrackerMerchantTaskCounts,
+        errors::StorageError,
+    >;
+
+    async fn count_finished_processes_by_merchant_business_status(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        business_status: &'static str,
+        finished_after: PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn count_processes_by_tag_runner_status(
+        &self,
+        tag: &str,
+        runner: storage_enums::ProcessTrackerRunner,
+    ) -> CustomResult<Vec<(storage_enums::ProcessTrackerStatus, String, i64)>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -107,6 +152,105 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_pending_processes_by_tag_runner(
+        &self,
+        tags: Vec<String>,
+        runner: storage_enums::ProcessTrackerRunner,
+        time_lower_limit: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_pending_processes_by_tag_runner(
+            &conn,
+            tags,
+            &runner.to_string(),
+            time_lower_limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn list_processes_by_merchant_task_constraints(
+        &self,
+        constraints: storage::process_tracker::ProcessTrackerMerchantTaskConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::list_by_merchant_task_constraints(&conn, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn count_overdue_processes_by_runner(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        scheduled_before: PrimitiveDateTime,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::count_overdue_by_runner(
+            &conn,
+            &runner.to_string(),
+            scheduled_before,
+            merchant_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn count_unfinished_processes_by_merchant(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        scheduled_before: PrimitiveDateTime,
+    ) -> CustomResult<
+        storage::process_tracker::ProcessTrackerMerchantTaskCounts,
+        errors::StorageError,
+    > {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::count_unfinished_by_merchant_runner(
+            &conn,
+            &runner.to_string(),
+            merchant_id,
+            profile_id,
+            scheduled_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn count_finished_processes_by_merchant_business_status(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        business_status: &'static str,
+        finished_after: PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::count_finished_by_merchant_business_status(
+            &conn,
+            &runner.to_string(),
+            merchant_id,
+            profile_id,
+            business_status,
+            finished_after,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn count_processes_by_tag_runner_status(
+        &self,
+        tag: &str,
+        runner: storage_enums::ProcessTrackerRunner,
+    ) -> CustomResult<Vec<(storage_enums::ProcessTrackerStatus, String, i64)>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::count_by_tag_runner_status(&conn, tag, &runner.to_string())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -230,11 +374,263 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_pending_processes_by_tag_runner(
+        &self,
+        tags: Vec<String>,
+        runner: storage_enums::ProcessTrackerRunner,
+        time_lower_limit: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let runner = runner.to_string();
+        let processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                tags.iter().all(|tag| process.tag.contains(tag))
+                    && process.runner.as_ref() == Some(&runner)
+                    && process
+                        .schedule_time
+                        .is_some_and(|schedule_time| schedule_time >= time_lower_limit)
+                    && matches!(
+                        process.status,
+                        storage_enums::ProcessTrackerStatus::New
+                            | storage_enums::ProcessTrackerStatus::Pending
+                            | storage_enums::ProcessTrackerStatus::ProcessStarted
+                            | storage_enums::ProcessTrackerStatus::Processing
+                    )
+            })
+            .cloned()
+            .collect();
+
+        Ok(processes)
+    }
+
+    async fn list_processes_by_merchant_task_constraints(
+        &self,
+        constraints: storage::process_tracker::ProcessTrackerMerchantTaskConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let runner = constraints.runner.to_string();
+        let tracking_data_matches = |process: &storage::ProcessTracker, key: &str, value: &str| {
+            process
+                .tracking_data
+                .get(key)
+                .and_then(|tracking_data_value| tracking_data_value.as_str())
+                == Some(value)
+        };
+
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                constraints.tags.iter().all(|tag| process.tag.contains(tag))
+                    && process.runner.as_ref() == Some(&runner)
+                    && process
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| constraints.names.contains(name))
+                    && tracking_data_matches(
+                        process,
+                        "merchant_id",
+                        constraints.merchant_id.get_string_repr(),
+                    )
+                    && constraints.profile_id.as_ref().map_or(true, |profile_id| {
+                        tracking_data_matches(process, "profile_id", profile_id.get_string_repr())
+                    })
+                    && constraints
+                        .status
+                        .map_or(true, |status| process.status == status)
+                    && constraints
+                        .business_status
+                        .as_ref()
+                        .map_or(true, |business_status| {
+                            &process.business_status == business_status
+                        })
+                    && constraints.schedule_time_start.map_or(true, |start| {
+                        process
+                            .schedule_time
+                            .is_some_and(|schedule_time| schedule_time >= start)
+                    })
+                    && constraints.schedule_time_end.map_or(true, |end| {
+                        process
+                            .schedule_time
+                            .is_some_and(|schedule_time| schedule_time <= end)
+                    })
+                    && constraints
+                        .starting_after
+                        .as_ref()
+                        .map_or(true, |(schedule_time, id)| {
+                            (process.schedule_time, &process.id) > (Some(*schedule_time), id)
+                        })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        processes.sort_by(|a, b| (a.schedule_time, &a.id).cmp(&(b.schedule_time, &b.id)));
+        processes.truncate(usize::try_from(constraints.limit).unwrap_or(0));
+
+        Ok(processes)
+    }
+
+    async fn count_overdue_processes_by_runner(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        scheduled_before: PrimitiveDateTime,
+        merchant_id: Option<&common_utils::id_type::MerchantId>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let runner = runner.to_string();
+        let overdue_count = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.runner.as_ref() == Some(&runner)
+                    && process
+                        .schedule_time
+                        .is_some_and(|schedule_time| schedule_time < scheduled_before)
+                    && matches!(
+                        process.status,
+                        storage_enums::ProcessTrackerStatus::New
+                            | storage_enums::ProcessTrackerStatus::Pending
+                    )
+                    && merchant_id.map_or(true, |merchant_id| {
+                        process
+                            .tracking_data
+                            .get("merchant_id")
+                            .and_then(|tracking_data_value| tracking_data_value.as_str())
+                            == Some(merchant_id.get_string_repr())
+                    })
+            })
+            .count();
+
+        Ok(i64::try_from(overdue_count).unwrap_or(i64::MAX))
+    }
+
+    async fn count_unfinished_processes_by_merchant(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        scheduled_before: PrimitiveDateTime,
+    ) -> CustomResult<
+        storage::process_tracker::ProcessTrackerMerchantTaskCounts,
+        errors::StorageError,
+    > {
+        let runner = runner.to_string();
+        let processes = self.processes.lock().await;
+        let unfinished_processes = processes
+            .iter()
+            .filter(|process| {
+                process.runner.as_ref() == Some(&runner)
+                    && process.status != storage_enums::ProcessTrackerStatus::Finish
+                    && is_mock_process_of_merchant(process, merchant_id, profile_id)
+            })
+            .collect::<Vec<_>>();
+        let pending_processes = unfinished_processes
+            .iter()
+            .filter(|process| {
+                matches!(
+                    process.status,
+                    storage_enums::ProcessTrackerStatus::New
+                        | storage_enums::ProcessTrackerStatus::Pending
+                )
+            })
+            .collect::<Vec<_>>();
+        let payments_count = unfinished_processes
+            .iter()
+            .filter_map(|process| {
+                process
+                    .tracking_data
+                    .get("global_payment_id")
+                    .and_then(|tracking_data_value| tracking_data_value.as_str())
+            })
+            .collect::<std::collections::HashSet<_>>()
+            .len();
+        let overdue_count = pending_processes
+            .iter()
+            .filter(|process| {
+                process
+                    .schedule_time
+                    .is_some_and(|schedule_time| schedule_time < scheduled_before)
+            })
+            .count();
+        let review_count = unfinished_processes
+            .iter()
+            .filter(|process| process.status == storage_enums::ProcessTrackerStatus::Review)
+            .count();
+
+        Ok(storage::process_tracker::ProcessTrackerMerchantTaskCounts {
+            payments_count: i64::try_from(payments_count).unwrap_or(i64::MAX),
+            pending_count: i64::try_from(pending_processes.len()).unwrap_or(i64::MAX),
+            overdue_count: i64::try_from(overdue_count).unwrap_or(i64::MAX),
+            review_count: i64::try_from(review_count).unwrap_or(i64::MAX),
+        })
+    }
+
+    async fn count_finished_processes_by_merchant_business_status(
+        &self,
+        runner: storage_enums::ProcessTrackerRunner,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        business_status: &'static str,
+        finished_after: PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let runner = runner.to_string();
+        let finished_count = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.runner.as_ref() == Some(&runner)
+                    && process.status == storage_enums::ProcessTrackerStatus::Finish
+                    && process.business_status == business_status
+                    && process.updated_at >= finished_after
+                    && is_mock_process_of_merchant(process, merchant_id, profile_id)
+            })
+            .count();
+
+        Ok(i64::try_from(finished_count).unwrap_or(i64::MAX))
+    }
+
+    async fn count_processes_by_tag_runner_status(
+        &self,
+        tag: &str,
+        runner: storage_enums::ProcessTrackerRunner,
+    ) -> CustomResult<Vec<(storage_enums::ProcessTrackerStatus, String, i64)>, errors::StorageError>
+    {
+        let runner = runner.to_string();
+        let mut counts = Vec::<(storage_enums::ProcessTrackerStatus, String, i64)>::new();
+        for process in self.processes.lock().await.iter().filter(|process| {
+            process.runner.as_ref() == Some(&runner)
+                && process.tag.iter().any(|process_tag| process_tag == tag)
+        }) {
+            match counts.iter_mut().find(|(status, business_status, _)| {
+                *status == process.status && *business_status == process.business_status
+            }) {
+                Some((_, _, count)) => *count += 1,
+                None => counts.push((process.status, process.business_status.clone(), 1)),
+            }
+        }
+
+        Ok(counts)
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
         let mut processes = self.processes.lock().await;
+        if processes.iter().any(|process| process.id == new.id) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "process_tracker",
+                key: Some(new.id.clone()),
+            })?
+        }
         let process = storage::ProcessTracker {
             id: new.id,
             name: new.name,
@@ -257,11 +653,20 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let stored_process = processes
+            .iter_mut()
+            .find(|stored_process| stored_process.id == this.id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No process tracker entry found for the id {}",
+                this.id
+            )))?;
+        *stored_process = storage::ProcessTrackerUpdateInternal::from(process)
+            .apply_changeset(stored_process.clone());
+        Ok(stored_process.clone())
     }
 
     async fn reset_process(
@@ -300,3 +705,22 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 }
+
+/// Whether the task is of the merchant, and of the profile if given, as per its tracking data
+fn is_mock_process_of_merchant(
+    process: &storage::ProcessTracker,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+) -> bool {
+    let get_tracking_data_value = |key: &str| {
+        process
+            .tracking_data
+            .get(key)
+            .and_then(|tracking_data_value| tracking_data_value.as_str())
+    };
+
+    get_tracking_data_value("merchant_id") == Some(merchant_id.get_string_repr())
+        && profile_id.map_or(true, |profile_id| {
+            get_tracking_data_value("profile_id") == Some(profile_id.get_string_repr())
+        })
+}
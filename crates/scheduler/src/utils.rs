@@ -13,8 +13,11 @@ use super::{
     env::logger,
 };
 use crate::{
-    configs::settings::SchedulerSettings, consumer::types::ProcessTrackerBatch, errors,
-    flow::SchedulerFlow, metrics, SchedulerInterface, SchedulerSessionState,
+    configs::settings::{SchedulerSettings, WorkflowRetryLimits, WorkflowRetrySettings},
+    consumer::types::ProcessTrackerBatch,
+    errors,
+    flow::SchedulerFlow,
+    metrics, SchedulerInterface, SchedulerSessionState,
 };
 
 pub async fn divide_and_append_tasks<T>(
@@ -337,11 +340,26 @@ pub fn get_outgoing_webhook_retry_schedule_time(
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<i32> {
-    let retry_mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
+    let retry_mapping = get_outgoing_webhook_retry_mapping(mapping, merchant_id);
+    get_retry_mapping_schedule_time(&retry_mapping, retry_count)
+}
+
+/// Get the outgoing webhook retry mapping applicable for the merchant
+pub fn get_outgoing_webhook_retry_mapping(
+    mapping: process_data::OutgoingWebhookRetryProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> process_data::RetryMapping {
+    match mapping.custom_merchant_mapping.get(merchant_id) {
         Some(map) => map.clone(),
         None => mapping.default_mapping,
-    };
+    }
+}
 
+/// Get the delay of the retry from the previous attempt, based on the retry count
+pub fn get_retry_mapping_schedule_time(
+    retry_mapping: &process_data::RetryMapping,
+    retry_count: i32,
+) -> Option<i32> {
     // For first try, get the `start_after` time
     if retry_count == 0 {
         Some(retry_mapping.start_after)
@@ -350,25 +368,281 @@ pub fn get_outgoing_webhook_retry_schedule_time(
     }
 }
 
+/// Get the delays of all the retries produced by the retry mapping, in the order of the retries
+pub fn get_retry_mapping_schedule_times(retry_mapping: &process_data::RetryMapping) -> Vec<i32> {
+    (0..)
+        .map_while(|retry_count| get_retry_mapping_schedule_time(retry_mapping, retry_count))
+        .collect()
+}
+
 pub fn get_pcr_payments_retry_schedule_time(
     mapping: process_data::RevenueRecoveryPaymentProcessTrackerMapping,
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Option<i32> {
+    let retry_algorithm = get_pcr_payments_retry_algorithm(&mapping, merchant_id);
     let mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
         Some(map) => map.clone(),
         None => mapping.default_mapping,
     };
     // TODO: check if the current scheduled time is not more than the configured timerange
 
-    // For first try, get the `start_after` time
-    if retry_count == 0 {
-        Some(mapping.start_after)
-    } else {
-        get_delay(retry_count, &mapping.frequencies)
+    match retry_algorithm {
+        process_data::RevenueRecoveryRetryAlgorithm::StaticMapping => {
+            // For first try, get the `start_after` time
+            if retry_count == 0 {
+                Some(mapping.start_after)
+            } else {
+                get_delay(retry_count, &mapping.frequencies)
+            }
+        }
+        process_data::RevenueRecoveryRetryAlgorithm::ExponentialBackoff(config) => Some(config)
+            .filter(|config| retry_count <= config.max_retries)
+            .map(|config| get_exponential_backoff_delay_with_jitter(&config, retry_count)),
+    }
+}
+
+/// Get the delay of the retry based on the retry count, the last configured frequency is repeated
+/// once all the retries of the mapping are exhausted
+pub fn get_pcr_payments_extended_retry_schedule_time(
+    mapping: process_data::RevenueRecoveryPaymentProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+) -> Option<i32> {
+    let retry_algorithm = get_pcr_payments_retry_algorithm(&mapping, merchant_id);
+    let retry_mapping = match mapping.custom_merchant_mapping.get(merchant_id) {
+        Some(map) => map.clone(),
+        None => mapping.default_mapping,
+    };
+
+    match retry_algorithm {
+        process_data::RevenueRecoveryRetryAlgorithm::StaticMapping => {
+            get_retry_mapping_schedule_time(&retry_mapping, retry_count).or_else(|| {
+                retry_mapping
+                    .frequencies
+                    .last()
+                    .map(|&(frequency, _)| frequency)
+            })
+        }
+        // The backoff is capped at the max delay, so it can be continued beyond the max retries
+        process_data::RevenueRecoveryRetryAlgorithm::ExponentialBackoff(config) => Some(
+            get_exponential_backoff_delay_with_jitter(&config, retry_count),
+        ),
+    }
+}
+
+/// Get the revenue recovery retry algorithm applicable for the merchant
+/// Get the retry window of the revenue recovery retries of the merchant, if one is configured
+pub fn get_pcr_payments_retry_window<'a>(
+    mapping: &'a process_data::RevenueRecoveryPaymentProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> Option<&'a process_data::RevenueRecoveryRetryWindow> {
+    mapping
+        .custom_merchant_retry_window
+        .get(merchant_id)
+        .or(mapping.default_retry_window.as_ref())
+}
+
+fn get_pcr_payments_retry_algorithm(
+    mapping: &process_data::RevenueRecoveryPaymentProcessTrackerMapping,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> process_data::RevenueRecoveryRetryAlgorithm {
+    mapping
+        .custom_merchant_retry_algorithm
+        .get(merchant_id)
+        .unwrap_or(&mapping.default_retry_algorithm)
+        .clone()
+}
+
+/// Get the delay of the retry grown exponentially from the base delay, capped at the max delay
+pub fn get_exponential_backoff_delay(
+    config: &process_data::ExponentialBackoffRetryConfig,
+    retry_count: i32,
+) -> i32 {
+    let delay = time::Duration::saturating_seconds_f64(
+        f64::from(config.base_delay_in_secs) * config.multiplier.powi(retry_count),
+    );
+    let max_delay = time::Duration::seconds(i64::from(config.max_delay_in_secs));
+
+    i32::try_from(delay.min(max_delay).whole_seconds()).unwrap_or(config.max_delay_in_secs)
+}
+
+/// Moves the delay earlier or later by up to the jitter percentage of the delay, in proportion to
+/// the `jitter_factor` which is expected to be within `-1.0..=1.0`
+pub fn apply_retry_delay_jitter(delay: i32, jitter_percentage: u8, jitter_factor: f64) -> i32 {
+    let jitter = time::Duration::saturating_seconds_f64(
+        f64::from(delay) * f64::from(jitter_percentage.min(100)) / 100.0
+            * jitter_factor.clamp(-1.0, 1.0),
+    );
+
+    i32::try_from(i64::from(delay).saturating_add(jitter.whole_seconds()))
+        .unwrap_or(delay)
+        .max(0)
+}
+
+fn get_exponential_backoff_delay_with_jitter(
+    config: &process_data::ExponentialBackoffRetryConfig,
+    retry_count: i32,
+) -> i32 {
+    let jitter_factor = rand::Rng::gen_range(&mut rand::thread_rng(), -1.0..=1.0);
+
+    apply_retry_delay_jitter(
+        get_exponential_backoff_delay(config, retry_count),
+        config.jitter_percentage,
+        jitter_factor,
+    )
+}
+
+/// Limits on the requeues of the failed runs of the workflows of the runner. The limits configured
+/// for the runner take precedence over the defaults of the runner, which take precedence over the
+/// configured default limits. `settings` is absent when the scheduler is not configured.
+pub fn get_workflow_retry_limits(
+    settings: Option<&WorkflowRetrySettings>,
+    runner: enums::ProcessTrackerRunner,
+) -> WorkflowRetryLimits {
+    settings
+        .and_then(|settings| settings.runners.get(&runner))
+        .copied()
+        .or_else(|| WorkflowRetryLimits::get_runner_default(runner))
+        .or_else(|| settings.map(|settings| settings.default))
+        .unwrap_or_default()
+}
+
+/// Time at which the task whose run failed is requeued, given the number of its runs which have
+/// failed in a row including this one. `None` once the task has been requeued the max number of
+/// times, in which case it is no longer to be picked up.
+pub fn get_failed_run_requeue_time(
+    retry_limits: &WorkflowRetryLimits,
+    execution_failure_count: u16,
+    current_time: time::PrimitiveDateTime,
+) -> Option<time::PrimitiveDateTime> {
+    (execution_failure_count <= retry_limits.max_execution_retries).then(|| {
+        let backoff = u64::from(retry_limits.retry_backoff_in_secs)
+            .saturating_mul(
+                2_u64.saturating_pow(u32::from(execution_failure_count.saturating_sub(1))),
+            )
+            .min(u64::from(retry_limits.max_retry_backoff_in_secs));
+
+        current_time.saturating_add(time::Duration::seconds(
+            i64::try_from(backoff).unwrap_or(i64::MAX),
+        ))
+    })
+}
+
+/// Get the delay of the retry as per the retry schedule of the merchant, `None` once the max
+/// retries of the schedule are exhausted
+pub fn get_pcr_payments_custom_retry_schedule_time(
+    retry_schedule: &process_data::RevenueRecoveryRetrySchedule,
+    retry_count: i32,
+) -> Option<i32> {
+    (0..=retry_schedule.max_retries)
+        .contains(&retry_count)
+        .then(|| get_pcr_payments_extended_custom_retry_schedule_time(retry_schedule, retry_count))
+}
+
+/// Get the delay of the retry as per the retry schedule of the merchant, the default delay of the
+/// schedule is used for the retries beyond its max retries
+pub fn get_pcr_payments_extended_custom_retry_schedule_time(
+    retry_schedule: &process_data::RevenueRecoveryRetrySchedule,
+    retry_count: i32,
+) -> i32 {
+    usize::try_from(retry_count)
+        .ok()
+        .and_then(|retry_index| retry_schedule.delays_in_secs.get(retry_index))
+        .copied()
+        .unwrap_or(retry_schedule.default_delay_in_secs)
+}
+
+/// Get the decline class of the error codes of a failed attempt, the first of the error codes
+/// which is mapped to a decline class deciding it
+pub fn get_pcr_decline_class<'a>(
+    mapping: &'a process_data::RevenueRecoveryPaymentProcessTrackerMapping,
+    error_codes: &[&str],
+) -> Option<&'a str> {
+    error_codes
+        .iter()
+        .find_map(|error_code| mapping.decline_classes.get(*error_code))
+        .map(String::as_str)
+}
+
+/// Decline class of the first of the error codes of the failed attempt which has a delay profile,
+/// along with the delay profile of the class
+pub fn get_pcr_decline_class_delay_profile<'a>(
+    mapping: &'a process_data::RevenueRecoveryPaymentProcessTrackerMapping,
+    error_codes: &[&str],
+) -> Option<(&'a str, &'a process_data::RevenueRecoveryDelayProfile)> {
+    error_codes.iter().find_map(|error_code| {
+        mapping
+            .decline_classes
+            .get_key_value(*error_code)
+            .and_then(|(_, decline_class)| {
+                mapping
+                    .decline_class_delay_profiles
+                    .get_key_value(decline_class)
+            })
+            .map(|(decline_class, delay_profile)| (decline_class.as_str(), delay_profile))
+    })
+}
+
+/// Get the time of the retry as per the delay profile. The delay of a `Delay` profile is picked
+/// between its bounds in proportion to the `spread_factor`, which is expected to be within
+/// `0.0..=1.0`.
+pub fn get_delay_profile_schedule_time(
+    delay_profile: &process_data::RevenueRecoveryDelayProfile,
+    current_time: time::PrimitiveDateTime,
+    spread_factor: f64,
+) -> Option<time::PrimitiveDateTime> {
+    match delay_profile {
+        process_data::RevenueRecoveryDelayProfile::Delay {
+            min_delay_in_secs,
+            max_delay_in_secs,
+        } => {
+            let spread = f64::from(max_delay_in_secs.saturating_sub(*min_delay_in_secs).max(0))
+                * spread_factor.clamp(0.0, 1.0);
+            let delay = time::Duration::seconds(i64::from(*min_delay_in_secs))
+                .saturating_add(time::Duration::saturating_seconds_f64(spread));
+
+            current_time.checked_add(delay)
+        }
+        process_data::RevenueRecoveryDelayProfile::DaysOfMonth { days, hour } => {
+            let time_of_day = time::Time::from_hms(*hour, 0, 0).ok()?;
+            let next_month_date = current_time
+                .date()
+                .replace_day(1)
+                .ok()
+                .and_then(|date| date.checked_add(time::Duration::days(32)))?;
+
+            // One of the days of the current month or the next month is the next day of the month
+            [current_time.date(), next_month_date]
+                .into_iter()
+                .flat_map(|date| {
+                    let last_day = time::util::days_in_year_month(date.year(), date.month());
+                    days.iter()
+                        .filter(|day| **day > 0)
+                        .filter_map(move |day| date.replace_day((*day).min(last_day)).ok())
+                })
+                .map(|date| time::PrimitiveDateTime::new(date, time_of_day))
+                .filter(|schedule_time| *schedule_time > current_time)
+                .min()
+        }
     }
 }
 
+/// Get the time of the retry after a decline of the error codes of the failed attempt, if the
+/// decline class of the error codes has a delay profile. Returns the decline class along with the
+/// time of the retry.
+pub fn get_pcr_decline_class_schedule_time<'a>(
+    mapping: &'a process_data::RevenueRecoveryPaymentProcessTrackerMapping,
+    error_codes: &[&str],
+    current_time: time::PrimitiveDateTime,
+) -> Option<(&'a str, time::PrimitiveDateTime)> {
+    let (decline_class, delay_profile) = get_pcr_decline_class_delay_profile(mapping, error_codes)?;
+    let spread_factor = rand::Rng::gen_range(&mut rand::thread_rng(), 0.0..=1.0);
+
+    get_delay_profile_schedule_time(delay_profile, current_time, spread_factor)
+        .map(|schedule_time| (decline_class, schedule_time))
+}
+
 /// Get the delay based on the retry count
 pub fn get_delay<'a>(
     retry_count: i32,
@@ -455,4 +729,429 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_get_retry_mapping_schedule_times() {
+        let retry_mapping = process_data::RetryMapping {
+            start_after: 60,
+            frequencies: vec![(300, 2), (600, 1)],
+        };
+
+        let delays = get_retry_mapping_schedule_times(&retry_mapping);
+
+        assert_eq!(delays, vec![60, 300, 300, 600]);
+        for (retry_count, delay) in (0..).zip(delays) {
+            assert_eq!(
+                get_retry_mapping_schedule_time(&retry_mapping, retry_count),
+                Some(delay)
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_pcr_payments_extended_retry_schedule_time() {
+        let get_mapping = || process_data::RevenueRecoveryPaymentProcessTrackerMapping {
+            default_mapping: process_data::RetryMapping {
+                start_after: 60,
+                frequencies: vec![(300, 2), (600, 1)],
+            },
+            custom_merchant_mapping: std::collections::HashMap::new(),
+            default_retry_algorithm: process_data::RevenueRecoveryRetryAlgorithm::StaticMapping,
+            ..Default::default()
+        };
+        let merchant_id = common_utils::id_type::MerchantId::get_merchant_id_not_found();
+
+        for (retry_count, expected_delay) in [(0, 60), (2, 300), (3, 600), (4, 600), (10, 600)] {
+            assert_eq!(
+                get_pcr_payments_extended_retry_schedule_time(
+                    get_mapping(),
+                    &merchant_id,
+                    retry_count
+                ),
+                Some(expected_delay),
+                "Delay and expected delay differ for `retry_count` = {retry_count}"
+            );
+        }
+        assert_eq!(
+            get_pcr_payments_retry_schedule_time(get_mapping(), &merchant_id, 4),
+            None
+        );
+    }
+
+    fn get_exponential_backoff_config() -> process_data::ExponentialBackoffRetryConfig {
+        process_data::ExponentialBackoffRetryConfig {
+            base_delay_in_secs: 60,
+            multiplier: 2.0,
+            max_delay_in_secs: 60 * 60 * 12,
+            jitter_percentage: 20,
+            max_retries: 15,
+        }
+    }
+
+    #[test]
+    fn test_exponential_backoff_delays_are_monotonic_until_capped() {
+        let config = get_exponential_backoff_config();
+
+        let delays = (0..=config.max_retries)
+            .map(|retry_count| get_exponential_backoff_delay(&config, retry_count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(delays.first(), Some(&60));
+        for (delay, next_delay) in delays.iter().zip(delays.iter().skip(1)) {
+            if *next_delay < config.max_delay_in_secs {
+                assert!(
+                    next_delay > delay,
+                    "{next_delay} is not greater than {delay}"
+                );
+            } else {
+                assert_eq!(*next_delay, config.max_delay_in_secs);
+            }
+        }
+        assert_eq!(delays.last(), Some(&config.max_delay_in_secs));
+    }
+
+    #[test]
+    fn test_retry_delay_jitter_stays_within_bounds() {
+        let config = get_exponential_backoff_config();
+
+        for retry_count in 0..=config.max_retries {
+            let delay = get_exponential_backoff_delay(&config, retry_count);
+            let max_jitter = delay * i32::from(config.jitter_percentage) / 100;
+
+            assert_eq!(apply_retry_delay_jitter(delay, 20, 0.0), delay);
+            assert_eq!(apply_retry_delay_jitter(delay, 20, 1.0), delay + max_jitter);
+            assert_eq!(
+                apply_retry_delay_jitter(delay, 20, -1.0),
+                delay - max_jitter
+            );
+            // Out of range jitter factors are clamped
+            assert_eq!(apply_retry_delay_jitter(delay, 20, 5.0), delay + max_jitter);
+
+            for _ in 0..100 {
+                let jittered_delay =
+                    get_exponential_backoff_delay_with_jitter(&config, retry_count);
+                assert!(
+                    (delay - max_jitter..=delay + max_jitter).contains(&jittered_delay),
+                    "{jittered_delay} is outside the jitter bounds of {delay}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_pcr_payments_retry_schedule_time_with_exponential_backoff() {
+        let merchant_id = common_utils::id_type::MerchantId::get_merchant_id_not_found();
+        let get_mapping = || process_data::RevenueRecoveryPaymentProcessTrackerMapping {
+            custom_merchant_retry_algorithm: std::collections::HashMap::from([(
+                merchant_id.clone(),
+                process_data::RevenueRecoveryRetryAlgorithm::ExponentialBackoff(
+                    process_data::ExponentialBackoffRetryConfig {
+                        jitter_percentage: 0,
+                        ..get_exponential_backoff_config()
+                    },
+                ),
+            )]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_pcr_payments_retry_schedule_time(get_mapping(), &merchant_id, 3),
+            Some(480)
+        );
+        assert_eq!(
+            get_pcr_payments_retry_schedule_time(get_mapping(), &merchant_id, 16),
+            None
+        );
+        assert_eq!(
+            get_pcr_payments_extended_retry_schedule_time(get_mapping(), &merchant_id, 16),
+            Some(60 * 60 * 12)
+        );
+        // Merchants without a retry algorithm keep using the retry mapping
+        assert_eq!(
+            get_pcr_payments_retry_schedule_time(
+                get_mapping(),
+                &common_utils::id_type::MerchantId::default(),
+                3
+            ),
+            Some(60 * 60 * 6)
+        );
+    }
+
+    #[test]
+    fn test_get_pcr_payments_custom_retry_schedule_time() {
+        let retry_schedule = process_data::RevenueRecoveryRetrySchedule {
+            name: String::from("weekly"),
+            delays_in_secs: vec![60 * 60, 60 * 60 * 24, 60 * 60 * 24 * 3],
+            default_delay_in_secs: 60 * 60 * 24 * 7,
+            max_retries: 5,
+        };
+
+        let retry_counts_and_expected_delays = [
+            (-1, None),
+            (0, Some(60 * 60)),
+            (2, Some(60 * 60 * 24 * 3)),
+            (3, Some(60 * 60 * 24 * 7)),
+            (5, Some(60 * 60 * 24 * 7)),
+            (6, None),
+        ];
+
+        for (retry_count, expected_delay) in retry_counts_and_expected_delays {
+            assert_eq!(
+                get_pcr_payments_custom_retry_schedule_time(&retry_schedule, retry_count),
+                expected_delay,
+                "Delay and expected delay differ for `retry_count` = {retry_count}"
+            );
+        }
+        assert_eq!(
+            get_pcr_payments_extended_custom_retry_schedule_time(&retry_schedule, 9),
+            60 * 60 * 24 * 7
+        );
+    }
+
+    #[test]
+    fn test_get_pcr_payments_retry_window() {
+        let merchant_id = common_utils::id_type::MerchantId::get_merchant_id_not_found();
+        let get_retry_window = |timezone: &str| process_data::RevenueRecoveryRetryWindow {
+            start_hour: 9,
+            end_hour: 20,
+            timezone: timezone.to_string(),
+            card_issuing_country_timezones: std::collections::HashMap::new(),
+            max_recovery_window_in_secs: None,
+        };
+        let mut mapping = process_data::RevenueRecoveryPaymentProcessTrackerMapping::default();
+
+        assert!(get_pcr_payments_retry_window(&mapping, &merchant_id).is_none());
+
+        mapping.default_retry_window = Some(get_retry_window("UTC"));
+        mapping.custom_merchant_retry_window = std::collections::HashMap::from([(
+            merchant_id.clone(),
+            get_retry_window("Asia/Tokyo"),
+        )]);
+
+        assert_eq!(
+            get_pcr_payments_retry_window(&mapping, &merchant_id)
+                .map(|retry_window| retry_window.timezone.as_str()),
+            Some("Asia/Tokyo")
+        );
+        assert_eq!(
+            get_pcr_payments_retry_window(&mapping, &common_utils::id_type::MerchantId::default())
+                .map(|retry_window| retry_window.timezone.as_str()),
+            Some("UTC")
+        );
+    }
+
+    #[allow(clippy::expect_used)]
+    fn get_date_time(year: i32, month: time::Month, day: u8, hour: u8) -> time::PrimitiveDateTime {
+        time::Date::from_calendar_date(year, month, day)
+            .and_then(|date| date.with_hms(hour, 0, 0))
+            .expect("valid date time")
+    }
+
+    fn get_decline_class_mapping() -> process_data::RevenueRecoveryPaymentProcessTrackerMapping {
+        process_data::RevenueRecoveryPaymentProcessTrackerMapping {
+            decline_classes: std::collections::HashMap::from([
+                ("UE_9000".to_string(), "insufficient_funds".to_string()),
+                ("51".to_string(), "insufficient_funds".to_string()),
+                ("05".to_string(), "do_not_honor".to_string()),
+                ("14".to_string(), "invalid_card".to_string()),
+            ]),
+            decline_class_delay_profiles: std::collections::HashMap::from([
+                (
+                    "insufficient_funds".to_string(),
+                    process_data::RevenueRecoveryDelayProfile::DaysOfMonth {
+                        days: vec![1, 15, 31],
+                        hour: 9,
+                    },
+                ),
+                (
+                    "do_not_honor".to_string(),
+                    process_data::RevenueRecoveryDelayProfile::Delay {
+                        min_delay_in_secs: 60 * 60 * 24 * 3,
+                        max_delay_in_secs: 60 * 60 * 24 * 5,
+                    },
+                ),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_pcr_decline_class_delay_profile() {
+        let mapping = get_decline_class_mapping();
+        let get_decline_class = |error_codes: &[&str]| {
+            get_pcr_decline_class_delay_profile(&mapping, error_codes)
+                .map(|(decline_class, _)| decline_class)
+        };
+
+        assert_eq!(
+            get_decline_class(&["UE_9000", "05"]),
+            Some("insufficient_funds")
+        );
+        // The codes without a class or a delay profile fall back to the retry algorithm
+        assert_eq!(get_decline_class(&["UE_0000", "05"]), Some("do_not_honor"));
+        assert_eq!(get_decline_class(&["14"]), None);
+        assert_eq!(get_decline_class(&[]), None);
+    }
+
+    #[test]
+    fn test_get_pcr_decline_class() {
+        let mapping = get_decline_class_mapping();
+
+        assert_eq!(
+            get_pcr_decline_class(&mapping, &["UE_0000", "05"]),
+            Some("do_not_honor")
+        );
+        // The class of a code is found even when the class has no delay profile
+        assert_eq!(
+            get_pcr_decline_class(&mapping, &["14"]),
+            Some("invalid_card")
+        );
+        assert_eq!(get_pcr_decline_class(&mapping, &["UE_0000"]), None);
+    }
+
+    #[test]
+    fn test_get_delay_profile_schedule_time() {
+        let current_time = get_date_time(2025, time::Month::January, 20, 12);
+        let delay_profile = process_data::RevenueRecoveryDelayProfile::Delay {
+            min_delay_in_secs: 60 * 60 * 24 * 3,
+            max_delay_in_secs: 60 * 60 * 24 * 5,
+        };
+
+        assert_eq!(
+            get_delay_profile_schedule_time(&delay_profile, current_time, 0.0),
+            Some(get_date_time(2025, time::Month::January, 23, 12))
+        );
+        assert_eq!(
+            get_delay_profile_schedule_time(&delay_profile, current_time, 0.5),
+            Some(get_date_time(2025, time::Month::January, 24, 12))
+        );
+        assert_eq!(
+            get_delay_profile_schedule_time(&delay_profile, current_time, 7.0),
+            Some(get_date_time(2025, time::Month::January, 25, 12))
+        );
+
+        let delay_profile = process_data::RevenueRecoveryDelayProfile::DaysOfMonth {
+            days: vec![1, 15, 31],
+            hour: 9,
+        };
+        let days_of_month_and_expected_schedule_times = [
+            (
+                get_date_time(2025, time::Month::January, 20, 12),
+                get_date_time(2025, time::Month::January, 31, 9),
+            ),
+            (
+                get_date_time(2025, time::Month::January, 31, 9),
+                get_date_time(2025, time::Month::February, 1, 9),
+            ),
+            // The days beyond the end of the month fall on the last day of the month
+            (
+                get_date_time(2025, time::Month::February, 16, 12),
+                get_date_time(2025, time::Month::February, 28, 9),
+            ),
+            (
+                get_date_time(2025, time::Month::December, 31, 10),
+                get_date_time(2026, time::Month::January, 1, 9),
+            ),
+        ];
+
+        for (current_time, expected_schedule_time) in days_of_month_and_expected_schedule_times {
+            assert_eq!(
+                get_delay_profile_schedule_time(&delay_profile, current_time, 0.0),
+                Some(expected_schedule_time),
+                "Unexpected schedule time for the current time {current_time}"
+            );
+        }
+    }
+
+    #[allow(clippy::expect_used)]
+    #[test]
+    fn test_get_pcr_decline_class_schedule_time() {
+        let mapping = get_decline_class_mapping();
+        let current_time = get_date_time(2025, time::Month::January, 20, 12);
+
+        for _ in 0..100 {
+            let (decline_class, schedule_time) =
+                get_pcr_decline_class_schedule_time(&mapping, &["05"], current_time)
+                    .expect("schedule time for the do not honor declines");
+
+            assert_eq!(decline_class, "do_not_honor");
+            assert!(
+                (get_date_time(2025, time::Month::January, 23, 12)
+                    ..=get_date_time(2025, time::Month::January, 25, 12))
+                    .contains(&schedule_time),
+                "{schedule_time} is outside the bounds of the delay profile"
+            );
+        }
+        assert_eq!(
+            get_pcr_decline_class_schedule_time(&mapping, &["14"], current_time),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_failed_runs_of_runners_are_requeued_with_their_limits() {
+        let settings = serde_json::from_value::<WorkflowRetrySettings>(serde_json::json!({
+            "default": {
+                "max_execution_retries": 2,
+                "retry_backoff_in_secs": 60,
+                "max_retry_backoff_in_secs": 600,
+            },
+            "runners": {
+                "refund_workflow_router": {
+                    "max_execution_retries": 1,
+                    "retry_backoff_in_secs": 30,
+                    "max_retry_backoff_in_secs": 30,
+                },
+            },
+        }))
+        .expect("valid workflow retry settings");
+        let current_time = get_date_time(2024, time::Month::January, 15, 10);
+
+        let refund_retry_limits = get_workflow_retry_limits(
+            Some(&settings),
+            enums::ProcessTrackerRunner::RefundWorkflowRouter,
+        );
+        let recovery_retry_limits = get_workflow_retry_limits(
+            Some(&settings),
+            enums::ProcessTrackerRunner::PassiveRecoveryWorkflow,
+        );
+
+        assert_eq!(
+            get_failed_run_requeue_time(&refund_retry_limits, 1, current_time),
+            Some(current_time + time::Duration::seconds(30))
+        );
+        assert_eq!(
+            get_failed_run_requeue_time(&refund_retry_limits, 2, current_time),
+            None
+        );
+
+        // The recovery runner keeps its own defaults, backing off until the max backoff
+        let recovery_requeue_times = (1..=6)
+            .map(|execution_failure_count| {
+                get_failed_run_requeue_time(
+                    &recovery_retry_limits,
+                    execution_failure_count,
+                    current_time,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            recovery_requeue_times,
+            [300, 600, 1200, 2400, 3600]
+                .into_iter()
+                .map(|backoff| Some(current_time + time::Duration::seconds(backoff)))
+                .chain([None])
+                .collect::<Vec<_>>()
+        );
+
+        // The runners without limits of their own use the configured default limits
+        assert_eq!(
+            get_workflow_retry_limits(
+                Some(&settings),
+                enums::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            ),
+            settings.default
+        );
+    }
 }
@@ -279,4 +279,10 @@ pub enum RecoveryError {
     RecordBackToBillingConnectorFailed,
     #[error("Failed to fetch billing connector account id")]
     BillingMerchantConnectorAccountIdNotFound,
+    #[error("Failed to update the payment attempt")]
+    PaymentAttemptUpdateFailed,
+    #[error("Failed to hand off the payment to the manual collection queue")]
+    ManualCollectionHandoffFailed,
+    #[error("Billing connector account is configured with an invalid outbound proxy")]
+    BillingConnectorProxyMisconfigured,
 }
@@ -40,6 +40,7 @@ pub struct MockDb {
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
+    pub events_archives: Arc<Mutex<Vec<store::events_archive::EventsArchive>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
@@ -63,6 +64,9 @@ pub struct MockDb {
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
     pub themes: Arc<Mutex<Vec<store::user::theme::Theme>>>,
+    #[cfg(feature = "v2")]
+    pub revenue_recovery_collection_items:
+        Arc<Mutex<Vec<store::revenue_recovery_collection_item::RevenueRecoveryCollectionItem>>>,
 }
 
 impl MockDb {
@@ -87,6 +91,7 @@ impl MockDb {
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
             events: Default::default(),
+            events_archives: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
@@ -109,6 +114,8 @@ impl MockDb {
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
             themes: Default::default(),
+            #[cfg(feature = "v2")]
+            revenue_recovery_collection_items: Default::default(),
         })
     }
 
@@ -111,6 +111,19 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "v2")]
+    async fn find_payment_attempts_by_profile_id_time_range(
+        &self,
+        _key_manager_state: &KeyManagerState,
+        _profile_id: &id_type::ProfileId,
+        _time_range: &common_utils::types::TimeRange,
+        _merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: common_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_preprocessing_id_merchant_id(
         &self,
@@ -237,4 +237,125 @@ impl PaymentIntentInterface for MockDb {
 
         Ok(payment_intent.clone())
     }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_intents_by_billing_connector_subscription_id_profile_id(
+        &self,
+        _state: &KeyManagerState,
+        billing_connector_subscription_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        _merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: &common_enums::MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+        let mut payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.profile_id.eq(profile_id)
+                    && payment_intent
+                        .feature_metadata
+                        .as_ref()
+                        .and_then(|feature_metadata| {
+                            feature_metadata.billing_connector_subscription_id.as_deref()
+                        })
+                        == Some(billing_connector_subscription_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_intents.sort_by_key(|payment_intent| payment_intent.created_at);
+
+        Ok(payment_intents
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_open_recovery_intents_by_billing_connector_id_profile_id(
+        &self,
+        _state: &KeyManagerState,
+        billing_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        profile_id: &common_utils::id_type::ProfileId,
+        _merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: &common_enums::MerchantStorageScheme,
+        starting_after: Option<&common_utils::id_type::GlobalPaymentId>,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+        let mut payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.profile_id.eq(profile_id)
+                    && !matches!(
+                        payment_intent.status,
+                        common_enums::IntentStatus::Succeeded
+                            | common_enums::IntentStatus::Cancelled
+                            | common_enums::IntentStatus::PartiallyCaptured
+                    )
+                    && payment_intent
+                        .feature_metadata
+                        .as_ref()
+                        .and_then(|feature_metadata| {
+                            feature_metadata.payment_revenue_recovery_metadata.as_ref()
+                        })
+                        .map(|recovery_metadata| &recovery_metadata.billing_connector_id)
+                        == Some(billing_connector_id)
+                    && starting_after.map_or(true, |starting_after| {
+                        payment_intent.id.get_string_repr() > starting_after.get_string_repr()
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_intents
+            .sort_by(|first, second| first.id.get_string_repr().cmp(second.id.get_string_repr()));
+
+        Ok(payment_intents
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_intents_by_billing_connector_customer_id_profile_id(
+        &self,
+        _state: &KeyManagerState,
+        billing_connector_customer_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        _merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: &common_enums::MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+        let mut payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.profile_id.eq(profile_id)
+                    && payment_intent
+                        .feature_metadata
+                        .as_ref()
+                        .and_then(|feature_metadata| {
+                            feature_metadata.payment_revenue_recovery_metadata.as_ref()
+                        })
+                        .map(|recovery_metadata| {
+                            recovery_metadata
+                                .billing_connector_payment_details
+                                .connector_customer_id
+                                .as_str()
+                        })
+                        == Some(billing_connector_customer_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_intents.sort_by_key(|payment_intent| payment_intent.created_at);
+
+        Ok(payment_intents
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
 }
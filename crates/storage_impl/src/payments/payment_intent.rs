@@ -9,7 +9,10 @@ use common_utils::{
 #[cfg(feature = "olap")]
 use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
 #[cfg(feature = "v1")]
-use diesel_models::payment_intent::PaymentIntentUpdate as DieselPaymentIntentUpdate;
+use diesel_models::payment_intent::{
+    PaymentIntentUpdate as DieselPaymentIntentUpdate,
+    PaymentIntentUpdateInternal as DieselPaymentIntentUpdateInternal,
+};
 #[cfg(feature = "olap")]
 use diesel_models::query::generics::db_metrics;
 #[cfg(all(feature = "v1", feature = "olap"))]
@@ -45,6 +48,8 @@ use router_env::{instrument, tracing};
 
 #[cfg(feature = "olap")]
 use crate::connection;
+#[cfg(feature = "v1")]
+use crate::redis::kv_store::{increment_entity_version, RedisConnInterface};
 use crate::{
     diesel_error_to_data_error,
     errors::{RedisErrorExt, StorageError},
@@ -228,9 +233,27 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     .await
                     .change_context(StorageError::EncryptionError)?;
 
-                let diesel_intent = diesel_intent_update
+                let mut diesel_intent = diesel_intent_update
                     .clone()
                     .apply_changeset(origin_diesel_intent.clone());
+                if DieselPaymentIntentUpdateInternal::from(diesel_intent_update.clone())
+                    .status
+                    .is_some()
+                {
+                    // Concurrent updates read the same version, so the next version is handed
+                    // out by redis rather than derived from the payment intent that was read
+                    diesel_intent.entity_version = increment_entity_version(
+                        &self
+                            .get_redis_conn()
+                            .map_err(|err| err.to_redis_failed_response(&key_str))?,
+                        key.clone(),
+                        &field,
+                        origin_diesel_intent.entity_version,
+                        self.ttl_for_kv,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?;
+                }
                 // Check for database presence as well Maybe use a read replica here ?
 
                 let redis_value = diesel_intent
@@ -522,6 +545,80 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             }
         }
     }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_intents_by_billing_connector_subscription_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_subscription_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: &MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        // Lookups without the intent id are served from the database for both the storage schemes
+        self.router_store
+            .find_payment_intents_by_billing_connector_subscription_id_profile_id(
+                state,
+                billing_connector_subscription_id,
+                profile_id,
+                merchant_key_store,
+                storage_scheme,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_open_recovery_intents_by_billing_connector_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: &MerchantStorageScheme,
+        starting_after: Option<&common_utils::id_type::GlobalPaymentId>,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        // Lookups without the intent id are served from the database for both the storage schemes
+        self.router_store
+            .find_open_recovery_intents_by_billing_connector_id_profile_id(
+                state,
+                billing_connector_id,
+                profile_id,
+                merchant_key_store,
+                storage_scheme,
+                starting_after,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn find_payment_intents_by_billing_connector_customer_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_customer_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: &MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        self.router_store
+            .find_payment_intents_by_billing_connector_customer_id_profile_id(
+                state,
+                billing_connector_customer_id,
+                profile_id,
+                merchant_key_store,
+                storage_scheme,
+                limit,
+                offset,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -719,6 +816,141 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         .change_context(StorageError::DecryptionError)
     }
 
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn find_payment_intents_by_billing_connector_subscription_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_subscription_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: &MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        use futures::future::try_join_all;
+
+        let conn = pg_connection_read(self).await?;
+        let diesel_payment_intents =
+            DieselPaymentIntent::find_by_billing_connector_subscription_id_profile_id(
+                &conn,
+                billing_connector_subscription_id,
+                profile_id,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })?;
+
+        try_join_all(
+            diesel_payment_intents
+                .into_iter()
+                .map(|diesel_payment_intent| {
+                    PaymentIntent::convert_back(
+                        state,
+                        diesel_payment_intent,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                }),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn find_open_recovery_intents_by_billing_connector_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: &MerchantStorageScheme,
+        starting_after: Option<&common_utils::id_type::GlobalPaymentId>,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        use futures::future::try_join_all;
+
+        let conn = pg_connection_read(self).await?;
+        let diesel_payment_intents =
+            DieselPaymentIntent::find_open_recovery_intents_by_billing_connector_id_profile_id(
+                &conn,
+                billing_connector_id,
+                profile_id,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })?;
+
+        try_join_all(
+            diesel_payment_intents
+                .into_iter()
+                .map(|diesel_payment_intent| {
+                    PaymentIntent::convert_back(
+                        state,
+                        diesel_payment_intent,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                }),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)
+    }
+
+    #[cfg(feature = "v2")]
+    #[instrument(skip_all)]
+    async fn find_payment_intents_by_billing_connector_customer_id_profile_id(
+        &self,
+        state: &KeyManagerState,
+        billing_connector_customer_id: &str,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: &MerchantStorageScheme,
+        limit: i64,
+        offset: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        use futures::future::try_join_all;
+
+        let conn = pg_connection_read(self).await?;
+        let diesel_payment_intents =
+            DieselPaymentIntent::find_by_billing_connector_customer_id_profile_id(
+                &conn,
+                billing_connector_customer_id,
+                profile_id,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })?;
+
+        try_join_all(
+            diesel_payment_intents
+                .into_iter()
+                .map(|diesel_payment_intent| {
+                    PaymentIntent::convert_back(
+                        state,
+                        diesel_payment_intent,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                }),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     #[instrument(skip_all)]
     async fn filter_payment_intent_by_constraints(
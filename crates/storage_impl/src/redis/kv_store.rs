@@ -272,6 +272,49 @@ where
         })
 }
 
+/// Bumps the entity version kept alongside `field` in the hash of `partition_key` past both the
+/// version the caller read and any version handed out by a concurrent update, in one script call.
+const INCREMENT_ENTITY_VERSION_SCRIPT: &str = r#"
+local version = tonumber(ARGV[2])
+local current = tonumber(redis.call('HGET', KEYS[1], ARGV[1]))
+if current ~= nil and current > version then
+    version = current
+end
+version = version + 1
+redis.call('HSET', KEYS[1], ARGV[1], version)
+if redis.call('TTL', KEYS[1]) < 0 then
+    redis.call('EXPIRE', KEYS[1], ARGV[3])
+end
+return version
+"#;
+
+/// The hash field holding the entity version of the entry stored at `field`
+pub fn entity_version_field(field: &str) -> String {
+    format!("entity_version_{field}")
+}
+
+/// Returns the next entity version of the entry stored at `field`, so that updates racing on the
+/// same entry in redis never hand out the same version twice
+pub async fn increment_entity_version(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    partition_key: PartitionKey<'_>,
+    field: &str,
+    current_entity_version: i64,
+    ttl: u32,
+) -> CustomResult<i64, RedisError> {
+    redis_conn
+        .evaluate_redis_script(
+            INCREMENT_ENTITY_VERSION_SCRIPT,
+            vec![redis_conn.add_prefix(&partition_key.to_string())],
+            vec![
+                entity_version_field(field),
+                current_entity_version.to_string(),
+                ttl.to_string(),
+            ],
+        )
+        .await
+}
+
 pub enum Op<'a> {
     Insert,
     Update(PartitionKey<'a>, &'a str, Option<&'a str>),
@@ -337,3 +380,67 @@ where
         storage_scheme
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use redis_interface::{RedisConnectionPool, RedisSettings};
+
+    use super::*;
+
+    const TTL: u32 = 60;
+
+    #[tokio::test]
+    async fn test_concurrent_increments_hand_out_distinct_entity_versions() {
+        let redis_conn = RedisConnectionPool::new(&RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        let partition_key = PartitionKey::CombinationKey {
+            combination: "test_concurrent_entity_version_increments",
+        };
+        redis_conn
+            .delete_key(&partition_key.to_string().into())
+            .await
+            .expect("failed to clear the hash");
+
+        // Both updates read the entry at the same entity version
+        let (first, second) = tokio::join!(
+            increment_entity_version(&redis_conn, partition_key.clone(), "pi_1", 1, TTL),
+            increment_entity_version(&redis_conn, partition_key.clone(), "pi_1", 1, TTL),
+        );
+        let mut versions = vec![
+            first.expect("failed to increment the entity version"),
+            second.expect("failed to increment the entity version"),
+        ];
+        versions.sort();
+
+        assert_eq!(versions, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_increment_moves_past_the_entity_version_read() {
+        let redis_conn = RedisConnectionPool::new(&RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        let partition_key = PartitionKey::CombinationKey {
+            combination: "test_entity_version_increment_past_read_version",
+        };
+        redis_conn
+            .delete_key(&partition_key.to_string().into())
+            .await
+            .expect("failed to clear the hash");
+
+        let stale_version =
+            increment_entity_version(&redis_conn, partition_key.clone(), "pi_1", 1, TTL)
+                .await
+                .expect("failed to increment the entity version");
+        // The entry was updated through postgres meanwhile, leaving the counter behind
+        let version = increment_entity_version(&redis_conn, partition_key, "pi_1", 7, TTL)
+            .await
+            .expect("failed to increment the entity version");
+
+        assert_eq!(stale_version, 2);
+        assert_eq!(version, 8);
+    }
+}
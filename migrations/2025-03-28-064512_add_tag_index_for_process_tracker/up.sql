@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS process_tracker_tag_index ON process_tracker USING GIN (tag);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS entity_version;
+
+ALTER TABLE refund DROP COLUMN IF EXISTS entity_version;
+
+ALTER TABLE dispute DROP COLUMN IF EXISTS entity_version;
+
+ALTER TABLE events DROP COLUMN IF EXISTS entity_version;
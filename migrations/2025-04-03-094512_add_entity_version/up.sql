@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS entity_version BIGINT NOT NULL DEFAULT 1;
+
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS entity_version BIGINT NOT NULL DEFAULT 1;
+
+ALTER TABLE dispute ADD COLUMN IF NOT EXISTS entity_version BIGINT NOT NULL DEFAULT 1;
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS entity_version BIGINT;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS revenue_recovery_decision_payment_id_created_at_index;
+
+DROP TABLE IF EXISTS revenue_recovery_decision;
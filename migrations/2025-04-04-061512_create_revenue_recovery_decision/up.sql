@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS revenue_recovery_decision (
+    id VARCHAR(64) NOT NULL PRIMARY KEY,
+    payment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    recovery_action VARCHAR(64),
+    attempt_triggered_by VARCHAR(64),
+    retry_count SMALLINT,
+    retry_threshold SMALLINT,
+    is_billing_connector_payment_sync_called BOOLEAN NOT NULL,
+    webhook_action VARCHAR(64),
+    task_id VARCHAR(255),
+    error VARCHAR(64),
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS revenue_recovery_decision_payment_id_created_at_index ON revenue_recovery_decision (payment_id, created_at);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS revenue_recovery_collection_item_open_payment_id_index;
+
+DROP INDEX IF EXISTS revenue_recovery_collection_item_profile_id_status_created_at_index;
+
+DROP TABLE IF EXISTS revenue_recovery_collection_item;
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS revenue_recovery_collection_item (
+    id VARCHAR(64) NOT NULL PRIMARY KEY,
+    payment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    merchant_reference_id VARCHAR(64),
+    amount_outstanding BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    last_error_code VARCHAR(255),
+    last_failure_reason TEXT,
+    customer_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    assignee VARCHAR(255),
+    assigned_by VARCHAR(64),
+    outcome VARCHAR(32),
+    resolved_by VARCHAR(64),
+    resolved_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL,
+    modified_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS revenue_recovery_collection_item_profile_id_status_created_at_index ON revenue_recovery_collection_item (profile_id, status, created_at);
+
+-- An invoice is handed off to the manual collection queue only once while it is open
+CREATE UNIQUE INDEX IF NOT EXISTS revenue_recovery_collection_item_open_payment_id_index ON revenue_recovery_collection_item (payment_id) WHERE status = 'open';
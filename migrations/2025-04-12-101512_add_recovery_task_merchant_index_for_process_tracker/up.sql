@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS process_tracker_recovery_merchant_id_schedule_time_index ON process_tracker ((tracking_data ->> 'merchant_id'), schedule_time, id) WHERE runner = 'PASSIVE_RECOVERY_WORKFLOW';
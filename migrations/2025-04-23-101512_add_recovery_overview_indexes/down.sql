@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS process_tracker_recovery_merchant_id_unfinished_status_index;
+
+DROP INDEX IF EXISTS process_tracker_recovery_merchant_id_retries_exhausted_index;
+
+DROP INDEX IF EXISTS revenue_recovery_decision_merchant_id_created_at_index;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS process_tracker_recovery_merchant_id_unfinished_status_index ON process_tracker ((tracking_data ->> 'merchant_id'), status) WHERE runner = 'PASSIVE_RECOVERY_WORKFLOW' AND status <> 'finish';
+
+CREATE INDEX IF NOT EXISTS process_tracker_recovery_merchant_id_retries_exhausted_index ON process_tracker ((tracking_data ->> 'merchant_id'), updated_at) WHERE runner = 'PASSIVE_RECOVERY_WORKFLOW' AND business_status = 'COMPLETED_EXECUTE_TASK_RETRIES_EXHAUSTED';
+
+CREATE INDEX IF NOT EXISTS revenue_recovery_decision_merchant_id_created_at_index ON revenue_recovery_decision (merchant_id, created_at);
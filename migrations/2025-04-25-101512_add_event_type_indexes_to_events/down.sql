@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS events_merchant_id_event_type_initial_events_index;
+
+DROP INDEX IF EXISTS events_business_profile_id_event_type_initial_events_index;
+
+DROP INDEX IF EXISTS events_merchant_id_event_class_initial_events_index;
+
+DROP INDEX IF EXISTS events_business_profile_id_event_class_initial_events_index;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS events_merchant_id_event_type_initial_events_index ON events (merchant_id, event_type, created_at) WHERE event_id = initial_attempt_id;
+
+CREATE INDEX IF NOT EXISTS events_business_profile_id_event_type_initial_events_index ON events (business_profile_id, event_type, created_at) WHERE event_id = initial_attempt_id;
+
+CREATE INDEX IF NOT EXISTS events_merchant_id_event_class_initial_events_index ON events (merchant_id, event_class, created_at) WHERE event_id = initial_attempt_id;
+
+CREATE INDEX IF NOT EXISTS events_business_profile_id_event_class_initial_events_index ON events (business_profile_id, event_class, created_at) WHERE event_id = initial_attempt_id;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS events_merchant_id_created_at_event_id_initial_events_index;
+
+DROP INDEX IF EXISTS events_business_profile_id_created_at_event_id_initial_events_index;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS events_merchant_id_created_at_event_id_initial_events_index ON events (merchant_id, created_at DESC, event_id DESC) WHERE event_id = initial_attempt_id;
+
+CREATE INDEX IF NOT EXISTS events_business_profile_id_created_at_event_id_initial_events_index ON events (business_profile_id, created_at DESC, event_id DESC) WHERE event_id = initial_attempt_id;
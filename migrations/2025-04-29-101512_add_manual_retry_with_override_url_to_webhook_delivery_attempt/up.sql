@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "WebhookDeliveryAttempt" ADD VALUE IF NOT EXISTS 'manual_retry_with_override_url';
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS events_archive (
+    id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    object_key VARCHAR(255) NOT NULL,
+    checksum VARCHAR(64) NOT NULL,
+    events_count INTEGER NOT NULL,
+    oldest_event_created_at TIMESTAMP NOT NULL,
+    newest_event_created_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS events_archive_merchant_id_created_at_index ON events_archive (merchant_id, created_at);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS default_recovery_payment_connector_id VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS revenue_recovery_retry_notification JSONB DEFAULT NULL;